@@ -1,7 +1,7 @@
 //! Defines the main context traits and IBC module callbacks
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::{Memo, PrefixedCoin, PrefixedDenom};
+use ibc_app_transfer_types::{Ics20Version, Memo, PrefixedCoin, PrefixedDenom};
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
@@ -13,6 +13,18 @@ pub trait TokenTransferValidationContext {
     /// get_port returns the portID for the transfer module.
     fn get_port(&self) -> Result<PortId, TokenTransferError>;
 
+    /// Returns the version of the token transfer application negotiated on
+    /// the given channel, which determines how the packets sent and received
+    /// on it are encoded.
+    ///
+    /// Channels opened before the host recorded their version are `ics20-1`
+    /// ones.
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Ics20Version, TokenTransferError>;
+
     /// Returns Ok() if the host chain supports sending coins.
     fn can_send_coins(&self) -> Result<(), TokenTransferError>;
 
@@ -68,6 +80,15 @@ pub trait TokenTransferValidationContext {
 
 /// Methods required in token transfer execution, to be implemented by the host.
 pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
+    /// Records the version of the token transfer application negotiated
+    /// during the opening handshake of the given channel.
+    fn store_channel_version(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        version: Ics20Version,
+    ) -> Result<(), TokenTransferError>;
+
     /// Executes the escrow of the tokens in a user account.
     ///
     /// `memo` field allows to incorporate additional contextual details in the
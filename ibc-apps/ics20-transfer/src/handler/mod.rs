@@ -3,18 +3,62 @@ mod on_recv_packet;
 mod send_transfer;
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::is_sender_chain_source;
-use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::packet::{PacketData, PacketDataV2};
+use ibc_app_transfer_types::{is_sender_chain_source, Ics20Version};
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Protobuf;
 pub use on_recv_packet::*;
 pub use send_transfer::*;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
+/// Decodes the data of a packet sent or received on a channel of the given
+/// version.
+///
+/// The JSON-encoded `ics20-1` packet data is converted to the v2 format,
+/// which all handlers process.
+pub fn decode_packet_data(
+    version: Ics20Version,
+    data: &[u8],
+) -> Result<PacketDataV2, TokenTransferError> {
+    match version {
+        Ics20Version::V1 => serde_json::from_slice::<PacketData>(data)
+            .map(PacketDataV2::from)
+            .map_err(|_| TokenTransferError::PacketDataDeserialization),
+        Ics20Version::V2 => {
+            let data = PacketDataV2::decode_vec(data)
+                .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+
+            data.validate_basic()?;
+
+            Ok(data)
+        }
+    }
+}
+
+/// Encodes packet data to be sent on a channel of the given version.
+///
+/// Fails if the channel is an `ics20-1` one and the packet data carries
+/// several tokens or forwarding hops.
+pub fn encode_packet_data(
+    version: Ics20Version,
+    data: PacketDataV2,
+) -> Result<Vec<u8>, TokenTransferError> {
+    match version {
+        Ics20Version::V1 => {
+            let data = PacketData::try_from(data)?;
+
+            Ok(serde_json::to_vec(&data).expect("PacketData's infallible Serialize impl failed"))
+        }
+        Ics20Version::V2 => Ok(data.encode_vec()),
+    }
+}
+
 pub fn refund_packet_token_execute(
     ctx_a: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
-    data: &PacketData,
+    data: &PacketDataV2,
 ) -> Result<(), TokenTransferError> {
     let sender = data
         .sender
@@ -22,28 +66,32 @@ pub fn refund_packet_token_execute(
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        ctx_a.unescrow_coins_execute(
-            &sender,
-            &packet.port_id_on_a,
-            &packet.chan_id_on_a,
-            &data.token,
-        )
-    }
-    // mint vouchers back to sender
-    else {
-        ctx_a.mint_coins_execute(&sender, &data.token)
+    for token in &data.tokens {
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            ctx_a.unescrow_coins_execute(
+                &sender,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                token,
+            )?;
+        }
+        // mint vouchers back to sender
+        else {
+            ctx_a.mint_coins_execute(&sender, token)?;
+        }
     }
+
+    Ok(())
 }
 
 pub fn refund_packet_token_validate(
     ctx_a: &impl TokenTransferValidationContext,
     packet: &Packet,
-    data: &PacketData,
+    data: &PacketDataV2,
 ) -> Result<(), TokenTransferError> {
     let sender = data
         .sender
@@ -51,18 +99,22 @@ pub fn refund_packet_token_validate(
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        ctx_a.unescrow_coins_validate(
-            &sender,
-            &packet.port_id_on_a,
-            &packet.chan_id_on_a,
-            &data.token,
-        )
-    } else {
-        ctx_a.mint_coins_validate(&sender, &data.token)
+    for token in &data.tokens {
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            ctx_a.unescrow_coins_validate(
+                &sender,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                token,
+            )?;
+        } else {
+            ctx_a.mint_coins_validate(&sender, token)?;
+        }
     }
+
+    Ok(())
 }
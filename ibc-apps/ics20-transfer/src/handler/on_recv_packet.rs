@@ -1,7 +1,7 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::DenomTraceEvent;
-use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{is_receiver_chain_source, TracePrefix};
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_app_transfer_types::{is_receiver_chain_source, PrefixedCoin, TracePrefix};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::module::ModuleExtras;
@@ -14,15 +14,27 @@ use crate::context::TokenTransferExecutionContext;
 /// to validate accounts and token info. But the result is then used for
 /// execution on the IBC side, including storing acknowledgements and emitting
 /// events.
+///
+/// All the tokens of the packet are validated before any of them is
+/// credited, so that the packet is either received in full or not at all.
+/// Packets naming forwarding hops are rejected, as forwarding is left to the
+/// packet forward middleware.
 pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
     ctx_b: &mut Ctx,
     packet: &Packet,
-    data: PacketData,
+    data: PacketDataV2,
 ) -> Result<ModuleExtras, (ModuleExtras, TokenTransferError)> {
     ctx_b
         .can_receive_coins()
         .map_err(|err| (ModuleExtras::empty(), err))?;
 
+    if data.forwarding.is_forwarded() {
+        return Err((
+            ModuleExtras::empty(),
+            TokenTransferError::ForwardingNotSupported,
+        ));
+    }
+
     let receiver_account = data.receiver.clone().try_into().map_err(|_| {
         (
             ModuleExtras::empty(),
@@ -30,87 +42,95 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         )
     })?;
 
-    let extras = if is_receiver_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        // sender chain is not the source, unescrow tokens
-        let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
-        let coin = {
-            let mut c = data.token;
-            c.denom.remove_trace_prefix(&prefix);
-            c
-        };
+    let mut extras = ModuleExtras::empty();
+
+    let received_coins: Vec<ReceivedCoin> = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            if is_receiver_chain_source(
+                packet.port_id_on_a.clone(),
+                packet.chan_id_on_a.clone(),
+                &token.denom,
+            ) {
+                // sender chain is not the source, unescrow tokens
+                let prefix =
+                    TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
+                let coin = {
+                    let mut c = token;
+                    c.denom.remove_trace_prefix(&prefix);
+                    c
+                };
 
-        // Note: it is correct to do the validation here because `recv_packet()`
-        // works slightly differently. We do not have a
-        // `on_recv_packet_validate()` callback because regardless of whether or
-        // not the app succeeds to receive the packet, we want to run the
-        // `execute()` phase. And this is because the app failing to receive
-        // does not constitute a failure of the message processing.
-        // Specifically, when the app fails to receive, we need to return
-        // a `TokenTransferAcknowledgement::Error` acknowledgement, which
-        // gets relayed back to the sender so that the escrowed tokens
-        // can be refunded.
-        ctx_b
-            .unescrow_coins_validate(
+                ReceivedCoin::Unescrowed(coin)
+            } else {
+                // sender chain is the source, mint vouchers
+                let prefix =
+                    TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
+                let coin = {
+                    let mut c = token;
+                    c.denom.add_trace_prefix(prefix);
+                    c
+                };
+
+                let denom_trace_event = DenomTraceEvent {
+                    trace_hash: ctx_b.denom_hash_string(&coin.denom),
+                    denom: coin.denom.clone(),
+                };
+                extras.events.push(denom_trace_event.into());
+
+                ReceivedCoin::Minted(coin)
+            }
+        })
+        .collect();
+
+    // Note: it is correct to do the validation here because `recv_packet()`
+    // works slightly differently. We do not have a
+    // `on_recv_packet_validate()` callback because regardless of whether or
+    // not the app succeeds to receive the packet, we want to run the
+    // `execute()` phase. And this is because the app failing to receive
+    // does not constitute a failure of the message processing.
+    // Specifically, when the app fails to receive, we need to return
+    // a `TokenTransferAcknowledgement::Error` acknowledgement, which
+    // gets relayed back to the sender so that the escrowed tokens
+    // can be refunded.
+    for received_coin in &received_coins {
+        let validated = match received_coin {
+            ReceivedCoin::Unescrowed(coin) => ctx_b.unescrow_coins_validate(
                 &receiver_account,
                 &packet.port_id_on_b,
                 &packet.chan_id_on_b,
-                &coin,
-            )
-            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
-        ctx_b
-            .unescrow_coins_execute(
+                coin,
+            ),
+            ReceivedCoin::Minted(coin) => ctx_b.mint_coins_validate(&receiver_account, coin),
+        };
+
+        validated.map_err(|token_err| (extras.clone(), token_err))?;
+    }
+
+    for received_coin in &received_coins {
+        let executed = match received_coin {
+            ReceivedCoin::Unescrowed(coin) => ctx_b.unescrow_coins_execute(
                 &receiver_account,
                 &packet.port_id_on_b,
                 &packet.chan_id_on_b,
-                &coin,
-            )
-            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
-
-        ModuleExtras::empty()
-    } else {
-        // sender chain is the source, mint vouchers
-        let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
-        let coin = {
-            let mut c = data.token;
-            c.denom.add_trace_prefix(prefix);
-            c
-        };
-
-        let extras = {
-            let denom_trace_event = DenomTraceEvent {
-                trace_hash: ctx_b.denom_hash_string(&coin.denom),
-                denom: coin.denom.clone(),
-            };
-            ModuleExtras {
-                events: vec![denom_trace_event.into()],
-                log: Vec::new(),
-            }
+                coin,
+            ),
+            ReceivedCoin::Minted(coin) => ctx_b.mint_coins_execute(&receiver_account, coin),
         };
 
-        // Note: it is correct to do the validation here because `recv_packet()`
-        // works slightly differently. We do not have a
-        // `on_recv_packet_validate()` callback because regardless of whether or
-        // not the app succeeds to receive the packet, we want to run the
-        // `execute()` phase. And this is because the app failing to receive
-        // does not constitute a failure of the message processing.
-        // Specifically, when the app fails to receive, we need to return
-        // a `TokenTransferAcknowledgement::Error` acknowledgement, which
-        // gets relayed back to the sender so that the escrowed tokens
-        // can be refunded.
-        ctx_b
-            .mint_coins_validate(&receiver_account, &coin)
-            .map_err(|token_err| (extras.clone(), token_err))?;
-
-        ctx_b
-            .mint_coins_execute(&receiver_account, &coin)
-            .map_err(|token_err| (extras.clone(), token_err))?;
-
-        extras
-    };
+        executed.map_err(|token_err| (extras.clone(), token_err))?;
+    }
 
     Ok(extras)
 }
+
+/// A token of a received packet, denominated the way the receiving chain
+/// traces it.
+enum ReceivedCoin {
+    /// The receiving chain is the source of the token, which it unescrows.
+    Unescrowed(PrefixedCoin),
+    /// The sending chain is the source of the token, whose vouchers the
+    /// receiving chain mints.
+    Minted(PrefixedCoin),
+}
@@ -1,6 +1,6 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::TransferEvent;
-use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
+use ibc_app_transfer_types::msgs::transfer::MsgTransferV2;
 use ibc_app_transfer_types::{is_sender_chain_source, MODULE_ID_STR};
 use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
 use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
@@ -10,18 +10,26 @@ use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::event::ModuleEvent;
 
+use super::encode_packet_data;
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
 /// Initiate a token transfer. Equivalent to calling [`send_transfer_validate`], followed by [`send_transfer_execute`].
+///
+/// Both a [`MsgTransfer`](ibc_app_transfer_types::msgs::transfer::MsgTransfer)
+/// and a [`MsgTransferV2`] are accepted. The packet is encoded according to
+/// the version of the channel it is sent on, so transfers carrying several
+/// tokens or forwarding hops can only be sent on `ics20-2` channels.
 pub fn send_transfer<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     token_ctx_a: &mut TokenCtx,
-    msg: MsgTransfer,
+    msg: impl Into<MsgTransferV2>,
 ) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketExecutionContext,
     TokenCtx: TokenTransferExecutionContext,
 {
+    let msg = msg.into();
+
     send_transfer_validate(send_packet_ctx_a, token_ctx_a, msg.clone())?;
     send_transfer_execute(send_packet_ctx_a, token_ctx_a, msg)
 }
@@ -30,14 +38,18 @@ where
 pub fn send_transfer_validate<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &SendPacketCtx,
     token_ctx_a: &TokenCtx,
-    msg: MsgTransfer,
+    msg: impl Into<MsgTransferV2>,
 ) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketValidationContext,
     TokenCtx: TokenTransferValidationContext,
 {
+    let msg = msg.into();
+
     token_ctx_a.can_send_coins()?;
 
+    msg.packet_data.validate_basic()?;
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -54,7 +66,7 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let token = &msg.packet_data.token;
+    let version = token_ctx_a.channel_version(&msg.port_id_on_a, &msg.chan_id_on_a)?;
 
     let sender: TokenCtx::AccountId = msg
         .packet_data
@@ -63,25 +75,26 @@ where
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        msg.port_id_on_a.clone(),
-        msg.chan_id_on_a.clone(),
-        &token.denom,
-    ) {
-        token_ctx_a.escrow_coins_validate(
-            &sender,
-            &msg.port_id_on_a,
-            &msg.chan_id_on_a,
-            token,
-            &msg.packet_data.memo,
-        )?;
-    } else {
-        token_ctx_a.burn_coins_validate(&sender, token, &msg.packet_data.memo)?;
+    for token in &msg.packet_data.tokens {
+        if is_sender_chain_source(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            token_ctx_a.escrow_coins_validate(
+                &sender,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                token,
+                &msg.packet_data.memo,
+            )?;
+        } else {
+            token_ctx_a.burn_coins_validate(&sender, token, &msg.packet_data.memo)?;
+        }
     }
 
     let packet = {
-        let data = serde_json::to_vec(&msg.packet_data)
-            .expect("PacketData's infallible Serialize impl failed");
+        let data = encode_packet_data(version, msg.packet_data)?;
 
         Packet {
             seq_on_a: sequence,
@@ -104,12 +117,14 @@ where
 pub fn send_transfer_execute<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     token_ctx_a: &mut TokenCtx,
-    msg: MsgTransfer,
+    msg: impl Into<MsgTransferV2>,
 ) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketExecutionContext,
     TokenCtx: TokenTransferExecutionContext,
 {
+    let msg = msg.into();
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -127,7 +142,7 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let token = &msg.packet_data.token;
+    let version = token_ctx_a.channel_version(&msg.port_id_on_a, &msg.chan_id_on_a)?;
 
     let sender = msg
         .packet_data
@@ -136,27 +151,26 @@ where
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        msg.port_id_on_a.clone(),
-        msg.chan_id_on_a.clone(),
-        &token.denom,
-    ) {
-        token_ctx_a.escrow_coins_execute(
-            &sender,
-            &msg.port_id_on_a,
-            &msg.chan_id_on_a,
-            token,
-            &msg.packet_data.memo,
-        )?;
-    } else {
-        token_ctx_a.burn_coins_execute(&sender, token, &msg.packet_data.memo)?;
+    for token in &msg.packet_data.tokens {
+        if is_sender_chain_source(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            token_ctx_a.escrow_coins_execute(
+                &sender,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                token,
+                &msg.packet_data.memo,
+            )?;
+        } else {
+            token_ctx_a.burn_coins_execute(&sender, token, &msg.packet_data.memo)?;
+        }
     }
 
     let packet = {
-        let data = {
-            serde_json::to_vec(&msg.packet_data)
-                .expect("PacketData's infallible Serialize impl failed")
-        };
+        let data = encode_packet_data(version, msg.packet_data.clone())?;
 
         Packet {
             seq_on_a: sequence,
@@ -173,19 +187,23 @@ where
     send_packet_execute(send_packet_ctx_a, packet)?;
 
     {
-        send_packet_ctx_a.log_message(format!(
-            "IBC fungible token transfer: {} --({})--> {}",
-            msg.packet_data.sender, token, msg.packet_data.receiver
-        ))?;
-
-        let transfer_event = TransferEvent {
-            sender: msg.packet_data.sender,
-            receiver: msg.packet_data.receiver,
-            amount: msg.packet_data.token.amount,
-            denom: msg.packet_data.token.denom,
-            memo: msg.packet_data.memo,
-        };
-        send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(transfer_event).into())?;
+        let packet_data = msg.packet_data;
+
+        for token in packet_data.tokens {
+            send_packet_ctx_a.log_message(format!(
+                "IBC fungible token transfer: {} --({})--> {}",
+                packet_data.sender, token, packet_data.receiver
+            ))?;
+
+            let transfer_event = TransferEvent {
+                sender: packet_data.sender.clone(),
+                receiver: packet_data.receiver.clone(),
+                amount: token.amount,
+                denom: token.denom,
+                memo: packet_data.memo.clone(),
+            };
+            send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(transfer_event).into())?;
+        }
 
         send_packet_ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
     }
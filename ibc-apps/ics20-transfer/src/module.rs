@@ -1,22 +1,24 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
-use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{ack_success_b64, VERSION};
+use ibc_app_transfer_types::{ack_success_b64, Ics20Version};
 use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
 use ibc_core::channel::types::channel::{Counterparty, Order};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::channel::types::Version;
-use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
 use ibc_core::router::types::module::ModuleExtras;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use crate::handler::{
-    process_recv_packet_execute, refund_packet_token_execute, refund_packet_token_validate,
+    decode_packet_data, process_recv_packet_execute, refund_packet_token_execute,
+    refund_packet_token_validate,
 };
 
+/// Both `ics20-1` and `ics20-2` may be proposed. If the relayer leaves the
+/// version empty, `ics20-1` is proposed, as every counterparty supports it.
 pub fn on_chan_open_init_validate(
     ctx: &impl TokenTransferValidationContext,
     order: Order,
@@ -40,25 +42,25 @@ pub fn on_chan_open_init_validate(
         });
     }
 
-    if !version.is_empty() {
-        version
-            .verify_is_expected(Version::new(VERSION.to_string()))
-            .map_err(ContextError::from)?;
-    }
+    proposed_version(version)?;
 
     Ok(())
 }
 
 pub fn on_chan_open_init_execute(
-    _ctx: &mut impl TokenTransferExecutionContext,
+    ctx: &mut impl TokenTransferExecutionContext,
     _order: Order,
     _connection_hops: &[ConnectionId],
-    _port_id: &PortId,
-    _channel_id: &ChannelId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _version: &Version,
+    version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    let version = proposed_version(version)?;
+
+    ctx.store_channel_version(port_id, channel_id, version)?;
+
+    Ok((ModuleExtras::empty(), version.into()))
 }
 
 pub fn on_chan_open_try_validate(
@@ -77,23 +79,26 @@ pub fn on_chan_open_try_validate(
         });
     }
 
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    Ics20Version::try_from(counterparty_version)?;
 
     Ok(())
 }
 
+/// Agrees to the version proposed by the counterparty.
 pub fn on_chan_open_try_execute(
-    _ctx: &mut impl TokenTransferExecutionContext,
+    ctx: &mut impl TokenTransferExecutionContext,
     _order: Order,
     _connection_hops: &[ConnectionId],
-    _port_id: &PortId,
-    _channel_id: &ChannelId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _counterparty_version: &Version,
+    counterparty_version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    let version = Ics20Version::try_from(counterparty_version)?;
+
+    ctx.store_channel_version(port_id, channel_id, version)?;
+
+    Ok((ModuleExtras::empty(), version.into()))
 }
 
 pub fn on_chan_open_ack_validate(
@@ -102,19 +107,23 @@ pub fn on_chan_open_ack_validate(
     _channel_id: &ChannelId,
     counterparty_version: &Version,
 ) -> Result<(), TokenTransferError> {
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    Ics20Version::try_from(counterparty_version)?;
 
     Ok(())
 }
 
+/// Records the version the counterparty agreed to, which is the one the
+/// channel ends up with.
 pub fn on_chan_open_ack_execute(
-    _ctx: &mut impl TokenTransferExecutionContext,
-    _port_id: &PortId,
-    _channel_id: &ChannelId,
-    _counterparty_version: &Version,
+    ctx: &mut impl TokenTransferExecutionContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
 ) -> Result<ModuleExtras, TokenTransferError> {
+    let version = Ics20Version::try_from(counterparty_version)?;
+
+    ctx.store_channel_version(port_id, channel_id, version)?;
+
     Ok(ModuleExtras::empty())
 }
 
@@ -170,10 +179,16 @@ pub fn on_recv_packet_execute(
     ctx_b: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
 ) -> (ModuleExtras, Acknowledgement) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        let ack =
-            AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into());
-        return (ModuleExtras::empty(), ack.into());
+    let decoded = ctx_b
+        .channel_version(&packet.port_id_on_b, &packet.chan_id_on_b)
+        .and_then(|version| decode_packet_data(version, &packet.data));
+
+    let data = match decoded {
+        Ok(data) => data,
+        Err(err) => {
+            let ack = AcknowledgementStatus::error(err.into());
+            return (ModuleExtras::empty(), ack.into());
+        }
     };
 
     let (mut extras, ack) = match process_recv_packet_execute(ctx_b, packet, data.clone()) {
@@ -181,15 +196,17 @@ pub fn on_recv_packet_execute(
         Err((extras, error)) => (extras, AcknowledgementStatus::error(error.into())),
     };
 
-    let recv_event = RecvEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        success: ack.is_successful(),
-    };
-    extras.events.push(recv_event.into());
+    for token in data.tokens {
+        let recv_event = RecvEvent {
+            sender: data.sender.clone(),
+            receiver: data.receiver.clone(),
+            denom: token.denom,
+            amount: token.amount,
+            memo: data.memo.clone(),
+            success: ack.is_successful(),
+        };
+        extras.events.push(recv_event.into());
+    }
 
     (extras, ack.into())
 }
@@ -203,8 +220,8 @@ pub fn on_acknowledgement_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let version = ctx.channel_version(&packet.port_id_on_a, &packet.chan_id_on_a)?;
+    let data = decode_packet_data(version, &packet.data)?;
 
     let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
         .map_err(|_| TokenTransferError::AckDeserialization)?;
@@ -222,11 +239,13 @@ pub fn on_acknowledgement_packet_execute(
     acknowledgement: &Acknowledgement,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        return (
-            ModuleExtras::empty(),
-            Err(TokenTransferError::PacketDataDeserialization),
-        );
+    let decoded = ctx
+        .channel_version(&packet.port_id_on_a, &packet.chan_id_on_a)
+        .and_then(|version| decode_packet_data(version, &packet.data));
+
+    let data = match decoded {
+        Ok(data) => data,
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
     };
 
     let Ok(acknowledgement) =
@@ -244,17 +263,25 @@ pub fn on_acknowledgement_packet_execute(
         }
     }
 
-    let ack_event = AckEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        acknowledgement: acknowledgement.clone(),
-    };
+    let mut events: Vec<ModuleEvent> = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            AckEvent {
+                sender: data.sender.clone(),
+                receiver: data.receiver.clone(),
+                denom: token.denom,
+                amount: token.amount,
+                memo: data.memo.clone(),
+                acknowledgement: acknowledgement.clone(),
+            }
+            .into()
+        })
+        .collect();
+    events.push(AckStatusEvent { acknowledgement }.into());
 
     let extras = ModuleExtras {
-        events: vec![ack_event.into(), AckStatusEvent { acknowledgement }.into()],
+        events,
         log: Vec::new(),
     };
 
@@ -269,8 +296,8 @@ pub fn on_timeout_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let version = ctx.channel_version(&packet.port_id_on_a, &packet.chan_id_on_a)?;
+    let data = decode_packet_data(version, &packet.data)?;
 
     refund_packet_token_validate(ctx, packet, &data)?;
 
@@ -282,32 +309,51 @@ pub fn on_timeout_packet_execute(
     packet: &Packet,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        return (
-            ModuleExtras::empty(),
-            Err(TokenTransferError::PacketDataDeserialization),
-        );
+    let decoded = ctx
+        .channel_version(&packet.port_id_on_a, &packet.chan_id_on_a)
+        .and_then(|version| decode_packet_data(version, &packet.data));
+
+    let data = match decoded {
+        Ok(data) => data,
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
     };
 
     if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
         return (ModuleExtras::empty(), Err(err));
     }
 
-    let timeout_event = TimeoutEvent {
-        refund_receiver: data.sender,
-        refund_denom: data.token.denom,
-        refund_amount: data.token.amount,
-        memo: data.memo,
-    };
+    let events: Vec<ModuleEvent> = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            TimeoutEvent {
+                refund_receiver: data.sender.clone(),
+                refund_denom: token.denom,
+                refund_amount: token.amount,
+                memo: data.memo.clone(),
+            }
+            .into()
+        })
+        .collect();
 
     let extras = ModuleExtras {
-        events: vec![timeout_event.into()],
+        events,
         log: Vec::new(),
     };
 
     (extras, Ok(()))
 }
 
+/// Returns the version proposed when opening a channel, which defaults to
+/// `ics20-1` if the relayer leaves it empty.
+fn proposed_version(version: &Version) -> Result<Ics20Version, TokenTransferError> {
+    if version.is_empty() {
+        return Ok(Ics20Version::V1);
+    }
+
+    Ics20Version::try_from(version)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -8,6 +8,7 @@ use ibc_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
 use super::amount::Amount;
 use super::denom::{BaseDenom, PrefixedDenom};
 use super::error::TokenTransferError;
+use super::raw::Token as RawToken;
 
 /// A `Coin` type with fully qualified `PrefixedDenom`.
 pub type PrefixedCoin = Coin<PrefixedDenom>;
@@ -109,6 +110,28 @@ impl<D: ToString> From<Coin<D>> for ProtoCoin {
     }
 }
 
+impl TryFrom<RawToken> for PrefixedCoin {
+    type Error = TokenTransferError;
+
+    fn try_from(raw_token: RawToken) -> Result<Self, Self::Error> {
+        let denom: PrefixedDenom = raw_token
+            .denom
+            .ok_or(TokenTransferError::EmptyBaseDenom)?
+            .try_into()?;
+        let amount = Amount::from_str(&raw_token.amount)?;
+        Ok(Self { denom, amount })
+    }
+}
+
+impl From<PrefixedCoin> for RawToken {
+    fn from(coin: PrefixedCoin) -> Self {
+        Self {
+            denom: Some(coin.denom.into()),
+            amount: coin.amount.to_string(),
+        }
+    }
+}
+
 impl From<BaseCoin> for PrefixedCoin {
     fn from(coin: BaseCoin) -> PrefixedCoin {
         PrefixedCoin {
@@ -10,6 +10,7 @@ use ibc_core::primitives::serializers;
 use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
 
 use super::error::TokenTransferError;
+use super::raw::{Denom as RawDenom, Hop as RawHop};
 
 /// The "base" of a denomination.
 ///
@@ -84,6 +85,14 @@ impl TracePrefix {
         }
     }
 
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
+
     /// Returns a string slice with [`TracePrefix`] removed.
     ///
     /// If the string starts with a [`TracePrefix`], i.e. `{port-id}/channel-{id}`,
@@ -118,6 +127,26 @@ impl Display for TracePrefix {
     }
 }
 
+impl TryFrom<RawHop> for TracePrefix {
+    type Error = TokenTransferError;
+
+    fn try_from(raw_hop: RawHop) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            raw_hop.port_id.parse()?,
+            raw_hop.channel_id.parse()?,
+        ))
+    }
+}
+
+impl From<TracePrefix> for RawHop {
+    fn from(prefix: TracePrefix) -> Self {
+        Self {
+            port_id: prefix.port_id.to_string(),
+            channel_id: prefix.channel_id.to_string(),
+        }
+    }
+}
+
 /// A full trace path modelled as a collection of `TracePrefix`s.
 ///
 /// Internally, the `TracePath` is modelled as a `Vec<TracePrefix>` but with the order reversed, i.e.
@@ -168,6 +197,19 @@ impl TracePath {
         Self(vec![])
     }
 
+    /// Builds a trace path out of prefixes given outermost first, i.e. in
+    /// the order they appear in the path's string representation.
+    pub fn from_prefixes(mut prefixes: Vec<TracePrefix>) -> Self {
+        prefixes.reverse();
+        Self(prefixes)
+    }
+
+    /// Returns the prefixes of the path outermost first, i.e. in the order
+    /// they appear in the path's string representation.
+    pub fn prefixes(&self) -> impl Iterator<Item = &TracePrefix> {
+        self.0.iter().rev()
+    }
+
     /// Returns a string slice with [`TracePath`] or all [`TracePrefix`]es repeatedly removed.
     ///
     /// If the string starts with a [`TracePath`], it returns a tuple of the removed
@@ -382,6 +424,40 @@ impl From<PrefixedDenom> for RawDenomTrace {
     }
 }
 
+/// Converts the structured denomination of ICS-20 v2 packets, whose trace
+/// lists the most recent hop first.
+impl TryFrom<RawDenom> for PrefixedDenom {
+    type Error = TokenTransferError;
+
+    fn try_from(raw_denom: RawDenom) -> Result<Self, Self::Error> {
+        let base_denom = BaseDenom::from_str(&raw_denom.base)?;
+        let trace_prefixes = raw_denom
+            .trace
+            .into_iter()
+            .map(TracePrefix::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            trace_path: TracePath::from_prefixes(trace_prefixes),
+            base_denom,
+        })
+    }
+}
+
+impl From<PrefixedDenom> for RawDenom {
+    fn from(denom: PrefixedDenom) -> Self {
+        Self {
+            base: denom.base_denom.to_string(),
+            trace: denom
+                .trace_path
+                .prefixes()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
 impl From<BaseDenom> for PrefixedDenom {
     fn from(denom: BaseDenom) -> Self {
         Self {
@@ -655,4 +731,24 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_raw_denom_trace_order() -> Result<(), TokenTransferError> {
+        let denom = PrefixedDenom::from_str("transfer/channel-0/transfer/channel-1/uatom")?;
+
+        let raw_denom = RawDenom::from(denom.clone());
+        assert_eq!(raw_denom.base, "uatom");
+        assert_eq!(
+            raw_denom
+                .trace
+                .iter()
+                .map(|hop| hop.channel_id.as_str())
+                .collect::<Vec<_>>(),
+            ["channel-0", "channel-1"]
+        );
+
+        assert_eq!(PrefixedDenom::try_from(raw_denom)?, denom);
+
+        Ok(())
+    }
 }
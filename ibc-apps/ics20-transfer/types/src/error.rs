@@ -11,6 +11,8 @@ use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use uint::FromDecStrErr;
 
+use crate::Ics20Version;
+
 #[derive(Display, Debug)]
 pub enum TokenTransferError {
     /// context error: `{0}`
@@ -47,6 +49,19 @@ pub enum TokenTransferError {
     InvalidAmount(FromDecStrErr),
     /// invalid token
     InvalidToken,
+    /// packet carries no tokens
+    EmptyTokens,
+    /// invalid forwarding: `{reason}`
+    InvalidForwarding { reason: String },
+    /// forwarding is not supported by the token transfer application alone
+    ForwardingNotSupported,
+    /// unsupported token transfer version: `{version}`
+    UnsupportedVersion { version: String },
+    /// packet data cannot be encoded as `{version}`: `{reason}`
+    IncompatiblePacketData {
+        version: Ics20Version,
+        reason: String,
+    },
     /// expected `{expect_order}` channel, got `{got_order}`
     ChannelNotUnordered {
         expect_order: Order,
@@ -77,6 +92,12 @@ pub enum TokenTransferError {
     InvalidCoin { coin: String },
     /// decoding raw bytes as UTF8 string error: `{0}`
     Utf8Decode(Utf8Error),
+    /// mismatched event type: expected `{expected}`, actual `{actual}`
+    MismatchedEventType { expected: String, actual: String },
+    /// missing event attribute `{key}`
+    MissingEventAttribute { key: String },
+    /// invalid event attribute `{key}`: `{reason}`
+    InvalidEventAttribute { key: String, reason: String },
     /// other error: `{0}`
     Other(String),
 }
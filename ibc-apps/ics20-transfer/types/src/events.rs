@@ -1,11 +1,15 @@
 //! Defines all token transfer event types
-use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use ibc_core::channel::types::acknowledgement::{AcknowledgementStatus, StatusValue};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_core::router::types::event::ModuleEvent;
 
 use super::Memo;
-use crate::{Amount, PrefixedDenom, MODULE_ID_STR};
+use crate::error::TokenTransferError;
+use crate::{Amount, PrefixedDenom, ACK_SUCCESS_B64, MODULE_ID_STR};
 
 const EVENT_TYPE_PACKET: &str = "fungible_token_packet";
 const EVENT_TYPE_TIMEOUT: &str = "timeout";
@@ -58,6 +62,23 @@ impl From<RecvEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for RecvEvent {
+    type Error = TokenTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_PACKET)?;
+
+        Ok(Self {
+            sender: Signer::from(event_attribute(&event, "sender")?.to_string()),
+            receiver: Signer::from(event_attribute(&event, "receiver")?.to_string()),
+            denom: parse_event_attribute(&event, "denom")?,
+            amount: parse_event_attribute(&event, "amount")?,
+            memo: parse_event_attribute(&event, "memo")?,
+            success: parse_event_attribute(&event, "success")?,
+        })
+    }
+}
+
 /// Event emitted in the `onAcknowledgePacket` module callback
 pub struct AckEvent {
     pub sender: Signer,
@@ -93,6 +114,23 @@ impl From<AckEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for AckEvent {
+    type Error = TokenTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_PACKET)?;
+
+        Ok(Self {
+            sender: Signer::from(event_attribute(&event, "sender")?.to_string()),
+            receiver: Signer::from(event_attribute(&event, "receiver")?.to_string()),
+            denom: parse_event_attribute(&event, "denom")?,
+            amount: parse_event_attribute(&event, "amount")?,
+            memo: parse_event_attribute(&event, "memo")?,
+            acknowledgement: acknowledgement_status(&event)?,
+        })
+    }
+}
+
 /// Event emitted in the `onAcknowledgePacket` module callback to indicate
 /// whether the acknowledgement is a success or a failure
 pub struct AckStatusEvent {
@@ -114,6 +152,25 @@ impl From<AckStatusEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for AckStatusEvent {
+    type Error = TokenTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_PACKET)?;
+
+        let acknowledgement = match (
+            event_attribute(&event, "success"),
+            event_attribute(&event, "error"),
+        ) {
+            (Ok(value), _) => AcknowledgementStatus::success(status_value("success", value)?),
+            (_, Ok(value)) => AcknowledgementStatus::error(status_value("error", value)?),
+            (Err(e), Err(_)) => return Err(e),
+        };
+
+        Ok(Self { acknowledgement })
+    }
+}
+
 /// Event emitted in the `onTimeoutPacket` module callback
 pub struct TimeoutEvent {
     pub refund_receiver: Signer,
@@ -143,6 +200,21 @@ impl From<TimeoutEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for TimeoutEvent {
+    type Error = TokenTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_TIMEOUT)?;
+
+        Ok(Self {
+            refund_receiver: Signer::from(event_attribute(&event, "refund_receiver")?.to_string()),
+            refund_denom: parse_event_attribute(&event, "refund_denom")?,
+            refund_amount: parse_event_attribute(&event, "refund_amount")?,
+            memo: parse_event_attribute(&event, "memo")?,
+        })
+    }
+}
+
 /// Event emitted in the `onRecvPacket` module callback when new tokens are minted
 pub struct DenomTraceEvent {
     pub trace_hash: Option<String>,
@@ -163,6 +235,21 @@ impl From<DenomTraceEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for DenomTraceEvent {
+    type Error = TokenTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_DENOM_TRACE)?;
+
+        Ok(Self {
+            trace_hash: event_attribute(&event, "trace_hash")
+                .ok()
+                .map(ToString::to_string),
+            denom: parse_event_attribute(&event, "denom")?,
+        })
+    }
+}
+
 /// Event emitted after a successful `sendTransfer`
 pub struct TransferEvent {
     pub sender: Signer,
@@ -195,6 +282,22 @@ impl From<TransferEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for TransferEvent {
+    type Error = TokenTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_TRANSFER)?;
+
+        Ok(Self {
+            sender: Signer::from(event_attribute(&event, "sender")?.to_string()),
+            receiver: Signer::from(event_attribute(&event, "receiver")?.to_string()),
+            amount: parse_event_attribute(&event, "amount")?,
+            denom: parse_event_attribute(&event, "denom")?,
+            memo: parse_event_attribute(&event, "memo")?,
+        })
+    }
+}
+
 impl From<Event> for ModuleEvent {
     fn from(ev: Event) -> Self {
         match ev {
@@ -207,3 +310,189 @@ impl From<Event> for ModuleEvent {
         }
     }
 }
+
+/// Parses a module event back into the event it was converted from.
+///
+/// As the packet events share the same type, they are told apart by their
+/// attributes: only the `AckEvent` carries an `acknowledgement`, and only the
+/// `RecvEvent` carries a `success` flag along with the `module` attribute.
+impl TryFrom<ModuleEvent> for Event {
+    type Error = TokenTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        let has_attribute = |key: &str| event.attributes.iter().any(|attr| attr.key == key);
+
+        match event.kind.as_str() {
+            EVENT_TYPE_PACKET if has_attribute("acknowledgement") => {
+                event.try_into().map(Event::Ack)
+            }
+            EVENT_TYPE_PACKET if has_attribute("module") => event.try_into().map(Event::Recv),
+            EVENT_TYPE_PACKET => event.try_into().map(Event::AckStatus),
+            EVENT_TYPE_TIMEOUT => event.try_into().map(Event::Timeout),
+            EVENT_TYPE_DENOM_TRACE => event.try_into().map(Event::DenomTrace),
+            EVENT_TYPE_TRANSFER => event.try_into().map(Event::Transfer),
+            _ => Err(TokenTransferError::MismatchedEventType {
+                expected: [
+                    EVENT_TYPE_PACKET,
+                    EVENT_TYPE_TIMEOUT,
+                    EVENT_TYPE_DENOM_TRACE,
+                    EVENT_TYPE_TRANSFER,
+                ]
+                .join(" | "),
+                actual: event.kind,
+            }),
+        }
+    }
+}
+
+/// Checks that a module event is of the expected type.
+fn verify_event_type(event: &ModuleEvent, expected: &str) -> Result<(), TokenTransferError> {
+    if event.kind != expected {
+        return Err(TokenTransferError::MismatchedEventType {
+            expected: expected.to_string(),
+            actual: event.kind.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the value of the attribute of a module event with the given key.
+fn event_attribute<'a>(event: &'a ModuleEvent, key: &str) -> Result<&'a str, TokenTransferError> {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.as_str())
+        .ok_or_else(|| TokenTransferError::MissingEventAttribute {
+            key: key.to_string(),
+        })
+}
+
+/// Parses the value of the attribute of a module event with the given key.
+fn parse_event_attribute<T>(event: &ModuleEvent, key: &str) -> Result<T, TokenTransferError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    T::from_str(event_attribute(event, key)?).map_err(|e| {
+        TokenTransferError::InvalidEventAttribute {
+            key: key.to_string(),
+            reason: e.to_string(),
+        }
+    })
+}
+
+/// Recovers the acknowledgement status of an `AckEvent`.
+///
+/// The event only carries the value of the status, so the acknowledgement is
+/// deemed successful whenever it is the success value written by this
+/// application, [`ACK_SUCCESS_B64`].
+fn acknowledgement_status(
+    event: &ModuleEvent,
+) -> Result<AcknowledgementStatus, TokenTransferError> {
+    let value = event_attribute(event, "acknowledgement")?;
+    let status_value = status_value("acknowledgement", value)?;
+
+    if value == ACK_SUCCESS_B64 {
+        Ok(AcknowledgementStatus::success(status_value))
+    } else {
+        Ok(AcknowledgementStatus::error(status_value))
+    }
+}
+
+/// Builds the acknowledgement status value held by the attribute with the
+/// given key.
+fn status_value(key: &str, value: &str) -> Result<StatusValue, TokenTransferError> {
+    StatusValue::new(value).map_err(|e| TokenTransferError::InvalidEventAttribute {
+        key: key.to_string(),
+        reason: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ack_success_b64;
+
+    #[test]
+    fn module_event_to_transfer_events() {
+        let recv_event = ModuleEvent::from(RecvEvent {
+            sender: Signer::from("sender".to_string()),
+            receiver: Signer::from("receiver".to_string()),
+            denom: PrefixedDenom::from_str("transfer/channel-0/uatom").unwrap(),
+            amount: Amount::from(10u64),
+            memo: Memo::from("memo"),
+            success: true,
+        });
+
+        let Event::Recv(recv_event) = Event::try_from(recv_event).unwrap() else {
+            panic!("expected a receive event");
+        };
+        assert_eq!(recv_event.receiver, Signer::from("receiver".to_string()));
+        assert_eq!(recv_event.amount, Amount::from(10u64));
+        assert!(recv_event.success);
+
+        let ack_event = ModuleEvent::from(AckEvent {
+            sender: Signer::from("sender".to_string()),
+            receiver: Signer::from("receiver".to_string()),
+            denom: PrefixedDenom::from_str("uatom").unwrap(),
+            amount: Amount::from(10u64),
+            memo: Memo::from(""),
+            acknowledgement: AcknowledgementStatus::success(ack_success_b64()),
+        });
+
+        let Event::Ack(ack_event) = Event::try_from(ack_event).unwrap() else {
+            panic!("expected an acknowledgement event");
+        };
+        assert!(ack_event.acknowledgement.is_successful());
+
+        let ack_status_event = ModuleEvent::from(AckStatusEvent {
+            acknowledgement: AcknowledgementStatus::error(StatusValue::new("failed").unwrap()),
+        });
+
+        let Event::AckStatus(ack_status_event) = Event::try_from(ack_status_event).unwrap() else {
+            panic!("expected an acknowledgement status event");
+        };
+        assert!(!ack_status_event.acknowledgement.is_successful());
+
+        let denom_trace_event = ModuleEvent::from(DenomTraceEvent {
+            trace_hash: None,
+            denom: PrefixedDenom::from_str("uatom").unwrap(),
+        });
+
+        let Event::DenomTrace(denom_trace_event) = Event::try_from(denom_trace_event).unwrap()
+        else {
+            panic!("expected a denomination trace event");
+        };
+        assert_eq!(denom_trace_event.trace_hash, None);
+    }
+
+    #[test]
+    fn module_event_to_transfer_event_fails() {
+        let mut timeout_event = ModuleEvent::from(TimeoutEvent {
+            refund_receiver: Signer::from("receiver".to_string()),
+            refund_denom: PrefixedDenom::from_str("uatom").unwrap(),
+            refund_amount: Amount::from(10u64),
+            memo: Memo::from(""),
+        });
+        timeout_event
+            .attributes
+            .retain(|attr| attr.key != "refund_amount");
+
+        assert!(matches!(
+            Event::try_from(timeout_event),
+            Err(TokenTransferError::MissingEventAttribute { .. })
+        ));
+
+        let unknown_event = ModuleEvent {
+            kind: "unknown".to_string(),
+            attributes: vec![],
+        };
+
+        assert!(matches!(
+            Event::try_from(unknown_event),
+            Err(TokenTransferError::MismatchedEventType { .. })
+        ));
+    }
+}
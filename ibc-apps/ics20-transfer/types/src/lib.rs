@@ -19,6 +19,7 @@ mod amount;
 mod coin;
 mod denom;
 mod memo;
+mod version;
 
 pub use amount::*;
 pub use coin::*;
@@ -27,9 +28,11 @@ pub mod error;
 pub mod events;
 pub mod msgs;
 pub mod packet;
+pub mod raw;
 pub use memo::*;
 /// Re-exports `U256` from `primitive-types` crate for convenience.
 pub use primitive_types::U256;
+pub use version::*;
 
 /// Re-exports ICS-20 token transfer proto types from the `ibc-proto` crate.
 pub mod proto {
@@ -46,6 +49,10 @@ pub const PORT_ID_STR: &str = "transfer";
 /// ICS20 application current version.
 pub const VERSION: &str = "ics20-1";
 
+/// ICS20 application version supporting multiple tokens per packet and
+/// forwarding.
+pub const VERSION_V2: &str = "ics20-2";
+
 /// The successful string used for creating an acknowledgement status,
 /// equivalent to `base64::encode(0x01)`.
 pub const ACK_SUCCESS_B64: &str = "AQ==";
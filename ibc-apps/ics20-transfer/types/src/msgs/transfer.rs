@@ -11,7 +11,7 @@ use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
 use ibc_proto::Protobuf;
 
 use crate::error::TokenTransferError;
-use crate::packet::PacketData;
+use crate::packet::{PacketData, PacketDataV2};
 
 pub(crate) const TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
 
@@ -118,3 +118,48 @@ impl TryFrom<Any> for MsgTransfer {
         }
     }
 }
+
+/// Message used to build an ICS-20 token transfer packet that may carry
+/// several tokens and forwarding hops, which only `ics20-2` channels accept.
+///
+/// As the `MsgTransfer` of `ibc-proto` does not carry the `tokens` and
+/// `forwarding` fields yet, this message has no protobuf counterpart and is
+/// built by the host directly. A [`MsgTransfer`] converts into it, so that
+/// the same handler serves both.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgTransferV2 {
+    /// the port on which the packet will be sent
+    pub port_id_on_a: PortId,
+    /// the channel by which the packet will be sent
+    pub chan_id_on_a: ChannelId,
+    /// token transfer packet data of the packet that will be sent
+    pub packet_data: PacketDataV2,
+    /// Timeout height relative to the current block height.
+    /// The timeout is disabled when set to None.
+    pub timeout_height_on_b: TimeoutHeight,
+    /// Timeout timestamp relative to the current block timestamp.
+    /// The timeout is disabled when set to 0.
+    pub timeout_timestamp_on_b: Timestamp,
+}
+
+impl From<MsgTransfer> for MsgTransferV2 {
+    fn from(msg: MsgTransfer) -> Self {
+        Self {
+            port_id_on_a: msg.port_id_on_a,
+            chan_id_on_a: msg.chan_id_on_a,
+            packet_data: msg.packet_data.into(),
+            timeout_height_on_b: msg.timeout_height_on_b,
+            timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
+        }
+    }
+}
@@ -1,13 +1,22 @@
-//! Contains the `PacketData` type that defines the structure of token transfers' packet bytes
+//! Contains the `PacketData` and `PacketDataV2` types that define the
+//! structure of token transfers' packet bytes
 
 use core::str::FromStr;
 
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_proto::Protobuf;
 
 use super::error::TokenTransferError;
-use super::{Amount, Memo, PrefixedCoin, PrefixedDenom};
+use super::raw::{
+    ForwardingPacketData as RawForwardingPacketData, FungibleTokenPacketDataV2 as RawPacketDataV2,
+};
+use super::{Amount, Ics20Version, Memo, PrefixedCoin, PrefixedDenom, TracePrefix};
+
+/// The maximum number of hops an ICS-20 v2 transfer may be forwarded
+/// through.
+pub const MAXIMUM_FORWARDING_HOPS: usize = 8;
 
 /// Defines the structure of token transfers' packet bytes
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -60,10 +69,219 @@ impl From<PacketData> for RawPacketData {
     }
 }
 
+/// Defines the structure of ICS-20 v2 token transfers' packet bytes, which
+/// are protobuf-encoded.
+///
+/// Unlike [`PacketData`], the packet carries any number of tokens, each
+/// denominated with a structured trace, and may name the hops through which
+/// the tokens are forwarded once received.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketDataV2 {
+    pub tokens: Vec<PrefixedCoin>,
+    pub sender: Signer,
+    pub receiver: Signer,
+    pub memo: Memo,
+    pub forwarding: ForwardingPacketData,
+}
+
+impl PacketDataV2 {
+    /// Performs the stateless checks of the packet data, which every packet
+    /// sent or received on an `ics20-2` channel must pass.
+    pub fn validate_basic(&self) -> Result<(), TokenTransferError> {
+        if self.tokens.is_empty() {
+            return Err(TokenTransferError::EmptyTokens);
+        }
+
+        if self.tokens.iter().any(|token| token.amount.is_zero()) {
+            return Err(TokenTransferError::InvalidToken);
+        }
+
+        self.forwarding.validate_basic()?;
+
+        if self.forwarding.is_forwarded() && !self.memo.as_ref().is_empty() {
+            return Err(TokenTransferError::InvalidForwarding {
+                reason: "memo must be empty when forwarding hops are set, use the destination memo instead".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl From<PacketData> for PacketDataV2 {
+    fn from(pkt_data: PacketData) -> Self {
+        Self {
+            tokens: vec![pkt_data.token],
+            sender: pkt_data.sender,
+            receiver: pkt_data.receiver,
+            memo: pkt_data.memo,
+            forwarding: ForwardingPacketData::default(),
+        }
+    }
+}
+
+/// Converts the packet data to the `ics20-1` format, which only carries a
+/// single token and no forwarding hops.
+impl TryFrom<PacketDataV2> for PacketData {
+    type Error = TokenTransferError;
+
+    fn try_from(mut pkt_data: PacketDataV2) -> Result<Self, Self::Error> {
+        if pkt_data.forwarding.is_forwarded() {
+            return Err(TokenTransferError::IncompatiblePacketData {
+                version: Ics20Version::V1,
+                reason: "forwarding is not supported".to_string(),
+            });
+        }
+
+        if pkt_data.tokens.len() != 1 {
+            return Err(TokenTransferError::IncompatiblePacketData {
+                version: Ics20Version::V1,
+                reason: format!("expected a single token, got {}", pkt_data.tokens.len()),
+            });
+        }
+
+        Ok(Self {
+            token: pkt_data.tokens.remove(0),
+            sender: pkt_data.sender,
+            receiver: pkt_data.receiver,
+            memo: pkt_data.memo,
+        })
+    }
+}
+
+impl Protobuf<RawPacketDataV2> for PacketDataV2 {}
+
+impl TryFrom<RawPacketDataV2> for PacketDataV2 {
+    type Error = TokenTransferError;
+
+    fn try_from(raw_pkt_data: RawPacketDataV2) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tokens: raw_pkt_data
+                .tokens
+                .into_iter()
+                .map(PrefixedCoin::try_from)
+                .collect::<Result<_, _>>()?,
+            sender: raw_pkt_data.sender.into(),
+            receiver: raw_pkt_data.receiver.into(),
+            memo: raw_pkt_data.memo.into(),
+            forwarding: raw_pkt_data
+                .forwarding
+                .map(ForwardingPacketData::try_from)
+                .transpose()?
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl From<PacketDataV2> for RawPacketDataV2 {
+    fn from(pkt_data: PacketDataV2) -> Self {
+        Self {
+            tokens: pkt_data.tokens.into_iter().map(Into::into).collect(),
+            sender: pkt_data.sender.to_string(),
+            receiver: pkt_data.receiver.to_string(),
+            memo: pkt_data.memo.to_string(),
+            forwarding: Some(pkt_data.forwarding.into()),
+        }
+    }
+}
+
+/// The hops through which the tokens of an ICS-20 v2 transfer are forwarded
+/// once received, and the memo of the transfer to the final receiver.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardingPacketData {
+    /// The hops the tokens are forwarded through, starting with the one
+    /// leaving the receiving chain.
+    pub hops: Vec<TracePrefix>,
+    /// The memo of the transfer to the final receiver.
+    pub destination_memo: Memo,
+}
+
+impl ForwardingPacketData {
+    /// Returns `true` if the tokens are to be forwarded past the receiving
+    /// chain.
+    pub fn is_forwarded(&self) -> bool {
+        !self.hops.is_empty()
+    }
+
+    pub fn validate_basic(&self) -> Result<(), TokenTransferError> {
+        if self.hops.len() > MAXIMUM_FORWARDING_HOPS {
+            return Err(TokenTransferError::InvalidForwarding {
+                reason: format!(
+                    "{} hops exceed the maximum of {MAXIMUM_FORWARDING_HOPS}",
+                    self.hops.len()
+                ),
+            });
+        }
+
+        if !self.is_forwarded() && !self.destination_memo.as_ref().is_empty() {
+            return Err(TokenTransferError::InvalidForwarding {
+                reason: "destination memo requires forwarding hops".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for ForwardingPacketData {
+    fn default() -> Self {
+        Self {
+            hops: Vec::new(),
+            destination_memo: "".into(),
+        }
+    }
+}
+
+impl TryFrom<RawForwardingPacketData> for ForwardingPacketData {
+    type Error = TokenTransferError;
+
+    fn try_from(raw_forwarding: RawForwardingPacketData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            hops: raw_forwarding
+                .hops
+                .into_iter()
+                .map(TracePrefix::try_from)
+                .collect::<Result<_, _>>()?,
+            destination_memo: raw_forwarding.destination_memo.into(),
+        })
+    }
+}
+
+impl From<ForwardingPacketData> for RawForwardingPacketData {
+    fn from(forwarding: ForwardingPacketData) -> Self {
+        Self {
+            destination_memo: forwarding.destination_memo.to_string(),
+            hops: forwarding.hops.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use primitive_types::U256;
 
+    use ibc_core::host::types::identifiers::ChannelId;
+
     use super::*;
     use crate::BaseCoin;
 
@@ -118,4 +336,75 @@ mod tests {
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data());
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data_without_memo());
     }
+
+    fn dummy_packet_data_v2() -> PacketDataV2 {
+        let mut pkt_data = PacketDataV2::from(PacketData::new_dummy());
+        pkt_data
+            .tokens
+            .push(PrefixedCoin::from_str("5transfer/channel-3/uosmo").unwrap());
+        pkt_data
+    }
+
+    fn dummy_forwarding(hops: usize) -> ForwardingPacketData {
+        ForwardingPacketData {
+            hops: (0..hops)
+                .map(|i| TracePrefix::new("transfer".parse().unwrap(), ChannelId::new(i as u64)))
+                .collect(),
+            destination_memo: "final memo".into(),
+        }
+    }
+
+    #[test]
+    fn test_packet_data_v2_proto_roundtrip() {
+        let mut pkt_data = dummy_packet_data_v2();
+        pkt_data.forwarding = dummy_forwarding(2);
+
+        let encoded = pkt_data.clone().encode_vec();
+        let decoded = PacketDataV2::decode_vec(&encoded).unwrap();
+
+        assert_eq!(decoded, pkt_data);
+    }
+
+    #[test]
+    fn test_packet_data_v2_validate_basic() {
+        dummy_packet_data_v2().validate_basic().unwrap();
+
+        let mut no_tokens = dummy_packet_data_v2();
+        no_tokens.tokens.clear();
+        assert!(no_tokens.validate_basic().is_err());
+
+        let mut zero_amount = dummy_packet_data_v2();
+        zero_amount.tokens[0].amount = U256::zero().into();
+        assert!(zero_amount.validate_basic().is_err());
+
+        let mut forwarded = dummy_packet_data_v2();
+        forwarded.forwarding = dummy_forwarding(MAXIMUM_FORWARDING_HOPS);
+        forwarded.validate_basic().unwrap();
+
+        forwarded.memo = "not allowed".into();
+        assert!(forwarded.validate_basic().is_err());
+
+        let mut too_many_hops = dummy_packet_data_v2();
+        too_many_hops.forwarding = dummy_forwarding(MAXIMUM_FORWARDING_HOPS + 1);
+        assert!(too_many_hops.validate_basic().is_err());
+
+        let mut stray_destination_memo = dummy_packet_data_v2();
+        stray_destination_memo.forwarding = dummy_forwarding(0);
+        assert!(stray_destination_memo.validate_basic().is_err());
+    }
+
+    #[test]
+    fn test_packet_data_v2_to_v1() {
+        let pkt_data = PacketDataV2::from(PacketData::new_dummy());
+        assert_eq!(
+            PacketData::try_from(pkt_data).unwrap(),
+            PacketData::new_dummy()
+        );
+
+        assert!(PacketData::try_from(dummy_packet_data_v2()).is_err());
+
+        let mut forwarded = PacketDataV2::from(PacketData::new_dummy());
+        forwarded.forwarding = dummy_forwarding(1);
+        assert!(PacketData::try_from(forwarded).is_err());
+    }
 }
@@ -0,0 +1,92 @@
+//! Protobuf definitions of the ICS-20 v2 packet data, mirroring those of
+//! `ibc-go`. They are defined here until `ibc-proto` ships them.
+use ibc_core::primitives::prelude::*;
+
+/// The packet data of an ICS-20 v2 token transfer.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct FungibleTokenPacketDataV2 {
+    /// the tokens to be transferred
+    #[prost(message, repeated, tag = "1")]
+    pub tokens: Vec<Token>,
+    /// the sender address
+    #[prost(string, tag = "2")]
+    pub sender: String,
+    /// the recipient address on the destination chain
+    #[prost(string, tag = "3")]
+    pub receiver: String,
+    /// optional memo
+    #[prost(string, tag = "4")]
+    pub memo: String,
+    /// optional forwarding information
+    #[prost(message, optional, tag = "5")]
+    pub forwarding: Option<ForwardingPacketData>,
+}
+
+impl prost::Name for FungibleTokenPacketDataV2 {
+    const NAME: &'static str = "FungibleTokenPacketDataV2";
+    const PACKAGE: &'static str = "ibc.applications.transfer.v2";
+}
+
+/// The hops through which the tokens of a transfer are forwarded, along
+/// with the memo of the transfer to the final receiver.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ForwardingPacketData {
+    /// the memo passed to the final receiver
+    #[prost(string, tag = "1")]
+    pub destination_memo: String,
+    /// the hops the tokens are forwarded through
+    #[prost(message, repeated, tag = "2")]
+    pub hops: Vec<Hop>,
+}
+
+impl prost::Name for ForwardingPacketData {
+    const NAME: &'static str = "ForwardingPacketData";
+    const PACKAGE: &'static str = "ibc.applications.transfer.v2";
+}
+
+/// A token with its denomination and amount.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Token {
+    /// the denomination of the token
+    #[prost(message, optional, tag = "1")]
+    pub denom: Option<Denom>,
+    /// the amount of the token, as a decimal string
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+impl prost::Name for Token {
+    const NAME: &'static str = "Token";
+    const PACKAGE: &'static str = "ibc.applications.transfer.v1";
+}
+
+/// A denomination with its structured trace of hops.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Denom {
+    /// the base denomination
+    #[prost(string, tag = "1")]
+    pub base: String,
+    /// the hops the token went through, most recent first
+    #[prost(message, repeated, tag = "3")]
+    pub trace: Vec<Hop>,
+}
+
+impl prost::Name for Denom {
+    const NAME: &'static str = "Denom";
+    const PACKAGE: &'static str = "ibc.applications.transfer.v1";
+}
+
+/// A port and channel pair, i.e. one hop of a trace or of a forwarding
+/// path.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Hop {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+}
+
+impl prost::Name for Hop {
+    const NAME: &'static str = "Hop";
+    const PACKAGE: &'static str = "ibc.applications.transfer.v1";
+}
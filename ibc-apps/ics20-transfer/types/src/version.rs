@@ -0,0 +1,111 @@
+//! Defines the versions of the token transfer application.
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use ibc_core::channel::types::Version;
+use ibc_core::primitives::prelude::*;
+
+use crate::error::TokenTransferError;
+use crate::{VERSION, VERSION_V2};
+
+/// The version of the token transfer application negotiated on a channel.
+///
+/// Channels of both versions coexist on a chain: `ics20-1` channels carry
+/// JSON-encoded [`PacketData`](crate::packet::PacketData) with a single
+/// token, while `ics20-2` channels carry protobuf-encoded
+/// [`PacketDataV2`](crate::packet::PacketDataV2) with any number of tokens
+/// and optional forwarding hops.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    ),
+    // The derived codecs cast the variant indices to `usize`.
+    allow(trivial_numeric_casts)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Ics20Version {
+    V1,
+    V2,
+}
+
+impl Ics20Version {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::V1 => VERSION,
+            Self::V2 => VERSION_V2,
+        }
+    }
+
+    /// Returns `true` if packets sent on a channel of this version may carry
+    /// several tokens and forwarding hops.
+    pub fn is_v2(&self) -> bool {
+        matches!(self, Self::V2)
+    }
+}
+
+impl Display for Ics20Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Ics20Version {
+    type Err = TokenTransferError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            VERSION => Ok(Self::V1),
+            VERSION_V2 => Ok(Self::V2),
+            _ => Err(TokenTransferError::UnsupportedVersion {
+                version: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<&Version> for Ics20Version {
+    type Error = TokenTransferError;
+
+    fn try_from(version: &Version) -> Result<Self, Self::Error> {
+        version.as_str().parse()
+    }
+}
+
+impl From<Ics20Version> for Version {
+    fn from(version: Ics20Version) -> Self {
+        Version::new(version.as_str().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_roundtrip() {
+        for version in [Ics20Version::V1, Ics20Version::V2] {
+            let channel_version = Version::from(version);
+
+            assert_eq!(Ics20Version::try_from(&channel_version).unwrap(), version);
+        }
+
+        assert_eq!(Ics20Version::V1.to_string(), "ics20-1");
+        assert_eq!(Ics20Version::V2.to_string(), "ics20-2");
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        for version in ["", "ics20-3", "ics20", "{\"fee_version\":\"ics29-1\"}"] {
+            assert!(version.parse::<Ics20Version>().is_err());
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Defines the main context traits of the interchain accounts controller and
+//! host submodules
+
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Signer;
+
+/// Methods required in interchain accounts controller validation, to be
+/// implemented by the host
+pub trait ControllerValidationContext {
+    /// Returns `true` if the controller submodule is enabled.
+    fn is_controller_enabled(&self) -> bool;
+
+    /// Returns the connection the given channel is built on, as recorded in
+    /// its channel end.
+    fn channel_connection_id(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ConnectionId, InterchainAccountError>;
+
+    /// Returns the channel the interchain account of the given controller
+    /// port is controlled through, if any.
+    fn active_channel_id(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Option<ChannelId>;
+
+    /// Returns the address, on the host chain, of the interchain account of
+    /// the given controller port, if it was registered.
+    fn interchain_account(&self, connection_id: &ConnectionId, port_id: &PortId) -> Option<Signer>;
+}
+
+/// Methods required in interchain accounts controller execution, to be
+/// implemented by the host
+pub trait ControllerExecutionContext: ControllerValidationContext {
+    fn store_active_channel_id(
+        &mut self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+        channel_id: ChannelId,
+    ) -> Result<(), InterchainAccountError>;
+
+    fn store_interchain_account(
+        &mut self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+        address: Signer,
+    ) -> Result<(), InterchainAccountError>;
+}
+
+/// Methods required in interchain accounts host validation, to be
+/// implemented by the host
+pub trait HostValidationContext {
+    /// Returns `true` if the host submodule is enabled.
+    fn is_host_enabled(&self) -> bool;
+
+    /// Returns the connection the given channel is built on, as recorded in
+    /// its channel end.
+    fn channel_connection_id(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ConnectionId, InterchainAccountError>;
+
+    /// Returns the channel through which the interchain account of the given
+    /// controller port is controlled, if any.
+    fn active_channel_id(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Option<ChannelId>;
+
+    /// Returns the address of the interchain account of the given controller
+    /// port, if it was created.
+    fn interchain_account(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Option<Signer>;
+
+    /// Returns `true` if interchain accounts are allowed to execute messages
+    /// of the given type.
+    fn is_message_allowed(&self, type_url: &str) -> bool;
+}
+
+/// Methods required in interchain accounts host execution, to be implemented
+/// by the host
+pub trait HostExecutionContext: HostValidationContext {
+    /// Creates a new interchain account for the given controller port and
+    /// returns its address.
+    fn create_interchain_account(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Signer, InterchainAccountError>;
+
+    fn store_active_channel_id(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        channel_id: ChannelId,
+    ) -> Result<(), InterchainAccountError>;
+
+    fn store_interchain_account(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        address: Signer,
+    ) -> Result<(), InterchainAccountError>;
+
+    /// Executes the messages on behalf of the interchain account and returns
+    /// their responses.
+    ///
+    /// The host must check that the interchain account is the signer of
+    /// every message, and must execute the messages atomically: if any of
+    /// them fails, none of their state changes may be committed.
+    fn execute_tx(
+        &mut self,
+        interchain_account: &Signer,
+        messages: Vec<Any>,
+    ) -> Result<Vec<Any>, InterchainAccountError>;
+}
@@ -0,0 +1,7 @@
+//! Implements the processing logic for the messages of the interchain
+//! accounts controller.
+mod register_interchain_account;
+mod send_tx;
+
+pub use register_interchain_account::*;
+pub use send_tx::*;
@@ -0,0 +1,124 @@
+use core::fmt::Debug;
+
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::msgs::register_interchain_account::MsgRegisterInterchainAccount;
+use ibc_app_interchain_accounts_types::{controller_port_id, host_port_id, Metadata};
+use ibc_core::channel::handler::{chan_open_init_execute, chan_open_init_validate};
+use ibc_core::channel::types::channel::State;
+use ibc_core::channel::types::msgs::MsgChannelOpenInit;
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+
+use crate::context::{ControllerExecutionContext, ControllerValidationContext};
+use crate::module::ControllerModule;
+
+/// Registers an interchain account. Equivalent to calling
+/// [`register_interchain_account_validate`], followed by
+/// [`register_interchain_account_execute`].
+pub fn register_interchain_account<Ctx, IcaCtx>(
+    ctx_a: &mut Ctx,
+    module: &mut ControllerModule<IcaCtx>,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<ChannelId, InterchainAccountError>
+where
+    Ctx: ExecutionContext,
+    IcaCtx: ControllerExecutionContext + Debug,
+{
+    register_interchain_account_validate(ctx_a, module, msg.clone())?;
+    register_interchain_account_execute(ctx_a, module, msg)
+}
+
+/// Validates the registration of an interchain account. If this succeeds,
+/// then it is legal to open the channel with
+/// [`register_interchain_account_execute`].
+pub fn register_interchain_account_validate<Ctx, IcaCtx>(
+    ctx_a: &Ctx,
+    module: &ControllerModule<IcaCtx>,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<(), InterchainAccountError>
+where
+    Ctx: ValidationContext,
+    IcaCtx: ControllerExecutionContext + Debug,
+{
+    let msg_chan_open_init = chan_open_init_msg(ctx_a, module.ctx(), msg)?;
+
+    chan_open_init_validate(ctx_a, module, msg_chan_open_init)?;
+
+    Ok(())
+}
+
+/// Opens the channel of the interchain account and returns its identifier.
+/// A prior call to [`register_interchain_account_validate`] MUST have
+/// succeeded.
+pub fn register_interchain_account_execute<Ctx, IcaCtx>(
+    ctx_a: &mut Ctx,
+    module: &mut ControllerModule<IcaCtx>,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<ChannelId, InterchainAccountError>
+where
+    Ctx: ExecutionContext,
+    IcaCtx: ControllerExecutionContext + Debug,
+{
+    let msg_chan_open_init = chan_open_init_msg(ctx_a, module.ctx(), msg)?;
+
+    let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
+
+    chan_open_init_execute(ctx_a, module, msg_chan_open_init)?;
+
+    Ok(chan_id_on_a)
+}
+
+/// Builds the `MsgChannelOpenInit` opening the channel of the interchain
+/// account, filling in the default version metadata if none was proposed.
+fn chan_open_init_msg(
+    ctx_a: &impl ValidationContext,
+    ica_ctx_a: &impl ControllerValidationContext,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<MsgChannelOpenInit, InterchainAccountError> {
+    if !ica_ctx_a.is_controller_enabled() {
+        return Err(InterchainAccountError::ControllerDisabled);
+    }
+
+    let port_id_on_a = controller_port_id(&msg.owner)?;
+
+    // A new channel may only be opened once the previous one of the account
+    // was closed, which happens when an ordered channel times out.
+    if let Some(chan_id_on_a) = ica_ctx_a.active_channel_id(&msg.conn_id_on_a, &port_id_on_a) {
+        let chan_end_path_on_a = ChannelEndPath::new(&port_id_on_a, &chan_id_on_a);
+        let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+        if *chan_end_on_a.state() != State::Closed {
+            return Err(InterchainAccountError::ActiveChannelAlreadySet {
+                connection_id: msg.conn_id_on_a,
+                port_id: port_id_on_a,
+                channel_id: chan_id_on_a,
+            });
+        }
+    }
+
+    let version_proposal = if msg.version.is_empty() {
+        let conn_end_on_a = ctx_a.resolve_connection_end(&msg.conn_id_on_a)?;
+        let conn_id_on_b = conn_end_on_a
+            .counterparty()
+            .connection_id()
+            .ok_or_else(|| InterchainAccountError::MissingCounterpartyConnection {
+                connection_id: msg.conn_id_on_a.clone(),
+            })?
+            .clone();
+
+        Metadata::new(msg.conn_id_on_a.clone(), conn_id_on_b).to_version()
+    } else {
+        msg.version
+    };
+
+    Ok(MsgChannelOpenInit {
+        port_id_on_a,
+        connection_hops_on_a: vec![msg.conn_id_on_a],
+        port_id_on_b: host_port_id(),
+        ordering: msg.ordering,
+        signer: msg.owner,
+        version_proposal,
+    })
+}
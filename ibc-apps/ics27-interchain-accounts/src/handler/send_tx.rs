@@ -0,0 +1,116 @@
+use ibc_app_interchain_accounts_types::controller_port_id;
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::msgs::send_tx::MsgSendTx;
+use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::host::types::identifiers::Sequence;
+use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+
+use crate::context::ControllerValidationContext;
+
+/// Sends a transaction to an interchain account. Equivalent to calling
+/// [`send_tx_validate`], followed by [`send_tx_execute`].
+pub fn send_tx<Ctx, IcaCtx>(
+    ctx_a: &mut Ctx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<Sequence, InterchainAccountError>
+where
+    Ctx: ExecutionContext,
+    IcaCtx: ControllerValidationContext,
+{
+    send_tx_validate(ctx_a, ica_ctx_a, msg.clone())?;
+    send_tx_execute(ctx_a, ica_ctx_a, msg)
+}
+
+/// Validates the sending of a transaction. If this succeeds, then it is legal
+/// to send the packet with [`send_tx_execute`].
+pub fn send_tx_validate<Ctx, IcaCtx>(
+    ctx_a: &Ctx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<(), InterchainAccountError>
+where
+    Ctx: ValidationContext,
+    IcaCtx: ControllerValidationContext,
+{
+    let packet = build_packet(ctx_a, ica_ctx_a, msg)?;
+
+    send_packet_validate(ctx_a, &packet)?;
+
+    Ok(())
+}
+
+/// Sends the packet executing the transaction on the host and returns its
+/// sequence. A prior call to [`send_tx_validate`] MUST have succeeded.
+pub fn send_tx_execute<Ctx, IcaCtx>(
+    ctx_a: &mut Ctx,
+    ica_ctx_a: &IcaCtx,
+    msg: MsgSendTx,
+) -> Result<Sequence, InterchainAccountError>
+where
+    Ctx: ExecutionContext,
+    IcaCtx: ControllerValidationContext,
+{
+    let packet = build_packet(ctx_a, ica_ctx_a, msg)?;
+    let sequence = packet.seq_on_a;
+
+    send_packet_execute(ctx_a, packet)?;
+
+    Ok(sequence)
+}
+
+fn build_packet(
+    ctx_a: &impl ValidationContext,
+    ica_ctx_a: &impl ControllerValidationContext,
+    msg: MsgSendTx,
+) -> Result<Packet, InterchainAccountError> {
+    if !ica_ctx_a.is_controller_enabled() {
+        return Err(InterchainAccountError::ControllerDisabled);
+    }
+
+    let port_id_on_a = controller_port_id(&msg.owner)?;
+
+    let chan_id_on_a = ica_ctx_a
+        .active_channel_id(&msg.conn_id_on_a, &port_id_on_a)
+        .ok_or_else(|| InterchainAccountError::NoActiveChannel {
+            connection_id: msg.conn_id_on_a.clone(),
+            port_id: port_id_on_a.clone(),
+        })?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&port_id_on_a, &chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    let port_id_on_b = chan_end_on_a.counterparty().port_id().clone();
+    let chan_id_on_b = chan_end_on_a
+        .counterparty()
+        .channel_id()
+        .ok_or_else(|| InterchainAccountError::NoActiveChannel {
+            connection_id: msg.conn_id_on_a.clone(),
+            port_id: port_id_on_a.clone(),
+        })?
+        .clone();
+
+    let seq_send_path_on_a = SeqSendPath::new(&port_id_on_a, &chan_id_on_a);
+    let sequence = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    let timeout_timestamp_on_b = (ctx_a.host_timestamp()? + msg.relative_timeout)
+        .map_err(|e| InterchainAccountError::Other(e.to_string()))?;
+
+    let data = serde_json::to_vec(&msg.packet_data)
+        .expect("InterchainAccountPacketData's infallible Serialize impl failed");
+
+    Ok(Packet {
+        seq_on_a: sequence,
+        port_id_on_a,
+        chan_id_on_a,
+        port_id_on_b,
+        chan_id_on_b,
+        data,
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b,
+    })
+}
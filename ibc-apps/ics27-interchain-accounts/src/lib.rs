@@ -0,0 +1,33 @@
+//! Implementation of the IBC [interchain
+//! accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+//! (ICS-27) controller and host logic.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+/// Re-exports the implementation of the IBC [interchain
+/// accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+/// (ICS-27) data structures.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_app_interchain_accounts_types::*;
+}
+
+pub mod context;
+#[cfg(feature = "serde")]
+pub mod handler;
+#[cfg(feature = "serde")]
+pub mod module;
@@ -0,0 +1,291 @@
+use core::fmt::Debug;
+
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::{validate_controller_port, validate_host_port, Metadata};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use super::{invalid_channel_flow, validate_ordering};
+use crate::context::{ControllerExecutionContext, ControllerValidationContext};
+
+/// The interchain accounts controller submodule.
+///
+/// Interchain account channels are opened from the controller port of the
+/// account owner, `icacontroller-{owner}`, hence the host router must route
+/// every port starting with
+/// [`CONTROLLER_PORT_PREFIX`](ibc_app_interchain_accounts_types::CONTROLLER_PORT_PREFIX)
+/// to this module.
+#[derive(Debug)]
+pub struct ControllerModule<Ctx> {
+    ctx: Ctx,
+}
+
+impl<Ctx> ControllerModule<Ctx>
+where
+    Ctx: ControllerExecutionContext,
+{
+    pub fn new(ctx: Ctx) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut Ctx {
+        &mut self.ctx
+    }
+}
+
+fn validate_open_init(
+    ctx: &impl ControllerValidationContext,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    counterparty: &Counterparty,
+    version: &Version,
+) -> Result<(), InterchainAccountError> {
+    if !ctx.is_controller_enabled() {
+        return Err(InterchainAccountError::ControllerDisabled);
+    }
+
+    validate_ordering(order)?;
+    validate_controller_port(port_id)?;
+    validate_host_port(counterparty.port_id())?;
+
+    let metadata = Metadata::from_version(version)?;
+    metadata.validate_controller(connection_hops)?;
+
+    // When reopening the channel of an existing interchain account, the
+    // proposed address, if any, must be the one of the account.
+    if let Some(address) = ctx.interchain_account(&metadata.controller_connection_id, port_id) {
+        if !metadata.address.as_ref().is_empty() && metadata.address != address {
+            return Err(InterchainAccountError::InvalidAddress {
+                expected: address.to_string(),
+                actual: metadata.address.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the version chosen by the host, returning its metadata.
+fn validate_open_ack(
+    ctx: &impl ControllerValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<Metadata, InterchainAccountError> {
+    validate_controller_port(port_id)?;
+
+    let connection_id = ctx.channel_connection_id(port_id, channel_id)?;
+
+    let metadata = Metadata::from_version(counterparty_version)?;
+    metadata.validate_controller(&[connection_id])?;
+
+    if metadata.address.as_ref().trim().is_empty() {
+        return Err(InterchainAccountError::MissingAddress);
+    }
+
+    if let Some(address) = ctx.interchain_account(&metadata.controller_connection_id, port_id) {
+        if metadata.address != address {
+            return Err(InterchainAccountError::InvalidAddress {
+                expected: address.to_string(),
+                actual: metadata.address.to_string(),
+            });
+        }
+    }
+
+    Ok(metadata)
+}
+
+impl<Ctx> Module for ControllerModule<Ctx>
+where
+    Ctx: ControllerExecutionContext + Debug,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        validate_open_init(
+            &self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            counterparty,
+            version,
+        )?;
+
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        validate_open_init(
+            &self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            counterparty,
+            version,
+        )?;
+
+        Ok((ModuleExtras::empty(), version.clone()))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(invalid_channel_flow("the controller cannot accept channel handshakes").into())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(invalid_channel_flow("the controller cannot accept channel handshakes").into())
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        validate_open_ack(&self.ctx, port_id, channel_id, counterparty_version)?;
+
+        Ok(())
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let metadata = validate_open_ack(&self.ctx, port_id, channel_id, counterparty_version)?;
+
+        self.ctx.store_active_channel_id(
+            &metadata.controller_connection_id,
+            port_id,
+            channel_id.clone(),
+        )?;
+        self.ctx.store_interchain_account(
+            &metadata.controller_connection_id,
+            port_id,
+            metadata.address,
+        )?;
+
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Err(invalid_channel_flow("the controller cannot accept channel handshakes").into())
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(invalid_channel_flow("the controller cannot accept channel handshakes").into())
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Err(invalid_channel_flow("interchain account channels cannot be closed").into())
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(invalid_channel_flow("interchain account channels cannot be closed").into())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let ack = AcknowledgementStatus::error(
+            invalid_channel_flow("the controller cannot receive packets").into(),
+        );
+
+        (ModuleExtras::empty(), Some(ack.into()))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
@@ -0,0 +1,354 @@
+use core::fmt::Debug;
+
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::events::{PacketEvent, RegisterAccountEvent};
+use ibc_app_interchain_accounts_types::{
+    validate_controller_port, validate_host_port, CosmosTx, InterchainAccountPacketData, Metadata,
+    PacketType, TxMsgData,
+};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use super::{invalid_channel_flow, validate_ordering};
+use crate::context::{HostExecutionContext, HostValidationContext};
+
+/// The interchain accounts host submodule, bound to the
+/// [`HOST_MODULE_ID_STR`](ibc_app_interchain_accounts_types::HOST_MODULE_ID_STR)
+/// port, which creates interchain accounts and executes the transactions
+/// their controllers send.
+#[derive(Debug)]
+pub struct HostModule<Ctx> {
+    ctx: Ctx,
+}
+
+impl<Ctx> HostModule<Ctx>
+where
+    Ctx: HostExecutionContext,
+{
+    pub fn new(ctx: Ctx) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut Ctx {
+        &mut self.ctx
+    }
+
+    fn process_recv_packet(
+        &mut self,
+        packet: &Packet,
+    ) -> Result<TxMsgData, InterchainAccountError> {
+        if !self.ctx.is_host_enabled() {
+            return Err(InterchainAccountError::HostDisabled);
+        }
+
+        let data = serde_json::from_slice::<InterchainAccountPacketData>(&packet.data)
+            .map_err(|_| InterchainAccountError::PacketDataDeserialization)?;
+
+        data.validate_basic()?;
+
+        match data.packet_type {
+            PacketType::ExecuteTx => {
+                let tx = CosmosTx::from_packet_data(&data)?;
+
+                let connection_id = self
+                    .ctx
+                    .channel_connection_id(&packet.port_id_on_b, &packet.chan_id_on_b)?;
+
+                let interchain_account = self
+                    .ctx
+                    .interchain_account(&connection_id, &packet.port_id_on_a)
+                    .ok_or_else(|| InterchainAccountError::MissingInterchainAccount {
+                        connection_id: connection_id.clone(),
+                        port_id: packet.port_id_on_a.clone(),
+                    })?;
+
+                if let Some(msg) = tx
+                    .messages
+                    .iter()
+                    .find(|msg| !self.ctx.is_message_allowed(&msg.type_url))
+                {
+                    return Err(InterchainAccountError::MessageNotAllowed {
+                        type_url: msg.type_url.clone(),
+                    });
+                }
+
+                let msg_responses = self.ctx.execute_tx(&interchain_account, tx.messages)?;
+
+                Ok(TxMsgData::new(msg_responses))
+            }
+            PacketType::Unspecified => Err(InterchainAccountError::UnsupportedPacketType),
+        }
+    }
+}
+
+/// Validates the version proposed by the controller, returning its metadata.
+fn validate_open_try(
+    ctx: &impl HostValidationContext,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<Metadata, InterchainAccountError> {
+    if !ctx.is_host_enabled() {
+        return Err(InterchainAccountError::HostDisabled);
+    }
+
+    validate_ordering(order)?;
+    validate_host_port(port_id)?;
+    validate_controller_port(counterparty.port_id())?;
+
+    let metadata = Metadata::from_version(counterparty_version)?;
+    metadata.validate_host(connection_hops)?;
+
+    // When reopening the channel of an existing interchain account, the
+    // proposed address, if any, must be the one of the account.
+    if let Some(address) =
+        ctx.interchain_account(&metadata.host_connection_id, counterparty.port_id())
+    {
+        if !metadata.address.as_ref().is_empty() && metadata.address != address {
+            return Err(InterchainAccountError::InvalidAddress {
+                expected: address.to_string(),
+                actual: metadata.address.to_string(),
+            });
+        }
+    }
+
+    Ok(metadata)
+}
+
+impl<Ctx> Module for HostModule<Ctx>
+where
+    Ctx: HostExecutionContext + Debug,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(invalid_channel_flow("the host cannot initiate channel handshakes").into())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(invalid_channel_flow("the host cannot initiate channel handshakes").into())
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let mut metadata = validate_open_try(
+            &self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            counterparty,
+            counterparty_version,
+        )?;
+
+        // The address of a new interchain account is only known once it is
+        // created, on execution.
+        if let Some(address) = self
+            .ctx
+            .interchain_account(&metadata.host_connection_id, counterparty.port_id())
+        {
+            metadata.address = address;
+        }
+
+        Ok(metadata.to_version())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let mut metadata = validate_open_try(
+            &self.ctx,
+            order,
+            connection_hops,
+            port_id,
+            counterparty,
+            counterparty_version,
+        )?;
+
+        let connection_id = metadata.host_connection_id.clone();
+        let controller_port_id = counterparty.port_id();
+
+        let mut extras = ModuleExtras::empty();
+
+        let address = match self
+            .ctx
+            .interchain_account(&connection_id, controller_port_id)
+        {
+            Some(address) => address,
+            None => {
+                let address = self
+                    .ctx
+                    .create_interchain_account(&connection_id, controller_port_id)?;
+
+                self.ctx.store_interchain_account(
+                    &connection_id,
+                    controller_port_id,
+                    address.clone(),
+                )?;
+
+                extras.events.push(
+                    RegisterAccountEvent {
+                        connection_id: connection_id.clone(),
+                        controller_port_id: controller_port_id.clone(),
+                        address: address.clone(),
+                    }
+                    .into(),
+                );
+
+                address
+            }
+        };
+
+        self.ctx
+            .store_active_channel_id(&connection_id, controller_port_id, channel_id.clone())?;
+
+        metadata.address = address;
+
+        Ok((extras, metadata.to_version()))
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Err(invalid_channel_flow("the host cannot initiate channel handshakes").into())
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(invalid_channel_flow("the host cannot initiate channel handshakes").into())
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Err(invalid_channel_flow("interchain account channels cannot be closed").into())
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(invalid_channel_flow("interchain account channels cannot be closed").into())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let result = self
+            .process_recv_packet(packet)
+            .and_then(|tx_msg_data| tx_msg_data.to_status_value());
+
+        let (ack, error) = match result {
+            Ok(value) => (AcknowledgementStatus::success(value), String::new()),
+            Err(err) => {
+                let error = err.to_string();
+                (AcknowledgementStatus::error(err.into()), error)
+            }
+        };
+
+        let packet_event = PacketEvent {
+            host_channel_id: packet.chan_id_on_b.clone(),
+            success: ack.is_successful(),
+            error,
+        };
+
+        let extras = ModuleExtras {
+            events: vec![packet_event.into()],
+            log: Vec::new(),
+        };
+
+        (extras, Some(ack.into()))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Err(invalid_channel_flow("the host cannot send packets").into())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (
+            ModuleExtras::empty(),
+            Err(invalid_channel_flow("the host cannot send packets").into()),
+        )
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Err(invalid_channel_flow("the host cannot send packets").into())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (
+            ModuleExtras::empty(),
+            Err(invalid_channel_flow("the host cannot send packets").into()),
+        )
+    }
+}
@@ -0,0 +1,26 @@
+//! Implements the interchain accounts controller and host submodules as IBC
+//! [`Module`](ibc_core::router::module::Module)s.
+mod controller;
+mod host;
+
+pub use controller::*;
+pub use host::*;
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_core::channel::types::channel::Order;
+use ibc_core::primitives::prelude::*;
+
+/// Checks that the channel is either ordered or unordered.
+fn validate_ordering(order: Order) -> Result<(), InterchainAccountError> {
+    match order {
+        Order::Ordered | Order::Unordered => Ok(()),
+        Order::None | Order::OrderedAllowTimeout => {
+            Err(InterchainAccountError::InvalidOrdering { order })
+        }
+    }
+}
+
+fn invalid_channel_flow(description: &str) -> InterchainAccountError {
+    InterchainAccountError::InvalidChannelFlow {
+        description: description.to_string(),
+    }
+}
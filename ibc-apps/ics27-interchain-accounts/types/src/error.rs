@@ -0,0 +1,143 @@
+//! Defines the interchain accounts error type
+use core::convert::Infallible;
+
+use displaydoc::Display;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum InterchainAccountError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// the interchain account owner cannot be empty
+    EmptyOwner,
+    /// invalid controller port `{port_id}`
+    InvalidControllerPort { port_id: PortId },
+    /// invalid host port `{port_id}`
+    InvalidHostPort { port_id: PortId },
+    /// invalid channel ordering `{order}`
+    InvalidOrdering { order: Order },
+    /// expected a single connection hop, got `{actual}`
+    InvalidConnectionHops { actual: usize },
+    /// invalid connection in version metadata: expected `{expected}`, got `{actual}`
+    InvalidConnection {
+        expected: ConnectionId,
+        actual: ConnectionId,
+    },
+    /// invalid version: expected `{expected}`, got `{actual}`
+    InvalidVersion { expected: String, actual: String },
+    /// unsupported encoding `{encoding}`
+    UnsupportedEncoding { encoding: String },
+    /// unsupported transaction type `{tx_type}`
+    UnsupportedTxType { tx_type: String },
+    /// interchain account address `{actual}` does not match the registered address `{expected}`
+    InvalidAddress { expected: String, actual: String },
+    /// the interchain account address is missing from the version metadata
+    MissingAddress,
+    /// failed to deserialize the channel version metadata
+    MetadataDeserialization,
+    /// the controller submodule is disabled
+    ControllerDisabled,
+    /// the host submodule is disabled
+    HostDisabled,
+    /// invalid channel flow: `{description}`
+    InvalidChannelFlow { description: String },
+    /// no active channel for connection `{connection_id}` and port `{port_id}`
+    NoActiveChannel {
+        connection_id: ConnectionId,
+        port_id: PortId,
+    },
+    /// channel `{channel_id}` is already active for connection `{connection_id}` and port `{port_id}`
+    ActiveChannelAlreadySet {
+        connection_id: ConnectionId,
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// no interchain account registered for connection `{connection_id}` and port `{port_id}`
+    MissingInterchainAccount {
+        connection_id: ConnectionId,
+        port_id: PortId,
+    },
+    /// the counterparty of connection `{connection_id}` is not known yet
+    MissingCounterpartyConnection { connection_id: ConnectionId },
+    /// missing packet data
+    MissingPacketData,
+    /// unsupported packet type
+    UnsupportedPacketType,
+    /// failed to deserialize packet data
+    PacketDataDeserialization,
+    /// failed to decode the transaction: `{reason}`
+    TxDeserialization { reason: String },
+    /// the transaction must contain at least one message
+    EmptyMessages,
+    /// message `{type_url}` is not allowed on this host
+    MessageNotAllowed { type_url: String },
+    /// failed to execute the transaction: `{reason}`
+    TxExecution { reason: String },
+    /// the relative timeout must be non-zero
+    MissingRelativeTimeout,
+    /// decoding raw msg error: `{reason}`
+    DecodeRawMsg { reason: String },
+    /// unknown msg type: `{msg_type}`
+    UnknownMsgType { msg_type: String },
+    /// other error: `{0}`
+    Other(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InterchainAccountError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Infallible> for InterchainAccountError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<ContextError> for InterchainAccountError {
+    fn from(err: ContextError) -> InterchainAccountError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for InterchainAccountError {
+    fn from(err: IdentifierError) -> InterchainAccountError {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<InterchainAccountError> for ChannelError {
+    fn from(err: InterchainAccountError) -> ChannelError {
+        ChannelError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<InterchainAccountError> for PacketError {
+    fn from(err: InterchainAccountError) -> PacketError {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<InterchainAccountError> for StatusValue {
+    fn from(err: InterchainAccountError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
@@ -0,0 +1,82 @@
+//! Defines all interchain accounts event types
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::HOST_MODULE_ID_STR;
+
+const EVENT_TYPE_PACKET: &str = "ics27_packet";
+const EVENT_TYPE_REGISTER_ACCOUNT: &str = "register_interchain_account";
+
+/// Contains all events variants that can be emitted from the interchain
+/// accounts application
+pub enum Event {
+    Packet(PacketEvent),
+    RegisterAccount(RegisterAccountEvent),
+}
+
+/// Event emitted by the host `onRecvPacket` module callback to indicate
+/// whether the transaction of the packet was executed successfully
+pub struct PacketEvent {
+    pub host_channel_id: ChannelId,
+    pub success: bool,
+    /// The reason the transaction failed, empty on success
+    pub error: String,
+}
+
+impl From<PacketEvent> for ModuleEvent {
+    fn from(ev: PacketEvent) -> Self {
+        let PacketEvent {
+            host_channel_id,
+            success,
+            error,
+        } = ev;
+        let mut attributes = vec![
+            ("module", HOST_MODULE_ID_STR).into(),
+            ("host_channel_id", host_channel_id).into(),
+            ("success", success).into(),
+        ];
+        if !error.is_empty() {
+            attributes.push(("error", error).into());
+        }
+        Self {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes,
+        }
+    }
+}
+
+/// Event emitted by the host chain when it creates an interchain account
+pub struct RegisterAccountEvent {
+    pub connection_id: ConnectionId,
+    pub controller_port_id: PortId,
+    pub address: Signer,
+}
+
+impl From<RegisterAccountEvent> for ModuleEvent {
+    fn from(ev: RegisterAccountEvent) -> Self {
+        let RegisterAccountEvent {
+            connection_id,
+            controller_port_id,
+            address,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_REGISTER_ACCOUNT.to_string(),
+            attributes: vec![
+                ("connection_id", connection_id).into(),
+                ("controller_port_id", controller_port_id).into(),
+                ("address", address).into(),
+            ],
+        }
+    }
+}
+
+impl From<Event> for ModuleEvent {
+    fn from(ev: Event) -> Self {
+        match ev {
+            Event::Packet(ev) => ev.into(),
+            Event::RegisterAccount(ev) => ev.into(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Implementation of the IBC [interchain
+//! accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+//! (ICS-27) data structures.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod metadata;
+mod packet;
+mod port;
+mod tx_msg_data;
+
+pub use metadata::*;
+pub use packet::*;
+pub use port::*;
+pub use tx_msg_data::*;
+pub mod error;
+pub mod events;
+pub mod msgs;
+
+/// Re-exports ICS-27 interchain accounts proto types from the `ibc-proto` crate.
+pub mod proto {
+    pub use ibc_proto::ibc::applications::interchain_accounts;
+}
+
+/// Module identifier for the ICS-27 controller submodule.
+pub const CONTROLLER_MODULE_ID_STR: &str = "icacontroller";
+
+/// Module identifier for the ICS-27 host submodule.
+pub const HOST_MODULE_ID_STR: &str = "icahost";
+
+/// ICS-27 interchain accounts current version.
+pub const VERSION: &str = "ics27-1";
+
+/// The only encoding of the transactions carried by interchain account
+/// packets supported by this implementation.
+pub const ENCODING_PROTOBUF: &str = "proto3";
+
+/// The only transaction type supported by this implementation, which is a
+/// list of Cosmos SDK messages executed atomically.
+pub const TX_TYPE_SDK_MULTI_MSG: &str = "sdk_multi_msg";
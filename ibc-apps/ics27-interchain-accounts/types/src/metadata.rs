@@ -0,0 +1,162 @@
+//! Defines the version metadata negotiated during the interchain account
+//! channel handshake.
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::error::InterchainAccountError;
+use crate::{ENCODING_PROTOBUF, TX_TYPE_SDK_MULTI_MSG, VERSION};
+
+/// The version of an interchain account channel.
+///
+/// It is encoded in the channel version as a JSON object, e.g.
+/// `{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-0","address":"","encoding":"proto3","tx_type":"sdk_multi_msg"}`.
+/// The address is left empty by the controller and filled in by the host
+/// during `ChanOpenTry`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    pub version: Version,
+    pub controller_connection_id: ConnectionId,
+    pub host_connection_id: ConnectionId,
+    pub address: Signer,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+impl Metadata {
+    /// Returns the default metadata proposed by a controller over the given
+    /// connection.
+    pub fn new(controller_connection_id: ConnectionId, host_connection_id: ConnectionId) -> Self {
+        Self {
+            version: Version::new(VERSION.to_string()),
+            controller_connection_id,
+            host_connection_id,
+            address: String::new().into(),
+            encoding: ENCODING_PROTOBUF.to_string(),
+            tx_type: TX_TYPE_SDK_MULTI_MSG.to_string(),
+        }
+    }
+
+    /// Checks that the metadata carries the version, encoding and
+    /// transaction type supported by this implementation.
+    pub fn validate_basic(&self) -> Result<(), InterchainAccountError> {
+        if self.version.as_str() != VERSION {
+            return Err(InterchainAccountError::InvalidVersion {
+                expected: VERSION.to_string(),
+                actual: self.version.to_string(),
+            });
+        }
+
+        if self.encoding != ENCODING_PROTOBUF {
+            return Err(InterchainAccountError::UnsupportedEncoding {
+                encoding: self.encoding.clone(),
+            });
+        }
+
+        if self.tx_type != TX_TYPE_SDK_MULTI_MSG {
+            return Err(InterchainAccountError::UnsupportedTxType {
+                tx_type: self.tx_type.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validates the metadata on the controller chain, whose end of the
+    /// channel goes through the given connection hops.
+    pub fn validate_controller(
+        &self,
+        connection_hops: &[ConnectionId],
+    ) -> Result<(), InterchainAccountError> {
+        self.validate_basic()?;
+
+        validate_connection(connection_hops, &self.controller_connection_id)
+    }
+
+    /// Validates the metadata on the host chain, whose end of the channel
+    /// goes through the given connection hops.
+    pub fn validate_host(
+        &self,
+        connection_hops: &[ConnectionId],
+    ) -> Result<(), InterchainAccountError> {
+        self.validate_basic()?;
+
+        validate_connection(connection_hops, &self.host_connection_id)
+    }
+}
+
+/// Checks that the channel goes through a single connection, which is the
+/// one recorded in the metadata.
+fn validate_connection(
+    connection_hops: &[ConnectionId],
+    metadata_connection_id: &ConnectionId,
+) -> Result<(), InterchainAccountError> {
+    let [connection_id] = connection_hops else {
+        return Err(InterchainAccountError::InvalidConnectionHops {
+            actual: connection_hops.len(),
+        });
+    };
+
+    if connection_id != metadata_connection_id {
+        return Err(InterchainAccountError::InvalidConnection {
+            expected: connection_id.clone(),
+            actual: metadata_connection_id.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+impl Metadata {
+    /// Parses the metadata out of a channel version.
+    pub fn from_version(version: &Version) -> Result<Self, InterchainAccountError> {
+        serde_json::from_str(version.as_str())
+            .map_err(|_| InterchainAccountError::MetadataDeserialization)
+    }
+
+    /// Encodes the metadata as a channel version.
+    pub fn to_version(&self) -> Version {
+        Version::new(
+            serde_json::to_string(self).expect("Metadata's infallible Serialize impl failed"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_validation() {
+        let metadata = Metadata::new(ConnectionId::new(0), ConnectionId::new(1));
+
+        assert!(metadata
+            .validate_controller(&[ConnectionId::new(0)])
+            .is_ok());
+        assert!(metadata.validate_host(&[ConnectionId::new(1)]).is_ok());
+        assert!(metadata.validate_host(&[ConnectionId::new(0)]).is_err());
+        assert!(metadata.validate_controller(&[]).is_err());
+
+        let mut metadata = metadata;
+        metadata.encoding = "proto3json".to_string();
+        assert!(metadata.validate_basic().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn metadata_version_roundtrip() {
+        let metadata = Metadata::new(ConnectionId::new(0), ConnectionId::new(1));
+        let version = metadata.to_version();
+
+        assert_eq!(
+            version.as_str(),
+            r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-1","address":"","encoding":"proto3","tx_type":"sdk_multi_msg"}"#
+        );
+        assert_eq!(Metadata::from_version(&version).unwrap(), metadata);
+        assert!(Metadata::from_version(&Version::new(VERSION.to_string())).is_err());
+    }
+}
@@ -0,0 +1,3 @@
+//! Defines the messages handled by the interchain accounts controller
+pub mod register_interchain_account;
+pub mod send_tx;
@@ -0,0 +1,95 @@
+//! Defines the message used to register an interchain account
+
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::Version;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::interchain_accounts::controller::v1::MsgRegisterInterchainAccount as RawMsgRegisterInterchainAccount;
+use ibc_proto::Protobuf;
+
+use crate::error::InterchainAccountError;
+
+pub(crate) const TYPE_URL: &str =
+    "/ibc.applications.interchain_accounts.controller.v1.MsgRegisterInterchainAccount";
+
+/// Message used to register an interchain account on the host chain at the
+/// other end of the given connection, by opening a channel from the
+/// controller port of the owner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgRegisterInterchainAccount {
+    /// the account owning the interchain account
+    pub owner: Signer,
+    /// the connection to the host chain
+    pub conn_id_on_a: ConnectionId,
+    /// The proposed channel version. If empty, the default version metadata
+    /// for the connection is used.
+    pub version: Version,
+    /// the ordering of the interchain account channel
+    pub ordering: Order,
+}
+
+impl TryFrom<RawMsgRegisterInterchainAccount> for MsgRegisterInterchainAccount {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw_msg: RawMsgRegisterInterchainAccount) -> Result<Self, Self::Error> {
+        if raw_msg.owner.trim().is_empty() {
+            return Err(InterchainAccountError::EmptyOwner);
+        }
+
+        // Interchain account channels are ordered unless stated otherwise.
+        let ordering = match Order::from_i32(raw_msg.ordering).map_err(ContextError::from)? {
+            Order::None => Order::Ordered,
+            ordering => ordering,
+        };
+
+        Ok(MsgRegisterInterchainAccount {
+            owner: raw_msg.owner.into(),
+            conn_id_on_a: raw_msg.connection_id.parse()?,
+            version: raw_msg.version.into(),
+            ordering,
+        })
+    }
+}
+
+impl From<MsgRegisterInterchainAccount> for RawMsgRegisterInterchainAccount {
+    fn from(domain_msg: MsgRegisterInterchainAccount) -> Self {
+        RawMsgRegisterInterchainAccount {
+            owner: domain_msg.owner.to_string(),
+            connection_id: domain_msg.conn_id_on_a.to_string(),
+            version: domain_msg.version.to_string(),
+            ordering: domain_msg.ordering as i32,
+        }
+    }
+}
+
+impl Protobuf<RawMsgRegisterInterchainAccount> for MsgRegisterInterchainAccount {}
+
+impl TryFrom<Any> for MsgRegisterInterchainAccount {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TYPE_URL => MsgRegisterInterchainAccount::decode_vec(&raw.value).map_err(|e| {
+                InterchainAccountError::DecodeRawMsg {
+                    reason: e.to_string(),
+                }
+            }),
+            _ => Err(InterchainAccountError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
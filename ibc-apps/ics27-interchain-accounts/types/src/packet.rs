@@ -0,0 +1,285 @@
+//! Contains the types that define the structure of interchain account
+//! packets' bytes.
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use base64::prelude::BASE64_STANDARD;
+#[cfg(feature = "serde")]
+use base64::Engine;
+use ibc_core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::interchain_accounts::v1::{
+    CosmosTx as RawCosmosTx, InterchainAccountPacketData as RawInterchainAccountPacketData,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::InterchainAccountError;
+
+/// The kind of operation requested by an interchain account packet.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketType {
+    Unspecified = 0,
+    /// Execute the `CosmosTx` carried by the packet on the host chain.
+    ExecuteTx = 1,
+}
+
+impl PacketType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "TYPE_UNSPECIFIED",
+            Self::ExecuteTx => "TYPE_EXECUTE_TX",
+        }
+    }
+
+    pub fn from_i32(nr: i32) -> Result<Self, InterchainAccountError> {
+        match nr {
+            0 => Ok(Self::Unspecified),
+            1 => Ok(Self::ExecuteTx),
+            _ => Err(InterchainAccountError::UnsupportedPacketType),
+        }
+    }
+}
+
+impl Display for PacketType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for PacketType {
+    type Err = InterchainAccountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "TYPE_UNSPECIFIED" => Ok(Self::Unspecified),
+            "TYPE_EXECUTE_TX" => Ok(Self::ExecuteTx),
+            _ => Err(InterchainAccountError::UnsupportedPacketType),
+        }
+    }
+}
+
+/// Defines the structure of interchain account packets' bytes.
+///
+/// Packets are JSON-encoded the way ibc-go encodes them, with the `data`
+/// bytes in base64, e.g. `{"data":"CgA=","memo":"","type":"TYPE_EXECUTE_TX"}`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "JsonPacketData", into = "JsonPacketData")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterchainAccountPacketData {
+    pub packet_type: PacketType,
+    /// The protobuf-encoded [`CosmosTx`] to execute.
+    pub data: Vec<u8>,
+    pub memo: String,
+}
+
+impl InterchainAccountPacketData {
+    /// Builds the packet data executing the given transaction on the host
+    /// chain.
+    pub fn execute_tx(tx: CosmosTx, memo: String) -> Self {
+        Self {
+            packet_type: PacketType::ExecuteTx,
+            data: tx.encode_vec(),
+            memo,
+        }
+    }
+
+    pub fn validate_basic(&self) -> Result<(), InterchainAccountError> {
+        if self.packet_type == PacketType::Unspecified {
+            return Err(InterchainAccountError::UnsupportedPacketType);
+        }
+
+        if self.data.is_empty() {
+            return Err(InterchainAccountError::MissingPacketData);
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawInterchainAccountPacketData> for InterchainAccountPacketData {}
+
+impl TryFrom<RawInterchainAccountPacketData> for InterchainAccountPacketData {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw_pkt_data: RawInterchainAccountPacketData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            packet_type: PacketType::from_i32(raw_pkt_data.r#type)?,
+            data: raw_pkt_data.data,
+            memo: raw_pkt_data.memo,
+        })
+    }
+}
+
+impl From<InterchainAccountPacketData> for RawInterchainAccountPacketData {
+    fn from(pkt_data: InterchainAccountPacketData) -> Self {
+        Self {
+            r#type: pkt_data.packet_type as i32,
+            data: pkt_data.data,
+            memo: pkt_data.memo,
+        }
+    }
+}
+
+/// The JSON encoding of an [`InterchainAccountPacketData`], with its fields
+/// in the order ibc-go sorts them in.
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JsonPacketData {
+    data: String,
+    #[serde(default)]
+    memo: String,
+    #[serde(rename = "type")]
+    packet_type: String,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<JsonPacketData> for InterchainAccountPacketData {
+    type Error = InterchainAccountError;
+
+    fn try_from(json_pkt_data: JsonPacketData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            packet_type: json_pkt_data.packet_type.parse()?,
+            data: BASE64_STANDARD
+                .decode(json_pkt_data.data)
+                .map_err(|_| InterchainAccountError::PacketDataDeserialization)?,
+            memo: json_pkt_data.memo,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<InterchainAccountPacketData> for JsonPacketData {
+    fn from(pkt_data: InterchainAccountPacketData) -> Self {
+        Self {
+            data: BASE64_STANDARD.encode(pkt_data.data),
+            memo: pkt_data.memo,
+            packet_type: pkt_data.packet_type.to_string(),
+        }
+    }
+}
+
+/// The transaction carried by an interchain account packet: a list of
+/// messages executed atomically by the interchain account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CosmosTx {
+    pub messages: Vec<Any>,
+}
+
+impl CosmosTx {
+    pub fn new(messages: Vec<Any>) -> Self {
+        Self { messages }
+    }
+
+    /// Decodes the transaction out of the given packet data.
+    pub fn from_packet_data(
+        pkt_data: &InterchainAccountPacketData,
+    ) -> Result<Self, InterchainAccountError> {
+        let tx = Self::decode_vec(&pkt_data.data).map_err(|e| {
+            InterchainAccountError::TxDeserialization {
+                reason: e.to_string(),
+            }
+        })?;
+
+        if tx.messages.is_empty() {
+            return Err(InterchainAccountError::EmptyMessages);
+        }
+
+        Ok(tx)
+    }
+}
+
+impl Protobuf<RawCosmosTx> for CosmosTx {}
+
+impl TryFrom<RawCosmosTx> for CosmosTx {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw_tx: RawCosmosTx) -> Result<Self, Self::Error> {
+        Ok(Self {
+            messages: raw_tx.messages,
+        })
+    }
+}
+
+impl From<CosmosTx> for RawCosmosTx {
+    fn from(tx: CosmosTx) -> Self {
+        Self {
+            messages: tx.messages,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_tx() -> CosmosTx {
+        CosmosTx::new(vec![Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3],
+        }])
+    }
+
+    #[test]
+    fn packet_data_validate_basic() {
+        let pkt_data = InterchainAccountPacketData::execute_tx(dummy_tx(), String::new());
+        assert!(pkt_data.validate_basic().is_ok());
+        assert_eq!(CosmosTx::from_packet_data(&pkt_data).unwrap(), dummy_tx());
+
+        let pkt_data = InterchainAccountPacketData {
+            packet_type: PacketType::Unspecified,
+            ..pkt_data
+        };
+        assert!(pkt_data.validate_basic().is_err());
+
+        let empty_tx =
+            InterchainAccountPacketData::execute_tx(CosmosTx::new(vec![]), String::new());
+        assert!(CosmosTx::from_packet_data(&empty_tx).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn packet_data_json_roundtrip() {
+        let pkt_data = InterchainAccountPacketData {
+            packet_type: PacketType::ExecuteTx,
+            data: vec![1, 2, 3],
+            memo: "memo".to_string(),
+        };
+
+        let json = serde_json::to_string(&pkt_data).unwrap();
+        assert_eq!(
+            json,
+            r#"{"data":"AQID","memo":"memo","type":"TYPE_EXECUTE_TX"}"#
+        );
+
+        let decoded: InterchainAccountPacketData = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, pkt_data);
+    }
+}
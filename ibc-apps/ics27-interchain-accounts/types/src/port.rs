@@ -0,0 +1,83 @@
+//! Defines the ports interchain account channels are opened on.
+use ibc_core::host::types::identifiers::PortId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::error::InterchainAccountError;
+use crate::HOST_MODULE_ID_STR;
+
+/// Prefix of the controller ports, which are suffixed with the owner of the
+/// interchain account.
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+
+/// Returns the port on which the host submodule is bound.
+pub fn host_port_id() -> PortId {
+    PortId::new(HOST_MODULE_ID_STR.to_string()).expect("never fails")
+}
+
+/// Returns the controller port of the interchain accounts owned by the given
+/// account, i.e. `icacontroller-{owner}`.
+pub fn controller_port_id(owner: &Signer) -> Result<PortId, InterchainAccountError> {
+    if owner.as_ref().trim().is_empty() {
+        return Err(InterchainAccountError::EmptyOwner);
+    }
+
+    Ok(PortId::new(format!("{CONTROLLER_PORT_PREFIX}{owner}"))?)
+}
+
+/// Returns `true` if the given port is a controller port.
+pub fn is_controller_port(port_id: &PortId) -> bool {
+    port_id
+        .as_str()
+        .strip_prefix(CONTROLLER_PORT_PREFIX)
+        .is_some_and(|owner| !owner.is_empty())
+}
+
+/// Returns `true` if the given port is the host port.
+pub fn is_host_port(port_id: &PortId) -> bool {
+    port_id.as_str() == HOST_MODULE_ID_STR
+}
+
+/// Checks that the given port is a controller port, as opposed to, say, the
+/// `icacontroller` module name alone.
+pub fn validate_controller_port(port_id: &PortId) -> Result<(), InterchainAccountError> {
+    if !is_controller_port(port_id) {
+        return Err(InterchainAccountError::InvalidControllerPort {
+            port_id: port_id.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that the given port is the host port.
+pub fn validate_host_port(port_id: &PortId) -> Result<(), InterchainAccountError> {
+    if !is_host_port(port_id) {
+        return Err(InterchainAccountError::InvalidHostPort {
+            port_id: port_id.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CONTROLLER_MODULE_ID_STR;
+
+    #[test]
+    fn controller_port_of_owner() {
+        let owner: Signer = "cosmos1owner".to_string().into();
+        let port_id = controller_port_id(&owner).unwrap();
+
+        assert_eq!(port_id.as_str(), "icacontroller-cosmos1owner");
+        assert!(is_controller_port(&port_id));
+        assert!(!is_host_port(&port_id));
+        assert!(!is_controller_port(
+            &CONTROLLER_MODULE_ID_STR.parse().unwrap()
+        ));
+        assert!(is_host_port(&host_port_id()));
+        assert!(controller_port_id(&String::new().into()).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+//! Defines the result written in the acknowledgement of a successfully
+//! executed interchain account transaction.
+#[cfg(feature = "serde")]
+use base64::prelude::BASE64_STANDARD;
+#[cfg(feature = "serde")]
+use base64::Engine;
+#[cfg(feature = "serde")]
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::primitives::prelude::*;
+use ibc_proto::cosmos::base::abci::v1beta1::TxMsgData as RawTxMsgData;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::error::InterchainAccountError;
+
+/// The responses of the messages of an interchain account transaction, in
+/// the order the messages were executed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TxMsgData {
+    pub msg_responses: Vec<Any>,
+}
+
+impl TxMsgData {
+    pub fn new(msg_responses: Vec<Any>) -> Self {
+        Self { msg_responses }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TxMsgData {
+    /// Returns the result of a successful acknowledgement: the
+    /// base64-encoded protobuf bytes of the responses.
+    pub fn to_status_value(&self) -> Result<StatusValue, InterchainAccountError> {
+        StatusValue::new(BASE64_STANDARD.encode(self.clone().encode_vec()))
+            .map_err(|e| InterchainAccountError::Other(e.to_string()))
+    }
+}
+
+impl Protobuf<RawTxMsgData> for TxMsgData {}
+
+impl TryFrom<RawTxMsgData> for TxMsgData {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw_data: RawTxMsgData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            msg_responses: raw_data.msg_responses,
+        })
+    }
+}
+
+impl From<TxMsgData> for RawTxMsgData {
+    #[allow(deprecated)]
+    fn from(data: TxMsgData) -> Self {
+        Self {
+            data: Vec::new(),
+            msg_responses: data.msg_responses,
+        }
+    }
+}
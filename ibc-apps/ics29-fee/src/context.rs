@@ -0,0 +1,100 @@
+//! Defines the main context traits of the fee middleware
+
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::{PacketFee, PacketId};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::types::transfer::PrefixedCoin;
+
+/// Methods required in fee middleware validation, to be implemented by the host
+pub trait FeeValidationContext {
+    type AccountId: TryFrom<Signer>;
+
+    /// Returns `true` if the fee middleware is enabled on the given channel,
+    /// i.e. if both ends agreed on a fee version during the channel handshake.
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> bool;
+
+    /// Returns the address registered by the relayer to receive the
+    /// acknowledgement and timeout fees it earns on the given channel, if any.
+    fn payee(&self, relayer: &Signer, channel_id: &ChannelId) -> Option<Signer>;
+
+    /// Returns the counterparty chain address registered by the relayer to
+    /// receive the receive fees it earns on the given channel, if any.
+    fn counterparty_payee(&self, relayer: &Signer, channel_id: &ChannelId) -> Option<Signer>;
+
+    /// Returns the forward relayer address recorded for a received packet
+    /// whose acknowledgement the wrapped application deferred, if any.
+    fn forward_relayer(&self, packet_id: &PacketId) -> Option<Signer>;
+
+    /// Returns the fees escrowed for the given packet, or an empty list if
+    /// there are none.
+    fn packet_fees(&self, packet_id: &PacketId) -> Result<Vec<PacketFee>, FeeError>;
+
+    /// Validates that the given coins can be moved from the payer account
+    /// into the fee escrow.
+    fn escrow_fee_validate(
+        &self,
+        payer: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError>;
+}
+
+/// Methods required in fee middleware execution, to be implemented by the host
+pub trait FeeExecutionContext: FeeValidationContext {
+    /// Marks the fee middleware as enabled on the given channel.
+    fn enable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError>;
+
+    /// Marks the fee middleware as disabled on the given channel.
+    fn disable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError>;
+
+    fn store_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        payee: Signer,
+    ) -> Result<(), FeeError>;
+
+    fn store_counterparty_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        counterparty_payee: Signer,
+    ) -> Result<(), FeeError>;
+
+    /// Records the forward relayer address of a received packet whose
+    /// acknowledgement the wrapped application deferred, until the
+    /// acknowledgement is written.
+    fn store_forward_relayer(
+        &mut self,
+        packet_id: &PacketId,
+        forward_relayer: Signer,
+    ) -> Result<(), FeeError>;
+
+    fn delete_forward_relayer(&mut self, packet_id: &PacketId) -> Result<(), FeeError>;
+
+    /// Stores the fees escrowed for the given packet, replacing any
+    /// previously stored ones.
+    fn store_packet_fees(
+        &mut self,
+        packet_id: &PacketId,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), FeeError>;
+
+    fn delete_packet_fees(&mut self, packet_id: &PacketId) -> Result<(), FeeError>;
+
+    /// Moves the given coins from the payer account into the fee escrow.
+    fn escrow_fee_execute(
+        &mut self,
+        payer: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError>;
+
+    /// Moves the given coins out of the fee escrow into the receiver account.
+    fn distribute_fee_execute(
+        &mut self,
+        receiver: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError>;
+}
@@ -0,0 +1,97 @@
+//! Implements the processing logic for the ICS-29 fee middleware messages.
+mod pay_packet_fee;
+mod pay_packet_fee_async;
+mod register_payee;
+
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::IncentivizedPacketEvent;
+use ibc_app_fee_types::{Fee, PacketFee, PacketId, MODULE_ID_STR};
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::host::ExecutionContext;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+pub use pay_packet_fee::*;
+pub use pay_packet_fee_async::*;
+pub use register_payee::*;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Checks that the total amount of the given packet fee can be escrowed from
+/// its refund address.
+fn escrow_packet_fee_validate(
+    fee_ctx: &impl FeeValidationContext,
+    packet_fee: &PacketFee,
+) -> Result<(), FeeError> {
+    packet_fee.validate_basic()?;
+
+    let payer = packet_fee
+        .refund_address
+        .clone()
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    fee_ctx.escrow_fee_validate(&payer, &packet_fee.fee.total()?)
+}
+
+/// Escrows the total amount of the given packet fee, appends it to the fees
+/// already stored for the packet and emits the updated fee total.
+fn escrow_packet_fee_execute<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    packet_id: PacketId,
+    packet_fee: PacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    let payer = packet_fee
+        .refund_address
+        .clone()
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    fee_ctx.escrow_fee_execute(&payer, &packet_fee.fee.total()?)?;
+
+    let mut packet_fees = fee_ctx.packet_fees(&packet_id)?;
+    packet_fees.push(packet_fee);
+
+    let total_fee = packet_fees
+        .iter()
+        .try_fold(Fee::default(), |total, packet_fee| {
+            total.checked_add(&packet_fee.fee)
+        })?;
+
+    fee_ctx.store_packet_fees(&packet_id, packet_fees)?;
+
+    {
+        let event = IncentivizedPacketEvent {
+            port_id: packet_id.port_id,
+            channel_id: packet_id.channel_id,
+            sequence: packet_id.sequence,
+            total_fee,
+        };
+        ctx.emit_ibc_event(ModuleEvent::from(event).into())?;
+
+        ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the fee middleware is enabled on the given channel.
+fn ensure_fee_enabled(
+    fee_ctx: &impl FeeValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(), FeeError> {
+    if !fee_ctx.is_fee_enabled(port_id, channel_id) {
+        return Err(FeeError::FeeNotEnabled {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+        });
+    }
+
+    Ok(())
+}
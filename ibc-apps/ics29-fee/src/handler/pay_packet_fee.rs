@@ -0,0 +1,63 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::msgs::pay_packet_fee::MsgPayPacketFee;
+use ibc_app_fee_types::{PacketFee, PacketId};
+use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+
+use super::{ensure_fee_enabled, escrow_packet_fee_execute, escrow_packet_fee_validate};
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Escrows a fee for the next packet sent on a channel. Equivalent to calling
+/// [`pay_packet_fee_validate`], followed by [`pay_packet_fee_execute`].
+pub fn pay_packet_fee<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    pay_packet_fee_validate(ctx_a, fee_ctx_a, msg.clone())?;
+    pay_packet_fee_execute(ctx_a, fee_ctx_a, msg)
+}
+
+/// Validates the fee payment. If this succeeds, then it is legal to escrow
+/// the fee with [`pay_packet_fee_execute`].
+pub fn pay_packet_fee_validate<Ctx, FeeCtx>(
+    ctx_a: &Ctx,
+    fee_ctx_a: &FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    ensure_fee_enabled(fee_ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?;
+
+    ctx_a.channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))?;
+
+    let packet_fee = PacketFee::new(msg.fee, msg.signer);
+
+    escrow_packet_fee_validate(fee_ctx_a, &packet_fee)
+}
+
+/// Escrows the fee for the next packet sent on the channel. A prior call to
+/// [`pay_packet_fee_validate`] MUST have succeeded.
+pub fn pay_packet_fee_execute<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let sequence = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    let packet_id = PacketId::new(msg.port_id_on_a, msg.chan_id_on_a, sequence);
+    let packet_fee = PacketFee::new(msg.fee, msg.signer);
+
+    escrow_packet_fee_execute(ctx_a, fee_ctx_a, packet_id, packet_fee)
+}
@@ -0,0 +1,77 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::msgs::pay_packet_fee_async::MsgPayPacketFeeAsync;
+use ibc_core::host::types::path::{CommitmentPath, SeqSendPath};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+
+use super::{ensure_fee_enabled, escrow_packet_fee_execute, escrow_packet_fee_validate};
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Escrows a fee for a packet already sent. Equivalent to calling
+/// [`pay_packet_fee_async_validate`], followed by
+/// [`pay_packet_fee_async_execute`].
+pub fn pay_packet_fee_async<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    pay_packet_fee_async_validate(ctx_a, fee_ctx_a, msg.clone())?;
+    pay_packet_fee_async_execute(ctx_a, fee_ctx_a, msg)
+}
+
+/// Validates the fee payment. The packet must have been sent and not yet
+/// acknowledged or timed out.
+pub fn pay_packet_fee_async_validate<Ctx, FeeCtx>(
+    ctx_a: &Ctx,
+    fee_ctx_a: &FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    let packet_id = &msg.packet_id;
+
+    ensure_fee_enabled(fee_ctx_a, &packet_id.port_id, &packet_id.channel_id)?;
+
+    let not_in_flight = || FeeError::PacketNotInFlight {
+        port_id: packet_id.port_id.clone(),
+        channel_id: packet_id.channel_id.clone(),
+        sequence: packet_id.sequence,
+    };
+
+    let seq_send_path_on_a = SeqSendPath::new(&packet_id.port_id, &packet_id.channel_id);
+    let next_seq_send_on_a = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    if packet_id.sequence >= next_seq_send_on_a {
+        return Err(not_in_flight());
+    }
+
+    let commitment_path_on_a = CommitmentPath::new(
+        &packet_id.port_id,
+        &packet_id.channel_id,
+        packet_id.sequence,
+    );
+    ctx_a
+        .get_packet_commitment(&commitment_path_on_a)
+        .map_err(|_| not_in_flight())?;
+
+    escrow_packet_fee_validate(fee_ctx_a, &msg.packet_fee)
+}
+
+/// Escrows the fee for the packet. A prior call to
+/// [`pay_packet_fee_async_validate`] MUST have succeeded.
+pub fn pay_packet_fee_async_execute<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    escrow_packet_fee_execute(ctx_a, fee_ctx_a, msg.packet_id, msg.packet_fee)
+}
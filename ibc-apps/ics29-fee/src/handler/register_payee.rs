@@ -0,0 +1,142 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::{RegisterCounterpartyPayeeEvent, RegisterPayeeEvent};
+use ibc_app_fee_types::msgs::register_counterparty_payee::MsgRegisterCounterpartyPayee;
+use ibc_app_fee_types::msgs::register_payee::MsgRegisterPayee;
+use ibc_app_fee_types::MODULE_ID_STR;
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use super::ensure_fee_enabled;
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Registers the address to pay a relayer's acknowledgement and timeout fees
+/// to. Equivalent to calling [`register_payee_validate`], followed by
+/// [`register_payee_execute`].
+pub fn register_payee<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    register_payee_validate(ctx, fee_ctx, msg.clone())?;
+    register_payee_execute(ctx, fee_ctx, msg)
+}
+
+/// Validates the registration of the address to pay a relayer's
+/// acknowledgement and timeout fees to.
+pub fn register_payee_validate<Ctx, FeeCtx>(
+    ctx: &Ctx,
+    fee_ctx: &FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    ctx.channel_end(&ChannelEndPath::new(&msg.port_id, &msg.channel_id))?;
+
+    ensure_fee_enabled(fee_ctx, &msg.port_id, &msg.channel_id)?;
+
+    let _payee: FeeCtx::AccountId = msg
+        .payee
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    Ok(())
+}
+
+/// Registers the payee of a relayer. A prior call to
+/// [`register_payee_validate`] MUST have succeeded.
+pub fn register_payee_execute<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    fee_ctx.store_payee(&msg.relayer, &msg.channel_id, msg.payee.clone())?;
+
+    {
+        let event = RegisterPayeeEvent {
+            relayer: msg.relayer,
+            payee: msg.payee,
+            channel_id: msg.channel_id,
+        };
+        ctx.emit_ibc_event(ModuleEvent::from(event).into())?;
+
+        ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
+
+/// Registers the counterparty address to pay a relayer's receive fees to.
+/// Equivalent to calling [`register_counterparty_payee_validate`], followed
+/// by [`register_counterparty_payee_execute`].
+pub fn register_counterparty_payee<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    register_counterparty_payee_validate(ctx, fee_ctx, msg.clone())?;
+    register_counterparty_payee_execute(ctx, fee_ctx, msg)
+}
+
+/// Validates the registration of the counterparty address to pay a
+/// relayer's receive fees to.
+pub fn register_counterparty_payee_validate<Ctx, FeeCtx>(
+    ctx: &Ctx,
+    fee_ctx: &FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    ctx.channel_end(&ChannelEndPath::new(&msg.port_id, &msg.channel_id))?;
+
+    ensure_fee_enabled(fee_ctx, &msg.port_id, &msg.channel_id)
+}
+
+/// Registers the counterparty payee of a relayer. A prior call to
+/// [`register_counterparty_payee_validate`] MUST have succeeded.
+pub fn register_counterparty_payee_execute<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    fee_ctx.store_counterparty_payee(
+        &msg.relayer,
+        &msg.channel_id,
+        msg.counterparty_payee.clone(),
+    )?;
+
+    {
+        let event = RegisterCounterpartyPayeeEvent {
+            relayer: msg.relayer,
+            counterparty_payee: msg.counterparty_payee,
+            channel_id: msg.channel_id,
+        };
+        ctx.emit_ibc_event(ModuleEvent::from(event).into())?;
+
+        ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
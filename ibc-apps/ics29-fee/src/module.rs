@@ -0,0 +1,882 @@
+//! Implements the fee middleware as an IBC [`Module`] wrapping the
+//! application it incentivises relayers for, along with its layer of the
+//! outbound side of a middleware stack.
+use core::fmt::Debug;
+
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::DistributeFeeEvent;
+use ibc_app_fee_types::transfer::PrefixedCoin;
+use ibc_app_fee_types::{IncentivizedAcknowledgement, Metadata, PacketFee, PacketId};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::Ics4Wrapper;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// The fee middleware, which wraps the `App` module and pays the relayers
+/// delivering its packets out of the fees escrowed in `Ctx`.
+///
+/// Channels opened with a version carrying the fee [`Metadata`] are
+/// fee-enabled: the acknowledgements written on them are wrapped in an
+/// [`IncentivizedAcknowledgement`]. All the other channels are passed
+/// through to the wrapped application untouched.
+///
+/// Applications deferring their acknowledgements must write them through a
+/// [`FeeIcs4Wrapper`] for them to be wrapped as well.
+#[derive(Debug)]
+pub struct FeeMiddleware<Ctx, App> {
+    ctx: Ctx,
+    app: App,
+}
+
+impl<Ctx, App> FeeMiddleware<Ctx, App>
+where
+    Ctx: FeeExecutionContext,
+    App: Module,
+{
+    pub fn new(ctx: Ctx, app: App) -> Self {
+        Self { ctx, app }
+    }
+
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut Ctx {
+        &mut self.ctx
+    }
+
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Pays out the coins to the receiver, falling back to the refund
+    /// address if the receiver is invalid or the payment fails.
+    fn distribute_fee(
+        &mut self,
+        receiver: &Signer,
+        refund_address: &Signer,
+        coins: Vec<PrefixedCoin>,
+        extras: &mut ModuleExtras,
+    ) {
+        if coins.is_empty() {
+            return;
+        }
+
+        let paid = !receiver.as_ref().is_empty()
+            && parse_account::<Ctx>(receiver)
+                .and_then(|account| self.ctx.distribute_fee_execute(&account, &coins))
+                .is_ok();
+
+        let receiver = if paid {
+            receiver
+        } else {
+            let refunded = parse_account::<Ctx>(refund_address)
+                .and_then(|account| self.ctx.distribute_fee_execute(&account, &coins));
+
+            if let Err(err) = refunded {
+                extras
+                    .log
+                    .push(format!("failed to refund fee to {refund_address}: {err}"));
+                return;
+            }
+
+            refund_address
+        };
+
+        extras.events.push(
+            DistributeFeeEvent {
+                receiver: receiver.clone(),
+                fee: coins,
+            }
+            .into(),
+        );
+    }
+
+    /// Returns the address to pay the acknowledgement or timeout fee of the
+    /// given relayer to.
+    fn payee_or_relayer(&self, relayer: &Signer, channel_id: &ChannelId) -> Signer {
+        self.ctx
+            .payee(relayer, channel_id)
+            .unwrap_or_else(|| relayer.clone())
+    }
+
+    fn distribute_packet_fees_on_ack(
+        &mut self,
+        packet_fees: Vec<PacketFee>,
+        forward_relayer: &Signer,
+        reverse_relayer: &Signer,
+        extras: &mut ModuleExtras,
+    ) -> Result<(), FeeError> {
+        for packet_fee in packet_fees {
+            let refund = packet_fee.fee.refund_on_ack()?;
+            let PacketFee {
+                fee,
+                refund_address,
+                ..
+            } = packet_fee;
+
+            self.distribute_fee(forward_relayer, &refund_address, fee.recv_fee, extras);
+            self.distribute_fee(reverse_relayer, &refund_address, fee.ack_fee, extras);
+            self.distribute_fee(&refund_address, &refund_address, refund, extras);
+        }
+
+        Ok(())
+    }
+
+    fn distribute_packet_fees_on_timeout(
+        &mut self,
+        packet_fees: Vec<PacketFee>,
+        timeout_relayer: &Signer,
+        extras: &mut ModuleExtras,
+    ) -> Result<(), FeeError> {
+        for packet_fee in packet_fees {
+            let refund = packet_fee.fee.refund_on_timeout()?;
+            let PacketFee {
+                fee,
+                refund_address,
+                ..
+            } = packet_fee;
+
+            self.distribute_fee(timeout_relayer, &refund_address, fee.timeout_fee, extras);
+            self.distribute_fee(&refund_address, &refund_address, refund, extras);
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_account<Ctx: FeeValidationContext>(signer: &Signer) -> Result<Ctx::AccountId, FeeError> {
+    signer
+        .clone()
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)
+}
+
+/// Parses the fee metadata out of a channel version, returning `None` if the
+/// version is not a fee version, in which case the channel is not
+/// fee-enabled.
+fn parse_metadata(version: &Version) -> Result<Option<Metadata>, FeeError> {
+    let Ok(metadata) = Metadata::from_version(version) else {
+        return Ok(None);
+    };
+
+    if !metadata.is_supported() {
+        return Err(FeeError::InvalidFeeVersion {
+            expected: ibc_app_fee_types::VERSION.to_string(),
+            actual: metadata.fee_version.to_string(),
+        });
+    }
+
+    Ok(Some(metadata))
+}
+
+/// Wraps the acknowledgement of the application in an
+/// [`IncentivizedAcknowledgement`] naming the given forward relayer.
+fn incentivize(
+    app_acknowledgement: Acknowledgement,
+    forward_relayer_address: Signer,
+) -> Acknowledgement {
+    let underlying_app_success =
+        serde_json::from_slice::<AcknowledgementStatus>(app_acknowledgement.as_bytes())
+            .map(|ack| ack.is_successful())
+            .unwrap_or(true);
+
+    IncentivizedAcknowledgement {
+        app_acknowledgement,
+        forward_relayer_address,
+        underlying_app_success,
+    }
+    .into()
+}
+
+fn append_extras(extras: &mut ModuleExtras, other: ModuleExtras) {
+    extras.events.extend(other.events);
+    extras.log.extend(other.log);
+}
+
+impl<Ctx, App> Module for FeeMiddleware<Ctx, App>
+where
+    Ctx: FeeExecutionContext + Debug,
+    App: Module,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        match parse_metadata(version)? {
+            Some(metadata) => {
+                let app_version = self.app.on_chan_open_init_validate(
+                    order,
+                    connection_hops,
+                    port_id,
+                    channel_id,
+                    counterparty,
+                    &metadata.app_version,
+                )?;
+
+                Ok(Metadata::new(app_version).to_version())
+            }
+            None => self.app.on_chan_open_init_validate(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                version,
+            ),
+        }
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        match parse_metadata(version)? {
+            Some(metadata) => {
+                let (extras, app_version) = self.app.on_chan_open_init_execute(
+                    order,
+                    connection_hops,
+                    port_id,
+                    channel_id,
+                    counterparty,
+                    &metadata.app_version,
+                )?;
+
+                self.ctx.enable_fee(port_id, channel_id)?;
+
+                Ok((extras, Metadata::new(app_version).to_version()))
+            }
+            None => self.app.on_chan_open_init_execute(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                version,
+            ),
+        }
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        match parse_metadata(counterparty_version)? {
+            Some(metadata) => {
+                let app_version = self.app.on_chan_open_try_validate(
+                    order,
+                    connection_hops,
+                    port_id,
+                    channel_id,
+                    counterparty,
+                    &metadata.app_version,
+                )?;
+
+                Ok(Metadata::new(app_version).to_version())
+            }
+            None => self.app.on_chan_open_try_validate(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                counterparty_version,
+            ),
+        }
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        match parse_metadata(counterparty_version)? {
+            Some(metadata) => {
+                let (extras, app_version) = self.app.on_chan_open_try_execute(
+                    order,
+                    connection_hops,
+                    port_id,
+                    channel_id,
+                    counterparty,
+                    &metadata.app_version,
+                )?;
+
+                self.ctx.enable_fee(port_id, channel_id)?;
+
+                Ok((extras, Metadata::new(app_version).to_version()))
+            }
+            None => self.app.on_chan_open_try_execute(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                counterparty_version,
+            ),
+        }
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        if !self.ctx.is_fee_enabled(port_id, channel_id) {
+            return self
+                .app
+                .on_chan_open_ack_validate(port_id, channel_id, counterparty_version);
+        }
+
+        let metadata =
+            parse_metadata(counterparty_version)?.ok_or(FeeError::MetadataDeserialization)?;
+
+        self.app
+            .on_chan_open_ack_validate(port_id, channel_id, &metadata.app_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        if !self.ctx.is_fee_enabled(port_id, channel_id) {
+            return self
+                .app
+                .on_chan_open_ack_execute(port_id, channel_id, counterparty_version);
+        }
+
+        let metadata =
+            parse_metadata(counterparty_version)?.ok_or(FeeError::MetadataDeserialization)?;
+
+        self.app
+            .on_chan_open_ack_execute(port_id, channel_id, &metadata.app_version)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.app.on_chan_open_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app.on_chan_open_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.app.on_chan_close_init_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app.on_chan_close_init_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.app.on_chan_close_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app.on_chan_close_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        match parse_metadata(proposed_version)? {
+            Some(metadata) => {
+                let app_version = self.app.on_chan_upgrade_init_validate(
+                    port_id,
+                    channel_id,
+                    proposed_order,
+                    proposed_connection_hops,
+                    &metadata.app_version,
+                )?;
+
+                Ok(Metadata::new(app_version).to_version())
+            }
+            None => self.app.on_chan_upgrade_init_validate(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                proposed_version,
+            ),
+        }
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        match parse_metadata(proposed_version)? {
+            Some(metadata) => {
+                let (extras, app_version) = self.app.on_chan_upgrade_init_execute(
+                    port_id,
+                    channel_id,
+                    proposed_order,
+                    proposed_connection_hops,
+                    &metadata.app_version,
+                )?;
+
+                Ok((extras, Metadata::new(app_version).to_version()))
+            }
+            None => self.app.on_chan_upgrade_init_execute(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                proposed_version,
+            ),
+        }
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        match parse_metadata(counterparty_version)? {
+            Some(metadata) => {
+                let app_version = self.app.on_chan_upgrade_try_validate(
+                    port_id,
+                    channel_id,
+                    proposed_order,
+                    proposed_connection_hops,
+                    &metadata.app_version,
+                )?;
+
+                Ok(Metadata::new(app_version).to_version())
+            }
+            None => self.app.on_chan_upgrade_try_validate(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                counterparty_version,
+            ),
+        }
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        match parse_metadata(counterparty_version)? {
+            Some(metadata) => {
+                let (extras, app_version) = self.app.on_chan_upgrade_try_execute(
+                    port_id,
+                    channel_id,
+                    proposed_order,
+                    proposed_connection_hops,
+                    &metadata.app_version,
+                )?;
+
+                Ok((extras, Metadata::new(app_version).to_version()))
+            }
+            None => self.app.on_chan_upgrade_try_execute(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                counterparty_version,
+            ),
+        }
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        let app_version = parse_metadata(counterparty_version)?
+            .map(|metadata| metadata.app_version)
+            .unwrap_or_else(|| counterparty_version.clone());
+
+        self.app
+            .on_chan_upgrade_ack_validate(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let app_version = parse_metadata(counterparty_version)?
+            .map(|metadata| metadata.app_version)
+            .unwrap_or_else(|| counterparty_version.clone());
+
+        self.app
+            .on_chan_upgrade_ack_execute(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> ModuleExtras {
+        // The version was agreed upon by both ends, hence is a supported fee
+        // version whenever it carries fee metadata.
+        let (fee_update, app_version) = match parse_metadata(version) {
+            Ok(Some(metadata)) => (
+                self.ctx.enable_fee(port_id, channel_id),
+                metadata.app_version,
+            ),
+            _ => (self.ctx.disable_fee(port_id, channel_id), version.clone()),
+        };
+
+        let mut extras = self.app.on_chan_upgrade_open_execute(
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            &app_version,
+        );
+
+        if let Err(err) = fee_update {
+            extras.log.push(format!(
+                "failed to update the fee status of channel {channel_id}: {err}"
+            ));
+        }
+
+        extras
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        if !self
+            .ctx
+            .is_fee_enabled(&packet.port_id_on_b, &packet.chan_id_on_b)
+        {
+            return self.app.on_recv_packet_execute(packet, relayer);
+        }
+
+        let (mut extras, app_acknowledgement) = self.app.on_recv_packet_execute(packet, relayer);
+
+        let forward_relayer_address = self
+            .ctx
+            .counterparty_payee(relayer, &packet.chan_id_on_b)
+            .unwrap_or_else(|| String::new().into());
+
+        let Some(app_acknowledgement) = app_acknowledgement else {
+            // the acknowledgement is wrapped once the application writes it,
+            // through the `FeeIcs4Wrapper`
+            let packet_id = PacketId::new(
+                packet.port_id_on_b.clone(),
+                packet.chan_id_on_b.clone(),
+                packet.seq_on_a,
+            );
+
+            if let Err(err) = self
+                .ctx
+                .store_forward_relayer(&packet_id, forward_relayer_address)
+            {
+                extras.log.push(format!(
+                    "failed to record the forward relayer of packet {}: {err}",
+                    packet.seq_on_a
+                ));
+            }
+
+            return (extras, None);
+        };
+
+        (
+            extras,
+            Some(incentivize(app_acknowledgement, forward_relayer_address)),
+        )
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        if !self
+            .ctx
+            .is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a)
+        {
+            return self
+                .app
+                .on_acknowledgement_packet_validate(packet, acknowledgement, relayer);
+        }
+
+        let ack = IncentivizedAcknowledgement::try_from(acknowledgement)?;
+
+        self.app
+            .on_acknowledgement_packet_validate(packet, &ack.app_acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        if !self
+            .ctx
+            .is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a)
+        {
+            return self
+                .app
+                .on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+        }
+
+        let ack = match IncentivizedAcknowledgement::try_from(acknowledgement) {
+            Ok(ack) => ack,
+            Err(err) => return (ModuleExtras::empty(), Err(err.into())),
+        };
+
+        let packet_id = PacketId::new(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+        );
+
+        let mut extras = ModuleExtras::empty();
+
+        let packet_fees = match self.ctx.packet_fees(&packet_id) {
+            Ok(packet_fees) => packet_fees,
+            Err(err) => return (extras, Err(err.into())),
+        };
+
+        if !packet_fees.is_empty() {
+            let reverse_relayer = self.payee_or_relayer(relayer, &packet.chan_id_on_a);
+
+            let distributed = self
+                .distribute_packet_fees_on_ack(
+                    packet_fees,
+                    &ack.forward_relayer_address,
+                    &reverse_relayer,
+                    &mut extras,
+                )
+                .and_then(|_| self.ctx.delete_packet_fees(&packet_id));
+
+            if let Err(err) = distributed {
+                return (extras, Err(err.into()));
+            }
+        }
+
+        let (app_extras, result) =
+            self.app
+                .on_acknowledgement_packet_execute(packet, &ack.app_acknowledgement, relayer);
+        append_extras(&mut extras, app_extras);
+
+        (extras, result)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.app.on_timeout_packet_validate(packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        if !self
+            .ctx
+            .is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a)
+        {
+            return self.app.on_timeout_packet_execute(packet, relayer);
+        }
+
+        let packet_id = PacketId::new(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+        );
+
+        let mut extras = ModuleExtras::empty();
+
+        let packet_fees = match self.ctx.packet_fees(&packet_id) {
+            Ok(packet_fees) => packet_fees,
+            Err(err) => return (extras, Err(err.into())),
+        };
+
+        if !packet_fees.is_empty() {
+            let timeout_relayer = self.payee_or_relayer(relayer, &packet.chan_id_on_a);
+
+            let distributed = self
+                .distribute_packet_fees_on_timeout(packet_fees, &timeout_relayer, &mut extras)
+                .and_then(|_| self.ctx.delete_packet_fees(&packet_id));
+
+            if let Err(err) = distributed {
+                return (extras, Err(err.into()));
+            }
+        }
+
+        let (app_extras, result) = self.app.on_timeout_packet_execute(packet, relayer);
+        append_extras(&mut extras, app_extras);
+
+        (extras, result)
+    }
+}
+
+/// The layer of the fee middleware in the outbound side of a middleware
+/// stack, which wraps the acknowledgements the application deferred on
+/// fee-enabled channels in an [`IncentivizedAcknowledgement`], naming the
+/// forward relayer recorded when the packet was received.
+#[derive(Debug)]
+pub struct FeeIcs4Wrapper<'a, Ctx, W> {
+    ctx: &'a mut Ctx,
+    next: &'a mut W,
+}
+
+impl<'a, Ctx, W> FeeIcs4Wrapper<'a, Ctx, W>
+where
+    Ctx: FeeExecutionContext,
+    W: Ics4Wrapper,
+{
+    pub fn new(ctx: &'a mut Ctx, next: &'a mut W) -> Self {
+        Self { ctx, next }
+    }
+}
+
+impl<Ctx, W> Ics4Wrapper for FeeIcs4Wrapper<'_, Ctx, W>
+where
+    Ctx: FeeExecutionContext,
+    W: Ics4Wrapper,
+{
+    fn send_packet(&mut self, packet: Packet) -> Result<(), ContextError> {
+        self.next.send_packet(packet)
+    }
+
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), ContextError> {
+        if !self
+            .ctx
+            .is_fee_enabled(&packet.port_id_on_b, &packet.chan_id_on_b)
+        {
+            return self.next.write_acknowledgement(packet, acknowledgement);
+        }
+
+        let packet_id = PacketId::new(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+        let forward_relayer_address = self
+            .ctx
+            .forward_relayer(&packet_id)
+            .ok_or_else(|| FeeError::ForwardRelayerNotFound {
+                port_id: packet.port_id_on_b.clone(),
+                channel_id: packet.chan_id_on_b.clone(),
+                sequence: packet.seq_on_a,
+            })
+            .map_err(PacketError::from)?;
+
+        self.ctx
+            .delete_forward_relayer(&packet_id)
+            .map_err(PacketError::from)?;
+
+        self.next.write_acknowledgement(
+            packet,
+            incentivize(acknowledgement, forward_relayer_address),
+        )
+    }
+
+    fn app_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, ContextError> {
+        let version = self.next.app_version(port_id, channel_id)?;
+
+        if !self.ctx.is_fee_enabled(port_id, channel_id) {
+            return Ok(version);
+        }
+
+        match parse_metadata(&version).map_err(ChannelError::from)? {
+            Some(metadata) => Ok(metadata.app_version),
+            None => Ok(version),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Defines the acknowledgement written on fee-enabled channels.
+#[cfg(feature = "serde")]
+use base64::prelude::BASE64_STANDARD;
+#[cfg(feature = "serde")]
+use base64::Engine;
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::ibc::applications::fee::v1::IncentivizedAcknowledgement as RawIncentivizedAcknowledgement;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+/// Wraps the acknowledgement of the underlying application with the address
+/// to which the receive fee must be paid on the sending chain.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncentivizedAcknowledgement {
+    pub app_acknowledgement: Acknowledgement,
+    /// The counterparty payee of the relayer that delivered the packet.
+    /// Empty if the relayer did not register one.
+    pub forward_relayer_address: Signer,
+    pub underlying_app_success: bool,
+}
+
+impl Protobuf<RawIncentivizedAcknowledgement> for IncentivizedAcknowledgement {}
+
+impl TryFrom<RawIncentivizedAcknowledgement> for IncentivizedAcknowledgement {
+    type Error = FeeError;
+
+    fn try_from(raw_ack: RawIncentivizedAcknowledgement) -> Result<Self, Self::Error> {
+        Ok(Self {
+            app_acknowledgement: raw_ack
+                .app_acknowledgement
+                .try_into()
+                .map_err(|_| FeeError::AckDeserialization)?,
+            forward_relayer_address: raw_ack.forward_relayer_address.into(),
+            underlying_app_success: raw_ack.underlying_app_success,
+        })
+    }
+}
+
+impl From<IncentivizedAcknowledgement> for RawIncentivizedAcknowledgement {
+    fn from(ack: IncentivizedAcknowledgement) -> Self {
+        RawIncentivizedAcknowledgement {
+            app_acknowledgement: ack.app_acknowledgement.into(),
+            forward_relayer_address: ack.forward_relayer_address.to_string(),
+            underlying_app_success: ack.underlying_app_success,
+        }
+    }
+}
+
+/// The JSON encoding of an [`IncentivizedAcknowledgement`], as written by
+/// ibc-go.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JsonIncentivizedAcknowledgement {
+    app_acknowledgement: String,
+    forward_relayer_address: String,
+    underlying_app_success: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<&Acknowledgement> for IncentivizedAcknowledgement {
+    type Error = FeeError;
+
+    fn try_from(ack: &Acknowledgement) -> Result<Self, Self::Error> {
+        let json_ack = serde_json::from_slice::<JsonIncentivizedAcknowledgement>(ack.as_bytes())
+            .map_err(|_| FeeError::AckDeserialization)?;
+
+        let app_acknowledgement = BASE64_STANDARD
+            .decode(json_ack.app_acknowledgement)
+            .map_err(|_| FeeError::AckDeserialization)?
+            .try_into()
+            .map_err(|_| FeeError::AckDeserialization)?;
+
+        Ok(Self {
+            app_acknowledgement,
+            forward_relayer_address: json_ack.forward_relayer_address.into(),
+            underlying_app_success: json_ack.underlying_app_success,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<IncentivizedAcknowledgement> for Acknowledgement {
+    fn from(ack: IncentivizedAcknowledgement) -> Self {
+        let json_ack = JsonIncentivizedAcknowledgement {
+            app_acknowledgement: BASE64_STANDARD.encode(ack.app_acknowledgement.as_bytes()),
+            forward_relayer_address: ack.forward_relayer_address.to_string(),
+            underlying_app_success: ack.underlying_app_success,
+        };
+
+        serde_json::to_vec(&json_ack)
+            .expect("JsonIncentivizedAcknowledgement's infallible Serialize impl failed")
+            .try_into()
+            .expect("a JSON object is never empty")
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn incentivized_ack_json_roundtrip() {
+        let ack = IncentivizedAcknowledgement {
+            app_acknowledgement: br#"{"result":"AQ=="}"#.to_vec().try_into().unwrap(),
+            forward_relayer_address: "cosmos1payee".to_string().into(),
+            underlying_app_success: true,
+        };
+
+        let encoded = Acknowledgement::from(ack.clone());
+
+        assert_eq!(
+            encoded.as_bytes(),
+            br#"{"app_acknowledgement":"eyJyZXN1bHQiOiJBUT09In0=","forward_relayer_address":"cosmos1payee","underlying_app_success":true}"#
+        );
+        assert_eq!(
+            IncentivizedAcknowledgement::try_from(&encoded).unwrap(),
+            ack
+        );
+    }
+}
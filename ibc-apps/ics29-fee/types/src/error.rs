@@ -0,0 +1,115 @@
+//! Defines the fee middleware error type
+use core::convert::Infallible;
+
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum FeeError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// invalid fee coin: `{0}`
+    InvalidCoin(TokenTransferError),
+    /// missing fee
+    MissingFee,
+    /// missing packet identifier
+    MissingPacketId,
+    /// fee must contain at least one non-zero amount
+    EmptyFee,
+    /// fee amount overflow
+    FeeOverflow,
+    /// invalid fee version: expected `{expected}`, got `{actual}`
+    InvalidFeeVersion { expected: String, actual: String },
+    /// fee middleware is not enabled on port `{port_id}` and channel `{channel_id}`
+    FeeNotEnabled {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// payee address cannot be empty
+    EmptyPayee,
+    /// restricting the relayers of a packet is not supported
+    RelayersNotSupported,
+    /// packet `{sequence}` on port `{port_id}` and channel `{channel_id}` is not in flight
+    PacketNotInFlight {
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+    },
+    /// no forward relayer recorded for the deferred acknowledgement of packet `{sequence}` on port `{port_id}` and channel `{channel_id}`
+    ForwardRelayerNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+    },
+    /// failed to parse as AccountId
+    ParseAccountFailure,
+    /// failed to deserialize the channel version metadata
+    MetadataDeserialization,
+    /// failed to deserialize incentivized acknowledgement
+    AckDeserialization,
+    /// decoding raw msg error: `{reason}`
+    DecodeRawMsg { reason: String },
+    /// unknown msg type: `{msg_type}`
+    UnknownMsgType { msg_type: String },
+    /// other error: `{0}`
+    Other(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FeeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidCoin(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Infallible> for FeeError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<ContextError> for FeeError {
+    fn from(err: ContextError) -> FeeError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for FeeError {
+    fn from(err: IdentifierError) -> FeeError {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<TokenTransferError> for FeeError {
+    fn from(err: TokenTransferError) -> FeeError {
+        Self::InvalidCoin(err)
+    }
+}
+
+impl From<FeeError> for ChannelError {
+    fn from(err: FeeError) -> ChannelError {
+        ChannelError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<FeeError> for PacketError {
+    fn from(err: FeeError) -> PacketError {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
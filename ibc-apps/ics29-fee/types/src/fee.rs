@@ -0,0 +1,322 @@
+//! Defines the fees paid to relayers for delivering a packet.
+use ibc_app_transfer_types::{Amount, PrefixedCoin, PrefixedDenom};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::ibc::applications::fee::v1::{Fee as RawFee, PacketFee as RawPacketFee};
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+/// The fees paid to the relayers delivering a packet and its acknowledgement
+/// or timeout.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fee {
+    /// Paid to the relayer delivering the packet to the counterparty chain
+    pub recv_fee: Vec<PrefixedCoin>,
+    /// Paid to the relayer delivering the acknowledgement back
+    pub ack_fee: Vec<PrefixedCoin>,
+    /// Paid to the relayer delivering a timeout of the packet
+    pub timeout_fee: Vec<PrefixedCoin>,
+}
+
+impl Fee {
+    pub fn new(
+        recv_fee: Vec<PrefixedCoin>,
+        ack_fee: Vec<PrefixedCoin>,
+        timeout_fee: Vec<PrefixedCoin>,
+    ) -> Self {
+        Self {
+            recv_fee,
+            ack_fee,
+            timeout_fee,
+        }
+    }
+
+    /// Checks that the fee contains at least one non-zero amount.
+    pub fn validate_basic(&self) -> Result<(), FeeError> {
+        let zero = Amount::from(0u64);
+
+        let is_empty = self
+            .recv_fee
+            .iter()
+            .chain(self.ack_fee.iter())
+            .chain(self.timeout_fee.iter())
+            .all(|coin| coin.amount == zero);
+
+        if is_empty {
+            return Err(FeeError::EmptyFee);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the amount escrowed for the fee: the receive fee plus the
+    /// largest of the acknowledgement and timeout fees, as only one of them
+    /// is ever paid out.
+    pub fn total(&self) -> Result<Vec<PrefixedCoin>, FeeError> {
+        let mut total = Coins::default();
+
+        total.add(&self.recv_fee)?;
+        total.add(&Coins::max(&self.ack_fee, &self.timeout_fee)?.into_vec())?;
+
+        Ok(total.into_vec())
+    }
+
+    /// Returns the part of the escrowed amount to refund once the
+    /// receive and acknowledgement fees have been paid out.
+    pub fn refund_on_ack(&self) -> Result<Vec<PrefixedCoin>, FeeError> {
+        let mut refund = Coins::max(&self.ack_fee, &self.timeout_fee)?;
+
+        refund.sub(&self.ack_fee)?;
+
+        Ok(refund.into_vec())
+    }
+
+    /// Returns the part of the escrowed amount to refund once the timeout
+    /// fee has been paid out.
+    pub fn refund_on_timeout(&self) -> Result<Vec<PrefixedCoin>, FeeError> {
+        let mut refund = Coins::default();
+
+        refund.add(&self.recv_fee)?;
+        refund.add(&Coins::max(&self.ack_fee, &self.timeout_fee)?.into_vec())?;
+        refund.sub(&self.timeout_fee)?;
+
+        Ok(refund.into_vec())
+    }
+
+    /// Adds up two fees, category by category.
+    pub fn checked_add(&self, other: &Fee) -> Result<Fee, FeeError> {
+        fn sum(lhs: &[PrefixedCoin], rhs: &[PrefixedCoin]) -> Result<Vec<PrefixedCoin>, FeeError> {
+            let mut coins = Coins::default();
+            coins.add(lhs)?;
+            coins.add(rhs)?;
+            Ok(coins.into_vec())
+        }
+
+        Ok(Fee {
+            recv_fee: sum(&self.recv_fee, &other.recv_fee)?,
+            ack_fee: sum(&self.ack_fee, &other.ack_fee)?,
+            timeout_fee: sum(&self.timeout_fee, &other.timeout_fee)?,
+        })
+    }
+}
+
+impl Protobuf<RawFee> for Fee {}
+
+impl TryFrom<RawFee> for Fee {
+    type Error = FeeError;
+
+    fn try_from(raw_fee: RawFee) -> Result<Self, Self::Error> {
+        fn parse_coins(
+            raw_coins: Vec<ibc_proto::cosmos::base::v1beta1::Coin>,
+        ) -> Result<Vec<PrefixedCoin>, FeeError> {
+            raw_coins
+                .into_iter()
+                .map(|coin| PrefixedCoin::try_from(coin).map_err(FeeError::from))
+                .collect()
+        }
+
+        Ok(Self {
+            recv_fee: parse_coins(raw_fee.recv_fee)?,
+            ack_fee: parse_coins(raw_fee.ack_fee)?,
+            timeout_fee: parse_coins(raw_fee.timeout_fee)?,
+        })
+    }
+}
+
+impl From<Fee> for RawFee {
+    fn from(fee: Fee) -> Self {
+        RawFee {
+            recv_fee: fee.recv_fee.into_iter().map(Into::into).collect(),
+            ack_fee: fee.ack_fee.into_iter().map(Into::into).collect(),
+            timeout_fee: fee.timeout_fee.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A fee escrowed for a packet, along with the account to refund the unpaid
+/// part of it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketFee {
+    pub fee: Fee,
+    pub refund_address: Signer,
+    /// Optional list of relayers allowed to claim the fee. Not supported
+    /// yet, hence always empty.
+    pub relayers: Vec<Signer>,
+}
+
+impl PacketFee {
+    pub fn new(fee: Fee, refund_address: Signer) -> Self {
+        Self {
+            fee,
+            refund_address,
+            relayers: Vec::new(),
+        }
+    }
+
+    pub fn validate_basic(&self) -> Result<(), FeeError> {
+        if !self.relayers.is_empty() {
+            return Err(FeeError::RelayersNotSupported);
+        }
+
+        self.fee.validate_basic()
+    }
+}
+
+impl Protobuf<RawPacketFee> for PacketFee {}
+
+impl TryFrom<RawPacketFee> for PacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw_packet_fee: RawPacketFee) -> Result<Self, Self::Error> {
+        Ok(Self {
+            fee: raw_packet_fee.fee.ok_or(FeeError::MissingFee)?.try_into()?,
+            refund_address: raw_packet_fee.refund_address.into(),
+            relayers: raw_packet_fee
+                .relayers
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+impl From<PacketFee> for RawPacketFee {
+    fn from(packet_fee: PacketFee) -> Self {
+        RawPacketFee {
+            fee: Some(packet_fee.fee.into()),
+            refund_address: packet_fee.refund_address.to_string(),
+            relayers: packet_fee
+                .relayers
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+}
+
+/// Coin amounts aggregated by denomination, used for the fee arithmetic.
+#[derive(Default)]
+struct Coins(BTreeMap<PrefixedDenom, Amount>);
+
+impl Coins {
+    /// Returns the largest amount of each denomination found in `lhs` or `rhs`.
+    fn max(lhs: &[PrefixedCoin], rhs: &[PrefixedCoin]) -> Result<Self, FeeError> {
+        let mut lhs_coins = Self::default();
+        lhs_coins.add(lhs)?;
+
+        let mut rhs_coins = Self::default();
+        rhs_coins.add(rhs)?;
+
+        for (denom, amount) in rhs_coins.0 {
+            let entry = lhs_coins.0.entry(denom).or_insert(amount);
+            *entry = (*entry).max(amount);
+        }
+
+        Ok(lhs_coins)
+    }
+
+    fn add(&mut self, coins: &[PrefixedCoin]) -> Result<(), FeeError> {
+        for coin in coins {
+            let entry = self
+                .0
+                .entry(coin.denom.clone())
+                .or_insert_with(|| Amount::from(0u64));
+            *entry = entry
+                .checked_add(coin.amount)
+                .ok_or(FeeError::FeeOverflow)?;
+        }
+
+        Ok(())
+    }
+
+    fn sub(&mut self, coins: &[PrefixedCoin]) -> Result<(), FeeError> {
+        for coin in coins {
+            let entry = self
+                .0
+                .entry(coin.denom.clone())
+                .or_insert_with(|| Amount::from(0u64));
+            *entry = entry
+                .checked_sub(coin.amount)
+                .ok_or(FeeError::FeeOverflow)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the non-zero coins, sorted by denomination.
+    fn into_vec(self) -> Vec<PrefixedCoin> {
+        let zero = Amount::from(0u64);
+
+        self.0
+            .into_iter()
+            .filter(|(_, amount)| *amount != zero)
+            .map(|(denom, amount)| PrefixedCoin { denom, amount })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coins(coins: &[(&str, u64)]) -> Vec<PrefixedCoin> {
+        coins
+            .iter()
+            .map(|(denom, amount)| PrefixedCoin {
+                denom: denom.parse().unwrap(),
+                amount: Amount::from(*amount),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fee_total_and_refunds() {
+        let fee = Fee::new(
+            coins(&[("stake", 10)]),
+            coins(&[("stake", 5), ("uatom", 3)]),
+            coins(&[("stake", 7)]),
+        );
+
+        assert_eq!(fee.total().unwrap(), coins(&[("stake", 17), ("uatom", 3)]));
+        assert_eq!(fee.refund_on_ack().unwrap(), coins(&[("stake", 2)]));
+        assert_eq!(
+            fee.refund_on_timeout().unwrap(),
+            coins(&[("stake", 10), ("uatom", 3)])
+        );
+
+        let sum = fee.checked_add(&fee).unwrap();
+        assert_eq!(sum.recv_fee, coins(&[("stake", 20)]));
+        assert_eq!(sum.ack_fee, coins(&[("stake", 10), ("uatom", 6)]));
+        assert_eq!(sum.timeout_fee, coins(&[("stake", 14)]));
+    }
+
+    #[test]
+    fn fee_validate_basic() {
+        let fee = Fee::new(coins(&[("stake", 0)]), vec![], coins(&[("stake", 0)]));
+        assert!(fee.validate_basic().is_err());
+
+        let fee = Fee::new(vec![], vec![], coins(&[("stake", 1)]));
+        assert!(fee.validate_basic().is_ok());
+    }
+}
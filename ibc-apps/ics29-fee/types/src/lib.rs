@@ -0,0 +1,47 @@
+//! Implementation of the IBC [relayer
+//! incentivisation](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md)
+//! (ICS-29) data structures.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod acknowledgement;
+mod fee;
+mod metadata;
+mod packet_id;
+
+pub use acknowledgement::*;
+pub use fee::*;
+pub use metadata::*;
+pub use packet_id::*;
+pub mod error;
+pub mod events;
+pub mod msgs;
+
+/// Re-exports ICS-29 fee middleware proto types from the `ibc-proto` crate.
+pub mod proto {
+    pub use ibc_proto::ibc::applications::fee;
+}
+
+/// Re-exports the ICS-20 coin types in which fees are denominated.
+pub mod transfer {
+    pub use ibc_app_transfer_types::{Amount, PrefixedCoin, PrefixedDenom};
+}
+
+/// Module identifier for the ICS-29 fee middleware.
+pub const MODULE_ID_STR: &str = "feeibc";
+
+/// ICS-29 fee middleware current version.
+pub const VERSION: &str = "ics29-1";
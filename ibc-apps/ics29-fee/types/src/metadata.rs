@@ -0,0 +1,69 @@
+//! Defines the version metadata of fee-enabled channels.
+use ibc_core::channel::types::Version;
+use ibc_core::primitives::prelude::*;
+
+#[cfg(feature = "serde")]
+use crate::error::FeeError;
+use crate::VERSION;
+
+/// The version of a fee-enabled channel, which wraps the version of the
+/// underlying application.
+///
+/// It is encoded in the channel version as a JSON object, e.g.
+/// `{"fee_version":"ics29-1","app_version":"ics20-1"}`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    pub fee_version: Version,
+    pub app_version: Version,
+}
+
+impl Metadata {
+    /// Wraps the given application version with the current fee version.
+    pub fn new(app_version: Version) -> Self {
+        Self {
+            fee_version: Version::new(VERSION.to_string()),
+            app_version,
+        }
+    }
+
+    /// Returns `true` if the metadata carries the fee version supported by
+    /// this implementation.
+    pub fn is_supported(&self) -> bool {
+        self.fee_version.as_str() == VERSION
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Metadata {
+    /// Parses the metadata out of a channel version.
+    pub fn from_version(version: &Version) -> Result<Self, FeeError> {
+        serde_json::from_str(version.as_str()).map_err(|_| FeeError::MetadataDeserialization)
+    }
+
+    /// Encodes the metadata as a channel version.
+    pub fn to_version(&self) -> Version {
+        Version::new(
+            serde_json::to_string(self).expect("Metadata's infallible Serialize impl failed"),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_version_roundtrip() {
+        let metadata = Metadata::new(Version::new("ics20-1".to_string()));
+        let version = metadata.to_version();
+
+        assert_eq!(
+            version.as_str(),
+            r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#
+        );
+        assert_eq!(Metadata::from_version(&version).unwrap(), metadata);
+        assert!(Metadata::from_version(&Version::new("ics20-1".to_string())).is_err());
+    }
+}
@@ -0,0 +1,5 @@
+//! Defines the fee middleware message types
+pub mod pay_packet_fee;
+pub mod pay_packet_fee_async;
+pub mod register_counterparty_payee;
+pub mod register_payee;
@@ -0,0 +1,97 @@
+//! Defines the message used to escrow a fee for the next packet sent on a channel
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::fee::v1::MsgPayPacketFee as RawMsgPayPacketFee;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+use crate::Fee;
+
+pub(crate) const TYPE_URL: &str = "/ibc.applications.fee.v1.MsgPayPacketFee";
+
+/// Message used to escrow a fee for the next packet sent on the given
+/// channel, typically submitted in the same transaction as the message
+/// sending the packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgPayPacketFee {
+    pub fee: Fee,
+    /// the port on which the packet will be sent
+    pub port_id_on_a: PortId,
+    /// the channel on which the packet will be sent
+    pub chan_id_on_a: ChannelId,
+    /// the account paying the fee, to which the unpaid fees are refunded
+    pub signer: Signer,
+    /// Optional list of relayers allowed to claim the fee. Not supported
+    /// yet, hence always empty.
+    pub relayers: Vec<Signer>,
+}
+
+impl TryFrom<RawMsgPayPacketFee> for MsgPayPacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgPayPacketFee) -> Result<Self, Self::Error> {
+        let msg = MsgPayPacketFee {
+            fee: raw_msg.fee.ok_or(FeeError::MissingFee)?.try_into()?,
+            port_id_on_a: raw_msg.source_port_id.parse()?,
+            chan_id_on_a: raw_msg.source_channel_id.parse()?,
+            signer: raw_msg.signer.into(),
+            relayers: raw_msg.relayers.into_iter().map(Into::into).collect(),
+        };
+
+        if !msg.relayers.is_empty() {
+            return Err(FeeError::RelayersNotSupported);
+        }
+
+        msg.fee.validate_basic()?;
+
+        Ok(msg)
+    }
+}
+
+impl From<MsgPayPacketFee> for RawMsgPayPacketFee {
+    fn from(domain_msg: MsgPayPacketFee) -> Self {
+        RawMsgPayPacketFee {
+            fee: Some(domain_msg.fee.into()),
+            source_port_id: domain_msg.port_id_on_a.to_string(),
+            source_channel_id: domain_msg.chan_id_on_a.to_string(),
+            signer: domain_msg.signer.to_string(),
+            relayers: domain_msg
+                .relayers
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgPayPacketFee> for MsgPayPacketFee {}
+
+impl TryFrom<Any> for MsgPayPacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TYPE_URL => {
+                MsgPayPacketFee::decode_vec(&raw.value).map_err(|e| FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
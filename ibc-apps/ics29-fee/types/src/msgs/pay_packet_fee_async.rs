@@ -0,0 +1,76 @@
+//! Defines the message used to escrow a fee for a packet already sent
+
+use ibc_core::primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::fee::v1::MsgPayPacketFeeAsync as RawMsgPayPacketFeeAsync;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+use crate::{PacketFee, PacketId};
+
+pub(crate) const TYPE_URL: &str = "/ibc.applications.fee.v1.MsgPayPacketFeeAsync";
+
+/// Message used to escrow a fee for a packet that was already sent and is
+/// still in flight, e.g. to top up the fee of a packet relayers ignore.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgPayPacketFeeAsync {
+    pub packet_id: PacketId,
+    /// the fee to escrow, refunded to its refund address if unpaid
+    pub packet_fee: PacketFee,
+}
+
+impl TryFrom<RawMsgPayPacketFeeAsync> for MsgPayPacketFeeAsync {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgPayPacketFeeAsync) -> Result<Self, Self::Error> {
+        let msg = MsgPayPacketFeeAsync {
+            packet_id: raw_msg
+                .packet_id
+                .ok_or(FeeError::MissingPacketId)?
+                .try_into()?,
+            packet_fee: raw_msg.packet_fee.ok_or(FeeError::MissingFee)?.try_into()?,
+        };
+
+        msg.packet_fee.validate_basic()?;
+
+        Ok(msg)
+    }
+}
+
+impl From<MsgPayPacketFeeAsync> for RawMsgPayPacketFeeAsync {
+    fn from(domain_msg: MsgPayPacketFeeAsync) -> Self {
+        RawMsgPayPacketFeeAsync {
+            packet_id: Some(domain_msg.packet_id.into()),
+            packet_fee: Some(domain_msg.packet_fee.into()),
+        }
+    }
+}
+
+impl Protobuf<RawMsgPayPacketFeeAsync> for MsgPayPacketFeeAsync {}
+
+impl TryFrom<Any> for MsgPayPacketFeeAsync {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TYPE_URL => {
+                MsgPayPacketFeeAsync::decode_vec(&raw.value).map_err(|e| FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Defines the message used by a relayer to register the address its receive
+//! fees are paid to on the counterparty chain
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::fee::v1::MsgRegisterCounterpartyPayee as RawMsgRegisterCounterpartyPayee;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+pub(crate) const TYPE_URL: &str = "/ibc.applications.fee.v1.MsgRegisterCounterpartyPayee";
+
+/// Message used by a relayer to register, on the chain packets are received
+/// on, the counterparty chain address its receive fees are paid to.
+///
+/// The address is forwarded in the acknowledgements of the packets the
+/// relayer delivers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgRegisterCounterpartyPayee {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    /// the relayer address, which signs the message
+    pub relayer: Signer,
+    pub counterparty_payee: Signer,
+}
+
+impl TryFrom<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgRegisterCounterpartyPayee) -> Result<Self, Self::Error> {
+        if raw_msg.counterparty_payee.trim().is_empty() {
+            return Err(FeeError::EmptyPayee);
+        }
+
+        Ok(MsgRegisterCounterpartyPayee {
+            port_id: raw_msg.port_id.parse()?,
+            channel_id: raw_msg.channel_id.parse()?,
+            relayer: raw_msg.relayer.into(),
+            counterparty_payee: raw_msg.counterparty_payee.into(),
+        })
+    }
+}
+
+impl From<MsgRegisterCounterpartyPayee> for RawMsgRegisterCounterpartyPayee {
+    fn from(domain_msg: MsgRegisterCounterpartyPayee) -> Self {
+        RawMsgRegisterCounterpartyPayee {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.channel_id.to_string(),
+            relayer: domain_msg.relayer.to_string(),
+            counterparty_payee: domain_msg.counterparty_payee.to_string(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {}
+
+impl TryFrom<Any> for MsgRegisterCounterpartyPayee {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TYPE_URL => MsgRegisterCounterpartyPayee::decode_vec(&raw.value).map_err(|e| {
+                FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                }
+            }),
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Defines the identifier of a packet fees are escrowed for.
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+use ibc_proto::ibc::core::channel::v1::PacketId as RawPacketId;
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+/// Uniquely identifies a packet sent by the host chain.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PacketId {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+}
+
+impl PacketId {
+    pub fn new(port_id: PortId, channel_id: ChannelId, sequence: Sequence) -> Self {
+        Self {
+            port_id,
+            channel_id,
+            sequence,
+        }
+    }
+}
+
+impl Display for PacketId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}/{}/{}", self.port_id, self.channel_id, self.sequence)
+    }
+}
+
+impl Protobuf<RawPacketId> for PacketId {}
+
+impl TryFrom<RawPacketId> for PacketId {
+    type Error = FeeError;
+
+    fn try_from(raw_packet_id: RawPacketId) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: raw_packet_id.port_id.parse()?,
+            channel_id: raw_packet_id.channel_id.parse()?,
+            sequence: raw_packet_id.sequence.into(),
+        })
+    }
+}
+
+impl From<PacketId> for RawPacketId {
+    fn from(packet_id: PacketId) -> Self {
+        RawPacketId {
+            port_id: packet_id.port_id.to_string(),
+            channel_id: packet_id.channel_id.to_string(),
+            sequence: packet_id.sequence.value(),
+        }
+    }
+}
@@ -88,6 +88,12 @@ pub enum NftTransferError {
     UnknownMsgType { msg_type: String },
     /// decoding raw bytes as UTF8 string error: `{0}`
     Utf8Decode(Utf8Error),
+    /// mismatched event type: expected `{expected}`, actual `{actual}`
+    MismatchedEventType { expected: String, actual: String },
+    /// missing event attribute `{key}`
+    MissingEventAttribute { key: String },
+    /// invalid event attribute `{key}`: `{reason}`
+    InvalidEventAttribute { key: String, reason: String },
     /// other error: `{0}`
     Other(String),
 }
@@ -1,11 +1,15 @@
 //! Defines Non-Fungible Token Transfer (ICS-721) event types.
-use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use core::fmt::Display;
+use core::str::FromStr;
+
+use ibc_core::channel::types::acknowledgement::{AcknowledgementStatus, StatusValue};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_core::router::types::event::ModuleEvent;
 
 use super::Memo;
-use crate::{PrefixedClassId, TokenId, TokenIds, MODULE_ID_STR};
+use crate::error::NftTransferError;
+use crate::{PrefixedClassId, TokenId, TokenIds, ACK_SUCCESS_B64, MODULE_ID_STR};
 
 const EVENT_TYPE_PACKET: &str = "non_fungible_token_packet";
 const EVENT_TYPE_TIMEOUT: &str = "timeout";
@@ -58,6 +62,23 @@ impl From<RecvEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for RecvEvent {
+    type Error = NftTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_PACKET)?;
+
+        Ok(Self {
+            sender: Signer::from(event_attribute(&event, "sender")?.to_string()),
+            receiver: Signer::from(event_attribute(&event, "receiver")?.to_string()),
+            class: parse_event_attribute(&event, "class")?,
+            tokens: token_ids(&event, "tokens")?,
+            memo: parse_event_attribute(&event, "memo")?,
+            success: parse_event_attribute(&event, "success")?,
+        })
+    }
+}
+
 /// Event emitted in the `onAcknowledgePacket` module callback
 pub struct AckEvent {
     pub sender: Signer,
@@ -93,6 +114,23 @@ impl From<AckEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for AckEvent {
+    type Error = NftTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_PACKET)?;
+
+        Ok(Self {
+            sender: Signer::from(event_attribute(&event, "sender")?.to_string()),
+            receiver: Signer::from(event_attribute(&event, "receiver")?.to_string()),
+            class: parse_event_attribute(&event, "class")?,
+            tokens: token_ids(&event, "tokens")?,
+            memo: parse_event_attribute(&event, "memo")?,
+            acknowledgement: acknowledgement_status(&event)?,
+        })
+    }
+}
+
 /// Event emitted in the `onAcknowledgePacket` module callback to indicate
 /// whether the acknowledgement is a success or a failure
 pub struct AckStatusEvent {
@@ -114,6 +152,25 @@ impl From<AckStatusEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for AckStatusEvent {
+    type Error = NftTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_PACKET)?;
+
+        let acknowledgement = match (
+            event_attribute(&event, "success"),
+            event_attribute(&event, "error"),
+        ) {
+            (Ok(value), _) => AcknowledgementStatus::success(status_value("success", value)?),
+            (_, Ok(value)) => AcknowledgementStatus::error(status_value("error", value)?),
+            (Err(e), Err(_)) => return Err(e),
+        };
+
+        Ok(Self { acknowledgement })
+    }
+}
+
 /// Event emitted in the `onTimeoutPacket` module callback
 pub struct TimeoutEvent {
     pub refund_receiver: Signer,
@@ -143,6 +200,21 @@ impl From<TimeoutEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for TimeoutEvent {
+    type Error = NftTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_TIMEOUT)?;
+
+        Ok(Self {
+            refund_receiver: Signer::from(event_attribute(&event, "refund_receiver")?.to_string()),
+            refund_class: parse_event_attribute(&event, "refund_class")?,
+            refund_tokens: token_ids(&event, "refund_tokens")?,
+            memo: parse_event_attribute(&event, "memo")?,
+        })
+    }
+}
+
 /// Event emitted in the `onRecvPacket` module callback when new tokens are minted
 pub struct TokenTraceEvent {
     pub trace_hash: Option<String>,
@@ -168,6 +240,22 @@ impl From<TokenTraceEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for TokenTraceEvent {
+    type Error = NftTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_TOKEN_TRACE)?;
+
+        Ok(Self {
+            trace_hash: event_attribute(&event, "trace_hash")
+                .ok()
+                .map(ToString::to_string),
+            class: parse_event_attribute(&event, "class")?,
+            token: parse_event_attribute(&event, "token")?,
+        })
+    }
+}
+
 /// Event emitted after a successful `sendTransfer`
 pub struct TransferEvent {
     pub sender: Signer,
@@ -200,6 +288,22 @@ impl From<TransferEvent> for ModuleEvent {
     }
 }
 
+impl TryFrom<ModuleEvent> for TransferEvent {
+    type Error = NftTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        verify_event_type(&event, EVENT_TYPE_TRANSFER)?;
+
+        Ok(Self {
+            sender: Signer::from(event_attribute(&event, "sender")?.to_string()),
+            receiver: Signer::from(event_attribute(&event, "receiver")?.to_string()),
+            class: parse_event_attribute(&event, "class")?,
+            tokens: token_ids(&event, "tokens")?,
+            memo: parse_event_attribute(&event, "memo")?,
+        })
+    }
+}
+
 impl From<Event> for ModuleEvent {
     fn from(ev: Event) -> Self {
         match ev {
@@ -212,3 +316,112 @@ impl From<Event> for ModuleEvent {
         }
     }
 }
+
+/// Parses a module event back into the event it was converted from.
+///
+/// As the packet events share the same type, they are told apart by their
+/// attributes: only the `AckEvent` carries an `acknowledgement`, and only the
+/// `RecvEvent` carries a `success` flag along with the `module` attribute.
+impl TryFrom<ModuleEvent> for Event {
+    type Error = NftTransferError;
+
+    fn try_from(event: ModuleEvent) -> Result<Self, Self::Error> {
+        let has_attribute = |key: &str| event.attributes.iter().any(|attr| attr.key == key);
+
+        match event.kind.as_str() {
+            EVENT_TYPE_PACKET if has_attribute("acknowledgement") => {
+                event.try_into().map(Event::Ack)
+            }
+            EVENT_TYPE_PACKET if has_attribute("module") => event.try_into().map(Event::Recv),
+            EVENT_TYPE_PACKET => event.try_into().map(Event::AckStatus),
+            EVENT_TYPE_TIMEOUT => event.try_into().map(Event::Timeout),
+            EVENT_TYPE_TOKEN_TRACE => event.try_into().map(Event::TokenTrace),
+            EVENT_TYPE_TRANSFER => event.try_into().map(Event::Transfer),
+            _ => Err(NftTransferError::MismatchedEventType {
+                expected: [
+                    EVENT_TYPE_PACKET,
+                    EVENT_TYPE_TIMEOUT,
+                    EVENT_TYPE_TOKEN_TRACE,
+                    EVENT_TYPE_TRANSFER,
+                ]
+                .join(" | "),
+                actual: event.kind,
+            }),
+        }
+    }
+}
+
+/// Checks that a module event is of the expected type.
+fn verify_event_type(event: &ModuleEvent, expected: &str) -> Result<(), NftTransferError> {
+    if event.kind != expected {
+        return Err(NftTransferError::MismatchedEventType {
+            expected: expected.to_string(),
+            actual: event.kind.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the value of the attribute of a module event with the given key.
+fn event_attribute<'a>(event: &'a ModuleEvent, key: &str) -> Result<&'a str, NftTransferError> {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.as_str())
+        .ok_or_else(|| NftTransferError::MissingEventAttribute {
+            key: key.to_string(),
+        })
+}
+
+/// Parses the value of the attribute of a module event with the given key.
+fn parse_event_attribute<T>(event: &ModuleEvent, key: &str) -> Result<T, NftTransferError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    T::from_str(event_attribute(event, key)?).map_err(|e| NftTransferError::InvalidEventAttribute {
+        key: key.to_string(),
+        reason: e.to_string(),
+    })
+}
+
+/// Parses the comma-separated token identifiers held by the attribute of a
+/// module event with the given key.
+fn token_ids(event: &ModuleEvent, key: &str) -> Result<TokenIds, NftTransferError> {
+    let token_ids = event_attribute(event, key)?
+        .split(',')
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    TokenIds::try_from(token_ids).map_err(|e| NftTransferError::InvalidEventAttribute {
+        key: key.to_string(),
+        reason: e.to_string(),
+    })
+}
+
+/// Recovers the acknowledgement status of an `AckEvent`.
+///
+/// The event only carries the value of the status, so the acknowledgement is
+/// deemed successful whenever it is the success value written by this
+/// application, [`ACK_SUCCESS_B64`].
+fn acknowledgement_status(event: &ModuleEvent) -> Result<AcknowledgementStatus, NftTransferError> {
+    let value = event_attribute(event, "acknowledgement")?;
+    let status_value = status_value("acknowledgement", value)?;
+
+    if value == ACK_SUCCESS_B64 {
+        Ok(AcknowledgementStatus::success(status_value))
+    } else {
+        Ok(AcknowledgementStatus::error(status_value))
+    }
+}
+
+/// Builds the acknowledgement status value held by the attribute with the
+/// given key.
+fn status_value(key: &str, value: &str) -> Result<StatusValue, NftTransferError> {
+    StatusValue::new(value).map_err(|e| NftTransferError::InvalidEventAttribute {
+        key: key.to_string(),
+        reason: e.to_string(),
+    })
+}
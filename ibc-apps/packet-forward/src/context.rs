@@ -0,0 +1,98 @@
+//! Defines the main context traits of the packet forward middleware
+use core::time::Duration;
+
+use ibc_app_packet_forward_types::error::ForwardError;
+use ibc_app_packet_forward_types::{
+    InFlightPacket, DEFAULT_FORWARD_RETRIES, DEFAULT_FORWARD_TIMEOUT,
+};
+use ibc_app_transfer::context::TokenTransferExecutionContext;
+use ibc_core::channel::context::ChannelIcs4Wrapper;
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::host::ExecutionContext;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::Ics4Wrapper;
+
+/// Methods required in packet forward middleware validation, to be
+/// implemented by the host
+pub trait PacketForwardValidationContext {
+    /// Returns the relative timeout of forwarded packets whose memo does not
+    /// specify one.
+    fn forward_timeout(&self) -> Duration {
+        DEFAULT_FORWARD_TIMEOUT
+    }
+
+    /// Returns the number of times a forwarded packet that timed out is sent
+    /// again, unless its memo specifies otherwise.
+    fn forward_retries(&self) -> u8 {
+        DEFAULT_FORWARD_RETRIES
+    }
+
+    /// Returns the address holding the tokens received on the given channel
+    /// from the given sender until they are forwarded to the next hop.
+    ///
+    /// The address must not be controlled by anyone, as the tokens it holds
+    /// are moved by the middleware only.
+    fn intermediate_receiver(
+        &self,
+        channel_id: &ChannelId,
+        original_sender: &Signer,
+    ) -> Result<Signer, ForwardError>;
+
+    /// Returns the record of the forwarded packet sent with the given
+    /// sequence on the given channel, if it is still in flight.
+    fn in_flight_packet(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<Option<InFlightPacket>, ForwardError>;
+}
+
+/// Methods required in packet forward middleware execution, to be
+/// implemented by the host
+pub trait PacketForwardExecutionContext: PacketForwardValidationContext {
+    type IbcCtx: ExecutionContext;
+    type TokenCtx: TokenTransferExecutionContext;
+
+    /// Returns the contexts the tokens are forwarded through: the IBC
+    /// context sending the forwarded packets, and the token transfer context
+    /// moving the tokens.
+    fn forward_ctx_mut(&mut self) -> (&mut Self::IbcCtx, &mut Self::TokenCtx);
+
+    /// Writes the acknowledgement held for a received packet through the
+    /// [`Ics4Wrapper`] of the middlewares wrapping this one, which may wrap
+    /// the acknowledgement in turn.
+    ///
+    /// The default implementation writes it straight through the core
+    /// channel handler, which only suits stacks this middleware is the
+    /// outermost layer of: hosts wrapping it, e.g. in the fee middleware,
+    /// must write it through the layers of their stack instead.
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), ForwardError> {
+        let (ibc_ctx, _) = self.forward_ctx_mut();
+
+        ChannelIcs4Wrapper::new(ibc_ctx).write_acknowledgement(packet, acknowledgement)?;
+
+        Ok(())
+    }
+
+    fn store_in_flight_packet(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        in_flight_packet: InFlightPacket,
+    ) -> Result<(), ForwardError>;
+
+    fn delete_in_flight_packet(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), ForwardError>;
+}
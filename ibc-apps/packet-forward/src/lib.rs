@@ -0,0 +1,29 @@
+//! Implementation of the Packet Forward Middleware logic, which forwards
+//! ICS-20 token transfers through intermediate chains.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+/// Re-exports the implementation of the Packet Forward Middleware data
+/// structures.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_app_packet_forward_types::*;
+}
+
+pub mod context;
+#[cfg(feature = "serde")]
+pub mod module;
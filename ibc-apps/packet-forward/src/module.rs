@@ -0,0 +1,589 @@
+//! Implements the packet forward middleware as an IBC [`Middleware`]
+//! wrapping the ICS-20 token transfer application.
+use core::fmt::Debug;
+use core::time::Duration;
+
+use ibc_app_packet_forward_types::error::ForwardError;
+use ibc_app_packet_forward_types::{ForwardMetadata, InFlightPacket, PacketMetadata};
+use ibc_app_transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use ibc_app_transfer::handler::{decode_packet_data, encode_packet_data, send_transfer};
+use ibc_app_transfer::types::msgs::transfer::MsgTransferV2;
+use ibc_app_transfer::types::packet::{ForwardingPacketData, PacketDataV2};
+use ibc_app_transfer::types::{
+    is_receiver_chain_source, Ics20Version, Memo, PrefixedCoin, TracePrefix,
+};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::host::types::path::SeqSendPath;
+use ibc_core::host::ValidationContext;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::Middleware;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::PacketForwardExecutionContext;
+
+/// The packet forward middleware, which wraps the ICS-20 `App` module and
+/// forwards the transfers it receives to the next hop named either in their
+/// memo or, for `ics20-2` transfers, in their forwarding hops.
+///
+/// A forwarded transfer is first received by the wrapped application on
+/// behalf of an intermediate account, then sent from that account to the
+/// next hop. The acknowledgement of the received packet is held until the
+/// forwarded packet is acknowledged, in which case its acknowledgement is
+/// passed back, or times out more times than it may be retried, in which
+/// case an error acknowledgement is written. Either way the tokens are
+/// returned to the previous hop on failure.
+///
+/// Transfers without forwarding instructions are passed through to the
+/// wrapped application untouched.
+#[derive(Debug)]
+pub struct PacketForwardMiddleware<Ctx, App> {
+    ctx: Ctx,
+    app: App,
+}
+
+impl<Ctx, App> PacketForwardMiddleware<Ctx, App>
+where
+    Ctx: PacketForwardExecutionContext + Debug,
+    App: Module,
+{
+    pub fn new(ctx: Ctx, app: App) -> Self {
+        Self { ctx, app }
+    }
+
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut Ctx {
+        &mut self.ctx
+    }
+
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Receives the tokens on behalf of the intermediate account and
+    /// forwards them to the next hop, returning `None` as acknowledgement
+    /// once the forwarded packet is in flight.
+    fn forward_packet(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+        version: Ics20Version,
+        original_data: PacketDataV2,
+        next_hop: NextHop,
+    ) -> Result<(ModuleExtras, Option<Acknowledgement>), ForwardError> {
+        let intermediate_receiver = self
+            .ctx
+            .intermediate_receiver(&packet.chan_id_on_b, &original_data.sender)?;
+
+        // the wrapped application receives the tokens on behalf of the
+        // intermediate account, and does not get to see the forwarding hops
+        let intermediate_data = PacketDataV2 {
+            receiver: intermediate_receiver.clone(),
+            forwarding: ForwardingPacketData::default(),
+            ..original_data.clone()
+        };
+
+        let intermediate_packet = Packet {
+            data: encode_packet_data(version, intermediate_data)?,
+            ..packet.clone()
+        };
+
+        let (mut extras, app_acknowledgement) = self
+            .app
+            .on_recv_packet_execute(&intermediate_packet, relayer);
+
+        match &app_acknowledgement {
+            Some(ack) if is_successful(ack) => {}
+            _ => return Ok((extras, app_acknowledgement)),
+        }
+
+        let coins = received_coins(packet, &original_data);
+
+        let forward_data = PacketDataV2 {
+            tokens: coins.clone(),
+            sender: intermediate_receiver.clone(),
+            receiver: next_hop.receiver,
+            memo: next_hop.memo,
+            forwarding: next_hop.forwarding,
+        };
+
+        let timeout = next_hop
+            .timeout
+            .unwrap_or_else(|| self.ctx.forward_timeout());
+        let retries = next_hop
+            .retries
+            .unwrap_or_else(|| self.ctx.forward_retries());
+
+        let sent = self.send_forward(
+            &next_hop.port_id,
+            &next_hop.channel_id,
+            forward_data,
+            timeout,
+        );
+
+        let sequence = match sent {
+            Ok(sequence) => sequence,
+            Err(err) => {
+                self.revert_receive(packet, &original_data, &coins, &intermediate_receiver)?;
+
+                return Ok((
+                    extras,
+                    Some(AcknowledgementStatus::error(err.into()).into()),
+                ));
+            }
+        };
+
+        self.ctx.store_in_flight_packet(
+            &next_hop.port_id,
+            &next_hop.channel_id,
+            sequence,
+            InFlightPacket::new(packet.clone(), timeout, retries),
+        )?;
+
+        extras.log.push(format!(
+            "forwarded packet {} on channel {} as packet {sequence} on channel {}",
+            packet.seq_on_a, packet.chan_id_on_b, next_hop.channel_id
+        ));
+
+        Ok((extras, None))
+    }
+
+    /// Sends the forwarded tokens to the next hop, returning the sequence of
+    /// the forwarded packet.
+    fn send_forward(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        packet_data: PacketDataV2,
+        timeout: Duration,
+    ) -> Result<Sequence, ForwardError> {
+        let (ibc_ctx, token_ctx) = self.ctx.forward_ctx_mut();
+
+        let timeout_timestamp_on_b = (ibc_ctx.host_timestamp()? + timeout)
+            .map_err(|e| ForwardError::Other(e.to_string()))?;
+
+        let seq_send_path = SeqSendPath::new(port_id, channel_id);
+        let sequence = ibc_ctx.get_next_sequence_send(&seq_send_path)?;
+
+        let msg = MsgTransferV2 {
+            port_id_on_a: port_id.clone(),
+            chan_id_on_a: channel_id.clone(),
+            packet_data,
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b,
+        };
+
+        send_transfer(ibc_ctx, token_ctx, msg)?;
+
+        Ok(sequence)
+    }
+
+    /// Undoes the receipt of the original packet by the wrapped application,
+    /// so that the tokens are only credited back on the previous hop once it
+    /// processes the error acknowledgement.
+    fn revert_receive(
+        &mut self,
+        original_packet: &Packet,
+        original_data: &PacketDataV2,
+        coins: &[PrefixedCoin],
+        intermediate_receiver: &Signer,
+    ) -> Result<(), ForwardError> {
+        let (_, token_ctx) = self.ctx.forward_ctx_mut();
+
+        let account: <Ctx::TokenCtx as TokenTransferValidationContext>::AccountId =
+            intermediate_receiver
+                .clone()
+                .try_into()
+                .map_err(|_| ForwardError::ParseAccountFailure)?;
+
+        for (token, coin) in original_data.tokens.iter().zip(coins) {
+            if is_receiver_chain_source(
+                original_packet.port_id_on_a.clone(),
+                original_packet.chan_id_on_a.clone(),
+                &token.denom,
+            ) {
+                token_ctx.escrow_coins_execute(
+                    &account,
+                    &original_packet.port_id_on_b,
+                    &original_packet.chan_id_on_b,
+                    coin,
+                    &Memo::from(""),
+                )?;
+            } else {
+                token_ctx.burn_coins_execute(&account, coin, &Memo::from(""))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gives up on the forwarded packet: returns the tokens to the previous
+    /// hop and writes an error acknowledgement for the original packet.
+    fn fail_forward(
+        &mut self,
+        in_flight_packet: &InFlightPacket,
+        forward_data: &PacketDataV2,
+        err: ForwardError,
+    ) -> Result<(), ForwardError> {
+        let original_packet = &in_flight_packet.original_packet;
+        let original_data = self.received_packet_data(original_packet)?;
+
+        self.revert_receive(
+            original_packet,
+            &original_data,
+            &forward_data.tokens,
+            &forward_data.sender,
+        )?;
+
+        self.ctx.write_acknowledgement(
+            original_packet,
+            AcknowledgementStatus::error(err.into()).into(),
+        )?;
+
+        Ok(())
+    }
+
+    fn acknowledge_forward(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        in_flight_packet: InFlightPacket,
+    ) -> Result<(), ForwardError> {
+        if is_successful(acknowledgement) {
+            self.ctx.write_acknowledgement(
+                &in_flight_packet.original_packet,
+                acknowledgement.clone(),
+            )?;
+        } else {
+            let forward_data = self.sent_packet_data(packet)?;
+
+            self.fail_forward(
+                &in_flight_packet,
+                &forward_data,
+                ForwardError::Other(format!(
+                    "forwarded packet {} on channel {} failed",
+                    packet.seq_on_a, packet.chan_id_on_a
+                )),
+            )?;
+        }
+
+        self.ctx.delete_in_flight_packet(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        )
+    }
+
+    fn timeout_forward(
+        &mut self,
+        packet: &Packet,
+        in_flight_packet: InFlightPacket,
+        extras: &mut ModuleExtras,
+    ) -> Result<(), ForwardError> {
+        let forward_data = self.sent_packet_data(packet)?;
+
+        self.ctx.delete_in_flight_packet(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        )?;
+
+        if in_flight_packet.retries_remaining > 0 {
+            let retried = self.send_forward(
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                forward_data.clone(),
+                in_flight_packet.timeout(),
+            );
+
+            match retried {
+                Ok(sequence) => {
+                    extras.log.push(format!(
+                        "retrying timed out packet {} on channel {} as packet {sequence}",
+                        packet.seq_on_a, packet.chan_id_on_a
+                    ));
+
+                    return self.ctx.store_in_flight_packet(
+                        &packet.port_id_on_a,
+                        &packet.chan_id_on_a,
+                        sequence,
+                        InFlightPacket {
+                            retries_remaining: in_flight_packet.retries_remaining - 1,
+                            ..in_flight_packet
+                        },
+                    );
+                }
+                Err(err) => {
+                    return self.fail_forward(&in_flight_packet, &forward_data, err);
+                }
+            }
+        }
+
+        self.fail_forward(
+            &in_flight_packet,
+            &forward_data,
+            ForwardError::Other(format!(
+                "forwarded packet {} on channel {} timed out",
+                packet.seq_on_a, packet.chan_id_on_a
+            )),
+        )
+    }
+
+    /// Decodes the data of a packet received by this chain, according to
+    /// the version of the channel it was received on.
+    fn received_packet_data(&mut self, packet: &Packet) -> Result<PacketDataV2, ForwardError> {
+        let (_, token_ctx) = self.ctx.forward_ctx_mut();
+
+        let version = token_ctx.channel_version(&packet.port_id_on_b, &packet.chan_id_on_b)?;
+
+        Ok(decode_packet_data(version, &packet.data)?)
+    }
+
+    /// Decodes the data of a packet sent by this chain, according to the
+    /// version of the channel it was sent on.
+    fn sent_packet_data(&mut self, packet: &Packet) -> Result<PacketDataV2, ForwardError> {
+        let (_, token_ctx) = self.ctx.forward_ctx_mut();
+
+        let version = token_ctx.channel_version(&packet.port_id_on_a, &packet.chan_id_on_a)?;
+
+        Ok(decode_packet_data(version, &packet.data)?)
+    }
+}
+
+impl<Ctx, App> Middleware for PacketForwardMiddleware<Ctx, App>
+where
+    Ctx: PacketForwardExecutionContext + Debug,
+    App: Module,
+{
+    type Inner = App;
+
+    fn inner(&self) -> &App {
+        &self.app
+    }
+
+    fn inner_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (_, token_ctx) = self.ctx.forward_ctx_mut();
+
+        let Ok(version) = token_ctx.channel_version(&packet.port_id_on_b, &packet.chan_id_on_b)
+        else {
+            return self.app.on_recv_packet_execute(packet, relayer);
+        };
+
+        let Ok(data) = decode_packet_data(version, &packet.data) else {
+            return self.app.on_recv_packet_execute(packet, relayer);
+        };
+
+        let next_hop = if data.forwarding.is_forwarded() {
+            NextHop::from_forwarding(&data)
+        } else {
+            match PacketMetadata::from_memo(&data.memo) {
+                Ok(Some(metadata)) => NextHop::from_metadata(metadata.forward),
+                Ok(None) => return self.app.on_recv_packet_execute(packet, relayer),
+                Err(err) => Err(err),
+            }
+        };
+
+        let forwarded = next_hop
+            .and_then(|next_hop| self.forward_packet(packet, relayer, version, data, next_hop));
+
+        match forwarded {
+            Ok(result) => result,
+            Err(err) => (
+                ModuleExtras::empty(),
+                Some(AcknowledgementStatus::error(err.into()).into()),
+            ),
+        }
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let in_flight_packet = match self.ctx.in_flight_packet(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        ) {
+            Ok(Some(in_flight_packet)) => in_flight_packet,
+            Ok(None) => {
+                return self
+                    .app
+                    .on_acknowledgement_packet_execute(packet, acknowledgement, relayer)
+            }
+            Err(err) => return (ModuleExtras::empty(), Err(err.into())),
+        };
+
+        // the wrapped application refunds the intermediate account if the
+        // forwarded packet failed
+        let (extras, result) =
+            self.app
+                .on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+
+        if result.is_err() {
+            return (extras, result);
+        }
+
+        let result = self
+            .acknowledge_forward(packet, acknowledgement, in_flight_packet)
+            .map_err(PacketError::from);
+
+        (extras, result)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let in_flight_packet = match self.ctx.in_flight_packet(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        ) {
+            Ok(Some(in_flight_packet)) => in_flight_packet,
+            Ok(None) => return self.app.on_timeout_packet_execute(packet, relayer),
+            Err(err) => return (ModuleExtras::empty(), Err(err.into())),
+        };
+
+        // the wrapped application refunds the intermediate account, which
+        // then either sends the tokens again or returns them
+        let (mut extras, result) = self.app.on_timeout_packet_execute(packet, relayer);
+
+        if result.is_err() {
+            return (extras, result);
+        }
+
+        let result = self
+            .timeout_forward(packet, in_flight_packet, &mut extras)
+            .map_err(PacketError::from);
+
+        (extras, result)
+    }
+}
+
+/// The hop a received transfer is forwarded to, along with the fields of
+/// the forwarded transfer that tell the next hop where to go from there.
+struct NextHop {
+    port_id: PortId,
+    channel_id: ChannelId,
+    receiver: Signer,
+    timeout: Option<Duration>,
+    retries: Option<u8>,
+    memo: Memo,
+    forwarding: ForwardingPacketData,
+}
+
+impl NextHop {
+    /// Takes the next hop out of the forwarding instructions of a memo.
+    fn from_metadata(forward: ForwardMetadata) -> Result<Self, ForwardError> {
+        let memo = match forward.next {
+            Some(next) => next.to_memo()?,
+            None => Memo::from(""),
+        };
+
+        Ok(Self {
+            port_id: forward.port_id,
+            channel_id: forward.channel_id,
+            receiver: forward.receiver,
+            timeout: forward.timeout,
+            retries: forward.retries,
+            memo,
+            forwarding: ForwardingPacketData::default(),
+        })
+    }
+
+    /// Takes the next hop out of the forwarding hops of an `ics20-2`
+    /// transfer, whose receiver is the final one. The destination memo is
+    /// set as the memo of the transfer to the last hop.
+    fn from_forwarding(data: &PacketDataV2) -> Result<Self, ForwardError> {
+        let mut hops = data.forwarding.hops.iter().cloned();
+
+        let Some(hop) = hops.next() else {
+            return Err(ForwardError::InvalidMetadata {
+                description: "no forwarding hops".to_string(),
+            });
+        };
+
+        let remaining_hops: Vec<TracePrefix> = hops.collect();
+        let destination_memo = data.forwarding.destination_memo.clone();
+
+        let (memo, forwarding) = if remaining_hops.is_empty() {
+            (destination_memo, ForwardingPacketData::default())
+        } else {
+            (
+                Memo::from(""),
+                ForwardingPacketData {
+                    hops: remaining_hops,
+                    destination_memo,
+                },
+            )
+        };
+
+        Ok(Self {
+            port_id: hop.port_id().clone(),
+            channel_id: hop.channel_id().clone(),
+            receiver: data.receiver.clone(),
+            timeout: None,
+            retries: None,
+            memo,
+            forwarding,
+        })
+    }
+}
+
+/// Returns the coins credited on this chain upon receiving the given packet,
+/// denominated the way this chain traces them.
+fn received_coins(packet: &Packet, data: &PacketDataV2) -> Vec<PrefixedCoin> {
+    data.tokens
+        .iter()
+        .map(|token| {
+            let mut coin = token.clone();
+
+            if is_receiver_chain_source(
+                packet.port_id_on_a.clone(),
+                packet.chan_id_on_a.clone(),
+                &coin.denom,
+            ) {
+                let prefix =
+                    TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
+                coin.denom.remove_trace_prefix(&prefix);
+            } else {
+                let prefix =
+                    TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
+                coin.denom.add_trace_prefix(prefix);
+            }
+
+            coin
+        })
+        .collect()
+}
+
+/// Returns `false` for error acknowledgements only, as acknowledgements
+/// that are not ICS-20 ones are not written by a failed transfer.
+fn is_successful(acknowledgement: &Acknowledgement) -> bool {
+    serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_bytes())
+        .map(|ack| ack.is_successful())
+        .unwrap_or(true)
+}
@@ -0,0 +1,69 @@
+//! Defines the packet forward middleware error type
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum ForwardError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// token transfer error: `{0}`
+    TokenTransfer(TokenTransferError),
+    /// invalid forward metadata: `{description}`
+    InvalidMetadata { description: String },
+    /// forward receiver cannot be empty
+    EmptyReceiver,
+    /// failed to serialize the memo of the forwarded packet
+    MemoSerialization,
+    /// packet `{sequence}` on port `{port_id}` and channel `{channel_id}` is not in flight
+    PacketNotInFlight {
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+    },
+    /// failed to parse as AccountId
+    ParseAccountFailure,
+    /// other error: `{0}`
+    Other(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ForwardError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::TokenTransfer(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for ForwardError {
+    fn from(err: ContextError) -> ForwardError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<TokenTransferError> for ForwardError {
+    fn from(err: TokenTransferError) -> ForwardError {
+        Self::TokenTransfer(err)
+    }
+}
+
+impl From<ForwardError> for PacketError {
+    fn from(err: ForwardError) -> PacketError {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<ForwardError> for StatusValue {
+    fn from(err: ForwardError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
@@ -0,0 +1,50 @@
+//! Defines the record kept for every packet forwarded to the next hop.
+use core::time::Duration;
+
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
+
+/// Tracks a packet forwarded to the next hop until it is acknowledged or
+/// times out, at which point the acknowledgement of the packet it was
+/// forwarded on behalf of is written.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InFlightPacket {
+    /// The packet received from the previous hop, whose acknowledgement is
+    /// held until the forwarded packet completes.
+    pub original_packet: Packet,
+    /// The relative timeout of the forwarded packet, in nanoseconds, reused
+    /// when the packet is sent again after timing out.
+    pub timeout_nanos: u64,
+    /// The number of times the forwarded packet may still be sent again
+    /// after timing out.
+    pub retries_remaining: u8,
+}
+
+impl InFlightPacket {
+    pub fn new(original_packet: Packet, timeout: Duration, retries_remaining: u8) -> Self {
+        Self {
+            original_packet,
+            timeout_nanos: u64::try_from(timeout.as_nanos()).unwrap_or(u64::MAX),
+            retries_remaining,
+        }
+    }
+
+    /// Returns the relative timeout of the forwarded packet.
+    pub fn timeout(&self) -> Duration {
+        Duration::from_nanos(self.timeout_nanos)
+    }
+}
@@ -0,0 +1,37 @@
+//! Implementation of the Packet Forward Middleware data structures, which
+//! allow ICS-20 token transfers to be forwarded through intermediate chains.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod in_flight;
+mod metadata;
+
+pub use in_flight::*;
+pub use metadata::*;
+pub mod error;
+
+use core::time::Duration;
+
+/// Module identifier for the packet forward middleware.
+pub const MODULE_ID_STR: &str = "packetforward";
+
+/// The relative timeout of forwarded packets whose memo does not specify
+/// one.
+pub const DEFAULT_FORWARD_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// The number of times a forwarded packet that timed out is sent again,
+/// unless its memo specifies otherwise.
+pub const DEFAULT_FORWARD_RETRIES: u8 = 1;
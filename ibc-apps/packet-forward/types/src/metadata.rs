@@ -0,0 +1,207 @@
+//! Defines the forwarding instructions carried in the memo of ICS-20
+//! transfers.
+use core::time::Duration;
+
+#[cfg(feature = "serde")]
+use ibc_app_transfer_types::Memo;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+#[cfg(feature = "serde")]
+use crate::error::ForwardError;
+
+/// The forwarding instructions carried in the memo of an ICS-20 transfer.
+///
+/// The memo is JSON-encoded the way the ibc-go packet forward middleware
+/// expects it, e.g.
+/// `{"forward":{"receiver":"cosmos1...","port":"transfer","channel":"channel-1"}}`,
+/// with the optional `timeout` given in nanoseconds.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "JsonPacketMetadata", into = "JsonPacketMetadata")
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketMetadata {
+    pub forward: ForwardMetadata,
+}
+
+/// Describes the next hop a received transfer is forwarded to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardMetadata {
+    /// The receiver of the tokens on the next hop.
+    pub receiver: Signer,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    /// The relative timeout of the forwarded packet. The host's default
+    /// applies if none is given.
+    pub timeout: Option<Duration>,
+    /// The number of times the forwarded packet is sent again if it times
+    /// out. The host's default applies if none is given.
+    pub retries: Option<u8>,
+    /// The forwarding instructions passed on to the next hop, if the tokens
+    /// are to be forwarded further.
+    pub next: Option<Box<PacketMetadata>>,
+}
+
+#[cfg(feature = "serde")]
+impl PacketMetadata {
+    /// Parses the forwarding instructions out of the memo of an ICS-20
+    /// transfer.
+    ///
+    /// Returns `None` if the memo is not a JSON object or carries no
+    /// `forward` key, i.e. if the transfer is not meant to be forwarded.
+    pub fn from_memo(memo: &Memo) -> Result<Option<Self>, ForwardError> {
+        let Ok(memo_keys) = serde_json::from_str::<MemoKeys>(memo.as_ref()) else {
+            return Ok(None);
+        };
+
+        if memo_keys.forward.is_none() {
+            return Ok(None);
+        }
+
+        serde_json::from_str::<Self>(memo.as_ref())
+            .map(Some)
+            .map_err(|e| ForwardError::InvalidMetadata {
+                description: e.to_string(),
+            })
+    }
+
+    /// Encodes the forwarding instructions as the memo of an ICS-20
+    /// transfer.
+    pub fn to_memo(&self) -> Result<Memo, ForwardError> {
+        serde_json::to_string(self)
+            .map(Memo::from)
+            .map_err(|_| ForwardError::MemoSerialization)
+    }
+}
+
+/// The keys of a JSON memo looked at to tell whether a transfer is meant to
+/// be forwarded.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MemoKeys {
+    #[serde(default)]
+    forward: Option<serde::de::IgnoredAny>,
+}
+
+/// The JSON encoding of a [`PacketMetadata`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JsonPacketMetadata {
+    forward: JsonForwardMetadata,
+}
+
+/// The JSON encoding of a [`ForwardMetadata`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JsonForwardMetadata {
+    receiver: String,
+    port: String,
+    channel: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timeout: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    retries: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    next: Option<Box<JsonPacketMetadata>>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<JsonPacketMetadata> for PacketMetadata {
+    type Error = ForwardError;
+
+    fn try_from(json_metadata: JsonPacketMetadata) -> Result<Self, Self::Error> {
+        let forward = json_metadata.forward;
+
+        if forward.receiver.trim().is_empty() {
+            return Err(ForwardError::EmptyReceiver);
+        }
+
+        let invalid_metadata =
+            |e: ibc_core::host::types::error::IdentifierError| ForwardError::InvalidMetadata {
+                description: e.to_string(),
+            };
+
+        let next = forward
+            .next
+            .map(|next| PacketMetadata::try_from(*next).map(Box::new))
+            .transpose()?;
+
+        Ok(Self {
+            forward: ForwardMetadata {
+                receiver: forward.receiver.into(),
+                port_id: forward.port.parse().map_err(invalid_metadata)?,
+                channel_id: forward.channel.parse().map_err(invalid_metadata)?,
+                timeout: forward.timeout.map(Duration::from_nanos),
+                retries: forward.retries,
+                next,
+            },
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<PacketMetadata> for JsonPacketMetadata {
+    fn from(metadata: PacketMetadata) -> Self {
+        let forward = metadata.forward;
+
+        Self {
+            forward: JsonForwardMetadata {
+                receiver: forward.receiver.to_string(),
+                port: forward.port_id.to_string(),
+                channel: forward.channel_id.to_string(),
+                timeout: forward
+                    .timeout
+                    .map(|timeout| u64::try_from(timeout.as_nanos()).unwrap_or(u64::MAX)),
+                retries: forward.retries,
+                next: forward
+                    .next
+                    .map(|next| Box::new(JsonPacketMetadata::from(*next))),
+            },
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memo_without_forward_key() {
+        for memo in ["", "some memo", "{}", r#"{"wasm":{"contract":"cosmos1"}}"#] {
+            assert_eq!(PacketMetadata::from_memo(&memo.into()).unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn memo_with_invalid_forward_key() {
+        for memo in [
+            r#"{"forward":{"receiver":"","port":"transfer","channel":"channel-1"}}"#,
+            r#"{"forward":{"receiver":"cosmos1","port":"transfer","channel":"chan"}}"#,
+            r#"{"forward":{"receiver":"cosmos1","port":"transfer"}}"#,
+        ] {
+            assert!(PacketMetadata::from_memo(&memo.into()).is_err());
+        }
+    }
+
+    #[test]
+    fn nested_memo_roundtrip() {
+        let memo: Memo = r#"{"forward":{"receiver":"cosmos1a","port":"transfer","channel":"channel-1","timeout":60000000000,"retries":2,"next":{"forward":{"receiver":"cosmos1b","port":"transfer","channel":"channel-7"}}}}"#.into();
+
+        let metadata = PacketMetadata::from_memo(&memo).unwrap().unwrap();
+
+        assert_eq!(metadata.forward.receiver, "cosmos1a".to_string().into());
+        assert_eq!(metadata.forward.channel_id, ChannelId::new(1));
+        assert_eq!(metadata.forward.timeout, Some(Duration::from_secs(60)));
+        assert_eq!(metadata.forward.retries, Some(2));
+
+        let next = metadata.forward.next.clone().unwrap();
+        assert_eq!(next.forward.channel_id, ChannelId::new(7));
+        assert_eq!(next.forward.timeout, None);
+        assert_eq!(next.forward.next, None);
+
+        assert_eq!(metadata.to_memo().unwrap(), memo);
+    }
+}
@@ -19,6 +19,29 @@ pub mod transfer {
     pub use ibc_app_transfer::*;
 }
 
+/// Re-exports the implementation of the IBC [relayer
+/// incentivisation](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md)
+/// (ICS-29) middleware logic.
+pub mod fee {
+    #[doc(inline)]
+    pub use ibc_app_fee::*;
+}
+
+/// Re-exports the implementation of the IBC [Interchain
+/// Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+/// (ICS-27) controller and host logic.
+pub mod interchain_accounts {
+    #[doc(inline)]
+    pub use ibc_app_interchain_accounts::*;
+}
+
+/// Re-exports the implementation of the packet forward middleware, which
+/// forwards ICS-20 token transfers through intermediate chains.
+pub mod packet_forward {
+    #[doc(inline)]
+    pub use ibc_app_packet_forward::*;
+}
+
 /// Re-exports the implementation of the IBC [Non-Fungible Token
 /// Transfer](https://github.com/cosmos/ibc/blob/main/spec/app/ics-721-nft-transfer/README.md)
 /// (ICS-721) application logic.
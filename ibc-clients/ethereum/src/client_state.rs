@@ -0,0 +1,245 @@
+//! This module includes trait implementations for the
+//! `ibc_client_ethereum_types::ClientState` type. Implemented traits include
+//! `ClientStateCommon`, `ClientStateValidation`, and `ClientStateExecution`.
+//!
+//! Note that this crate defines a newtype wrapper around the
+//! `ibc_client_ethereum_types::ClientState` type in order to enable
+//! implementing a foreign trait on a foreign type (i.e. the orphan rule in
+//! Rust). As such, this module also includes some trait implementations that
+//! serve to pass through traits implemented on the wrapped `ClientState` type.
+//!
+//! Unlike the other clients, the wrapper is generic over the
+//! [`EthereumHostFunctions`] it verifies headers and proofs with, as there is
+//! no default implementation of the BLS signature verification they rely on.
+
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+use ibc_client_ethereum_types::error::Error;
+use ibc_client_ethereum_types::raw::ClientState as RawEthClientState;
+use ibc_client_ethereum_types::{ClientState as ClientStateType, EthereumHostFunctions};
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+/// Newtype wrapper around the `ClientState` type imported from the
+/// `ibc-client-ethereum-types` crate. This wrapper exists so that we can
+/// bypass Rust's orphan rules and implement traits from
+/// `ibc::core::client::context` on the `ClientState` type.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
+pub struct ClientState<H: EthereumHostFunctions>(
+    ClientStateType,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<fn() -> H>,
+);
+
+impl<H: EthereumHostFunctions> ClientState<H> {
+    pub fn inner(&self) -> &ClientStateType {
+        &self.0
+    }
+}
+
+impl<H: EthereumHostFunctions> Clone for ClientState<H> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<H: EthereumHostFunctions> Debug for ClientState<H> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ClientState").field(&self.0).finish()
+    }
+}
+
+impl<H: EthereumHostFunctions> PartialEq for ClientState<H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<H: EthereumHostFunctions> From<ClientStateType> for ClientState<H> {
+    fn from(client_state: ClientStateType) -> Self {
+        Self(client_state, PhantomData)
+    }
+}
+
+impl<H: EthereumHostFunctions> Protobuf<RawEthClientState> for ClientState<H> {}
+
+impl<H: EthereumHostFunctions> TryFrom<RawEthClientState> for ClientState<H> {
+    type Error = Error;
+
+    fn try_from(raw: RawEthClientState) -> Result<Self, Self::Error> {
+        Ok(ClientStateType::try_from(raw)?.into())
+    }
+}
+
+impl<H: EthereumHostFunctions> From<ClientState<H>> for RawEthClientState {
+    fn from(client_state: ClientState<H>) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl<H: EthereumHostFunctions> Protobuf<Any> for ClientState<H> {}
+
+impl<H: EthereumHostFunctions> TryFrom<Any> for ClientState<H> {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(ClientStateType::try_from(raw)?.into())
+    }
+}
+
+impl<H: EthereumHostFunctions> From<ClientState<H>> for Any {
+    fn from(client_state: ClientState<H>) -> Self {
+        client_state.0.into()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use core::time::Duration;
+
+    use ibc_client_ethereum_types::{BlsPublicKey, BlsSignature, Fork, ForkParameters};
+    use ibc_core_commitment_types::proto::ics23::{HostFunctionsManager, HostFunctionsProvider};
+
+    use super::*;
+
+    pub const TRUSTED_SLOT: u64 = 72;
+
+    pub const STORAGE_PROOF: &str = include_str!("../tests/data/storage_proof.hex");
+
+    /// Domain separation tag of the BLS signatures of the beacon chain.
+    const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    /// Host functions backed by `blst` for the BLS aggregate signatures of
+    /// the sync committees, as on the beacon chain.
+    pub struct TestHostFunctions;
+
+    impl EthereumHostFunctions for TestHostFunctions {
+        fn sha256(message: &[u8]) -> [u8; 32] {
+            HostFunctionsManager::sha2_256(message)
+        }
+
+        fn keccak256(message: &[u8]) -> [u8; 32] {
+            HostFunctionsManager::keccak_256(message)
+        }
+
+        fn bls_fast_aggregate_verify(
+            public_keys: &[&BlsPublicKey],
+            message: &[u8; 32],
+            signature: &BlsSignature,
+        ) -> bool {
+            let Ok(signature) = blst::min_pk::Signature::from_bytes(signature.as_bytes()) else {
+                return false;
+            };
+            let Ok(public_keys) = public_keys
+                .iter()
+                .map(|public_key| blst::min_pk::PublicKey::from_bytes(public_key.as_bytes()))
+                .collect::<Result<Vec<_>, _>>()
+            else {
+                return false;
+            };
+            let public_keys: Vec<_> = public_keys.iter().collect();
+
+            signature.fast_aggregate_verify(true, message, BLS_DST, &public_keys)
+                == blst::BLST_ERROR::BLST_SUCCESS
+        }
+    }
+
+    pub fn decode_hex(hex: &str) -> Vec<u8> {
+        let hex = hex.trim();
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn decode_root(hex: &str) -> [u8; 32] {
+        decode_hex(hex).try_into().unwrap()
+    }
+
+    /// Returns the client state the fixtures were generated for, i.e. a
+    /// chain with the minimal preset at the Deneb fork from genesis.
+    pub fn client_state() -> ClientState<TestHostFunctions> {
+        let fork = |version: u8, epoch: u64| Fork {
+            version: [version, 0, 0, 1],
+            epoch,
+        };
+
+        ClientStateType {
+            chain_id: 1,
+            genesis_validators_root: decode_root(
+                "44797095dc339c311d90c9679b8bf4b60048a576e5a71d70e5877d82475e5d5f",
+            ),
+            genesis_time: 1_700_000_000,
+            fork_parameters: ForkParameters {
+                genesis_fork_version: [0, 0, 0, 1],
+                altair: fork(1, 0),
+                bellatrix: fork(2, 0),
+                capella: fork(3, 0),
+                deneb: fork(4, 0),
+                electra: fork(5, u64::MAX),
+            },
+            seconds_per_slot: 6,
+            slots_per_epoch: 8,
+            epochs_per_sync_committee_period: 8,
+            min_sync_committee_participants: 16,
+            trusting_period: Duration::from_secs(24 * 60 * 60),
+            latest_slot: TRUSTED_SLOT,
+            frozen_height: None,
+            ibc_contract_address: decode_hex("3b283d22b75aff56db33aed0f04a19fb16decb54")
+                .try_into()
+                .unwrap(),
+            ibc_commitment_slot: {
+                let mut slot = [0u8; 32];
+                slot[31] = 1;
+                slot
+            },
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_client::types::Height;
+
+    use super::fixtures::*;
+    use super::*;
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = client_state();
+
+        let any = Any::from(client_state.clone());
+
+        assert_eq!(
+            ClientState::<TestHostFunctions>::try_from(any).unwrap(),
+            client_state
+        );
+    }
+
+    #[test]
+    fn client_state_verify_height() {
+        let client_state = client_state();
+        let latest_height = client_state.inner().latest_height();
+
+        assert!(validate_proof_height(client_state.inner(), latest_height).is_ok());
+        assert!(
+            validate_proof_height(client_state.inner(), latest_height.decrement().unwrap()).is_ok()
+        );
+        assert!(validate_proof_height(client_state.inner(), latest_height.increment()).is_err());
+        assert!(validate_proof_height(client_state.inner(), Height::new(1, 1).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+use ibc_client_ethereum_types::error::Error;
+use ibc_client_ethereum_types::{
+    client_type as eth_client_type, commitment_slot, ClientState as ClientStateType,
+    ConsensusState as ConsensusStateType, EthereumHostFunctions, StorageProof,
+};
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::identifiers::ClientType;
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+
+impl<H: EthereumHostFunctions> ClientStateCommon for ClientState<H> {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        verify_consensus_state(consensus_state)
+    }
+
+    fn client_type(&self) -> ClientType {
+        eth_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.0.latest_height()
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        validate_proof_height(self.inner(), proof_height)
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        verify_membership::<H>(self.inner(), prefix, proof, root, path, value)
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        verify_non_membership::<H>(self.inner(), prefix, proof, root, path)
+    }
+}
+
+/// Verify an `Any` consensus state by attempting to convert it to an
+/// Ethereum `ConsensusState`, which checks the lengths of its roots.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_consensus_state(consensus_state: Any) -> Result<(), ClientError> {
+    ConsensusStateType::try_from(consensus_state)?;
+
+    Ok(())
+}
+
+/// Validate the given proof height against the client state's latest height,
+/// returning an error if the proof height is greater than the latest height
+/// of the client state.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn validate_proof_height(
+    client_state: &ClientStateType,
+    proof_height: Height,
+) -> Result<(), ClientError> {
+    let latest_height = client_state.latest_height();
+
+    if latest_height < proof_height {
+        return Err(ClientError::InvalidProofHeight {
+            latest_height,
+            proof_height,
+        });
+    }
+
+    Ok(())
+}
+
+/// Verify that the IBC contract committed to the given value at the given
+/// path, i.e. that the storage proof holds the digest of the value in the
+/// commitment slot of the path, against the storage root of the contract.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_membership<H: EthereumHostFunctions>(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    let (storage_proof, storage_root, slot) =
+        decode_storage_proof::<H>(client_state, prefix, proof, root, path)?;
+
+    storage_proof
+        .verify_membership::<H>(&storage_root, &slot, &value)
+        .map_err(Into::into)
+}
+
+/// Verify that the IBC contract holds no commitment at the given path.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_non_membership<H: EthereumHostFunctions>(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+) -> Result<(), ClientError> {
+    let (storage_proof, storage_root, slot) =
+        decode_storage_proof::<H>(client_state, prefix, proof, root, path)?;
+
+    storage_proof
+        .verify_non_membership::<H>(&storage_root, &slot)
+        .map_err(Into::into)
+}
+
+/// Decodes the storage proof, along with the storage root it is verified
+/// against and the commitment slot of the path.
+fn decode_storage_proof<H: EthereumHostFunctions>(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+) -> Result<(StorageProof, [u8; 32], [u8; 32]), ClientError> {
+    let storage_proof = StorageProof::try_from(proof)?;
+
+    let storage_root = root
+        .as_bytes()
+        .try_into()
+        .map_err(|_| Error::InvalidLength {
+            name: "storage root".to_string(),
+            expected: 32,
+            actual: root.as_bytes().len(),
+        })?;
+
+    let slot = commitment_slot::<H>(
+        prefix,
+        path.to_string().as_bytes(),
+        &client_state.ibc_commitment_slot,
+    );
+
+    Ok((storage_proof, storage_root, slot))
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_host::types::identifiers::{ChannelId, PortId, Sequence};
+    use ibc_core_host::types::path::CommitmentPath;
+
+    use super::*;
+    use crate::client_state::fixtures::*;
+
+    fn commitment_path(sequence: u64) -> Path {
+        Path::Commitment(CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::new(0),
+            Sequence::from(sequence),
+        ))
+    }
+
+    #[test]
+    fn test_verify_membership() {
+        let client_state = client_state();
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let proof = CommitmentProofBytes::try_from(decode_hex(STORAGE_PROOF)).unwrap();
+        let root = CommitmentRoot::from_bytes(&decode_hex(
+            "afb523b293660f451fcccea3a882d6fe74849bbb738dada35e8a90625d8c4c92",
+        ));
+        let value = decode_hex("24527998d56331b30bb8ee4cae94fe0c5489144d5ecfbe5070193bcf89855d06");
+
+        client_state
+            .verify_membership(&prefix, &proof, &root, commitment_path(1), value.clone())
+            .unwrap();
+        client_state
+            .verify_non_membership(&prefix, &proof, &root, commitment_path(2))
+            .unwrap();
+
+        assert!(client_state
+            .verify_membership(&prefix, &proof, &root, commitment_path(2), value.clone())
+            .is_err());
+        assert!(client_state
+            .verify_non_membership(&prefix, &proof, &root, commitment_path(1))
+            .is_err());
+
+        let other_root = CommitmentRoot::from_bytes(&[2u8; 32]);
+        assert!(client_state
+            .verify_membership(&prefix, &proof, &other_root, commitment_path(1), value)
+            .is_err());
+    }
+}
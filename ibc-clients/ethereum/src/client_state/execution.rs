@@ -0,0 +1,286 @@
+use ibc_client_ethereum_types::error::Error;
+use ibc_client_ethereum_types::{
+    updated_consensus_state, ClientState as ClientStateType, ConsensusState as ConsensusStateType,
+    EthereumHostFunctions, Header as EthHeader,
+};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+
+impl<E, H> ClientStateExecution<E> for ClientState<H>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+    H: EthereumHostFunctions,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        initialise(self.inner(), ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        update_state::<E, H>(self.inner(), ctx, client_id, header)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        update_on_misbehaviour(self.inner(), ctx, client_id, client_message)
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        update_on_recovery(
+            self.inner().clone(),
+            ctx,
+            subject_client_id,
+            substitute_client_state,
+            substitute_consensus_state,
+        )
+    }
+}
+
+/// Seed the host store with initial client and consensus states.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn initialise<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+{
+    let eth_consensus_state: ConsensusStateType = consensus_state.try_into()?;
+
+    if eth_consensus_state.slot != client_state.latest_slot {
+        return Err(ClientError::Other {
+            description: format!(
+                "consensus state at slot {} does not match the latest slot {} of the client",
+                eth_consensus_state.slot, client_state.latest_slot
+            ),
+        });
+    }
+
+    store_client_and_consensus_states(ctx, client_id, client_state.clone(), eth_consensus_state)
+}
+
+/// Update the host store with the consensus state at the finalized slot of a
+/// verified header, moving the latest slot of the client state forward if
+/// need be.
+///
+/// Headers finalizing a slot the client already has a consensus state for
+/// are a no-op, as their consensus state was checked to be the same one by
+/// `check_for_misbehaviour`.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn update_state<E, H>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+    H: EthereumHostFunctions,
+{
+    let header = EthHeader::try_from(header)?;
+    let header_height = header.height();
+
+    let maybe_existing_consensus_state = ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            header_height.revision_number(),
+            header_height.revision_height(),
+        ))
+        .ok();
+
+    if maybe_existing_consensus_state.is_none() {
+        let trusted_consensus_state: ConsensusStateType = ctx
+            .consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                header.trusted_height.revision_number(),
+                header.trusted_height.revision_height(),
+            ))?
+            .try_into()
+            .map_err(Into::into)?;
+
+        let new_consensus_state =
+            updated_consensus_state::<H>(client_state, &trusted_consensus_state, &header)?;
+        let new_client_state = client_state.clone().with_latest_slot(header.slot());
+
+        let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
+        let host_height = ExtClientValidationContext::host_height(ctx)?;
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                header_height.revision_number(),
+                header_height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            header_height,
+            host_timestamp,
+            host_height,
+        )?;
+    }
+
+    Ok(vec![header_height])
+}
+
+/// Commit a frozen client state, which was frozen as a result of having exhibited
+/// misbehaviour, to the store.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn update_on_misbehaviour<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    _client_message: Any,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+{
+    // As for Tendermint clients, the frozen height is the same for all
+    // misbehaviour.
+    let frozen_client_state = client_state.clone().with_frozen_height(Height::min(0));
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        frozen_client_state.into(),
+    )?;
+
+    Ok(())
+}
+
+/// Update the subject client state with the latest slot and trusting period
+/// of the substitute client state, unfreezing it, and store the substitute
+/// consensus state at the new latest slot in response to a successful client
+/// recovery.
+///
+/// This function is typically implemented as part of the [`ClientStateExecution`]
+/// trait, but has been made standalone in order to enable greater flexibility
+/// of the ClientState APIs.
+pub fn update_on_recovery<E>(
+    subject_client_state: ClientStateType,
+    ctx: &mut E,
+    subject_client_id: &ClientId,
+    substitute_client_state: Any,
+    substitute_consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+{
+    let substitute_client_state = ClientStateType::try_from(substitute_client_state)?;
+
+    let new_client_state = ClientStateType {
+        latest_slot: substitute_client_state.latest_slot,
+        trusting_period: substitute_client_state.trusting_period,
+        min_sync_committee_participants: substitute_client_state.min_sync_committee_participants,
+        frozen_height: None,
+        ..subject_client_state
+    };
+
+    let eth_consensus_state: ConsensusStateType = substitute_consensus_state.try_into()?;
+
+    store_client_and_consensus_states(
+        ctx,
+        subject_client_id,
+        new_client_state,
+        eth_consensus_state,
+    )
+}
+
+/// Stores the client state, the consensus state at its latest height, and
+/// the update metadata of that height.
+fn store_client_and_consensus_states<E>(
+    ctx: &mut E,
+    client_id: &ClientId,
+    client_state: ClientStateType,
+    consensus_state: ConsensusStateType,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+{
+    let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
+    let host_height = ExtClientValidationContext::host_height(ctx)?;
+
+    let latest_height = client_state.latest_height();
+
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ),
+        consensus_state.into(),
+    )?;
+    ctx.store_client_state(ClientStatePath::new(client_id.clone()), client_state.into())?;
+    ctx.store_update_meta(
+        client_id.clone(),
+        latest_height,
+        host_timestamp,
+        host_height,
+    )?;
+
+    Ok(())
+}
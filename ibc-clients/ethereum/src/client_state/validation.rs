@@ -0,0 +1,281 @@
+use ibc_client_ethereum_types::{
+    verify_header, ClientState as ClientStateType, ConsensusState as ConsensusStateType,
+    EthereumHostFunctions, Header as EthHeader, Misbehaviour as EthMisbehaviour,
+    ETHEREUM_HEADER_TYPE_URL, ETHEREUM_MISBEHAVIOUR_TYPE_URL,
+};
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::context::{Convertible, ExtClientValidationContext};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_handler_types::metering::CostEvent;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+
+impl<V, H> ClientStateValidation<V> for ClientState<H>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    H: EthereumHostFunctions,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message::<V, H>(self.inner(), ctx, client_id, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(ctx, client_id, client_message)
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        status(self.inner(), ctx, client_id)
+    }
+
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        check_substitute(self.inner(), substitute_client_state)
+    }
+}
+
+/// Verify the client message as part of the client state validation process.
+///
+/// A header must be verified against the consensus state at its trusted
+/// height, at the current host time. Both headers of a misbehaviour must be
+/// verified against their respective trusted consensus states.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_client_message<V, H>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<(), ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    H: EthereumHostFunctions,
+{
+    match client_message.type_url.as_str() {
+        ETHEREUM_HEADER_TYPE_URL => {
+            let header = EthHeader::try_from(client_message)?;
+
+            verify_trusted_header::<V, H>(client_state, ctx, client_id, &header)
+                .map(|_| ())
+                .map_err(|e| ClientError::HeaderVerificationFailure {
+                    reason: e.to_string(),
+                })
+        }
+        ETHEREUM_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = EthMisbehaviour::try_from(client_message)?;
+
+            for header in [&misbehaviour.header_1, &misbehaviour.header_2] {
+                verify_trusted_header::<V, H>(client_state, ctx, client_id, header).map_err(
+                    |e| ClientError::MisbehaviourHandlingFailure {
+                        reason: e.to_string(),
+                    },
+                )?;
+            }
+
+            Ok(())
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Verifies the header against the consensus state at its trusted height,
+/// returning the consensus state it updates the client to.
+pub(crate) fn verify_trusted_header<V, H>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    header: &EthHeader,
+) -> Result<ConsensusStateType, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    H: EthereumHostFunctions,
+{
+    let trusted_consensus_state: ConsensusStateType = ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            header.trusted_height.revision_number(),
+            header.trusted_height.revision_height(),
+        ))?
+        .try_into()
+        .map_err(Into::into)?;
+
+    // The sync committee signs with a single aggregate signature.
+    ctx.charge(CostEvent::SignaturesChecked { count: 1 })?;
+
+    let consensus_state = verify_header::<H>(
+        client_state,
+        &trusted_consensus_state,
+        header,
+        ctx.host_timestamp()?,
+    )?;
+
+    Ok(consensus_state)
+}
+
+/// Check for misbehaviour on the client state as part of the client state
+/// validation process.
+///
+/// A verified misbehaviour always is misbehaviour, whereas a verified header
+/// is only if the client already has a different consensus state at the
+/// finalized slot of the header, which then makes for two conflicting
+/// finalized headers.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn check_for_misbehaviour<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<bool, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    match client_message.type_url.as_str() {
+        ETHEREUM_HEADER_TYPE_URL => {
+            let header = EthHeader::try_from(client_message)?;
+            let header_height = header.height();
+
+            let maybe_existing_consensus_state = ctx
+                .consensus_state(&ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    header_height.revision_number(),
+                    header_height.revision_height(),
+                ))
+                .ok();
+
+            let Some(existing_consensus_state) = maybe_existing_consensus_state else {
+                return Ok(false);
+            };
+
+            let existing_consensus_state: ConsensusStateType =
+                existing_consensus_state.try_into().map_err(Into::into)?;
+
+            Ok(existing_consensus_state.state_root
+                != header
+                    .consensus_update
+                    .finalized_header
+                    .execution
+                    .state_root
+                || existing_consensus_state.storage_root.as_bytes()
+                    != header.account_update.storage_root.as_slice())
+        }
+        ETHEREUM_MISBEHAVIOUR_TYPE_URL => Ok(true),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Query the status of the client state.
+///
+/// The client is expired once its latest consensus state is older than the
+/// trusting period, as no header can then be verified from it.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn status<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+) -> Result<Status, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    if client_state.is_frozen() {
+        return Ok(Status::Frozen);
+    }
+
+    let latest_height = client_state.latest_height();
+
+    let latest_consensus_state: ConsensusStateType =
+        match ctx.consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        )) {
+            Ok(cs) => cs.try_into().map_err(Into::into)?,
+            // if the client state does not have an associated consensus state for its latest height
+            // then it must be expired
+            Err(_) => return Ok(Status::Expired),
+        };
+
+    let now = ctx.host_timestamp()?;
+
+    if let Some(elapsed_since_latest_consensus_state) =
+        now.duration_since(&latest_consensus_state.timestamp)
+    {
+        if elapsed_since_latest_consensus_state >= client_state.trusting_period {
+            return Ok(Status::Expired);
+        }
+    }
+
+    Ok(Status::Active)
+}
+
+/// Check that the subject and substitute client states match as part of
+/// the client recovery validation step.
+///
+/// The subject and substitute client states match if they follow the same
+/// chain, under the same parameters, and verify proofs against the same IBC
+/// contract. Only their latest slot, frozen height, trusting period and
+/// minimum participation may differ.
+pub fn check_substitute(
+    subject_client_state: &ClientStateType,
+    substitute_client_state: Any,
+) -> Result<(), ClientError> {
+    let substitute_client_state = ClientStateType::try_from(substitute_client_state)?;
+
+    let ClientStateType {
+        chain_id,
+        genesis_validators_root,
+        genesis_time,
+        fork_parameters,
+        seconds_per_slot,
+        slots_per_epoch,
+        epochs_per_sync_committee_period,
+        min_sync_committee_participants: _,
+        trusting_period: _,
+        latest_slot: _,
+        frozen_height: _,
+        ibc_contract_address,
+        ibc_commitment_slot,
+    } = subject_client_state;
+
+    (chain_id == &substitute_client_state.chain_id
+        && genesis_validators_root == &substitute_client_state.genesis_validators_root
+        && genesis_time == &substitute_client_state.genesis_time
+        && fork_parameters == &substitute_client_state.fork_parameters
+        && seconds_per_slot == &substitute_client_state.seconds_per_slot
+        && slots_per_epoch == &substitute_client_state.slots_per_epoch
+        && epochs_per_sync_committee_period
+            == &substitute_client_state.epochs_per_sync_committee_period
+        && ibc_contract_address == &substitute_client_state.ibc_contract_address
+        && ibc_commitment_slot == &substitute_client_state.ibc_commitment_slot)
+        .then_some(())
+        .ok_or(ClientError::ClientRecoveryStateMismatch)
+}
@@ -0,0 +1,34 @@
+//! Ethereum sync committee light client implementation along with
+//! re-exporting the necessary types from `ibc-client-ethereum-types` crate.
+//!
+//! The cryptographic primitives of the client, BLS signature verification in
+//! particular, are provided by the host through the
+//! [`EthereumHostFunctions`](types::EthereumHostFunctions) trait, which the
+//! client state is generic over.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+
+pub const ETHEREUM_CLIENT_TYPE: &str = "ethereum";
+
+/// Re-exports Ethereum light client data structures from the
+/// `ibc-client-ethereum-types` crate.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_ethereum_types::*;
+}
@@ -0,0 +1,760 @@
+//! Defines the beacon chain objects of the light client protocol, along with
+//! their SSZ roots and the Merkle branches proving them
+
+use ibc_primitives::prelude::*;
+
+use crate::bls::{BlsPublicKey, BlsSignature};
+use crate::error::Error;
+use crate::host_functions::EthereumHostFunctions;
+use crate::raw::{
+    BeaconBlockHeader as RawBeaconBlockHeader, ExecutionPayloadHeader as RawExecutionPayloadHeader,
+    Fork as RawFork, ForkParameters as RawForkParameters,
+    LightClientHeader as RawLightClientHeader, LightClientUpdate as RawLightClientUpdate,
+    SyncAggregate as RawSyncAggregate, SyncCommittee as RawSyncCommittee,
+};
+use crate::ssz::{
+    byte_list_root, bytes_root, hash_pair, is_valid_merkle_branch, merkleize, uint64_chunk, Chunk,
+};
+use crate::to_array;
+
+/// The domain type of the signatures of the sync committees.
+pub const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [7, 0, 0, 0];
+
+/// The generalized index of the finalized checkpoint root in the beacon
+/// state, before Electra.
+pub const FINALIZED_ROOT_GINDEX: u64 = 105;
+/// The generalized index of the next sync committee in the beacon state,
+/// before Electra.
+pub const NEXT_SYNC_COMMITTEE_GINDEX: u64 = 55;
+/// The generalized index of the finalized checkpoint root in the beacon
+/// state, from Electra on.
+pub const FINALIZED_ROOT_GINDEX_ELECTRA: u64 = 169;
+/// The generalized index of the next sync committee in the beacon state,
+/// from Electra on.
+pub const NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA: u64 = 87;
+/// The generalized index of the execution payload header in the beacon block
+/// body.
+pub const EXECUTION_PAYLOAD_GINDEX: u64 = 25;
+
+const LOGS_BLOOM_LENGTH: usize = 256;
+const MAX_EXTRA_DATA_BYTES: usize = 32;
+
+/// The forks of the beacon chain, in order of activation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ForkName {
+    Genesis,
+    Altair,
+    Bellatrix,
+    Capella,
+    Deneb,
+    Electra,
+}
+
+impl ForkName {
+    /// Returns the generalized index of the finalized checkpoint root in the
+    /// beacon state of the fork.
+    pub fn finalized_root_gindex(self) -> u64 {
+        if self >= Self::Electra {
+            FINALIZED_ROOT_GINDEX_ELECTRA
+        } else {
+            FINALIZED_ROOT_GINDEX
+        }
+    }
+
+    /// Returns the generalized index of the next sync committee in the
+    /// beacon state of the fork.
+    pub fn next_sync_committee_gindex(self) -> u64 {
+        if self >= Self::Electra {
+            NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA
+        } else {
+            NEXT_SYNC_COMMITTEE_GINDEX
+        }
+    }
+}
+
+/// A fork version, along with the epoch it activates at.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fork {
+    pub version: [u8; 4],
+    pub epoch: u64,
+}
+
+impl TryFrom<RawFork> for Fork {
+    type Error = Error;
+
+    fn try_from(raw: RawFork) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: to_array(&raw.version, "fork version")?,
+            epoch: raw.epoch,
+        })
+    }
+}
+
+impl From<Fork> for RawFork {
+    fn from(value: Fork) -> Self {
+        Self {
+            version: value.version.to_vec(),
+            epoch: value.epoch,
+        }
+    }
+}
+
+/// The forks of the beacon chain followed by the client. Forks that are not
+/// scheduled yet activate at epoch `u64::MAX`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForkParameters {
+    pub genesis_fork_version: [u8; 4],
+    pub altair: Fork,
+    pub bellatrix: Fork,
+    pub capella: Fork,
+    pub deneb: Fork,
+    pub electra: Fork,
+}
+
+impl ForkParameters {
+    /// Checks that the forks activate in order.
+    pub fn validate(&self) -> Result<(), Error> {
+        let epochs = [
+            self.altair.epoch,
+            self.bellatrix.epoch,
+            self.capella.epoch,
+            self.deneb.epoch,
+            self.electra.epoch,
+        ];
+
+        if epochs.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(Error::UnorderedForks);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the fork active at the given epoch, along with its version.
+    pub fn fork_at(&self, epoch: u64) -> (ForkName, [u8; 4]) {
+        [
+            (ForkName::Electra, &self.electra),
+            (ForkName::Deneb, &self.deneb),
+            (ForkName::Capella, &self.capella),
+            (ForkName::Bellatrix, &self.bellatrix),
+            (ForkName::Altair, &self.altair),
+        ]
+        .into_iter()
+        .find(|(_, fork)| epoch >= fork.epoch)
+        .map_or(
+            (ForkName::Genesis, self.genesis_fork_version),
+            |(name, fork)| (name, fork.version),
+        )
+    }
+}
+
+impl TryFrom<RawForkParameters> for ForkParameters {
+    type Error = Error;
+
+    fn try_from(raw: RawForkParameters) -> Result<Self, Self::Error> {
+        fn fork(raw: Option<RawFork>, name: &str) -> Result<Fork, Error> {
+            raw.ok_or_else(|| Error::InvalidRawClientState {
+                reason: format!("missing {name} fork"),
+            })?
+            .try_into()
+        }
+
+        Ok(Self {
+            genesis_fork_version: to_array(&raw.genesis_fork_version, "genesis fork version")?,
+            altair: fork(raw.altair, "altair")?,
+            bellatrix: fork(raw.bellatrix, "bellatrix")?,
+            capella: fork(raw.capella, "capella")?,
+            deneb: fork(raw.deneb, "deneb")?,
+            electra: fork(raw.electra, "electra")?,
+        })
+    }
+}
+
+impl From<ForkParameters> for RawForkParameters {
+    fn from(value: ForkParameters) -> Self {
+        Self {
+            genesis_fork_version: value.genesis_fork_version.to_vec(),
+            altair: Some(value.altair.into()),
+            bellatrix: Some(value.bellatrix.into()),
+            capella: Some(value.capella.into()),
+            deneb: Some(value.deneb.into()),
+            electra: Some(value.electra.into()),
+        }
+    }
+}
+
+/// Returns the domain of the signatures of the sync committees under the
+/// given fork version, as per the `compute_domain` function of the consensus
+/// specifications.
+pub fn compute_sync_committee_domain<H: EthereumHostFunctions>(
+    fork_version: [u8; 4],
+    genesis_validators_root: &[u8; 32],
+) -> Chunk {
+    let mut version_chunk = [0u8; 32];
+    version_chunk[..4].copy_from_slice(&fork_version);
+
+    let fork_data_root = hash_pair::<H>(&version_chunk, genesis_validators_root);
+
+    let mut domain = [0u8; 32];
+    domain[..4].copy_from_slice(&DOMAIN_SYNC_COMMITTEE);
+    domain[4..].copy_from_slice(&fork_data_root[..28]);
+    domain
+}
+
+/// Returns the root signed over for the object with the given root under the
+/// given domain, as per the `compute_signing_root` function of the consensus
+/// specifications.
+pub fn compute_signing_root<H: EthereumHostFunctions>(
+    object_root: &Chunk,
+    domain: &Chunk,
+) -> Chunk {
+    hash_pair::<H>(object_root, domain)
+}
+
+fn to_branch(raw: Vec<Vec<u8>>, name: &str) -> Result<Vec<Chunk>, Error> {
+    raw.iter().map(|node| to_array(node, name)).collect()
+}
+
+fn from_branch(branch: Vec<Chunk>) -> Vec<Vec<u8>> {
+    branch.into_iter().map(|node| node.to_vec()).collect()
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BeaconBlockHeader {
+    pub slot: u64,
+    pub proposer_index: u64,
+    pub parent_root: [u8; 32],
+    pub state_root: [u8; 32],
+    pub body_root: [u8; 32],
+}
+
+impl BeaconBlockHeader {
+    pub fn hash_tree_root<H: EthereumHostFunctions>(&self) -> Chunk {
+        merkleize::<H>(
+            &[
+                uint64_chunk(self.slot),
+                uint64_chunk(self.proposer_index),
+                self.parent_root,
+                self.state_root,
+                self.body_root,
+            ],
+            5,
+        )
+    }
+}
+
+impl TryFrom<RawBeaconBlockHeader> for BeaconBlockHeader {
+    type Error = Error;
+
+    fn try_from(raw: RawBeaconBlockHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            slot: raw.slot,
+            proposer_index: raw.proposer_index,
+            parent_root: to_array(&raw.parent_root, "parent root")?,
+            state_root: to_array(&raw.state_root, "state root")?,
+            body_root: to_array(&raw.body_root, "body root")?,
+        })
+    }
+}
+
+impl From<BeaconBlockHeader> for RawBeaconBlockHeader {
+    fn from(value: BeaconBlockHeader) -> Self {
+        Self {
+            slot: value.slot,
+            proposer_index: value.proposer_index,
+            parent_root: value.parent_root.to_vec(),
+            state_root: value.state_root.to_vec(),
+            body_root: value.body_root.to_vec(),
+        }
+    }
+}
+
+/// The header of an execution payload, from Capella on. The blob gas fields
+/// are only part of the header from Deneb on.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionPayloadHeader {
+    pub parent_hash: [u8; 32],
+    pub fee_recipient: [u8; 20],
+    pub state_root: [u8; 32],
+    pub receipts_root: [u8; 32],
+    pub logs_bloom: Vec<u8>,
+    pub prev_randao: [u8; 32],
+    pub block_number: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub timestamp: u64,
+    pub extra_data: Vec<u8>,
+    /// Little-endian 256-bit integer.
+    pub base_fee_per_gas: [u8; 32],
+    pub block_hash: [u8; 32],
+    pub transactions_root: [u8; 32],
+    pub withdrawals_root: [u8; 32],
+    pub blob_gas_used: u64,
+    pub excess_blob_gas: u64,
+}
+
+impl ExecutionPayloadHeader {
+    /// Returns the root of the header under the given fork.
+    pub fn hash_tree_root<H: EthereumHostFunctions>(&self, fork: ForkName) -> Chunk {
+        let mut fee_recipient = [0u8; 32];
+        fee_recipient[..20].copy_from_slice(&self.fee_recipient);
+
+        let mut fields = vec![
+            self.parent_hash,
+            fee_recipient,
+            self.state_root,
+            self.receipts_root,
+            bytes_root::<H>(&self.logs_bloom),
+            self.prev_randao,
+            uint64_chunk(self.block_number),
+            uint64_chunk(self.gas_limit),
+            uint64_chunk(self.gas_used),
+            uint64_chunk(self.timestamp),
+            byte_list_root::<H>(&self.extra_data, MAX_EXTRA_DATA_BYTES),
+            self.base_fee_per_gas,
+            self.block_hash,
+            self.transactions_root,
+        ];
+
+        if fork >= ForkName::Capella {
+            fields.push(self.withdrawals_root);
+        }
+
+        if fork >= ForkName::Deneb {
+            fields.push(uint64_chunk(self.blob_gas_used));
+            fields.push(uint64_chunk(self.excess_blob_gas));
+        }
+
+        merkleize::<H>(&fields, fields.len())
+    }
+}
+
+impl TryFrom<RawExecutionPayloadHeader> for ExecutionPayloadHeader {
+    type Error = Error;
+
+    fn try_from(raw: RawExecutionPayloadHeader) -> Result<Self, Self::Error> {
+        if raw.logs_bloom.len() != LOGS_BLOOM_LENGTH {
+            return Err(Error::InvalidLength {
+                name: "logs bloom".to_string(),
+                expected: LOGS_BLOOM_LENGTH,
+                actual: raw.logs_bloom.len(),
+            });
+        }
+
+        if raw.extra_data.len() > MAX_EXTRA_DATA_BYTES {
+            return Err(Error::InvalidLength {
+                name: "extra data".to_string(),
+                expected: MAX_EXTRA_DATA_BYTES,
+                actual: raw.extra_data.len(),
+            });
+        }
+
+        Ok(Self {
+            parent_hash: to_array(&raw.parent_hash, "parent hash")?,
+            fee_recipient: to_array(&raw.fee_recipient, "fee recipient")?,
+            state_root: to_array(&raw.state_root, "execution state root")?,
+            receipts_root: to_array(&raw.receipts_root, "receipts root")?,
+            logs_bloom: raw.logs_bloom,
+            prev_randao: to_array(&raw.prev_randao, "prev randao")?,
+            block_number: raw.block_number,
+            gas_limit: raw.gas_limit,
+            gas_used: raw.gas_used,
+            timestamp: raw.timestamp,
+            extra_data: raw.extra_data,
+            base_fee_per_gas: to_array(&raw.base_fee_per_gas, "base fee per gas")?,
+            block_hash: to_array(&raw.block_hash, "block hash")?,
+            transactions_root: to_array(&raw.transactions_root, "transactions root")?,
+            withdrawals_root: to_array(&raw.withdrawals_root, "withdrawals root")?,
+            blob_gas_used: raw.blob_gas_used,
+            excess_blob_gas: raw.excess_blob_gas,
+        })
+    }
+}
+
+impl From<ExecutionPayloadHeader> for RawExecutionPayloadHeader {
+    fn from(value: ExecutionPayloadHeader) -> Self {
+        Self {
+            parent_hash: value.parent_hash.to_vec(),
+            fee_recipient: value.fee_recipient.to_vec(),
+            state_root: value.state_root.to_vec(),
+            receipts_root: value.receipts_root.to_vec(),
+            logs_bloom: value.logs_bloom,
+            prev_randao: value.prev_randao.to_vec(),
+            block_number: value.block_number,
+            gas_limit: value.gas_limit,
+            gas_used: value.gas_used,
+            timestamp: value.timestamp,
+            extra_data: value.extra_data,
+            base_fee_per_gas: value.base_fee_per_gas.to_vec(),
+            block_hash: value.block_hash.to_vec(),
+            transactions_root: value.transactions_root.to_vec(),
+            withdrawals_root: value.withdrawals_root.to_vec(),
+            blob_gas_used: value.blob_gas_used,
+            excess_blob_gas: value.excess_blob_gas,
+        }
+    }
+}
+
+/// A beacon block header, along with the execution payload header of its
+/// block and the branch proving it against the block body.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LightClientHeader {
+    pub beacon: BeaconBlockHeader,
+    pub execution: ExecutionPayloadHeader,
+    pub execution_branch: Vec<Chunk>,
+}
+
+impl LightClientHeader {
+    /// Verifies the execution payload header against the body root of the
+    /// beacon block header, under the given fork.
+    pub fn verify_execution_branch<H: EthereumHostFunctions>(
+        &self,
+        fork: ForkName,
+    ) -> Result<(), Error> {
+        if fork < ForkName::Capella {
+            return Err(Error::PreCapellaSlot {
+                slot: self.beacon.slot,
+            });
+        }
+
+        if !is_valid_merkle_branch::<H>(
+            &self.execution.hash_tree_root::<H>(fork),
+            &self.execution_branch,
+            EXECUTION_PAYLOAD_GINDEX,
+            &self.beacon.body_root,
+        ) {
+            return Err(Error::InvalidExecutionBranch);
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<RawLightClientHeader> for LightClientHeader {
+    type Error = Error;
+
+    fn try_from(raw: RawLightClientHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            beacon: raw
+                .beacon
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing beacon header".to_string(),
+                })?
+                .try_into()?,
+            execution: raw
+                .execution
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing execution payload header".to_string(),
+                })?
+                .try_into()?,
+            execution_branch: to_branch(raw.execution_branch, "execution branch node")?,
+        })
+    }
+}
+
+impl From<LightClientHeader> for RawLightClientHeader {
+    fn from(value: LightClientHeader) -> Self {
+        Self {
+            beacon: Some(value.beacon.into()),
+            execution: Some(value.execution.into()),
+            execution_branch: from_branch(value.execution_branch),
+        }
+    }
+}
+
+/// The members of a sync committee, along with their aggregate public key.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyncCommittee {
+    pub pubkeys: Vec<BlsPublicKey>,
+    pub aggregate_pubkey: BlsPublicKey,
+}
+
+impl SyncCommittee {
+    pub fn hash_tree_root<H: EthereumHostFunctions>(&self) -> Chunk {
+        let pubkeys: Vec<Chunk> = self
+            .pubkeys
+            .iter()
+            .map(|pubkey| bytes_root::<H>(pubkey.as_bytes()))
+            .collect();
+
+        hash_pair::<H>(
+            &merkleize::<H>(&pubkeys, pubkeys.len()),
+            &bytes_root::<H>(self.aggregate_pubkey.as_bytes()),
+        )
+    }
+}
+
+impl TryFrom<RawSyncCommittee> for SyncCommittee {
+    type Error = Error;
+
+    fn try_from(raw: RawSyncCommittee) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pubkeys: raw
+                .pubkeys
+                .into_iter()
+                .map(BlsPublicKey::try_from)
+                .collect::<Result<_, _>>()?,
+            aggregate_pubkey: raw.aggregate_pubkey.try_into()?,
+        })
+    }
+}
+
+impl From<SyncCommittee> for RawSyncCommittee {
+    fn from(value: SyncCommittee) -> Self {
+        Self {
+            pubkeys: value.pubkeys.into_iter().map(Into::into).collect(),
+            aggregate_pubkey: value.aggregate_pubkey.into(),
+        }
+    }
+}
+
+/// The participation of the members of a sync committee to a signature,
+/// along with the signature aggregated over the participants.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyncAggregate {
+    /// Bit vector flagging the participants, in little-endian bit order.
+    pub sync_committee_bits: Vec<u8>,
+    pub sync_committee_signature: BlsSignature,
+}
+
+impl SyncAggregate {
+    /// Returns whether the committee member at the given index participated.
+    pub fn is_participant(&self, index: usize) -> bool {
+        self.sync_committee_bits
+            .get(index / 8)
+            .is_some_and(|byte| (byte >> (index % 8)) & 1 == 1)
+    }
+
+    /// Returns the number of participants.
+    pub fn num_participants(&self) -> u64 {
+        self.sync_committee_bits
+            .iter()
+            .map(|byte| u64::from(byte.count_ones()))
+            .sum()
+    }
+
+    /// Returns the public keys of the participants among the members of the
+    /// given committee, whose size must match the number of bits.
+    pub fn participant_pubkeys<'a>(
+        &self,
+        committee: &'a SyncCommittee,
+    ) -> Result<Vec<&'a BlsPublicKey>, Error> {
+        if self.sync_committee_bits.len() * 8 != committee.pubkeys.len() {
+            return Err(Error::InvalidSyncCommitteeBits {
+                bits: self.sync_committee_bits.len() * 8,
+                committee: committee.pubkeys.len(),
+            });
+        }
+
+        Ok(committee
+            .pubkeys
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.is_participant(*index))
+            .map(|(_, pubkey)| pubkey)
+            .collect())
+    }
+}
+
+impl TryFrom<RawSyncAggregate> for SyncAggregate {
+    type Error = Error;
+
+    fn try_from(raw: RawSyncAggregate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sync_committee_bits: raw.sync_committee_bits,
+            sync_committee_signature: raw.sync_committee_signature.try_into()?,
+        })
+    }
+}
+
+impl From<SyncAggregate> for RawSyncAggregate {
+    fn from(value: SyncAggregate) -> Self {
+        Self {
+            sync_committee_bits: value.sync_committee_bits,
+            sync_committee_signature: value.sync_committee_signature.into(),
+        }
+    }
+}
+
+/// A light client update, in which the sync committee attests to a beacon
+/// block header whose state finalizes another one.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LightClientUpdate {
+    pub attested_header: BeaconBlockHeader,
+    /// The next sync committee in the attested state, if disclosed.
+    pub next_sync_committee: Option<SyncCommittee>,
+    pub next_sync_committee_branch: Vec<Chunk>,
+    pub finalized_header: LightClientHeader,
+    pub finality_branch: Vec<Chunk>,
+    pub sync_aggregate: SyncAggregate,
+    pub signature_slot: u64,
+}
+
+impl LightClientUpdate {
+    /// Verifies the finalized header and the next sync committee, if any,
+    /// against the state root of the attested header, under the given fork
+    /// of the attested header.
+    pub fn verify_branches<H: EthereumHostFunctions>(&self, fork: ForkName) -> Result<(), Error> {
+        if !is_valid_merkle_branch::<H>(
+            &self.finalized_header.beacon.hash_tree_root::<H>(),
+            &self.finality_branch,
+            fork.finalized_root_gindex(),
+            &self.attested_header.state_root,
+        ) {
+            return Err(Error::InvalidFinalityBranch);
+        }
+
+        if let Some(next_sync_committee) = &self.next_sync_committee {
+            if !is_valid_merkle_branch::<H>(
+                &next_sync_committee.hash_tree_root::<H>(),
+                &self.next_sync_committee_branch,
+                fork.next_sync_committee_gindex(),
+                &self.attested_header.state_root,
+            ) {
+                return Err(Error::InvalidNextSyncCommitteeBranch);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<RawLightClientUpdate> for LightClientUpdate {
+    type Error = Error;
+
+    fn try_from(raw: RawLightClientUpdate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attested_header: raw
+                .attested_header
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing attested header".to_string(),
+                })?
+                .try_into()?,
+            next_sync_committee: raw.next_sync_committee.map(TryInto::try_into).transpose()?,
+            next_sync_committee_branch: to_branch(
+                raw.next_sync_committee_branch,
+                "next sync committee branch node",
+            )?,
+            finalized_header: raw
+                .finalized_header
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing finalized header".to_string(),
+                })?
+                .try_into()?,
+            finality_branch: to_branch(raw.finality_branch, "finality branch node")?,
+            sync_aggregate: raw
+                .sync_aggregate
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing sync aggregate".to_string(),
+                })?
+                .try_into()?,
+            signature_slot: raw.signature_slot,
+        })
+    }
+}
+
+impl From<LightClientUpdate> for RawLightClientUpdate {
+    fn from(value: LightClientUpdate) -> Self {
+        Self {
+            attested_header: Some(value.attested_header.into()),
+            next_sync_committee: value.next_sync_committee.map(Into::into),
+            next_sync_committee_branch: from_branch(value.next_sync_committee_branch),
+            finalized_header: Some(value.finalized_header.into()),
+            finality_branch: from_branch(value.finality_branch),
+            sync_aggregate: Some(value.sync_aggregate.into()),
+            signature_slot: value.signature_slot,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::TestHostFunctions;
+
+    #[test]
+    fn test_fork_at() {
+        let fork = |version: u8, epoch: u64| Fork {
+            version: [version, 0, 0, 0],
+            epoch,
+        };
+        let params = ForkParameters {
+            genesis_fork_version: [0, 0, 0, 0],
+            altair: fork(1, 10),
+            bellatrix: fork(2, 20),
+            capella: fork(3, 30),
+            deneb: fork(4, 40),
+            electra: fork(5, u64::MAX),
+        };
+
+        assert!(params.validate().is_ok());
+        assert_eq!(params.fork_at(0), (ForkName::Genesis, [0, 0, 0, 0]));
+        assert_eq!(params.fork_at(29), (ForkName::Bellatrix, [2, 0, 0, 0]));
+        assert_eq!(params.fork_at(40), (ForkName::Deneb, [4, 0, 0, 0]));
+        assert_eq!(
+            params.fork_at(u64::MAX - 1),
+            (ForkName::Deneb, [4, 0, 0, 0])
+        );
+
+        let unordered = ForkParameters {
+            capella: fork(3, 50),
+            ..params
+        };
+        assert!(unordered.validate().is_err());
+    }
+
+    #[test]
+    fn test_sync_aggregate_participants() {
+        let committee = SyncCommittee {
+            pubkeys: (0..16u8).map(|i| BlsPublicKey::new([i; 48])).collect(),
+            aggregate_pubkey: BlsPublicKey::new([0xff; 48]),
+        };
+        let aggregate = SyncAggregate {
+            sync_committee_bits: vec![0b0000_0101, 0b1000_0000],
+            sync_committee_signature: BlsSignature::new([0; 96]),
+        };
+
+        assert_eq!(aggregate.num_participants(), 3);
+
+        let participants = aggregate.participant_pubkeys(&committee).unwrap();
+        assert_eq!(
+            participants,
+            vec![
+                &committee.pubkeys[0],
+                &committee.pubkeys[2],
+                &committee.pubkeys[15]
+            ]
+        );
+
+        let short = SyncAggregate {
+            sync_committee_bits: vec![0xff],
+            ..aggregate
+        };
+        assert!(short.participant_pubkeys(&committee).is_err());
+    }
+
+    #[test]
+    fn test_beacon_block_header_root() {
+        // Root of the default beacon block header, i.e. of five zero chunks.
+        let header = BeaconBlockHeader {
+            slot: 0,
+            proposer_index: 0,
+            parent_root: [0; 32],
+            state_root: [0; 32],
+            body_root: [0; 32],
+        };
+
+        assert_eq!(
+            header.hash_tree_root::<TestHostFunctions>(),
+            merkleize::<TestHostFunctions>(&[], 8)
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! Defines the BLS12-381 public keys and signatures of the sync committees
+
+use ibc_primitives::prelude::*;
+
+use crate::error::Error;
+use crate::to_array;
+
+pub const BLS_PUBLIC_KEY_LENGTH: usize = 48;
+pub const BLS_SIGNATURE_LENGTH: usize = 96;
+
+/// A compressed BLS12-381 public key.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Vec<u8>", into = "Vec<u8>")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlsPublicKey([u8; BLS_PUBLIC_KEY_LENGTH]);
+
+impl BlsPublicKey {
+    pub fn new(bytes: [u8; BLS_PUBLIC_KEY_LENGTH]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; BLS_PUBLIC_KEY_LENGTH] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for BlsPublicKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self(to_array(bytes, "BLS public key")?))
+    }
+}
+
+impl TryFrom<Vec<u8>> for BlsPublicKey {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl From<BlsPublicKey> for Vec<u8> {
+    fn from(value: BlsPublicKey) -> Self {
+        value.0.to_vec()
+    }
+}
+
+/// A compressed BLS12-381 signature.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Vec<u8>", into = "Vec<u8>")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlsSignature([u8; BLS_SIGNATURE_LENGTH]);
+
+impl BlsSignature {
+    pub fn new(bytes: [u8; BLS_SIGNATURE_LENGTH]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; BLS_SIGNATURE_LENGTH] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for BlsSignature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self(to_array(bytes, "BLS signature")?))
+    }
+}
+
+impl TryFrom<Vec<u8>> for BlsSignature {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl From<BlsSignature> for Vec<u8> {
+    fn from(value: BlsSignature) -> Self {
+        value.0.to_vec()
+    }
+}
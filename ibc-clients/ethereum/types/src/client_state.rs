@@ -0,0 +1,311 @@
+//! Implements the core [`ClientState`] type for the Ethereum light client.
+
+use core::time::Duration;
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::beacon::{ForkName, ForkParameters};
+use crate::error::Error;
+use crate::raw::ClientState as RawClientState;
+use crate::to_array;
+
+pub const ETHEREUM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.ClientState";
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Contains the core implementation of the Ethereum light client.
+///
+/// The client follows the finalized headers of the beacon chain, whose slots
+/// are the revision heights of the client's heights in revision zero, and
+/// verifies proofs against the storage of the IBC contract deployed on its
+/// execution layer.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    /// The chain ID of the execution layer.
+    pub chain_id: u64,
+    pub genesis_validators_root: [u8; 32],
+    /// The genesis time of the beacon chain, in seconds since the Unix epoch.
+    pub genesis_time: u64,
+    pub fork_parameters: ForkParameters,
+    pub seconds_per_slot: u64,
+    pub slots_per_epoch: u64,
+    pub epochs_per_sync_committee_period: u64,
+    /// The minimum number of sync committee members that must have signed an
+    /// update for it to be applied.
+    pub min_sync_committee_participants: u64,
+    /// The duration for which a consensus state is trusted, which must be
+    /// below the weak subjectivity period of the beacon chain.
+    pub trusting_period: Duration,
+    /// The slot of the latest finalized header the client was updated to.
+    pub latest_slot: u64,
+    pub frozen_height: Option<Height>,
+    /// The address of the IBC contract on the execution layer.
+    pub ibc_contract_address: [u8; 20],
+    /// The storage slot of the mapping from the hashed paths of the IBC
+    /// contract to their commitments.
+    pub ibc_commitment_slot: [u8; 32],
+}
+
+impl ClientState {
+    /// Checks that the parameters of the beacon chain are consistent.
+    pub fn validate(&self) -> Result<(), Error> {
+        for (name, value) in [
+            ("chain id", self.chain_id),
+            ("seconds per slot", self.seconds_per_slot),
+            ("slots per epoch", self.slots_per_epoch),
+            (
+                "epochs per sync committee period",
+                self.epochs_per_sync_committee_period,
+            ),
+            (
+                "minimum sync committee participants",
+                self.min_sync_committee_participants,
+            ),
+            ("latest slot", self.latest_slot),
+        ] {
+            if value == 0 {
+                return Err(Error::ZeroParameter {
+                    name: name.to_string(),
+                });
+            }
+        }
+
+        if self.trusting_period.is_zero() {
+            return Err(Error::ZeroParameter {
+                name: "trusting period".to_string(),
+            });
+        }
+
+        self.fork_parameters.validate()
+    }
+
+    /// Returns the latest height of the client, i.e. the height of revision
+    /// zero at the latest slot.
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.latest_slot).expect("latest slot is never zero")
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    pub fn with_frozen_height(self, h: Height) -> Self {
+        Self {
+            frozen_height: Some(h),
+            ..self
+        }
+    }
+
+    /// Returns the client state following an update to the given slot, which
+    /// only moves the latest slot forward.
+    pub fn with_latest_slot(self, slot: u64) -> Self {
+        Self {
+            latest_slot: self.latest_slot.max(slot),
+            ..self
+        }
+    }
+
+    pub fn compute_epoch(&self, slot: u64) -> u64 {
+        slot.checked_div(self.slots_per_epoch).unwrap_or_default()
+    }
+
+    /// Returns the sync committee period of the given slot.
+    pub fn compute_sync_committee_period(&self, slot: u64) -> u64 {
+        self.compute_epoch(slot)
+            .checked_div(self.epochs_per_sync_committee_period)
+            .unwrap_or_default()
+    }
+
+    /// Returns the fork active at the given slot, along with its version.
+    pub fn fork_at_slot(&self, slot: u64) -> (ForkName, [u8; 4]) {
+        self.fork_parameters.fork_at(self.compute_epoch(slot))
+    }
+
+    /// Returns the timestamp of the given slot.
+    pub fn timestamp_at_slot(&self, slot: u64) -> Result<Timestamp, Error> {
+        let nanos = slot
+            .checked_mul(self.seconds_per_slot)
+            .and_then(|secs| secs.checked_add(self.genesis_time))
+            .and_then(|secs| secs.checked_mul(NANOS_PER_SECOND))
+            .ok_or(Error::SlotOverflow { slot })?;
+
+        Ok(Timestamp::from_nanoseconds(nanos)?)
+    }
+
+    /// Returns the slot at the given timestamp, i.e. the current slot if the
+    /// timestamp is the current time.
+    pub fn slot_at_timestamp(&self, timestamp: Timestamp) -> u64 {
+        (timestamp.nanoseconds() / NANOS_PER_SECOND)
+            .saturating_sub(self.genesis_time)
+            .checked_div(self.seconds_per_slot)
+            .unwrap_or_default()
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let fork_parameters = raw
+            .fork_parameters
+            .ok_or_else(|| Error::InvalidRawClientState {
+                reason: "missing fork parameters".to_string(),
+            })?
+            .try_into()?;
+
+        let trusting_period = raw
+            .trusting_period
+            .ok_or_else(|| Error::InvalidRawClientState {
+                reason: "missing trusting period".to_string(),
+            })?
+            .try_into()
+            .map_err(|_| Error::InvalidRawClientState {
+                reason: "negative trusting period".to_string(),
+            })?;
+
+        // A `frozen_height` of `0` means "not frozen", as for Tendermint
+        // clients.
+        let frozen_height = raw
+            .frozen_height
+            .and_then(|height| Height::try_from(height).ok());
+
+        let client_state = Self {
+            chain_id: raw.chain_id,
+            genesis_validators_root: to_array(
+                &raw.genesis_validators_root,
+                "genesis validators root",
+            )?,
+            genesis_time: raw.genesis_time,
+            fork_parameters,
+            seconds_per_slot: raw.seconds_per_slot,
+            slots_per_epoch: raw.slots_per_epoch,
+            epochs_per_sync_committee_period: raw.epochs_per_sync_committee_period,
+            min_sync_committee_participants: raw.min_sync_committee_participants,
+            trusting_period,
+            latest_slot: raw.latest_slot,
+            frozen_height,
+            ibc_contract_address: to_array(&raw.ibc_contract_address, "IBC contract address")?,
+            ibc_commitment_slot: to_array(&raw.ibc_commitment_slot, "IBC commitment slot")?,
+        };
+
+        client_state.validate()?;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            chain_id: value.chain_id,
+            genesis_validators_root: value.genesis_validators_root.to_vec(),
+            genesis_time: value.genesis_time,
+            fork_parameters: Some(value.fork_parameters.into()),
+            seconds_per_slot: value.seconds_per_slot,
+            slots_per_epoch: value.slots_per_epoch,
+            epochs_per_sync_committee_period: value.epochs_per_sync_committee_period,
+            min_sync_committee_participants: value.min_sync_committee_participants,
+            trusting_period: Some(value.trusting_period.into()),
+            latest_slot: value.latest_slot,
+            frozen_height: value.frozen_height.map(Into::into),
+            ibc_contract_address: value.ibc_contract_address.to_vec(),
+            ibc_commitment_slot: value.ibc_commitment_slot.to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state =
+                Protobuf::<RawClientState>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            ETHEREUM_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: ETHEREUM_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::client_state;
+
+    #[test]
+    fn test_client_state_roundtrip() {
+        let client_state = client_state();
+
+        let any: Any = client_state.clone().into();
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+
+        let frozen = client_state.with_frozen_height(Height::new(0, 1).unwrap());
+        let any: Any = frozen.clone().into();
+        assert_eq!(ClientState::try_from(any).unwrap(), frozen);
+    }
+
+    #[test]
+    fn test_client_state_validation() {
+        assert!(client_state().validate().is_ok());
+
+        let zero_slots = ClientState {
+            slots_per_epoch: 0,
+            ..client_state()
+        };
+        assert!(zero_slots.validate().is_err());
+
+        let zero_latest_slot = ClientState {
+            latest_slot: 0,
+            ..client_state()
+        };
+        let raw = RawClientState::from(zero_latest_slot);
+        assert!(ClientState::try_from(raw).is_err());
+    }
+
+    #[test]
+    fn test_slot_arithmetic() {
+        let client_state = client_state();
+        let slots_per_period =
+            client_state.slots_per_epoch * client_state.epochs_per_sync_committee_period;
+
+        assert_eq!(client_state.compute_sync_committee_period(0), 0);
+        assert_eq!(
+            client_state.compute_sync_committee_period(slots_per_period - 1),
+            0
+        );
+        assert_eq!(
+            client_state.compute_sync_committee_period(slots_per_period),
+            1
+        );
+
+        let timestamp = client_state.timestamp_at_slot(100).unwrap();
+        assert_eq!(client_state.slot_at_timestamp(timestamp), 100);
+    }
+}
@@ -0,0 +1,134 @@
+//! Defines the Ethereum light client's `ConsensusState` type
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::raw::ConsensusState as RawConsensusState;
+use crate::to_array;
+
+pub const ETHEREUM_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.ConsensusState";
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Defines the Ethereum light client's consensus state: the execution state
+/// at a finalized slot, along with the roots of the sync committees of the
+/// period of that slot.
+///
+/// The commitment root of the consensus state is the storage root of the
+/// IBC contract, which membership proofs are verified against.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub slot: u64,
+    /// The state root of the execution layer.
+    pub state_root: [u8; 32],
+    /// The storage root of the IBC contract.
+    pub storage_root: CommitmentRoot,
+    pub timestamp: Timestamp,
+    /// The root of the sync committee of the period of the slot.
+    pub current_sync_committee: [u8; 32],
+    /// The root of the sync committee of the following period, if known.
+    pub next_sync_committee: Option<[u8; 32]>,
+}
+
+impl ConsensusState {
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.storage_root
+    }
+
+    /// Returns the storage root of the IBC contract.
+    pub fn storage_root(&self) -> Result<[u8; 32], Error> {
+        to_array(self.storage_root.as_bytes(), "storage root")
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let timestamp = raw.timestamp.checked_mul(NANOS_PER_SECOND).ok_or_else(|| {
+            Error::InvalidRawConsensusState {
+                reason: format!("timestamp {} overflows", raw.timestamp),
+            }
+        })?;
+
+        let next_sync_committee = if raw.next_sync_committee.is_empty() {
+            None
+        } else {
+            Some(to_array(
+                &raw.next_sync_committee,
+                "next sync committee root",
+            )?)
+        };
+
+        Ok(Self {
+            slot: raw.slot,
+            state_root: to_array(&raw.state_root, "state root")?,
+            storage_root: CommitmentRoot::from_bytes(&to_array::<32>(
+                &raw.storage_root,
+                "storage root",
+            )?),
+            timestamp: Timestamp::from_nanoseconds(timestamp)?,
+            current_sync_committee: to_array(
+                &raw.current_sync_committee,
+                "current sync committee root",
+            )?,
+            next_sync_committee,
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            slot: value.slot,
+            state_root: value.state_root.to_vec(),
+            storage_root: value.storage_root.into_vec(),
+            timestamp: value.timestamp.nanoseconds() / NANOS_PER_SECOND,
+            current_sync_committee: value.current_sync_committee.to_vec(),
+            next_sync_committee: value
+                .next_sync_committee
+                .map(|root| root.to_vec())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state =
+                Protobuf::<RawConsensusState>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            ETHEREUM_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: ETHEREUM_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
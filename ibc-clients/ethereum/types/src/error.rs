@@ -0,0 +1,130 @@
+//! Defines the Ethereum light client's error type
+
+use displaydoc::Display;
+use ibc_core_client_types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::{ParseTimestampError, Timestamp};
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// invalid raw misbehaviour: `{reason}`
+    InvalidRawMisbehaviour { reason: String },
+    /// invalid raw proof: `{reason}`
+    InvalidRawProof { reason: String },
+    /// invalid timestamp: `{0}`
+    InvalidTimestamp(ParseTimestampError),
+    /// invalid length of `{name}`: expected `{expected}` bytes, got `{actual}`
+    InvalidLength {
+        name: String,
+        expected: usize,
+        actual: usize,
+    },
+    /// `{name}` cannot be zero
+    ZeroParameter { name: String },
+    /// fork epochs must be in the order of the forks
+    UnorderedForks,
+    /// slot `{slot}` precedes the Capella fork
+    PreCapellaSlot { slot: u64 },
+    /// slot `{slot}` overflows the timestamps of the client
+    SlotOverflow { slot: u64 },
+    /// slots of the update are not ordered: signature `{signature_slot}`, attested `{attested_slot}`, finalized `{finalized_slot}`, trusted `{trusted_slot}`
+    UnorderedSlots {
+        signature_slot: u64,
+        attested_slot: u64,
+        finalized_slot: u64,
+        trusted_slot: u64,
+    },
+    /// trusting period elapsed since the trusted consensus state at `{trusted_timestamp}`, now `{current_timestamp}`
+    TrustingPeriodElapsed {
+        trusted_timestamp: Timestamp,
+        current_timestamp: Timestamp,
+    },
+    /// signature slot `{signature_slot}` is ahead of the current slot `{current_slot}`
+    FutureSignatureSlot {
+        signature_slot: u64,
+        current_slot: u64,
+    },
+    /// update signed in sync committee period `{signature_period}` cannot be verified from period `{store_period}`
+    UnexpectedSyncCommitteePeriod {
+        signature_period: u64,
+        store_period: u64,
+    },
+    /// update finalized in sync committee period `{finalized_period}` cannot be applied to period `{store_period}`
+    UnexpectedFinalizedPeriod {
+        finalized_period: u64,
+        store_period: u64,
+    },
+    /// next sync committee of the trusted consensus state is unknown
+    MissingNextSyncCommittee,
+    /// sync committee does not match the one of the trusted consensus state
+    SyncCommitteeMismatch,
+    /// sync committee bits of length `{bits}` do not match a committee of `{committee}` members
+    InvalidSyncCommitteeBits { bits: usize, committee: usize },
+    /// `{participants}` sync committee participants are below the minimum of `{min}`
+    InsufficientParticipants { participants: u64, min: u64 },
+    /// `{participants}` sync committee participants out of `{committee}` fall short of a two-thirds supermajority
+    NoSupermajority { participants: u64, committee: u64 },
+    /// invalid finality branch
+    InvalidFinalityBranch,
+    /// invalid execution payload branch
+    InvalidExecutionBranch,
+    /// invalid next sync committee branch
+    InvalidNextSyncCommitteeBranch,
+    /// invalid sync committee aggregate signature
+    InvalidSignature,
+    /// invalid RLP encoding: `{reason}`
+    InvalidRlp { reason: String },
+    /// invalid trie node: `{reason}`
+    InvalidTrieNode { reason: String },
+    /// missing trie node in proof
+    MissingTrieNode,
+    /// trie node does not match its hash
+    TrieNodeHashMismatch,
+    /// account of the IBC contract is absent from the state
+    MissingAccount,
+    /// storage root of the IBC contract does not match the account proof
+    StorageRootMismatch,
+    /// value is absent from the storage of the IBC contract
+    MissingValue,
+    /// value does not match the commitment in the storage of the IBC contract
+    ValueMismatch,
+    /// value is present in the storage of the IBC contract
+    UnexpectedValue,
+    /// misbehaviour headers finalize different slots: `{slot_1}` and `{slot_2}`
+    MisbehaviourSlotMismatch { slot_1: u64, slot_2: u64 },
+    /// misbehaviour headers finalize identical beacon headers
+    IdenticalHeaders,
+    /// Ethereum clients cannot be upgraded
+    UpgradeNotSupported,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::InvalidTimestamp(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<ParseTimestampError> for Error {
+    fn from(e: ParseTimestampError) -> Self {
+        Self::InvalidTimestamp(e)
+    }
+}
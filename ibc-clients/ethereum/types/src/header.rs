@@ -0,0 +1,135 @@
+//! Defines the domain type for Ethereum headers
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::beacon::{LightClientUpdate, SyncCommittee};
+use crate::error::Error;
+use crate::proof::AccountProof;
+use crate::raw::Header as RawHeader;
+
+pub const ETHEREUM_HEADER_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.Header";
+
+/// Ethereum header, with which the client is updated to a finalized slot.
+///
+/// It carries a light client update signed by the sync committee of the
+/// trusted consensus state, or by the next one, along with the proof of the
+/// storage root of the IBC contract at the finalized execution state.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    /// The height of the consensus state the update is verified from.
+    pub trusted_height: Height,
+    /// The sync committee that signed the update, whose root must be one of
+    /// those of the trusted consensus state.
+    pub trusted_sync_committee: SyncCommittee,
+    pub consensus_update: LightClientUpdate,
+    pub account_update: AccountProof,
+}
+
+impl Header {
+    /// Returns the slot of the finalized header.
+    pub fn slot(&self) -> u64 {
+        self.consensus_update.finalized_header.beacon.slot
+    }
+
+    /// Returns the height of the finalized header.
+    pub fn height(&self) -> Height {
+        Height::new(0, self.slot()).expect("finalized slot is never zero")
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        let trusted_height = raw
+            .trusted_height
+            .ok_or_else(|| Error::InvalidRawHeader {
+                reason: "missing trusted height".to_string(),
+            })?
+            .try_into()
+            .map_err(|e: ClientError| Error::InvalidRawHeader {
+                reason: e.to_string(),
+            })?;
+
+        let header = Self {
+            trusted_height,
+            trusted_sync_committee: raw
+                .trusted_sync_committee
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing trusted sync committee".to_string(),
+                })?
+                .try_into()?,
+            consensus_update: raw
+                .consensus_update
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing consensus update".to_string(),
+                })?
+                .try_into()?,
+            account_update: raw
+                .account_update
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing account update".to_string(),
+                })?
+                .try_into()?,
+        };
+
+        if header.slot() == 0 {
+            return Err(Error::InvalidRawHeader {
+                reason: "finalized slot cannot be zero".to_string(),
+            });
+        }
+
+        Ok(header)
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            trusted_height: Some(value.trusted_height.into()),
+            trusted_sync_committee: Some(value.trusted_sync_committee.into()),
+            consensus_update: Some(value.consensus_update.into()),
+            account_update: Some(value.account_update.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
+            let header = Protobuf::<RawHeader>::decode(value).map_err(|e| {
+                ClientError::InvalidRawHeader {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(header)
+        }
+
+        match raw.type_url.as_str() {
+            ETHEREUM_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: ETHEREUM_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
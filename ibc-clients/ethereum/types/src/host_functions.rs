@@ -0,0 +1,30 @@
+//! Defines the cryptographic primitives the Ethereum light client relies on,
+//! which are left for the host to provide
+
+use crate::bls::{BlsPublicKey, BlsSignature};
+
+/// Provides the hash functions and the BLS signature verification of the
+/// Ethereum light client.
+///
+/// Hosts typically back these with precompiles or native host calls, as BLS
+/// verification is too costly to run within most execution environments.
+/// Hashes may alternatively be backed by the `HostFunctionsManager` of
+/// `ics23`.
+pub trait EthereumHostFunctions {
+    /// Returns the SHA-256 digest of the message, which SSZ merkleization
+    /// hashes with.
+    fn sha256(message: &[u8]) -> [u8; 32];
+
+    /// Returns the Keccak-256 digest of the message, which the
+    /// Merkle-Patricia tries of the execution layer hash with.
+    fn keccak256(message: &[u8]) -> [u8; 32];
+
+    /// Returns whether the signature is a valid BLS12-381 aggregate signature
+    /// of the message by all the given public keys, as per the
+    /// `FastAggregateVerify` function of the consensus specifications.
+    fn bls_fast_aggregate_verify(
+        public_keys: &[&BlsPublicKey],
+        message: &[u8; 32],
+        signature: &BlsSignature,
+    ) -> bool;
+}
@@ -0,0 +1,244 @@
+//! Ethereum light client, which follows the finalized beacon chain through
+//! the sync committee light client protocol, and verifies the IBC state of
+//! the execution layer through Merkle-Patricia trie storage proofs.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+use core::str::FromStr;
+
+use ibc_core_host_types::identifiers::ClientType;
+use ibc_primitives::prelude::*;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod beacon;
+mod bls;
+mod client_state;
+mod consensus_state;
+mod header;
+mod host_functions;
+mod misbehaviour;
+mod proof;
+mod verify;
+
+pub use beacon::*;
+pub use bls::*;
+pub use client_state::*;
+pub use consensus_state::*;
+pub use header::*;
+pub use host_functions::*;
+pub use misbehaviour::*;
+pub use proof::*;
+pub use verify::*;
+
+pub mod error;
+pub mod mpt;
+pub mod raw;
+pub mod ssz;
+
+pub const ETHEREUM_CLIENT_TYPE: &str = "ethereum";
+
+/// Returns the Ethereum `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(ETHEREUM_CLIENT_TYPE).expect("Never fails because it's valid")
+}
+
+/// Converts the bytes into an array of `N` bytes, failing with the name of
+/// the decoded field if their length differs.
+pub(crate) fn to_array<const N: usize>(bytes: &[u8], name: &str) -> Result<[u8; N], error::Error> {
+    bytes.try_into().map_err(|_| error::Error::InvalidLength {
+        name: name.to_string(),
+        expected: N,
+        actual: bytes.len(),
+    })
+}
+
+/// Light client updates of a chain with the minimal preset, whose sync
+/// committees of 32 members sign with BLS keys derived from fixed seeds, so
+/// that their aggregate signatures verify as on the beacon chain.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use core::time::Duration;
+
+    use ibc_core_commitment_types::commitment::CommitmentRoot;
+    use ibc_core_commitment_types::proto::ics23::{HostFunctionsManager, HostFunctionsProvider};
+    use ibc_proto::Protobuf;
+
+    use super::*;
+
+    pub const GENESIS_VALIDATORS_ROOT: &str =
+        "44797095dc339c311d90c9679b8bf4b60048a576e5a71d70e5877d82475e5d5f";
+    pub const IBC_CONTRACT_ADDRESS: &str = "3b283d22b75aff56db33aed0f04a19fb16decb54";
+
+    /// Roots of the sync committees of the trusted period, of the next one,
+    /// and of the one after, which the header of the next period discloses.
+    pub const CURRENT_SYNC_COMMITTEE_ROOT: &str =
+        "a792ec3006727daa4409c897d03a7b080d421927c8490290d41033c237e784a1";
+    pub const NEXT_SYNC_COMMITTEE_ROOT: &str =
+        "2173f2fa1a8456b0c5450b38eb36944b9e38cd8cafa57bf463169c0bc8bedeb5";
+    pub const NEXT_NEXT_SYNC_COMMITTEE_ROOT: &str =
+        "60c78b2e4dedca721935ca0435095867e4d88c5a2f798d3e74dcaf2da98d365d";
+
+    pub const TRUSTED_SLOT: u64 = 72;
+
+    /// Header finalizing slot 88, in the trusted period.
+    pub const HEADER_CURRENT_PERIOD: &str = include_str!("../tests/data/header_current_period.hex");
+    /// Header finalizing slot 130, in the period following the trusted one.
+    pub const HEADER_NEXT_PERIOD: &str = include_str!("../tests/data/header_next_period.hex");
+    /// Header finalizing another beacon block header than
+    /// `HEADER_CURRENT_PERIOD` at the same slot.
+    pub const HEADER_CONFLICTING: &str = include_str!("../tests/data/header_conflicting.hex");
+    /// `HEADER_CURRENT_PERIOD`, signed by 20 members of the committee only.
+    pub const HEADER_MINORITY: &str = include_str!("../tests/data/header_minority.hex");
+
+    /// Storage root of the IBC contract at the slot of
+    /// `HEADER_CURRENT_PERIOD`, along with the proof of a commitment in it.
+    pub const STORAGE_ROOT: &str =
+        "afb523b293660f451fcccea3a882d6fe74849bbb738dada35e8a90625d8c4c92";
+    pub const STORAGE_PROOF: &str = include_str!("../tests/data/storage_proof.hex");
+    pub const COMMITMENT_PREFIX: &[u8] = b"ibc";
+    pub const COMMITMENT_PATH: &str = "commitments/ports/transfer/channels/channel-0/sequences/1";
+    pub const COMMITMENT_VALUE: &str =
+        "24527998d56331b30bb8ee4cae94fe0c5489144d5ecfbe5070193bcf89855d06";
+    pub const COMMITMENT_SLOT: &str =
+        "1f653fb5fca60978fa8475208d78bfd13aadc5ab36acbe1cb9fb562099676fc9";
+    /// RLP encoding of the digest of the commitment value, as stored.
+    pub const STORED_VALUE: &str =
+        "a06d1d2e51349695ac791744978bb337b5d5477fdf11e243ef7af52024ab59ba14";
+    /// A path without commitment, whose absence the storage proof also
+    /// proves.
+    pub const ABSENT_COMMITMENT_PATH: &str =
+        "commitments/ports/transfer/channels/channel-0/sequences/2";
+    pub const ABSENT_COMMITMENT_SLOT: &str =
+        "b26540db1e7879a7d4a9f5b1c305f100d7fb616fd6bc46c38f32df6910fb67ca";
+
+    /// Domain separation tag of the BLS signatures of the beacon chain.
+    const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    /// Host functions backed by `blst` for the BLS aggregate signatures of
+    /// the sync committees, as on the beacon chain.
+    pub struct TestHostFunctions;
+
+    impl EthereumHostFunctions for TestHostFunctions {
+        fn sha256(message: &[u8]) -> [u8; 32] {
+            HostFunctionsManager::sha2_256(message)
+        }
+
+        fn keccak256(message: &[u8]) -> [u8; 32] {
+            HostFunctionsManager::keccak_256(message)
+        }
+
+        fn bls_fast_aggregate_verify(
+            public_keys: &[&BlsPublicKey],
+            message: &[u8; 32],
+            signature: &BlsSignature,
+        ) -> bool {
+            let Ok(signature) = blst::min_pk::Signature::from_bytes(signature.as_bytes()) else {
+                return false;
+            };
+            let Ok(public_keys) = public_keys
+                .iter()
+                .map(|public_key| blst::min_pk::PublicKey::from_bytes(public_key.as_bytes()))
+                .collect::<Result<Vec<_>, _>>()
+            else {
+                return false;
+            };
+            let public_keys: Vec<_> = public_keys.iter().collect();
+
+            signature.fast_aggregate_verify(true, message, BLS_DST, &public_keys)
+                == blst::BLST_ERROR::BLST_SUCCESS
+        }
+    }
+
+    pub fn decode_hex(hex: &str) -> Vec<u8> {
+        let hex = hex.trim();
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    pub fn decode_root(hex: &str) -> [u8; 32] {
+        decode_hex(hex).try_into().unwrap()
+    }
+
+    /// Returns a client state of a chain with the minimal preset, i.e. with
+    /// 8 slots per epoch and 8 epochs per sync committee period, at the
+    /// Deneb fork from genesis.
+    pub fn client_state() -> ClientState {
+        let fork = |version: u8, epoch: u64| Fork {
+            version: [version, 0, 0, 1],
+            epoch,
+        };
+
+        ClientState {
+            chain_id: 1,
+            genesis_validators_root: decode_root(GENESIS_VALIDATORS_ROOT),
+            genesis_time: 1_700_000_000,
+            fork_parameters: ForkParameters {
+                genesis_fork_version: [0, 0, 0, 1],
+                altair: fork(1, 0),
+                bellatrix: fork(2, 0),
+                capella: fork(3, 0),
+                deneb: fork(4, 0),
+                electra: fork(5, u64::MAX),
+            },
+            seconds_per_slot: 6,
+            slots_per_epoch: 8,
+            epochs_per_sync_committee_period: 8,
+            min_sync_committee_participants: 16,
+            trusting_period: Duration::from_secs(24 * 60 * 60),
+            latest_slot: TRUSTED_SLOT,
+            frozen_height: None,
+            ibc_contract_address: decode_hex(IBC_CONTRACT_ADDRESS).try_into().unwrap(),
+            ibc_commitment_slot: {
+                let mut slot = [0u8; 32];
+                slot[31] = 1;
+                slot
+            },
+        }
+    }
+
+    /// Returns the consensus state the headers of the fixtures are verified
+    /// from.
+    pub fn trusted_consensus_state() -> ConsensusState {
+        ConsensusState {
+            slot: TRUSTED_SLOT,
+            state_root: [1u8; 32],
+            storage_root: CommitmentRoot::from_bytes(&[2u8; 32]),
+            timestamp: client_state().timestamp_at_slot(TRUSTED_SLOT).unwrap(),
+            current_sync_committee: decode_root(CURRENT_SYNC_COMMITTEE_ROOT),
+            next_sync_committee: Some(decode_root(NEXT_SYNC_COMMITTEE_ROOT)),
+        }
+    }
+
+    pub fn header(hex: &str) -> Header {
+        Protobuf::<raw::Header>::decode_vec(&decode_hex(hex)).unwrap()
+    }
+
+    pub fn storage_proof() -> StorageProof {
+        Protobuf::<raw::StorageProof>::decode_vec(&decode_hex(STORAGE_PROOF)).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensures that the validation in `ClientType::from_str` doesn't fail for the Ethereum client type
+    #[test]
+    pub fn test_ethereum_client_type() {
+        let _ = ClientType::from_str(ETHEREUM_CLIENT_TYPE).unwrap();
+    }
+}
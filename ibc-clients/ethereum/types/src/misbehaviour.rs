@@ -0,0 +1,142 @@
+//! Defines the misbehaviour type for the Ethereum light client
+
+use ibc_core_client_types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::header::Header;
+use crate::raw::{Header as RawHeader, Misbehaviour as RawMisbehaviour};
+
+pub const ETHEREUM_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.Misbehaviour";
+
+/// Ethereum light client's misbehaviour type: two headers finalizing
+/// different beacon block headers at the same slot, which the sync
+/// committee only signs if a majority of it is dishonest.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub header_1: Header,
+    pub header_2: Header,
+}
+
+impl Misbehaviour {
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.header_1.slot() != self.header_2.slot() {
+            return Err(Error::MisbehaviourSlotMismatch {
+                slot_1: self.header_1.slot(),
+                slot_2: self.header_2.slot(),
+            });
+        }
+
+        if self.header_1.consensus_update.finalized_header.beacon
+            == self.header_2.consensus_update.finalized_header.beacon
+        {
+            return Err(Error::IdenticalHeaders);
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        let header = |raw: Option<RawHeader>, name: &str| -> Result<Header, Error> {
+            raw.ok_or_else(|| Error::InvalidRawMisbehaviour {
+                reason: format!("missing {name}"),
+            })?
+            .try_into()
+        };
+
+        let misbehaviour = Self {
+            header_1: header(raw.header_1, "header 1")?,
+            header_2: header(raw.header_2, "header 2")?,
+        };
+
+        misbehaviour.validate_basic()?;
+
+        Ok(misbehaviour)
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            header_1: Some(value.header_1.into()),
+            header_2: Some(value.header_2.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, ClientError> {
+        fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
+            let misbehaviour =
+                Protobuf::<RawMisbehaviour>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(misbehaviour)
+        }
+
+        match raw.type_url.as_str() {
+            ETHEREUM_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(&raw.value),
+            _ => Err(ClientError::UnknownMisbehaviourType {
+                misbehaviour_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: ETHEREUM_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn test_misbehaviour_validate_basic() {
+        let misbehaviour = Misbehaviour {
+            header_1: header(HEADER_CURRENT_PERIOD),
+            header_2: header(HEADER_CONFLICTING),
+        };
+        misbehaviour.validate_basic().unwrap();
+
+        let any: Any = misbehaviour.clone().into();
+        assert_eq!(Misbehaviour::try_from(any).unwrap(), misbehaviour);
+
+        let identical = Misbehaviour {
+            header_2: misbehaviour.header_1.clone(),
+            ..misbehaviour.clone()
+        };
+        assert!(matches!(
+            identical.validate_basic(),
+            Err(Error::IdenticalHeaders)
+        ));
+
+        let different_slots = Misbehaviour {
+            header_2: header(HEADER_NEXT_PERIOD),
+            ..misbehaviour
+        };
+        assert!(matches!(
+            different_slots.validate_basic(),
+            Err(Error::MisbehaviourSlotMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,358 @@
+//! Implements the verification of the Merkle-Patricia trie proofs of the
+//! execution layer, as returned by the `eth_getProof` RPC method, along with
+//! the RLP decoding of their nodes.
+
+use core::mem::size_of;
+
+use ibc_primitives::prelude::*;
+
+use crate::error::Error;
+use crate::host_functions::EthereumHostFunctions;
+
+/// The root of an empty trie, i.e. the Keccak-256 digest of the RLP encoding
+/// of an empty string.
+pub const EMPTY_TRIE_ROOT: [u8; 32] = [
+    0x56, 0xe8, 0x1f, 0x17, 0x1b, 0xcc, 0x55, 0xa6, 0xff, 0x83, 0x45, 0xe6, 0x92, 0xc0, 0xf8, 0x6e,
+    0x5b, 0x48, 0xe0, 0x1b, 0x99, 0x6c, 0xad, 0xc0, 0x01, 0x62, 0x2f, 0xb5, 0xe3, 0x63, 0xb4, 0x21,
+];
+
+/// Verifies the proof of the value at the given key of the trie with the
+/// given root, returning the value, or `None` if the proof shows the key to
+/// be absent.
+///
+/// The proof lists the RLP-encoded nodes of the path to the key, starting
+/// from the root node. Nodes shorter than 32 bytes are embedded within their
+/// parent rather than listed.
+pub fn verify_proof<H: EthereumHostFunctions>(
+    root: &[u8; 32],
+    key: &[u8],
+    proof: &[Vec<u8>],
+) -> Result<Option<Vec<u8>>, Error> {
+    let nibbles: Vec<u8> = key.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect();
+
+    let mut proof = proof.iter();
+    let mut expected = NodeRef::Hash(*root);
+    let mut pos = 0;
+
+    loop {
+        let node = match expected {
+            NodeRef::Hash(hash) => {
+                let Some(node) = proof.next() else {
+                    if hash == EMPTY_TRIE_ROOT {
+                        return Ok(None);
+                    }
+                    return Err(Error::MissingTrieNode);
+                };
+                if H::keccak256(node) != hash {
+                    return Err(Error::TrieNodeHashMismatch);
+                }
+                node.as_slice()
+            }
+            NodeRef::Inline(node) => node,
+        };
+
+        let items = rlp::decode_list(node)?;
+
+        match items.as_slice() {
+            [branches @ .., value] if branches.len() == 16 => {
+                let Some(&nibble) = nibbles.get(pos) else {
+                    let value = value.as_string()?;
+                    return Ok((!value.is_empty()).then(|| value.to_vec()));
+                };
+                pos += 1;
+
+                match node_ref(&branches[usize::from(nibble)])? {
+                    Some(child) => expected = child,
+                    None => return Ok(None),
+                }
+            }
+            [path, child] => {
+                let (path, is_leaf) = decode_compact_path(path.as_string()?)?;
+                let remaining = nibbles.get(pos..).unwrap_or_default();
+
+                if is_leaf {
+                    if remaining == path.as_slice() {
+                        return Ok(Some(child.as_string()?.to_vec()));
+                    }
+                    return Ok(None);
+                }
+
+                if !remaining.starts_with(&path) {
+                    return Ok(None);
+                }
+                pos += path.len();
+
+                match node_ref(child)? {
+                    Some(child) => expected = child,
+                    None => {
+                        return Err(Error::InvalidTrieNode {
+                            reason: "extension without child".to_string(),
+                        })
+                    }
+                }
+            }
+            _ => {
+                return Err(Error::InvalidTrieNode {
+                    reason: format!("node of {} items", items.len()),
+                })
+            }
+        }
+    }
+}
+
+/// A reference from a node to its child.
+enum NodeRef<'a> {
+    /// The hash of a child listed in the proof.
+    Hash([u8; 32]),
+    /// A child embedded in its parent.
+    Inline(&'a [u8]),
+}
+
+/// Returns the reference to the child in the given item, if any.
+fn node_ref<'a>(item: &rlp::Item<'a>) -> Result<Option<NodeRef<'a>>, Error> {
+    match item {
+        rlp::Item::String([]) => Ok(None),
+        rlp::Item::String(bytes) => {
+            let hash = (*bytes).try_into().map_err(|_| Error::InvalidTrieNode {
+                reason: format!("child reference of {} bytes", bytes.len()),
+            })?;
+            Ok(Some(NodeRef::Hash(hash)))
+        }
+        rlp::Item::List { raw, .. } => Ok(Some(NodeRef::Inline(raw))),
+    }
+}
+
+/// Decodes the hex-prefix encoded path of a leaf or extension node into its
+/// nibbles, along with whether the node is a leaf.
+fn decode_compact_path(encoded: &[u8]) -> Result<(Vec<u8>, bool), Error> {
+    let Some((&first, rest)) = encoded.split_first() else {
+        return Err(Error::InvalidTrieNode {
+            reason: "empty path".to_string(),
+        });
+    };
+
+    let flag = first >> 4;
+
+    if flag > 3 {
+        return Err(Error::InvalidTrieNode {
+            reason: format!("invalid path flag {flag}"),
+        });
+    }
+
+    let mut path = Vec::with_capacity(rest.len() * 2 + 1);
+    if flag & 1 == 1 {
+        path.push(first & 0x0f);
+    }
+    path.extend(rest.iter().flat_map(|b| [b >> 4, b & 0x0f]));
+
+    Ok((path, flag & 2 == 2))
+}
+
+/// Implements the decoding of the RLP items the trie nodes and accounts are
+/// made of, and the encoding of the storage values.
+pub mod rlp {
+    use super::*;
+
+    /// A decoded RLP item.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Item<'a> {
+        /// A byte string.
+        String(&'a [u8]),
+        /// A list, along with its whole encoding and the encoding of its
+        /// items.
+        List { raw: &'a [u8], payload: &'a [u8] },
+    }
+
+    impl<'a> Item<'a> {
+        /// Returns the bytes of the item if it is a string.
+        pub fn as_string(&self) -> Result<&'a [u8], Error> {
+            match self {
+                Self::String(bytes) => Ok(bytes),
+                Self::List { .. } => Err(invalid("expected a string, got a list")),
+            }
+        }
+    }
+
+    /// Decodes the items of the list encoded by the given bytes, which must
+    /// not contain anything past the list.
+    pub fn decode_list(bytes: &[u8]) -> Result<Vec<Item<'_>>, Error> {
+        let (item, rest) = decode_item(bytes)?;
+
+        if !rest.is_empty() {
+            return Err(invalid("trailing bytes"));
+        }
+
+        let Item::List { mut payload, .. } = item else {
+            return Err(invalid("expected a list, got a string"));
+        };
+
+        let mut items = Vec::new();
+        while !payload.is_empty() {
+            let (item, rest) = decode_item(payload)?;
+            items.push(item);
+            payload = rest;
+        }
+
+        Ok(items)
+    }
+
+    /// Decodes the first item of the given bytes, returning it along with the
+    /// bytes following it.
+    pub fn decode_item(bytes: &[u8]) -> Result<(Item<'_>, &[u8]), Error> {
+        let Some(&prefix) = bytes.first() else {
+            return Err(invalid("unexpected end of input"));
+        };
+
+        let (header_len, payload_len, is_list) = match prefix {
+            0x00..=0x7f => {
+                let (item, rest) = bytes.split_at(1);
+                return Ok((Item::String(item), rest));
+            }
+            0x80..=0xb7 => (1, usize::from(prefix - 0x80), false),
+            0xb8..=0xbf => {
+                let len_of_len = usize::from(prefix - 0xb7);
+                (1 + len_of_len, decode_length(bytes, len_of_len)?, false)
+            }
+            0xc0..=0xf7 => (1, usize::from(prefix - 0xc0), true),
+            0xf8..=0xff => {
+                let len_of_len = usize::from(prefix - 0xf7);
+                (1 + len_of_len, decode_length(bytes, len_of_len)?, true)
+            }
+        };
+
+        let end = header_len
+            .checked_add(payload_len)
+            .filter(|end| *end <= bytes.len())
+            .ok_or_else(|| invalid("unexpected end of input"))?;
+
+        let (raw, rest) = bytes.split_at(end);
+        let payload = raw.get(header_len..).unwrap_or_default();
+
+        let item = if is_list {
+            Item::List { raw, payload }
+        } else {
+            Item::String(payload)
+        };
+
+        Ok((item, rest))
+    }
+
+    /// Decodes the big-endian length of `len_of_len` bytes following the
+    /// prefix of the item.
+    fn decode_length(bytes: &[u8], len_of_len: usize) -> Result<usize, Error> {
+        let len_bytes = bytes
+            .get(1..1 + len_of_len)
+            .ok_or_else(|| invalid("unexpected end of input"))?;
+
+        if len_bytes.len() > size_of::<usize>() {
+            return Err(invalid("length overflow"));
+        }
+
+        Ok(len_bytes
+            .iter()
+            .fold(0, |len, byte| (len << 8) | usize::from(*byte)))
+    }
+
+    /// Encodes the bytes as an RLP string.
+    pub fn encode_string(bytes: &[u8]) -> Vec<u8> {
+        match bytes {
+            [byte] if *byte < 0x80 => vec![*byte],
+            _ if bytes.len() <= 55 => {
+                let mut encoded = Vec::with_capacity(bytes.len() + 1);
+                encoded.push(0x80 + bytes.len() as u8);
+                encoded.extend_from_slice(bytes);
+                encoded
+            }
+            _ => {
+                let len = bytes.len().to_be_bytes();
+                let len = &len[len.iter().take_while(|b| **b == 0).count()..];
+                let mut encoded = Vec::with_capacity(bytes.len() + len.len() + 1);
+                encoded.push(0xb7 + len.len() as u8);
+                encoded.extend_from_slice(len);
+                encoded.extend_from_slice(bytes);
+                encoded
+            }
+        }
+    }
+
+    fn invalid(reason: &str) -> Error {
+        Error::InvalidRlp {
+            reason: reason.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{decode_hex, TestHostFunctions};
+
+    #[test]
+    fn test_rlp_roundtrip() {
+        for len in [0, 1, 55, 56, 300] {
+            let bytes = vec![0xab; len];
+            let encoded = rlp::encode_string(&bytes);
+            let (item, rest) = rlp::decode_item(&encoded).unwrap();
+            assert_eq!(item, rlp::Item::String(&bytes));
+            assert!(rest.is_empty());
+        }
+
+        assert_eq!(rlp::encode_string(&[0x7f]), vec![0x7f]);
+        assert_eq!(rlp::encode_string(&[0x80]), vec![0x81, 0x80]);
+    }
+
+    #[test]
+    fn test_rlp_rejects_truncated_input() {
+        assert!(rlp::decode_item(&[0x83, 0x01, 0x02]).is_err());
+        assert!(rlp::decode_item(&[0xb9, 0x01]).is_err());
+        assert!(rlp::decode_list(&[0xc2, 0x01]).is_err());
+        assert!(rlp::decode_list(&[0x01]).is_err());
+        assert!(rlp::decode_list(&[0xc1, 0x01, 0x02]).is_err());
+    }
+
+    #[test]
+    fn test_empty_trie() {
+        let key = [0u8; 32];
+
+        assert_eq!(
+            TestHostFunctions::keccak256(&[0x80]),
+            EMPTY_TRIE_ROOT,
+            "empty trie root is the hash of an empty string"
+        );
+        assert_eq!(
+            verify_proof::<TestHostFunctions>(&EMPTY_TRIE_ROOT, &key, &[]).unwrap(),
+            None
+        );
+        assert!(verify_proof::<TestHostFunctions>(&[1u8; 32], &key, &[]).is_err());
+    }
+
+    #[test]
+    fn test_verify_proof() {
+        use crate::fixtures::{
+            decode_root, storage_proof, ABSENT_COMMITMENT_SLOT, COMMITMENT_SLOT, STORAGE_ROOT,
+            STORED_VALUE,
+        };
+
+        let root = decode_root(STORAGE_ROOT);
+        let proof = storage_proof().proof;
+        let key = TestHostFunctions::keccak256(&decode_hex(COMMITMENT_SLOT));
+
+        let value = verify_proof::<TestHostFunctions>(&root, &key, &proof).unwrap();
+        assert_eq!(value, Some(decode_hex(STORED_VALUE)));
+
+        let absent = verify_proof::<TestHostFunctions>(
+            &root,
+            &TestHostFunctions::keccak256(&decode_hex(ABSENT_COMMITMENT_SLOT)),
+            &proof,
+        )
+        .unwrap();
+        assert_eq!(absent, None);
+
+        // Tampering with any node breaks the proof.
+        let mut tampered = proof.clone();
+        let last = tampered.last_mut().unwrap();
+        let byte = last.last_mut().unwrap();
+        *byte ^= 1;
+        assert!(verify_proof::<TestHostFunctions>(&root, &key, &tampered).is_err());
+    }
+}
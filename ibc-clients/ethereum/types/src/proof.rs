@@ -0,0 +1,221 @@
+//! Defines the proofs of the storage of the IBC contract, which the client
+//! verifies the commitments of the counterparty against
+
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::host_functions::EthereumHostFunctions;
+use crate::mpt::{rlp, verify_proof};
+use crate::raw::{AccountProof as RawAccountProof, StorageProof as RawStorageProof};
+use crate::to_array;
+
+/// The proof of the account of the IBC contract against the state root of
+/// the execution layer, disclosing its storage root.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountProof {
+    pub storage_root: [u8; 32],
+    pub proof: Vec<Vec<u8>>,
+}
+
+impl AccountProof {
+    /// Verifies that the account at the given address has the storage root
+    /// of the proof.
+    pub fn verify<H: EthereumHostFunctions>(
+        &self,
+        state_root: &[u8; 32],
+        address: &[u8; 20],
+    ) -> Result<(), Error> {
+        let account = verify_proof::<H>(state_root, &H::keccak256(address), &self.proof)?
+            .ok_or(Error::MissingAccount)?;
+
+        // An account is the list of its nonce, balance, storage root and
+        // code hash.
+        let account = rlp::decode_list(&account)?;
+        let [_, _, storage_root, _] = account.as_slice() else {
+            return Err(Error::InvalidTrieNode {
+                reason: format!("account of {} items", account.len()),
+            });
+        };
+
+        if storage_root.as_string()? != self.storage_root.as_slice() {
+            return Err(Error::StorageRootMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawAccountProof> for AccountProof {}
+
+impl TryFrom<RawAccountProof> for AccountProof {
+    type Error = Error;
+
+    fn try_from(raw: RawAccountProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            storage_root: to_array(&raw.storage_root, "storage root")?,
+            proof: raw.proof,
+        })
+    }
+}
+
+impl From<AccountProof> for RawAccountProof {
+    fn from(value: AccountProof) -> Self {
+        Self {
+            storage_root: value.storage_root.to_vec(),
+            proof: value.proof,
+        }
+    }
+}
+
+/// The proof of a slot against the storage root of the IBC contract.
+///
+/// The IBC contract stores the Keccak-256 digest of the value committed to at
+/// an ICS-24 path in the slot returned by [`commitment_slot`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageProof {
+    pub proof: Vec<Vec<u8>>,
+}
+
+impl StorageProof {
+    /// Verifies that the slot holds the digest of the value.
+    pub fn verify_membership<H: EthereumHostFunctions>(
+        &self,
+        storage_root: &[u8; 32],
+        slot: &[u8; 32],
+        value: &[u8],
+    ) -> Result<(), Error> {
+        let stored = verify_proof::<H>(storage_root, &H::keccak256(slot), &self.proof)?
+            .ok_or(Error::MissingValue)?;
+
+        // Storage values are RLP-encoded without their leading zeros.
+        let digest = H::keccak256(value);
+        let trimmed = &digest[digest.iter().take_while(|b| **b == 0).count()..];
+
+        if stored != rlp::encode_string(trimmed) {
+            return Err(Error::ValueMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that the slot is empty.
+    pub fn verify_non_membership<H: EthereumHostFunctions>(
+        &self,
+        storage_root: &[u8; 32],
+        slot: &[u8; 32],
+    ) -> Result<(), Error> {
+        match verify_proof::<H>(storage_root, &H::keccak256(slot), &self.proof)? {
+            Some(_) => Err(Error::UnexpectedValue),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Protobuf<RawStorageProof> for StorageProof {}
+
+impl TryFrom<RawStorageProof> for StorageProof {
+    type Error = Error;
+
+    fn try_from(raw: RawStorageProof) -> Result<Self, Self::Error> {
+        Ok(Self { proof: raw.proof })
+    }
+}
+
+impl From<StorageProof> for RawStorageProof {
+    fn from(value: StorageProof) -> Self {
+        Self { proof: value.proof }
+    }
+}
+
+impl TryFrom<&CommitmentProofBytes> for StorageProof {
+    type Error = Error;
+
+    fn try_from(value: &CommitmentProofBytes) -> Result<Self, Self::Error> {
+        Protobuf::<RawStorageProof>::decode_vec(value.as_ref()).map_err(|e| {
+            Error::InvalidRawProof {
+                reason: e.to_string(),
+            }
+        })
+    }
+}
+
+/// Returns the storage slot of the IBC contract holding the commitment at
+/// the given prefixed path, i.e. the slot of the path in the mapping stored
+/// at `mapping_slot`, as laid out by Solidity.
+pub fn commitment_slot<H: EthereumHostFunctions>(
+    prefix: &CommitmentPrefix,
+    path: &[u8],
+    mapping_slot: &[u8; 32],
+) -> [u8; 32] {
+    let mut prefixed_path = prefix.as_bytes().to_vec();
+    prefixed_path.extend_from_slice(path);
+
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(&H::keccak256(&prefixed_path));
+    buf[32..].copy_from_slice(mapping_slot);
+    H::keccak256(&buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn test_commitment_slot() {
+        let prefix = CommitmentPrefix::try_from(COMMITMENT_PREFIX.to_vec()).unwrap();
+        let mapping_slot = client_state().ibc_commitment_slot;
+
+        assert_eq!(
+            commitment_slot::<TestHostFunctions>(
+                &prefix,
+                COMMITMENT_PATH.as_bytes(),
+                &mapping_slot
+            )
+            .to_vec(),
+            decode_hex(COMMITMENT_SLOT)
+        );
+        assert_eq!(
+            commitment_slot::<TestHostFunctions>(
+                &prefix,
+                ABSENT_COMMITMENT_PATH.as_bytes(),
+                &mapping_slot
+            )
+            .to_vec(),
+            decode_hex(ABSENT_COMMITMENT_SLOT)
+        );
+    }
+
+    #[test]
+    fn test_storage_proof() {
+        let proof = storage_proof();
+        let root = decode_root(STORAGE_ROOT);
+        let slot = decode_root(COMMITMENT_SLOT);
+        let absent_slot = decode_root(ABSENT_COMMITMENT_SLOT);
+        let value = decode_hex(COMMITMENT_VALUE);
+
+        proof
+            .verify_membership::<TestHostFunctions>(&root, &slot, &value)
+            .unwrap();
+        proof
+            .verify_non_membership::<TestHostFunctions>(&root, &absent_slot)
+            .unwrap();
+
+        assert!(matches!(
+            proof.verify_membership::<TestHostFunctions>(&root, &slot, b"other value"),
+            Err(Error::ValueMismatch)
+        ));
+        assert!(matches!(
+            proof.verify_membership::<TestHostFunctions>(&root, &absent_slot, &value),
+            Err(Error::MissingValue)
+        ));
+        assert!(matches!(
+            proof.verify_non_membership::<TestHostFunctions>(&root, &slot),
+            Err(Error::UnexpectedValue)
+        ));
+    }
+}
@@ -0,0 +1,296 @@
+//! Protobuf definitions of the Ethereum light client messages, in the
+//! `ibc.lightclients.ethereum.v1` package. Roots, hashes and keys are encoded
+//! as raw bytes, and timestamps as seconds since the Unix epoch, as on the
+//! beacon chain.
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Duration;
+use ibc_proto::ibc::core::client::v1::Height;
+
+const PACKAGE: &str = "ibc.lightclients.ethereum.v1";
+
+/// The parameters of the beacon chain followed by the client, along with the
+/// IBC contract whose storage it verifies proofs against.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(uint64, tag = "1")]
+    pub chain_id: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub genesis_validators_root: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub genesis_time: u64,
+    #[prost(message, optional, tag = "4")]
+    pub fork_parameters: Option<ForkParameters>,
+    #[prost(uint64, tag = "5")]
+    pub seconds_per_slot: u64,
+    #[prost(uint64, tag = "6")]
+    pub slots_per_epoch: u64,
+    #[prost(uint64, tag = "7")]
+    pub epochs_per_sync_committee_period: u64,
+    #[prost(uint64, tag = "8")]
+    pub min_sync_committee_participants: u64,
+    #[prost(message, optional, tag = "9")]
+    pub trusting_period: Option<Duration>,
+    #[prost(uint64, tag = "10")]
+    pub latest_slot: u64,
+    #[prost(message, optional, tag = "11")]
+    pub frozen_height: Option<Height>,
+    #[prost(bytes = "vec", tag = "12")]
+    pub ibc_contract_address: Vec<u8>,
+    #[prost(bytes = "vec", tag = "13")]
+    pub ibc_commitment_slot: Vec<u8>,
+}
+
+impl prost::Name for ClientState {
+    const NAME: &'static str = "ClientState";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+/// The fork versions of the beacon chain, along with the epochs they
+/// activate at.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ForkParameters {
+    #[prost(bytes = "vec", tag = "1")]
+    pub genesis_fork_version: Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub altair: Option<Fork>,
+    #[prost(message, optional, tag = "3")]
+    pub bellatrix: Option<Fork>,
+    #[prost(message, optional, tag = "4")]
+    pub capella: Option<Fork>,
+    #[prost(message, optional, tag = "5")]
+    pub deneb: Option<Fork>,
+    #[prost(message, optional, tag = "6")]
+    pub electra: Option<Fork>,
+}
+
+impl prost::Name for ForkParameters {
+    const NAME: &'static str = "ForkParameters";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Fork {
+    #[prost(bytes = "vec", tag = "1")]
+    pub version: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub epoch: u64,
+}
+
+impl prost::Name for Fork {
+    const NAME: &'static str = "Fork";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+/// The state of the chain at a finalized slot, along with the roots of the
+/// sync committees of its period.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    #[prost(uint64, tag = "1")]
+    pub slot: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub storage_root: Vec<u8>,
+    #[prost(uint64, tag = "4")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "5")]
+    pub current_sync_committee: Vec<u8>,
+    /// Empty if the next sync committee is unknown.
+    #[prost(bytes = "vec", tag = "6")]
+    pub next_sync_committee: Vec<u8>,
+}
+
+impl prost::Name for ConsensusState {
+    const NAME: &'static str = "ConsensusState";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BeaconBlockHeader {
+    #[prost(uint64, tag = "1")]
+    pub slot: u64,
+    #[prost(uint64, tag = "2")]
+    pub proposer_index: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub parent_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub body_root: Vec<u8>,
+}
+
+impl prost::Name for BeaconBlockHeader {
+    const NAME: &'static str = "BeaconBlockHeader";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+/// The execution payload header, as of the latest supported fork. The blob
+/// gas fields are ignored before Deneb.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ExecutionPayloadHeader {
+    #[prost(bytes = "vec", tag = "1")]
+    pub parent_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub fee_recipient: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub receipts_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub logs_bloom: Vec<u8>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub prev_randao: Vec<u8>,
+    #[prost(uint64, tag = "7")]
+    pub block_number: u64,
+    #[prost(uint64, tag = "8")]
+    pub gas_limit: u64,
+    #[prost(uint64, tag = "9")]
+    pub gas_used: u64,
+    #[prost(uint64, tag = "10")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "11")]
+    pub extra_data: Vec<u8>,
+    /// Little-endian 256-bit integer, as in SSZ.
+    #[prost(bytes = "vec", tag = "12")]
+    pub base_fee_per_gas: Vec<u8>,
+    #[prost(bytes = "vec", tag = "13")]
+    pub block_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "14")]
+    pub transactions_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "15")]
+    pub withdrawals_root: Vec<u8>,
+    #[prost(uint64, tag = "16")]
+    pub blob_gas_used: u64,
+    #[prost(uint64, tag = "17")]
+    pub excess_blob_gas: u64,
+}
+
+impl prost::Name for ExecutionPayloadHeader {
+    const NAME: &'static str = "ExecutionPayloadHeader";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LightClientHeader {
+    #[prost(message, optional, tag = "1")]
+    pub beacon: Option<BeaconBlockHeader>,
+    #[prost(message, optional, tag = "2")]
+    pub execution: Option<ExecutionPayloadHeader>,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub execution_branch: Vec<Vec<u8>>,
+}
+
+impl prost::Name for LightClientHeader {
+    const NAME: &'static str = "LightClientHeader";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SyncCommittee {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub pubkeys: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub aggregate_pubkey: Vec<u8>,
+}
+
+impl prost::Name for SyncCommittee {
+    const NAME: &'static str = "SyncCommittee";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SyncAggregate {
+    #[prost(bytes = "vec", tag = "1")]
+    pub sync_committee_bits: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub sync_committee_signature: Vec<u8>,
+}
+
+impl prost::Name for SyncAggregate {
+    const NAME: &'static str = "SyncAggregate";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+/// A light client update of the beacon chain, finalizing a header attested
+/// to by the sync committee.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LightClientUpdate {
+    #[prost(message, optional, tag = "1")]
+    pub attested_header: Option<BeaconBlockHeader>,
+    /// Absent if the update does not disclose the next sync committee.
+    #[prost(message, optional, tag = "2")]
+    pub next_sync_committee: Option<SyncCommittee>,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub next_sync_committee_branch: Vec<Vec<u8>>,
+    #[prost(message, optional, tag = "4")]
+    pub finalized_header: Option<LightClientHeader>,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub finality_branch: Vec<Vec<u8>>,
+    #[prost(message, optional, tag = "6")]
+    pub sync_aggregate: Option<SyncAggregate>,
+    #[prost(uint64, tag = "7")]
+    pub signature_slot: u64,
+}
+
+impl prost::Name for LightClientUpdate {
+    const NAME: &'static str = "LightClientUpdate";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+/// The proof of the account of the IBC contract against the execution state
+/// root.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AccountProof {
+    #[prost(bytes = "vec", tag = "1")]
+    pub storage_root: Vec<u8>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub proof: Vec<Vec<u8>>,
+}
+
+impl prost::Name for AccountProof {
+    const NAME: &'static str = "AccountProof";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+/// The proof of a slot against the storage root of the IBC contract, which
+/// the commitment proofs of the client encode.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StorageProof {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub proof: Vec<Vec<u8>>,
+}
+
+impl prost::Name for StorageProof {
+    const NAME: &'static str = "StorageProof";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    #[prost(message, optional, tag = "1")]
+    pub trusted_height: Option<Height>,
+    #[prost(message, optional, tag = "2")]
+    pub trusted_sync_committee: Option<SyncCommittee>,
+    #[prost(message, optional, tag = "3")]
+    pub consensus_update: Option<LightClientUpdate>,
+    #[prost(message, optional, tag = "4")]
+    pub account_update: Option<AccountProof>,
+}
+
+impl prost::Name for Header {
+    const NAME: &'static str = "Header";
+    const PACKAGE: &'static str = PACKAGE;
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    #[prost(message, optional, tag = "1")]
+    pub header_1: Option<Header>,
+    #[prost(message, optional, tag = "2")]
+    pub header_2: Option<Header>,
+}
+
+impl prost::Name for Misbehaviour {
+    const NAME: &'static str = "Misbehaviour";
+    const PACKAGE: &'static str = PACKAGE;
+}
@@ -0,0 +1,190 @@
+//! Implements the SSZ merkleization the beacon chain commits to its objects
+//! with, along with the verification of the Merkle branches of their fields.
+//!
+//! Only the fixed-size types and the byte lists of the light client objects
+//! are supported.
+
+use ibc_primitives::prelude::*;
+
+use crate::host_functions::EthereumHostFunctions;
+
+/// A 32-byte SSZ chunk, and thus a node of a Merkle tree.
+pub type Chunk = [u8; 32];
+
+/// Returns the hash of the concatenation of both nodes.
+pub fn hash_pair<H: EthereumHostFunctions>(left: &Chunk, right: &Chunk) -> Chunk {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(left);
+    buf[32..].copy_from_slice(right);
+    H::sha256(&buf)
+}
+
+/// Returns the root of the Merkle tree whose leaves are the chunks, padded
+/// with zero chunks up to the power of two following `limit`.
+///
+/// The limit is raised to the number of chunks if below it.
+pub fn merkleize<H: EthereumHostFunctions>(chunks: &[Chunk], limit: usize) -> Chunk {
+    let width = limit.max(chunks.len()).max(1).next_power_of_two();
+
+    let mut zero = [0u8; 32];
+
+    if chunks.is_empty() {
+        for _ in 0..width.trailing_zeros() {
+            zero = hash_pair::<H>(&zero, &zero);
+        }
+        return zero;
+    }
+
+    let mut layer = chunks.to_vec();
+
+    for _ in 0..width.trailing_zeros() {
+        if layer.len() % 2 == 1 {
+            layer.push(zero);
+        }
+        layer = layer
+            .chunks_exact(2)
+            .map(|pair| hash_pair::<H>(&pair[0], &pair[1]))
+            .collect();
+        zero = hash_pair::<H>(&zero, &zero);
+    }
+
+    layer[0]
+}
+
+/// Returns the chunk of a `uint64`, i.e. its little-endian bytes padded with
+/// zeros.
+pub fn uint64_chunk(value: u64) -> Chunk {
+    let mut chunk = [0u8; 32];
+    chunk[..8].copy_from_slice(&value.to_le_bytes());
+    chunk
+}
+
+/// Splits the bytes into chunks, padding the last one with zeros.
+pub fn pack_bytes(bytes: &[u8]) -> Vec<Chunk> {
+    bytes
+        .chunks(32)
+        .map(|bytes| {
+            let mut chunk = [0u8; 32];
+            chunk[..bytes.len()].copy_from_slice(bytes);
+            chunk
+        })
+        .collect()
+}
+
+/// Returns the root of a list from the root of its elements and its length.
+pub fn mix_in_length<H: EthereumHostFunctions>(root: &Chunk, length: usize) -> Chunk {
+    hash_pair::<H>(root, &uint64_chunk(length as u64))
+}
+
+/// Returns the root of a fixed-size byte vector.
+pub fn bytes_root<H: EthereumHostFunctions>(bytes: &[u8]) -> Chunk {
+    let chunks = pack_bytes(bytes);
+    merkleize::<H>(&chunks, chunks.len())
+}
+
+/// Returns the root of a byte list of at most `limit` bytes.
+pub fn byte_list_root<H: EthereumHostFunctions>(bytes: &[u8], limit: usize) -> Chunk {
+    let root = merkleize::<H>(&pack_bytes(bytes), (limit + 31) / 32);
+    mix_in_length::<H>(&root, bytes.len())
+}
+
+/// Returns whether the branch proves the leaf at the generalized index
+/// `gindex` of the tree with the given root, as per the
+/// `is_valid_merkle_branch` function of the consensus specifications.
+///
+/// The branch lists the siblings of the path from the leaf up to the root,
+/// and must thus be as long as the depth of the generalized index.
+pub fn is_valid_merkle_branch<H: EthereumHostFunctions>(
+    leaf: &Chunk,
+    branch: &[Chunk],
+    gindex: u64,
+    root: &Chunk,
+) -> bool {
+    if gindex == 0 {
+        return false;
+    }
+
+    let depth = (63 - gindex.leading_zeros()) as usize;
+
+    if branch.len() != depth {
+        return false;
+    }
+
+    let mut node = *leaf;
+
+    for (i, sibling) in branch.iter().enumerate() {
+        node = if (gindex >> i) & 1 == 1 {
+            hash_pair::<H>(sibling, &node)
+        } else {
+            hash_pair::<H>(&node, sibling)
+        };
+    }
+
+    node == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{decode_hex, TestHostFunctions};
+
+    #[test]
+    fn test_merkleize() {
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+        let c = [3u8; 32];
+
+        let ab = hash_pair::<TestHostFunctions>(&a, &b);
+        let c0 = hash_pair::<TestHostFunctions>(&c, &[0u8; 32]);
+
+        assert_eq!(merkleize::<TestHostFunctions>(&[a], 1), a);
+        assert_eq!(merkleize::<TestHostFunctions>(&[a, b], 2), ab);
+        assert_eq!(
+            merkleize::<TestHostFunctions>(&[a, b, c], 3),
+            hash_pair::<TestHostFunctions>(&ab, &c0)
+        );
+
+        // Limits pad the tree with zero subtrees.
+        let zero_1 = hash_pair::<TestHostFunctions>(&[0u8; 32], &[0u8; 32]);
+        assert_eq!(
+            merkleize::<TestHostFunctions>(&[a, b], 4),
+            hash_pair::<TestHostFunctions>(&ab, &zero_1)
+        );
+        assert_eq!(merkleize::<TestHostFunctions>(&[], 2), zero_1);
+    }
+
+    #[test]
+    fn test_byte_list_root() {
+        // Root of an empty `ByteList[32]`, i.e. the hash of two zero chunks.
+        assert_eq!(
+            byte_list_root::<TestHostFunctions>(&[], 32).to_vec(),
+            decode_hex("f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b")
+        );
+    }
+
+    #[test]
+    fn test_is_valid_merkle_branch() {
+        let leaves = [[1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]];
+        let root = merkleize::<TestHostFunctions>(&leaves, 4);
+        let right = hash_pair::<TestHostFunctions>(&leaves[2], &leaves[3]);
+
+        // The second leaf of a tree of depth 2 is at generalized index 5.
+        let branch = [leaves[0], right];
+
+        assert!(is_valid_merkle_branch::<TestHostFunctions>(
+            &leaves[1], &branch, 5, &root
+        ));
+        assert!(!is_valid_merkle_branch::<TestHostFunctions>(
+            &leaves[1], &branch, 4, &root
+        ));
+        assert!(!is_valid_merkle_branch::<TestHostFunctions>(
+            &leaves[0], &branch, 5, &root
+        ));
+        assert!(!is_valid_merkle_branch::<TestHostFunctions>(
+            &leaves[1],
+            &branch[..1],
+            5,
+            &root
+        ));
+    }
+}
@@ -0,0 +1,408 @@
+//! Implements the verification of Ethereum headers against a trusted
+//! consensus state, following the `validate_light_client_update` and
+//! `apply_light_client_update` functions of the consensus specifications.
+
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::Timestamp;
+
+use crate::beacon::{compute_signing_root, compute_sync_committee_domain};
+use crate::client_state::ClientState;
+use crate::consensus_state::ConsensusState;
+use crate::error::Error;
+use crate::header::Header;
+use crate::host_functions::EthereumHostFunctions;
+
+/// Verifies the header against the trusted consensus state at the current
+/// time, and returns the consensus state it updates the client to.
+///
+/// The update must be signed by the sync committee of the period of the
+/// trusted consensus state, or by the next one if known, and must finalize a
+/// slot following the trusted one, whose execution state holds the storage
+/// root of the IBC contract disclosed by the header.
+///
+/// As the client only keeps finalized updates, and not the best valid ones,
+/// the update must be signed by a two-thirds supermajority of the committee,
+/// as required by `process_light_client_update` to apply an update, on top
+/// of the minimum participation of the client state.
+pub fn verify_header<H: EthereumHostFunctions>(
+    client_state: &ClientState,
+    trusted_consensus_state: &ConsensusState,
+    header: &Header,
+    current_timestamp: Timestamp,
+) -> Result<ConsensusState, Error> {
+    let update = &header.consensus_update;
+
+    let trusting_period_elapsed = current_timestamp
+        .duration_since(&trusted_consensus_state.timestamp)
+        .is_some_and(|elapsed| elapsed >= client_state.trusting_period);
+
+    if trusting_period_elapsed {
+        return Err(Error::TrustingPeriodElapsed {
+            trusted_timestamp: trusted_consensus_state.timestamp,
+            current_timestamp,
+        });
+    }
+
+    let current_slot = client_state.slot_at_timestamp(current_timestamp);
+
+    if update.signature_slot > current_slot {
+        return Err(Error::FutureSignatureSlot {
+            signature_slot: update.signature_slot,
+            current_slot,
+        });
+    }
+
+    let attested_slot = update.attested_header.slot;
+    let finalized_slot = update.finalized_header.beacon.slot;
+
+    if !(update.signature_slot > attested_slot
+        && attested_slot >= finalized_slot
+        && finalized_slot > trusted_consensus_state.slot)
+    {
+        return Err(Error::UnorderedSlots {
+            signature_slot: update.signature_slot,
+            attested_slot,
+            finalized_slot,
+            trusted_slot: trusted_consensus_state.slot,
+        });
+    }
+
+    let store_period = client_state.compute_sync_committee_period(trusted_consensus_state.slot);
+    let signature_period = client_state.compute_sync_committee_period(update.signature_slot);
+
+    let sync_committee_root = if signature_period == store_period {
+        trusted_consensus_state.current_sync_committee
+    } else if signature_period == store_period + 1 {
+        trusted_consensus_state
+            .next_sync_committee
+            .ok_or(Error::MissingNextSyncCommittee)?
+    } else {
+        return Err(Error::UnexpectedSyncCommitteePeriod {
+            signature_period,
+            store_period,
+        });
+    };
+
+    if header.trusted_sync_committee.hash_tree_root::<H>() != sync_committee_root {
+        return Err(Error::SyncCommitteeMismatch);
+    }
+
+    let participants = update
+        .sync_aggregate
+        .participant_pubkeys(&header.trusted_sync_committee)?;
+
+    if (participants.len() as u64) < client_state.min_sync_committee_participants {
+        return Err(Error::InsufficientParticipants {
+            participants: participants.len() as u64,
+            min: client_state.min_sync_committee_participants,
+        });
+    }
+
+    let committee_size = header.trusted_sync_committee.pubkeys.len() as u64;
+
+    if (participants.len() as u64) * 3 < committee_size * 2 {
+        return Err(Error::NoSupermajority {
+            participants: participants.len() as u64,
+            committee: committee_size,
+        });
+    }
+
+    update.verify_branches::<H>(client_state.fork_at_slot(attested_slot).0)?;
+    update
+        .finalized_header
+        .verify_execution_branch::<H>(client_state.fork_at_slot(finalized_slot).0)?;
+
+    // The signature is made under the fork of the slot preceding the
+    // signature slot, i.e. the slot of the block the committee signed in.
+    let (_, fork_version) = client_state.fork_at_slot(update.signature_slot.max(1) - 1);
+    let domain =
+        compute_sync_committee_domain::<H>(fork_version, &client_state.genesis_validators_root);
+    let signing_root =
+        compute_signing_root::<H>(&update.attested_header.hash_tree_root::<H>(), &domain);
+
+    if !H::bls_fast_aggregate_verify(
+        &participants,
+        &signing_root,
+        &update.sync_aggregate.sync_committee_signature,
+    ) {
+        return Err(Error::InvalidSignature);
+    }
+
+    header.account_update.verify::<H>(
+        &update.finalized_header.execution.state_root,
+        &client_state.ibc_contract_address,
+    )?;
+
+    updated_consensus_state::<H>(client_state, trusted_consensus_state, header)
+}
+
+/// Returns the consensus state a header, assumed verified, updates the
+/// client to from the trusted consensus state.
+///
+/// The sync committees of the consensus state are those of the period of the
+/// finalized slot, which is either the period of the trusted consensus state
+/// or the following one.
+pub fn updated_consensus_state<H: EthereumHostFunctions>(
+    client_state: &ClientState,
+    trusted_consensus_state: &ConsensusState,
+    header: &Header,
+) -> Result<ConsensusState, Error> {
+    let update = &header.consensus_update;
+
+    let store_period = client_state.compute_sync_committee_period(trusted_consensus_state.slot);
+    let attested_period = client_state.compute_sync_committee_period(update.attested_header.slot);
+    let finalized_period = client_state.compute_sync_committee_period(header.slot());
+
+    // The next sync committee of the attested state is only the next one of
+    // the finalized slot if both are in the same period.
+    let update_next_sync_committee = update
+        .next_sync_committee
+        .as_ref()
+        .filter(|_| attested_period == finalized_period)
+        .map(|committee| committee.hash_tree_root::<H>());
+
+    let (current_sync_committee, next_sync_committee) = if finalized_period == store_period {
+        let next_sync_committee = match (
+            trusted_consensus_state.next_sync_committee,
+            update_next_sync_committee,
+        ) {
+            (Some(known), Some(disclosed)) if known != disclosed => {
+                return Err(Error::SyncCommitteeMismatch)
+            }
+            (known, disclosed) => known.or(disclosed),
+        };
+
+        (
+            trusted_consensus_state.current_sync_committee,
+            next_sync_committee,
+        )
+    } else if finalized_period == store_period + 1 {
+        (
+            trusted_consensus_state
+                .next_sync_committee
+                .ok_or(Error::MissingNextSyncCommittee)?,
+            update_next_sync_committee,
+        )
+    } else {
+        return Err(Error::UnexpectedFinalizedPeriod {
+            finalized_period,
+            store_period,
+        });
+    };
+
+    Ok(ConsensusState {
+        slot: header.slot(),
+        state_root: update.finalized_header.execution.state_root,
+        storage_root: CommitmentRoot::from_bytes(&header.account_update.storage_root),
+        timestamp: client_state.timestamp_at_slot(header.slot())?,
+        current_sync_committee,
+        next_sync_committee,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::*;
+
+    fn verify(
+        client_state: &ClientState,
+        trusted_consensus_state: &ConsensusState,
+        header: &Header,
+    ) -> Result<ConsensusState, Error> {
+        let current_timestamp = client_state
+            .timestamp_at_slot(header.consensus_update.signature_slot + 1)
+            .unwrap();
+
+        verify_header::<TestHostFunctions>(
+            client_state,
+            trusted_consensus_state,
+            header,
+            current_timestamp,
+        )
+    }
+
+    #[test]
+    fn test_verify_header_current_period() {
+        let client_state = client_state();
+        let trusted = trusted_consensus_state();
+        let header = header(HEADER_CURRENT_PERIOD);
+
+        let consensus_state = verify(&client_state, &trusted, &header).unwrap();
+
+        assert_eq!(consensus_state.slot, 88);
+        assert_eq!(
+            consensus_state.timestamp,
+            client_state.timestamp_at_slot(88).unwrap()
+        );
+        assert_eq!(
+            consensus_state.state_root,
+            header
+                .consensus_update
+                .finalized_header
+                .execution
+                .state_root
+        );
+        assert_eq!(
+            consensus_state.storage_root.as_bytes(),
+            decode_hex(STORAGE_ROOT)
+        );
+        assert_eq!(
+            consensus_state.current_sync_committee,
+            trusted.current_sync_committee
+        );
+        assert_eq!(
+            consensus_state.next_sync_committee,
+            trusted.next_sync_committee
+        );
+
+        // A conflicting header signed by the same committee verifies as
+        // well, which makes for a misbehaviour.
+        verify(
+            &client_state,
+            &trusted,
+            &crate::fixtures::header(HEADER_CONFLICTING),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_verify_header_next_period() {
+        let client_state = client_state();
+        let trusted = trusted_consensus_state();
+        let header = header(HEADER_NEXT_PERIOD);
+
+        let consensus_state = verify(&client_state, &trusted, &header).unwrap();
+
+        assert_eq!(consensus_state.slot, 130);
+        assert_eq!(
+            Some(consensus_state.current_sync_committee),
+            trusted.next_sync_committee
+        );
+        assert_eq!(
+            consensus_state
+                .next_sync_committee
+                .map(|root| root.to_vec()),
+            Some(decode_hex(NEXT_NEXT_SYNC_COMMITTEE_ROOT))
+        );
+
+        // The committee of the next period must be known to verify its
+        // signatures.
+        let unknown_next = ConsensusState {
+            next_sync_committee: None,
+            ..trusted
+        };
+        assert!(matches!(
+            verify(&client_state, &unknown_next, &header),
+            Err(Error::MissingNextSyncCommittee)
+        ));
+    }
+
+    #[test]
+    fn test_verify_header_timing() {
+        let client_state = client_state();
+        let trusted = trusted_consensus_state();
+        let header = header(HEADER_CURRENT_PERIOD);
+        let signature_slot = header.consensus_update.signature_slot;
+
+        let before_signature = client_state.timestamp_at_slot(signature_slot - 1).unwrap();
+        assert!(matches!(
+            verify_header::<TestHostFunctions>(&client_state, &trusted, &header, before_signature),
+            Err(Error::FutureSignatureSlot { .. })
+        ));
+
+        let expired = (trusted.timestamp + client_state.trusting_period).unwrap();
+        assert!(matches!(
+            verify_header::<TestHostFunctions>(&client_state, &trusted, &header, expired),
+            Err(Error::TrustingPeriodElapsed { .. })
+        ));
+
+        let stale = ConsensusState {
+            slot: header.slot(),
+            ..trusted
+        };
+        assert!(matches!(
+            verify(&client_state, &stale, &header),
+            Err(Error::UnorderedSlots { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_header_rejects_tampering() {
+        let client_state = client_state();
+        let trusted = trusted_consensus_state();
+        let header = header(HEADER_CURRENT_PERIOD);
+
+        let mut tampered = header.clone();
+        tampered.consensus_update.attested_header.proposer_index += 1;
+        assert!(matches!(
+            verify(&client_state, &trusted, &tampered),
+            Err(Error::InvalidSignature)
+        ));
+
+        let mut tampered = header.clone();
+        tampered.consensus_update.finality_branch[0][0] ^= 1;
+        assert!(matches!(
+            verify(&client_state, &trusted, &tampered),
+            Err(Error::InvalidFinalityBranch)
+        ));
+
+        let mut tampered = header.clone();
+        tampered
+            .consensus_update
+            .finalized_header
+            .execution
+            .gas_used += 1;
+        assert!(matches!(
+            verify(&client_state, &trusted, &tampered),
+            Err(Error::InvalidExecutionBranch)
+        ));
+
+        let mut tampered = header.clone();
+        tampered.account_update.storage_root[0] ^= 1;
+        assert!(matches!(
+            verify(&client_state, &trusted, &tampered),
+            Err(Error::StorageRootMismatch)
+        ));
+
+        let mut tampered = header.clone();
+        tampered.trusted_sync_committee.pubkeys.swap(0, 1);
+        assert!(matches!(
+            verify(&client_state, &trusted, &tampered),
+            Err(Error::SyncCommitteeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_verify_header_participation() {
+        let header = header(HEADER_CURRENT_PERIOD);
+        let participants = header.consensus_update.sync_aggregate.num_participants();
+
+        let demanding = ClientState {
+            min_sync_committee_participants: participants + 1,
+            ..client_state()
+        };
+        assert!(matches!(
+            verify(&demanding, &trusted_consensus_state(), &header),
+            Err(Error::InsufficientParticipants { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_header_requires_supermajority() {
+        let client_state = client_state();
+        let header = header(HEADER_MINORITY);
+        let participants = header.consensus_update.sync_aggregate.num_participants();
+
+        // Validly signed by 20 members out of 32, above the minimum of the
+        // client state but short of two thirds.
+        assert!(participants >= client_state.min_sync_committee_participants);
+        assert!(matches!(
+            verify(&client_state, &trusted_consensus_state(), &header),
+            Err(Error::NoSupermajority {
+                participants: 20,
+                committee: 32,
+            })
+        ));
+    }
+}
@@ -0,0 +1,164 @@
+//! This module includes trait implementations for the
+//! `ibc_client_solomachine_types::ClientState` type. Implemented traits include
+//! `ClientStateCommon`, `ClientStateValidation`, and `ClientStateExecution`.
+//!
+//! Note that this crate defines a newtype wrapper around the
+//! `ibc_client_solomachine_types::ClientState` type in order to enable
+//! implementing a foreign trait on a foreign type (i.e. the orphan rule in
+//! Rust). As such, this module also includes some trait implementations that
+//! serve to pass through traits implemented on the wrapped `ClientState` type.
+
+use ibc_client_solomachine_types::error::Error;
+use ibc_client_solomachine_types::proto::v3::ClientState as RawSmClientState;
+use ibc_client_solomachine_types::ClientState as ClientStateType;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+/// Newtype wrapper around the `ClientState` type imported from the
+/// `ibc-client-solomachine-types` crate. This wrapper exists so that we can
+/// bypass Rust's orphan rules and implement traits from
+/// `ibc::core::client::context` on the `ClientState` type.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, derive_more::From)]
+pub struct ClientState(ClientStateType);
+
+impl ClientState {
+    pub fn inner(&self) -> &ClientStateType {
+        &self.0
+    }
+}
+
+impl Protobuf<RawSmClientState> for ClientState {}
+
+impl TryFrom<RawSmClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawSmClientState) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for RawSmClientState {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_client_solomachine_types::{ConsensusState as ConsensusStateType, PublicKey};
+    use ibc_core_client::types::{Height, Status};
+    use ibc_primitives::Timestamp;
+    use tendermint::PublicKey as TmPublicKey;
+
+    use super::*;
+
+    const ED25519_PUB_KEY: [u8; 32] = [
+        0x8a, 0x88, 0xe3, 0xdd, 0x74, 0x09, 0xf1, 0x95, 0xfd, 0x52, 0xdb, 0x2d, 0x3c, 0xba, 0x5d,
+        0x72, 0xca, 0x67, 0x09, 0xbf, 0x1d, 0x94, 0x12, 0x1b, 0xf3, 0x74, 0x88, 0x01, 0xb4, 0x0f,
+        0x6f, 0x5c,
+    ];
+
+    fn client_state(sequence: u64) -> ClientState {
+        let consensus_state = ConsensusStateType::new(
+            PublicKey::Single(TmPublicKey::from_raw_ed25519(&ED25519_PUB_KEY).unwrap()),
+            "diversifier".to_string(),
+            Timestamp::from_nanoseconds(1).unwrap(),
+        );
+
+        ClientState(ClientStateType::new(sequence, consensus_state).unwrap())
+    }
+
+    #[test]
+    fn client_state_verify_height() {
+        let client_state = client_state(10);
+
+        struct Test {
+            name: &'static str,
+            height: Height,
+            want_pass: bool,
+        }
+
+        let tests = vec![
+            Test {
+                name: "Proof height at the current sequence",
+                height: Height::new(0, 10).unwrap(),
+                want_pass: true,
+            },
+            Test {
+                name: "Proof height at a past sequence",
+                height: Height::new(0, 9).unwrap(),
+                want_pass: false,
+            },
+            Test {
+                name: "Proof height at a future sequence",
+                height: Height::new(0, 11).unwrap(),
+                want_pass: false,
+            },
+            Test {
+                name: "Proof height of another revision",
+                height: Height::new(1, 10).unwrap(),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = validate_proof_height(client_state.inner(), test.height);
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "validate_proof_height() failed for test {}, with error {:?}",
+                test.name,
+                res.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn client_state_status() {
+        let client_state = client_state(1);
+        assert_eq!(
+            client_state.inner().latest_height(),
+            Height::new(0, 1).unwrap()
+        );
+        assert_eq!(status(client_state.inner()), Status::Active);
+
+        let frozen_client_state = client_state.inner().clone().with_frozen();
+        assert_eq!(status(&frozen_client_state), Status::Frozen);
+    }
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = client_state(3);
+
+        let any = Any::from(client_state.clone());
+
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+    }
+}
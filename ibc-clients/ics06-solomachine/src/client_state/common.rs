@@ -0,0 +1,204 @@
+use ibc_client_solomachine_types::error::Error;
+use ibc_client_solomachine_types::{
+    client_type as sm_client_type, ClientState as ClientStateType,
+    ConsensusState as ConsensusStateType, SignBytes, TimestampedSignatureData,
+};
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_commitment_types::merkle::apply_prefix;
+use ibc_core_host::types::identifiers::ClientType;
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use tendermint::crypto::default::signature::Verifier as DefaultSignatureVerifier;
+use tendermint::crypto::signature::Verifier as SignatureVerifier;
+
+use super::ClientState;
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        verify_consensus_state(consensus_state)
+    }
+
+    fn client_type(&self) -> ClientType {
+        sm_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.0.latest_height()
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        validate_proof_height(self.inner(), proof_height)
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        verify_membership::<DefaultSignatureVerifier>(self.inner(), prefix, proof, path, value)
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        verify_non_membership::<DefaultSignatureVerifier>(self.inner(), prefix, proof, path)
+    }
+}
+
+/// Verify an `Any` consensus state by attempting to convert it to a
+/// solo machine `ConsensusState`, which checks its public key, diversifier
+/// and timestamp.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_consensus_state(consensus_state: Any) -> Result<(), ClientError> {
+    ConsensusStateType::try_from(consensus_state)?;
+
+    Ok(())
+}
+
+/// Validate the given proof height against the client state's latest height.
+///
+/// Solo machines sign proofs with their current sequence, so the proof height
+/// must be exactly the latest height of the client: the height of revision
+/// zero at the current sequence.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn validate_proof_height(
+    client_state: &ClientStateType,
+    proof_height: Height,
+) -> Result<(), ClientError> {
+    let latest_height = client_state.latest_height();
+
+    if latest_height < proof_height {
+        return Err(ClientError::InvalidProofHeight {
+            latest_height,
+            proof_height,
+        });
+    }
+
+    if latest_height != proof_height {
+        return Err(Error::ProofHeightMismatch {
+            proof_height: proof_height.revision_height(),
+            sequence: client_state.sequence(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Verify that the solo machine signed the given value at the given path.
+///
+/// The proof is a [`TimestampedSignatureData`], whose signature must be over
+/// the [`SignBytes`] of the value at the client's current sequence and
+/// diversifier, and whose timestamp must not be older than that of the
+/// client's consensus state.
+///
+/// Unlike in `ibc-go`, verifying a proof does not increment the sequence of
+/// the client, since [`ClientStateCommon`] verification does not get to
+/// write to the store. The solo machine rather advances its sequence by
+/// submitting a header, which makes all the proofs signed at the previous
+/// sequence unusable.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_membership<S: SignatureVerifier>(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    verify_signature::<S>(client_state, prefix, proof, path, value)
+}
+
+/// Verify that the solo machine signed the absence of a value at the given
+/// path, i.e. an empty value.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_non_membership<S: SignatureVerifier>(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    path: Path,
+) -> Result<(), ClientError> {
+    verify_signature::<S>(client_state, prefix, proof, path, Vec::new())
+}
+
+fn verify_signature<S: SignatureVerifier>(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    let consensus_state = &client_state.consensus_state;
+
+    let proof = TimestampedSignatureData::decode_vec(proof.as_ref()).map_err(|e| {
+        ClientError::ClientSpecific {
+            description: format!("invalid solo machine proof: {e}"),
+        }
+    })?;
+
+    if proof.timestamp < consensus_state.timestamp {
+        return Err(Error::TimestampTooLow {
+            actual: proof.timestamp,
+            latest: consensus_state.timestamp,
+        }
+        .into());
+    }
+
+    let sign_bytes = SignBytes {
+        sequence: client_state.sequence(),
+        timestamp: proof.timestamp,
+        diversifier: consensus_state.diversifier.clone(),
+        path: signed_path(prefix, path),
+        data: value,
+    };
+
+    consensus_state
+        .verify_signature::<S>(sign_bytes, &proof.signature_data)
+        .map_err(Into::into)
+}
+
+/// Returns the path signed over by the solo machine, i.e. the prefixed
+/// merkle path joined as `/{prefix}/{path}`.
+fn signed_path(prefix: &CommitmentPrefix, path: Path) -> Vec<u8> {
+    apply_prefix(prefix, vec![path.to_string()])
+        .key_path
+        .iter()
+        .map(|key| format!("/{key}"))
+        .collect::<String>()
+        .into_bytes()
+}
@@ -0,0 +1,212 @@
+use ibc_client_solomachine_types::error::Error;
+use ibc_client_solomachine_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as SmHeader,
+};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        initialise(self.inner(), ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        update_state(self.inner(), ctx, client_id, header)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        update_on_misbehaviour(self.inner(), ctx, client_id, client_message)
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        update_on_recovery(
+            ctx,
+            subject_client_id,
+            substitute_client_state,
+            substitute_consensus_state,
+        )
+    }
+}
+
+/// Seed the host store with initial client and consensus states.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn initialise<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+{
+    let sm_consensus_state: ConsensusStateType = consensus_state.try_into()?;
+
+    store_client_and_consensus_states(ctx, client_id, client_state.clone(), sm_consensus_state)
+}
+
+/// Update the host store with the client state following a verified header,
+/// along with the new consensus state at the incremented sequence.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn update_state<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+{
+    let header = SmHeader::try_from(header)?;
+
+    let new_client_state = client_state.clone().with_header(header)?;
+    let new_height = new_client_state.latest_height();
+    let new_consensus_state = new_client_state.consensus_state.clone();
+
+    store_client_and_consensus_states(ctx, client_id, new_client_state, new_consensus_state)?;
+
+    Ok(vec![new_height])
+}
+
+/// Commit a frozen client state, which was frozen as a result of having exhibited
+/// misbehaviour, to the store.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn update_on_misbehaviour<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    _client_message: Any,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+{
+    let frozen_client_state = client_state.clone().with_frozen();
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        frozen_client_state.into(),
+    )?;
+
+    Ok(())
+}
+
+/// Replace the subject client state and its consensus state by the
+/// substitute ones in response to a successful client recovery, unfreezing
+/// the client.
+///
+/// This function is typically implemented as part of the [`ClientStateExecution`]
+/// trait, but has been made standalone in order to enable greater flexibility
+/// of the ClientState APIs.
+pub fn update_on_recovery<E>(
+    ctx: &mut E,
+    subject_client_id: &ClientId,
+    substitute_client_state: Any,
+    substitute_consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+{
+    let substitute_client_state = ClientStateType::try_from(substitute_client_state)?;
+
+    let mut new_client_state = substitute_client_state;
+    new_client_state.is_frozen = false;
+
+    let sm_consensus_state: ConsensusStateType = substitute_consensus_state.try_into()?;
+
+    store_client_and_consensus_states(ctx, subject_client_id, new_client_state, sm_consensus_state)
+}
+
+/// Stores the client state, the consensus state at its latest height, and
+/// the update metadata of that height.
+fn store_client_and_consensus_states<E>(
+    ctx: &mut E,
+    client_id: &ClientId,
+    client_state: ClientStateType,
+    consensus_state: ConsensusStateType,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+{
+    let host_timestamp = ExtClientValidationContext::host_timestamp(ctx)?;
+    let host_height = ExtClientValidationContext::host_height(ctx)?;
+
+    let latest_height = client_state.latest_height();
+
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ),
+        consensus_state.into(),
+    )?;
+    ctx.store_client_state(ClientStatePath::new(client_id.clone()), client_state.into())?;
+    ctx.store_update_meta(
+        client_id.clone(),
+        latest_height,
+        host_timestamp,
+        host_height,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use ibc_client_solomachine_types::error::Error;
+use ibc_client_solomachine_types::{
+    ClientState as ClientStateType, Header as SmHeader, Misbehaviour as SmMisbehaviour,
+    SOLOMACHINE_HEADER_TYPE_URL, SOLOMACHINE_MISBEHAVIOUR_TYPE_URL,
+};
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::context::ClientValidationContext;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_handler_types::metering::CostEvent;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use tendermint::crypto::default::signature::Verifier as DefaultSignatureVerifier;
+use tendermint::crypto::signature::Verifier as SignatureVerifier;
+
+use super::ClientState;
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ClientValidationContext,
+{
+    /// The default verification logic delegates to the standalone
+    /// `verify_client_message` function with the default signature verifier
+    /// of `tendermint`. Hosts with their own signature verification may wrap
+    /// the client state in a newtype calling [`verify_client_message`] with
+    /// their verifier instead.
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message::<V, DefaultSignatureVerifier>(self.inner(), ctx, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(client_message)
+    }
+
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        Ok(status(self.inner()))
+    }
+
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        check_substitute(self.inner(), substitute_client_state)
+    }
+}
+
+/// Verify the client message as part of the client state validation process.
+///
+/// A header must be signed by the current public key of the solo machine
+/// over its current sequence and diversifier, and must not be older than its
+/// latest timestamp. Both signatures of a misbehaviour must be by the
+/// current public key over the sequence of the misbehaviour.
+///
+/// The host is charged for the signatures checked, counting each signature
+/// of a multisig.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible. It takes the
+/// signature verifier as a type parameter so that hosts can plug in their
+/// own implementation.
+pub fn verify_client_message<V, S>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_message: Any,
+) -> Result<(), ClientError>
+where
+    V: ClientValidationContext,
+    S: SignatureVerifier,
+{
+    let consensus_state = &client_state.consensus_state;
+
+    match client_message.type_url.as_str() {
+        SOLOMACHINE_HEADER_TYPE_URL => {
+            let header = SmHeader::try_from(client_message)?;
+
+            if header.timestamp < consensus_state.timestamp {
+                return Err(Error::TimestampTooLow {
+                    actual: header.timestamp,
+                    latest: consensus_state.timestamp,
+                }
+                .into());
+            }
+
+            let sign_bytes =
+                header.sign_bytes(client_state.sequence(), &consensus_state.diversifier);
+
+            ctx.charge(CostEvent::SignaturesChecked {
+                count: header.signature.signature_count(),
+            })?;
+
+            consensus_state
+                .verify_signature::<S>(sign_bytes, &header.signature)
+                .map_err(|e| ClientError::HeaderVerificationFailure {
+                    reason: e.to_string(),
+                })
+        }
+        SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = SmMisbehaviour::try_from(client_message)?;
+
+            // The timestamps of the signatures are not checked against the
+            // latest timestamp, so that past misbehaviour can be submitted.
+            for signature_and_data in [&misbehaviour.signature_one, &misbehaviour.signature_two] {
+                let sign_bytes = signature_and_data
+                    .sign_bytes(misbehaviour.sequence, &consensus_state.diversifier);
+
+                ctx.charge(CostEvent::SignaturesChecked {
+                    count: signature_and_data.signature.signature_count(),
+                })?;
+
+                consensus_state
+                    .verify_signature::<S>(sign_bytes, &signature_and_data.signature)
+                    .map_err(|e| ClientError::MisbehaviourHandlingFailure {
+                        reason: e.to_string(),
+                    })?;
+            }
+
+            Ok(())
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Check for misbehaviour on the client state as part of the client state
+/// validation process.
+///
+/// A verified misbehaviour, i.e. two signatures over different data at the
+/// same sequence, always is misbehaviour, whereas a verified header never is.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn check_for_misbehaviour(client_message: Any) -> Result<bool, ClientError> {
+    match client_message.type_url.as_str() {
+        SOLOMACHINE_HEADER_TYPE_URL => Ok(false),
+        SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Ok(true),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Query the status of the client state.
+///
+/// Solo machine clients never expire: they are active until frozen.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn status(client_state: &ClientStateType) -> Status {
+    if client_state.is_frozen() {
+        Status::Frozen
+    } else {
+        Status::Active
+    }
+}
+
+/// Check that the substitute client state may replace the subject client
+/// state as part of the client recovery validation step.
+///
+/// The substitute must be a solo machine client state signing with another
+/// public key than the subject, as recovering a client is pointless if the
+/// key it was frozen for is still in use.
+pub fn check_substitute(
+    subject_client_state: &ClientStateType,
+    substitute_client_state: Any,
+) -> Result<(), ClientError> {
+    let substitute_client_state = ClientStateType::try_from(substitute_client_state)?;
+
+    if subject_client_state.consensus_state.public_key
+        == substitute_client_state.consensus_state.public_key
+    {
+        return Err(Error::SamePublicKey.into());
+    }
+
+    Ok(())
+}
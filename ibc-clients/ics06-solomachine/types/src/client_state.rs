@@ -0,0 +1,207 @@
+//! Implements the core [`ClientState`] type for the solo machine light client.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::ClientState as RawSmClientState;
+use ibc_proto::Protobuf;
+
+use crate::consensus_state::ConsensusState;
+use crate::error::Error;
+use crate::header::Header;
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.ClientState";
+
+/// Contains the core implementation of the solo machine light client.
+///
+/// The client tracks the sequence of the solo machine, which is the revision
+/// height of its latest height, along with the consensus state the solo
+/// machine currently signs with.
+///
+/// The sequence is never zero, and is thus only set through the constructors
+/// of the client state, which validate it.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ClientStateFields")
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    sequence: u64,
+    pub is_frozen: bool,
+    pub consensus_state: ConsensusState,
+}
+
+/// The fields of a [`ClientState`], deserialized before being validated.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ClientStateFields {
+    sequence: u64,
+    is_frozen: bool,
+    consensus_state: ConsensusState,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ClientStateFields> for ClientState {
+    type Error = Error;
+
+    fn try_from(fields: ClientStateFields) -> Result<Self, Self::Error> {
+        let client_state = Self::new(fields.sequence, fields.consensus_state)?;
+
+        Ok(Self {
+            is_frozen: fields.is_frozen,
+            ..client_state
+        })
+    }
+}
+
+impl ClientState {
+    pub fn new(sequence: u64, consensus_state: ConsensusState) -> Result<Self, Error> {
+        if sequence == 0 {
+            return Err(Error::ZeroSequence);
+        }
+
+        Ok(Self {
+            sequence,
+            is_frozen: false,
+            consensus_state,
+        })
+    }
+
+    /// Returns the current sequence of the solo machine, which is never zero.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Returns the latest height of the client, i.e. the height of revision
+    /// zero at the current sequence.
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence).expect("sequence is never zero")
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.is_frozen
+    }
+
+    /// Returns the client state following the given verified header: the
+    /// sequence is incremented, and the new public key and diversifier are
+    /// signed with from then on.
+    pub fn with_header(self, header: Header) -> Result<Self, Error> {
+        Ok(Self {
+            sequence: self
+                .sequence
+                .checked_add(1)
+                .ok_or(Error::SequenceOverflow)?,
+            consensus_state: ConsensusState::new(
+                header.new_public_key,
+                header.new_diversifier,
+                header.timestamp,
+            ),
+            ..self
+        })
+    }
+
+    pub fn with_frozen(self) -> Self {
+        Self {
+            is_frozen: true,
+            ..self
+        }
+    }
+}
+
+impl Protobuf<RawSmClientState> for ClientState {}
+
+impl TryFrom<RawSmClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawSmClientState) -> Result<Self, Self::Error> {
+        let consensus_state = raw
+            .consensus_state
+            .ok_or_else(|| Error::InvalidRawClientState {
+                reason: "missing consensus state".into(),
+            })?
+            .try_into()?;
+
+        let client_state = Self::new(raw.sequence, consensus_state)?;
+
+        Ok(Self {
+            is_frozen: raw.is_frozen,
+            ..client_state
+        })
+    }
+}
+
+impl From<ClientState> for RawSmClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            sequence: value.sequence,
+            is_frozen: value.is_frozen,
+            consensus_state: Some(value.consensus_state.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state =
+                Protobuf::<RawSmClientState>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawSmClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_primitives::Timestamp;
+    use tendermint::PublicKey as TmPublicKey;
+
+    use super::*;
+    use crate::fixtures::*;
+    use crate::public_key::PublicKey;
+
+    #[test]
+    fn test_zero_sequence_is_rejected() {
+        let consensus_state = ConsensusState::new(
+            PublicKey::Single(
+                TmPublicKey::from_raw_ed25519(&decode_hex(ED25519_PUB_KEY_A)).unwrap(),
+            ),
+            "diversifier".to_string(),
+            Timestamp::from_nanoseconds(TIMESTAMP).unwrap(),
+        );
+
+        assert!(ClientState::new(0, consensus_state.clone()).is_err());
+
+        let client_state = ClientState::new(1, consensus_state).unwrap();
+        assert_eq!(client_state.latest_height(), Height::new(0, 1).unwrap());
+
+        let mut raw = RawSmClientState::from(client_state.clone());
+        assert_eq!(ClientState::try_from(raw.clone()).unwrap(), client_state);
+
+        raw.sequence = 0;
+        assert!(ClientState::try_from(raw).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+//! Defines the solo machine's `ConsensusState` type
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::ConsensusState as RawConsensusState;
+use ibc_proto::Protobuf;
+use tendermint::crypto::signature::Verifier;
+
+use crate::error::Error;
+use crate::public_key::PublicKey;
+use crate::sign_bytes::SignBytes;
+use crate::signature::SignatureData;
+
+pub const SOLOMACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.ConsensusState";
+
+/// Defines the solo machine light client's consensus state: the public key
+/// and diversifier the solo machine currently signs with, along with the
+/// timestamp of its latest update.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub public_key: PublicKey,
+    pub diversifier: String,
+    pub timestamp: Timestamp,
+    /// Solo machines do not commit to their state, so this root is always
+    /// empty. It only exists for the consensus state to expose a root like
+    /// those of other clients.
+    #[cfg_attr(feature = "serde", serde(skip, default = "empty_root"))]
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new(public_key: PublicKey, diversifier: String, timestamp: Timestamp) -> Self {
+        Self {
+            public_key,
+            diversifier,
+            timestamp,
+            root: empty_root(),
+        }
+    }
+
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    /// Verifies that the solo machine signed `sign_bytes` with its current
+    /// public key.
+    pub fn verify_signature<V: Verifier>(
+        &self,
+        sign_bytes: SignBytes,
+        signature: &SignatureData,
+    ) -> Result<(), Error> {
+        self.public_key
+            .verify_signature::<V>(&sign_bytes.encode_vec(), signature)
+    }
+}
+
+fn empty_root() -> CommitmentRoot {
+    CommitmentRoot::from_bytes(&[])
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let public_key = raw.public_key.ok_or(Error::MissingPublicKey)?.try_into()?;
+
+        if raw.diversifier.trim().is_empty() && !raw.diversifier.is_empty() {
+            return Err(Error::BlankDiversifier);
+        }
+
+        if raw.timestamp == 0 {
+            return Err(Error::ZeroTimestamp);
+        }
+
+        Ok(Self::new(
+            public_key,
+            raw.diversifier,
+            Timestamp::from_nanoseconds(raw.timestamp)?,
+        ))
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            public_key: Some(value.public_key.into()),
+            diversifier: value.diversifier,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state =
+                Protobuf::<RawConsensusState>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
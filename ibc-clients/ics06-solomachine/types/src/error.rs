@@ -0,0 +1,83 @@
+//! Defines the Solo Machine light client's error type
+
+use displaydoc::Display;
+use ibc_core_client_types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::{ParseTimestampError, Timestamp};
+use tendermint::crypto::signature::Error as SignatureError;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// invalid raw misbehaviour: `{reason}`
+    InvalidRawMisbehaviour { reason: String },
+    /// invalid timestamp: `{0}`
+    InvalidTimestamp(ParseTimestampError),
+    /// sequence cannot be zero
+    ZeroSequence,
+    /// sequence overflow
+    SequenceOverflow,
+    /// timestamp cannot be zero
+    ZeroTimestamp,
+    /// diversifier cannot contain only spaces
+    BlankDiversifier,
+    /// missing public key
+    MissingPublicKey,
+    /// unsupported public key type: `{type_url}`
+    UnsupportedPublicKey { type_url: String },
+    /// invalid public key: `{reason}`
+    InvalidPublicKey { reason: String },
+    /// empty signature
+    EmptySignature,
+    /// invalid signature data: `{reason}`
+    InvalidSignatureData { reason: String },
+    /// signature verification failed: `{0}`
+    SignatureVerification(SignatureError),
+    /// multisig signature verification failed: `{reason}`
+    MultisigVerification { reason: String },
+    /// solo machine timestamp `{actual}` is older than its latest timestamp `{latest}`
+    TimestampTooLow {
+        actual: Timestamp,
+        latest: Timestamp,
+    },
+    /// proof height `{proof_height}` does not match the sequence `{sequence}` of the client
+    ProofHeightMismatch { proof_height: u64, sequence: u64 },
+    /// misbehaviour signatures are identical
+    IdenticalSignatures,
+    /// misbehaviour signatures are over identical data
+    IdenticalSignedData,
+    /// substitute client has the same public key as the subject client
+    SamePublicKey,
+    /// solo machine clients cannot be upgraded
+    UpgradeNotSupported,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::InvalidTimestamp(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<ParseTimestampError> for Error {
+    fn from(e: ParseTimestampError) -> Self {
+        Self::InvalidTimestamp(e)
+    }
+}
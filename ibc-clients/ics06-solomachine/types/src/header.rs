@@ -0,0 +1,183 @@
+//! Defines the domain type for solo machine headers
+
+use ibc_core_client_types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::Header as RawHeader;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::public_key::PublicKey;
+use crate::sign_bytes::{HeaderData, SignBytes, SENTINEL_HEADER_PATH};
+use crate::signature::SignatureData;
+
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Header";
+
+/// Solo machine header, with which a solo machine rotates its public key and
+/// diversifier. It is signed with the current public key over the current
+/// sequence and diversifier.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub timestamp: Timestamp,
+    pub signature: SignatureData,
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl Header {
+    /// Returns the bytes the header is signed over, given the sequence and
+    /// diversifier of the solo machine prior to the update.
+    pub fn sign_bytes(&self, sequence: u64, diversifier: &str) -> SignBytes {
+        let header_data = HeaderData {
+            new_public_key: self.new_public_key.clone(),
+            new_diversifier: self.new_diversifier.clone(),
+        };
+
+        SignBytes {
+            sequence,
+            timestamp: self.timestamp,
+            diversifier: diversifier.to_string(),
+            path: SENTINEL_HEADER_PATH.as_bytes().to_vec(),
+            data: header_data.encode_vec(),
+        }
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        if raw.timestamp == 0 {
+            return Err(Error::ZeroTimestamp);
+        }
+
+        if raw.signature.is_empty() {
+            return Err(Error::EmptySignature);
+        }
+
+        if raw.new_diversifier.trim().is_empty() && !raw.new_diversifier.is_empty() {
+            return Err(Error::BlankDiversifier);
+        }
+
+        let signature =
+            SignatureData::decode_vec(&raw.signature).map_err(|e| Error::InvalidRawHeader {
+                reason: e.to_string(),
+            })?;
+
+        Ok(Self {
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)?,
+            signature,
+            new_public_key: raw
+                .new_public_key
+                .ok_or(Error::MissingPublicKey)?
+                .try_into()?,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            timestamp: value.timestamp.nanoseconds(),
+            signature: value.signature.encode_vec(),
+            new_public_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
+            let header = Protobuf::<RawHeader>::decode(value).map_err(|e| {
+                ClientError::InvalidRawHeader {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(header)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint::crypto::default::signature::Verifier;
+
+    use super::*;
+    use crate::fixtures::*;
+    use crate::ConsensusState;
+
+    fn header() -> Header {
+        Header {
+            timestamp: Timestamp::from_nanoseconds(TIMESTAMP).unwrap(),
+            signature: SignatureData::Single {
+                signature: decode_hex(HEADER_SIGNATURE_A),
+            },
+            new_public_key: PublicKey::Single(ed25519_key(ED25519_PUB_KEY_B)),
+            new_diversifier: "new diversifier".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_header_sign_bytes() {
+        let sign_bytes = header().sign_bytes(1, "diversifier");
+
+        assert_eq!(sign_bytes.encode_vec(), decode_hex(HEADER_SIGN_BYTES));
+    }
+
+    #[test]
+    fn test_header_signature() {
+        let header = header();
+        let consensus_state = ConsensusState::new(
+            PublicKey::Single(ed25519_key(ED25519_PUB_KEY_A)),
+            "diversifier".to_string(),
+            Timestamp::from_nanoseconds(TIMESTAMP).unwrap(),
+        );
+
+        consensus_state
+            .verify_signature::<Verifier>(header.sign_bytes(1, "diversifier"), &header.signature)
+            .unwrap();
+
+        // the signature is bound to the sequence and diversifier
+        assert!(consensus_state
+            .verify_signature::<Verifier>(header.sign_bytes(2, "diversifier"), &header.signature)
+            .is_err());
+        assert!(consensus_state
+            .verify_signature::<Verifier>(header.sign_bytes(1, "other"), &header.signature)
+            .is_err());
+    }
+
+    #[test]
+    fn test_header_proto_roundtrip() {
+        let header = header();
+
+        let any = Any::from(header.clone());
+
+        assert_eq!(Header::try_from(any).unwrap(), header);
+    }
+}
@@ -0,0 +1,110 @@
+//! ICS-06: Solo Machine Client implements a client verification algorithm for
+//! standalone machines, such as phones, browsers or custodial services, which
+//! sign with a single public key or a multisig rather than running a
+//! consensus algorithm.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+use core::str::FromStr;
+
+use ibc_core_host_types::identifiers::ClientType;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod client_state;
+mod consensus_state;
+mod header;
+mod misbehaviour;
+mod public_key;
+mod sign_bytes;
+mod signature;
+
+pub use client_state::*;
+pub use consensus_state::*;
+pub use header::*;
+pub use misbehaviour::*;
+pub use public_key::*;
+pub use sign_bytes::*;
+pub use signature::*;
+
+pub mod error;
+pub mod raw;
+
+/// Re-exports ICS-06 Solo Machine light client from `ibc-proto` crate.
+pub mod proto {
+    pub use ibc_proto::ibc::lightclients::solomachine::*;
+}
+
+pub const SOLOMACHINE_CLIENT_TYPE: &str = "06-solomachine";
+
+/// Returns the solo machine `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(SOLOMACHINE_CLIENT_TYPE).expect("Never fails because it's valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ensures that the validation in `ClientType::from_str` doesn't fail for the solo machine client type
+    #[test]
+    pub fn test_sm_client_type() {
+        let _ = ClientType::from_str(SOLOMACHINE_CLIENT_TYPE).unwrap();
+    }
+}
+
+/// Keys and signatures produced with an independent ed25519 and secp256k1
+/// implementation, over sign bytes encoded by hand.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use ibc_primitives::prelude::*;
+    use tendermint::PublicKey as TmPublicKey;
+
+    pub const TIMESTAMP: u64 = 1_700_000_000_000_000_000;
+
+    pub const ED25519_PUB_KEY_A: &str =
+        "8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c";
+    pub const ED25519_PUB_KEY_B: &str =
+        "8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394";
+    pub const SECP256K1_PUB_KEY_C: &str =
+        "02531fe6068134503d2723133227c867ac8fa6c83c537e9a44c3c5bdbdcb1fe337";
+
+    /// Sign bytes of a header at sequence 1 and diversifier "diversifier",
+    /// rotating to key B and diversifier "new diversifier".
+    pub const HEADER_SIGN_BYTES: &str = "0801108080a8b1e39fe7cb171a0b64697665727369666965722212736f6c6f6d616368696e653a6865616465722a560a430a1d2f636f736d6f732e63727970746f2e656432353531392e5075624b657912220a208139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394120f6e6577206469766572736966696572";
+    /// Signature of [`HEADER_SIGN_BYTES`] by key A.
+    pub const HEADER_SIGNATURE_A: &str = "05da43d9817546a773b6122da2a14ace6a5b48b8064486ba80f3928a6431e7c8aba2f1d4b321dce6cfcb76bb9534a42b17f12c18a5df8524f0226108476ade05";
+
+    /// Sign bytes of `data` at `/ibc/connections/connection-0` and sequence 5.
+    pub const PROOF_SIGN_BYTES: &str = "0805108080a8b1e39fe7cb17221d2f6962632f636f6e6e656374696f6e732f636f6e6e656374696f6e2d302a0464617461";
+    /// Signature of [`PROOF_SIGN_BYTES`] by key A.
+    pub const PROOF_SIGNATURE_A: &str = "47143525dcddeb1212bd18e09386ed36a0655ef31b90381d91517fb0903d7a15e8f90fa964f81001bf53223215fb7084402ffcc4addf95dfe98caca8e7331606";
+    /// Signature of [`PROOF_SIGN_BYTES`] by key C.
+    pub const PROOF_SIGNATURE_C: &str = "b954e0dfdb84223d9098ee10f1fb851d39dd3f45838689b672446a2c2081eb6447440ab5cc3690ecf9bcde3729ecd65eab0a146815c948559149a529a4ae08ee";
+
+    pub fn decode_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    pub fn ed25519_key(hex: &str) -> TmPublicKey {
+        TmPublicKey::from_raw_ed25519(&decode_hex(hex)).unwrap()
+    }
+
+    pub fn secp256k1_key(hex: &str) -> TmPublicKey {
+        TmPublicKey::from_raw_secp256k1(&decode_hex(hex)).unwrap()
+    }
+}
@@ -0,0 +1,167 @@
+//! Defines the misbehaviour type for the solo machine light client
+
+use ibc_core_client_types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::Misbehaviour as RawMisbehaviour;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::sign_bytes::SignatureAndData;
+
+pub const SOLOMACHINE_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Misbehaviour";
+
+/// Solo machine light client's misbehaviour type: two signatures by the solo
+/// machine over different data at the same sequence.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub sequence: u64,
+    pub signature_one: SignatureAndData,
+    pub signature_two: SignatureAndData,
+}
+
+impl Misbehaviour {
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.sequence == 0 {
+            return Err(Error::ZeroSequence);
+        }
+
+        if self.signature_one.signature == self.signature_two.signature {
+            return Err(Error::IdenticalSignatures);
+        }
+
+        if self.signature_one.path == self.signature_two.path
+            && self.signature_one.data == self.signature_two.data
+        {
+            return Err(Error::IdenticalSignedData);
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        let signature_one = raw
+            .signature_one
+            .ok_or_else(|| Error::InvalidRawMisbehaviour {
+                reason: "missing signature one".into(),
+            })?
+            .try_into()?;
+
+        let signature_two = raw
+            .signature_two
+            .ok_or_else(|| Error::InvalidRawMisbehaviour {
+                reason: "missing signature two".into(),
+            })?
+            .try_into()?;
+
+        let misbehaviour = Self {
+            sequence: raw.sequence,
+            signature_one,
+            signature_two,
+        };
+
+        misbehaviour.validate_basic()?;
+
+        Ok(misbehaviour)
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            sequence: value.sequence,
+            signature_one: Some(value.signature_one.into()),
+            signature_two: Some(value.signature_two.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, ClientError> {
+        fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
+            let misbehaviour =
+                Protobuf::<RawMisbehaviour>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(misbehaviour)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(&raw.value),
+            _ => Err(ClientError::UnknownMisbehaviourType {
+                misbehaviour_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_primitives::Timestamp;
+
+    use super::*;
+    use crate::fixtures::*;
+    use crate::SignatureData;
+
+    fn signature_and_data(signature: &str, data: &[u8]) -> SignatureAndData {
+        SignatureAndData {
+            signature: SignatureData::Single {
+                signature: decode_hex(signature),
+            },
+            path: b"/ibc/connections/connection-0".to_vec(),
+            data: data.to_vec(),
+            timestamp: Timestamp::from_nanoseconds(TIMESTAMP).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_misbehaviour_validate_basic() {
+        let misbehaviour = Misbehaviour {
+            sequence: 5,
+            signature_one: signature_and_data(PROOF_SIGNATURE_A, b"data"),
+            signature_two: signature_and_data(PROOF_SIGNATURE_C, b"other data"),
+        };
+        misbehaviour.validate_basic().unwrap();
+
+        let any = Any::from(misbehaviour.clone());
+        assert_eq!(Misbehaviour::try_from(any).unwrap(), misbehaviour);
+
+        let zero_sequence = Misbehaviour {
+            sequence: 0,
+            ..misbehaviour.clone()
+        };
+        assert!(zero_sequence.validate_basic().is_err());
+
+        let identical_signatures = Misbehaviour {
+            signature_two: signature_and_data(PROOF_SIGNATURE_A, b"other data"),
+            ..misbehaviour.clone()
+        };
+        assert!(identical_signatures.validate_basic().is_err());
+
+        let identical_data = Misbehaviour {
+            signature_two: signature_and_data(PROOF_SIGNATURE_C, b"data"),
+            ..misbehaviour
+        };
+        assert!(identical_data.validate_basic().is_err());
+    }
+}
@@ -0,0 +1,316 @@
+//! Defines the public keys a solo machine signs with
+
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+use tendermint::crypto::signature::Verifier;
+use tendermint::{PublicKey as TmPublicKey, Signature as TmSignature};
+
+use crate::error::Error;
+use crate::raw::{Ed25519PubKey, LegacyAminoPubKey, Secp256k1PubKey};
+use crate::signature::SignatureData;
+
+pub const SECP256K1_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+pub const ED25519_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+pub const MULTISIG_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.multisig.LegacyAminoPubKey";
+
+/// The public key of a solo machine: either a single secp256k1 or ed25519
+/// key, or a `threshold`-of-n multisig of such keys, which may themselves
+/// be multisigs.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PublicKey {
+    Single(TmPublicKey),
+    Multisig {
+        threshold: u32,
+        public_keys: Vec<PublicKey>,
+    },
+}
+
+impl PublicKey {
+    /// Verifies `signature` over `msg` with the signature verifier `V`.
+    ///
+    /// A multisig signature is valid if the signatures of at least
+    /// `threshold` of its public keys are, following the Cosmos SDK's
+    /// `LegacyAminoPubKey` rules.
+    pub fn verify_signature<V: Verifier>(
+        &self,
+        msg: &[u8],
+        signature: &SignatureData,
+    ) -> Result<(), Error> {
+        match (self, signature) {
+            (Self::Single(public_key), SignatureData::Single { signature }) => {
+                let signature = TmSignature::try_from(signature.as_slice()).map_err(|e| {
+                    Error::InvalidSignatureData {
+                        reason: e.to_string(),
+                    }
+                })?;
+
+                V::verify(*public_key, msg, &signature).map_err(Error::SignatureVerification)
+            }
+            (
+                Self::Multisig {
+                    threshold,
+                    public_keys,
+                },
+                SignatureData::Multi {
+                    signers,
+                    signatures,
+                },
+            ) => {
+                if signers.len() != public_keys.len() {
+                    return Err(Error::MultisigVerification {
+                        reason: format!(
+                            "bit array size {} does not match the {} public keys",
+                            signers.len(),
+                            public_keys.len()
+                        ),
+                    });
+                }
+
+                let signer_count = signers.iter().filter(|signed| **signed).count();
+
+                if signer_count != signatures.len() {
+                    return Err(Error::MultisigVerification {
+                        reason: format!(
+                            "{} signatures given for {signer_count} signers",
+                            signatures.len()
+                        ),
+                    });
+                }
+
+                if signer_count < *threshold as usize {
+                    return Err(Error::MultisigVerification {
+                        reason: format!(
+                            "{signer_count} signers is below the threshold {threshold}"
+                        ),
+                    });
+                }
+
+                public_keys
+                    .iter()
+                    .zip(signers)
+                    .filter(|(_, signed)| **signed)
+                    .zip(signatures)
+                    .try_for_each(|((public_key, _), signature)| {
+                        public_key.verify_signature::<V>(msg, signature)
+                    })
+            }
+            (Self::Single(_), SignatureData::Multi { .. }) => Err(Error::InvalidSignatureData {
+                reason: "multisig signature given for a single public key".into(),
+            }),
+            (Self::Multisig { .. }, SignatureData::Single { .. }) => {
+                Err(Error::InvalidSignatureData {
+                    reason: "single signature given for a multisig public key".into(),
+                })
+            }
+        }
+    }
+}
+
+impl TryFrom<Any> for PublicKey {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SECP256K1_PUB_KEY_TYPE_URL => {
+                let raw_key: Secp256k1PubKey = decode_raw_key(&raw.value)?;
+
+                TmPublicKey::from_raw_secp256k1(&raw_key.key)
+                    .map(Self::Single)
+                    .ok_or_else(|| Error::InvalidPublicKey {
+                        reason: "invalid secp256k1 public key".into(),
+                    })
+            }
+            ED25519_PUB_KEY_TYPE_URL => {
+                let raw_key: Ed25519PubKey = decode_raw_key(&raw.value)?;
+
+                TmPublicKey::from_raw_ed25519(&raw_key.key)
+                    .map(Self::Single)
+                    .ok_or_else(|| Error::InvalidPublicKey {
+                        reason: "invalid ed25519 public key".into(),
+                    })
+            }
+            MULTISIG_PUB_KEY_TYPE_URL => {
+                let raw_key: LegacyAminoPubKey = decode_raw_key(&raw.value)?;
+
+                if raw_key.threshold == 0 || raw_key.threshold as usize > raw_key.public_keys.len()
+                {
+                    return Err(Error::InvalidPublicKey {
+                        reason: format!(
+                            "invalid multisig threshold {} for {} public keys",
+                            raw_key.threshold,
+                            raw_key.public_keys.len()
+                        ),
+                    });
+                }
+
+                let public_keys = raw_key
+                    .public_keys
+                    .into_iter()
+                    .map(Self::try_from)
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Self::Multisig {
+                    threshold: raw_key.threshold,
+                    public_keys,
+                })
+            }
+            _ => Err(Error::UnsupportedPublicKey {
+                type_url: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(public_key: PublicKey) -> Self {
+        match public_key {
+            PublicKey::Single(public_key @ TmPublicKey::Ed25519(_)) => Any {
+                type_url: ED25519_PUB_KEY_TYPE_URL.to_string(),
+                value: prost::Message::encode_to_vec(&Ed25519PubKey {
+                    key: public_key.to_bytes(),
+                }),
+            },
+            PublicKey::Single(public_key) => Any {
+                type_url: SECP256K1_PUB_KEY_TYPE_URL.to_string(),
+                value: prost::Message::encode_to_vec(&Secp256k1PubKey {
+                    key: public_key.to_bytes(),
+                }),
+            },
+            PublicKey::Multisig {
+                threshold,
+                public_keys,
+            } => Any {
+                type_url: MULTISIG_PUB_KEY_TYPE_URL.to_string(),
+                value: prost::Message::encode_to_vec(&LegacyAminoPubKey {
+                    threshold,
+                    public_keys: public_keys.into_iter().map(Into::into).collect(),
+                }),
+            },
+        }
+    }
+}
+
+impl Protobuf<Any> for PublicKey {}
+
+fn decode_raw_key<M: prost::Message + Default>(value: &[u8]) -> Result<M, Error> {
+    M::decode(value).map_err(|e| Error::InvalidPublicKey {
+        reason: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint::crypto::default::signature::Verifier;
+
+    use super::*;
+    use crate::fixtures::*;
+
+    fn multisig() -> PublicKey {
+        PublicKey::Multisig {
+            threshold: 2,
+            public_keys: vec![
+                PublicKey::Single(ed25519_key(ED25519_PUB_KEY_A)),
+                PublicKey::Single(secp256k1_key(SECP256K1_PUB_KEY_C)),
+                PublicKey::Single(ed25519_key(ED25519_PUB_KEY_B)),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_single_signature() {
+        let msg = decode_hex(PROOF_SIGN_BYTES);
+
+        let signature_a = SignatureData::Single {
+            signature: decode_hex(PROOF_SIGNATURE_A),
+        };
+        let signature_c = SignatureData::Single {
+            signature: decode_hex(PROOF_SIGNATURE_C),
+        };
+
+        PublicKey::Single(ed25519_key(ED25519_PUB_KEY_A))
+            .verify_signature::<Verifier>(&msg, &signature_a)
+            .unwrap();
+        PublicKey::Single(secp256k1_key(SECP256K1_PUB_KEY_C))
+            .verify_signature::<Verifier>(&msg, &signature_c)
+            .unwrap();
+
+        assert!(PublicKey::Single(ed25519_key(ED25519_PUB_KEY_B))
+            .verify_signature::<Verifier>(&msg, &signature_a)
+            .is_err());
+        assert!(PublicKey::Single(ed25519_key(ED25519_PUB_KEY_A))
+            .verify_signature::<Verifier>(&msg[1..], &signature_a)
+            .is_err());
+    }
+
+    #[test]
+    fn test_multisig_signature() {
+        let msg = decode_hex(PROOF_SIGN_BYTES);
+
+        let signature_a = SignatureData::Single {
+            signature: decode_hex(PROOF_SIGNATURE_A),
+        };
+        let signature_c = SignatureData::Single {
+            signature: decode_hex(PROOF_SIGNATURE_C),
+        };
+
+        let signature = SignatureData::Multi {
+            signers: vec![true, true, false],
+            signatures: vec![signature_a.clone(), signature_c.clone()],
+        };
+        multisig()
+            .verify_signature::<Verifier>(&msg, &signature)
+            .unwrap();
+
+        // below the threshold
+        let signature = SignatureData::Multi {
+            signers: vec![true, false, false],
+            signatures: vec![signature_a.clone()],
+        };
+        assert!(multisig()
+            .verify_signature::<Verifier>(&msg, &signature)
+            .is_err());
+
+        // signatures attributed to the wrong signers
+        let signature = SignatureData::Multi {
+            signers: vec![false, true, true],
+            signatures: vec![signature_a.clone(), signature_c.clone()],
+        };
+        assert!(multisig()
+            .verify_signature::<Verifier>(&msg, &signature)
+            .is_err());
+
+        // bit array not matching the public keys
+        let signature = SignatureData::Multi {
+            signers: vec![true, true],
+            signatures: vec![signature_a, signature_c],
+        };
+        assert!(multisig()
+            .verify_signature::<Verifier>(&msg, &signature)
+            .is_err());
+    }
+
+    #[test]
+    fn test_public_key_any_roundtrip() {
+        let public_key = multisig();
+
+        let any = Any::from(public_key.clone());
+
+        assert_eq!(any.type_url, MULTISIG_PUB_KEY_TYPE_URL);
+        assert_eq!(PublicKey::try_from(any).unwrap(), public_key);
+    }
+
+    #[test]
+    fn test_invalid_multisig_threshold() {
+        let any = Any {
+            type_url: MULTISIG_PUB_KEY_TYPE_URL.to_string(),
+            value: prost::Message::encode_to_vec(&LegacyAminoPubKey {
+                threshold: 2,
+                public_keys: vec![PublicKey::Single(ed25519_key(ED25519_PUB_KEY_A)).into()],
+            }),
+        };
+
+        assert!(PublicKey::try_from(any).is_err());
+    }
+}
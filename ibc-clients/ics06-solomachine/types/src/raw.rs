@@ -0,0 +1,101 @@
+//! Protobuf definitions of the Cosmos SDK public keys and signature data a
+//! solo machine signs with, mirroring those of `cosmos-sdk`. Only the
+//! messages the solo machine client decodes are defined here.
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+
+/// A secp256k1 public key, in its 33-byte compressed form.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Secp256k1PubKey {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: Vec<u8>,
+}
+
+impl prost::Name for Secp256k1PubKey {
+    const NAME: &'static str = "PubKey";
+    const PACKAGE: &'static str = "cosmos.crypto.secp256k1";
+}
+
+/// An ed25519 public key.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Ed25519PubKey {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: Vec<u8>,
+}
+
+impl prost::Name for Ed25519PubKey {
+    const NAME: &'static str = "PubKey";
+    const PACKAGE: &'static str = "cosmos.crypto.ed25519";
+}
+
+/// A `threshold`-of-`public_keys` multisig public key.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LegacyAminoPubKey {
+    #[prost(uint32, tag = "1")]
+    pub threshold: u32,
+    #[prost(message, repeated, tag = "2")]
+    pub public_keys: Vec<Any>,
+}
+
+impl prost::Name for LegacyAminoPubKey {
+    const NAME: &'static str = "LegacyAminoPubKey";
+    const PACKAGE: &'static str = "cosmos.crypto.multisig";
+}
+
+/// A bit array whose last byte only stores `extra_bits_stored` bits.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CompactBitArray {
+    #[prost(uint32, tag = "1")]
+    pub extra_bits_stored: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub elems: Vec<u8>,
+}
+
+impl prost::Name for CompactBitArray {
+    const NAME: &'static str = "CompactBitArray";
+    const PACKAGE: &'static str = "cosmos.crypto.multisig.v1beta1";
+}
+
+/// The signature data of a single or multisig public key, i.e. the
+/// `SignatureDescriptor.Data` message of the Cosmos SDK.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignatureData {
+    #[prost(oneof = "signature_data::Sum", tags = "1, 2")]
+    pub sum: Option<signature_data::Sum>,
+}
+
+impl prost::Name for SignatureData {
+    const NAME: &'static str = "SignatureDescriptor.Data";
+    const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+}
+
+pub mod signature_data {
+    use super::*;
+
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Sum {
+        #[prost(message, tag = "1")]
+        Single(Single),
+        #[prost(message, tag = "2")]
+        Multi(Multi),
+    }
+
+    /// The signature of a single public key.
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Single {
+        #[prost(int32, tag = "1")]
+        pub mode: i32,
+        #[prost(bytes = "vec", tag = "2")]
+        pub signature: Vec<u8>,
+    }
+
+    /// The signatures of the signers of a multisig public key, flagged in
+    /// `bitarray`.
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Multi {
+        #[prost(message, optional, tag = "1")]
+        pub bitarray: Option<CompactBitArray>,
+        #[prost(message, repeated, tag = "2")]
+        pub signatures: Vec<SignatureData>,
+    }
+}
@@ -0,0 +1,208 @@
+//! Defines the data a solo machine signs, along with the signed data carried
+//! by proofs and misbehaviour
+
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::ibc::lightclients::solomachine::v3::{
+    HeaderData as RawHeaderData, SignBytes as RawSignBytes,
+    SignatureAndData as RawSignatureAndData,
+    TimestampedSignatureData as RawTimestampedSignatureData,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::public_key::PublicKey;
+use crate::signature::SignatureData;
+
+/// The path signed over by a solo machine updating its public key and
+/// diversifier.
+pub const SENTINEL_HEADER_PATH: &str = "solomachine:header";
+
+/// The bytes a solo machine signs, binding the signed `data` at `path` to
+/// its current sequence and diversifier.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignBytes {
+    pub sequence: u64,
+    pub timestamp: Timestamp,
+    pub diversifier: String,
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+impl Protobuf<RawSignBytes> for SignBytes {}
+
+impl TryFrom<RawSignBytes> for SignBytes {
+    type Error = Error;
+
+    fn try_from(raw: RawSignBytes) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: raw.sequence,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)?,
+            diversifier: raw.diversifier,
+            path: raw.path,
+            data: raw.data,
+        })
+    }
+}
+
+impl From<SignBytes> for RawSignBytes {
+    fn from(value: SignBytes) -> Self {
+        Self {
+            sequence: value.sequence,
+            timestamp: value.timestamp.nanoseconds(),
+            diversifier: value.diversifier,
+            path: value.path,
+            data: value.data,
+        }
+    }
+}
+
+/// The data signed by a solo machine updating its public key and
+/// diversifier.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeaderData {
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl Protobuf<RawHeaderData> for HeaderData {}
+
+impl TryFrom<RawHeaderData> for HeaderData {
+    type Error = Error;
+
+    fn try_from(raw: RawHeaderData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            new_public_key: raw.new_pub_key.ok_or(Error::MissingPublicKey)?.try_into()?,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<HeaderData> for RawHeaderData {
+    fn from(value: HeaderData) -> Self {
+        Self {
+            new_pub_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+/// The signature over some data along with the timestamp it was signed at,
+/// as carried by the proofs of a solo machine.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimestampedSignatureData {
+    pub signature_data: SignatureData,
+    pub timestamp: Timestamp,
+}
+
+impl Protobuf<RawTimestampedSignatureData> for TimestampedSignatureData {}
+
+impl TryFrom<RawTimestampedSignatureData> for TimestampedSignatureData {
+    type Error = Error;
+
+    fn try_from(raw: RawTimestampedSignatureData) -> Result<Self, Self::Error> {
+        if raw.signature_data.is_empty() {
+            return Err(Error::EmptySignature);
+        }
+
+        if raw.timestamp == 0 {
+            return Err(Error::ZeroTimestamp);
+        }
+
+        Ok(Self {
+            signature_data: SignatureData::decode_vec(&raw.signature_data).map_err(|e| {
+                Error::InvalidSignatureData {
+                    reason: e.to_string(),
+                }
+            })?,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)?,
+        })
+    }
+}
+
+impl From<TimestampedSignatureData> for RawTimestampedSignatureData {
+    fn from(value: TimestampedSignatureData) -> Self {
+        Self {
+            signature_data: value.signature_data.encode_vec(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// A signature along with the data it signs, as carried by the
+/// misbehaviour of a solo machine.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureAndData {
+    pub signature: SignatureData,
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+    pub timestamp: Timestamp,
+}
+
+impl SignatureAndData {
+    /// Returns the bytes the signature was produced over, given the sequence
+    /// of the misbehaviour and the diversifier of the solo machine.
+    pub fn sign_bytes(&self, sequence: u64, diversifier: &str) -> SignBytes {
+        SignBytes {
+            sequence,
+            timestamp: self.timestamp,
+            diversifier: diversifier.to_string(),
+            path: self.path.clone(),
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl Protobuf<RawSignatureAndData> for SignatureAndData {}
+
+impl TryFrom<RawSignatureAndData> for SignatureAndData {
+    type Error = Error;
+
+    fn try_from(raw: RawSignatureAndData) -> Result<Self, Self::Error> {
+        if raw.signature.is_empty() {
+            return Err(Error::EmptySignature);
+        }
+
+        if raw.path.is_empty() {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "signed path cannot be empty".into(),
+            });
+        }
+
+        if raw.data.is_empty() {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "signed data cannot be empty".into(),
+            });
+        }
+
+        if raw.timestamp == 0 {
+            return Err(Error::ZeroTimestamp);
+        }
+
+        Ok(Self {
+            signature: SignatureData::decode_vec(&raw.signature).map_err(|e| {
+                Error::InvalidSignatureData {
+                    reason: e.to_string(),
+                }
+            })?,
+            path: raw.path,
+            data: raw.data,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)?,
+        })
+    }
+}
+
+impl From<SignatureAndData> for RawSignatureAndData {
+    fn from(value: SignatureAndData) -> Self {
+        Self {
+            signature: value.signature.encode_vec(),
+            path: value.path,
+            data: value.data,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
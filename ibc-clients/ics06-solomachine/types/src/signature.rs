@@ -0,0 +1,212 @@
+//! Defines the signature data a solo machine produces over `SignBytes`
+
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+
+use crate::error::Error;
+use crate::raw::signature_data::{Multi as RawMulti, Single as RawSingle, Sum as RawSum};
+use crate::raw::{CompactBitArray as RawCompactBitArray, SignatureData as RawSignatureData};
+
+/// The signature data of a single or multisig public key.
+///
+/// The sign mode of the raw signature data is ignored, since solo machine
+/// signatures are always verified over the encoded
+/// [`SignBytes`](crate::SignBytes) directly.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureData {
+    /// The signature of a single public key.
+    Single { signature: Vec<u8> },
+    /// The signatures of a multisig public key. `signers` flags which of the
+    /// public keys of the multisig signed, and `signatures` holds their
+    /// signatures in the same order.
+    Multi {
+        signers: Vec<bool>,
+        signatures: Vec<SignatureData>,
+    },
+}
+
+impl SignatureData {
+    /// Returns `true` if there is no signature at all.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Single { signature } => signature.is_empty(),
+            Self::Multi { signatures, .. } => signatures.is_empty(),
+        }
+    }
+
+    /// Returns the number of signatures to check, i.e. the single signatures
+    /// held, however deeply nested in multisigs.
+    pub fn signature_count(&self) -> u64 {
+        match self {
+            Self::Single { .. } => 1,
+            Self::Multi { signatures, .. } => signatures.iter().map(Self::signature_count).sum(),
+        }
+    }
+}
+
+impl Protobuf<RawSignatureData> for SignatureData {}
+
+impl TryFrom<RawSignatureData> for SignatureData {
+    type Error = Error;
+
+    fn try_from(raw: RawSignatureData) -> Result<Self, Self::Error> {
+        match raw.sum {
+            Some(RawSum::Single(single)) => Ok(Self::Single {
+                signature: single.signature,
+            }),
+            Some(RawSum::Multi(multi)) => {
+                let signers = multi
+                    .bitarray
+                    .map(decode_bit_array)
+                    .transpose()?
+                    .ok_or_else(|| Error::InvalidSignatureData {
+                        reason: "missing multisig bit array".into(),
+                    })?;
+
+                let signatures = multi
+                    .signatures
+                    .into_iter()
+                    .map(Self::try_from)
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Self::Multi {
+                    signers,
+                    signatures,
+                })
+            }
+            None => Err(Error::InvalidSignatureData {
+                reason: "missing signature data".into(),
+            }),
+        }
+    }
+}
+
+impl From<SignatureData> for RawSignatureData {
+    fn from(value: SignatureData) -> Self {
+        let sum = match value {
+            SignatureData::Single { signature } => RawSum::Single(RawSingle { mode: 0, signature }),
+            SignatureData::Multi {
+                signers,
+                signatures,
+            } => RawSum::Multi(RawMulti {
+                bitarray: Some(encode_bit_array(&signers)),
+                signatures: signatures.into_iter().map(Into::into).collect(),
+            }),
+        };
+
+        Self { sum: Some(sum) }
+    }
+}
+
+/// Decodes a `CompactBitArray`, whose bits are stored most significant
+/// first and whose last byte only stores `extra_bits_stored` bits.
+fn decode_bit_array(raw: RawCompactBitArray) -> Result<Vec<bool>, Error> {
+    let extra_bits = raw.extra_bits_stored as usize;
+
+    let size = match (extra_bits, raw.elems.len()) {
+        (0, len) => len * 8,
+        (1..=7, len) if len > 0 => (len - 1) * 8 + extra_bits,
+        _ => {
+            return Err(Error::InvalidSignatureData {
+                reason: format!(
+                    "invalid bit array: {} extra bits stored over {} bytes",
+                    raw.extra_bits_stored,
+                    raw.elems.len()
+                ),
+            })
+        }
+    };
+
+    Ok((0..size)
+        .map(|i| raw.elems[i / 8] & (1 << (7 - i % 8)) != 0)
+        .collect())
+}
+
+fn encode_bit_array(bits: &[bool]) -> RawCompactBitArray {
+    let mut elems = vec![0u8; (bits.len() + 7) / 8];
+
+    for (i, _) in bits.iter().enumerate().filter(|(_, bit)| **bit) {
+        elems[i / 8] |= 1 << (7 - i % 8);
+    }
+
+    RawCompactBitArray {
+        extra_bits_stored: (bits.len() % 8) as u32,
+        elems,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_array_roundtrip() {
+        for signers in [
+            vec![],
+            vec![true],
+            vec![false, true, true],
+            vec![true, false, false, false, false, false, false, true],
+            vec![true, false, false, false, false, false, false, false, true],
+        ] {
+            let raw = encode_bit_array(&signers);
+
+            assert_eq!(decode_bit_array(raw).unwrap(), signers);
+        }
+    }
+
+    #[test]
+    fn test_invalid_bit_array() {
+        let raw = RawCompactBitArray {
+            extra_bits_stored: 3,
+            elems: vec![],
+        };
+        assert!(decode_bit_array(raw).is_err());
+
+        let raw = RawCompactBitArray {
+            extra_bits_stored: 8,
+            elems: vec![0xff],
+        };
+        assert!(decode_bit_array(raw).is_err());
+    }
+
+    #[test]
+    fn test_signature_data_roundtrip() {
+        let signature_data = SignatureData::Multi {
+            signers: vec![true, false, true],
+            signatures: vec![
+                SignatureData::Single {
+                    signature: vec![1; 64],
+                },
+                SignatureData::Single {
+                    signature: vec![2; 64],
+                },
+            ],
+        };
+
+        let encoded = signature_data.clone().encode_vec();
+
+        assert_eq!(SignatureData::decode_vec(&encoded).unwrap(), signature_data);
+    }
+
+    #[test]
+    fn test_signature_count() {
+        let single = || SignatureData::Single {
+            signature: vec![1; 64],
+        };
+
+        let signature_data = SignatureData::Multi {
+            signers: vec![true, true],
+            signatures: vec![
+                single(),
+                SignatureData::Multi {
+                    signers: vec![true, false, true],
+                    signatures: vec![single(), single()],
+                },
+            ],
+        };
+
+        assert_eq!(single().signature_count(), 1);
+        assert_eq!(signature_data.signature_count(), 3);
+    }
+}
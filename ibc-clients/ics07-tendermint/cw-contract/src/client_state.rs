@@ -0,0 +1,228 @@
+//! Defines the client state of the contract, a newtype wrapper around the
+//! Tendermint `ClientState` verifying client messages with the
+//! [`CwHostFunctions`].
+
+use ibc_client_cw::context::Context;
+use ibc_client_tendermint::client_state::{
+    check_for_misbehaviour, check_substitute, initialise, prune_expired_consensus_states, status,
+    update_on_misbehaviour, update_on_recovery, update_on_upgrade, update_state,
+    verify_client_message, ClientState as TmClientState,
+};
+use ibc_client_tendermint::host_functions::{HostSha256, HostVerifier};
+use ibc_client_tendermint::types::ClientState as ClientStateType;
+use ibc_core::client::context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::host::types::identifiers::{ClientId, ClientType as ClientTypeId};
+use ibc_core::host::types::path::Path;
+use ibc_core::primitives::proto::Any;
+
+use crate::client_type::TendermintClient;
+use crate::host_functions::CwHostFunctions;
+
+type TendermintContext<'a> = Context<'a, TendermintClient>;
+
+/// Newtype wrapper around the Tendermint `ClientState`, delegating to it but
+/// for the verification of client messages, which goes through the
+/// [`CwHostFunctions`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState(TmClientState);
+
+impl ClientState {
+    pub fn inner(&self) -> &ClientStateType {
+        self.0.inner()
+    }
+}
+
+impl From<ClientStateType> for ClientState {
+    fn from(client_state: ClientStateType) -> Self {
+        Self(client_state.into())
+    }
+}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(Self(TmClientState::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        self.0.verify_consensus_state(consensus_state)
+    }
+
+    fn client_type(&self) -> ClientTypeId {
+        self.0.client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.0.latest_height()
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        self.0.validate_proof_height(proof_height)
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+        proof_upgrade_client: CommitmentProofBytes,
+        proof_upgrade_consensus_state: CommitmentProofBytes,
+        root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        self.0.verify_upgrade_client(
+            upgraded_client_state,
+            upgraded_consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+            root,
+        )
+    }
+
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.0.verify_membership(prefix, proof, root, path, value)
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        self.0.verify_non_membership(prefix, proof, root, path)
+    }
+}
+
+impl<'a> ClientStateValidation<TendermintContext<'a>> for ClientState {
+    fn verify_client_message(
+        &self,
+        ctx: &TendermintContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message::<_, HostSha256<CwHostFunctions>>(
+            self.inner(),
+            ctx,
+            client_id,
+            client_message,
+            &HostVerifier::<CwHostFunctions>::default(),
+        )
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &TendermintContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(self.inner(), ctx, client_id, client_message)
+    }
+
+    fn status(
+        &self,
+        ctx: &TendermintContext<'a>,
+        client_id: &ClientId,
+    ) -> Result<Status, ClientError> {
+        status(self.inner(), ctx, client_id)
+    }
+
+    fn check_substitute(
+        &self,
+        _ctx: &TendermintContext<'a>,
+        substitute_client_state: Any,
+    ) -> Result<(), ClientError> {
+        check_substitute::<TendermintContext<'a>>(self.inner(), substitute_client_state)
+    }
+}
+
+impl<'a> ClientStateExecution<TendermintContext<'a>> for ClientState {
+    fn initialise(
+        &self,
+        ctx: &mut TendermintContext<'a>,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        initialise(self.inner(), ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut TendermintContext<'a>,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        update_state::<_, HostSha256<CwHostFunctions>>(self.inner(), ctx, client_id, header)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut TendermintContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        update_on_misbehaviour(self.inner(), ctx, client_id, client_message)
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        ctx: &mut TendermintContext<'a>,
+        client_id: &ClientId,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        update_on_upgrade(
+            self.inner(),
+            ctx,
+            client_id,
+            upgraded_client_state,
+            upgraded_consensus_state,
+        )
+    }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut TendermintContext<'a>,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        update_on_recovery(
+            self.inner().clone(),
+            ctx,
+            subject_client_id,
+            substitute_client_state,
+            substitute_consensus_state,
+        )
+    }
+
+    fn prune_expired_consensus_states(
+        &self,
+        ctx: &mut TendermintContext<'a>,
+        client_id: &ClientId,
+        budget: usize,
+    ) -> Result<usize, ClientError> {
+        prune_expired_consensus_states(self.inner(), ctx, client_id, budget)
+    }
+}
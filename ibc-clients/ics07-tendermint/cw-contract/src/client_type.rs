@@ -1,7 +1,8 @@
 use ibc_client_cw::api::ClientType;
-use ibc_client_tendermint::client_state::ClientState;
 use ibc_client_tendermint::consensus_state::ConsensusState;
 
+use crate::client_state::ClientState;
+
 /// A unit struct that represents the Tendermint client type.
 #[derive(Clone, Debug)]
 pub struct TendermintClient;
@@ -0,0 +1,65 @@
+use cosmwasm_std::Api;
+use ibc_client_tendermint::host_functions::{DefaultHostFunctions, HostFunctionsProvider};
+
+/// The host functions of the contract, verifying signatures with the
+/// cryptographic API of the CosmWasm VM, which also batches the verification
+/// of ed25519 signatures.
+///
+/// As the CosmWasm API has no SHA-256 function, the digests are computed
+/// within the contract.
+#[derive(Clone, Debug)]
+pub struct CwHostFunctions;
+
+impl HostFunctionsProvider for CwHostFunctions {
+    const ED25519_BATCH_VERIFY: bool = true;
+
+    fn sha2_256(message: &[u8]) -> [u8; 32] {
+        DefaultHostFunctions::sha2_256(message)
+    }
+
+    fn ed25519_verify(signature: &[u8], message: &[u8], public_key: &[u8]) -> bool {
+        api()
+            .ed25519_verify(message, signature, public_key)
+            .unwrap_or(false)
+    }
+
+    fn secp256k1_verify(signature: &[u8], message: &[u8], public_key: &[u8]) -> bool {
+        api()
+            .secp256k1_verify(&Self::sha2_256(message), signature, public_key)
+            .unwrap_or(false)
+    }
+
+    fn ed25519_batch_verify(
+        signatures: &[&[u8]],
+        messages: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> bool {
+        api()
+            .ed25519_batch_verify(messages, signatures, public_keys)
+            .unwrap_or(false)
+    }
+}
+
+/// Returns the API of the CosmWasm VM running the contract.
+#[cfg(target_arch = "wasm32")]
+fn api() -> impl Api {
+    cosmwasm_std::ExternalApi::new()
+}
+
+/// Returns the mock API of `cosmwasm-std`, backed by the same cryptographic
+/// implementations as the VM, when the contract is not compiled to Wasm and
+/// the `mock-api` feature is enabled, e.g. to run it natively in tests.
+#[cfg(all(not(target_arch = "wasm32"), feature = "mock-api"))]
+fn api() -> impl Api {
+    cosmwasm_std::testing::MockApi::default()
+}
+
+/// Fails when the contract is neither compiled to Wasm nor allowed to verify
+/// signatures with the mock API, as there is then no VM to verify them with.
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "mock-api")))]
+fn api() -> cosmwasm_std::testing::MockApi {
+    panic!(
+        "the host functions of the contract are only available when compiled to Wasm, \
+         or natively with the `mock-api` feature"
+    )
+}
@@ -11,5 +11,7 @@
 )]
 #![forbid(unsafe_code)]
 
+pub mod client_state;
 pub mod client_type;
 pub mod entrypoint;
+pub mod host_functions;
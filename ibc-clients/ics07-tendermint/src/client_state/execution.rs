@@ -1,15 +1,19 @@
 use ibc_client_tendermint_types::{
     ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
+    HeaderBundle as TmHeaderBundle, TENDERMINT_HEADER_BUNDLE_TYPE_URL,
 };
 use ibc_core_client::context::prelude::*;
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::Height;
+use ibc_core_handler_types::metering::CostEvent;
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
+use tendermint::crypto::Sha256;
 
-use super::ClientState;
+use super::{header_hash, ClientState};
+use crate::host_functions::{DefaultHostFunctions, HostSha256};
 
 impl<E> ClientStateExecution<E> for ClientState
 where
@@ -33,7 +37,7 @@ where
         client_id: &ClientId,
         header: Any,
     ) -> Result<Vec<Height>, ClientError> {
-        update_state(self.inner(), ctx, client_id, header)
+        update_state::<E, HostSha256<DefaultHostFunctions>>(self.inner(), ctx, client_id, header)
     }
 
     fn update_state_on_misbehaviour(
@@ -78,8 +82,23 @@ where
             substitute_consensus_state,
         )
     }
+
+    fn prune_expired_consensus_states(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        budget: usize,
+    ) -> Result<usize, ClientError> {
+        prune_expired_consensus_states(self.inner(), ctx, client_id, budget)
+    }
 }
 
+/// The maximum number of consensus states pruned by a client update.
+///
+/// It is twice the number of consensus states an update stores, so that a
+/// backlog of expired consensus states shrinks with every update.
+pub const UPDATE_PRUNING_BUDGET: usize = 2;
+
 /// Seed the host store with initial client and consensus states.
 ///
 /// Note that this function is typically implemented as part of the
@@ -127,10 +146,19 @@ where
 /// Update the host store with a new client state, pruning old states from the
 /// store if need be.
 ///
+/// The header may also be a header bundle, in which case the client is
+/// updated to its final header only.
+///
+/// The header verified against the consensus state of the client, i.e. the
+/// first header of a bundle, is recorded in the verified-header cache of the
+/// host, hashed with `H`, which must be the hasher the header was verified
+/// with. It is recorded here rather than upon verification, so that only the
+/// headers of the updates actually executed are cached.
+///
 /// Note that this function is typically implemented as part of the
 /// [`ClientStateExecution`] trait, but has been made a standalone function
 /// in order to make the ClientState APIs more flexible.
-pub fn update_state<E>(
+pub fn update_state<E, H>(
     client_state: &ClientStateType,
     ctx: &mut E,
     client_id: &ClientId,
@@ -141,8 +169,23 @@ where
     E::ClientStateRef: From<ClientStateType>,
     ConsensusStateType: Convertible<E::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+    H: Sha256,
 {
-    let header = TmHeader::try_from(header)?;
+    let (verified_header, header) = match header.type_url.as_str() {
+        TENDERMINT_HEADER_BUNDLE_TYPE_URL => {
+            let header_bundle = TmHeaderBundle::try_from(header)?;
+
+            (
+                header_bundle.first_header().clone(),
+                header_bundle.into_final_header(),
+            )
+        }
+        _ => {
+            let header = TmHeader::try_from(header)?;
+
+            (header.clone(), header)
+        }
+    };
     let header_height = header.height();
 
     prune_oldest_consensus_state(client_state, ctx, client_id)?;
@@ -189,6 +232,12 @@ where
         )?;
     }
 
+    ctx.cache_verified_header(
+        client_id,
+        &header_hash::<H>(&verified_header, &client_state.as_light_client_options()?),
+        &verified_header.trusted_height,
+    )?;
+
     Ok(vec![header_height])
 }
 
@@ -311,6 +360,9 @@ where
 /// Removes consensus states from the client store whose timestamps
 /// are less than or equal to the host timestamp. This ensures that
 /// the client store does not amass a buildup of stale consensus states.
+///
+/// At most [`UPDATE_PRUNING_BUDGET`] consensus states are pruned, hosts
+/// pruning the rest in bulk with [`prune_expired_consensus_states`].
 pub fn prune_oldest_consensus_state<E>(
     client_state: &ClientStateType,
     ctx: &mut E,
@@ -322,9 +374,47 @@ where
     ConsensusStateType: Convertible<E::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
 {
-    let mut heights = ctx.consensus_state_heights(client_id)?;
+    prune_expired_consensus_states(client_state, ctx, client_id, UPDATE_PRUNING_BUDGET)?;
 
-    heights.sort();
+    Ok(())
+}
+
+/// Removes the expired consensus states of the client, oldest first, and
+/// returns how many were removed, which is at most `budget`.
+///
+/// Only the first `budget` heights of the client are read, through
+/// [`ExtClientValidationContext::ascending_consensus_state_heights`], hence
+/// the work done is bounded by the budget however many consensus states
+/// the client has.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn prune_expired_consensus_states<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    budget: usize,
+) -> Result<usize, ClientError>
+where
+    E: ClientExecutionContext + ExtClientValidationContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    let host_timestamp =
+        ctx.host_timestamp()?
+            .into_tm_time()
+            .ok_or_else(|| ClientError::Other {
+                description: String::from("host timestamp is not a valid TM timestamp"),
+            })?;
+
+    let heights: Vec<Height> = ctx
+        .ascending_consensus_state_heights(client_id)?
+        .take(budget)
+        .collect();
+
+    let mut pruned = 0;
 
     for height in heights {
         let client_consensus_state_path = ClientConsensusStatePath::new(
@@ -336,13 +426,6 @@ where
         let tm_consensus_state: ConsensusStateType =
             consensus_state.try_into().map_err(Into::into)?;
 
-        let host_timestamp =
-            ctx.host_timestamp()?
-                .into_tm_time()
-                .ok_or_else(|| ClientError::Other {
-                    description: String::from("host timestamp is not a valid TM timestamp"),
-                })?;
-
         let tm_consensus_state_timestamp = tm_consensus_state.timestamp();
         let tm_consensus_state_expiry = (tm_consensus_state_timestamp
             + client_state.trusting_period)
@@ -356,11 +439,14 @@ where
             break;
         }
 
+        ctx.charge(CostEvent::ConsensusStatesPruned { count: 1 })?;
         ctx.delete_consensus_state(client_consensus_state_path)?;
         ctx.delete_update_meta(client_id.clone(), height)?;
+
+        pruned += 1;
     }
 
-    Ok(())
+    Ok(pruned)
 }
 
 /// Update the `client_state`'s ID, trusting period, latest height, processed height,
@@ -4,6 +4,7 @@ use ibc_client_tendermint_types::{
 };
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::metering::CostEvent;
 use ibc_core_host::types::identifiers::{ChainId, ClientId};
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
@@ -14,6 +15,7 @@ use tendermint::{Hash, Time};
 use tendermint_light_client_verifier::options::Options;
 use tendermint_light_client_verifier::Verifier;
 
+use super::{commit_signature_count, header_hash};
 use crate::types::Header;
 
 /// Determines whether or not two conflicting headers at the same height would
@@ -60,24 +62,40 @@ where
 
     let current_timestamp = ctx.host_timestamp()?;
 
-    verify_misbehaviour_header::<H>(
-        header_1,
-        chain_id,
-        options,
-        trusted_consensus_state_1.timestamp(),
-        trusted_consensus_state_1.next_validators_hash,
-        current_timestamp,
-        verifier,
-    )?;
-    verify_misbehaviour_header::<H>(
-        header_2,
-        chain_id,
-        options,
-        trusted_consensus_state_2.timestamp(),
-        trusted_consensus_state_2.next_validators_hash,
-        current_timestamp,
-        verifier,
-    )
+    // Headers already verified by a client update are not verified again.
+    let mut headers = Vec::new();
+
+    for (header, trusted_consensus_state) in [
+        (header_1, trusted_consensus_state_1),
+        (header_2, trusted_consensus_state_2),
+    ] {
+        let header_hash = header_hash::<H>(header, options);
+
+        if !ctx.is_header_verified(client_id, &header_hash, &header.trusted_height)? {
+            headers.push((header, trusted_consensus_state));
+        }
+    }
+
+    ctx.charge(CostEvent::SignaturesChecked {
+        count: headers
+            .iter()
+            .map(|(header, _)| commit_signature_count(header))
+            .sum(),
+    })?;
+
+    for (header, trusted_consensus_state) in headers {
+        verify_misbehaviour_header::<H>(
+            header,
+            chain_id,
+            options,
+            trusted_consensus_state.timestamp(),
+            trusted_consensus_state.next_validators_hash,
+            current_timestamp,
+            verifier,
+        )?;
+    }
+
+    Ok(())
 }
 
 pub fn verify_misbehaviour_header<H>(
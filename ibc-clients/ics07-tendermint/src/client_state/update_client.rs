@@ -1,11 +1,17 @@
 use ibc_client_tendermint_types::error::{Error, IntoResult};
-use ibc_client_tendermint_types::{ConsensusState as ConsensusStateType, Header as TmHeader};
+use ibc_client_tendermint_types::proto::v1::Header as RawHeader;
+use ibc_client_tendermint_types::{
+    ConsensusState as ConsensusStateType, Header as TmHeader, HeaderBundle as TmHeaderBundle,
+};
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::Height;
+use ibc_core_handler_types::metering::CostEvent;
 use ibc_core_host::types::identifiers::{ChainId, ClientId};
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+use tendermint::block::CommitSig;
 use tendermint::crypto::Sha256;
 use tendermint::merkle::MerkleHash;
 use tendermint_light_client_verifier::options::Options;
@@ -33,72 +39,187 @@ where
     // to have the same revision number. We ensure this here.
     header.verify_chain_id_version_matches_height(chain_id)?;
 
-    // Delegate to tendermint-light-client, which contains the required checks
-    // of the new header against the trusted consensus state.
-    {
-        let trusted_state = {
-            let trusted_client_cons_state_path = ClientConsensusStatePath::new(
-                client_id.clone(),
-                header.trusted_height.revision_number(),
-                header.trusted_height.revision_height(),
-            );
-            let trusted_consensus_state: ConsensusStateType = ctx
-                .consensus_state(&trusted_client_cons_state_path)?
-                .try_into()
-                .map_err(Into::into)?;
-
-            header.check_trusted_next_validator_set::<H>(
-                &trusted_consensus_state.next_validators_hash,
-            )?;
-
-            TrustedBlockState {
-                chain_id: &chain_id
-                    .as_str()
-                    .try_into()
-                    .map_err(|e| ClientError::Other {
-                        description: format!("failed to parse chain id: {}", e),
-                    })?,
-                header_time: trusted_consensus_state.timestamp(),
-                height: header
-                    .trusted_height
-                    .revision_height()
-                    .try_into()
-                    .map_err(|_| ClientError::ClientSpecific {
-                        description: Error::InvalidHeaderHeight {
-                            height: header.trusted_height.revision_height(),
-                        }
-                        .to_string(),
-                    })?,
-                next_validators: &header.trusted_next_validator_set,
-                next_validators_hash: trusted_consensus_state.next_validators_hash,
-            }
-        };
-
-        let untrusted_state = UntrustedBlockState {
-            signed_header: &header.signed_header,
-            validators: &header.validator_set,
-            // NB: This will skip the
-            // VerificationPredicates::next_validators_match check for the
-            // untrusted state.
-            next_validators: None,
-        };
-
-        let now =
-            ctx.host_timestamp()?
-                .into_tm_time()
-                .ok_or_else(|| ClientError::ClientSpecific {
-                    description: "host timestamp is not a valid TM timestamp".to_string(),
-                })?;
-
-        // main header verification, delegated to the tendermint-light-client crate.
-        verifier
-            .verify_update_header(untrusted_state, trusted_state, options, now)
-            .into_result()?;
+    // Competing relayers may submit the same header within a block, in which
+    // case only the first one is verified. The header is cached by the
+    // execution of the update, see `update_state`.
+    if ctx.is_header_verified(
+        client_id,
+        &header_hash::<H>(header, options),
+        &header.trusted_height,
+    )? {
+        return Ok(());
+    }
+
+    let trusted_consensus_state: ConsensusStateType = {
+        let trusted_client_cons_state_path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            header.trusted_height.revision_number(),
+            header.trusted_height.revision_height(),
+        );
+
+        ctx.consensus_state(&trusted_client_cons_state_path)?
+            .try_into()
+            .map_err(Into::into)?
+    };
+
+    verify_header_against_trusted_state::<V, H>(
+        ctx,
+        header,
+        &trusted_consensus_state,
+        chain_id,
+        options,
+        verifier,
+    )
+}
+
+/// Verifies a bundle of headers as a skipping chain: the first header against
+/// the consensus state of the client at its trusted height, and every other
+/// header against the consensus state derived from the one before it.
+///
+/// The consensus states of the intermediate headers are never stored, so
+/// they only serve as trusted states within this verification.
+pub fn verify_header_bundle<V, H>(
+    ctx: &V,
+    header_bundle: &TmHeaderBundle,
+    client_id: &ClientId,
+    chain_id: &ChainId,
+    options: &Options,
+    verifier: &impl Verifier,
+) -> Result<(), ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    H: MerkleHash + Sha256 + Default,
+{
+    // Checks the fields of every header, and that they form a chain on the
+    // same chain id, hence on the revision checked for the first header.
+    header_bundle.validate_basic::<H>()?;
+
+    let first_header = header_bundle.first_header();
+
+    verify_header::<V, H>(ctx, first_header, client_id, chain_id, options, verifier)?;
+
+    let mut trusted_consensus_state =
+        ConsensusStateType::from(first_header.signed_header.header.clone());
+
+    for header in header_bundle.headers().skip(1) {
+        verify_header_against_trusted_state::<V, H>(
+            ctx,
+            header,
+            &trusted_consensus_state,
+            chain_id,
+            options,
+            verifier,
+        )?;
+
+        trusted_consensus_state = ConsensusStateType::from(header.signed_header.header.clone());
     }
 
     Ok(())
 }
 
+/// Delegates to tendermint-light-client the required checks of the header
+/// against the given consensus state, trusted at the trusted height of the
+/// header.
+fn verify_header_against_trusted_state<V, H>(
+    ctx: &V,
+    header: &TmHeader,
+    trusted_consensus_state: &ConsensusStateType,
+    chain_id: &ChainId,
+    options: &Options,
+    verifier: &impl Verifier,
+) -> Result<(), ClientError>
+where
+    V: ExtClientValidationContext,
+    H: MerkleHash + Sha256 + Default,
+{
+    header.check_trusted_next_validator_set::<H>(&trusted_consensus_state.next_validators_hash)?;
+
+    let trusted_state = TrustedBlockState {
+        chain_id: &chain_id
+            .as_str()
+            .try_into()
+            .map_err(|e| ClientError::Other {
+                description: format!("failed to parse chain id: {}", e),
+            })?,
+        header_time: trusted_consensus_state.timestamp(),
+        height: header
+            .trusted_height
+            .revision_height()
+            .try_into()
+            .map_err(|_| ClientError::ClientSpecific {
+                description: Error::InvalidHeaderHeight {
+                    height: header.trusted_height.revision_height(),
+                }
+                .to_string(),
+            })?,
+        next_validators: &header.trusted_next_validator_set,
+        next_validators_hash: trusted_consensus_state.next_validators_hash,
+    };
+
+    let untrusted_state = UntrustedBlockState {
+        signed_header: &header.signed_header,
+        validators: &header.validator_set,
+        // NB: This will skip the
+        // VerificationPredicates::next_validators_match check for the
+        // untrusted state.
+        next_validators: None,
+    };
+
+    let now = ctx
+        .host_timestamp()?
+        .into_tm_time()
+        .ok_or_else(|| ClientError::ClientSpecific {
+            description: "host timestamp is not a valid TM timestamp".to_string(),
+        })?;
+
+    ctx.charge(CostEvent::SignaturesChecked {
+        count: commit_signature_count(header),
+    })?;
+
+    // main header verification, delegated to the tendermint-light-client crate.
+    verifier
+        .verify_update_header(untrusted_state, trusted_state, options, now)
+        .into_result()?;
+
+    Ok(())
+}
+
+/// Returns the number of signatures of the commit of the given header that
+/// are for the committed block, which are those the light client checks.
+/// Absent and nil votes carry no signature for the block.
+pub fn commit_signature_count(header: &TmHeader) -> u64 {
+    header
+        .signed_header
+        .commit
+        .signatures
+        .iter()
+        .filter(|signature| matches!(signature, CommitSig::BlockIdFlagCommit { .. }))
+        .count() as u64
+}
+
+/// Returns the hash a verified header is cached under, i.e. the hash of its
+/// encoding, which covers the commit and the validator sets provided along
+/// with the signed header, and of the options it is verified with.
+///
+/// The options are those of the client state, so that a header verified
+/// under a given trust level, trusting period or clock drift is not taken as
+/// verified once the client has other parameters.
+pub fn header_hash<H>(header: &TmHeader, options: &Options) -> Vec<u8>
+where
+    H: Sha256,
+{
+    let mut bytes = Protobuf::<RawHeader>::encode_vec(header.clone());
+
+    bytes.extend(options.trust_threshold.numerator().to_be_bytes());
+    bytes.extend(options.trust_threshold.denominator().to_be_bytes());
+    bytes.extend(options.trusting_period.as_nanos().to_be_bytes());
+    bytes.extend(options.clock_drift.as_nanos().to_be_bytes());
+
+    H::digest(bytes).to_vec()
+}
+
 /// Checks for misbehaviour upon receiving a new consensus state as part
 /// of a client update.
 pub fn check_for_misbehaviour_on_update<V>(
@@ -1,6 +1,8 @@
 use ibc_client_tendermint_types::{
     ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
-    Misbehaviour as TmMisbehaviour, TENDERMINT_HEADER_TYPE_URL, TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+    HeaderBundle as TmHeaderBundle, Misbehaviour as TmMisbehaviour,
+    TENDERMINT_HEADER_BUNDLE_TYPE_URL, TENDERMINT_HEADER_TYPE_URL,
+    TENDERMINT_MISBEHAVIOUR_TYPE_URL,
 };
 use ibc_core_client::context::client_state::ClientStateValidation;
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
@@ -10,13 +12,13 @@ use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
-use tendermint::crypto::default::Sha256;
 use tendermint::crypto::Sha256 as Sha256Trait;
 use tendermint::merkle::MerkleHash;
-use tendermint_light_client_verifier::{ProdVerifier, Verifier};
+use tendermint_light_client_verifier::Verifier;
 
 use super::{check_for_misbehaviour_on_misbehavior, check_for_misbehaviour_on_update, ClientState};
-use crate::client_state::{verify_header, verify_misbehaviour};
+use crate::client_state::{verify_header, verify_header_bundle, verify_misbehaviour};
+use crate::host_functions::{DefaultHostFunctions, HostSha256, HostVerifier};
 
 impl<V> ClientStateValidation<V> for ClientState
 where
@@ -27,11 +29,11 @@ where
     /// The default verification logic exposed by ibc-rs simply delegates to a
     /// standalone `verify_client_message` function. This is to make it as
     /// simple as possible for those who merely need the default
-    /// [`ProdVerifier`] behaviour, as well as those who require custom
-    /// verification logic.
+    /// [`HostVerifier`] behaviour over the [`DefaultHostFunctions`], as well
+    /// as those who require custom verification logic.
     ///
-    /// In a situation where the Tendermint [`ProdVerifier`] doesn't provide the
-    /// desired outcome, users should define a custom verifier struct and then
+    /// In a situation where the [`HostVerifier`] doesn't provide the desired
+    /// outcome, users should define a custom verifier struct and then
     /// implement the [`Verifier`] trait for it.
     ///
     /// In order to wire up the custom verifier, create a newtype `ClientState`
@@ -42,19 +44,22 @@ where
     /// of those functions. Then, when it comes to implementing the
     /// `verify_client_message` method, use the [`verify_client_message`]
     /// function and pass your custom verifier object as the `verifier`
-    /// parameter.
+    /// parameter. Hosts merely providing their own cryptographic primitives
+    /// may pass a [`HostVerifier`] over their own
+    /// [`HostFunctionsProvider`](crate::host_functions::HostFunctionsProvider)
+    /// along with the matching [`HostSha256`] hasher.
     fn verify_client_message(
         &self,
         ctx: &V,
         client_id: &ClientId,
         client_message: Any,
     ) -> Result<(), ClientError> {
-        verify_client_message::<V, Sha256>(
+        verify_client_message::<V, HostSha256<DefaultHostFunctions>>(
             self.inner(),
             ctx,
             client_id,
             client_message,
-            &ProdVerifier::default(),
+            &HostVerifier::<DefaultHostFunctions>::default(),
         )
     }
 
@@ -110,6 +115,17 @@ where
                 verifier,
             )
         }
+        TENDERMINT_HEADER_BUNDLE_TYPE_URL => {
+            let header_bundle = TmHeaderBundle::try_from(client_message)?;
+            verify_header_bundle::<V, H>(
+                ctx,
+                &header_bundle,
+                client_id,
+                client_state.chain_id(),
+                &client_state.as_light_client_options()?,
+                verifier,
+            )
+        }
         TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
             let misbehaviour = TmMisbehaviour::try_from(client_message)?;
             verify_misbehaviour::<V, H>(
@@ -173,6 +189,25 @@ where
             let header = TmHeader::try_from(client_message)?;
             check_for_misbehaviour_on_update(ctx, header, client_id, &client_state.latest_height)
         }
+        TENDERMINT_HEADER_BUNDLE_TYPE_URL => {
+            // Every header of the bundle is checked, as an intermediate
+            // header conflicting with a stored consensus state is as much
+            // evidence of misbehaviour as the final one.
+            let header_bundle = TmHeaderBundle::try_from(client_message)?;
+
+            for header in header_bundle.headers() {
+                if check_for_misbehaviour_on_update(
+                    ctx,
+                    header.clone(),
+                    client_id,
+                    &client_state.latest_height,
+                )? {
+                    return Ok(true);
+                }
+            }
+
+            Ok(false)
+        }
         TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
             let misbehaviour = TmMisbehaviour::try_from(client_message)?;
             check_for_misbehaviour_on_misbehavior(misbehaviour.header1(), misbehaviour.header2())
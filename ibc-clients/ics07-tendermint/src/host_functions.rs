@@ -0,0 +1,643 @@
+//! Routes the cryptography of the Tendermint client, i.e. the SHA-256 hashing
+//! and the verification of the signatures of validators, through functions
+//! provided by the host, in the manner of the ICS-23 `HostFunctionsProvider`.
+//!
+//! Hosts with native or precompiled implementations of these primitives, such
+//! as CosmWasm or zkVM hosts, implement [`HostFunctionsProvider`] and verify
+//! client messages with the [`HostVerifier`] and the [`HostSha256`] hasher.
+//! The default implementation of the client verifies them with the
+//! [`DefaultHostFunctions`]:
+//!
+//! ```ignore
+//! verify_client_message::<V, HostSha256<MyHostFunctions>>(
+//!     client_state,
+//!     ctx,
+//!     client_id,
+//!     client_message,
+//!     &HostVerifier::<MyHostFunctions>::default(),
+//! )
+//! ```
+
+use core::marker::PhantomData;
+
+use ibc_primitives::prelude::*;
+use tendermint::block::CommitSig;
+use tendermint::crypto::default::signature::Verifier as DefaultSignatureVerifier;
+use tendermint::crypto::default::Sha256 as DefaultSha256;
+use tendermint::crypto::signature::{Error as SignatureError, Verifier as SignatureVerifier};
+use tendermint::crypto::Sha256;
+use tendermint::merkle::{Hash as MerkleHashOutput, MerkleHash, NonIncremental};
+use tendermint::trust_threshold::TrustThreshold as _;
+use tendermint::validator::Info as ValidatorInfo;
+use tendermint::vote::{SignedVote, Type as VoteType, ValidatorIndex, Vote};
+use tendermint::{PublicKey, Signature};
+use tendermint_light_client_verifier::errors::VerificationError;
+use tendermint_light_client_verifier::operations::{
+    ProdCommitValidator, VotingPowerCalculator, VotingPowerTally,
+};
+use tendermint_light_client_verifier::predicates::VerificationPredicates;
+use tendermint_light_client_verifier::types::{SignedHeader, TrustThreshold, ValidatorSet};
+use tendermint_light_client_verifier::PredicateVerifier;
+use tendermint_proto::v0_38::crypto::public_key::Sum as RawPublicKeySum;
+use tendermint_proto::v0_38::crypto::PublicKey as RawPublicKey;
+
+/// The cryptographic primitives the Tendermint client verifies headers
+/// with, provided by the host.
+pub trait HostFunctionsProvider {
+    /// Whether the host verifies ed25519 signatures as a batch, in which case
+    /// the [`HostVerifier`] verifies those of all the voters of a commit
+    /// through [`Self::ed25519_batch_verify`].
+    ///
+    /// Otherwise, the signatures are verified one at a time until the trust
+    /// threshold is reached, as with the `ProdVerifier`.
+    const ED25519_BATCH_VERIFY: bool = false;
+
+    /// Returns the SHA-256 digest of the message.
+    fn sha2_256(message: &[u8]) -> [u8; 32];
+
+    /// Returns whether the ed25519 signature of the message is valid for the
+    /// given 32-byte public key.
+    fn ed25519_verify(signature: &[u8], message: &[u8], public_key: &[u8]) -> bool;
+
+    /// Returns whether the secp256k1 ECDSA signature of the SHA-256 digest of
+    /// the message is valid for the given SEC1-encoded public key.
+    fn secp256k1_verify(signature: &[u8], message: &[u8], public_key: &[u8]) -> bool;
+
+    /// Returns whether all the ed25519 signatures of the messages are valid
+    /// for their public keys, the signature, message and public key of an
+    /// entry sharing the same index.
+    ///
+    /// Hosts with batch verification should override this method, which
+    /// verifies the signatures one at a time by default, along with
+    /// [`Self::ED25519_BATCH_VERIFY`].
+    fn ed25519_batch_verify(
+        signatures: &[&[u8]],
+        messages: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> bool {
+        signatures.len() == messages.len()
+            && signatures.len() == public_keys.len()
+            && signatures.iter().zip(messages).zip(public_keys).all(
+                |((signature, message), public_key)| {
+                    Self::ed25519_verify(signature, message, public_key)
+                },
+            )
+    }
+}
+
+/// The host functions of the default implementation of the client, backed by
+/// the pure Rust implementations of tendermint-rs.
+///
+/// Note that secp256k1 signatures are only verified when the `secp256k1`
+/// feature of the `tendermint` crate is enabled.
+#[derive(Clone, Debug, Default)]
+pub struct DefaultHostFunctions;
+
+impl HostFunctionsProvider for DefaultHostFunctions {
+    fn sha2_256(message: &[u8]) -> [u8; 32] {
+        DefaultSha256::digest(message)
+    }
+
+    fn ed25519_verify(signature: &[u8], message: &[u8], public_key: &[u8]) -> bool {
+        PublicKey::from_raw_ed25519(public_key)
+            .is_some_and(|public_key| default_verify(public_key, message, signature))
+    }
+
+    fn secp256k1_verify(signature: &[u8], message: &[u8], public_key: &[u8]) -> bool {
+        let raw_public_key = RawPublicKey {
+            sum: Some(RawPublicKeySum::Secp256k1(public_key.to_vec())),
+        };
+
+        PublicKey::try_from(raw_public_key)
+            .is_ok_and(|public_key| default_verify(public_key, message, signature))
+    }
+}
+
+fn default_verify(public_key: PublicKey, message: &[u8], signature: &[u8]) -> bool {
+    Signature::try_from(signature).is_ok_and(|signature| {
+        DefaultSignatureVerifier::verify(public_key, message, &signature).is_ok()
+    })
+}
+
+/// The SHA-256 hasher of the client, computing digests with
+/// [`HostFunctionsProvider::sha2_256`].
+pub struct HostSha256<P>(PhantomData<fn() -> P>);
+
+impl<P> Default for HostSha256<P> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: HostFunctionsProvider> Sha256 for HostSha256<P> {
+    fn digest(data: impl AsRef<[u8]>) -> [u8; 32] {
+        P::sha2_256(data.as_ref())
+    }
+}
+
+impl<P: HostFunctionsProvider> MerkleHash for HostSha256<P> {
+    fn empty_hash(&mut self) -> MerkleHashOutput {
+        NonIncremental::<Self>::default().empty_hash()
+    }
+
+    fn leaf_hash(&mut self, bytes: &[u8]) -> MerkleHashOutput {
+        NonIncremental::<Self>::default().leaf_hash(bytes)
+    }
+
+    fn inner_hash(&mut self, left: MerkleHashOutput, right: MerkleHashOutput) -> MerkleHashOutput {
+        NonIncremental::<Self>::default().inner_hash(left, right)
+    }
+}
+
+/// The signature verifier of the client, verifying the signatures of
+/// validators with [`HostFunctionsProvider::ed25519_verify`] or
+/// [`HostFunctionsProvider::secp256k1_verify`], depending on their keys.
+pub struct HostSignatureVerifier<P>(PhantomData<fn() -> P>);
+
+impl<P: HostFunctionsProvider> SignatureVerifier for HostSignatureVerifier<P> {
+    fn verify(pubkey: PublicKey, msg: &[u8], signature: &Signature) -> Result<(), SignatureError> {
+        let verified = match pubkey.ed25519() {
+            Some(public_key) => P::ed25519_verify(signature.as_bytes(), msg, public_key.as_bytes()),
+            None => P::secp256k1_verify(signature.as_bytes(), msg, &pubkey.to_bytes()),
+        };
+
+        if verified {
+            Ok(())
+        } else {
+            Err(SignatureError::VerificationFailed)
+        }
+    }
+}
+
+/// The voting power calculator of the client, verifying the signatures of a
+/// commit with [`HostSignatureVerifier`].
+///
+/// As with the `ProdVotingPowerCalculator`, signatures are verified until the
+/// voting power of the validators having signed reaches the trust threshold,
+/// unless the host opts into [`HostFunctionsProvider::ED25519_BATCH_VERIFY`].
+/// The ed25519 signatures of all the validators of the set having voted are
+/// then verified through a single call to
+/// [`HostFunctionsProvider::ed25519_batch_verify`].
+pub struct HostVotingPowerCalculator<P>(PhantomData<fn() -> P>);
+
+impl<P> Default for HostVotingPowerCalculator<P> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: HostFunctionsProvider> VotingPowerCalculator for HostVotingPowerCalculator<P> {
+    fn voting_power_in(
+        &self,
+        signed_header: &SignedHeader,
+        validator_set: &ValidatorSet,
+        trust_threshold: TrustThreshold,
+    ) -> Result<VotingPowerTally, VerificationError> {
+        let mut votes = CommitVotes::new(signed_header)?;
+
+        votes.voting_power_in::<P>(
+            validator_set,
+            trust_threshold,
+            self.total_power_of(validator_set),
+        )
+    }
+
+    fn voting_power_in_sets(
+        &self,
+        signed_header: &SignedHeader,
+        first_set: (&ValidatorSet, TrustThreshold),
+        second_set: (&ValidatorSet, TrustThreshold),
+    ) -> Result<(VotingPowerTally, VotingPowerTally), VerificationError> {
+        let mut votes = CommitVotes::new(signed_header)?;
+
+        let first_tally = votes.voting_power_in::<P>(
+            first_set.0,
+            first_set.1,
+            self.total_power_of(first_set.0),
+        )?;
+        let second_tally = votes.voting_power_in::<P>(
+            second_set.0,
+            second_set.1,
+            self.total_power_of(second_set.0),
+        )?;
+
+        Ok((first_tally, second_tally))
+    }
+}
+
+/// A vote for the block of a commit, along with its sign bytes.
+struct CommitVote {
+    signed_vote: SignedVote,
+    sign_bytes: Vec<u8>,
+    /// Whether the signature was verified for a previous validator set.
+    verified: bool,
+}
+
+/// The votes for the block of a commit, sorted by validator address.
+struct CommitVotes(Vec<CommitVote>);
+
+// The error type is the one of the `VotingPowerCalculator` methods.
+#[allow(clippy::result_large_err)]
+impl CommitVotes {
+    fn new(signed_header: &SignedHeader) -> Result<Self, VerificationError> {
+        let commit = &signed_header.commit;
+
+        let mut votes = Vec::with_capacity(commit.signatures.len());
+
+        for (index, commit_sig) in commit.signatures.iter().enumerate() {
+            let CommitSig::BlockIdFlagCommit {
+                validator_address,
+                timestamp,
+                signature,
+            } = commit_sig
+            else {
+                continue;
+            };
+
+            let vote = Vote {
+                vote_type: VoteType::Precommit,
+                height: commit.height,
+                round: commit.round,
+                block_id: Some(commit.block_id),
+                timestamp: Some(*timestamp),
+                validator_address: *validator_address,
+                validator_index: ValidatorIndex::try_from(index)
+                    .map_err(VerificationError::tendermint)?,
+                signature: signature.clone(),
+                extension: Vec::new(),
+                extension_signature: None,
+            };
+
+            let signed_vote = SignedVote::from_vote(vote, signed_header.header.chain_id.clone())
+                .ok_or_else(VerificationError::missing_signature)?;
+
+            votes.push(CommitVote {
+                sign_bytes: signed_vote.sign_bytes(),
+                signed_vote,
+                verified: false,
+            });
+        }
+
+        votes.sort_unstable_by_key(|vote| vote.signed_vote.validator_id());
+
+        if let Some(pair) = votes
+            .windows(2)
+            .find(|pair| pair[0].signed_vote.validator_id() == pair[1].signed_vote.validator_id())
+        {
+            return Err(VerificationError::duplicate_validator(
+                pair[0].signed_vote.validator_id(),
+            ));
+        }
+
+        Ok(Self(votes))
+    }
+
+    /// Tallies the voting power of the validators of the set having voted,
+    /// after verifying the signatures of their votes, all at once if the host
+    /// batches ed25519 verification, or until the trust threshold is reached
+    /// otherwise.
+    fn voting_power_in<P: HostFunctionsProvider>(
+        &mut self,
+        validator_set: &ValidatorSet,
+        trust_threshold: TrustThreshold,
+        total: u64,
+    ) -> Result<VotingPowerTally, VerificationError> {
+        let voters: Vec<(&ValidatorInfo, usize)> = validator_set
+            .validators()
+            .iter()
+            .filter_map(|validator| {
+                self.0
+                    .binary_search_by_key(&validator.address, |vote| {
+                        vote.signed_vote.validator_id()
+                    })
+                    .ok()
+                    .map(|index| (validator, index))
+            })
+            .collect();
+
+        let tallied = if P::ED25519_BATCH_VERIFY {
+            self.verify_batch::<P>(&voters)?;
+
+            voters.iter().map(|(validator, _)| validator.power()).sum()
+        } else {
+            let mut tallied = 0;
+
+            for &voter in &voters {
+                self.verify_one::<P>(voter)?;
+
+                tallied += voter.0.power();
+
+                if trust_threshold.is_enough_power(tallied, total) {
+                    break;
+                }
+            }
+
+            tallied
+        };
+
+        Ok(VotingPowerTally {
+            total,
+            tallied,
+            trust_threshold,
+        })
+    }
+
+    /// Verifies the signatures of the votes of the given validators not yet
+    /// verified, the ed25519 ones as a batch.
+    fn verify_batch<P: HostFunctionsProvider>(
+        &mut self,
+        voters: &[(&ValidatorInfo, usize)],
+    ) -> Result<(), VerificationError> {
+        let (ed25519_voters, other_voters): (Vec<_>, Vec<_>) = voters
+            .iter()
+            .copied()
+            .filter(|(_, index)| !self.0[*index].verified)
+            .partition(|(validator, _)| validator.pub_key.ed25519().is_some());
+
+        let public_keys: Vec<_> = ed25519_voters
+            .iter()
+            .filter_map(|(validator, _)| validator.pub_key.ed25519())
+            .collect();
+        let public_keys: Vec<&[u8]> = public_keys.iter().map(|key| key.as_bytes()).collect();
+        let signatures: Vec<&[u8]> = ed25519_voters
+            .iter()
+            .map(|(_, index)| self.0[*index].signed_vote.signature().as_bytes())
+            .collect();
+        let messages: Vec<&[u8]> = ed25519_voters
+            .iter()
+            .map(|(_, index)| self.0[*index].sign_bytes.as_slice())
+            .collect();
+
+        let batch_verified = ed25519_voters.is_empty()
+            || P::ed25519_batch_verify(&signatures, &messages, &public_keys);
+
+        // A failed batch is verified again one signature at a time, in order
+        // to report the invalid one.
+        let voters_to_verify = if batch_verified {
+            other_voters
+        } else {
+            ed25519_voters
+                .iter()
+                .chain(&other_voters)
+                .copied()
+                .collect()
+        };
+
+        for voter in voters_to_verify {
+            self.verify_one::<P>(voter)?;
+        }
+
+        for (_, index) in voters {
+            self.0[*index].verified = true;
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the signature of the vote of the given validator, unless
+    /// already verified.
+    fn verify_one<P: HostFunctionsProvider>(
+        &mut self,
+        (validator, index): (&ValidatorInfo, usize),
+    ) -> Result<(), VerificationError> {
+        let vote = &mut self.0[index];
+
+        if vote.verified {
+            return Ok(());
+        }
+
+        validator
+            .verify_signature::<HostSignatureVerifier<P>>(
+                &vote.sign_bytes,
+                vote.signed_vote.signature(),
+            )
+            .map_err(|_| {
+                VerificationError::invalid_signature(
+                    vote.signed_vote.signature().as_bytes().to_vec(),
+                    Box::new(validator.clone()),
+                    vote.sign_bytes.clone(),
+                )
+            })?;
+
+        vote.verified = true;
+
+        Ok(())
+    }
+}
+
+/// The verification predicates of the client, hashing with [`HostSha256`].
+pub struct HostPredicates<P>(PhantomData<fn() -> P>);
+
+impl<P> Default for HostPredicates<P> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: HostFunctionsProvider> VerificationPredicates for HostPredicates<P> {
+    type Sha256 = HostSha256<P>;
+}
+
+/// A verifier of Tendermint headers carrying out all its cryptography with
+/// the functions of the host, to be passed to the standalone verification
+/// functions of the client in place of the `ProdVerifier`.
+pub type HostVerifier<P> =
+    PredicateVerifier<HostPredicates<P>, HostVotingPowerCalculator<P>, ProdCommitValidator>;
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use tendermint::block::{self, CommitSig, Header, Height, Round};
+    use tendermint::hash::AppHash;
+    use tendermint::validator::{Info as ValidatorInfo, Set as ValidatorSet};
+    use tendermint::{account, chain, vote, Hash, Time};
+
+    use super::*;
+
+    static BATCH_VERIFY_CALLS: AtomicUsize = AtomicUsize::new(0);
+    static BATCH_VERIFIED_SIGNATURES: AtomicUsize = AtomicUsize::new(0);
+    static VERIFY_CALLS: AtomicUsize = AtomicUsize::new(0);
+    static SEQUENTIAL_VERIFY_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    /// Host functions batching ed25519 verification and accepting every
+    /// signature, recording the calls.
+    struct RecordingHostFunctions;
+
+    impl HostFunctionsProvider for RecordingHostFunctions {
+        const ED25519_BATCH_VERIFY: bool = true;
+
+        fn sha2_256(message: &[u8]) -> [u8; 32] {
+            DefaultHostFunctions::sha2_256(message)
+        }
+
+        fn ed25519_verify(_signature: &[u8], _message: &[u8], _public_key: &[u8]) -> bool {
+            VERIFY_CALLS.fetch_add(1, Ordering::SeqCst);
+            true
+        }
+
+        fn secp256k1_verify(_signature: &[u8], _message: &[u8], _public_key: &[u8]) -> bool {
+            VERIFY_CALLS.fetch_add(1, Ordering::SeqCst);
+            true
+        }
+
+        fn ed25519_batch_verify(
+            signatures: &[&[u8]],
+            _messages: &[&[u8]],
+            _public_keys: &[&[u8]],
+        ) -> bool {
+            BATCH_VERIFY_CALLS.fetch_add(1, Ordering::SeqCst);
+            BATCH_VERIFIED_SIGNATURES.fetch_add(signatures.len(), Ordering::SeqCst);
+            true
+        }
+    }
+
+    /// Host functions without batch verification accepting every signature,
+    /// recording the calls.
+    struct SequentialHostFunctions;
+
+    impl HostFunctionsProvider for SequentialHostFunctions {
+        fn sha2_256(message: &[u8]) -> [u8; 32] {
+            DefaultHostFunctions::sha2_256(message)
+        }
+
+        fn ed25519_verify(_signature: &[u8], _message: &[u8], _public_key: &[u8]) -> bool {
+            SEQUENTIAL_VERIFY_CALLS.fetch_add(1, Ordering::SeqCst);
+            true
+        }
+
+        fn secp256k1_verify(_signature: &[u8], _message: &[u8], _public_key: &[u8]) -> bool {
+            SEQUENTIAL_VERIFY_CALLS.fetch_add(1, Ordering::SeqCst);
+            true
+        }
+    }
+
+    /// Host functions rejecting every signature.
+    struct RejectingHostFunctions;
+
+    impl HostFunctionsProvider for RejectingHostFunctions {
+        fn sha2_256(message: &[u8]) -> [u8; 32] {
+            DefaultHostFunctions::sha2_256(message)
+        }
+
+        fn ed25519_verify(_signature: &[u8], _message: &[u8], _public_key: &[u8]) -> bool {
+            false
+        }
+
+        fn secp256k1_verify(_signature: &[u8], _message: &[u8], _public_key: &[u8]) -> bool {
+            false
+        }
+    }
+
+    /// Returns a header signed by all the validators of the returned set, with
+    /// signatures only valid for host functions accepting any of them.
+    fn signed_header_and_validators(validator_count: u8) -> (SignedHeader, ValidatorSet) {
+        let validators: Vec<ValidatorInfo> = (1..=validator_count)
+            .map(|i| {
+                let public_key = PublicKey::from_raw_ed25519(&[i; 32]).expect("valid key");
+                ValidatorInfo::new(public_key, vote::Power::from(10u32))
+            })
+            .collect();
+        let validator_set = ValidatorSet::without_proposer(validators.clone());
+
+        let height = Height::from(10u32);
+        let time = Time::unix_epoch();
+
+        let header = Header {
+            version: block::header::Version { block: 11, app: 0 },
+            chain_id: chain::Id::try_from("test-chain").expect("valid chain id"),
+            height,
+            time,
+            last_block_id: None,
+            last_commit_hash: None,
+            data_hash: None,
+            validators_hash: validator_set.hash(),
+            next_validators_hash: validator_set.hash(),
+            consensus_hash: Hash::None,
+            app_hash: AppHash::default(),
+            last_results_hash: None,
+            evidence_hash: None,
+            proposer_address: account::Id::new([0; 20]),
+        };
+
+        let commit = block::Commit {
+            height,
+            round: Round::default(),
+            block_id: block::Id {
+                hash: header.hash(),
+                part_set_header: Default::default(),
+            },
+            signatures: validators
+                .iter()
+                .map(|validator| CommitSig::BlockIdFlagCommit {
+                    validator_address: validator.address,
+                    timestamp: time,
+                    signature: Signature::new([1; 64]).expect("valid signature"),
+                })
+                .collect(),
+        };
+
+        (
+            SignedHeader::new(header, commit).expect("valid signed header"),
+            validator_set,
+        )
+    }
+
+    #[test]
+    fn test_signatures_are_verified_by_host_functions() {
+        let (signed_header, validator_set) = signed_header_and_validators(4);
+
+        let calculator = HostVotingPowerCalculator::<RecordingHostFunctions>::default();
+
+        let (first_tally, second_tally) = calculator
+            .voting_power_in_sets(
+                &signed_header,
+                (&validator_set, TrustThreshold::ONE_THIRD),
+                (&validator_set, TrustThreshold::TWO_THIRDS),
+            )
+            .expect("signatures are accepted by the host functions");
+
+        assert_eq!(first_tally.tallied, 40);
+        assert_eq!(second_tally.tallied, 40);
+
+        // The ed25519 signatures are verified as a single batch, and not
+        // verified again for the second validator set.
+        assert_eq!(BATCH_VERIFY_CALLS.load(Ordering::SeqCst), 1);
+        assert_eq!(BATCH_VERIFIED_SIGNATURES.load(Ordering::SeqCst), 4);
+        assert_eq!(VERIFY_CALLS.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_signatures_are_verified_up_to_the_trust_threshold() {
+        let (signed_header, validator_set) = signed_header_and_validators(4);
+
+        let calculator = HostVotingPowerCalculator::<SequentialHostFunctions>::default();
+
+        let (first_tally, second_tally) = calculator
+            .voting_power_in_sets(
+                &signed_header,
+                (&validator_set, TrustThreshold::ONE_THIRD),
+                (&validator_set, TrustThreshold::TWO_THIRDS),
+            )
+            .expect("signatures are accepted by the host functions");
+
+        assert_eq!(first_tally.tallied, 20);
+        assert_eq!(second_tally.tallied, 30);
+
+        // Verification stops once the threshold is reached, and the signatures
+        // verified for the first validator set are not verified again.
+        assert_eq!(SEQUENTIAL_VERIFY_CALLS.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_signatures_rejected_by_host_functions() {
+        let (signed_header, validator_set) = signed_header_and_validators(4);
+
+        let calculator = HostVotingPowerCalculator::<RejectingHostFunctions>::default();
+
+        let res =
+            calculator.voting_power_in(&signed_header, &validator_set, TrustThreshold::TWO_THIRDS);
+
+        assert!(res.is_err());
+    }
+}
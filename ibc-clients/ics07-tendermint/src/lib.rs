@@ -18,6 +18,8 @@ extern crate std;
 
 pub mod client_state;
 pub mod consensus_state;
+pub mod host_functions;
+pub mod misbehaviour_detector;
 
 pub const TENDERMINT_CLIENT_TYPE: &str = "07-tendermint";
 
@@ -0,0 +1,198 @@
+//! Detects the misbehaviour of the chain tracked by a Tendermint client, and
+//! builds the [`Misbehaviour`](TmMisbehaviour) evidence that freezes the
+//! client.
+//!
+//! Relayers and watchers compare the headers submitted to a client against a
+//! [`TrustedHeaderSource`], usually a full node of the tracked chain, which
+//! reveals two kinds of misbehaviour:
+//!
+//! - a fork, when the source has another block at the height of the header;
+//! - a BFT time violation, when the source has not reached the height of the
+//!   header yet, but its latest block is not older than the header.
+//!
+//! The header of the source is given the trusted height and validator set of
+//! the submitted header, so that both are verified against the same
+//! consensus state of the client.
+//!
+//! The detector does not bisect the chain of the source: the validators
+//! having signed its header must hold at least the trust level of the
+//! voting power of the trusted validator set, which may not be the case when
+//! the validator set changed since the trusted height. The detector then
+//! fails with [`ConflictingHeaderNotTrusted`](Error::ConflictingHeaderNotTrusted),
+//! and the evidence must instead be built from an intermediate header of the
+//! source, once the client trusts it.
+
+use ibc_client_tendermint_types::error::Error;
+use ibc_client_tendermint_types::{
+    Header as TmHeader, Misbehaviour as TmMisbehaviour, TrustThreshold,
+};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use tendermint::block::signed_header::SignedHeader;
+use tendermint::crypto::Sha256;
+use tendermint::merkle::MerkleHash;
+use tendermint::trust_threshold::{TrustThreshold as _, TrustThresholdFraction};
+use tendermint::validator::Set as ValidatorSet;
+use tendermint_light_client_verifier::operations::{
+    ProdVotingPowerCalculator, VotingPowerCalculator,
+};
+
+use crate::client_state::check_for_misbehaviour_on_misbehavior;
+
+/// A source of the headers of the chain tracked by a Tendermint client,
+/// trusted by the party looking for misbehaviour.
+pub trait TrustedHeaderSource {
+    /// Returns the latest height of the chain.
+    fn latest_height(&self) -> Result<Height, ClientError>;
+
+    /// Returns the signed header of the chain at the given height, or `None`
+    /// if the chain has not reached it yet.
+    fn signed_header(&self, height: &Height) -> Result<Option<SignedHeader>, ClientError>;
+
+    /// Returns the validator set of the chain at the given height.
+    fn validator_set(&self, height: &Height) -> Result<ValidatorSet, ClientError>;
+}
+
+/// Compares a header submitted to the client against the headers of the
+/// source, and returns the evidence of misbehaviour they reveal, if any.
+///
+/// The submitted header is assumed to have convinced the client, i.e. it is
+/// not verified here. The header of the source must however be signed by
+/// validators holding the given trust level of the client in the trusted
+/// validator set of the submitted header, as no intermediate header is
+/// fetched to bridge a validator set change.
+pub fn detect_misbehaviour<S, H>(
+    source: &S,
+    client_id: &ClientId,
+    trust_level: TrustThreshold,
+    header: &TmHeader,
+) -> Result<Option<TmMisbehaviour>, ClientError>
+where
+    S: TrustedHeaderSource,
+    H: MerkleHash + Sha256 + Default,
+{
+    let signed_header = match source.signed_header(&header.height())? {
+        // The source has a block at the height of the header, which only
+        // conflicts with the header on a fork.
+        Some(signed_header) => {
+            if signed_header.commit.block_id.hash == header.signed_header.commit.block_id.hash {
+                return Ok(None);
+            }
+
+            signed_header
+        }
+        // The source has not reached the height of the header, whose time
+        // must then be after the time of the latest block of the source.
+        None => {
+            let latest_height = source.latest_height()?;
+
+            // No evidence can be built against the client's trusted height
+            // from a source lagging behind it.
+            if latest_height <= header.trusted_height {
+                return Ok(None);
+            }
+
+            let signed_header =
+                source
+                    .signed_header(&latest_height)?
+                    .ok_or_else(|| ClientError::Other {
+                        description: format!("no header at the latest height {latest_height}"),
+                    })?;
+
+            if header.signed_header.header.time > signed_header.header.time {
+                return Ok(None);
+            }
+
+            signed_header
+        }
+    };
+
+    let trusted_header = trusted_header(source, header, signed_header)?;
+
+    verify_trusted_overlap(&trusted_header, trust_level.try_into()?)?;
+
+    build_misbehaviour::<H>(client_id, header.clone(), trusted_header)
+}
+
+/// Builds the evidence of misbehaviour of two conflicting headers, ordered
+/// as the client expects, i.e. the highest one first.
+///
+/// Returns `None` if the headers are no evidence of misbehaviour, i.e. are
+/// the same header, or are at different heights with increasing times.
+pub fn build_misbehaviour<H>(
+    client_id: &ClientId,
+    header_1: TmHeader,
+    header_2: TmHeader,
+) -> Result<Option<TmMisbehaviour>, ClientError>
+where
+    H: MerkleHash + Sha256 + Default,
+{
+    let (header_1, header_2) = if header_1.height() >= header_2.height() {
+        (header_1, header_2)
+    } else {
+        (header_2, header_1)
+    };
+
+    if !check_for_misbehaviour_on_misbehavior(&header_1, &header_2)? {
+        return Ok(None);
+    }
+
+    let misbehaviour = TmMisbehaviour::new(client_id.clone(), header_1, header_2);
+
+    misbehaviour.validate_basic::<H>()?;
+
+    Ok(Some(misbehaviour))
+}
+
+/// Returns the header of the source for the given signed header, trusting
+/// the same consensus state of the client as the submitted header.
+fn trusted_header<S>(
+    source: &S,
+    header: &TmHeader,
+    signed_header: SignedHeader,
+) -> Result<TmHeader, ClientError>
+where
+    S: TrustedHeaderSource,
+{
+    let height = Height::new(
+        header.trusted_height.revision_number(),
+        signed_header.header.height.value(),
+    )?;
+
+    Ok(TmHeader {
+        signed_header,
+        validator_set: source.validator_set(&height)?,
+        trusted_height: header.trusted_height,
+        trusted_next_validator_set: header.trusted_next_validator_set.clone(),
+    })
+}
+
+/// Checks that the validators having signed the header of the source hold
+/// enough of the voting power of the trusted validator set for the client to
+/// verify it against its trusted consensus state.
+fn verify_trusted_overlap(
+    trusted_header: &TmHeader,
+    trust_threshold: TrustThresholdFraction,
+) -> Result<(), ClientError> {
+    let tally = ProdVotingPowerCalculator::default()
+        .voting_power_in(
+            &trusted_header.signed_header,
+            &trusted_header.trusted_next_validator_set,
+            trust_threshold,
+        )
+        .map_err(|e| Error::VerificationError {
+            detail: Box::new(e.0),
+        })?;
+
+    if !trust_threshold.is_enough_power(tally.tallied, tally.total) {
+        return Err(Error::ConflictingHeaderNotTrusted {
+            height: trusted_header.height(),
+            reason: tally,
+        }
+        .into());
+    }
+
+    Ok(())
+}
@@ -58,6 +58,8 @@ pub enum Error {
     InvalidRawHeader(TendermintError),
     /// invalid raw misbehaviour: `{reason}`
     InvalidRawMisbehaviour { reason: String },
+    /// invalid header bundle: `{reason}`
+    InvalidHeaderBundle { reason: String },
     /// given other previous updates, header timestamp should be at most `{max}`, but was `{actual}`
     HeaderTimestampTooHigh { actual: String, max: String },
     /// given other previous updates, header timestamp should be at least `{min}`, but was `{actual}`
@@ -93,6 +95,11 @@ pub enum Error {
     MisbehaviourHeadersBlockHashesEqual,
     /// headers are not at same height and are monotonically increasing
     MisbehaviourHeadersNotAtSameHeight,
+    /// the header at height `{height}` conflicting with the submitted header is not signed by enough of the trusted validators to be evidence of misbehaviour: `{reason}`
+    ConflictingHeaderNotTrusted {
+        height: Height,
+        reason: VotingPowerTally,
+    },
 }
 
 #[cfg(feature = "std")]
@@ -0,0 +1,240 @@
+//! Defines the header bundle type for the tendermint light client
+
+use ibc_core_client_types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+use tendermint::crypto::Sha256;
+use tendermint::merkle::MerkleHash;
+
+use crate::error::Error;
+use crate::header::Header;
+use crate::raw::v1::HeaderBundle as RawHeaderBundle;
+
+pub const TENDERMINT_HEADER_BUNDLE_TYPE_URL: &str =
+    "/ibc_rs.lightclients.tendermint.v1.HeaderBundle";
+
+/// Tendermint light client's header bundle type, an ordered list of headers
+/// updating the client in a single message when a single header cannot be
+/// trusted from any of its consensus states, e.g. after a long outage.
+///
+/// The first header is verified against a consensus state of the client, and
+/// every other header against the one before it, i.e. the trusted height of
+/// a header is the height of the previous one. Only the consensus state of
+/// the last header is stored.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawHeaders", into = "RawHeaders")
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeaderBundle {
+    intermediate_headers: Vec<Header>,
+    final_header: Header,
+}
+
+impl HeaderBundle {
+    pub fn new(mut headers: Vec<Header>) -> Result<Self, Error> {
+        let final_header = headers.pop().ok_or_else(|| Error::InvalidHeaderBundle {
+            reason: "a header bundle must contain at least one header".to_owned(),
+        })?;
+
+        Ok(Self {
+            intermediate_headers: headers,
+            final_header,
+        })
+    }
+
+    /// Returns the headers of the bundle, in order.
+    pub fn headers(&self) -> impl Iterator<Item = &Header> {
+        self.intermediate_headers
+            .iter()
+            .chain(core::iter::once(&self.final_header))
+    }
+
+    /// Returns the first header of the bundle, which is verified against a
+    /// consensus state of the client.
+    pub fn first_header(&self) -> &Header {
+        self.intermediate_headers
+            .first()
+            .unwrap_or(&self.final_header)
+    }
+
+    /// Returns the last header of the bundle, which the client is updated to.
+    pub fn final_header(&self) -> &Header {
+        &self.final_header
+    }
+
+    pub fn into_final_header(self) -> Header {
+        self.final_header
+    }
+
+    pub fn into_headers(self) -> Vec<Header> {
+        let mut headers = self.intermediate_headers;
+        headers.push(self.final_header);
+        headers
+    }
+
+    /// Checks that the headers are valid, from the same chain, and that each
+    /// of them trusts the height of the one before it.
+    pub fn validate_basic<H: MerkleHash + Sha256 + Default>(&self) -> Result<(), Error> {
+        for header in self.headers() {
+            header.validate_basic::<H>()?;
+        }
+
+        for (previous, header) in self.headers().zip(self.headers().skip(1)) {
+            if previous.signed_header.header.chain_id != header.signed_header.header.chain_id {
+                return Err(Error::InvalidHeaderBundle {
+                    reason: "headers must have identical chain_ids".to_owned(),
+                });
+            }
+
+            if header.trusted_height != previous.height() {
+                return Err(Error::InvalidHeaderBundle {
+                    reason: format!(
+                        "header at height {} trusts height {} instead of the previous header height {}",
+                        header.height(),
+                        header.trusted_height,
+                        previous.height()
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Serde representation of a [`HeaderBundle`], deserialized through
+/// [`HeaderBundle::new`] so that an empty bundle is rejected.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RawHeaders {
+    headers: Vec<Header>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawHeaders> for HeaderBundle {
+    type Error = Error;
+
+    fn try_from(raw: RawHeaders) -> Result<Self, Self::Error> {
+        Self::new(raw.headers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<HeaderBundle> for RawHeaders {
+    fn from(value: HeaderBundle) -> Self {
+        Self {
+            headers: value.into_headers(),
+        }
+    }
+}
+
+impl Protobuf<RawHeaderBundle> for HeaderBundle {}
+
+impl TryFrom<RawHeaderBundle> for HeaderBundle {
+    type Error = Error;
+
+    fn try_from(raw: RawHeaderBundle) -> Result<Self, Self::Error> {
+        let headers = raw
+            .headers
+            .into_iter()
+            .map(Header::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(headers)
+    }
+}
+
+impl From<HeaderBundle> for RawHeaderBundle {
+    fn from(value: HeaderBundle) -> Self {
+        RawHeaderBundle {
+            headers: value.into_headers().into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for HeaderBundle {}
+
+impl TryFrom<Any> for HeaderBundle {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header_bundle(value: &[u8]) -> Result<HeaderBundle, ClientError> {
+            let header_bundle =
+                Protobuf::<RawHeaderBundle>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(header_bundle)
+        }
+        match raw.type_url.as_str() {
+            TENDERMINT_HEADER_BUNDLE_TYPE_URL => decode_header_bundle(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<HeaderBundle> for Any {
+    fn from(header_bundle: HeaderBundle) -> Self {
+        Any {
+            type_url: TENDERMINT_HEADER_BUNDLE_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeaderBundle>::encode_vec(header_bundle),
+        }
+    }
+}
+
+impl core::fmt::Display for HeaderBundle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "HeaderBundle {{ trusted_height: {}, heights: [",
+            self.first_header().trusted_height
+        )?;
+        for (i, header) in self.headers().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", header.height())?;
+        }
+        write!(f, "] }}")
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_rejects_empty_header_bundle() {
+        let parsed = serde_json::from_str::<HeaderBundle>(r#"{"headers":[]}"#);
+        assert!(parsed.is_err());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_rejects_empty_header_bundle() {
+        let any = Any {
+            type_url: TENDERMINT_HEADER_BUNDLE_TYPE_URL.to_string(),
+            value: prost::Message::encode_to_vec(&RawHeaderBundle { headers: vec![] }),
+        };
+
+        assert!(HeaderBundle::try_from(any).is_err());
+    }
+
+    #[test]
+    fn type_url_is_owned_by_the_crate() {
+        use prost::Name;
+
+        assert_eq!(
+            RawHeaderBundle::type_url(),
+            TENDERMINT_HEADER_BUNDLE_TYPE_URL
+        );
+    }
+}
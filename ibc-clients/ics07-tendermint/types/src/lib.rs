@@ -23,16 +23,19 @@ extern crate std;
 mod client_state;
 mod consensus_state;
 mod header;
+mod header_bundle;
 mod misbehaviour;
 mod trust_threshold;
 
 pub use client_state::*;
 pub use consensus_state::*;
 pub use header::*;
+pub use header_bundle::*;
 pub use misbehaviour::*;
 pub use trust_threshold::*;
 
 pub mod error;
+pub mod raw;
 
 /// Re-exports ICS-07 Tendermint light client from `ibc-proto` crate.
 pub mod proto {
@@ -0,0 +1,17 @@
+// @generated
+/// HeaderBundle is an ordered list of headers, each trusting the one before
+/// it, updating the client through the intermediate heights of a bisection.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HeaderBundle {
+    #[prost(message, repeated, tag = "1")]
+    pub headers: ::prost::alloc::vec::Vec<::ibc_proto::ibc::lightclients::tendermint::v1::Header>,
+}
+impl ::prost::Name for HeaderBundle {
+    const NAME: &'static str = "HeaderBundle";
+    const PACKAGE: &'static str = "ibc_rs.lightclients.tendermint.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("ibc_rs.lightclients.tendermint.v1.{}", Self::NAME)
+    }
+}
+// @@protoc_insertion_point(module)
@@ -0,0 +1,10 @@
+//! Protobuf definitions of the Tendermint light client messages that are not
+//! part of `ibc-proto`, in the `ibc_rs.lightclients.tendermint.v1` package
+//! owned by this crate rather than by ibc-go.
+//!
+//! The types are generated from the `.proto` files under the `proto`
+//! directory of this crate with `make gen-proto`.
+
+pub mod v1 {
+    include!("prost/ibc_rs.lightclients.tendermint.v1.rs");
+}
@@ -0,0 +1,655 @@
+//! This module includes trait implementations for the
+//! `ibc_client_wasm_types::ClientState` type. Implemented traits include
+//! `ClientStateCommon`, `ClientStateValidation`, and `ClientStateExecution`.
+//!
+//! Note that this crate defines a newtype wrapper around the
+//! `ibc_client_wasm_types::ClientState` type in order to enable implementing a
+//! foreign trait on a foreign type (i.e. the orphan rule in Rust). As such,
+//! this module also includes some trait implementations that serve to pass
+//! through traits implemented on the wrapped `ClientState` type.
+
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::error::Error;
+use ibc_client_wasm_types::proto::v1::ClientState as RawWasmClientState;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+/// Newtype wrapper around the `ClientState` type imported from the
+/// `ibc-client-wasm-types` crate. This wrapper exists so that we can bypass
+/// Rust's orphan rules and implement traits from `ibc::core::client::context`
+/// on the `ClientState` type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState(ClientStateType);
+
+impl ClientState {
+    pub fn inner(&self) -> &ClientStateType {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> ClientStateType {
+        self.0
+    }
+}
+
+impl From<ClientStateType> for ClientState {
+    fn from(client_state: ClientStateType) -> Self {
+        Self(client_state)
+    }
+}
+
+impl Protobuf<RawWasmClientState> for ClientState {}
+
+impl TryFrom<RawWasmClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawWasmClientState) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for RawWasmClientState {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod mock {
+    //! A host running a mock VM, whose contract follows scripted responses
+    //! and records the calls it receives.
+
+    use core::cell::RefCell;
+
+    use ibc_client_wasm_types::Bytes;
+    use ibc_core_client::context::{
+        ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
+    };
+    use ibc_core_client::types::Height;
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::types::identifiers::ClientId;
+    use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+    use ibc_primitives::{Signer, Timestamp};
+    use prost::Message;
+
+    use super::*;
+    use crate::consensus_state::ConsensusState;
+    use crate::context::{WasmClientExecutionContext, WasmClientValidationContext};
+    pub use crate::handler::checksum;
+    use crate::store::{self, Order, StoreEntries, StoreWrite, WasmStore};
+    use crate::vm::{ContractEnv, ContractOutput, WasmVm};
+
+    pub const AUTHORITY: &str = "authority";
+
+    pub fn client_id() -> ClientId {
+        ClientId::new("08-wasm", 0).unwrap()
+    }
+
+    pub fn client_state(checksum: Bytes, latest_height: u64) -> ClientState {
+        ClientStateType {
+            data: b"data".to_vec(),
+            checksum,
+            latest_height: Height::new(0, latest_height).unwrap(),
+        }
+        .into()
+    }
+
+    /// A call the mock contract received.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Call {
+        pub entry_point: &'static str,
+        pub env: ContractEnv,
+        pub msg: String,
+    }
+
+    /// A VM whose contract answers every call with the scripted response,
+    /// after making the scripted writes and reading the scripted keys.
+    #[derive(Default)]
+    pub struct MockVm {
+        pub codes: RefCell<Vec<Bytes>>,
+        pub calls: RefCell<Vec<Call>>,
+        pub response: RefCell<Vec<u8>>,
+        pub writes: RefCell<Vec<StoreWrite>>,
+        pub reads: RefCell<Vec<Vec<u8>>>,
+        pub read_values: RefCell<Vec<Option<Vec<u8>>>>,
+    }
+
+    impl MockVm {
+        fn call(
+            &self,
+            entry_point: &'static str,
+            env: &ContractEnv,
+            store: &dyn WasmStore,
+            msg: &[u8],
+        ) -> Result<ContractOutput, Error> {
+            self.calls.borrow_mut().push(Call {
+                entry_point,
+                env: env.clone(),
+                msg: String::from_utf8(msg.to_vec()).unwrap(),
+            });
+
+            for key in self.reads.borrow().iter() {
+                let value = store.get(key).map_err(|e| Error::Vm {
+                    reason: e.to_string(),
+                })?;
+                self.read_values.borrow_mut().push(value);
+            }
+
+            Ok(ContractOutput {
+                data: self.response.borrow().clone(),
+                writes: self.writes.borrow().clone(),
+            })
+        }
+    }
+
+    impl WasmVm for MockVm {
+        fn store_code(&self, byte_code: &[u8]) -> Result<Bytes, Error> {
+            let checksum = checksum(byte_code);
+            self.codes.borrow_mut().push(checksum.clone());
+            Ok(checksum)
+        }
+
+        fn remove_code(&self, checksum: &[u8]) -> Result<(), Error> {
+            self.codes.borrow_mut().retain(|code| code != checksum);
+            Ok(())
+        }
+
+        fn instantiate(
+            &self,
+            env: &ContractEnv,
+            store: &dyn WasmStore,
+            msg: &[u8],
+        ) -> Result<ContractOutput, Error> {
+            self.call("instantiate", env, store, msg)
+        }
+
+        fn sudo(
+            &self,
+            env: &ContractEnv,
+            store: &dyn WasmStore,
+            msg: &[u8],
+        ) -> Result<ContractOutput, Error> {
+            self.call("sudo", env, store, msg)
+        }
+
+        fn migrate(
+            &self,
+            env: &ContractEnv,
+            store: &dyn WasmStore,
+            msg: &[u8],
+        ) -> Result<ContractOutput, Error> {
+            self.call("migrate", env, store, msg)
+        }
+
+        fn query(
+            &self,
+            env: &ContractEnv,
+            store: &dyn WasmStore,
+            msg: &[u8],
+        ) -> Result<Vec<u8>, Error> {
+            self.call("query", env, store, msg)
+                .map(|output| output.data)
+        }
+    }
+
+    /// The error the mock host returns from the context methods the 08-wasm
+    /// client does not use.
+    fn unsupported(method: &str) -> ContextError {
+        ClientError::Other {
+            description: format!("`{method}` is not supported by the mock host"),
+        }
+        .into()
+    }
+
+    /// A host keeping the stores of its clients in memory.
+    #[derive(Default)]
+    pub struct MockHost {
+        pub vm: MockVm,
+        pub stores: BTreeMap<ClientId, BTreeMap<Vec<u8>, Vec<u8>>>,
+        pub checksums: Vec<Bytes>,
+    }
+
+    impl MockHost {
+        pub fn with_client(client_state: ClientState) -> Self {
+            let mut host = Self {
+                checksums: vec![client_state.inner().checksum.clone()],
+                ..Default::default()
+            };
+            host.stores.entry(client_id()).or_default().insert(
+                ClientStatePath::leaf().into_bytes(),
+                Any::from(client_state).encode_to_vec(),
+            );
+            host
+        }
+
+        pub fn store(&self, client_id: &ClientId) -> BTreeMap<Vec<u8>, Vec<u8>> {
+            self.stores.get(client_id).cloned().unwrap_or_default()
+        }
+    }
+
+    impl ClientValidationContext for MockHost {
+        type ClientStateRef = ClientState;
+        type ConsensusStateRef = ConsensusState;
+
+        fn client_state(&self, client_id: &ClientId) -> Result<ClientState, ContextError> {
+            let value = self
+                .client_store_get(client_id, ClientStatePath::leaf().as_bytes())?
+                .ok_or(ClientError::ClientStateNotFound {
+                    client_id: client_id.clone(),
+                })?;
+
+            Ok(
+                Protobuf::<Any>::decode(value.as_slice()).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?,
+            )
+        }
+
+        fn consensus_state(
+            &self,
+            path: &ClientConsensusStatePath,
+        ) -> Result<ConsensusState, ContextError> {
+            let value = self
+                .client_store_get(&path.client_id, path.leaf().as_bytes())?
+                .ok_or(ClientError::ConsensusStateNotFound {
+                    client_id: path.client_id.clone(),
+                    height: Height::new(path.revision_number, path.revision_height)?,
+                })?;
+
+            Ok(
+                Protobuf::<Any>::decode(value.as_slice()).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?,
+            )
+        }
+
+        fn client_update_meta(
+            &self,
+            _client_id: &ClientId,
+            _height: &Height,
+        ) -> Result<(Timestamp, Height), ContextError> {
+            Err(unsupported("client_update_meta"))
+        }
+    }
+
+    impl ExtClientValidationContext for MockHost {
+        fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+            Ok(Timestamp::from_nanoseconds(1_000).unwrap())
+        }
+
+        fn host_height(&self) -> Result<Height, ContextError> {
+            Ok(Height::new(0, 100).unwrap())
+        }
+
+        fn consensus_state_heights(
+            &self,
+            _client_id: &ClientId,
+        ) -> Result<Vec<Height>, ContextError> {
+            Err(unsupported("consensus_state_heights"))
+        }
+
+        fn next_consensus_state(
+            &self,
+            _client_id: &ClientId,
+            _height: &Height,
+        ) -> Result<Option<ConsensusState>, ContextError> {
+            Err(unsupported("next_consensus_state"))
+        }
+
+        fn prev_consensus_state(
+            &self,
+            _client_id: &ClientId,
+            _height: &Height,
+        ) -> Result<Option<ConsensusState>, ContextError> {
+            Err(unsupported("prev_consensus_state"))
+        }
+    }
+
+    impl ClientExecutionContext for MockHost {
+        type ClientStateMut = ClientState;
+
+        fn store_client_state(
+            &mut self,
+            _client_state_path: ClientStatePath,
+            _client_state: ClientState,
+        ) -> Result<(), ContextError> {
+            Err(unsupported("store_client_state"))
+        }
+
+        fn store_consensus_state(
+            &mut self,
+            _consensus_state_path: ClientConsensusStatePath,
+            _consensus_state: ConsensusState,
+        ) -> Result<(), ContextError> {
+            Err(unsupported("store_consensus_state"))
+        }
+
+        fn delete_consensus_state(
+            &mut self,
+            _consensus_state_path: ClientConsensusStatePath,
+        ) -> Result<(), ContextError> {
+            Err(unsupported("delete_consensus_state"))
+        }
+
+        fn store_update_meta(
+            &mut self,
+            _client_id: ClientId,
+            _height: Height,
+            _host_timestamp: Timestamp,
+            _host_height: Height,
+        ) -> Result<(), ContextError> {
+            Err(unsupported("store_update_meta"))
+        }
+
+        fn delete_update_meta(
+            &mut self,
+            _client_id: ClientId,
+            _height: Height,
+        ) -> Result<(), ContextError> {
+            Err(unsupported("delete_update_meta"))
+        }
+    }
+
+    impl WasmClientValidationContext for MockHost {
+        type Vm = MockVm;
+
+        fn wasm_vm(&self) -> &MockVm {
+            &self.vm
+        }
+
+        fn client_store_get(
+            &self,
+            client_id: &ClientId,
+            key: &[u8],
+        ) -> Result<Option<Vec<u8>>, ContextError> {
+            Ok(self.store(client_id).get(key).cloned())
+        }
+
+        fn client_store_range(
+            &self,
+            client_id: &ClientId,
+            start: Option<&[u8]>,
+            end: Option<&[u8]>,
+            order: Order,
+        ) -> Result<StoreEntries, ContextError> {
+            Ok(store::range(&self.store(client_id), start, end, order))
+        }
+
+        fn has_checksum(&self, checksum: &[u8]) -> Result<bool, ContextError> {
+            Ok(self.checksums.iter().any(|stored| stored == checksum))
+        }
+
+        fn wasm_authority(&self) -> Result<Signer, ContextError> {
+            Ok(AUTHORITY.to_string().into())
+        }
+
+        fn max_wasm_byte_code_size(&self) -> usize {
+            8
+        }
+    }
+
+    impl WasmClientExecutionContext for MockHost {
+        fn client_store_set(
+            &mut self,
+            client_id: &ClientId,
+            key: Vec<u8>,
+            value: Vec<u8>,
+        ) -> Result<(), ContextError> {
+            self.stores
+                .entry(client_id.clone())
+                .or_default()
+                .insert(key, value);
+            Ok(())
+        }
+
+        fn client_store_remove(
+            &mut self,
+            client_id: &ClientId,
+            key: &[u8],
+        ) -> Result<(), ContextError> {
+            if let Some(store) = self.stores.get_mut(client_id) {
+                store.remove(key);
+            }
+            Ok(())
+        }
+
+        fn store_checksum(&mut self, checksum: Vec<u8>) -> Result<(), ContextError> {
+            self.checksums.push(checksum);
+            Ok(())
+        }
+
+        fn delete_checksum(&mut self, checksum: &[u8]) -> Result<(), ContextError> {
+            self.checksums.retain(|stored| stored != checksum);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_client_wasm_types::client_message::ClientMessage;
+    use ibc_client_wasm_types::consensus_state::ConsensusState as ConsensusStateType;
+    use ibc_client_wasm_types::{SUBJECT_PREFIX, SUBSTITUTE_PREFIX};
+    use ibc_core_client::context::client_state::{ClientStateExecution, ClientStateValidation};
+    use ibc_core_client::types::{Height, Status};
+    use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+    use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
+    use prost::Message;
+
+    use super::mock::*;
+    use super::*;
+    use crate::store::StoreWrite;
+
+    fn client_message() -> Any {
+        ClientMessage {
+            data: b"header".to_vec(),
+        }
+        .into()
+    }
+
+    fn consensus_state() -> Any {
+        ConsensusStateType::new(b"consensus".to_vec()).into()
+    }
+
+    #[test]
+    fn test_initialise_instantiates_contract() {
+        let client_state = client_state(checksum(b"code"), 5);
+        let mut host = MockHost {
+            checksums: vec![checksum(b"code")],
+            ..Default::default()
+        };
+        *host.vm.writes.borrow_mut() = vec![StoreWrite::Set {
+            key: ClientStatePath::leaf().into_bytes(),
+            value: b"client state".to_vec(),
+        }];
+
+        client_state
+            .initialise(&mut host, &client_id(), consensus_state())
+            .unwrap();
+
+        let calls = host.vm.calls.borrow();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].entry_point, "instantiate");
+        assert_eq!(calls[0].env.checksum, checksum(b"code"));
+        assert!(calls[0]
+            .msg
+            .starts_with(r#"{"client_state":"ZGF0YQ==","consensus_state":"Y29uc2Vuc3Vz","#));
+        assert_eq!(
+            host.store(&client_id())
+                .get(ClientStatePath::leaf().as_bytes()),
+            Some(&b"client state".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_initialise_unknown_checksum() {
+        let client_state = client_state(checksum(b"code"), 5);
+        let mut host = MockHost::default();
+
+        assert!(client_state
+            .initialise(&mut host, &client_id(), consensus_state())
+            .is_err());
+        assert!(host.vm.calls.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_status() {
+        let client_state = client_state(checksum(b"code"), 5);
+
+        // A client not created yet only needs its byte code to be stored.
+        assert_eq!(
+            client_state
+                .status(&MockHost::default(), &client_id())
+                .unwrap(),
+            Status::Unauthorized
+        );
+
+        let host = MockHost::with_client(client_state.clone());
+        *host.vm.response.borrow_mut() = br#"{"is_valid":true,"status":"Frozen"}"#.to_vec();
+
+        assert_eq!(
+            client_state.status(&host, &client_id()).unwrap(),
+            Status::Frozen
+        );
+        assert_eq!(host.vm.calls.borrow()[0].msg, r#"{"status":{}}"#);
+    }
+
+    #[test]
+    fn test_update_state_returns_heights() {
+        let client_state = client_state(checksum(b"code"), 5);
+        let mut host = MockHost::with_client(client_state.clone());
+        *host.vm.response.borrow_mut() =
+            br#"{"heights":[{"revision_number":0,"revision_height":6}]}"#.to_vec();
+        *host.vm.writes.borrow_mut() = vec![StoreWrite::Set {
+            key: b"consensusStates/0-6".to_vec(),
+            value: b"consensus".to_vec(),
+        }];
+
+        let heights = client_state
+            .update_state(&mut host, &client_id(), client_message())
+            .unwrap();
+
+        assert_eq!(heights, vec![Height::new(0, 6).unwrap()]);
+        assert_eq!(
+            host.vm.calls.borrow()[0].msg,
+            r#"{"update_state":{"client_message":"aGVhZGVy"}}"#
+        );
+        assert!(host
+            .store(&client_id())
+            .contains_key(b"consensusStates/0-6".as_slice()));
+    }
+
+    #[test]
+    fn test_verify_membership_rejects_writes() {
+        let client_state = client_state(checksum(b"code"), 5);
+        let host = MockHost::with_client(client_state.clone());
+        *host.vm.writes.borrow_mut() = vec![StoreWrite::Remove {
+            key: ClientStatePath::leaf().into_bytes(),
+        }];
+
+        let path = ClientConsensusStatePath::new(client_id(), 0, 5);
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let proof = CommitmentProofBytes::try_from(b"proof".to_vec()).unwrap();
+
+        assert!(client_state
+            .verify_membership_at_height(
+                &host,
+                &path,
+                &prefix,
+                &proof,
+                Path::ClientState(ClientStatePath::new(client_id())),
+                b"value".to_vec(),
+            )
+            .is_err());
+
+        host.vm.writes.borrow_mut().clear();
+
+        client_state
+            .verify_membership_at_height(
+                &host,
+                &path,
+                &prefix,
+                &proof,
+                Path::ClientState(ClientStatePath::new(client_id())),
+                b"value".to_vec(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_update_on_recovery() {
+        let subject_client_state = client_state(checksum(b"code"), 5);
+        let substitute_client_state = client_state(checksum(b"code"), 9);
+        let mut host = MockHost::with_client(subject_client_state.clone());
+
+        let substitute_key = |key: &str| [SUBSTITUTE_PREFIX, key.as_bytes()].concat();
+        *host.vm.reads.borrow_mut() = vec![
+            substitute_key("clientState"),
+            substitute_key("consensusStates/0-9"),
+        ];
+        *host.vm.writes.borrow_mut() = vec![StoreWrite::Set {
+            key: [SUBJECT_PREFIX, b"clientState"].concat(),
+            value: b"recovered".to_vec(),
+        }];
+
+        subject_client_state
+            .update_on_recovery(
+                &mut host,
+                &client_id(),
+                substitute_client_state.clone().into(),
+                consensus_state(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            *host.vm.read_values.borrow(),
+            vec![
+                Some(Any::from(substitute_client_state.clone()).encode_to_vec()),
+                Some(consensus_state().encode_to_vec()),
+            ]
+        );
+        assert_eq!(
+            host.store(&client_id())
+                .get(ClientStatePath::leaf().as_bytes()),
+            Some(&b"recovered".to_vec())
+        );
+
+        // The contract may not write to the substitute client.
+        *host.vm.writes.borrow_mut() = vec![StoreWrite::Remove {
+            key: substitute_key("clientState"),
+        }];
+
+        assert!(subject_client_state
+            .update_on_recovery(
+                &mut host,
+                &client_id(),
+                substitute_client_state.into(),
+                consensus_state(),
+            )
+            .is_err());
+    }
+}
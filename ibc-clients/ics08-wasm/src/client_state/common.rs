@@ -0,0 +1,121 @@
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::client_type as wasm_client_type;
+use ibc_client_wasm_types::consensus_state::ConsensusState as ConsensusStateType;
+use ibc_client_wasm_types::error::Error;
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::identifiers::ClientType;
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        verify_consensus_state(consensus_state)
+    }
+
+    fn client_type(&self) -> ClientType {
+        wasm_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.0.latest_height
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        validate_proof_height(self.inner(), proof_height)
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(Error::Unsupported {
+            reason: "client upgrades".to_string(),
+        }
+        .into())
+    }
+
+    /// 08-wasm clients verify proofs with their contract, through
+    /// `verify_membership_at_height`, as they cannot without the context.
+    fn verify_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+        _value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        Err(Error::Unsupported {
+            reason: "proof verification against a commitment root".to_string(),
+        }
+        .into())
+    }
+
+    /// 08-wasm clients verify proofs with their contract, through
+    /// `verify_non_membership_at_height`, as they cannot without the context.
+    fn verify_non_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+    ) -> Result<(), ClientError> {
+        Err(Error::Unsupported {
+            reason: "proof verification against a commitment root".to_string(),
+        }
+        .into())
+    }
+}
+
+/// Verify an `Any` consensus state by attempting to convert it to an 08-wasm
+/// `ConsensusState`, which must hold the consensus state of its contract.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_consensus_state(consensus_state: Any) -> Result<(), ClientError> {
+    let consensus_state = ConsensusStateType::try_from(consensus_state)?;
+
+    if consensus_state.data.is_empty() {
+        return Err(Error::DecodeError {
+            reason: "empty consensus state data".to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Validate the given proof height against the client state's latest height,
+/// returning an error if the proof height is greater than the latest height
+/// of the client state.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn validate_proof_height(
+    client_state: &ClientStateType,
+    proof_height: Height,
+) -> Result<(), ClientError> {
+    let latest_height = client_state.latest_height;
+
+    if latest_height < proof_height {
+        return Err(ClientError::InvalidProofHeight {
+            latest_height,
+            proof_height,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,221 @@
+use ibc_client_wasm_types::client_message::ClientMessage;
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::consensus_state::ConsensusState as ConsensusStateType;
+use ibc_client_wasm_types::error::Error;
+use ibc_core_client::context::client_state::ClientStateExecution;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+use crate::checksum_hex;
+use crate::context::WasmClientExecutionContext;
+use crate::contract::{self, InstantiateMsg, SudoMsg};
+use crate::store::{self, ClientStore, MigrationStore};
+use crate::vm::WasmVm;
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: WasmClientExecutionContext,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        initialise(self.inner(), ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        update_state(self.inner(), ctx, client_id, header)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        update_on_misbehaviour(self.inner(), ctx, client_id, client_message)
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::Unsupported {
+            reason: "client upgrades".to_string(),
+        }
+        .into())
+    }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        update_on_recovery(
+            self.inner(),
+            ctx,
+            subject_client_id,
+            substitute_client_state,
+            substitute_consensus_state,
+        )
+    }
+}
+
+/// Instantiate the contract of the client with its initial client and
+/// consensus states, which the contract stores.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn initialise<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: WasmClientExecutionContext,
+{
+    if !ctx.has_checksum(&client_state.checksum)? {
+        return Err(Error::UnknownChecksum {
+            checksum: checksum_hex(&client_state.checksum),
+        }
+        .into());
+    }
+
+    let msg = InstantiateMsg {
+        client_state: client_state.data.clone(),
+        consensus_state: ConsensusStateType::try_from(consensus_state)?.data,
+        checksum: client_state.checksum.clone(),
+    };
+
+    let env = contract::contract_env(ctx, client_id, &client_state.checksum)?;
+
+    let output = ctx.wasm_vm().instantiate(
+        &env,
+        &ClientStore::new(ctx, client_id),
+        &contract::encode(&msg)?,
+    )?;
+
+    contract::decode_result(&output.data)?;
+
+    store::apply_writes(ctx, client_id, output.writes)?;
+
+    Ok(())
+}
+
+/// Update the client with a verified header through its contract, returning
+/// the heights of the consensus states it stored.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn update_state<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: WasmClientExecutionContext,
+{
+    let msg = SudoMsg::UpdateState {
+        client_message: ClientMessage::try_from(header)?.data,
+    };
+
+    let env = contract::contract_env(ctx, client_id, &client_state.checksum)?;
+
+    let (result, writes) = contract::sudo(ctx, &ClientStore::new(ctx, client_id), &env, &msg)?;
+
+    store::apply_writes(ctx, client_id, writes)?;
+
+    Ok(result.heights.unwrap_or_default())
+}
+
+/// Update the client on misbehaviour through its contract, which freezes it.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn update_on_misbehaviour<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<(), ClientError>
+where
+    E: WasmClientExecutionContext,
+{
+    let msg = SudoMsg::UpdateStateOnMisbehaviour {
+        client_message: ClientMessage::try_from(client_message)?.data,
+    };
+
+    let env = contract::contract_env(ctx, client_id, &client_state.checksum)?;
+
+    let (_, writes) = contract::sudo(ctx, &ClientStore::new(ctx, client_id), &env, &msg)?;
+
+    store::apply_writes(ctx, client_id, writes)?;
+
+    Ok(())
+}
+
+/// Migrate the substitute client state and latest consensus state to the
+/// subject client through its contract, in response to a successful client
+/// recovery.
+///
+/// The contract reads the subject client under the [`SUBJECT_PREFIX`] and
+/// the substitute client under the [`SUBSTITUTE_PREFIX`] of a
+/// [`MigrationStore`], and may only write to the former.
+///
+/// This function is typically implemented as part of the [`ClientStateExecution`]
+/// trait, but has been made standalone in order to enable greater flexibility
+/// of the ClientState APIs.
+///
+/// [`SUBJECT_PREFIX`]: ibc_client_wasm_types::SUBJECT_PREFIX
+/// [`SUBSTITUTE_PREFIX`]: ibc_client_wasm_types::SUBSTITUTE_PREFIX
+pub fn update_on_recovery<E>(
+    subject_client_state: &ClientStateType,
+    ctx: &mut E,
+    subject_client_id: &ClientId,
+    substitute_client_state: Any,
+    substitute_consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: WasmClientExecutionContext,
+{
+    let migration_store = MigrationStore::new(
+        ClientStore::new(ctx, subject_client_id),
+        subject_client_id,
+        substitute_client_state,
+        substitute_consensus_state,
+    )?;
+
+    let env = contract::contract_env(ctx, subject_client_id, &subject_client_state.checksum)?;
+
+    let (_, writes) = contract::sudo(ctx, &migration_store, &env, &SudoMsg::MigrateClientStore {})?;
+
+    let writes = writes
+        .into_iter()
+        .map(store::subject_write)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    store::apply_writes(ctx, subject_client_id, writes)?;
+
+    Ok(())
+}
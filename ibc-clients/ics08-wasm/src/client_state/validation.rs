@@ -0,0 +1,296 @@
+use core::str::FromStr;
+
+use ibc_client_wasm_types::client_message::ClientMessage;
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::error::Error;
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::proto::v1::Height as RawHeight;
+use ibc_core_client::types::{Height, Status};
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
+
+use super::ClientState;
+use crate::context::WasmClientValidationContext;
+use crate::contract::{self, MerklePath, QueryMsg, SudoMsg};
+use crate::store::ClientStore;
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: WasmClientValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message(self.inner(), ctx, client_id, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(self.inner(), ctx, client_id, client_message)
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        status(self.inner(), ctx, client_id)
+    }
+
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        check_substitute(self.inner(), substitute_client_state)
+    }
+
+    fn verify_upgrade_client_at_height(
+        &self,
+        _ctx: &V,
+        _client_cons_state_path: &ClientConsensusStatePath,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+    ) -> Result<(), ClientError> {
+        Err(Error::Unsupported {
+            reason: "client upgrades".to_string(),
+        }
+        .into())
+    }
+
+    fn verify_membership_at_height(
+        &self,
+        ctx: &V,
+        client_cons_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let msg = SudoMsg::VerifyMembership {
+            proof: proof.clone().into(),
+            path: merkle_path(prefix, path)?,
+            value,
+            height: proof_height(client_cons_state_path),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        };
+
+        verify_proof(self.inner(), ctx, &client_cons_state_path.client_id, &msg)
+    }
+
+    fn verify_non_membership_at_height(
+        &self,
+        ctx: &V,
+        client_cons_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        let msg = SudoMsg::VerifyNonMembership {
+            proof: proof.clone().into(),
+            path: merkle_path(prefix, path)?,
+            height: proof_height(client_cons_state_path),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        };
+
+        verify_proof(self.inner(), ctx, &client_cons_state_path.client_id, &msg)
+    }
+
+    fn timestamp_at_height(
+        &self,
+        ctx: &V,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Timestamp, ClientError> {
+        timestamp_at_height(self.inner(), ctx, client_cons_state_path)
+    }
+}
+
+/// Verify the client message by querying the contract of the client.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_client_message<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<(), ClientError>
+where
+    V: WasmClientValidationContext,
+{
+    let msg = QueryMsg::VerifyClientMessage {
+        client_message: ClientMessage::try_from(client_message)?.data,
+    };
+
+    contract::query(ctx, client_id, &client_state.checksum, &msg)?;
+
+    Ok(())
+}
+
+/// Check for misbehaviour by querying the contract of the client.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn check_for_misbehaviour<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<bool, ClientError>
+where
+    V: WasmClientValidationContext,
+{
+    let msg = QueryMsg::CheckForMisbehaviour {
+        client_message: ClientMessage::try_from(client_message)?.data,
+    };
+
+    let response = contract::query(ctx, client_id, &client_state.checksum, &msg)?;
+
+    Ok(response.found_misbehaviour.unwrap_or_default())
+}
+
+/// Query the status of the client state from its contract.
+///
+/// A client not created yet has no contract to query, and is active as long
+/// as the byte code it is created with is stored.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn status<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+) -> Result<Status, ClientError>
+where
+    V: WasmClientValidationContext,
+{
+    let client_state_key = ClientStatePath::leaf();
+
+    if ctx
+        .client_store_get(client_id, client_state_key.as_bytes())?
+        .is_none()
+    {
+        if ctx.has_checksum(&client_state.checksum)? {
+            return Ok(Status::Active);
+        }
+
+        return Ok(Status::Unauthorized);
+    }
+
+    let response = contract::query(ctx, client_id, &client_state.checksum, &QueryMsg::Status {})?;
+
+    let status = response.status.ok_or(Error::InvalidContractResponse {
+        reason: "missing status".to_string(),
+    })?;
+
+    // Contracts display the status as `ibc-go` does, e.g. "Active".
+    Status::from_str(&status.to_uppercase())
+}
+
+/// Check that the subject and substitute client states match as part of
+/// the client recovery validation step.
+///
+/// The subject and substitute clients must run the contract of the same
+/// checksum, which checks the rest of their client states while migrating
+/// the substitute one.
+pub fn check_substitute(
+    subject_client_state: &ClientStateType,
+    substitute_client_state: Any,
+) -> Result<(), ClientError> {
+    let substitute_client_state = ClientStateType::try_from(substitute_client_state)?;
+
+    (subject_client_state.checksum == substitute_client_state.checksum)
+        .then_some(())
+        .ok_or(ClientError::ClientRecoveryStateMismatch)
+}
+
+/// Returns the timestamp of the consensus state at the given path, by
+/// querying the contract of the client.
+pub fn timestamp_at_height<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_cons_state_path: &ClientConsensusStatePath,
+) -> Result<Timestamp, ClientError>
+where
+    V: WasmClientValidationContext,
+{
+    let msg = QueryMsg::TimestampAtHeight {
+        height: Height::new(
+            client_cons_state_path.revision_number,
+            client_cons_state_path.revision_height,
+        )?,
+    };
+
+    let response = contract::query(
+        ctx,
+        &client_cons_state_path.client_id,
+        &client_state.checksum,
+        &msg,
+    )?;
+
+    let timestamp = response.timestamp.ok_or(Error::InvalidContractResponse {
+        reason: "missing timestamp".to_string(),
+    })?;
+
+    Timestamp::from_nanoseconds(timestamp).map_err(|e| {
+        Error::InvalidContractResponse {
+            reason: e.to_string(),
+        }
+        .into()
+    })
+}
+
+/// Verifies a proof by calling the contract, which may not write to the
+/// store of the client while doing so.
+fn verify_proof<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    msg: &SudoMsg,
+) -> Result<(), ClientError>
+where
+    V: WasmClientValidationContext,
+{
+    let env = contract::contract_env(ctx, client_id, &client_state.checksum)?;
+
+    let (_, writes) = contract::sudo(ctx, &ClientStore::new(ctx, client_id), &env, msg)?;
+
+    if !writes.is_empty() {
+        return Err(Error::UnexpectedContractWrite {
+            call: "verifying a proof".to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Returns the path of a proof as contracts expect it, i.e. the commitment
+/// prefix followed by the path.
+fn merkle_path(prefix: &CommitmentPrefix, path: Path) -> Result<MerklePath, ClientError> {
+    let prefix = String::from_utf8(prefix.as_bytes().to_vec()).map_err(|e| Error::DecodeError {
+        reason: format!("commitment prefix is not UTF-8: {e}"),
+    })?;
+
+    Ok(MerklePath {
+        key_path: vec![prefix, path.to_string()],
+    })
+}
+
+fn proof_height(client_cons_state_path: &ClientConsensusStatePath) -> RawHeight {
+    RawHeight {
+        revision_number: client_cons_state_path.revision_number,
+        revision_height: client_cons_state_path.revision_height,
+    }
+}
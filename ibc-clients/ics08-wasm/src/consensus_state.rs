@@ -0,0 +1,101 @@
+//! This module includes trait implementations for the
+//! `ibc_client_wasm_types::ConsensusState` type. It implements the
+//! `ConsensusStateTrait` for `ConsensusState` by defining a newtype wrapper in
+//! order to circumvent Rust's orphan rule, which disallows foreign traits from
+//! being implemented on foreign types. This module also includes some trait
+//! implementations that serve to pass through traits implemented on the wrapped
+//! `ConsensusState` type.
+//!
+//! The consensus state of an 08-wasm client is opaque to the host, which
+//! leaves its root and timestamp to the contract of the client, through the
+//! `ClientStateValidation` methods taking the path of a consensus state.
+
+use ibc_client_wasm_types::consensus_state::ConsensusState as ConsensusStateType;
+use ibc_client_wasm_types::proto::v1::ConsensusState as RawWasmConsensusState;
+use ibc_core_client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+/// Newtype wrapper around the `ConsensusState` type imported from the
+/// `ibc-client-wasm-types` crate. This wrapper exists so that we can bypass
+/// Rust's orphan rules and implement traits from `ibc::core::client::context`
+/// on the `ConsensusState` type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    inner: ConsensusStateType,
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn inner(&self) -> &ConsensusStateType {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> ConsensusStateType {
+        self.inner
+    }
+}
+
+impl From<ConsensusStateType> for ConsensusState {
+    fn from(inner: ConsensusStateType) -> Self {
+        Self {
+            inner,
+            root: CommitmentRoot::from_bytes(&[]),
+        }
+    }
+}
+
+impl From<ConsensusState> for ConsensusStateType {
+    fn from(value: ConsensusState) -> Self {
+        value.inner
+    }
+}
+
+impl Protobuf<RawWasmConsensusState> for ConsensusState {}
+
+impl TryFrom<RawWasmConsensusState> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: RawWasmConsensusState) -> Result<Self, Self::Error> {
+        Ok(ConsensusStateType::try_from(raw)?.into())
+    }
+}
+
+impl From<ConsensusState> for RawWasmConsensusState {
+    fn from(consensus_state: ConsensusState) -> Self {
+        consensus_state.inner.into()
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(ConsensusStateType::try_from(raw)?.into())
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        consensus_state.inner.into()
+    }
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    /// The root of an 08-wasm consensus state is empty, as only its contract
+    /// knows what it commits to.
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    /// The timestamp of an 08-wasm consensus state is unset, as only its
+    /// contract knows it.
+    fn timestamp(&self) -> Timestamp {
+        Timestamp::none()
+    }
+}
@@ -0,0 +1,84 @@
+//! Defines the contexts hosts implement to run 08-wasm clients.
+//!
+//! Contracts keep their state in the store of their client, under keys of
+//! their own, among which "clientState" and "consensusStates/{height}" hold
+//! the 08-wasm client and consensus states. Hosts backing the store of a
+//! client with the "clients/{client_id}/" prefix of their provable store, as
+//! `ibc-go` does, hence read these states as they read those of any other
+//! client.
+//!
+//! The client charges the host for the values its contracts read from and
+//! write to the store of their client, through
+//! [`ClientValidationContext::charge`](ibc_core_client::context::ClientValidationContext::charge),
+//! so hosts metering their own store need not charge the `client_store_*`
+//! methods again.
+
+use ibc_core_client::context::{ExtClientExecutionContext, ExtClientValidationContext};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+use crate::store::{Order, StoreEntries};
+use crate::vm::WasmVm;
+use crate::MAX_WASM_BYTE_CODE_SIZE;
+
+/// The client validation context of hosts running 08-wasm clients.
+pub trait WasmClientValidationContext: ExtClientValidationContext {
+    type Vm: WasmVm;
+
+    /// Returns the VM running the contracts of the host.
+    fn wasm_vm(&self) -> &Self::Vm;
+
+    /// Returns the value stored at the given key of the store of a client.
+    fn client_store_get(
+        &self,
+        client_id: &ClientId,
+        key: &[u8],
+    ) -> Result<Option<Vec<u8>>, ContextError>;
+
+    /// Returns the entries of the store of a client whose keys are within
+    /// `start` (inclusive) and `end` (exclusive), in the given order.
+    fn client_store_range(
+        &self,
+        client_id: &ClientId,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Result<StoreEntries, ContextError>;
+
+    /// Returns whether the byte code of the given checksum is stored.
+    fn has_checksum(&self, checksum: &[u8]) -> Result<bool, ContextError>;
+
+    /// Returns the signer allowed to store, remove and migrate contracts,
+    /// typically the governance account of the host.
+    fn wasm_authority(&self) -> Result<Signer, ContextError>;
+
+    /// Returns the maximum size of the byte code of a contract.
+    fn max_wasm_byte_code_size(&self) -> usize {
+        MAX_WASM_BYTE_CODE_SIZE
+    }
+}
+
+/// The client execution context of hosts running 08-wasm clients.
+pub trait WasmClientExecutionContext:
+    WasmClientValidationContext + ExtClientExecutionContext
+{
+    /// Sets the value at the given key of the store of a client.
+    fn client_store_set(
+        &mut self,
+        client_id: &ClientId,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(), ContextError>;
+
+    /// Removes the value at the given key of the store of a client.
+    fn client_store_remove(&mut self, client_id: &ClientId, key: &[u8])
+        -> Result<(), ContextError>;
+
+    /// Records the checksum of stored byte code.
+    fn store_checksum(&mut self, checksum: Vec<u8>) -> Result<(), ContextError>;
+
+    /// Deletes the record of the checksum of removed byte code.
+    fn delete_checksum(&mut self, checksum: &[u8]) -> Result<(), ContextError>;
+}
@@ -0,0 +1,243 @@
+//! Defines the messages 08-wasm clients send to their contract and the
+//! responses they expect back, as the JSON documents `ibc-go` exchanges with
+//! contracts, and `ibc-client-cw` implements on the contract side.
+
+use ibc_client_wasm_types::error::Error;
+use ibc_client_wasm_types::serializer::Base64;
+use ibc_client_wasm_types::Bytes;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::proto::v1::Height as RawHeight;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::context::WasmClientValidationContext;
+use crate::store::{ClientStore, StoreWrite, WasmStore};
+use crate::vm::{ContractEnv, WasmVm};
+
+/// The message instantiating the contract of a new client, holding the
+/// client and consensus states its contract is initialised with.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct InstantiateMsg {
+    #[serde(with = "Base64")]
+    pub client_state: Bytes,
+    #[serde(with = "Base64")]
+    pub consensus_state: Bytes,
+    #[serde(with = "Base64")]
+    pub checksum: Bytes,
+}
+
+/// The path of a proof, whose first key is the commitment prefix.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MerklePath {
+    pub key_path: Vec<String>,
+}
+
+/// The messages sent to the `sudo` entry point of the contract, which may
+/// write to the store of the client.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SudoMsg {
+    UpdateState {
+        #[serde(with = "Base64")]
+        client_message: Bytes,
+    },
+    UpdateStateOnMisbehaviour {
+        #[serde(with = "Base64")]
+        client_message: Bytes,
+    },
+    VerifyMembership {
+        #[serde(with = "Base64")]
+        proof: Bytes,
+        path: MerklePath,
+        #[serde(with = "Base64")]
+        value: Bytes,
+        height: RawHeight,
+        delay_block_period: u64,
+        delay_time_period: u64,
+    },
+    VerifyNonMembership {
+        #[serde(with = "Base64")]
+        proof: Bytes,
+        path: MerklePath,
+        height: RawHeight,
+        delay_block_period: u64,
+        delay_time_period: u64,
+    },
+    MigrateClientStore {},
+}
+
+/// The messages sent to the `query` entry point of the contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Status {},
+    TimestampAtHeight {
+        height: Height,
+    },
+    VerifyClientMessage {
+        #[serde(with = "Base64")]
+        client_message: Bytes,
+    },
+    CheckForMisbehaviour {
+        #[serde(with = "Base64")]
+        client_message: Bytes,
+    },
+}
+
+/// The response of the contract to a [`QueryMsg`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct QueryResponse {
+    pub is_valid: bool,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub found_misbehaviour: Option<bool>,
+    #[serde(default)]
+    pub timestamp: Option<u64>,
+}
+
+/// The response of the contract to an [`InstantiateMsg`] or a [`SudoMsg`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct ContractResult {
+    #[serde(default)]
+    pub heights: Option<Vec<Height>>,
+}
+
+/// Returns the environment the contract of the given client runs in.
+pub(crate) fn contract_env<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    checksum: &[u8],
+) -> Result<ContractEnv, ClientError>
+where
+    V: WasmClientValidationContext,
+{
+    Ok(ContractEnv {
+        client_id: client_id.clone(),
+        checksum: checksum.to_vec(),
+        host_height: ctx.host_height()?,
+        host_timestamp: ctx.host_timestamp()?,
+    })
+}
+
+/// Queries the contract of the given client.
+pub(crate) fn query<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    checksum: &[u8],
+    msg: &QueryMsg,
+) -> Result<QueryResponse, ClientError>
+where
+    V: WasmClientValidationContext,
+{
+    let env = contract_env(ctx, client_id, checksum)?;
+
+    let response = ctx
+        .wasm_vm()
+        .query(&env, &ClientStore::new(ctx, client_id), &encode(msg)?)?;
+
+    let response: QueryResponse = decode(&response)?;
+
+    if !response.is_valid {
+        return Err(Error::InvalidContractResponse {
+            reason: "the contract deemed the query invalid".to_string(),
+        }
+        .into());
+    }
+
+    Ok(response)
+}
+
+/// Calls the `sudo` entry point of the contract over the given store,
+/// returning its result along with the writes it made to the store.
+pub(crate) fn sudo<V>(
+    ctx: &V,
+    store: &dyn WasmStore,
+    env: &ContractEnv,
+    msg: &SudoMsg,
+) -> Result<(ContractResult, Vec<StoreWrite>), ClientError>
+where
+    V: WasmClientValidationContext,
+{
+    let output = ctx.wasm_vm().sudo(env, store, &encode(msg)?)?;
+
+    Ok((decode_result(&output.data)?, output.writes))
+}
+
+/// Decodes the result of an `instantiate` or `sudo` call, which contracts
+/// may leave empty.
+pub(crate) fn decode_result(data: &[u8]) -> Result<ContractResult, ClientError> {
+    if data.is_empty() {
+        return Ok(ContractResult::default());
+    }
+
+    decode(data)
+}
+
+pub(crate) fn encode<T: Serialize>(msg: &T) -> Result<Vec<u8>, ClientError> {
+    serde_json::to_vec(msg).map_err(|e| ClientError::Other {
+        description: format!("failed to encode contract message: {e}"),
+    })
+}
+
+fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T, ClientError> {
+    serde_json::from_slice(data).map_err(|e| {
+        Error::InvalidContractResponse {
+            reason: e.to_string(),
+        }
+        .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_match_contract_json() {
+        let msg = SudoMsg::VerifyMembership {
+            proof: b"proof".to_vec(),
+            path: MerklePath {
+                key_path: vec![
+                    "ibc".to_string(),
+                    "clients/07-tendermint-0/clientState".to_string(),
+                ],
+            },
+            value: b"value".to_vec(),
+            height: RawHeight {
+                revision_number: 0,
+                revision_height: 5,
+            },
+            delay_block_period: 0,
+            delay_time_period: 0,
+        };
+
+        // The height is left to the serialization of `ibc-proto`, which the
+        // contract deserializes it with.
+        assert!(encode(&msg).unwrap().starts_with(
+            br#"{"verify_membership":{"proof":"cHJvb2Y=","path":{"key_path":["ibc","clients/07-tendermint-0/clientState"]},"value":"dmFsdWU=","height":"#
+        ));
+
+        assert_eq!(
+            encode(&SudoMsg::MigrateClientStore {}).unwrap(),
+            br#"{"migrate_client_store":{}}"#
+        );
+        assert_eq!(encode(&QueryMsg::Status {}).unwrap(), br#"{"status":{}}"#);
+    }
+
+    #[test]
+    fn test_decode_responses() {
+        let response: QueryResponse =
+            decode(br#"{"is_valid":true,"status":"Active","genesis_metadata":[]}"#).unwrap();
+        assert_eq!(response.status.as_deref(), Some("Active"));
+
+        let result =
+            decode_result(br#"{"heights":[{"revision_number":0,"revision_height":7}]}"#).unwrap();
+        assert_eq!(result.heights, Some(vec![Height::new(0, 7).unwrap()]));
+
+        assert_eq!(decode_result(b"").unwrap(), ContractResult::default());
+    }
+}
@@ -0,0 +1,332 @@
+//! Implements the [`WasmVm`] over `cosmwasm-vm`, the VM `ibc-go` runs the
+//! contracts of 08-wasm clients with.
+//!
+//! Contracts run over a snapshot of the store of their client, taken before
+//! the call, whose writes are recorded and handed back to the client.
+
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{
+    Addr, Binary, BlockInfo, ContractInfo, ContractResult, Empty, Env, MessageInfo, Record,
+    Response, SystemError, SystemResult,
+};
+use cosmwasm_vm::{
+    call_instantiate_raw, call_migrate_raw, call_query_raw, call_sudo_raw, Backend, BackendApi,
+    BackendError, BackendResult, Cache, Checksum, GasInfo, Instance, InstanceOptions, Querier,
+    Storage, VmError,
+};
+use ibc_client_wasm_types::error::Error;
+use ibc_client_wasm_types::Bytes;
+use ibc_primitives::prelude::*;
+
+use crate::store::{self, Order, StoreWrite, WasmStore};
+use crate::vm::{ContractEnv, ContractOutput, WasmVm};
+
+/// The cache of compiled contracts the [`CosmwasmVm`] runs.
+///
+/// As creating a cache is `unsafe`, hosts create it themselves, with the
+/// `CacheOptions` of their choice.
+pub type CosmwasmCache = Cache<HostApi, SnapshotStorage, NoQuerier>;
+
+/// Runs the contracts of 08-wasm clients with `cosmwasm-vm`.
+pub struct CosmwasmVm {
+    cache: CosmwasmCache,
+    chain_id: String,
+    gas_limit: u64,
+}
+
+impl CosmwasmVm {
+    /// Creates a VM running contracts from the given cache, on the chain of
+    /// the given identifier, with at most the given gas per call.
+    pub fn new(cache: CosmwasmCache, chain_id: String, gas_limit: u64) -> Self {
+        Self {
+            cache,
+            chain_id,
+            gas_limit,
+        }
+    }
+
+    fn instance(
+        &self,
+        env: &ContractEnv,
+        store: &dyn WasmStore,
+    ) -> Result<Instance<HostApi, SnapshotStorage, NoQuerier>, Error> {
+        let checksum = Checksum::try_from(env.checksum.as_slice()).map_err(vm_error)?;
+
+        let backend = Backend {
+            api: HostApi,
+            storage: SnapshotStorage::new(store)?,
+            querier: NoQuerier,
+        };
+
+        let options = InstanceOptions {
+            gas_limit: self.gas_limit,
+            print_debug: false,
+        };
+
+        self.cache
+            .get_instance(&checksum, backend, options)
+            .map_err(vm_error)
+    }
+
+    fn env(&self, env: &ContractEnv) -> Result<Vec<u8>, Error> {
+        let env = Env {
+            block: BlockInfo {
+                height: env.host_height.revision_height(),
+                time: cosmwasm_std::Timestamp::from_nanos(env.host_timestamp.nanoseconds()),
+                chain_id: self.chain_id.clone(),
+            },
+            transaction: None,
+            contract: ContractInfo {
+                address: Addr::unchecked(env.client_id.as_str()),
+            },
+        };
+
+        cosmwasm_std::to_json_vec(&env).map_err(vm_error)
+    }
+
+    /// Runs an entry point returning a `Response`, and returns its data
+    /// along with the writes the contract made. Responses carrying messages
+    /// are rejected.
+    fn execute<F>(
+        &self,
+        env: &ContractEnv,
+        store: &dyn WasmStore,
+        call: F,
+    ) -> Result<ContractOutput, Error>
+    where
+        F: FnOnce(
+            &mut Instance<HostApi, SnapshotStorage, NoQuerier>,
+            &[u8],
+        ) -> Result<Vec<u8>, VmError>,
+    {
+        let mut instance = self.instance(env, store)?;
+
+        let result = call(&mut instance, &self.env(env)?).map_err(vm_error)?;
+
+        let response = match cosmwasm_std::from_json::<ContractResult<Response<Empty>>>(&result)
+            .map_err(vm_error)?
+        {
+            ContractResult::Ok(response) => response,
+            ContractResult::Err(reason) => return Err(Error::Contract { reason }),
+        };
+
+        // As `ibc-go`, contracts may not dispatch messages on behalf of
+        // their client.
+        if !response.messages.is_empty() {
+            return Err(Error::UnexpectedContractMessages {
+                count: response.messages.len(),
+            });
+        }
+
+        let writes = instance
+            .recycle()
+            .map(|backend| backend.storage.writes)
+            .ok_or(Error::Vm {
+                reason: "failed to recover the storage of the contract instance".to_string(),
+            })?;
+
+        Ok(ContractOutput {
+            data: response.data.map(Binary::into).unwrap_or_default(),
+            writes,
+        })
+    }
+}
+
+impl WasmVm for CosmwasmVm {
+    fn store_code(&self, byte_code: &[u8]) -> Result<Bytes, Error> {
+        let checksum = self.cache.save_wasm(byte_code).map_err(vm_error)?;
+
+        Ok(checksum.into())
+    }
+
+    fn remove_code(&self, checksum: &[u8]) -> Result<(), Error> {
+        let checksum = Checksum::try_from(checksum).map_err(vm_error)?;
+
+        self.cache.remove_wasm(&checksum).map_err(vm_error)
+    }
+
+    fn instantiate(
+        &self,
+        env: &ContractEnv,
+        store: &dyn WasmStore,
+        msg: &[u8],
+    ) -> Result<ContractOutput, Error> {
+        let info = MessageInfo {
+            sender: Addr::unchecked(env.client_id.as_str()),
+            funds: Vec::new(),
+        };
+        let info = cosmwasm_std::to_json_vec(&info).map_err(vm_error)?;
+
+        self.execute(env, store, |instance, env| {
+            call_instantiate_raw(instance, env, &info, msg)
+        })
+    }
+
+    fn sudo(
+        &self,
+        env: &ContractEnv,
+        store: &dyn WasmStore,
+        msg: &[u8],
+    ) -> Result<ContractOutput, Error> {
+        self.execute(env, store, |instance, env| {
+            call_sudo_raw(instance, env, msg)
+        })
+    }
+
+    fn migrate(
+        &self,
+        env: &ContractEnv,
+        store: &dyn WasmStore,
+        msg: &[u8],
+    ) -> Result<ContractOutput, Error> {
+        self.execute(env, store, |instance, env| {
+            call_migrate_raw(instance, env, msg)
+        })
+    }
+
+    fn query(
+        &self,
+        env: &ContractEnv,
+        store: &dyn WasmStore,
+        msg: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let mut instance = self.instance(env, store)?;
+
+        let result = call_query_raw(&mut instance, &self.env(env)?, msg).map_err(vm_error)?;
+
+        match cosmwasm_std::from_json::<ContractResult<Binary>>(&result).map_err(vm_error)? {
+            ContractResult::Ok(response) => Ok(response.into()),
+            ContractResult::Err(reason) => Err(Error::Contract { reason }),
+        }
+    }
+}
+
+/// The storage contracts run over: a snapshot of the store of their client,
+/// recording the writes made to it.
+pub struct SnapshotStorage {
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+    writes: Vec<StoreWrite>,
+    iterators: Vec<std::vec::IntoIter<Record>>,
+}
+
+impl SnapshotStorage {
+    fn new(store: &dyn WasmStore) -> Result<Self, Error> {
+        let entries = store.entries().map_err(vm_error)?.into_iter().collect();
+
+        Ok(Self {
+            entries,
+            writes: Vec::new(),
+            iterators: Vec::new(),
+        })
+    }
+}
+
+impl Storage for SnapshotStorage {
+    fn get(&self, key: &[u8]) -> BackendResult<Option<Vec<u8>>> {
+        (Ok(self.entries.get(key).cloned()), GasInfo::free())
+    }
+
+    fn scan(
+        &mut self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: cosmwasm_std::Order,
+    ) -> BackendResult<u32> {
+        let order = match order {
+            cosmwasm_std::Order::Ascending => Order::Ascending,
+            cosmwasm_std::Order::Descending => Order::Descending,
+        };
+
+        let Ok(iterator_id) = u32::try_from(self.iterators.len()) else {
+            return (
+                Err(BackendError::unknown("too many iterators")),
+                GasInfo::free(),
+            );
+        };
+
+        self.iterators
+            .push(store::range(&self.entries, start, end, order).into_iter());
+
+        (Ok(iterator_id), GasInfo::free())
+    }
+
+    fn next(&mut self, iterator_id: u32) -> BackendResult<Option<Record>> {
+        let result = self
+            .iterators
+            .get_mut(iterator_id as usize)
+            .map(Iterator::next)
+            .ok_or(BackendError::iterator_does_not_exist(iterator_id));
+
+        (result, GasInfo::free())
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) -> BackendResult<()> {
+        self.entries.insert(key.to_vec(), value.to_vec());
+        self.writes.push(StoreWrite::Set {
+            key: key.to_vec(),
+            value: value.to_vec(),
+        });
+
+        (Ok(()), GasInfo::free())
+    }
+
+    fn remove(&mut self, key: &[u8]) -> BackendResult<()> {
+        self.entries.remove(key);
+        self.writes.push(StoreWrite::Remove { key: key.to_vec() });
+
+        (Ok(()), GasInfo::free())
+    }
+}
+
+/// The API contracts run with, which takes the client identifiers
+/// contracts are addressed by as they are.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HostApi;
+
+impl BackendApi for HostApi {
+    fn addr_validate(&self, input: &str) -> BackendResult<()> {
+        let result = match input.is_empty() {
+            true => Err(BackendError::user_err("empty address")),
+            false => Ok(()),
+        };
+
+        (result, GasInfo::free())
+    }
+
+    fn addr_canonicalize(&self, human: &str) -> BackendResult<Vec<u8>> {
+        (Ok(human.as_bytes().to_vec()), GasInfo::free())
+    }
+
+    fn addr_humanize(&self, canonical: &[u8]) -> BackendResult<String> {
+        let result = String::from_utf8(canonical.to_vec())
+            .map_err(|_| BackendError::user_err("invalid canonical address"));
+
+        (result, GasInfo::free())
+    }
+}
+
+/// The querier contracts run with, as 08-wasm contracts do not query the
+/// host.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoQuerier;
+
+impl Querier for NoQuerier {
+    fn query_raw(
+        &self,
+        _request: &[u8],
+        _gas_limit: u64,
+    ) -> BackendResult<SystemResult<ContractResult<Binary>>> {
+        let result = SystemResult::Err(SystemError::UnsupportedRequest {
+            kind: "08-wasm contracts cannot query the host".to_string(),
+        });
+
+        (Ok(result), GasInfo::free())
+    }
+}
+
+fn vm_error(e: impl ToString) -> Error {
+    Error::Vm {
+        reason: e.to_string(),
+    }
+}
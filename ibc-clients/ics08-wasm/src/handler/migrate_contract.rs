@@ -0,0 +1,91 @@
+//! Protocol logic for processing ICS-08 messages of type `MsgMigrateContract`.
+
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::error::Error;
+use ibc_client_wasm_types::msgs::migrate_contract::MsgMigrateContract;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use prost::Message;
+
+use super::{validate_authority, validate_stored_checksum};
+use crate::checksum_hex;
+use crate::context::{WasmClientExecutionContext, WasmClientValidationContext};
+use crate::contract;
+use crate::store::{self, ClientStore};
+use crate::vm::WasmVm;
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgMigrateContract) -> Result<(), ContextError>
+where
+    Ctx: WasmClientValidationContext,
+{
+    validate_authority(ctx, &msg.signer)?;
+
+    validate_stored_checksum(ctx, &msg.checksum)?;
+
+    let client_state = wasm_client_state(ctx, &msg.client_id)?;
+
+    if client_state.checksum == msg.checksum {
+        return Err(ClientError::from(Error::ChecksumUnchanged {
+            client_id: msg.client_id,
+            checksum: checksum_hex(&msg.checksum),
+        })
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Calls the `migrate` entry point of the new contract of the client, then
+/// points the client state, as the contract left it, to the new checksum.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgMigrateContract) -> Result<(), ContextError>
+where
+    Ctx: WasmClientExecutionContext,
+{
+    let env = contract::contract_env(ctx, &msg.client_id, &msg.checksum)?;
+
+    let output = ctx
+        .wasm_vm()
+        .migrate(&env, &ClientStore::new(ctx, &msg.client_id), &msg.msg)
+        .map_err(ClientError::from)?;
+
+    store::apply_writes(ctx, &msg.client_id, output.writes)?;
+
+    let client_state = ClientStateType {
+        checksum: msg.checksum,
+        ..wasm_client_state(ctx, &msg.client_id)?
+    };
+
+    ctx.client_store_set(
+        &msg.client_id,
+        ClientStatePath::leaf().into_bytes(),
+        Any::from(client_state).encode_to_vec(),
+    )?;
+
+    Ok(())
+}
+
+/// Returns the 08-wasm client state stored in the store of the client.
+fn wasm_client_state<Ctx>(ctx: &Ctx, client_id: &ClientId) -> Result<ClientStateType, ContextError>
+where
+    Ctx: WasmClientValidationContext,
+{
+    let not_wasm_client = || {
+        ClientError::from(Error::NotWasmClient {
+            client_id: client_id.clone(),
+        })
+    };
+
+    let value = ctx
+        .client_store_get(client_id, ClientStatePath::leaf().as_bytes())?
+        .ok_or(ClientError::ClientStateNotFound {
+            client_id: client_id.clone(),
+        })?;
+
+    let any = Any::decode(value.as_slice()).map_err(|_| not_wasm_client())?;
+
+    Ok(ClientStateType::try_from(any).map_err(|_| not_wasm_client())?)
+}
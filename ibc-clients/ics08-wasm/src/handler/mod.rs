@@ -0,0 +1,191 @@
+//! Implements the messages managing the contracts of 08-wasm clients.
+//!
+//! These messages are not part of the
+//! [`MsgEnvelope`](ibc_core_handler_types::msgs::MsgEnvelope), hence hosts
+//! route them to the `validate` and `execute` functions of their handler
+//! themselves.
+
+use ibc_client_wasm_types::error::Error;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use sha2::{Digest, Sha256};
+
+use crate::checksum_hex;
+use crate::context::WasmClientValidationContext;
+
+pub mod migrate_contract;
+pub mod remove_checksum;
+pub mod store_code;
+
+/// The length of the checksum of byte code, i.e. of its SHA-256 digest.
+pub const CHECKSUM_LENGTH: usize = 32;
+
+/// Returns the checksum of the given byte code.
+pub fn checksum(byte_code: &[u8]) -> Vec<u8> {
+    Sha256::digest(byte_code).to_vec()
+}
+
+/// Checks that the message is signed by the authority of the host.
+fn validate_authority<Ctx>(ctx: &Ctx, signer: &Signer) -> Result<(), ContextError>
+where
+    Ctx: WasmClientValidationContext,
+{
+    let authority = ctx.wasm_authority()?;
+
+    if signer != &authority {
+        return Err(ClientError::from(Error::UnauthorizedSigner {
+            signer: signer.to_string(),
+            authority: authority.to_string(),
+        })
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Checks that the checksum has the length of a SHA-256 digest and that the
+/// byte code it refers to is stored.
+fn validate_stored_checksum<Ctx>(ctx: &Ctx, checksum: &[u8]) -> Result<(), ContextError>
+where
+    Ctx: WasmClientValidationContext,
+{
+    if checksum.len() != CHECKSUM_LENGTH {
+        return Err(ClientError::from(Error::InvalidChecksum {
+            reason: format!(
+                "expected {CHECKSUM_LENGTH} bytes, got {} bytes",
+                checksum.len()
+            ),
+        })
+        .into());
+    }
+
+    if !ctx.has_checksum(checksum)? {
+        return Err(ClientError::from(Error::UnknownChecksum {
+            checksum: checksum_hex(checksum),
+        })
+        .into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_client_wasm_types::msgs::migrate_contract::MsgMigrateContract;
+    use ibc_client_wasm_types::msgs::remove_checksum::MsgRemoveChecksum;
+    use ibc_client_wasm_types::msgs::store_code::MsgStoreCode;
+    use ibc_core_host::types::path::ClientStatePath;
+    use ibc_primitives::proto::Any;
+    use prost::Message;
+
+    use super::*;
+    use crate::client_state::mock::*;
+    use crate::store::StoreWrite;
+
+    fn store_code_msg(signer: &str, wasm_byte_code: &[u8]) -> MsgStoreCode {
+        MsgStoreCode {
+            signer: signer.to_string().into(),
+            wasm_byte_code: wasm_byte_code.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_store_code() {
+        let mut host = MockHost::default();
+        let msg = store_code_msg(AUTHORITY, b"code");
+
+        store_code::validate(&host, msg.clone()).unwrap();
+        let checksum = store_code::execute(&mut host, msg.clone()).unwrap();
+
+        assert_eq!(checksum, super::checksum(b"code"));
+        assert_eq!(host.checksums, vec![checksum.clone()]);
+        assert_eq!(*host.vm.codes.borrow(), vec![checksum]);
+
+        // The same byte code cannot be stored twice.
+        assert!(store_code::validate(&host, msg).is_err());
+    }
+
+    #[test]
+    fn test_store_code_validation() {
+        let host = MockHost::default();
+
+        assert!(store_code::validate(&host, store_code_msg("signer", b"code")).is_err());
+        assert!(store_code::validate(&host, store_code_msg(AUTHORITY, b"")).is_err());
+        assert!(store_code::validate(&host, store_code_msg(AUTHORITY, b"too large")).is_err());
+    }
+
+    #[test]
+    fn test_remove_checksum() {
+        let mut host = MockHost::default();
+        let checksum = store_code::execute(&mut host, store_code_msg(AUTHORITY, b"code")).unwrap();
+
+        let msg = MsgRemoveChecksum {
+            signer: AUTHORITY.to_string().into(),
+            checksum: checksum.clone(),
+        };
+
+        remove_checksum::validate(&host, msg.clone()).unwrap();
+        remove_checksum::execute(&mut host, msg.clone()).unwrap();
+
+        assert!(host.checksums.is_empty());
+        assert!(host.vm.codes.borrow().is_empty());
+        assert!(remove_checksum::validate(&host, msg).is_err());
+
+        let msg = MsgRemoveChecksum {
+            signer: AUTHORITY.to_string().into(),
+            checksum: b"short".to_vec(),
+        };
+
+        assert!(remove_checksum::validate(&host, msg).is_err());
+    }
+
+    #[test]
+    fn test_migrate_contract() {
+        let mut host = MockHost::with_client(client_state(checksum(b"old"), 5));
+        host.checksums.push(checksum(b"new"));
+        *host.vm.writes.borrow_mut() = vec![StoreWrite::Set {
+            key: b"migrated".to_vec(),
+            value: b"true".to_vec(),
+        }];
+
+        let msg = MsgMigrateContract {
+            signer: AUTHORITY.to_string().into(),
+            client_id: client_id(),
+            checksum: checksum(b"new"),
+            msg: b"{}".to_vec(),
+        };
+
+        migrate_contract::validate(&host, msg.clone()).unwrap();
+        migrate_contract::execute(&mut host, msg.clone()).unwrap();
+
+        let calls = host.vm.calls.borrow();
+        assert_eq!(calls[0].entry_point, "migrate");
+        assert_eq!(calls[0].env.checksum, checksum(b"new"));
+
+        let store = host.store(&client_id());
+        assert_eq!(
+            store.get(ClientStatePath::leaf().as_bytes()),
+            Some(&Any::from(client_state(checksum(b"new"), 5)).encode_to_vec())
+        );
+        assert!(store.contains_key(b"migrated".as_slice()));
+
+        // The client already runs the contract of the checksum.
+        assert!(migrate_contract::validate(&host, msg).is_err());
+    }
+
+    #[test]
+    fn test_migrate_contract_unknown_checksum() {
+        let host = MockHost::with_client(client_state(checksum(b"old"), 5));
+
+        let msg = MsgMigrateContract {
+            signer: AUTHORITY.to_string().into(),
+            client_id: client_id(),
+            checksum: checksum(b"new"),
+            msg: b"{}".to_vec(),
+        };
+
+        assert!(migrate_contract::validate(&host, msg).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+//! Protocol logic for processing ICS-08 messages of type `MsgRemoveChecksum`.
+
+use ibc_client_wasm_types::msgs::remove_checksum::MsgRemoveChecksum;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+
+use super::{validate_authority, validate_stored_checksum};
+use crate::context::{WasmClientExecutionContext, WasmClientValidationContext};
+use crate::vm::WasmVm;
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgRemoveChecksum) -> Result<(), ContextError>
+where
+    Ctx: WasmClientValidationContext,
+{
+    validate_authority(ctx, &msg.signer)?;
+
+    validate_stored_checksum(ctx, &msg.checksum)
+}
+
+/// Deletes the record of the checksum and removes its byte code from the VM.
+///
+/// Clients running the contract of the checksum can no longer be updated
+/// nor verify proofs, until they are migrated to another one.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgRemoveChecksum) -> Result<(), ContextError>
+where
+    Ctx: WasmClientExecutionContext,
+{
+    ctx.delete_checksum(&msg.checksum)?;
+
+    ctx.wasm_vm()
+        .remove_code(&msg.checksum)
+        .map_err(ClientError::from)?;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Protocol logic for processing ICS-08 messages of type `MsgStoreCode`.
+
+use ibc_client_wasm_types::error::Error;
+use ibc_client_wasm_types::msgs::store_code::MsgStoreCode;
+use ibc_client_wasm_types::Bytes;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_primitives::prelude::*;
+
+use super::{checksum, validate_authority};
+use crate::checksum_hex;
+use crate::context::{WasmClientExecutionContext, WasmClientValidationContext};
+use crate::vm::WasmVm;
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgStoreCode) -> Result<(), ContextError>
+where
+    Ctx: WasmClientValidationContext,
+{
+    validate_authority(ctx, &msg.signer)?;
+
+    let size = msg.wasm_byte_code.len();
+    let max_size = ctx.max_wasm_byte_code_size();
+
+    if size == 0 || size > max_size {
+        return Err(ClientError::from(Error::InvalidWasmByteCode { size, max_size }).into());
+    }
+
+    let checksum = checksum(&msg.wasm_byte_code);
+
+    if ctx.has_checksum(&checksum)? {
+        return Err(ClientError::from(Error::ChecksumAlreadyStored {
+            checksum: checksum_hex(&checksum),
+        })
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Stores the byte code in the VM and records its checksum, which it
+/// returns.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgStoreCode) -> Result<Bytes, ContextError>
+where
+    Ctx: WasmClientExecutionContext,
+{
+    let expected_checksum = checksum(&msg.wasm_byte_code);
+
+    let checksum = ctx
+        .wasm_vm()
+        .store_code(&msg.wasm_byte_code)
+        .map_err(ClientError::from)?;
+
+    if checksum != expected_checksum {
+        return Err(ClientError::from(Error::InvalidChecksum {
+            reason: format!(
+                "the VM stored the byte code under {} instead of {}",
+                checksum_hex(&checksum),
+                checksum_hex(&expected_checksum)
+            ),
+        })
+        .into());
+    }
+
+    ctx.store_checksum(checksum.clone())?;
+
+    Ok(checksum)
+}
@@ -0,0 +1,59 @@
+//! ICS 08: Wasm proxy light client implementation for hosts, along with
+//! re-exporting the necessary types from `ibc-client-wasm-types` crate.
+//!
+//! An 08-wasm client leaves the verification of client messages and proofs
+//! to a contract, identified by the checksum of its byte code, which the host
+//! runs through the [`WasmVm`](vm::WasmVm) of its
+//! [`WasmClientValidationContext`](context::WasmClientValidationContext).
+//! Contracts read and write the store of their client directly, as they do on
+//! `ibc-go` hosts, hence the [`ClientState`](client_state::ClientState) of
+//! this crate stores nothing itself.
+//!
+//! The [`handler`] module implements the messages storing, removing and
+//! migrating the contracts of the host.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+use ibc_primitives::prelude::*;
+use subtle_encoding::{Encoding, Hex};
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod context;
+pub mod contract;
+pub mod handler;
+pub mod store;
+pub mod vm;
+
+#[cfg(feature = "cosmwasm-vm")]
+pub mod cosmwasm;
+
+/// The maximum size of the byte code of a contract, as enforced by `ibc-go`.
+pub const MAX_WASM_BYTE_CODE_SIZE: usize = 3 * 1024 * 1024;
+
+/// Returns the hex encoding of a checksum, as displayed in errors.
+pub(crate) fn checksum_hex(checksum: &[u8]) -> String {
+    Hex::lower_case()
+        .encode_to_string(checksum)
+        .unwrap_or_default()
+}
+
+/// Re-exports ICS-08 Wasm light client data structures from the
+/// `ibc-client-wasm-types` crate.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_wasm_types::*;
+}
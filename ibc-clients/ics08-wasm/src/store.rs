@@ -0,0 +1,283 @@
+//! Defines the store of an 08-wasm client, as its contract sees it.
+//!
+//! Contracts only read the store while they run: the writes they make are
+//! returned by the [`WasmVm`](crate::vm::WasmVm) and applied to the store
+//! of the client once the call succeeded, so that a failed call leaves the
+//! store of the host untouched.
+
+use core::ops::Bound;
+
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::error::Error;
+use ibc_client_wasm_types::{SUBJECT_PREFIX, SUBSTITUTE_PREFIX};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::metering::CostEvent;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use prost::Message;
+
+use crate::context::{WasmClientExecutionContext, WasmClientValidationContext};
+
+/// The order in which the entries of a range are returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Order {
+    Ascending,
+    Descending,
+}
+
+/// A write made by a contract to the store of its client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StoreWrite {
+    Set { key: Vec<u8>, value: Vec<u8> },
+    Remove { key: Vec<u8> },
+}
+
+/// The key-value entries of a store, as returned by its ranges.
+pub type StoreEntries = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// Read access to the store of a client, handed to its contract.
+pub trait WasmStore {
+    /// Returns the value stored at the given key, if any.
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, ContextError>;
+
+    /// Returns the entries whose keys are within `start` (inclusive) and
+    /// `end` (exclusive), in the given order.
+    fn range(
+        &self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Result<StoreEntries, ContextError>;
+
+    /// Returns all the entries of the store, in ascending order.
+    fn entries(&self) -> Result<StoreEntries, ContextError> {
+        self.range(None, None, Order::Ascending)
+    }
+}
+
+/// The store of a client of the host, as exposed by its
+/// [`WasmClientValidationContext`].
+///
+/// The host is charged for the values the contract reads, as returned by the
+/// store.
+pub struct ClientStore<'a, V> {
+    ctx: &'a V,
+    client_id: &'a ClientId,
+}
+
+impl<'a, V: WasmClientValidationContext> ClientStore<'a, V> {
+    pub fn new(ctx: &'a V, client_id: &'a ClientId) -> Self {
+        Self { ctx, client_id }
+    }
+}
+
+impl<'a, V: WasmClientValidationContext> WasmStore for ClientStore<'a, V> {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, ContextError> {
+        let value = self.ctx.client_store_get(self.client_id, key)?;
+        self.ctx
+            .charge(CostEvent::store_read(value.as_deref().unwrap_or_default()))?;
+
+        Ok(value)
+    }
+
+    fn range(
+        &self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Result<StoreEntries, ContextError> {
+        let entries = self
+            .ctx
+            .client_store_range(self.client_id, start, end, order)?;
+
+        for (_, value) in &entries {
+            self.ctx.charge(CostEvent::store_read(value))?;
+        }
+
+        Ok(entries)
+    }
+}
+
+/// The store a contract migrates the state of a substitute client to its
+/// subject client with, during a client recovery.
+///
+/// Keys prefixed with [`SUBJECT_PREFIX`] map to the store of the subject
+/// client, as do unprefixed ones, while keys prefixed with
+/// [`SUBSTITUTE_PREFIX`] map to a read-only view of the substitute client
+/// holding its client state and its latest consensus state.
+pub struct MigrationStore<S> {
+    subject: S,
+    substitute: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl<S: WasmStore> MigrationStore<S> {
+    pub fn new(
+        subject: S,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<Self, ClientError> {
+        let latest_height =
+            ClientStateType::try_from(substitute_client_state.clone())?.latest_height;
+
+        let consensus_state_key = ClientConsensusStatePath::new(
+            subject_client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        )
+        .leaf();
+
+        let substitute = BTreeMap::from([
+            (
+                ClientStatePath::leaf().into_bytes(),
+                substitute_client_state.encode_to_vec(),
+            ),
+            (
+                consensus_state_key.into_bytes(),
+                substitute_consensus_state.encode_to_vec(),
+            ),
+        ]);
+
+        Ok(Self {
+            subject,
+            substitute,
+        })
+    }
+}
+
+impl<S: WasmStore> WasmStore for MigrationStore<S> {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, ContextError> {
+        if let Some(key) = key.strip_prefix(SUBSTITUTE_PREFIX) {
+            return Ok(self.substitute.get(key).cloned());
+        }
+
+        self.subject
+            .get(key.strip_prefix(SUBJECT_PREFIX).unwrap_or(key))
+    }
+
+    /// Ranges over the store the prefix of `start`, or else of `end`, maps
+    /// to, returning the keys with that prefix.
+    fn range(
+        &self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Result<StoreEntries, ContextError> {
+        let bound = start.or(end).unwrap_or_default();
+
+        let prefix = [SUBSTITUTE_PREFIX, SUBJECT_PREFIX]
+            .into_iter()
+            .find(|prefix| bound.starts_with(prefix))
+            .unwrap_or_default();
+
+        let strip = |key: Option<&'_ [u8]>| -> Option<Vec<u8>> {
+            key.map(|key| key.strip_prefix(prefix).unwrap_or_default().to_vec())
+        };
+        let (start, end) = (strip(start), strip(end));
+
+        let entries = if prefix == SUBSTITUTE_PREFIX {
+            range(&self.substitute, start.as_deref(), end.as_deref(), order)
+        } else {
+            self.subject
+                .range(start.as_deref(), end.as_deref(), order)?
+        };
+
+        Ok(entries
+            .into_iter()
+            .map(|(key, value)| ([prefix, key.as_slice()].concat(), value))
+            .collect())
+    }
+
+    /// Returns the entries of both clients, under their prefix.
+    fn entries(&self) -> Result<StoreEntries, ContextError> {
+        let mut entries = self.range(Some(SUBJECT_PREFIX), None, Order::Ascending)?;
+        entries.extend(self.range(Some(SUBSTITUTE_PREFIX), None, Order::Ascending)?);
+        entries.sort();
+
+        Ok(entries)
+    }
+}
+
+/// Maps a write of the contract to the store of the subject client,
+/// rejecting the writes to the substitute client.
+pub fn subject_write(write: StoreWrite) -> Result<StoreWrite, ClientError> {
+    let strip = |key: Vec<u8>| -> Result<Vec<u8>, ClientError> {
+        if key.starts_with(SUBSTITUTE_PREFIX) {
+            return Err(Error::UnexpectedContractWrite {
+                call: "writing to the substitute client".to_string(),
+            }
+            .into());
+        }
+
+        Ok(key
+            .strip_prefix(SUBJECT_PREFIX)
+            .map(<[u8]>::to_vec)
+            .unwrap_or(key))
+    };
+
+    Ok(match write {
+        StoreWrite::Set { key, value } => StoreWrite::Set {
+            key: strip(key)?,
+            value,
+        },
+        StoreWrite::Remove { key } => StoreWrite::Remove { key: strip(key)? },
+    })
+}
+
+/// Returns the entries of the map whose keys are within `start` (inclusive)
+/// and `end` (exclusive), in the given order.
+pub fn range(
+    map: &BTreeMap<Vec<u8>, Vec<u8>>,
+    start: Option<&[u8]>,
+    end: Option<&[u8]>,
+    order: Order,
+) -> StoreEntries {
+    let bounds = (
+        start.map_or(Bound::Unbounded, Bound::Included),
+        end.map_or(Bound::Unbounded, Bound::Excluded),
+    );
+
+    if let (Bound::Included(start), Bound::Excluded(end)) = bounds {
+        if start >= end {
+            return Vec::new();
+        }
+    }
+
+    let entries = map
+        .range::<[u8], _>(bounds)
+        .map(|(key, value)| (key.clone(), value.clone()));
+
+    match order {
+        Order::Ascending => entries.collect(),
+        Order::Descending => entries.rev().collect(),
+    }
+}
+
+/// Applies the writes a contract made to the store of the given client,
+/// charging the host for each.
+pub fn apply_writes<E>(
+    ctx: &mut E,
+    client_id: &ClientId,
+    writes: Vec<StoreWrite>,
+) -> Result<(), ContextError>
+where
+    E: WasmClientExecutionContext,
+{
+    for write in writes {
+        match write {
+            StoreWrite::Set { key, value } => {
+                ctx.charge(CostEvent::store_write(&value))?;
+                ctx.client_store_set(client_id, key, value)?;
+            }
+            StoreWrite::Remove { key } => {
+                ctx.charge(CostEvent::store_write(&[]))?;
+                ctx.client_store_remove(client_id, &key)?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! Defines the interface of the virtual machine running the contracts of
+//! 08-wasm clients, which hosts implement over the VM of their choice.
+
+use ibc_client_wasm_types::error::Error;
+use ibc_client_wasm_types::Bytes;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+use crate::store::{StoreWrite, WasmStore};
+
+/// The environment a contract runs in, i.e. the client it runs for and the
+/// current state of the host.
+///
+/// Contracts built with `ibc-client-cw` expect to find the identifier of
+/// their client in the address of the contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractEnv {
+    pub client_id: ClientId,
+    pub checksum: Bytes,
+    pub host_height: Height,
+    pub host_timestamp: Timestamp,
+}
+
+/// The outcome of a contract call allowed to write to the store of its
+/// client.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContractOutput {
+    /// The data the contract responded with.
+    pub data: Vec<u8>,
+    /// The writes the contract made to the store, in order.
+    pub writes: Vec<StoreWrite>,
+}
+
+/// Stores and runs the contracts of 08-wasm clients.
+///
+/// Messages and responses are the JSON documents defined in the
+/// [`contract`](crate::contract) module. Calls fail with [`Error::Vm`] when
+/// the VM itself fails, e.g. running out of gas, and with
+/// [`Error::Contract`] when the contract returns an error.
+pub trait WasmVm {
+    /// Compiles and stores the given byte code, returning its checksum.
+    fn store_code(&self, byte_code: &[u8]) -> Result<Bytes, Error>;
+
+    /// Removes the byte code of the given checksum.
+    fn remove_code(&self, checksum: &[u8]) -> Result<(), Error>;
+
+    /// Calls the `instantiate` entry point of the contract.
+    fn instantiate(
+        &self,
+        env: &ContractEnv,
+        store: &dyn WasmStore,
+        msg: &[u8],
+    ) -> Result<ContractOutput, Error>;
+
+    /// Calls the `sudo` entry point of the contract.
+    fn sudo(
+        &self,
+        env: &ContractEnv,
+        store: &dyn WasmStore,
+        msg: &[u8],
+    ) -> Result<ContractOutput, Error>;
+
+    /// Calls the `migrate` entry point of the contract.
+    fn migrate(
+        &self,
+        env: &ContractEnv,
+        store: &dyn WasmStore,
+        msg: &[u8],
+    ) -> Result<ContractOutput, Error>;
+
+    /// Calls the `query` entry point of the contract, returning its
+    /// response.
+    fn query(&self, env: &ContractEnv, store: &dyn WasmStore, msg: &[u8])
+        -> Result<Vec<u8>, Error>;
+}
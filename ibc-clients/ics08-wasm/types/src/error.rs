@@ -1,7 +1,9 @@
 //! Defines the error type for the ICS-08 Wasm light client.
 
 use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
 use ibc_core_host_types::error::IdentifierError;
+use ibc_core_host_types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
 
 /// The main error type
@@ -13,6 +15,35 @@ pub enum Error {
     DecodeError { reason: String },
     /// invalid client state latest height: `{reason}`
     InvalidLatestHeight { reason: String },
+    /// invalid checksum: `{reason}`
+    InvalidChecksum { reason: String },
+    /// unknown checksum `{checksum}`
+    UnknownChecksum { checksum: String },
+    /// checksum `{checksum}` is already stored
+    ChecksumAlreadyStored { checksum: String },
+    /// client `{client_id}` already runs the contract of checksum `{checksum}`
+    ChecksumUnchanged {
+        client_id: ClientId,
+        checksum: String,
+    },
+    /// invalid wasm byte code of `{size}` bytes, expected at most `{max_size}` bytes
+    InvalidWasmByteCode { size: usize, max_size: usize },
+    /// unauthorized signer `{signer}`, expected `{authority}`
+    UnauthorizedSigner { signer: String, authority: String },
+    /// client `{client_id}` is not an 08-wasm client
+    NotWasmClient { client_id: ClientId },
+    /// VM error: `{reason}`
+    Vm { reason: String },
+    /// contract error: `{reason}`
+    Contract { reason: String },
+    /// invalid contract response: `{reason}`
+    InvalidContractResponse { reason: String },
+    /// contracts cannot write to the store while `{call}`
+    UnexpectedContractWrite { call: String },
+    /// contracts cannot send messages, yet `{count}` were sent
+    UnexpectedContractMessages { count: usize },
+    /// unsupported by 08-wasm clients: `{reason}`
+    Unsupported { reason: String },
 }
 
 #[cfg(feature = "std")]
@@ -25,6 +56,14 @@ impl std::error::Error for Error {
     }
 }
 
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
 impl From<IdentifierError> for Error {
     fn from(e: IdentifierError) -> Self {
         Self::InvalidIdentifier(e)
@@ -11,12 +11,24 @@
     rust_2018_idioms
 )]
 
+/// Re-exports implementations of ICS-06 Solo Machine light client.
+pub mod solomachine {
+    #[doc(inline)]
+    pub use ibc_client_solomachine::*;
+}
+
 /// Re-exports implementations of ICS-07 Tendermint light client.
 pub mod tendermint {
     #[doc(inline)]
     pub use ibc_client_tendermint::*;
 }
 
+/// Re-exports implementations of the Ethereum sync committee light client.
+pub mod ethereum {
+    #[doc(inline)]
+    pub use ibc_client_ethereum::*;
+}
+
 /// Re-exports implementations of ICS-08 Wasm light client types.
 pub mod wasm_types {
     #[doc(inline)]
@@ -6,10 +6,12 @@ use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
-use ibc_core_host_types::path::Path;
+use ibc_core_host_types::path::{ClientConsensusStatePath, Path};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
 
+use crate::consensus_state::ConsensusState;
 use crate::context::{ClientExecutionContext, ClientValidationContext};
 use crate::Convertible;
 
@@ -135,6 +137,83 @@ where
     ///
     /// Returns `Ok` if the subject and substitute client states match, `Err` otherwise.
     fn check_substitute(&self, ctx: &V, substitute_client_state: Any) -> Result<(), ClientError>;
+
+    /// Verifies the proofs of the upgraded client and consensus states,
+    /// against the consensus state stored at the given path.
+    ///
+    /// Defaults to [`ClientStateCommon::verify_upgrade_client`] against the
+    /// root of the consensus state.
+    fn verify_upgrade_client_at_height(
+        &self,
+        ctx: &V,
+        client_cons_state_path: &ClientConsensusStatePath,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+        proof_upgrade_client: CommitmentProofBytes,
+        proof_upgrade_consensus_state: CommitmentProofBytes,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ctx.consensus_state(client_cons_state_path)?;
+
+        self.verify_upgrade_client(
+            upgraded_client_state,
+            upgraded_consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+            consensus_state.root(),
+        )
+    }
+
+    /// Verifies a proof of the existence of a value at a given path, against
+    /// the consensus state stored at the given path.
+    ///
+    /// Defaults to [`ClientStateCommon::verify_membership`] against the root
+    /// of the consensus state. Clients that need the context to verify
+    /// proofs, such as 08-wasm clients handing them to their contract,
+    /// override it instead.
+    fn verify_membership_at_height(
+        &self,
+        ctx: &V,
+        client_cons_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ctx.consensus_state(client_cons_state_path)?;
+
+        self.verify_membership(prefix, proof, consensus_state.root(), path, value)
+    }
+
+    /// Verifies a proof of the absence of a value at a given path, against
+    /// the consensus state stored at the given path.
+    ///
+    /// Defaults to [`ClientStateCommon::verify_non_membership`] against the
+    /// root of the consensus state.
+    fn verify_non_membership_at_height(
+        &self,
+        ctx: &V,
+        client_cons_state_path: &ClientConsensusStatePath,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ctx.consensus_state(client_cons_state_path)?;
+
+        self.verify_non_membership(prefix, proof, consensus_state.root(), path)
+    }
+
+    /// Returns the timestamp of the consensus state stored at the given path.
+    ///
+    /// Defaults to the timestamp of the consensus state itself.
+    fn timestamp_at_height(
+        &self,
+        ctx: &V,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Timestamp, ClientError> {
+        let consensus_state = ctx.consensus_state(client_cons_state_path)?;
+
+        Ok(consensus_state.timestamp())
+    }
 }
 
 /// `ClientState` methods which require access to the client's
@@ -201,6 +280,22 @@ where
         substitute_client_state: Any,
         substitute_consensus_state: Any,
     ) -> Result<(), ClientError>;
+
+    /// Prunes the expired consensus states of the client, oldest first, and
+    /// returns how many were pruned, which is at most `budget`.
+    ///
+    /// Hosts may call it outside of client updates, e.g. from a maintenance
+    /// or governance entrypoint, to prune the expired consensus states of a
+    /// client in bulk. Clients whose consensus states do not expire prune
+    /// nothing, which is the default.
+    fn prune_expired_consensus_states(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _budget: usize,
+    ) -> Result<usize, ClientError> {
+        Ok(0)
+    }
 }
 
 /// Primary client trait. Defines all the methods that clients must implement.
@@ -1,5 +1,6 @@
 use ibc_core_client_types::Height;
 use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::metering::CostEvent;
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_core_host_types::path::{ClientConsensusStatePath, ClientStatePath};
 use ibc_primitives::prelude::*;
@@ -39,6 +40,29 @@ pub trait ClientValidationContext: Sized {
         client_id: &ClientId,
         height: &Height,
     ) -> Result<(Timestamp, Height), ContextError>;
+
+    /// Charges the host for the resources consumed while handling a message,
+    /// such as the proofs and signatures verified, or the consensus states
+    /// pruned.
+    ///
+    /// This is the single metering hook of the host: the core handlers reach
+    /// it through `ValidationContext::charge`, which delegates to it.
+    ///
+    /// The core handlers never charge the reads and writes of the store, as
+    /// they only see the decoded values the getters and setters of the
+    /// contexts exchange, not the bytes the store holds. These are the job
+    /// of the store layer of the host, which implements the getters and
+    /// setters over the raw values, e.g. by calling this hook from there
+    /// with [`CostEvent::store_read`] and [`CostEvent::store_write`]. Hosts
+    /// whose store is already metered charge nothing more.
+    ///
+    /// Hosts that meter IBC may price the event, e.g. with a
+    /// [`CostTable`](ibc_core_handler_types::metering::CostTable), and return
+    /// an error once the budget of the message is exhausted, which aborts its
+    /// handling. Does nothing by default.
+    fn charge(&self, _event: CostEvent) -> Result<(), ContextError> {
+        Ok(())
+    }
 }
 
 /// Defines the methods that all client `ExecutionContext`s (precisely the
@@ -123,6 +147,24 @@ pub trait ExtClientValidationContext: ClientValidationContext {
     /// Returns all the heights at which a consensus state is stored.
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
 
+    /// Returns an iterator over the heights at which a consensus state is
+    /// stored, in ascending order.
+    ///
+    /// Defaults to sorting the heights returned by
+    /// [`consensus_state_heights`](Self::consensus_state_heights). Hosts
+    /// storing consensus states in height order should override it to read
+    /// their store lazily, so that pruning only reads the heights it prunes.
+    fn ascending_consensus_state_heights<'a>(
+        &'a self,
+        client_id: &ClientId,
+    ) -> Result<Box<dyn Iterator<Item = Height> + 'a>, ContextError> {
+        let mut heights = self.consensus_state_heights(client_id)?;
+
+        heights.sort();
+
+        Ok(Box::new(heights.into_iter()))
+    }
+
     /// Search for the lowest consensus state higher than `height`.
     fn next_consensus_state(
         &self,
@@ -136,6 +178,40 @@ pub trait ExtClientValidationContext: ClientValidationContext {
         client_id: &ClientId,
         height: &Height,
     ) -> Result<Option<Self::ConsensusStateRef>, ContextError>;
+
+    /// Returns whether the header of the given hash was already verified
+    /// against the consensus state of the client at the given trusted height.
+    ///
+    /// Clients skip the verification of the headers found in this cache,
+    /// which makes the duplicate updates submitted by competing relayers
+    /// cheap. As the verification of a header depends on the host timestamp,
+    /// hosts are expected to only keep the cache for the duration of a block.
+    ///
+    /// Returns `false` by default, i.e. the cache is disabled.
+    fn is_header_verified(
+        &self,
+        _client_id: &ClientId,
+        _header_hash: &[u8],
+        _trusted_height: &Height,
+    ) -> Result<bool, ContextError> {
+        Ok(false)
+    }
+
+    /// Records that the header of the given hash was verified against the
+    /// consensus state of the client at the given trusted height.
+    ///
+    /// Clients record their headers upon executing an update, so that the
+    /// cache never holds the headers of an update that was only validated.
+    /// The hash is derived by the client, which may cover the parameters of
+    /// the client along with the header. Does nothing by default.
+    fn cache_verified_header(
+        &self,
+        _client_id: &ClientId,
+        _header_hash: &[u8],
+        _trusted_height: &Height,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
 }
 
 /// An optional trait that extends the client context required during execution.
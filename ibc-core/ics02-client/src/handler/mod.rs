@@ -1,6 +1,8 @@
 //! This module implements the processing logic for ICS2 (client abstractions and functions) msgs.
 
 pub mod create_client;
+pub mod prune_consensus_states;
 pub mod recover_client;
+pub mod register_counterparty;
 pub mod update_client;
 pub mod upgrade_client;
@@ -0,0 +1,45 @@
+//! Protocol logic for pruning the expired consensus states of a client in
+//! bulk, outside of client updates.
+//!
+//! Client updates only prune a few expired consensus states each. Hosts
+//! prune the rest, e.g. those of clients no longer updated, through the
+//! `prune_consensus_states` entrypoint of `ibc-core-handler`.
+
+use ibc_core_client_context::prelude::*;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::{ExecutionContext, ValidationContext};
+
+/// Checks that the client exists.
+pub fn validate<Ctx>(ctx: &Ctx, client_id: &ClientId) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.get_client_validation_context()
+        .client_state(client_id)?;
+
+    Ok(())
+}
+
+/// Prunes the expired consensus states of the client, oldest first, and
+/// returns how many were pruned, which is at most `budget`.
+///
+/// Pruning fewer consensus states than the budget means that the client has
+/// no expired consensus state left, otherwise hosts may call it again, e.g.
+/// in the next block.
+pub fn execute<Ctx>(
+    ctx: &mut Ctx,
+    client_id: &ClientId,
+    budget: usize,
+) -> Result<usize, ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let client_exec_ctx = ctx.get_client_execution_context();
+
+    let client_state = client_exec_ctx.client_state(client_id)?;
+
+    let pruned = client_state.prune_expired_consensus_states(client_exec_ctx, client_id, budget)?;
+
+    Ok(pruned)
+}
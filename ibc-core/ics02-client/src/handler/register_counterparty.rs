@@ -0,0 +1,69 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgRegisterCounterparty`.
+
+use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::msgs::MsgRegisterCounterparty;
+use ibc_core_client_types::CounterpartyInfo;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::Sequence;
+use ibc_core_host::types::path::{ClientCounterpartyPath, SeqSendV2Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+/// Validates that the client exists and is active, and that no counterparty
+/// was registered for it yet: the counterparty of a client cannot be changed.
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgRegisterCounterparty) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.validate_message_signer(&msg.signer)?;
+
+    let client_val_ctx = ctx.get_client_validation_context();
+    let client_state = client_val_ctx.client_state(&msg.client_id)?;
+
+    client_state
+        .status(client_val_ctx, &msg.client_id)?
+        .verify_is_active()?;
+
+    let counterparty_path = ClientCounterpartyPath::new(msg.client_id.clone());
+    if ctx.client_counterparty(&counterparty_path).is_ok() {
+        return Err(ClientError::CounterpartyAlreadyRegistered {
+            client_id: msg.client_id,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Registers the counterparty of the client, and initializes the sequence of
+/// the IBC v2 packets sent through it.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgRegisterCounterparty) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let MsgRegisterCounterparty {
+        client_id,
+        counterparty_client_id,
+        counterparty_commitment_prefix,
+        ..
+    } = msg;
+
+    ctx.store_client_counterparty(
+        &ClientCounterpartyPath::new(client_id.clone()),
+        CounterpartyInfo::new(
+            counterparty_client_id.clone(),
+            counterparty_commitment_prefix,
+        ),
+    )?;
+    ctx.store_next_sequence_send_v2(&SeqSendV2Path::new(&client_id), Sequence::from(1))?;
+
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+
+    ctx.log_message(format!(
+        "success: registered client {counterparty_client_id} as the counterparty of client {client_id}"
+    ))?;
+
+    Ok(())
+}
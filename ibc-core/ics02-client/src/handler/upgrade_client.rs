@@ -1,11 +1,11 @@
 //! Protocol logic specific to processing ICS2 messages of type `MsgUpgradeAnyClient`.
 //!
 use ibc_core_client_context::prelude::*;
-use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::events::UpgradeClient;
 use ibc_core_client_types::msgs::MsgUpgradeClient;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::metering::CostEvent;
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
@@ -30,26 +30,26 @@ where
         .status(client_val_ctx, &client_id)?
         .verify_is_active()?;
 
-    // Read the latest consensus state from the host chain store.
+    // The proofs are verified against the latest consensus state.
     let old_client_cons_state_path = ClientConsensusStatePath::new(
-        client_id.clone(),
+        client_id,
         old_client_state.latest_height().revision_number(),
         old_client_state.latest_height().revision_height(),
     );
-    let old_consensus_state = client_val_ctx
-        .consensus_state(&old_client_cons_state_path)
-        .map_err(|_| ClientError::ConsensusStateNotFound {
-            client_id,
-            height: old_client_state.latest_height(),
-        })?;
+
+    ctx.charge(CostEvent::proof_verified(&msg.proof_upgrade_client))?;
+    ctx.charge(CostEvent::proof_verified(
+        &msg.proof_upgrade_consensus_state,
+    ))?;
 
     // Validate the upgraded client state and consensus state and verify proofs against the root
-    old_client_state.verify_upgrade_client(
+    old_client_state.verify_upgrade_client_at_height(
+        client_val_ctx,
+        &old_client_cons_state_path,
         msg.upgraded_client_state.clone(),
         msg.upgraded_consensus_state,
         msg.proof_upgrade_client,
         msg.proof_upgrade_consensus_state,
-        old_consensus_state.root(),
     )?;
 
     Ok(())
@@ -0,0 +1,123 @@
+//! Defines the counterparty of a client, as registered for IBC v2.
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+use crate::raw::CounterpartyInfo as RawCounterpartyInfo;
+
+/// The counterparty client of a client, with the prefix under which the
+/// counterparty chain commits to the IBC v2 packets.
+///
+/// IBC v2 packets are sent between two clients which are each other's
+/// counterparty, rather than over a channel.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CounterpartyInfo {
+    pub client_id: ClientId,
+    pub commitment_prefix: CommitmentPrefix,
+}
+
+impl CounterpartyInfo {
+    pub fn new(client_id: ClientId, commitment_prefix: CommitmentPrefix) -> Self {
+        Self {
+            client_id,
+            commitment_prefix,
+        }
+    }
+}
+
+impl Protobuf<RawCounterpartyInfo> for CounterpartyInfo {}
+
+impl TryFrom<RawCounterpartyInfo> for CounterpartyInfo {
+    type Error = ClientError;
+
+    fn try_from(raw: RawCounterpartyInfo) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: raw
+                .client_id
+                .parse()
+                .map_err(ClientError::InvalidClientIdentifier)?,
+            commitment_prefix: commitment_prefix_from_merkle_prefix(raw.merkle_prefix)?,
+        })
+    }
+}
+
+impl From<CounterpartyInfo> for RawCounterpartyInfo {
+    fn from(value: CounterpartyInfo) -> Self {
+        Self {
+            merkle_prefix: merkle_prefix_from_commitment_prefix(value.commitment_prefix),
+            client_id: value.client_id.to_string(),
+        }
+    }
+}
+
+/// Converts the merkle prefix of `ibc-go` into a commitment prefix.
+///
+/// The merkle prefix is the path of the store under which the IBC keys are
+/// committed, whose last element is prepended to the keys. Only the prefixes
+/// made of a single store, such as `["ibc", ""]`, are supported.
+pub(crate) fn commitment_prefix_from_merkle_prefix(
+    merkle_prefix: Vec<Vec<u8>>,
+) -> Result<CommitmentPrefix, ClientError> {
+    let mut merkle_prefix = merkle_prefix.into_iter();
+
+    let store_prefix = merkle_prefix.next().ok_or(ClientError::EmptyPrefix)?;
+
+    match merkle_prefix.next() {
+        None => {}
+        Some(key_prefix) if key_prefix.is_empty() && merkle_prefix.next().is_none() => {}
+        Some(_) => {
+            return Err(ClientError::InvalidMerklePrefix {
+                reason: "only single-store merkle prefixes are supported".to_string(),
+            })
+        }
+    }
+
+    CommitmentPrefix::try_from(store_prefix).map_err(|_| ClientError::EmptyPrefix)
+}
+
+/// Converts a commitment prefix into the merkle prefix of `ibc-go`.
+pub(crate) fn merkle_prefix_from_commitment_prefix(
+    commitment_prefix: CommitmentPrefix,
+) -> Vec<Vec<u8>> {
+    vec![commitment_prefix.into_vec(), Vec::new()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merkle_prefix_conversion() {
+        let prefix = commitment_prefix_from_merkle_prefix(vec![b"ibc".to_vec()]).unwrap();
+        assert_eq!(prefix.as_bytes(), b"ibc");
+
+        let prefix =
+            commitment_prefix_from_merkle_prefix(vec![b"ibc".to_vec(), Vec::new()]).unwrap();
+        assert_eq!(
+            merkle_prefix_from_commitment_prefix(prefix),
+            vec![b"ibc".to_vec(), Vec::new()]
+        );
+
+        assert!(commitment_prefix_from_merkle_prefix(vec![]).is_err());
+        assert!(commitment_prefix_from_merkle_prefix(vec![Vec::new()]).is_err());
+        assert!(
+            commitment_prefix_from_merkle_prefix(vec![b"ibc".to_vec(), b"key".to_vec()]).is_err()
+        );
+    }
+}
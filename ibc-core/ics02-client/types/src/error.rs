@@ -6,6 +6,7 @@ use displaydoc::Display;
 use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
+use ibc_core_host_types::path::Path;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Timestamp;
 
@@ -48,6 +49,12 @@ pub enum ClientError {
     UnknownClientStateType { client_state_type: String },
     /// empty prefix
     EmptyPrefix,
+    /// invalid merkle prefix: `{reason}`
+    InvalidMerklePrefix { reason: String },
+    /// counterparty of client `{client_id}` not found
+    CounterpartyNotFound { client_id: ClientId },
+    /// counterparty of client `{client_id}` is already registered
+    CounterpartyAlreadyRegistered { client_id: ClientId },
     /// unknown client consensus state type: `{consensus_state_type}`
     UnknownConsensusStateType { consensus_state_type: String },
     /// unknown header type: `{header_type}`
@@ -95,6 +102,10 @@ pub enum ClientError {
     InvalidSigner { reason: String },
     /// ics23 verification failure error: `{0}`
     Ics23Verification(CommitmentError),
+    /// localhost verification failure: the host store does not hold the expected value at `{path}`
+    LocalhostMembershipFailure { path: Path },
+    /// localhost verification failure: the host store holds a value at `{path}`
+    LocalhostNonMembershipFailure { path: Path },
     /// misbehaviour handling failed with reason: `{reason}`
     MisbehaviourHandlingFailure { reason: String },
     /// client specific error: `{description}`
@@ -103,6 +114,12 @@ pub enum ClientError {
     CounterOverflow,
     /// update client message did not contain valid header or misbehaviour
     InvalidUpdateClientMessage,
+    /// mismatched event type: expected `{expected}`, actual `{actual}`
+    MismatchedEventType { expected: String, actual: String },
+    /// missing event attribute `{key}`
+    MissingEventAttribute { key: String },
+    /// invalid event attribute `{key}`: `{reason}`
+    InvalidEventAttribute { key: String, reason: String },
     /// other error: `{description}`
     Other { description: String },
 }
@@ -1,10 +1,14 @@
 //! Types for the IBC events emitted from Tendermint Websocket by the client module.
+use core::fmt::Display;
+use core::str::FromStr;
+
 use derive_more::From;
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_primitives::prelude::*;
 use subtle_encoding::hex;
 use tendermint::abci;
 
+use crate::error::ClientError;
 use crate::height::Height;
 
 /// Client event types
@@ -52,6 +56,14 @@ impl From<ClientIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for ClientIdAttribute {
+    type Error = ClientError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<ClientId>(event, CLIENT_ID_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -76,6 +88,14 @@ impl From<ClientTypeAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for ClientTypeAttribute {
+    type Error = ClientError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<ClientType>(event, CLIENT_TYPE_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -100,6 +120,14 @@ impl From<ConsensusHeightAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for ConsensusHeightAttribute {
+    type Error = ClientError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<Height>(event, CONSENSUS_HEIGHT_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -129,6 +157,25 @@ impl From<ConsensusHeightsAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for ConsensusHeightsAttribute {
+    type Error = ClientError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        let consensus_heights = event_attribute(event, CONSENSUS_HEIGHTS_ATTRIBUTE_KEY)?
+            .split(',')
+            .filter(|consensus_height| !consensus_height.is_empty())
+            .map(|consensus_height| {
+                Height::from_str(consensus_height).map_err(|e| ClientError::InvalidEventAttribute {
+                    key: CONSENSUS_HEIGHTS_ATTRIBUTE_KEY.to_string(),
+                    reason: e.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::from(consensus_heights))
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -160,6 +207,21 @@ impl From<HeaderAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for HeaderAttribute {
+    type Error = ClientError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        let header = hex::decode(event_attribute(event, HEADER_ATTRIBUTE_KEY)?).map_err(|e| {
+            ClientError::InvalidEventAttribute {
+                key: HEADER_ATTRIBUTE_KEY.to_string(),
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self::from(header))
+    }
+}
+
 /// CreateClient event signals the creation of a new on-chain client (IBC client).
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -220,6 +282,20 @@ impl From<CreateClient> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for CreateClient {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CREATE_CLIENT_EVENT)?;
+
+        Ok(Self {
+            client_id: (&event).try_into()?,
+            client_type: (&event).try_into()?,
+            consensus_height: (&event).try_into()?,
+        })
+    }
+}
+
 /// UpdateClient event signals a recent update of an on-chain client (IBC Client).
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -306,6 +382,22 @@ impl From<UpdateClient> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for UpdateClient {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, UPDATE_CLIENT_EVENT)?;
+
+        Ok(Self {
+            client_id: (&event).try_into()?,
+            client_type: (&event).try_into()?,
+            consensus_height: (&event).try_into()?,
+            consensus_heights: (&event).try_into()?,
+            header: (&event).try_into()?,
+        })
+    }
+}
+
 /// ClientMisbehaviour event signals the update of an on-chain client (IBC Client) with evidence of
 /// misbehaviour.
 #[cfg_attr(
@@ -357,6 +449,19 @@ impl From<ClientMisbehaviour> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for ClientMisbehaviour {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CLIENT_MISBEHAVIOUR_EVENT)?;
+
+        Ok(Self {
+            client_id: (&event).try_into()?,
+            client_type: (&event).try_into()?,
+        })
+    }
+}
+
 /// Signals a recent upgrade of an on-chain client (IBC Client).
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -416,3 +521,59 @@ impl From<UpgradeClient> for abci::Event {
         }
     }
 }
+
+impl TryFrom<abci::Event> for UpgradeClient {
+    type Error = ClientError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, UPGRADE_CLIENT_EVENT)?;
+
+        Ok(Self {
+            client_id: (&event).try_into()?,
+            client_type: (&event).try_into()?,
+            consensus_height: (&event).try_into()?,
+        })
+    }
+}
+
+/// Checks that an ABCI event is of the expected type.
+fn verify_event_type(event: &abci::Event, expected: &str) -> Result<(), ClientError> {
+    if event.kind != expected {
+        return Err(ClientError::MismatchedEventType {
+            expected: expected.to_string(),
+            actual: event.kind.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the value of the attribute of an ABCI event with the given key.
+fn event_attribute<'a>(event: &'a abci::Event, key: &str) -> Result<&'a str, ClientError> {
+    let attribute = event
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key_str().is_ok_and(|k| k == key))
+        .ok_or_else(|| ClientError::MissingEventAttribute {
+            key: key.to_string(),
+        })?;
+
+    attribute
+        .value_str()
+        .map_err(|e| ClientError::InvalidEventAttribute {
+            key: key.to_string(),
+            reason: e.to_string(),
+        })
+}
+
+/// Parses the value of the attribute of an ABCI event with the given key.
+fn parse_event_attribute<T>(event: &abci::Event, key: &str) -> Result<T, ClientError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    T::from_str(event_attribute(event, key)?).map_err(|e| ClientError::InvalidEventAttribute {
+        key: key.to_string(),
+        reason: e.to_string(),
+    })
+}
@@ -14,12 +14,15 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+mod counterparty;
 pub mod error;
 pub mod events;
 mod height;
 pub mod msgs;
+pub mod raw;
 mod status;
 
+pub use counterparty::*;
 pub use height::*;
 pub use status::*;
 
@@ -10,12 +10,14 @@ use ibc_proto::google::protobuf::Any;
 mod create_client;
 mod misbehaviour;
 mod recover_client;
+mod register_counterparty;
 mod update_client;
 mod upgrade_client;
 
 pub use create_client::*;
 pub use misbehaviour::*;
 pub use recover_client::*;
+pub use register_counterparty::*;
 pub use update_client::*;
 pub use upgrade_client::*;
 
@@ -33,6 +35,7 @@ pub enum ClientMsg {
     Misbehaviour(MsgSubmitMisbehaviour),
     UpgradeClient(MsgUpgradeClient),
     RecoverClient(MsgRecoverClient),
+    RegisterCounterparty(MsgRegisterCounterparty),
 }
 
 pub enum MsgUpdateOrMisbehaviour {
@@ -0,0 +1,70 @@
+//! Definition of domain type message `MsgRegisterCounterparty`.
+
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::Protobuf;
+
+use crate::counterparty::{
+    commitment_prefix_from_merkle_prefix, merkle_prefix_from_commitment_prefix,
+};
+use crate::error::ClientError;
+use crate::raw::MsgRegisterCounterparty as RawMsgRegisterCounterparty;
+
+pub const REGISTER_COUNTERPARTY_TYPE_URL: &str = "/ibc.core.client.v2.MsgRegisterCounterparty";
+
+/// Defines the message used to register the counterparty of a client, which
+/// lets IBC v2 packets be sent and received through the client.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRegisterCounterparty {
+    /// The client whose counterparty is registered
+    pub client_id: ClientId,
+    /// The counterparty client, tracking the host on the counterparty chain
+    pub counterparty_client_id: ClientId,
+    /// The prefix under which the counterparty chain commits to the packets
+    pub counterparty_commitment_prefix: CommitmentPrefix,
+    /// The signer of the message
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgRegisterCounterparty> for MsgRegisterCounterparty {}
+
+impl TryFrom<RawMsgRegisterCounterparty> for MsgRegisterCounterparty {
+    type Error = ClientError;
+
+    fn try_from(raw: RawMsgRegisterCounterparty) -> Result<Self, Self::Error> {
+        Ok(MsgRegisterCounterparty {
+            client_id: raw
+                .client_id
+                .parse()
+                .map_err(ClientError::InvalidClientIdentifier)?,
+            counterparty_client_id: raw
+                .counterparty_client_id
+                .parse()
+                .map_err(ClientError::InvalidClientIdentifier)?,
+            counterparty_commitment_prefix: commitment_prefix_from_merkle_prefix(
+                raw.counterparty_merkle_prefix,
+            )?,
+            signer: raw.signer.into(),
+        })
+    }
+}
+
+impl From<MsgRegisterCounterparty> for RawMsgRegisterCounterparty {
+    fn from(ics_msg: MsgRegisterCounterparty) -> Self {
+        RawMsgRegisterCounterparty {
+            client_id: ics_msg.client_id.to_string(),
+            counterparty_merkle_prefix: merkle_prefix_from_commitment_prefix(
+                ics_msg.counterparty_commitment_prefix,
+            ),
+            counterparty_client_id: ics_msg.counterparty_client_id.to_string(),
+            signer: ics_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Protobuf definitions of the IBC v2 client messages, mirroring those of
+//! `ibc-go`. They are defined here until `ibc-proto` ships them.
+use ibc_primitives::prelude::*;
+
+/// The counterparty of a client, through which IBC v2 packets are sent and
+/// received.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CounterpartyInfo {
+    /// the merkle path of the counterparty's store, under which it commits to
+    /// the IBC v2 packets
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub merkle_prefix: Vec<Vec<u8>>,
+    /// the identifier of the counterparty client
+    #[prost(string, tag = "2")]
+    pub client_id: String,
+}
+
+impl prost::Name for CounterpartyInfo {
+    const NAME: &'static str = "CounterpartyInfo";
+    const PACKAGE: &'static str = "ibc.core.client.v2";
+}
+
+/// Registers the counterparty of a client.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgRegisterCounterparty {
+    #[prost(string, tag = "1")]
+    pub client_id: String,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub counterparty_merkle_prefix: Vec<Vec<u8>>,
+    #[prost(string, tag = "3")]
+    pub counterparty_client_id: String,
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+impl prost::Name for MsgRegisterCounterparty {
+    const NAME: &'static str = "MsgRegisterCounterparty";
+    const PACKAGE: &'static str = "ibc.core.client.v2";
+}
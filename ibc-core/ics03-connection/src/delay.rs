@@ -13,6 +13,12 @@ pub fn verify_conn_delay_passed<Ctx>(
 where
     Ctx: ValidationContext,
 {
+    // The localhost client is never updated, as it always tracks the host
+    // itself, and its connection has no delay period.
+    if connection_end.client_id().is_localhost() {
+        return Ok(());
+    }
+
     // Fetch the current host chain time and height.
     let current_host_time = ctx.host_timestamp()?;
     let current_host_height = ctx.host_height()?;
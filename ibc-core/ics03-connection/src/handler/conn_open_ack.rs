@@ -8,6 +8,7 @@ use ibc_core_connection_types::msgs::MsgConnectionOpenAck;
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::metering::CostEvent;
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
@@ -73,9 +74,6 @@ where
             msg.proofs_height_on_b.revision_height(),
         );
 
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-
         let prefix_on_a = ctx_a.commitment_prefix();
         let prefix_on_b = vars.conn_end_on_a.counterparty().prefix();
 
@@ -92,22 +90,26 @@ where
                 vars.conn_end_on_a.delay_period(),
             )?;
 
+            ctx_a.charge(CostEvent::proof_verified(&msg.proof_conn_end_on_b))?;
             client_state_of_b_on_a
-                .verify_membership(
+                .verify_membership_at_height(
+                    client_val_ctx_a,
+                    &client_cons_state_path_on_a,
                     prefix_on_b,
                     &msg.proof_conn_end_on_b,
-                    consensus_state_of_b_on_a.root(),
                     Path::Connection(ConnectionPath::new(&msg.conn_id_on_b)),
                     expected_conn_end_on_b.encode_vec(),
                 )
                 .map_err(ConnectionError::VerifyConnectionState)?;
         }
 
+        ctx_a.charge(CostEvent::proof_verified(&msg.proof_client_state_of_a_on_b))?;
         client_state_of_b_on_a
-            .verify_membership(
+            .verify_membership_at_height(
+                client_val_ctx_a,
+                &client_cons_state_path_on_a,
                 prefix_on_b,
                 &msg.proof_client_state_of_a_on_b,
-                consensus_state_of_b_on_a.root(),
                 Path::ClientState(ClientStatePath::new(vars.client_id_on_b().clone())),
                 msg.client_state_of_a_on_b.to_vec(),
             )
@@ -125,11 +127,15 @@ where
             msg.consensus_height_of_a_on_b.revision_height(),
         );
 
+        ctx_a.charge(CostEvent::proof_verified(
+            &msg.proof_consensus_state_of_a_on_b,
+        ))?;
         client_state_of_b_on_a
-            .verify_membership(
+            .verify_membership_at_height(
+                client_val_ctx_a,
+                &client_cons_state_path_on_a,
                 prefix_on_b,
                 &msg.proof_consensus_state_of_a_on_b,
-                consensus_state_of_b_on_a.root(),
                 Path::ClientConsensusState(client_cons_state_path_on_b),
                 expected_consensus_state_of_a_on_b.into().to_vec(),
             )
@@ -7,6 +7,7 @@ use ibc_core_connection_types::msgs::MsgConnectionOpenTry;
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::metering::CostEvent;
 use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
 use ibc_core_host::types::path::{
     ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
@@ -73,9 +74,6 @@ where
             msg.proofs_height_on_a.revision_height(),
         );
 
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-
         let prefix_on_a = vars.conn_end_on_b.counterparty().prefix();
         let prefix_on_b = ctx_b.commitment_prefix();
 
@@ -88,22 +86,26 @@ where
                 msg.delay_period,
             )?;
 
+            ctx_b.charge(CostEvent::proof_verified(&msg.proof_conn_end_on_a))?;
             client_state_of_a_on_b
-                .verify_membership(
+                .verify_membership_at_height(
+                    client_val_ctx_b,
+                    &client_cons_state_path_on_b,
                     prefix_on_a,
                     &msg.proof_conn_end_on_a,
-                    consensus_state_of_a_on_b.root(),
                     Path::Connection(ConnectionPath::new(&vars.conn_id_on_a)),
                     expected_conn_end_on_a.encode_vec(),
                 )
                 .map_err(ConnectionError::VerifyConnectionState)?;
         }
 
+        ctx_b.charge(CostEvent::proof_verified(&msg.proof_client_state_of_b_on_a))?;
         client_state_of_a_on_b
-            .verify_membership(
+            .verify_membership_at_height(
+                client_val_ctx_b,
+                &client_cons_state_path_on_b,
                 prefix_on_a,
                 &msg.proof_client_state_of_b_on_a,
-                consensus_state_of_a_on_b.root(),
                 Path::ClientState(ClientStatePath::new(client_id_on_a.clone())),
                 msg.client_state_of_b_on_a.to_vec(),
             )
@@ -121,11 +123,15 @@ where
             msg.consensus_height_of_b_on_a.revision_height(),
         );
 
+        ctx_b.charge(CostEvent::proof_verified(
+            &msg.proof_consensus_state_of_b_on_a,
+        ))?;
         client_state_of_a_on_b
-            .verify_membership(
+            .verify_membership_at_height(
+                client_val_ctx_b,
+                &client_cons_state_path_on_b,
                 prefix_on_a,
                 &msg.proof_consensus_state_of_b_on_a,
-                consensus_state_of_a_on_b.root(),
                 Path::ClientConsensusState(client_cons_state_path_on_a),
                 expected_consensus_state_of_b_on_a.into().to_vec(),
             )
@@ -18,6 +18,7 @@ extern crate std;
 
 pub mod delay;
 pub mod handler;
+pub mod verify;
 
 /// Re-exports ICS-03 data structures from the `ibc-core-connection-types` crate
 pub mod types {
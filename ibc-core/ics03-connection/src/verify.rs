@@ -0,0 +1,167 @@
+//! Verification of the counterparty's state through the client of a
+//! connection end.
+
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::metering::CostEvent;
+use ibc_core_host::types::path::{ClientConsensusStatePath, Path};
+use ibc_core_host::{ClientStateRef, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+/// Verifies proofs of the counterparty's state at a given height, using the
+/// client of a connection end.
+///
+/// Proofs are verified by the client stored by the host, against its
+/// consensus state at the proof height. For the sentinel `connection-localhost`
+/// connection, the `09-localhost` client rather reads the proven paths from the
+/// host's own store, and ignores the proofs.
+///
+/// The host is charged for each proof verified by a stored client.
+pub struct CounterpartyVerifier<'a, Ctx>
+where
+    Ctx: ValidationContext,
+{
+    ctx: &'a Ctx,
+    conn_end: &'a ConnectionEnd,
+    client: CounterpartyClient<Ctx>,
+}
+
+enum CounterpartyClient<Ctx>
+where
+    Ctx: ValidationContext,
+{
+    Stored {
+        client_state: ClientStateRef<Ctx>,
+        client_cons_state_path: ClientConsensusStatePath,
+    },
+    Localhost,
+}
+
+impl<'a, Ctx> CounterpartyVerifier<'a, Ctx>
+where
+    Ctx: ValidationContext,
+{
+    /// Checks that the client of the connection end is active, and that it
+    /// can verify proofs at the given height.
+    pub fn new(
+        ctx: &'a Ctx,
+        conn_end: &'a ConnectionEnd,
+        proof_height: Height,
+    ) -> Result<Self, ContextError> {
+        let client_id = conn_end.client_id();
+
+        if client_id.is_localhost() {
+            ctx.localhost_client_state()?
+                .validate_proof_height(proof_height)?;
+
+            return Ok(Self {
+                ctx,
+                conn_end,
+                client: CounterpartyClient::Localhost,
+            });
+        }
+
+        let client_val_ctx = ctx.get_client_validation_context();
+        let client_state = client_val_ctx.client_state(client_id)?;
+
+        client_state
+            .status(client_val_ctx, client_id)?
+            .verify_is_active()?;
+
+        client_state.validate_proof_height(proof_height)?;
+
+        let client_cons_state_path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            proof_height.revision_number(),
+            proof_height.revision_height(),
+        );
+
+        Ok(Self {
+            ctx,
+            conn_end,
+            client: CounterpartyClient::Stored {
+                client_state,
+                client_cons_state_path,
+            },
+        })
+    }
+
+    /// Returns the timestamp of the counterparty at the proof height.
+    ///
+    /// For the localhost client, this is the current timestamp of the host.
+    pub fn timestamp(&self) -> Result<Timestamp, ContextError> {
+        match &self.client {
+            CounterpartyClient::Stored {
+                client_state,
+                client_cons_state_path,
+            } => Ok(client_state.timestamp_at_height(
+                self.ctx.get_client_validation_context(),
+                client_cons_state_path,
+            )?),
+            CounterpartyClient::Localhost => self.ctx.host_timestamp(),
+        }
+    }
+
+    /// Verifies that the counterparty stores the given value at the given path.
+    pub fn verify_membership(
+        &self,
+        proof: &CommitmentProofBytes,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        match &self.client {
+            CounterpartyClient::Stored {
+                client_state,
+                client_cons_state_path,
+            } => {
+                self.ctx.charge(CostEvent::proof_verified(proof))?;
+
+                client_state.verify_membership_at_height(
+                    self.ctx.get_client_validation_context(),
+                    client_cons_state_path,
+                    self.conn_end.counterparty().prefix(),
+                    proof,
+                    path,
+                    value,
+                )
+            }
+            CounterpartyClient::Localhost => self
+                .ctx
+                .localhost_client_state()?
+                .verify_membership(self.ctx, path, value),
+        }
+    }
+
+    /// Verifies that the counterparty stores no value at the given path.
+    pub fn verify_non_membership(
+        &self,
+        proof: &CommitmentProofBytes,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        match &self.client {
+            CounterpartyClient::Stored {
+                client_state,
+                client_cons_state_path,
+            } => {
+                self.ctx.charge(CostEvent::proof_verified(proof))?;
+
+                client_state.verify_non_membership_at_height(
+                    self.ctx.get_client_validation_context(),
+                    client_cons_state_path,
+                    self.conn_end.counterparty().prefix(),
+                    proof,
+                    path,
+                )
+            }
+            CounterpartyClient::Localhost => self
+                .ctx
+                .localhost_client_state()?
+                .verify_non_membership(self.ctx, path),
+        }
+    }
+}
@@ -83,6 +83,12 @@ pub enum ConnectionError {
     TimestampOverflow(TimestampOverflowError),
     /// connection counter overflow error
     CounterOverflow,
+    /// mismatched event type: expected `{expected}`, actual `{actual}`
+    MismatchedEventType { expected: String, actual: String },
+    /// missing event attribute `{key}`
+    MissingEventAttribute { key: String },
+    /// invalid event attribute `{key}`: `{reason}`
+    InvalidEventAttribute { key: String, reason: String },
     /// other error: `{description}`
     Other { description: String },
 }
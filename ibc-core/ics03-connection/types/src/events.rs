@@ -1,14 +1,19 @@
 //! Types for the IBC events emitted from Tendermint Websocket by the connection module.
 
+use core::fmt::Display;
+use core::str::FromStr;
+
 use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_primitives::prelude::*;
 use tendermint::abci;
 
+use crate::error::ConnectionError;
+
 /// Connection event types
-const CONNECTION_OPEN_INIT_EVENT: &str = "connection_open_init";
-const CONNECTION_OPEN_TRY_EVENT: &str = "connection_open_try";
-const CONNECTION_OPEN_ACK_EVENT: &str = "connection_open_ack";
-const CONNECTION_OPEN_CONFIRM_EVENT: &str = "connection_open_confirm";
+pub const CONNECTION_OPEN_INIT_EVENT: &str = "connection_open_init";
+pub const CONNECTION_OPEN_TRY_EVENT: &str = "connection_open_try";
+pub const CONNECTION_OPEN_ACK_EVENT: &str = "connection_open_ack";
+pub const CONNECTION_OPEN_CONFIRM_EVENT: &str = "connection_open_confirm";
 
 /// The content of the `key` field for the attribute containing the connection identifier.
 pub const CONN_ID_ATTRIBUTE_KEY: &str = "connection_id";
@@ -67,6 +72,32 @@ impl From<Attributes> for Vec<abci::EventAttribute> {
     }
 }
 
+/// Parse attributes from Tendermint ABCI tags
+impl TryFrom<&abci::Event> for Attributes {
+    type Error = ConnectionError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        let counterparty_connection_id =
+            match event_attribute(event, COUNTERPARTY_CONN_ID_ATTRIBUTE_KEY)? {
+                "" => None,
+                _ => Some(parse_event_attribute(
+                    event,
+                    COUNTERPARTY_CONN_ID_ATTRIBUTE_KEY,
+                )?),
+            };
+
+        Ok(Self {
+            connection_id: parse_event_attribute(event, CONN_ID_ATTRIBUTE_KEY)?,
+            client_id: parse_event_attribute(event, CLIENT_ID_ATTRIBUTE_KEY)?,
+            counterparty_connection_id,
+            counterparty_client_id: parse_event_attribute(
+                event,
+                COUNTERPARTY_CLIENT_ID_ATTRIBUTE_KEY,
+            )?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -125,6 +156,16 @@ impl From<OpenInit> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenInit {
+    type Error = ConnectionError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CONNECTION_OPEN_INIT_EVENT)?;
+
+        Ok(Self((&event).try_into()?))
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -184,6 +225,16 @@ impl From<OpenTry> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenTry {
+    type Error = ConnectionError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CONNECTION_OPEN_TRY_EVENT)?;
+
+        Ok(Self((&event).try_into()?))
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -243,6 +294,16 @@ impl From<OpenAck> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenAck {
+    type Error = ConnectionError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CONNECTION_OPEN_ACK_EVENT)?;
+
+        Ok(Self((&event).try_into()?))
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -302,6 +363,58 @@ impl From<OpenConfirm> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenConfirm {
+    type Error = ConnectionError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CONNECTION_OPEN_CONFIRM_EVENT)?;
+
+        Ok(Self((&event).try_into()?))
+    }
+}
+
+/// Checks that an ABCI event is of the expected type.
+fn verify_event_type(event: &abci::Event, expected: &str) -> Result<(), ConnectionError> {
+    if event.kind != expected {
+        return Err(ConnectionError::MismatchedEventType {
+            expected: expected.to_string(),
+            actual: event.kind.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the value of the attribute of an ABCI event with the given key.
+fn event_attribute<'a>(event: &'a abci::Event, key: &str) -> Result<&'a str, ConnectionError> {
+    let attribute = event
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key_str().is_ok_and(|k| k == key))
+        .ok_or_else(|| ConnectionError::MissingEventAttribute {
+            key: key.to_string(),
+        })?;
+
+    attribute
+        .value_str()
+        .map_err(|e| ConnectionError::InvalidEventAttribute {
+            key: key.to_string(),
+            reason: e.to_string(),
+        })
+}
+
+/// Parses the value of the attribute of an ABCI event with the given key.
+fn parse_event_attribute<T>(event: &abci::Event, key: &str) -> Result<T, ConnectionError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    T::from_str(event_attribute(event, key)?).map_err(|e| ConnectionError::InvalidEventAttribute {
+        key: key.to_string(),
+        reason: e.to_string(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -410,4 +523,44 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn abci_to_ibc_connection_events() {
+        let client_type = ClientType::from_str("07-tendermint")
+            .expect("never fails because it's a valid client type");
+        let conn_id_on_a = ConnectionId::zero();
+        let client_id_on_a = client_type.build_client_id(0);
+        let client_id_on_b = client_type.build_client_id(1);
+
+        let open_init = OpenInit::new(conn_id_on_a.clone(), client_id_on_a, client_id_on_b);
+        let abci_event = AbciEvent::from(open_init.clone());
+
+        assert_eq!(OpenInit::try_from(abci_event.clone()).unwrap(), open_init);
+        assert!(matches!(
+            OpenTry::try_from(abci_event.clone()),
+            Err(ConnectionError::MismatchedEventType { .. })
+        ));
+
+        let mut abci_event = abci_event;
+        abci_event
+            .attributes
+            .retain(|a| a.key_str().unwrap() != CONN_ID_ATTRIBUTE_KEY);
+
+        assert!(matches!(
+            OpenInit::try_from(abci_event),
+            Err(ConnectionError::MissingEventAttribute { .. })
+        ));
+
+        let open_ack = OpenAck::new(
+            conn_id_on_a,
+            client_type.build_client_id(0),
+            ConnectionId::new(1),
+            client_type.build_client_id(1),
+        );
+
+        assert_eq!(
+            OpenAck::try_from(AbciEvent::from(open_ack.clone())).unwrap(),
+            open_ack
+        );
+    }
 }
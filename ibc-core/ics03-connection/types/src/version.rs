@@ -63,7 +63,11 @@ impl Version {
     pub fn compatibles() -> Vec<Self> {
         vec![Self {
             identifier: "1".to_string(),
-            features: vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()],
+            features: vec![
+                "ORDER_ORDERED".to_string(),
+                "ORDER_UNORDERED".to_string(),
+                "ORDER_ORDERED_ALLOW_TIMEOUT".to_string(),
+            ],
         }]
     }
 }
@@ -351,6 +355,23 @@ mod tests {
                 name: "Compatible versions".to_string(),
                 supported: Version::compatibles(),
                 counterparty: Version::compatibles(),
+                picked: Ok(Version {
+                    identifier: "1".to_string(),
+                    features: vec![
+                        "ORDER_ORDERED".to_string(),
+                        "ORDER_UNORDERED".to_string(),
+                        "ORDER_ORDERED_ALLOW_TIMEOUT".to_string(),
+                    ],
+                }),
+                want_pass: true,
+            },
+            Test {
+                name: "Counterparty without ordered allow timeout".to_string(),
+                supported: Version::compatibles(),
+                counterparty: vec![Version {
+                    identifier: "1".to_string(),
+                    features: vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()],
+                }],
                 picked: Ok(Version {
                     identifier: "1".to_string(),
                     features: vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()],
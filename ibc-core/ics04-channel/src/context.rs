@@ -1,15 +1,23 @@
 //! ICS4 (channel) context.
 
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
 use ibc_core_channel_types::channel::ChannelEnd;
 use ibc_core_channel_types::commitment::PacketCommitment;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::Version;
 use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
-use ibc_core_host::types::identifiers::{ConnectionId, Sequence};
+use ibc_core_host::localhost::LocalhostClientState;
+use ibc_core_host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath, SeqSendPath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::middleware::Ics4Wrapper;
 use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+use crate::handler::{send_packet, write_acknowledgement};
 
 /// Methods required in send packet validation, to be implemented by the host
 pub trait SendPacketValidationContext {
@@ -24,6 +32,13 @@ pub trait SendPacketValidationContext {
     /// Returns the ConnectionState for the given identifier `connection_id`.
     fn connection_end(&self, connection_id: &ConnectionId) -> Result<ConnectionEnd, ContextError>;
 
+    /// Returns the sentinel `09-localhost` client state, whose latest height
+    /// is the current height of the host.
+    fn localhost_client_state(&self) -> Result<LocalhostClientState, ContextError>;
+
+    /// Returns the current timestamp of the host.
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
 }
@@ -43,7 +58,15 @@ where
     }
 
     fn connection_end(&self, connection_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
-        self.connection_end(connection_id)
+        self.resolve_connection_end(connection_id)
+    }
+
+    fn localhost_client_state(&self) -> Result<LocalhostClientState, ContextError> {
+        self.localhost_client_state()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.host_timestamp()
     }
 
     fn get_next_sequence_send(
@@ -103,3 +126,46 @@ where
         self.log_message(message)
     }
 }
+
+/// The bottom of a middleware stack, which sends the packets and writes the
+/// acknowledgements of the stack through the core channel handler.
+#[derive(Debug)]
+pub struct ChannelIcs4Wrapper<'a, Ctx> {
+    ctx: &'a mut Ctx,
+}
+
+impl<'a, Ctx> ChannelIcs4Wrapper<'a, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    pub fn new(ctx: &'a mut Ctx) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<Ctx> Ics4Wrapper for ChannelIcs4Wrapper<'_, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    fn send_packet(&mut self, packet: Packet) -> Result<(), ContextError> {
+        send_packet(self.ctx, packet)
+    }
+
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), ContextError> {
+        write_acknowledgement(self.ctx, packet, acknowledgement)
+    }
+
+    fn app_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, ContextError> {
+        let chan_end_path = ChannelEndPath::new(port_id, channel_id);
+
+        Ok(self.ctx.channel_end(&chan_end_path)?.version().clone())
+    }
+}
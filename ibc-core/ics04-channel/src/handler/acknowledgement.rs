@@ -3,18 +3,18 @@ use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::AcknowledgePacket;
 use ibc_core_channel_types::msgs::MsgAcknowledgement;
-use ibc_core_client::context::prelude::*;
 use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, SeqAckPath,
-};
+use ibc_core_host::types::path::{AckPath, ChannelEndPath, CommitmentPath, Path, SeqAckPath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::upgrade::handle_flush_state;
+use crate::verify::ChannelVerifier;
+
 pub fn acknowledgement_packet_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -76,13 +76,15 @@ where
     {
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
 
-        if let Order::Ordered = chan_end_on_a.ordering {
+        if let Order::Ordered | Order::OrderedAllowTimeout = chan_end_on_a.ordering {
             // Note: in validation, we verified that `msg.packet.sequence == nextSeqRecv`
             // (where `nextSeqRecv` is the value in the store)
             let seq_ack_path_on_a =
                 SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
             ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, msg.packet.seq_on_a.increment())?;
         }
+
+        handle_flush_state(ctx_a, &msg.packet.port_id_on_a, &msg.packet.chan_id_on_a)?;
     }
 
     // emit events and logs
@@ -113,7 +115,18 @@ where
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
-    chan_end_on_a.verify_state_matches(&ChannelState::Open)?;
+    // In-flight packets are still acknowledged while the channel end is
+    // flushing as part of an upgrade.
+    if !matches!(
+        chan_end_on_a.state,
+        ChannelState::Open | ChannelState::Flushing
+    ) {
+        return Err(PacketError::InvalidChannelState {
+            channel_id: packet.chan_id_on_a.clone(),
+            state: chan_end_on_a.state,
+        }
+        .into());
+    }
 
     let counterparty = Counterparty::new(
         packet.port_id_on_b.clone(),
@@ -123,7 +136,7 @@ where
     chan_end_on_a.verify_counterparty_matches(&counterparty)?;
 
     let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
-    let conn_end_on_a = ctx_a.connection_end(conn_id_on_a)?;
+    let conn_end_on_a = ctx_a.resolve_connection_end(conn_id_on_a)?;
 
     conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
 
@@ -152,7 +165,7 @@ where
         .into());
     }
 
-    if let Order::Ordered = chan_end_on_a.ordering {
+    if let Order::Ordered | Order::OrderedAllowTimeout = chan_end_on_a.ordering {
         let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
         let next_seq_ack = ctx_a.get_next_sequence_ack(&seq_ack_path_on_a)?;
         if packet.seq_on_a != next_seq_ack {
@@ -166,24 +179,14 @@ where
 
     // Verify proofs
     {
-        let client_id_on_a = conn_end_on_a.client_id();
-
-        let client_val_ctx_a = ctx_a.get_client_validation_context();
-
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
-        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+        let verifier_of_b_on_a = ChannelVerifier::new(
+            ctx_a,
+            chan_end_on_a.connection_hops(),
+            &conn_end_on_a,
+            &msg.proof_acked_on_b,
+            msg.proof_height_on_b,
+        )?;
 
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
         let ack_commitment = compute_ack_commitment(&msg.acknowledgement);
         let ack_path_on_b =
             AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
@@ -191,14 +194,8 @@ where
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
         // Verify the proof for the packet against the chain store.
-        client_state_of_b_on_a
-            .verify_membership(
-                conn_end_on_a.counterparty().prefix(),
-                &msg.proof_acked_on_b,
-                consensus_state_of_b_on_a.root(),
-                Path::Ack(ack_path_on_b),
-                ack_commitment.into_vec(),
-            )
+        verifier_of_b_on_a
+            .verify_membership(Path::Ack(ack_path_on_b), ack_commitment.into_vec())
             .map_err(|e| ChannelError::PacketVerificationFailed {
                 sequence: packet.seq_on_a,
                 client_error: e,
@@ -4,16 +4,18 @@ use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State, State as
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::CloseConfirm;
 use ibc_core_channel_types::msgs::MsgChannelCloseConfirm;
-use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_connection::verify::CounterpartyVerifier;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
+use ibc_core_host::types::path::{ChannelEndPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::upgrade::delete_upgrade_info;
+
 pub fn chan_close_confirm_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -49,6 +51,9 @@ where
             chan_end_on_b
         };
         ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
+
+        // Closing the channel end also ends any ongoing upgrade.
+        delete_upgrade_info(ctx_b, &msg.port_id_on_b, &msg.chan_id_on_b)?;
     }
 
     // emit events and logs
@@ -104,31 +109,15 @@ where
     // Validate that the channel end is in a state where it can be closed.
     chan_end_on_b.verify_not_closed()?;
 
-    let conn_end_on_b = ctx_b.connection_end(&chan_end_on_b.connection_hops()[0])?;
+    let conn_end_on_b = ctx_b.resolve_connection_end(&chan_end_on_b.connection_hops()[0])?;
 
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
     // Verify proofs
     {
-        let client_id_on_b = conn_end_on_b.client_id();
-
-        let client_val_ctx_b = ctx_b.get_client_validation_context();
-
-        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
+        let verifier_of_a_on_b =
+            CounterpartyVerifier::new(ctx_b, &conn_end_on_b, msg.proof_height_on_a)?;
 
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
-            .verify_is_active()?;
-        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
-
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-        let prefix_on_a = conn_end_on_b.counterparty().prefix();
         let port_id_on_a = &chan_end_on_b.counterparty().port_id;
         let chan_id_on_a = chan_end_on_b
             .counterparty()
@@ -140,22 +129,24 @@ where
             },
         )?;
 
-        let expected_chan_end_on_a = ChannelEnd::new(
-            ChannelState::Closed,
-            *chan_end_on_b.ordering(),
-            Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
-            vec![conn_id_on_a.clone()],
-            chan_end_on_b.version().clone(),
-        )?;
+        let expected_chan_end_on_a = {
+            let mut chan_end_on_a = ChannelEnd::new(
+                ChannelState::Closed,
+                *chan_end_on_b.ordering(),
+                Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+                vec![conn_id_on_a.clone()],
+                chan_end_on_b.version().clone(),
+            )?;
+            chan_end_on_a.set_upgrade_sequence(msg.counterparty_upgrade_sequence);
+            chan_end_on_a
+        };
         let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_a_on_b
+        verifier_of_a_on_b
             .verify_membership(
-                prefix_on_a,
                 &msg.proof_chan_end_on_a,
-                consensus_state_of_a_on_b.root(),
                 Path::ChannelEnd(chan_end_path_on_a),
                 expected_chan_end_on_a.encode_vec(),
             )
@@ -12,6 +12,8 @@ use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::upgrade::delete_upgrade_info;
+
 pub fn chan_close_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -48,6 +50,9 @@ where
         };
 
         ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+
+        // Closing the channel end also ends any ongoing upgrade.
+        delete_upgrade_info(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?;
     }
 
     // emit events and logs
@@ -105,18 +110,21 @@ where
     // An OPEN IBC connection running on the local (host) chain should exist.
     chan_end_on_a.verify_connection_hops_length()?;
 
-    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+    let conn_end_on_a = ctx_a.resolve_connection_end(&chan_end_on_a.connection_hops()[0])?;
 
     conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
 
     let client_id_on_a = conn_end_on_a.client_id();
 
-    let client_val_ctx_a = ctx_a.get_client_validation_context();
+    // The localhost client is always active.
+    if !client_id_on_a.is_localhost() {
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
 
-    let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-    client_state_of_b_on_a
-        .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-        .verify_is_active()?;
+        client_state_of_b_on_a
+            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+            .verify_is_active()?;
+    }
 
     Ok(())
 }
@@ -3,16 +3,17 @@ use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State, State as
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenAck;
 use ibc_core_channel_types::msgs::MsgChannelOpenAck;
-use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
+use ibc_core_host::types::path::{ChannelEndPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use crate::verify::ChannelVerifier;
+
 pub fn chan_open_ack_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -99,56 +100,40 @@ where
     chan_end_on_a.verify_state_matches(&ChannelState::Init)?;
 
     // An OPEN IBC connection running on the local (host) chain should exist.
-    chan_end_on_a.verify_connection_hops_length()?;
+    chan_end_on_a.verify_connection_hops_not_empty()?;
 
-    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+    let conn_end_on_a = ctx_a.resolve_connection_end(&chan_end_on_a.connection_hops()[0])?;
 
     conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
 
     // Verify proofs
     {
-        let client_id_on_a = conn_end_on_a.client_id();
-        let client_val_ctx_a = ctx_a.get_client_validation_context();
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
-        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
-
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let prefix_on_b = conn_end_on_a.counterparty().prefix();
-        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
-        let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
-            ChannelError::UndefinedConnectionCounterparty {
-                connection_id: chan_end_on_a.connection_hops()[0].clone(),
-            },
+        let verifier_of_b_on_a = ChannelVerifier::new(
+            ctx_a,
+            chan_end_on_a.connection_hops(),
+            &conn_end_on_a,
+            &msg.proof_chan_end_on_b,
+            msg.proof_height_on_b,
         )?;
 
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let connection_hops_on_b = verifier_of_b_on_a.counterparty_connection_hops()?;
+
         let expected_chan_end_on_b = ChannelEnd::new(
             ChannelState::TryOpen,
             // Note: Both ends of a channel must have the same ordering, so it's
             // fine to use A's ordering here
             *chan_end_on_a.ordering(),
             Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
-            vec![conn_id_on_b.clone()],
+            connection_hops_on_b,
             msg.version_on_b.clone(),
         )?;
         let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, &msg.chan_id_on_b);
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a
+        verifier_of_b_on_a
             .verify_membership(
-                prefix_on_b,
-                &msg.proof_chan_end_on_b,
-                consensus_state_of_b_on_a.root(),
                 Path::ChannelEnd(chan_end_path_on_b),
                 expected_chan_end_on_b.encode_vec(),
             )
@@ -115,17 +115,21 @@ where
 
     msg.verify_connection_hops_length()?;
     // An IBC connection running on the local (host) chain should exist.
-    let conn_end_on_a = ctx_a.connection_end(&msg.connection_hops_on_a[0])?;
+    let conn_end_on_a = ctx_a.resolve_connection_end(&msg.connection_hops_on_a[0])?;
 
     // Note: Not needed check if the connection end is OPEN. Optimistic channel handshake is allowed.
 
     let client_id_on_a = conn_end_on_a.client_id();
-    let client_val_ctx_a = ctx_a.get_client_validation_context();
-    let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
 
-    client_state_of_b_on_a
-        .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-        .verify_is_active()?;
+    // The localhost client is always active.
+    if !client_id_on_a.is_localhost() {
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+
+        client_state_of_b_on_a
+            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+            .verify_is_active()?;
+    }
 
     let conn_version = conn_end_on_a.versions();
 
@@ -4,19 +4,18 @@ use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State as Channel
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenTry;
 use ibc_core_channel_types::msgs::MsgChannelOpenTry;
-use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::ChannelId;
-use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, Path, SeqAckPath, SeqRecvPath, SeqSendPath,
-};
+use ibc_core_host::types::path::{ChannelEndPath, Path, SeqAckPath, SeqRecvPath, SeqSendPath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use crate::verify::ChannelVerifier;
+
 pub fn chan_open_try_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -123,7 +122,7 @@ where
 
     msg.verify_connection_hops_length()?;
 
-    let conn_end_on_b = ctx_b.connection_end(&msg.connection_hops_on_b[0])?;
+    let conn_end_on_b = ctx_b.resolve_connection_end(&msg.connection_hops_on_b[0])?;
 
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
@@ -133,48 +132,31 @@ where
 
     // Verify proofs
     {
-        let client_id_on_b = conn_end_on_b.client_id();
-        let client_val_ctx_b = ctx_b.get_client_validation_context();
-        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
-
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
-            .verify_is_active()?;
-
-        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
-
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-        let prefix_on_a = conn_end_on_b.counterparty().prefix();
+        let verifier_of_a_on_b = ChannelVerifier::new(
+            ctx_b,
+            &msg.connection_hops_on_b,
+            &conn_end_on_b,
+            &msg.proof_chan_end_on_a,
+            msg.proof_height_on_a,
+        )?;
+
         let port_id_on_a = msg.port_id_on_a.clone();
         let chan_id_on_a = msg.chan_id_on_a.clone();
-        let conn_id_on_a = conn_end_on_b.counterparty().connection_id().ok_or(
-            ChannelError::UndefinedConnectionCounterparty {
-                connection_id: msg.connection_hops_on_b[0].clone(),
-            },
-        )?;
+        let connection_hops_on_a = verifier_of_a_on_b.counterparty_connection_hops()?;
 
         let expected_chan_end_on_a = ChannelEnd::new(
             ChannelState::Init,
             msg.ordering,
             Counterparty::new(msg.port_id_on_b.clone(), None),
-            vec![conn_id_on_a.clone()],
+            connection_hops_on_a,
             msg.version_supported_on_a.clone(),
         )?;
         let chan_end_path_on_a = ChannelEndPath::new(&port_id_on_a, &chan_id_on_a);
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_a_on_b
+        verifier_of_a_on_b
             .verify_membership(
-                prefix_on_a,
-                &msg.proof_chan_end_on_a,
-                consensus_state_of_a_on_b.root(),
                 Path::ChannelEnd(chan_end_path_on_a),
                 expected_chan_end_on_a.encode_vec(),
             )
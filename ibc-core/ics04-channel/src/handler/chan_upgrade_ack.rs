@@ -0,0 +1,260 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeAck`.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeAck;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeAck;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_connection::verify::CounterpartyVerifier;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, CounterpartyUpgradePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{abort_upgrade, start_flushing, verify_upgrade_compatibility};
+
+pub fn chan_upgrade_ack_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeAck,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    // An incompatible upgrade is aborted in `execute` rather than failing the
+    // message, in which case the application is not involved.
+    match check_counterparty_upgrade(ctx_a, &msg) {
+        Err(ContextError::ChannelError(ChannelError::UpgradeAborted { .. })) => return Ok(()),
+        res => res?,
+    }
+
+    module.on_chan_upgrade_ack_validate(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.counterparty_upgrade.fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_ack_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeAck,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    match check_counterparty_upgrade(ctx_a, &msg) {
+        Err(ContextError::ChannelError(e @ ChannelError::UpgradeAborted { .. })) => {
+            ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+            return abort_upgrade(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a, e);
+        }
+        res => res?,
+    }
+
+    let extras = module.on_chan_upgrade_ack_execute(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.counterparty_upgrade.fields.version,
+    )?;
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let mut chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let mut upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+
+    // state changes
+    {
+        // In the crossing hellos case, chain A already started flushing upon
+        // `ChanUpgradeTry`.
+        if chan_end_on_a.state == ChannelState::Open {
+            start_flushing(
+                ctx_a,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                &mut chan_end_on_a,
+                &mut upgrade_on_a,
+            )?;
+            ctx_a.store_channel_upgrade(&upgrade_path_on_a, upgrade_on_a.clone())?;
+        }
+
+        if !ctx_a.has_inflight_packets(&chan_end_path_on_a)? {
+            chan_end_on_a.set_state(ChannelState::FlushComplete);
+        }
+
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
+
+        let counterparty_upgrade_path_on_a =
+            CounterpartyUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+        ctx_a.store_counterparty_upgrade(
+            &counterparty_upgrade_path_on_a,
+            msg.counterparty_upgrade.clone(),
+        )?;
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade ack".to_string())?;
+
+        let core_event = {
+            let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+            let chan_id_on_b = chan_end_on_a
+                .counterparty()
+                .channel_id
+                .clone()
+                .ok_or(ChannelError::MissingCounterparty)?;
+
+            IbcEvent::UpgradeAckChannel(UpgradeAck::new(
+                msg.port_id_on_a.clone(),
+                msg.chan_id_on_a.clone(),
+                port_id_on_b,
+                chan_id_on_b,
+                upgrade_on_a.fields.connection_hops,
+                upgrade_on_a.fields.version,
+                upgrade_on_a.fields.ordering,
+                chan_end_on_a.upgrade_sequence(),
+            ))
+        };
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_a.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeAck) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // The channel end is `FLUSHING` in the crossing hellos case.
+    if !matches!(
+        chan_end_on_a.state,
+        ChannelState::Open | ChannelState::Flushing
+    ) {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel state must be OPEN or FLUSHING".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        }
+        .into());
+    }
+
+    // Ensure an upgrade was initiated on chain A.
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+
+    let conn_end_on_a = ctx_a.resolve_connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let verifier_of_b_on_a =
+            CounterpartyVerifier::new(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
+
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
+            ChannelError::UndefinedConnectionCounterparty {
+                connection_id: chan_end_on_a.connection_hops()[0].clone(),
+            },
+        )?;
+
+        let expected_chan_end_on_b = {
+            let mut chan_end_on_b = ChannelEnd::new(
+                ChannelState::Flushing,
+                *chan_end_on_a.ordering(),
+                Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+                vec![conn_id_on_b.clone()],
+                chan_end_on_a.version().clone(),
+            )?;
+            chan_end_on_b.set_upgrade_sequence(chan_end_on_a.upgrade_sequence());
+            chan_end_on_b
+        };
+        let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, chan_id_on_b);
+
+        // Verify the proof for the channel state against the expected channel end.
+        verifier_of_b_on_a
+            .verify_membership(
+                &msg.proof_chan_end_on_b,
+                Path::ChannelEnd(chan_end_path_on_b),
+                expected_chan_end_on_b.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+
+        let upgrade_path_on_b = ChannelUpgradePath::new(port_id_on_b, chan_id_on_b);
+
+        // Verify the proof for the upgrade chain B agreed to.
+        verifier_of_b_on_a
+            .verify_membership(
+                &msg.proof_upgrade_on_b,
+                Path::ChannelUpgrade(upgrade_path_on_b),
+                msg.counterparty_upgrade.clone().encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+    }
+
+    Ok(())
+}
+
+/// Checks the upgrade of chain B against the one of chain A, and that the
+/// upgrade timeout set by chain B has not elapsed.
+///
+/// A failure is reported as [`ChannelError::UpgradeAborted`], as it results
+/// in the upgrade being aborted rather than a failed message.
+fn check_counterparty_upgrade<Ctx>(
+    ctx_a: &Ctx,
+    msg: &MsgChannelUpgradeAck,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_sequence_on_a = ctx_a.channel_end(&chan_end_path_on_a)?.upgrade_sequence();
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+
+    let proposed_conn_end_on_a =
+        ctx_a.resolve_connection_end(&upgrade_on_a.fields.connection_hops[0])?;
+
+    verify_upgrade_compatibility(
+        &upgrade_on_a.fields,
+        &msg.counterparty_upgrade.fields,
+        &proposed_conn_end_on_a,
+        upgrade_sequence_on_a,
+    )?;
+
+    let timeout = msg.counterparty_upgrade.timeout()?;
+
+    if timeout.has_expired(ctx_a.host_height()?, &ctx_a.host_timestamp()?) {
+        return Err(ChannelError::UpgradeAborted {
+            upgrade_sequence: upgrade_sequence_on_a,
+            description: ChannelError::UpgradeTimeoutElapsed.to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeCancel`.
+
+use ibc_core_channel_types::channel::State as ChannelState;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeCancel;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeCancel;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_connection::verify::CounterpartyVerifier;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{restore_channel, write_error_receipt};
+
+pub fn chan_upgrade_cancel_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    _module: &dyn Module,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)
+}
+
+pub fn chan_upgrade_cancel_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    _module: &mut dyn Module,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let upgrade_sequence = msg.error_receipt.sequence;
+
+    // state changes
+    let chan_end_on_a = restore_channel(
+        ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        upgrade_sequence,
+    )?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade cancel".to_string())?;
+
+        let core_event = {
+            let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+            let chan_id_on_b = chan_end_on_a
+                .counterparty()
+                .channel_id
+                .clone()
+                .ok_or(ChannelError::MissingCounterparty)?;
+
+            IbcEvent::UpgradeCancelChannel(UpgradeCancel::new(
+                msg.port_id_on_a.clone(),
+                msg.chan_id_on_a.clone(),
+                port_id_on_b,
+                chan_id_on_b,
+                upgrade_sequence,
+            ))
+        };
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+    }
+
+    // Chain B may still be mid-upgrade, e.g. if it aborted upon
+    // `ChanUpgradeTry`: the error receipt lets it cancel as well.
+    write_error_receipt(
+        ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &chan_end_on_a,
+        upgrade_sequence,
+        "upgrade cancelled".to_string(),
+    )
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeCancel) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // Ensure an upgrade is in progress on chain A.
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+
+    // Once flushing completed, chain B may already have switched to the
+    // upgraded parameters: the upgrade can only time out.
+    if chan_end_on_a.state == ChannelState::FlushComplete {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel state cannot be FLUSHCOMPLETE".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        }
+        .into());
+    }
+
+    // The error receipt must not relate to a previous upgrade attempt.
+    if msg.error_receipt.sequence < chan_end_on_a.upgrade_sequence() {
+        return Err(ChannelError::InvalidUpgradeSequence {
+            expected: chan_end_on_a.upgrade_sequence(),
+            actual: msg.error_receipt.sequence,
+        }
+        .into());
+    }
+
+    let conn_end_on_a = ctx_a.resolve_connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let verifier_of_b_on_a =
+            CounterpartyVerifier::new(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
+
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let receipt_path_on_b = ChannelUpgradeErrorPath::new(port_id_on_b, chan_id_on_b);
+
+        // Verify the proof for the error receipt written by chain B.
+        verifier_of_b_on_a
+            .verify_membership(
+                &msg.proof_error_receipt_on_b,
+                Path::ChannelUpgradeError(receipt_path_on_b),
+                msg.error_receipt.clone().encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeConfirm`.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeConfirm;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeConfirm;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_connection::verify::CounterpartyVerifier;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, CounterpartyUpgradePath, Path,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{abort_upgrade, open_upgrade};
+
+pub fn chan_upgrade_confirm_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    _module: &dyn Module,
+    msg: MsgChannelUpgradeConfirm,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_b, &msg)?;
+
+    // An elapsed timeout aborts the upgrade in `execute` rather than failing
+    // the message.
+    match check_counterparty_upgrade(ctx_b, &msg) {
+        Err(ContextError::ChannelError(ChannelError::UpgradeAborted { .. })) => Ok(()),
+        res => res,
+    }
+}
+
+pub fn chan_upgrade_confirm_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeConfirm,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    match check_counterparty_upgrade(ctx_b, &msg) {
+        Err(ContextError::ChannelError(e @ ChannelError::UpgradeAborted { .. })) => {
+            ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+            return abort_upgrade(ctx_b, &msg.port_id_on_b, &msg.chan_id_on_b, e);
+        }
+        res => res?,
+    }
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let mut chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    // state changes
+    {
+        if !ctx_b.has_inflight_packets(&chan_end_path_on_b)? {
+            chan_end_on_b.set_state(ChannelState::FlushComplete);
+        }
+
+        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b.clone())?;
+
+        let counterparty_upgrade_path_on_b =
+            CounterpartyUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+        ctx_b.store_counterparty_upgrade(
+            &counterparty_upgrade_path_on_b,
+            msg.counterparty_upgrade.clone(),
+        )?;
+    }
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: channel upgrade confirm".to_string())?;
+
+        let core_event = {
+            let port_id_on_a = chan_end_on_b.counterparty().port_id.clone();
+            let chan_id_on_a = chan_end_on_b
+                .counterparty()
+                .channel_id
+                .clone()
+                .ok_or(ChannelError::MissingCounterparty)?;
+
+            IbcEvent::UpgradeConfirmChannel(UpgradeConfirm::new(
+                msg.port_id_on_b.clone(),
+                msg.chan_id_on_b.clone(),
+                port_id_on_a,
+                chan_id_on_a,
+                chan_end_on_b.state,
+                chan_end_on_b.upgrade_sequence(),
+            ))
+        };
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(core_event)?;
+    }
+
+    // Both ends are done flushing: the upgrade can be completed right away.
+    if chan_end_on_b.state == ChannelState::FlushComplete
+        && msg.counterparty_chan_state == ChannelState::FlushComplete
+    {
+        open_upgrade(ctx_b, module, &msg.port_id_on_b, &msg.chan_id_on_b)?;
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelUpgradeConfirm) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&ChannelState::Flushing)?;
+
+    let conn_end_on_b = ctx_b.resolve_connection_end(&chan_end_on_b.connection_hops()[0])?;
+
+    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let verifier_of_a_on_b =
+            CounterpartyVerifier::new(ctx_b, &conn_end_on_b, msg.proof_height_on_a)?;
+
+        let port_id_on_a = &chan_end_on_b.counterparty().port_id;
+        let chan_id_on_a = chan_end_on_b
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let conn_id_on_a = conn_end_on_b.counterparty().connection_id().ok_or(
+            ChannelError::UndefinedConnectionCounterparty {
+                connection_id: chan_end_on_b.connection_hops()[0].clone(),
+            },
+        )?;
+
+        let expected_chan_end_on_a = {
+            let mut chan_end_on_a = ChannelEnd::new(
+                msg.counterparty_chan_state,
+                *chan_end_on_b.ordering(),
+                Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+                vec![conn_id_on_a.clone()],
+                chan_end_on_b.version().clone(),
+            )?;
+            chan_end_on_a.set_upgrade_sequence(chan_end_on_b.upgrade_sequence());
+            chan_end_on_a
+        };
+        let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
+
+        // Verify the proof for the channel state against the expected channel end.
+        verifier_of_a_on_b
+            .verify_membership(
+                &msg.proof_chan_end_on_a,
+                Path::ChannelEnd(chan_end_path_on_a),
+                expected_chan_end_on_a.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+
+        let upgrade_path_on_a = ChannelUpgradePath::new(port_id_on_a, chan_id_on_a);
+
+        // Verify the proof for the upgrade stored on chain A upon `ChanUpgradeAck`.
+        verifier_of_a_on_b
+            .verify_membership(
+                &msg.proof_upgrade_on_a,
+                Path::ChannelUpgrade(upgrade_path_on_a),
+                msg.counterparty_upgrade.clone().encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the upgrade timeout set by chain A has not elapsed.
+///
+/// An elapsed timeout is reported as [`ChannelError::UpgradeAborted`], as it
+/// results in the upgrade being aborted rather than a failed message.
+fn check_counterparty_upgrade<Ctx>(
+    ctx_b: &Ctx,
+    msg: &MsgChannelUpgradeConfirm,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let upgrade_sequence_on_b = ctx_b.channel_end(&chan_end_path_on_b)?.upgrade_sequence();
+
+    let timeout = msg.counterparty_upgrade.timeout()?;
+
+    if timeout.has_expired(ctx_b.host_height()?, &ctx_b.host_timestamp()?) {
+        return Err(ChannelError::UpgradeAborted {
+            upgrade_sequence: upgrade_sequence_on_b,
+            description: ChannelError::UpgradeTimeoutElapsed.to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
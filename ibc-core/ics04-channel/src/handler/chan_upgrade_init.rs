@@ -0,0 +1,142 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeInit`.
+
+use ibc_core_channel_types::channel::State as ChannelState;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeInit;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeInit;
+use ibc_core_channel_types::upgrade::{Upgrade, UpgradeFields};
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::Sequence;
+use ibc_core_host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+
+use super::upgrade::{delete_upgrade_info, verify_upgrade_fields};
+
+pub fn chan_upgrade_init_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeInit,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    module.on_chan_upgrade_init_validate(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        msg.fields.ordering,
+        &msg.fields.connection_hops,
+        &msg.fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_init_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeInit,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let (extras, version) = module.on_chan_upgrade_init_execute(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        msg.fields.ordering,
+        &msg.fields.connection_hops,
+        &msg.fields.version,
+    )?;
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    let upgrade_fields = UpgradeFields::new(
+        msg.fields.ordering,
+        msg.fields.connection_hops.clone(),
+        version,
+    );
+
+    // state changes
+    let chan_end_on_a = {
+        let chan_end_on_a = {
+            let mut chan_end_on_a = chan_end_on_a.clone();
+            chan_end_on_a.set_upgrade_sequence(chan_end_on_a.upgrade_sequence() + 1);
+            chan_end_on_a
+        };
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
+
+        // Any previous, not yet completed, upgrade attempt is superseded.
+        delete_upgrade_info(ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a)?;
+
+        let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+        ctx_a.store_channel_upgrade(
+            &upgrade_path_on_a,
+            Upgrade::new(upgrade_fields.clone(), None, Sequence::from(0)),
+        )?;
+
+        chan_end_on_a
+    };
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade init".to_string())?;
+
+        let core_event = {
+            let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+            let chan_id_on_b = chan_end_on_a
+                .counterparty()
+                .channel_id
+                .clone()
+                .ok_or(ChannelError::MissingCounterparty)?;
+
+            IbcEvent::UpgradeInitChannel(UpgradeInit::new(
+                msg.port_id_on_a.clone(),
+                msg.chan_id_on_a.clone(),
+                port_id_on_b,
+                chan_id_on_b,
+                upgrade_fields.connection_hops,
+                upgrade_fields.version,
+                upgrade_fields.ordering,
+                chan_end_on_a.upgrade_sequence(),
+            ))
+        };
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_a.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeInit) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // Only an open channel end can be upgraded.
+    chan_end_on_a.verify_state_matches(&ChannelState::Open)?;
+
+    verify_upgrade_fields(&chan_end_on_a, &msg.fields)?;
+
+    let proposed_conn_end_on_a = ctx_a.resolve_connection_end(&msg.fields.connection_hops[0])?;
+
+    proposed_conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    Ok(())
+}
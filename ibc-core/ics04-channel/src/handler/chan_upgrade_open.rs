@@ -0,0 +1,147 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeOpen`.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeOpen;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_connection::verify::CounterpartyVerifier;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{ChannelEndPath, ChannelUpgradePath, Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::open_upgrade;
+
+pub fn chan_upgrade_open_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    _module: &dyn Module,
+    msg: MsgChannelUpgradeOpen,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)
+}
+
+pub fn chan_upgrade_open_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeOpen,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+
+    open_upgrade(ctx_a, module, &msg.port_id_on_a, &msg.chan_id_on_a)
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeOpen) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    chan_end_on_a.verify_state_matches(&ChannelState::FlushComplete)?;
+
+    if ctx_a.has_inflight_packets(&chan_end_path_on_a)? {
+        return Err(ChannelError::PendingInflightPackets.into());
+    }
+
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+
+    let conn_end_on_a = ctx_a.resolve_connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let verifier_of_b_on_a =
+            CounterpartyVerifier::new(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
+
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let counterparty =
+            Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone()));
+
+        let expected_chan_end_on_b = match msg.counterparty_chan_state {
+            // Chain B already completed the upgrade, and may even have started
+            // a new one since.
+            ChannelState::Open => {
+                if msg.counterparty_upgrade_sequence < chan_end_on_a.upgrade_sequence() {
+                    return Err(ChannelError::InvalidUpgradeSequence {
+                        expected: chan_end_on_a.upgrade_sequence(),
+                        actual: msg.counterparty_upgrade_sequence,
+                    }
+                    .into());
+                }
+
+                let upgrade_conn_id_on_a = &upgrade_on_a.fields.connection_hops[0];
+                let upgrade_conn_end_on_a = ctx_a.resolve_connection_end(upgrade_conn_id_on_a)?;
+                let upgrade_conn_id_on_b = upgrade_conn_end_on_a
+                    .counterparty()
+                    .connection_id()
+                    .ok_or(ChannelError::UndefinedConnectionCounterparty {
+                        connection_id: upgrade_conn_id_on_a.clone(),
+                    })?;
+
+                let mut chan_end_on_b = ChannelEnd::new(
+                    ChannelState::Open,
+                    upgrade_on_a.fields.ordering,
+                    counterparty,
+                    vec![upgrade_conn_id_on_b.clone()],
+                    upgrade_on_a.fields.version.clone(),
+                )?;
+                chan_end_on_b.set_upgrade_sequence(msg.counterparty_upgrade_sequence);
+                chan_end_on_b
+            }
+            ChannelState::FlushComplete => {
+                let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
+                    ChannelError::UndefinedConnectionCounterparty {
+                        connection_id: chan_end_on_a.connection_hops()[0].clone(),
+                    },
+                )?;
+
+                let mut chan_end_on_b = ChannelEnd::new(
+                    ChannelState::FlushComplete,
+                    *chan_end_on_a.ordering(),
+                    counterparty,
+                    vec![conn_id_on_b.clone()],
+                    chan_end_on_a.version().clone(),
+                )?;
+                chan_end_on_b.set_upgrade_sequence(chan_end_on_a.upgrade_sequence());
+                chan_end_on_b
+            }
+            state => {
+                return Err(ChannelError::InvalidState {
+                    expected: "Counterparty channel state must be OPEN or FLUSHCOMPLETE"
+                        .to_string(),
+                    actual: state.to_string(),
+                }
+                .into())
+            }
+        };
+        let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, chan_id_on_b);
+
+        // Verify the proof for the channel state against the expected channel end.
+        verifier_of_b_on_a
+            .verify_membership(
+                &msg.proof_chan_end_on_b,
+                Path::ChannelEnd(chan_end_path_on_b),
+                expected_chan_end_on_b.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeTimeout`.
+
+use ibc_core_channel_types::channel::{Counterparty, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeTimeout;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeTimeout;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_connection::verify::CounterpartyVerifier;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{ChannelEndPath, ChannelUpgradePath, Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{restore_channel, write_error_receipt};
+
+pub fn chan_upgrade_timeout_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    _module: &dyn Module,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)
+}
+
+pub fn chan_upgrade_timeout_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    _module: &mut dyn Module,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_sequence_on_a = ctx_a.channel_end(&chan_end_path_on_a)?.upgrade_sequence();
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let timeout = *ctx_a.channel_upgrade(&upgrade_path_on_a)?.timeout()?;
+
+    // state changes
+    let chan_end_on_a = restore_channel(
+        ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        upgrade_sequence_on_a,
+    )?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade timeout".to_string())?;
+
+        let core_event = {
+            let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+            let chan_id_on_b = chan_end_on_a
+                .counterparty()
+                .channel_id
+                .clone()
+                .ok_or(ChannelError::MissingCounterparty)?;
+
+            IbcEvent::UpgradeTimeoutChannel(UpgradeTimeout::new(
+                msg.port_id_on_a.clone(),
+                msg.chan_id_on_a.clone(),
+                port_id_on_b,
+                chan_id_on_b,
+                timeout.height,
+                timeout.timestamp,
+                upgrade_sequence_on_a,
+            ))
+        };
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+    }
+
+    // The error receipt lets chain B cancel the upgrade on its end.
+    write_error_receipt(
+        ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &chan_end_on_a,
+        upgrade_sequence_on_a,
+        ChannelError::UpgradeTimeoutElapsed.to_string(),
+    )
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeTimeout) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    if !chan_end_on_a.is_upgrading() {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel state must be FLUSHING or FLUSHCOMPLETE".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        }
+        .into());
+    }
+
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+    let timeout = upgrade_on_a.timeout()?;
+
+    let counterparty_chan_end = &msg.counterparty_chan_end;
+
+    counterparty_chan_end.verify_counterparty_matches(&Counterparty::new(
+        msg.port_id_on_a.clone(),
+        Some(msg.chan_id_on_a.clone()),
+    ))?;
+
+    match counterparty_chan_end.state {
+        // Chain B must not have moved to `FLUSHCOMPLETE` past the timeout.
+        ChannelState::Flushing => {}
+        // Chain B must not have completed the upgrade.
+        ChannelState::Open
+            if counterparty_chan_end.upgrade_sequence() < chan_end_on_a.upgrade_sequence() => {}
+        ChannelState::Open => {
+            return Err(ChannelError::InvalidUpgradeSequence {
+                expected: chan_end_on_a.upgrade_sequence(),
+                actual: counterparty_chan_end.upgrade_sequence(),
+            }
+            .into())
+        }
+        state => {
+            return Err(ChannelError::InvalidState {
+                expected: "Counterparty channel state must be OPEN or FLUSHING".to_string(),
+                actual: state.to_string(),
+            }
+            .into())
+        }
+    }
+
+    let conn_end_on_a = ctx_a.resolve_connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let verifier_of_b_on_a =
+            CounterpartyVerifier::new(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
+
+        // check that the upgrade timeout has passed on the other end
+        let timestamp_of_b = verifier_of_b_on_a.timestamp()?;
+
+        if !timeout.has_expired(msg.proof_height_on_b, &timestamp_of_b) {
+            return Err(ChannelError::UpgradeTimeoutNotReached.into());
+        }
+
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, chan_id_on_b);
+
+        // Verify the proof for the channel state of chain B.
+        verifier_of_b_on_a
+            .verify_membership(
+                &msg.proof_chan_end_on_b,
+                Path::ChannelEnd(chan_end_path_on_b),
+                counterparty_chan_end.clone().encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+    }
+
+    Ok(())
+}
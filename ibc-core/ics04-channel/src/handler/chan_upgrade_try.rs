@@ -0,0 +1,315 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeTry`.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeTry;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeTry;
+use ibc_core_channel_types::upgrade::{Upgrade, UpgradeFields};
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_connection::verify::CounterpartyVerifier;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::Sequence;
+use ibc_core_host::types::path::{ChannelEndPath, ChannelUpgradePath, Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use super::upgrade::{
+    abort_upgrade, start_flushing, verify_upgrade_compatibility, verify_upgrade_fields,
+};
+
+pub fn chan_upgrade_try_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeTry,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    let (upgrade_on_b, upgrade_sequence_on_b) = validate(ctx_b, &msg)?;
+
+    // An incompatible upgrade is aborted in `execute` rather than failing the
+    // message, in which case the application is not involved.
+    match check_counterparty_upgrade(ctx_b, &msg, &upgrade_on_b, upgrade_sequence_on_b) {
+        Err(ContextError::ChannelError(ChannelError::UpgradeAborted { .. })) => return Ok(()),
+        res => res?,
+    }
+
+    module.on_chan_upgrade_try_validate(
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        upgrade_on_b.fields.ordering,
+        &upgrade_on_b.fields.connection_hops,
+        &msg.counterparty_upgrade_fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_try_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeTry,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let mut chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+    let (mut upgrade_on_b, upgrade_sequence_on_b) = proposed_upgrade(ctx_b, &msg, &chan_end_on_b)?;
+
+    let upgrade_check =
+        check_counterparty_upgrade(ctx_b, &msg, &upgrade_on_b, upgrade_sequence_on_b);
+
+    // The upgrade sequence is fast-forwarded to the one of the counterparty,
+    // even if the upgrade ends up being aborted.
+    chan_end_on_b
+        .set_upgrade_sequence(upgrade_sequence_on_b.max(msg.counterparty_upgrade_sequence));
+
+    // In the crossing hellos case, the upgrade chain B initiated is aborted
+    // along with the one of chain A.
+    if let Err(ContextError::ChannelError(e @ ChannelError::UpgradeAborted { .. })) = upgrade_check
+    {
+        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+
+        return abort_upgrade(ctx_b, &msg.port_id_on_b, &msg.chan_id_on_b, e);
+    }
+    upgrade_check?;
+
+    let (extras, version) = module.on_chan_upgrade_try_execute(
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        upgrade_on_b.fields.ordering,
+        &upgrade_on_b.fields.connection_hops,
+        &msg.counterparty_upgrade_fields.version,
+    )?;
+
+    // state changes
+    {
+        upgrade_on_b.fields.version = version;
+        start_flushing(
+            ctx_b,
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+            &mut chan_end_on_b,
+            &mut upgrade_on_b,
+        )?;
+
+        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b.clone())?;
+
+        let upgrade_path_on_b = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+        ctx_b.store_channel_upgrade(&upgrade_path_on_b, upgrade_on_b.clone())?;
+    }
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: channel upgrade try".to_string())?;
+
+        let core_event = {
+            let port_id_on_a = chan_end_on_b.counterparty().port_id.clone();
+            let chan_id_on_a = chan_end_on_b
+                .counterparty()
+                .channel_id
+                .clone()
+                .ok_or(ChannelError::MissingCounterparty)?;
+
+            IbcEvent::UpgradeTryChannel(UpgradeTry::new(
+                msg.port_id_on_b.clone(),
+                msg.chan_id_on_b.clone(),
+                port_id_on_a,
+                chan_id_on_a,
+                upgrade_on_b.fields.connection_hops,
+                upgrade_on_b.fields.version,
+                upgrade_on_b.fields.ordering,
+                chan_end_on_b.upgrade_sequence(),
+            ))
+        };
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_b.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the message, and returns the upgrade proposed by chain B along
+/// with its upgrade sequence.
+fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelUpgradeTry) -> Result<(Upgrade, u64), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
+
+    let (upgrade_on_b, upgrade_sequence_on_b) = proposed_upgrade(ctx_b, msg, &chan_end_on_b)?;
+
+    let conn_end_on_b = ctx_b.resolve_connection_end(&chan_end_on_b.connection_hops()[0])?;
+
+    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let verifier_of_a_on_b =
+            CounterpartyVerifier::new(ctx_b, &conn_end_on_b, msg.proof_height_on_a)?;
+
+        let port_id_on_a = &chan_end_on_b.counterparty().port_id;
+        let chan_id_on_a = chan_end_on_b
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let conn_id_on_a = conn_end_on_b.counterparty().connection_id().ok_or(
+            ChannelError::UndefinedConnectionCounterparty {
+                connection_id: chan_end_on_b.connection_hops()[0].clone(),
+            },
+        )?;
+
+        let expected_chan_end_on_a = {
+            let mut chan_end_on_a = ChannelEnd::new(
+                ChannelState::Open,
+                *chan_end_on_b.ordering(),
+                Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+                vec![conn_id_on_a.clone()],
+                chan_end_on_b.version().clone(),
+            )?;
+            chan_end_on_a.set_upgrade_sequence(msg.counterparty_upgrade_sequence);
+            chan_end_on_a
+        };
+        let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
+
+        // Verify the proof for the channel state against the expected channel end.
+        verifier_of_a_on_b
+            .verify_membership(
+                &msg.proof_chan_end_on_a,
+                Path::ChannelEnd(chan_end_path_on_a),
+                expected_chan_end_on_a.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+
+        // The upgrade on chain A has neither a timeout nor a next sequence
+        // send set, since it has not started flushing yet.
+        let expected_upgrade_on_a = Upgrade::new(
+            msg.counterparty_upgrade_fields.clone(),
+            None,
+            Sequence::from(0),
+        );
+        let upgrade_path_on_a = ChannelUpgradePath::new(port_id_on_a, chan_id_on_a);
+
+        // Verify the proof for the upgrade proposed by chain A.
+        verifier_of_a_on_b
+            .verify_membership(
+                &msg.proof_upgrade_on_a,
+                Path::ChannelUpgrade(upgrade_path_on_a),
+                expected_upgrade_on_a.encode_vec(),
+            )
+            .map_err(ChannelError::VerifyChannelFailed)?;
+    }
+
+    Ok((upgrade_on_b, upgrade_sequence_on_b))
+}
+
+/// Returns the upgrade chain B proposes along with the upgrade sequence it
+/// corresponds to.
+///
+/// If chain B did not initiate an upgrade itself, the upgrade is derived from
+/// the one proposed by chain A, and the upgrade sequence is incremented as
+/// `ChanUpgradeInit` would have.
+fn proposed_upgrade<Ctx>(
+    ctx_b: &Ctx,
+    msg: &MsgChannelUpgradeTry,
+    chan_end_on_b: &ChannelEnd,
+) -> Result<(Upgrade, u64), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let upgrade_path_on_b = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+
+    match ctx_b.channel_upgrade(&upgrade_path_on_b) {
+        // Crossing hellos: both ends initiated an upgrade.
+        Ok(upgrade_on_b) => {
+            if upgrade_on_b.fields.connection_hops != msg.proposed_upgrade_connection_hops {
+                return Err(ChannelError::UpgradeFieldsMismatch {
+                    expected: format!("{:?}", upgrade_on_b.fields.connection_hops),
+                    actual: format!("{:?}", msg.proposed_upgrade_connection_hops),
+                }
+                .into());
+            }
+
+            Ok((upgrade_on_b, chan_end_on_b.upgrade_sequence()))
+        }
+        Err(ContextError::ChannelError(ChannelError::UpgradeNotFound { .. })) => {
+            let fields = UpgradeFields::new(
+                msg.counterparty_upgrade_fields.ordering,
+                msg.proposed_upgrade_connection_hops.clone(),
+                msg.counterparty_upgrade_fields.version.clone(),
+            );
+
+            fields.validate_basic()?;
+            verify_upgrade_fields(chan_end_on_b, &fields)?;
+
+            let proposed_conn_end_on_b =
+                ctx_b.resolve_connection_end(&fields.connection_hops[0])?;
+
+            proposed_conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+
+            Ok((
+                Upgrade::new(fields, None, Sequence::from(0)),
+                chan_end_on_b.upgrade_sequence() + 1,
+            ))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Checks the upgrade proposed by chain A against the one of chain B.
+///
+/// An incompatibility is reported as [`ChannelError::UpgradeAborted`], as it
+/// results in an error receipt rather than a failed message.
+fn check_counterparty_upgrade<Ctx>(
+    ctx_b: &Ctx,
+    msg: &MsgChannelUpgradeTry,
+    upgrade_on_b: &Upgrade,
+    upgrade_sequence_on_b: u64,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if msg.counterparty_upgrade_sequence < upgrade_sequence_on_b {
+        // The upgrade of chain A is outdated. Chain A is forced to abort it by
+        // an error receipt at the previous upgrade sequence, so that it then
+        // catches up with the upgrade sequence of chain B.
+        return Err(ChannelError::UpgradeAborted {
+            upgrade_sequence: upgrade_sequence_on_b - 1,
+            description: format!(
+                "counterparty upgrade sequence `{}` is smaller than the current upgrade sequence `{}`",
+                msg.counterparty_upgrade_sequence, upgrade_sequence_on_b
+            ),
+        }
+        .into());
+    }
+
+    let proposed_conn_end_on_b =
+        ctx_b.resolve_connection_end(&upgrade_on_b.fields.connection_hops[0])?;
+
+    verify_upgrade_compatibility(
+        &upgrade_on_b.fields,
+        &msg.counterparty_upgrade_fields,
+        &proposed_conn_end_on_b,
+        msg.counterparty_upgrade_sequence,
+    )?;
+
+    Ok(())
+}
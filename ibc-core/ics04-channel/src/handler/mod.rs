@@ -6,10 +6,20 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_ack;
+mod chan_upgrade_cancel;
+mod chan_upgrade_confirm;
+mod chan_upgrade_init;
+mod chan_upgrade_open;
+mod chan_upgrade_timeout;
+mod chan_upgrade_try;
 mod recv_packet;
 mod send_packet;
 mod timeout;
 mod timeout_on_close;
+mod upgrade;
+pub mod v2;
+mod write_acknowledgement;
 
 pub use acknowledgement::*;
 pub use chan_close_confirm::*;
@@ -18,7 +28,15 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+pub use chan_upgrade_ack::*;
+pub use chan_upgrade_cancel::*;
+pub use chan_upgrade_confirm::*;
+pub use chan_upgrade_init::*;
+pub use chan_upgrade_open::*;
+pub use chan_upgrade_timeout::*;
+pub use chan_upgrade_try::*;
 pub use recv_packet::*;
 pub use send_packet::*;
 pub use timeout::*;
 pub use timeout_on_close::*;
+pub use write_acknowledgement::*;
@@ -1,16 +1,15 @@
 use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
-use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
-use ibc_core_channel_types::events::{ReceivePacket, WriteAcknowledgement};
+use ibc_core_channel_types::events::ReceivePacket;
 use ibc_core_channel_types::msgs::MsgRecvPacket;
 use ibc_core_channel_types::packet::Receipt;
-use ibc_core_client::context::prelude::*;
 use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
+    AckPath, ChannelEndPath, CommitmentPath, CounterpartyUpgradePath, Path, ReceiptPath,
     SeqRecvPath,
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
@@ -18,6 +17,9 @@ use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry;
 
+use super::write_acknowledgement::write_acknowledgement_execute;
+use crate::verify::ChannelVerifier;
+
 pub fn recv_packet_validate<ValCtx>(ctx_b: &ValCtx, msg: MsgRecvPacket) -> Result<(), ContextError>
 where
     ValCtx: ValidationContext,
@@ -53,7 +55,7 @@ where
                     ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
                 ctx_b.get_packet_receipt(&receipt_path_on_b).is_ok()
             }
-            Order::Ordered => {
+            Order::Ordered | Order::OrderedAllowTimeout => {
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
                 let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
@@ -69,6 +71,17 @@ where
         }
     }
 
+    // Over ordered allow timeout channels, a packet that timed out is not
+    // passed to the module: its timeout is recorded instead, so that the
+    // packets sent after it can still be received.
+    if chan_end_on_b.ordering == Order::OrderedAllowTimeout
+        && msg
+            .packet
+            .timed_out(&ctx_b.host_timestamp()?, ctx_b.host_height()?)
+    {
+        return write_timeout_receipt(ctx_b, &msg);
+    }
+
     let (extras, acknowledgement) = module.on_recv_packet_execute(&msg.packet, &msg.signer);
 
     // state changes
@@ -84,7 +97,7 @@ where
 
                 ctx_b.store_packet_receipt(&receipt_path_on_b, Receipt::Ok)?;
             }
-            Order::Ordered => {
+            Order::Ordered | Order::OrderedAllowTimeout => {
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
                 let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
@@ -92,22 +105,11 @@ where
             }
             _ => {}
         }
-        let ack_path_on_b = AckPath::new(
-            &msg.packet.port_id_on_b,
-            &msg.packet.chan_id_on_b,
-            msg.packet.seq_on_a,
-        );
-        // `writeAcknowledgement` handler state changes
-        ctx_b.store_packet_acknowledgement(
-            &ack_path_on_b,
-            compute_ack_commitment(&acknowledgement),
-        )?;
     }
 
     // emit events and logs
     {
         ctx_b.log_message("success: packet receive".to_string())?;
-        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
 
         let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
         let event = IbcEvent::ReceivePacket(ReceivePacket::new(
@@ -117,13 +119,12 @@ where
         ));
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(event)?;
-        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
-            msg.packet,
-            acknowledgement,
-            conn_id_on_b.clone(),
-        ));
-        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
-        ctx_b.emit_ibc_event(event)?;
+
+        // `writeAcknowledgement` handler, unless the module defers writing
+        // the acknowledgement
+        if let Some(acknowledgement) = acknowledgement {
+            write_acknowledgement_execute(ctx_b, &msg.packet, acknowledgement)?;
+        }
 
         for module_event in extras.events {
             ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
@@ -147,7 +148,46 @@ where
         ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
 
-    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
+    // Packets sent before an upgrade started are still received while the
+    // channel end is being upgraded.
+    if !matches!(
+        chan_end_on_b.state,
+        ChannelState::Open | ChannelState::Flushing | ChannelState::FlushComplete
+    ) {
+        return Err(PacketError::InvalidChannelState {
+            channel_id: msg.packet.chan_id_on_b.clone(),
+            state: chan_end_on_b.state,
+        }
+        .into());
+    }
+
+    // Once the counterparty started flushing, only the packets it sent
+    // before doing so may be received.
+    if chan_end_on_b.is_upgrading() {
+        let counterparty_upgrade_path_on_b =
+            CounterpartyUpgradePath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+
+        let counterparty_upgrade = match ctx_b.counterparty_upgrade(&counterparty_upgrade_path_on_b)
+        {
+            Ok(counterparty_upgrade) => Some(counterparty_upgrade),
+            Err(ContextError::ChannelError(ChannelError::CounterpartyUpgradeNotFound {
+                ..
+            })) => None,
+            Err(e) => return Err(e),
+        };
+
+        if let Some(counterparty_upgrade) = counterparty_upgrade {
+            let next_seq_send_on_a = counterparty_upgrade.next_sequence_send;
+
+            if !next_seq_send_on_a.is_zero() && msg.packet.seq_on_a >= next_seq_send_on_a {
+                return Err(PacketError::InvalidPacketSequence {
+                    given_sequence: msg.packet.seq_on_a,
+                    next_sequence: next_seq_send_on_a,
+                }
+                .into());
+            }
+        }
+    }
 
     let counterparty = Counterparty::new(
         msg.packet.port_id_on_a.clone(),
@@ -157,44 +197,37 @@ where
     chan_end_on_b.verify_counterparty_matches(&counterparty)?;
 
     let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
-    let conn_end_on_b = ctx_b.connection_end(conn_id_on_b)?;
+    let conn_end_on_b = ctx_b.resolve_connection_end(conn_id_on_b)?;
 
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
-    let latest_height = ctx_b.host_height()?;
-    if msg.packet.timeout_height_on_b.has_expired(latest_height) {
-        return Err(PacketError::LowPacketHeight {
-            chain_height: latest_height,
-            timeout_height: msg.packet.timeout_height_on_b,
+    // Over ordered allow timeout channels, a packet that timed out is still
+    // received, only to record its timeout.
+    if chan_end_on_b.ordering != Order::OrderedAllowTimeout {
+        let latest_height = ctx_b.host_height()?;
+        if msg.packet.timeout_height_on_b.has_expired(latest_height) {
+            return Err(PacketError::LowPacketHeight {
+                chain_height: latest_height,
+                timeout_height: msg.packet.timeout_height_on_b,
+            }
+            .into());
         }
-        .into());
-    }
 
-    let latest_timestamp = ctx_b.host_timestamp()?;
-    if let Expiry::Expired = latest_timestamp.check_expiry(&msg.packet.timeout_timestamp_on_b) {
-        return Err(PacketError::LowPacketTimestamp.into());
+        let latest_timestamp = ctx_b.host_timestamp()?;
+        if let Expiry::Expired = latest_timestamp.check_expiry(&msg.packet.timeout_timestamp_on_b) {
+            return Err(PacketError::LowPacketTimestamp.into());
+        }
     }
 
     // Verify proofs
     {
-        let client_id_on_b = conn_end_on_b.client_id();
-        let client_val_ctx_b = ctx_b.get_client_validation_context();
-        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
-
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
-            .verify_is_active()?;
-
-        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
-
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+        let verifier_of_a_on_b = ChannelVerifier::new(
+            ctx_b,
+            chan_end_on_b.connection_hops(),
+            &conn_end_on_b,
+            &msg.proof_commitment_on_a,
+            msg.proof_height_on_a,
+        )?;
 
         let expected_commitment_on_a = compute_packet_commitment(
             &msg.packet.data,
@@ -210,11 +243,8 @@ where
         verify_conn_delay_passed(ctx_b, msg.proof_height_on_a, &conn_end_on_b)?;
 
         // Verify the proof for the packet against the chain store.
-        client_state_of_a_on_b
+        verifier_of_a_on_b
             .verify_membership(
-                conn_end_on_b.counterparty().prefix(),
-                &msg.proof_commitment_on_a,
-                consensus_state_of_a_on_b.root(),
                 Path::Commitment(commitment_path_on_a),
                 expected_commitment_on_a.into_vec(),
             )
@@ -226,7 +256,7 @@ where
     }
 
     match chan_end_on_b.ordering {
-        Order::Ordered => {
+        Order::Ordered | Order::OrderedAllowTimeout => {
             let seq_recv_path_on_b =
                 SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
             let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
@@ -287,3 +317,28 @@ where
 
     Ok(())
 }
+
+/// Records the timeout of a packet received over an ordered allow timeout
+/// channel, and moves on to the next sequence to receive.
+fn write_timeout_receipt<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    msg: &MsgRecvPacket,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let receipt_path_on_b = ReceiptPath::new(
+        &msg.packet.port_id_on_b,
+        &msg.packet.chan_id_on_b,
+        msg.packet.seq_on_a,
+    );
+    ctx_b.store_packet_receipt(&receipt_path_on_b, Receipt::Timeout)?;
+
+    let seq_recv_path_on_b = SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+    let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
+    ctx_b.store_next_sequence_recv(&seq_recv_path_on_b, next_seq_recv.increment())?;
+
+    ctx_b.log_message("success: packet timeout receipt".to_string())?;
+
+    Ok(())
+}
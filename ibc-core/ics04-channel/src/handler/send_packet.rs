@@ -41,6 +41,15 @@ pub fn send_packet_validate(
     // This allows for optimistic packet processing before a channel opens
     chan_end_on_a.verify_not_closed()?;
 
+    // No new packets may be sent while the channel end is being upgraded.
+    if chan_end_on_a.is_upgrading() {
+        return Err(PacketError::InvalidChannelState {
+            channel_id: packet.chan_id_on_a.clone(),
+            state: chan_end_on_a.state,
+        }
+        .into());
+    }
+
     let counterparty = Counterparty::new(
         packet.port_id_on_b.clone(),
         Some(packet.chan_id_on_b.clone()),
@@ -54,35 +63,53 @@ pub fn send_packet_validate(
 
     let client_id_on_a = conn_end_on_a.client_id();
 
-    let client_val_ctx_a = ctx_a.get_client_validation_context();
-
-    let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-    client_state_of_b_on_a
-        .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-        .verify_is_active()?;
-
-    let latest_height_on_a = client_state_of_b_on_a.latest_height();
-
-    if packet.timeout_height_on_b.has_expired(latest_height_on_a) {
-        return Err(PacketError::LowPacketHeight {
-            chain_height: latest_height_on_a,
-            timeout_height: packet.timeout_height_on_b,
+    // The localhost client tracks the host itself, so its latest height and
+    // timestamp are those of the host.
+    let (latest_height_on_a, latest_timestamp) = if client_id_on_a.is_localhost() {
+        let client_state_of_b_on_a = ctx_a.localhost_client_state()?;
+
+        (
+            client_state_of_b_on_a.latest_height(),
+            ctx_a.host_timestamp()?,
+        )
+    } else {
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+
+        client_state_of_b_on_a
+            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+            .verify_is_active()?;
+
+        let latest_height_on_a = client_state_of_b_on_a.latest_height();
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            latest_height_on_a.revision_number(),
+            latest_height_on_a.revision_height(),
+        );
+        let latest_timestamp = client_state_of_b_on_a
+            .timestamp_at_height(client_val_ctx_a, &client_cons_state_path_on_a)?;
+
+        (latest_height_on_a, latest_timestamp)
+    };
+
+    // Over a multi-hop channel, the client of the first connection tracks an
+    // intermediate chain rather than the counterparty, whose height and
+    // timestamp are thus unknown to the host.
+    if !chan_end_on_a.is_multihop() {
+        if packet.timeout_height_on_b.has_expired(latest_height_on_a) {
+            return Err(PacketError::LowPacketHeight {
+                chain_height: latest_height_on_a,
+                timeout_height: packet.timeout_height_on_b,
+            }
+            .into());
         }
-        .into());
-    }
 
-    let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-        client_id_on_a.clone(),
-        latest_height_on_a.revision_number(),
-        latest_height_on_a.revision_height(),
-    );
-    let consensus_state_of_b_on_a =
-        client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-    let latest_timestamp = consensus_state_of_b_on_a.timestamp();
-    let packet_timestamp = packet.timeout_timestamp_on_b;
-    if let Expiry::Expired = latest_timestamp.check_expiry(&packet_timestamp) {
-        return Err(PacketError::LowPacketTimestamp.into());
+        let packet_timestamp = packet.timeout_timestamp_on_b;
+        if let Expiry::Expired = latest_timestamp.check_expiry(&packet_timestamp) {
+            return Err(PacketError::LowPacketTimestamp.into());
+        }
     }
 
     let seq_send_path_on_a = SeqSendPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
@@ -3,18 +3,20 @@ use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ChannelClosed, TimeoutPacket};
 use ibc_core_channel_types::msgs::{MsgTimeout, MsgTimeoutOnClose};
-use ibc_core_client::context::prelude::*;
+use ibc_core_channel_types::packet::{Packet, Receipt};
 use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
+    ChannelEndPath, CommitmentPath, Path, ReceiptPath, SeqAckPath, SeqRecvPath,
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
 use super::timeout_on_close;
+use super::upgrade::{delete_upgrade_info, handle_flush_state};
+use crate::verify::ChannelVerifier;
 
 pub enum TimeoutMsgType {
     Timeout(MsgTimeout),
@@ -85,12 +87,28 @@ where
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
 
         if let Order::Ordered = chan_end_on_a.ordering {
+            // A timeout on an ordered channel closes it, which also ends
+            // any ongoing upgrade.
+            if chan_end_on_a.state == State::Flushing {
+                delete_upgrade_info(ctx_a, &packet.port_id_on_a, &packet.chan_id_on_a)?;
+            }
+
             let mut chan_end_on_a = chan_end_on_a;
             chan_end_on_a.state = State::Closed;
             ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
 
             chan_end_on_a
         } else {
+            if let Order::OrderedAllowTimeout = chan_end_on_a.ordering {
+                // The timed out packet is skipped by the acknowledgements,
+                // which are processed in order.
+                // Note: in validation, we verified that `packet.sequence == nextSeqAck`
+                let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+                ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, packet.seq_on_a.increment())?;
+            }
+
+            handle_flush_state(ctx_a, &packet.port_id_on_a, &packet.chan_id_on_a)?;
+
             chan_end_on_a
         }
     };
@@ -137,7 +155,15 @@ where
         &msg.packet.chan_id_on_a,
     ))?;
 
-    chan_end_on_a.verify_state_matches(&State::Open)?;
+    // In-flight packets still time out while the channel end is flushing as
+    // part of an upgrade.
+    if !matches!(chan_end_on_a.state, State::Open | State::Flushing) {
+        return Err(PacketError::InvalidChannelState {
+            channel_id: msg.packet.chan_id_on_a.clone(),
+            state: chan_end_on_a.state,
+        }
+        .into());
+    }
 
     let counterparty = Counterparty::new(
         msg.packet.port_id_on_b.clone(),
@@ -147,7 +173,7 @@ where
     chan_end_on_a.verify_counterparty_matches(&counterparty)?;
 
     let conn_id_on_a = chan_end_on_a.connection_hops()[0].clone();
-    let conn_end_on_a = ctx_a.connection_end(&conn_id_on_a)?;
+    let conn_end_on_a = ctx_a.resolve_connection_end(&conn_id_on_a)?;
 
     //verify packet commitment
     let commitment_path_on_a = CommitmentPath::new(
@@ -175,32 +201,28 @@ where
         .into());
     }
 
+    if let Order::OrderedAllowTimeout = chan_end_on_a.ordering {
+        verify_next_sequence_ack(ctx_a, &msg.packet)?;
+    }
+
     // Verify proofs
     {
-        let client_id_on_a = conn_end_on_a.client_id();
-        let client_val_ctx_a = ctx_a.get_client_validation_context();
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
-
-        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+        let verifier_of_b_on_a = ChannelVerifier::new(
+            ctx_a,
+            chan_end_on_a.connection_hops(),
+            &conn_end_on_a,
+            &msg.proof_unreceived_on_b,
+            msg.proof_height_on_b,
+        )?;
 
         // check that timeout height or timeout timestamp has passed on the other end
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let timestamp_of_b = consensus_state_of_b_on_a.timestamp();
-
-        if !msg.packet.timed_out(&timestamp_of_b, msg.proof_height_on_b) {
+        let height_of_b = verifier_of_b_on_a.height();
+        let timestamp_of_b = verifier_of_b_on_a.timestamp()?;
+
+        if !msg.packet.timed_out(&timestamp_of_b, height_of_b) {
             return Err(PacketError::PacketTimeoutNotReached {
                 timeout_height: msg.packet.timeout_height_on_b,
-                chain_height: msg.proof_height_on_b,
+                chain_height: height_of_b,
                 timeout_timestamp: msg.packet.timeout_timestamp_on_b,
                 chain_timestamp: timestamp_of_b,
             }
@@ -221,28 +243,42 @@ where
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
 
-                client_state_of_b_on_a.verify_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
+                verifier_of_b_on_a.verify_membership(
                     Path::SeqRecv(seq_recv_path_on_b),
                     msg.packet.seq_on_a.to_vec(),
                 )
             }
-            Order::Unordered => {
+            // The counterparty moved past the packet, which it must then have
+            // recorded the timeout of rather than received.
+            Order::OrderedAllowTimeout if msg.packet.seq_on_a < msg.next_seq_recv_on_b => {
                 let receipt_path_on_b = ReceiptPath::new(
                     &msg.packet.port_id_on_b,
                     &msg.packet.chan_id_on_b,
                     msg.packet.seq_on_a,
                 );
 
-                client_state_of_b_on_a.verify_non_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
-                    Path::Receipt(receipt_path_on_b),
+                verifier_of_b_on_a
+                    .verify_membership(Path::Receipt(receipt_path_on_b), Receipt::Timeout.to_vec())
+            }
+            // Otherwise, the counterparty has not reached the packet yet.
+            Order::OrderedAllowTimeout => {
+                let seq_recv_path_on_b =
+                    SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+
+                verifier_of_b_on_a.verify_membership(
+                    Path::SeqRecv(seq_recv_path_on_b),
+                    msg.next_seq_recv_on_b.to_vec(),
                 )
             }
+            Order::Unordered => {
+                let receipt_path_on_b = ReceiptPath::new(
+                    &msg.packet.port_id_on_b,
+                    &msg.packet.chan_id_on_b,
+                    msg.packet.seq_on_a,
+                );
+
+                verifier_of_b_on_a.verify_non_membership(Path::Receipt(receipt_path_on_b))
+            }
             Order::None => {
                 return Err(ContextError::ChannelError(ChannelError::InvalidOrderType {
                     expected: "Channel ordering cannot be None".to_string(),
@@ -261,3 +297,25 @@ where
 
     Ok(())
 }
+
+/// Checks that the packet is the next one to be acknowledged, which an ordered
+/// allow timeout channel requires of timed out packets too.
+pub(super) fn verify_next_sequence_ack<Ctx>(
+    ctx_a: &Ctx,
+    packet: &Packet,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+    let next_seq_ack = ctx_a.get_next_sequence_ack(&seq_ack_path_on_a)?;
+    if packet.seq_on_a != next_seq_ack {
+        return Err(PacketError::InvalidPacketSequence {
+            given_sequence: packet.seq_on_a,
+            next_sequence: next_seq_ack,
+        }
+        .into());
+    }
+
+    Ok(())
+}
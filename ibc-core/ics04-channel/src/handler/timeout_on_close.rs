@@ -2,16 +2,17 @@ use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::msgs::MsgTimeoutOnClose;
-use ibc_core_client::context::prelude::*;
+use ibc_core_channel_types::packet::Receipt;
 use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_connection::verify::CounterpartyVerifier;
 use ibc_core_handler_types::error::ContextError;
-use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
-};
+use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath, Path, ReceiptPath, SeqRecvPath};
 use ibc_core_host::ValidationContext;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::timeout::verify_next_sequence_ack;
+
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgTimeoutOnClose) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -56,29 +57,21 @@ where
         .into());
     }
 
+    if let Order::OrderedAllowTimeout = chan_end_on_a.ordering {
+        verify_next_sequence_ack(ctx_a, packet)?;
+    }
+
+    // Timeouts on close are only supported over a single connection hop.
+    chan_end_on_a.verify_connection_hops_length()?;
+
     let conn_id_on_a = chan_end_on_a.connection_hops()[0].clone();
-    let conn_end_on_a = ctx_a.connection_end(&conn_id_on_a)?;
+    let conn_end_on_a = ctx_a.resolve_connection_end(&conn_id_on_a)?;
 
     // Verify proofs
     {
-        let client_id_on_a = conn_end_on_a.client_id();
-        let client_val_ctx_a = ctx_a.get_client_validation_context();
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+        let verifier_of_b_on_a =
+            CounterpartyVerifier::new(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
 
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
-
-        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
-
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let prefix_on_b = conn_end_on_a.counterparty().prefix();
         let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
         let chan_id_on_b = chan_end_on_a
             .counterparty()
@@ -94,23 +87,25 @@ where
             packet.port_id_on_a.clone(),
             Some(packet.chan_id_on_a.clone()),
         );
-        let expected_chan_end_on_b = ChannelEnd::new(
-            State::Closed,
-            *chan_end_on_a.ordering(),
-            expected_counterparty,
-            expected_conn_hops_on_b,
-            chan_end_on_a.version().clone(),
-        )?;
+        let expected_chan_end_on_b = {
+            let mut chan_end_on_b = ChannelEnd::new(
+                State::Closed,
+                *chan_end_on_a.ordering(),
+                expected_counterparty,
+                expected_conn_hops_on_b,
+                chan_end_on_a.version().clone(),
+            )?;
+            chan_end_on_b.set_upgrade_sequence(msg.counterparty_upgrade_sequence);
+            chan_end_on_b
+        };
 
         let chan_end_path_on_b = ChannelEndPath(port_id_on_b, chan_id_on_b.clone());
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a
+        verifier_of_b_on_a
             .verify_membership(
-                prefix_on_b,
                 &msg.proof_close_on_b,
-                consensus_state_of_b_on_a.root(),
                 Path::ChannelEnd(chan_end_path_on_b),
                 expected_chan_end_on_b.encode_vec(),
             )
@@ -120,6 +115,32 @@ where
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
         let next_seq_recv_verification_result = match chan_end_on_a.ordering {
+            // The counterparty may have recorded the timeout of the packet
+            // before its channel end was closed.
+            Order::OrderedAllowTimeout if packet.seq_on_a < msg.next_seq_recv_on_b => {
+                let receipt_path_on_b = ReceiptPath::new(
+                    &msg.packet.port_id_on_b,
+                    &msg.packet.chan_id_on_b,
+                    msg.packet.seq_on_a,
+                );
+
+                verifier_of_b_on_a.verify_membership(
+                    &msg.proof_unreceived_on_b,
+                    Path::Receipt(receipt_path_on_b),
+                    Receipt::Timeout.to_vec(),
+                )
+            }
+            // Otherwise, the counterparty has not reached the packet yet.
+            Order::OrderedAllowTimeout => {
+                let seq_recv_path_on_b =
+                    SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+
+                verifier_of_b_on_a.verify_membership(
+                    &msg.proof_unreceived_on_b,
+                    Path::SeqRecv(seq_recv_path_on_b),
+                    msg.next_seq_recv_on_b.to_vec(),
+                )
+            }
             Order::Ordered => {
                 if packet.seq_on_a < msg.next_seq_recv_on_b {
                     return Err(PacketError::InvalidPacketSequence {
@@ -131,10 +152,8 @@ where
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
 
-                client_state_of_b_on_a.verify_membership(
-                    conn_end_on_a.counterparty().prefix(),
+                verifier_of_b_on_a.verify_membership(
                     &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
                     Path::SeqRecv(seq_recv_path_on_b),
                     packet.seq_on_a.to_vec(),
                 )
@@ -146,10 +165,8 @@ where
                     msg.packet.seq_on_a,
                 );
 
-                client_state_of_b_on_a.verify_non_membership(
-                    conn_end_on_a.counterparty().prefix(),
+                verifier_of_b_on_a.verify_non_membership(
                     &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
                     Path::Receipt(receipt_path_on_b),
                 )
             }
@@ -0,0 +1,353 @@
+//! Helpers shared by the handlers of the channel upgrade handshake.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Order, State};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::{UpgradeError, UpgradeOpen};
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::types::identifiers::{ChannelId, PortId};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, CounterpartyUpgradePath,
+    SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+
+/// Checks that the proposed upgrade fields actually change the channel end,
+/// and that the change of ordering, if any, is supported.
+pub(super) fn verify_upgrade_fields(
+    chan_end: &ChannelEnd,
+    fields: &UpgradeFields,
+) -> Result<(), ChannelError> {
+    if fields.is_identical_to(chan_end) {
+        return Err(ChannelError::IdenticalUpgradeFields);
+    }
+
+    // An unordered channel cannot become ordered, as the packets sent
+    // before the upgrade may have been received in any order.
+    if chan_end.ordering == Order::Unordered
+        && matches!(fields.ordering, Order::Ordered | Order::OrderedAllowTimeout)
+    {
+        return Err(ChannelError::UnsupportedOrderingUpgrade {
+            from: chan_end.ordering,
+            to: fields.ordering,
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that the upgrade proposed by this chain is compatible with the one
+/// proposed by the counterparty.
+///
+/// A failure here does not fail the message: the upgrade is aborted instead,
+/// which is why the error is wrapped into [`ChannelError::UpgradeAborted`].
+pub(super) fn verify_upgrade_compatibility(
+    proposed: &UpgradeFields,
+    counterparty: &UpgradeFields,
+    proposed_conn_end: &ConnectionEnd,
+    upgrade_sequence: u64,
+) -> Result<(), ChannelError> {
+    let abort = |description: String| ChannelError::UpgradeAborted {
+        upgrade_sequence,
+        description,
+    };
+
+    if proposed.ordering != counterparty.ordering {
+        return Err(abort(format!(
+            "proposed ordering `{}` does not match the counterparty ordering `{}`",
+            proposed.ordering, counterparty.ordering
+        )));
+    }
+
+    if proposed.version != counterparty.version {
+        return Err(abort(format!(
+            "proposed version `{}` does not match the counterparty version `{}`",
+            proposed.version, counterparty.version
+        )));
+    }
+
+    if proposed_conn_end.state() != &ConnectionState::Open {
+        return Err(abort(format!(
+            "proposed connection `{}` is not OPEN",
+            proposed.connection_hops[0]
+        )));
+    }
+
+    if proposed_conn_end.counterparty().connection_id() != counterparty.connection_hops.first() {
+        return Err(abort(format!(
+            "counterparty of the proposed connection `{}` does not match the counterparty connection hops",
+            proposed.connection_hops[0]
+        )));
+    }
+
+    Ok(())
+}
+
+/// Moves the channel end to `FLUSHING`, recording in the upgrade the next
+/// sequence to be sent and the timeout the counterparty must respect.
+pub(super) fn start_flushing<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    chan_end: &mut ChannelEnd,
+    upgrade: &mut Upgrade,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    chan_end.set_state(State::Flushing);
+
+    let seq_send_path = SeqSendPath::new(port_id, channel_id);
+    upgrade.next_sequence_send = ctx.get_next_sequence_send(&seq_send_path)?;
+
+    let timeout_timestamp = (ctx.host_timestamp()? + ctx.channel_upgrade_timeout())
+        .map_err(|_| ChannelError::InvalidUpgradeTimeout)?;
+    upgrade.timeout = Some(UpgradeTimeout::new(
+        TimeoutHeight::Never,
+        timeout_timestamp,
+    )?);
+
+    Ok(())
+}
+
+/// Restores the channel end to `OPEN` with its current parameters and the
+/// given upgrade sequence, and removes all upgrade related state.
+pub(super) fn restore_channel<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    upgrade_sequence: u64,
+) -> Result<ChannelEnd, ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let chan_end_path = ChannelEndPath::new(port_id, channel_id);
+    let mut chan_end = ctx.channel_end(&chan_end_path)?;
+
+    chan_end.set_state(State::Open);
+    chan_end.set_upgrade_sequence(upgrade_sequence);
+
+    ctx.store_channel(&chan_end_path, chan_end.clone())?;
+    delete_upgrade_info(ctx, port_id, channel_id)?;
+
+    Ok(chan_end)
+}
+
+/// Removes the upgrade and counterparty upgrade stored for the channel end.
+pub(super) fn delete_upgrade_info<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx.delete_channel_upgrade(&ChannelUpgradePath::new(port_id, channel_id))?;
+    ctx.delete_counterparty_upgrade(&CounterpartyUpgradePath::new(port_id, channel_id))?;
+
+    Ok(())
+}
+
+/// Writes an error receipt for the given upgrade sequence, allowing the
+/// counterparty to cancel the upgrade on its end, and emits the corresponding
+/// event.
+pub(super) fn write_error_receipt<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    chan_end: &ChannelEnd,
+    upgrade_sequence: u64,
+    message: String,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let receipt_path = ChannelUpgradeErrorPath::new(port_id, channel_id);
+    ctx.store_channel_upgrade_error(
+        &receipt_path,
+        ErrorReceipt::new(upgrade_sequence, message.clone()),
+    )?;
+
+    let port_id_on_cp = chan_end.counterparty().port_id.clone();
+    let chan_id_on_cp = chan_end
+        .counterparty()
+        .channel_id
+        .clone()
+        .ok_or(ChannelError::MissingCounterparty)?;
+
+    ctx.log_message(format!(
+        "channel upgrade aborted at sequence {upgrade_sequence}: {message}"
+    ))?;
+    ctx.emit_ibc_event(IbcEvent::UpgradeErrorChannel(UpgradeError::new(
+        port_id.clone(),
+        channel_id.clone(),
+        port_id_on_cp,
+        chan_id_on_cp,
+        upgrade_sequence,
+        message,
+    )))?;
+
+    Ok(())
+}
+
+/// Aborts an ongoing upgrade: the channel end is restored to `OPEN`, any
+/// stored upgrade is removed, and an error receipt is written for the
+/// counterparty.
+///
+/// The receipt is written at the upgrade sequence carried by a
+/// [`ChannelError::UpgradeAborted`] error, which may lag behind the one of
+/// the channel end, or at the one of the channel end otherwise.
+pub(super) fn abort_upgrade<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    error: ChannelError,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let chan_end_path = ChannelEndPath::new(port_id, channel_id);
+    let upgrade_sequence = ctx.channel_end(&chan_end_path)?.upgrade_sequence();
+
+    let chan_end = restore_channel(ctx, port_id, channel_id, upgrade_sequence)?;
+
+    let (receipt_sequence, message) = match error {
+        ChannelError::UpgradeAborted {
+            upgrade_sequence,
+            description,
+        } => (upgrade_sequence, description),
+        e => (upgrade_sequence, e.to_string()),
+    };
+
+    write_error_receipt(
+        ctx,
+        port_id,
+        channel_id,
+        &chan_end,
+        receipt_sequence,
+        message,
+    )
+}
+
+/// Completes the upgrade: the channel end switches to the upgraded
+/// parameters and goes back to `OPEN`.
+pub(super) fn open_upgrade<Ctx>(
+    ctx: &mut Ctx,
+    module: &mut dyn Module,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let chan_end_path = ChannelEndPath::new(port_id, channel_id);
+    let mut chan_end = ctx.channel_end(&chan_end_path)?;
+    let upgrade = ctx.channel_upgrade(&ChannelUpgradePath::new(port_id, channel_id))?;
+    let fields = upgrade.fields;
+
+    // state changes
+    {
+        chan_end.set_state(State::Open);
+        chan_end.set_ordering(fields.ordering);
+        chan_end.set_connection_hops(fields.connection_hops.clone());
+        chan_end.set_version(fields.version.clone());
+
+        ctx.store_channel(&chan_end_path, chan_end.clone())?;
+        delete_upgrade_info(ctx, port_id, channel_id)?;
+    }
+
+    let extras = module.on_chan_upgrade_open_execute(
+        port_id,
+        channel_id,
+        fields.ordering,
+        &fields.connection_hops,
+        &fields.version,
+    );
+
+    // emit events and logs
+    {
+        ctx.log_message("success: channel upgrade open".to_string())?;
+
+        let port_id_on_cp = chan_end.counterparty().port_id.clone();
+        let chan_id_on_cp = chan_end
+            .counterparty()
+            .channel_id
+            .clone()
+            .ok_or(ChannelError::MissingCounterparty)?;
+
+        ctx.emit_ibc_event(IbcEvent::UpgradeOpenChannel(UpgradeOpen::new(
+            port_id.clone(),
+            channel_id.clone(),
+            port_id_on_cp,
+            chan_id_on_cp,
+            State::Open,
+            fields.connection_hops,
+            fields.version,
+            fields.ordering,
+            chan_end.upgrade_sequence(),
+        )))?;
+
+        for module_event in extras.events {
+            ctx.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Updates the flushing status of a channel end after one of its in-flight
+/// packets got acknowledged or timed out: the upgrade is aborted if the
+/// counterparty upgrade timeout elapsed, otherwise the channel end moves to
+/// `FLUSHCOMPLETE` once no packets are left in flight.
+pub(super) fn handle_flush_state<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let chan_end_path = ChannelEndPath::new(port_id, channel_id);
+    let mut chan_end = ctx.channel_end(&chan_end_path)?;
+
+    if chan_end.state != State::Flushing {
+        return Ok(());
+    }
+
+    // The counterparty upgrade is only known once the counterparty started
+    // flushing as well.
+    let counterparty_upgrade_path = CounterpartyUpgradePath::new(port_id, channel_id);
+    let counterparty_upgrade = match ctx.counterparty_upgrade(&counterparty_upgrade_path) {
+        Ok(counterparty_upgrade) => counterparty_upgrade,
+        Err(ContextError::ChannelError(ChannelError::CounterpartyUpgradeNotFound { .. })) => {
+            return Ok(())
+        }
+        Err(e) => return Err(e),
+    };
+
+    let timeout = counterparty_upgrade.timeout()?;
+
+    if timeout.has_expired(ctx.host_height()?, &ctx.host_timestamp()?) {
+        abort_upgrade(
+            ctx,
+            port_id,
+            channel_id,
+            ChannelError::UpgradeTimeoutElapsed,
+        )?;
+    } else if !ctx.has_inflight_packets(&chan_end_path)? {
+        chan_end.set_state(State::FlushComplete);
+        ctx.store_channel(&chan_end_path, chan_end)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::v2::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::v2::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::v2::events::AcknowledgePacket;
+use ibc_core_channel_types::v2::msgs::MsgAcknowledgement;
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::metering::CostEvent;
+use ibc_core_host::types::path::{AckV2Path, CommitmentV2Path, Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+
+use super::{
+    client_state_at, emit_module_extras, payload_route, payload_route_mut, verify_counterparty,
+};
+
+/// Validates the acknowledgement of an IBC v2 packet sent by the host, proven
+/// to be written by the counterparty of the source client of the packet.
+pub fn acknowledgement_packet_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    router: &impl Router,
+    msg: MsgAcknowledgement,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let packet = &msg.packet;
+
+    let counterparty = verify_counterparty(ctx_a, &packet.client_id_on_a, &packet.client_id_on_b)?;
+
+    // A packet whose commitment was already deleted is a no-op.
+    let commitment_path_on_a = CommitmentV2Path::new(&packet.client_id_on_a, packet.seq_on_a);
+    let Ok(commitment_on_a) = ctx_a.get_packet_commitment_v2(&commitment_path_on_a) else {
+        return Ok(());
+    };
+
+    if commitment_on_a != compute_packet_commitment(packet) {
+        return Err(PacketError::IncorrectPacketCommitment {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    let acknowledgement = &msg.acknowledgement;
+    if !acknowledgement.is_universal_error()
+        && acknowledgement.app_acknowledgements().len() != packet.payloads.len()
+    {
+        return Err(PacketError::MismatchedAcknowledgementCount {
+            expected: packet.payloads.len(),
+            actual: acknowledgement.app_acknowledgements().len(),
+        }
+        .into());
+    }
+
+    // Verify proofs
+    {
+        let (client_state_of_b_on_a, client_cons_state_path_on_a) =
+            client_state_at(ctx_a, &packet.client_id_on_a, msg.proof_height_on_b)?;
+
+        let ack_path_on_b = AckV2Path::new(&packet.client_id_on_b, packet.seq_on_a);
+
+        ctx_a.charge(CostEvent::proof_verified(&msg.proof_acked_on_b))?;
+        client_state_of_b_on_a
+            .verify_membership_at_height(
+                ctx_a.get_client_validation_context(),
+                &client_cons_state_path_on_a,
+                &counterparty.commitment_prefix,
+                &msg.proof_acked_on_b,
+                Path::AckV2(ack_path_on_b),
+                compute_ack_commitment(acknowledgement).into_vec(),
+            )
+            .map_err(|e| ChannelError::PacketVerificationFailed {
+                sequence: packet.seq_on_a,
+                client_error: e,
+            })
+            .map_err(PacketError::Channel)?;
+    }
+
+    for (index, payload) in packet.payloads.iter().enumerate() {
+        payload_route(router, &payload.port_id_on_a)?.on_acknowledgement_payload_validate(
+            &packet.client_id_on_a,
+            &packet.client_id_on_b,
+            packet.seq_on_a,
+            payload,
+            app_acknowledgement(acknowledgement, index)?,
+            &msg.signer,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Acknowledges an IBC v2 packet sent by the host, passing the acknowledgement
+/// of each of its payloads to the module bound to its port, and deletes the
+/// commitment to the packet.
+pub fn acknowledgement_packet_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    router: &mut impl Router,
+    msg: MsgAcknowledgement,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let packet = &msg.packet;
+
+    // Check if another relayer already relayed the acknowledgement.
+    // We don't want to fail the transaction in this case.
+    let commitment_path_on_a = CommitmentV2Path::new(&packet.client_id_on_a, packet.seq_on_a);
+    if ctx_a
+        .get_packet_commitment_v2(&commitment_path_on_a)
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    let mut module_extras = Vec::with_capacity(packet.payloads.len());
+
+    for (index, payload) in packet.payloads.iter().enumerate() {
+        let (extras, cb_result) = payload_route_mut(router, &payload.port_id_on_a)?
+            .on_acknowledgement_payload_execute(
+                &packet.client_id_on_a,
+                &packet.client_id_on_b,
+                packet.seq_on_a,
+                payload,
+                app_acknowledgement(&msg.acknowledgement, index)?,
+                &msg.signer,
+            );
+
+        cb_result?;
+
+        module_extras.push(extras);
+    }
+
+    // state changes
+    ctx_a.delete_packet_commitment_v2(&commitment_path_on_a)?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: packet acknowledgement".to_string())?;
+
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(IbcEvent::AcknowledgePacketV2(AcknowledgePacket::new(
+            msg.packet,
+        )))?;
+
+        emit_module_extras(ctx_a, module_extras)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the acknowledgement of the payload at the given index, which the
+/// acknowledgement must hold once its count was checked against the payloads.
+fn app_acknowledgement(
+    acknowledgement: &Acknowledgement,
+    payload_index: usize,
+) -> Result<&[u8], PacketError> {
+    acknowledgement
+        .app_acknowledgement(payload_index)
+        .ok_or(PacketError::InvalidAcknowledgement)
+}
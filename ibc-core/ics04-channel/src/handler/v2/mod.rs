@@ -0,0 +1,124 @@
+//! This module implements the processing logic of the IBC v2 packet
+//! messages, which are sent between two clients that are each other's
+//! counterparty rather than over a channel.
+//!
+//! Each payload of a packet is routed to the [`PayloadModule`] bound to its
+//! port by the [`Router`].
+mod acknowledgement;
+mod recv_packet;
+mod send_packet;
+mod timeout;
+
+pub use acknowledgement::*;
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::{CounterpartyInfo, Height};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::types::identifiers::{ClientId, PortId};
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientCounterpartyPath};
+use ibc_core_host::{ClientStateRef, ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_core_router::types::error::RouterError;
+use ibc_core_router::types::module::ModuleExtras;
+use ibc_core_router::v2::PayloadModule;
+use ibc_primitives::prelude::*;
+pub use recv_packet::*;
+pub use send_packet::*;
+pub use timeout::*;
+
+/// Returns the counterparty registered for the given client of the host,
+/// after checking that it is the client on the other end of the packet.
+fn verify_counterparty<Ctx>(
+    ctx: &Ctx,
+    client_id: &ClientId,
+    counterparty_client_id: &ClientId,
+) -> Result<CounterpartyInfo, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let counterparty = ctx.client_counterparty(&ClientCounterpartyPath::new(client_id.clone()))?;
+
+    if &counterparty.client_id != counterparty_client_id {
+        return Err(PacketError::MismatchedCounterpartyClient {
+            expected: counterparty.client_id,
+            actual: counterparty_client_id.clone(),
+        }
+        .into());
+    }
+
+    Ok(counterparty)
+}
+
+/// Returns the state of the given client of the host, after checking that it
+/// is active and can verify proofs at the given height, along with the path
+/// of its consensus state at that height.
+fn client_state_at<Ctx>(
+    ctx: &Ctx,
+    client_id: &ClientId,
+    proof_height: Height,
+) -> Result<(ClientStateRef<Ctx>, ClientConsensusStatePath), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let client_val_ctx = ctx.get_client_validation_context();
+    let client_state = client_val_ctx.client_state(client_id)?;
+
+    client_state
+        .status(client_val_ctx, client_id)?
+        .verify_is_active()?;
+
+    client_state.validate_proof_height(proof_height)?;
+
+    let client_cons_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        proof_height.revision_number(),
+        proof_height.revision_height(),
+    );
+
+    Ok((client_state, client_cons_state_path))
+}
+
+fn payload_route<'a>(
+    router: &'a impl Router,
+    port_id: &PortId,
+) -> Result<&'a dyn PayloadModule, ContextError> {
+    Ok(router
+        .get_payload_route(port_id)
+        .ok_or(RouterError::UnknownPort {
+            port_id: port_id.clone(),
+        })?)
+}
+
+fn payload_route_mut<'a>(
+    router: &'a mut impl Router,
+    port_id: &PortId,
+) -> Result<&'a mut dyn PayloadModule, ContextError> {
+    Ok(router
+        .get_payload_route_mut(port_id)
+        .ok_or(RouterError::UnknownPort {
+            port_id: port_id.clone(),
+        })?)
+}
+
+/// Emits the events and logs of the modules the payloads of a packet were
+/// routed to.
+fn emit_module_extras<Ctx>(
+    ctx: &mut Ctx,
+    module_extras: Vec<ModuleExtras>,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    for extras in module_extras {
+        for module_event in extras.events {
+            ctx.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::v2::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::v2::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::v2::events::{ReceivePacket, WriteAcknowledgement};
+use ibc_core_channel_types::v2::msgs::MsgRecvPacket;
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::metering::CostEvent;
+use ibc_core_host::types::path::{AckV2Path, CommitmentV2Path, Path, ReceiptV2Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_core_router::v2::RecvPayloadResult;
+use ibc_primitives::prelude::*;
+
+use super::{
+    client_state_at, emit_module_extras, payload_route, payload_route_mut, verify_counterparty,
+};
+
+/// Validates the receipt of an IBC v2 packet, proven to be committed to by the
+/// counterparty of the destination client of the packet.
+pub fn recv_packet_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    router: &impl Router,
+    msg: MsgRecvPacket,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    let packet = &msg.packet;
+
+    let counterparty = verify_counterparty(ctx_b, &packet.client_id_on_b, &packet.client_id_on_a)?;
+
+    let latest_timestamp = ctx_b.host_timestamp()?;
+    if packet.timed_out(&latest_timestamp) {
+        return Err(PacketError::LowPacketTimestamp.into());
+    }
+
+    // A packet that was already received is a no-op.
+    let receipt_path_on_b = ReceiptV2Path::new(&packet.client_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_receipt_v2(&receipt_path_on_b).is_ok() {
+        return Ok(());
+    }
+
+    // Verify proofs
+    {
+        let (client_state_of_a_on_b, client_cons_state_path_on_b) =
+            client_state_at(ctx_b, &packet.client_id_on_b, msg.proof_height_on_a)?;
+
+        let commitment_path_on_a = CommitmentV2Path::new(&packet.client_id_on_a, packet.seq_on_a);
+
+        ctx_b.charge(CostEvent::proof_verified(&msg.proof_commitment_on_a))?;
+        client_state_of_a_on_b
+            .verify_membership_at_height(
+                ctx_b.get_client_validation_context(),
+                &client_cons_state_path_on_b,
+                &counterparty.commitment_prefix,
+                &msg.proof_commitment_on_a,
+                Path::CommitmentV2(commitment_path_on_a),
+                compute_packet_commitment(packet).into_vec(),
+            )
+            .map_err(|e| ChannelError::PacketVerificationFailed {
+                sequence: packet.seq_on_a,
+                client_error: e,
+            })
+            .map_err(PacketError::Channel)?;
+    }
+
+    for payload in &packet.payloads {
+        payload_route(router, &payload.port_id_on_b)?;
+    }
+
+    Ok(())
+}
+
+/// Receives an IBC v2 packet, passing each of its payloads to the module bound
+/// to its port, and writes the acknowledgement of the packet.
+///
+/// Once a payload fails to be received, the next ones are not passed to
+/// their modules, and the packet is acknowledged with the universal error
+/// acknowledgement.
+pub fn recv_packet_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    router: &mut impl Router,
+    msg: MsgRecvPacket,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let packet = msg.packet;
+
+    // Check if another relayer already relayed the packet.
+    // We don't want to fail the transaction in this case.
+    let receipt_path_on_b = ReceiptV2Path::new(&packet.client_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_receipt_v2(&receipt_path_on_b).is_ok() {
+        return Ok(());
+    }
+
+    let mut app_acknowledgements = Vec::with_capacity(packet.payloads.len());
+    let mut module_extras = Vec::with_capacity(packet.payloads.len());
+    let mut failed = false;
+
+    for payload in &packet.payloads {
+        let (extras, result) = payload_route_mut(router, &payload.port_id_on_b)?
+            .on_recv_payload_execute(
+                &packet.client_id_on_a,
+                &packet.client_id_on_b,
+                packet.seq_on_a,
+                payload,
+                &msg.signer,
+            );
+
+        module_extras.push(extras);
+
+        match result {
+            RecvPayloadResult::Success(app_acknowledgement) => {
+                app_acknowledgements.push(app_acknowledgement)
+            }
+            RecvPayloadResult::Failure => {
+                failed = true;
+                break;
+            }
+        }
+    }
+
+    let acknowledgement = if failed {
+        Acknowledgement::universal_error()
+    } else {
+        Acknowledgement::new(app_acknowledgements)?
+    };
+
+    // state changes
+    {
+        ctx_b.store_packet_receipt_v2(&receipt_path_on_b, Receipt::Ok)?;
+
+        let ack_path_on_b = AckV2Path::new(&packet.client_id_on_b, packet.seq_on_a);
+        ctx_b.store_packet_acknowledgement_v2(
+            &ack_path_on_b,
+            compute_ack_commitment(&acknowledgement),
+        )?;
+    }
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: packet receive".to_string())?;
+        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
+
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(IbcEvent::ReceivePacketV2(ReceivePacket::new(
+            packet.clone(),
+        )))?;
+        ctx_b.emit_ibc_event(IbcEvent::WriteAcknowledgementV2(WriteAcknowledgement::new(
+            packet,
+            acknowledgement,
+        )))?;
+
+        emit_module_extras(ctx_b, module_extras)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::v2::commitment::compute_packet_commitment;
+use ibc_core_channel_types::v2::events::SendPacket;
+use ibc_core_channel_types::v2::msgs::MsgSendPacket;
+use ibc_core_channel_types::v2::packet::Packet;
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{ClientCounterpartyPath, CommitmentV2Path, SeqSendV2Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Expiry;
+
+use super::{emit_module_extras, payload_route, payload_route_mut};
+
+/// Validates the sending of an IBC v2 packet through the client of the
+/// message, to its registered counterparty client.
+pub fn send_packet_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    router: &impl Router,
+    msg: MsgSendPacket,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let counterparty =
+        ctx_a.client_counterparty(&ClientCounterpartyPath::new(msg.client_id_on_a.clone()))?;
+
+    let client_val_ctx_a = ctx_a.get_client_validation_context();
+    let client_state_of_b_on_a = client_val_ctx_a.client_state(&msg.client_id_on_a)?;
+
+    client_state_of_b_on_a
+        .status(client_val_ctx_a, &msg.client_id_on_a)?
+        .verify_is_active()?;
+
+    let latest_timestamp = ctx_a.host_timestamp()?;
+    if let Expiry::Expired = latest_timestamp.check_expiry(&msg.timeout_timestamp_on_b) {
+        return Err(PacketError::LowPacketTimestamp.into());
+    }
+
+    let seq_on_a = ctx_a.get_next_sequence_send_v2(&SeqSendV2Path::new(&msg.client_id_on_a))?;
+
+    for payload in &msg.payloads {
+        payload_route(router, &payload.port_id_on_a)?.on_send_payload_validate(
+            &msg.client_id_on_a,
+            &counterparty.client_id,
+            seq_on_a,
+            payload,
+            &msg.signer,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Sends an IBC v2 packet, committing to it under the next sequence of the
+/// client of the message.
+pub fn send_packet_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    router: &mut impl Router,
+    msg: MsgSendPacket,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let counterparty =
+        ctx_a.client_counterparty(&ClientCounterpartyPath::new(msg.client_id_on_a.clone()))?;
+
+    let seq_send_path_on_a = SeqSendV2Path::new(&msg.client_id_on_a);
+    let seq_on_a = ctx_a.get_next_sequence_send_v2(&seq_send_path_on_a)?;
+
+    let packet = Packet {
+        seq_on_a,
+        client_id_on_a: msg.client_id_on_a,
+        client_id_on_b: counterparty.client_id,
+        timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
+        payloads: msg.payloads,
+    };
+
+    let mut module_extras = Vec::with_capacity(packet.payloads.len());
+
+    for payload in &packet.payloads {
+        let (extras, cb_result) = payload_route_mut(router, &payload.port_id_on_a)?
+            .on_send_payload_execute(
+                &packet.client_id_on_a,
+                &packet.client_id_on_b,
+                seq_on_a,
+                payload,
+                &msg.signer,
+            );
+
+        cb_result?;
+
+        module_extras.push(extras);
+    }
+
+    // state changes
+    {
+        ctx_a.store_next_sequence_send_v2(&seq_send_path_on_a, seq_on_a.increment())?;
+
+        let commitment_path_on_a = CommitmentV2Path::new(&packet.client_id_on_a, seq_on_a);
+        ctx_a.store_packet_commitment_v2(
+            &commitment_path_on_a,
+            compute_packet_commitment(&packet),
+        )?;
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: packet send".to_string())?;
+
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(IbcEvent::SendPacketV2(SendPacket::new(packet)))?;
+
+        emit_module_extras(ctx_a, module_extras)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::v2::commitment::compute_packet_commitment;
+use ibc_core_channel_types::v2::events::TimeoutPacket;
+use ibc_core_channel_types::v2::msgs::MsgTimeout;
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::metering::CostEvent;
+use ibc_core_host::types::path::{CommitmentV2Path, Path, ReceiptV2Path};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+
+use super::{
+    client_state_at, emit_module_extras, payload_route, payload_route_mut, verify_counterparty,
+};
+
+/// Validates the timeout of an IBC v2 packet sent by the host, proven to be
+/// unreceived by the counterparty of the source client of the packet at a
+/// height past its timeout.
+pub fn timeout_packet_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    router: &impl Router,
+    msg: MsgTimeout,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let packet = &msg.packet;
+
+    let counterparty = verify_counterparty(ctx_a, &packet.client_id_on_a, &packet.client_id_on_b)?;
+
+    // A packet whose commitment was already deleted is a no-op.
+    let commitment_path_on_a = CommitmentV2Path::new(&packet.client_id_on_a, packet.seq_on_a);
+    let Ok(commitment_on_a) = ctx_a.get_packet_commitment_v2(&commitment_path_on_a) else {
+        return Ok(());
+    };
+
+    if commitment_on_a != compute_packet_commitment(packet) {
+        return Err(PacketError::IncorrectPacketCommitment {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    // Verify proofs
+    {
+        let (client_state_of_b_on_a, client_cons_state_path_on_a) =
+            client_state_at(ctx_a, &packet.client_id_on_a, msg.proof_height_on_b)?;
+
+        let timestamp_of_b = client_state_of_b_on_a.timestamp_at_height(
+            ctx_a.get_client_validation_context(),
+            &client_cons_state_path_on_a,
+        )?;
+        if !packet.timed_out(&timestamp_of_b) {
+            return Err(PacketError::PacketTimeoutTimestampNotReached {
+                timeout_timestamp: packet.timeout_timestamp_on_b,
+                chain_timestamp: timestamp_of_b,
+            }
+            .into());
+        }
+
+        let receipt_path_on_b = ReceiptV2Path::new(&packet.client_id_on_b, packet.seq_on_a);
+
+        ctx_a.charge(CostEvent::proof_verified(&msg.proof_unreceived_on_b))?;
+        client_state_of_b_on_a
+            .verify_non_membership_at_height(
+                ctx_a.get_client_validation_context(),
+                &client_cons_state_path_on_a,
+                &counterparty.commitment_prefix,
+                &msg.proof_unreceived_on_b,
+                Path::ReceiptV2(receipt_path_on_b),
+            )
+            .map_err(|e| ChannelError::PacketVerificationFailed {
+                sequence: packet.seq_on_a,
+                client_error: e,
+            })
+            .map_err(PacketError::Channel)?;
+    }
+
+    for payload in &packet.payloads {
+        payload_route(router, &payload.port_id_on_a)?.on_timeout_payload_validate(
+            &packet.client_id_on_a,
+            &packet.client_id_on_b,
+            packet.seq_on_a,
+            payload,
+            &msg.signer,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Times out an IBC v2 packet sent by the host, notifying the module bound to
+/// the port of each of its payloads, and deletes the commitment to the packet.
+pub fn timeout_packet_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    router: &mut impl Router,
+    msg: MsgTimeout,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let packet = msg.packet;
+
+    // Check if another relayer already relayed the timeout.
+    // We don't want to fail the transaction in this case.
+    let commitment_path_on_a = CommitmentV2Path::new(&packet.client_id_on_a, packet.seq_on_a);
+    if ctx_a
+        .get_packet_commitment_v2(&commitment_path_on_a)
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    let mut module_extras = Vec::with_capacity(packet.payloads.len());
+
+    for payload in &packet.payloads {
+        let (extras, cb_result) = payload_route_mut(router, &payload.port_id_on_a)?
+            .on_timeout_payload_execute(
+                &packet.client_id_on_a,
+                &packet.client_id_on_b,
+                packet.seq_on_a,
+                payload,
+                &msg.signer,
+            );
+
+        cb_result?;
+
+        module_extras.push(extras);
+    }
+
+    // state changes
+    ctx_a.delete_packet_commitment_v2(&commitment_path_on_a)?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: packet timeout".to_string())?;
+
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(IbcEvent::TimeoutPacketV2(TimeoutPacket::new(packet)))?;
+
+        emit_module_extras(ctx_a, module_extras)?;
+    }
+
+    Ok(())
+}
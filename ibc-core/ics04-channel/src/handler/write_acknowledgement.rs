@@ -0,0 +1,139 @@
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{Order, State as ChannelState};
+use ibc_core_channel_types::commitment::compute_ack_commitment;
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::events::WriteAcknowledgement;
+use ibc_core_channel_types::packet::{Packet, Receipt};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{AckPath, ChannelEndPath, ReceiptPath, SeqRecvPath};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+/// Writes the acknowledgement of a received packet, including all necessary
+/// validation.
+///
+/// This is meant for modules which deferred the acknowledgement of the
+/// packet by returning none from `on_recv_packet_execute`, e.g. until the
+/// outcome of some other operation is known. It may be called in any later
+/// block, as long as no acknowledgement was written for the packet yet.
+///
+/// Equivalent to calling [`write_acknowledgement_validate`], followed by
+/// [`write_acknowledgement_execute`]
+pub fn write_acknowledgement<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: &Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    write_acknowledgement_validate(ctx_b, packet)?;
+    write_acknowledgement_execute(ctx_b, packet, acknowledgement)
+}
+
+/// Validate that writing an acknowledgement for the given packet would
+/// succeed: the packet must have been received over an open channel end,
+/// and not be acknowledged yet.
+pub fn write_acknowledgement_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    packet: &Packet,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    // Packets received before an upgrade started are still acknowledged
+    // while the channel end is being upgraded.
+    if !matches!(
+        chan_end_on_b.state,
+        ChannelState::Open | ChannelState::Flushing | ChannelState::FlushComplete
+    ) {
+        return Err(PacketError::InvalidChannelState {
+            channel_id: packet.chan_id_on_b.clone(),
+            state: chan_end_on_b.state,
+        }
+        .into());
+    }
+
+    let receipt_path_on_b =
+        ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+    let packet_received = match chan_end_on_b.ordering {
+        Order::Unordered => matches!(
+            ctx_b.get_packet_receipt(&receipt_path_on_b),
+            Ok(Receipt::Ok)
+        ),
+        Order::Ordered | Order::OrderedAllowTimeout => {
+            let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+            let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
+
+            // Over ordered allow timeout channels, the sequences of the
+            // packets that timed out are skipped without being received.
+            packet.seq_on_a < next_seq_recv
+                && !matches!(
+                    ctx_b.get_packet_receipt(&receipt_path_on_b),
+                    Ok(Receipt::Timeout)
+                )
+        }
+        Order::None => {
+            return Err(ContextError::ChannelError(ChannelError::InvalidOrderType {
+                expected: "Channel ordering cannot be None".to_string(),
+                actual: chan_end_on_b.ordering.to_string(),
+            }))
+        }
+    };
+
+    if !packet_received {
+        return Err(PacketError::PacketNotReceived {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_acknowledgement(&ack_path_on_b).is_ok() {
+        return Err(PacketError::AcknowledgementExists {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Write the acknowledgement of the packet without any validation.
+///
+/// A prior call to [`write_acknowledgement_validate`] MUST have succeeded.
+pub fn write_acknowledgement_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: &Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    ctx_b.store_packet_acknowledgement(&ack_path_on_b, compute_ack_commitment(&acknowledgement))?;
+
+    // emit events and logs
+    {
+        let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+        let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+        let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
+
+        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
+
+        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+            packet.clone(),
+            acknowledgement,
+            conn_id_on_b.clone(),
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(event)?;
+    }
+
+    Ok(())
+}
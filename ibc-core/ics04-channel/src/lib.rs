@@ -18,6 +18,7 @@ extern crate std;
 
 pub mod context;
 pub mod handler;
+pub mod verify;
 
 /// Re-exports ICS-04 data structures from the `ibc-core-channel-types` crate.
 pub mod types {
@@ -0,0 +1,302 @@
+//! Verification of the counterparty's state along the connection hops of a
+//! channel end.
+
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::multihop::MsgMultihopProofs;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_connection::types::{ConnectionEnd, State as ConnectionState};
+use ibc_core_connection::verify::CounterpartyVerifier;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::metering::CostEvent;
+use ibc_core_host::types::identifiers::ConnectionId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ConnectionPath, Path};
+use ibc_core_host::{ClientStateRef, ConsensusStateRef, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+use ibc_primitives::{Timestamp, ToVec};
+
+/// Verifies a proof of the counterparty's state, taken at a given height,
+/// along the connection hops of a channel end.
+///
+/// Over a single connection hop, the proof is verified by the client of the
+/// connection end, as done by [`CounterpartyVerifier`].
+///
+/// Over more than one connection hop, the proof is an encoded
+/// [`MsgMultihopProofs`]. The client of the first connection end only knows
+/// of the first intermediate chain: each intermediate chain is then proven to
+/// store the connection end of the next hop, and the consensus state of the
+/// next chain, down to the counterparty. The proofs are all verified by the
+/// client of the first connection end, which must thus be able to verify
+/// proofs of every chain along the path, against the commitment roots of
+/// their consensus states.
+///
+/// The host is charged for each proof verified by a stored client.
+pub struct ChannelVerifier<'a, Ctx>
+where
+    Ctx: ValidationContext,
+{
+    ctx: &'a Ctx,
+    proof: &'a CommitmentProofBytes,
+    hops: Hops<'a, Ctx>,
+}
+
+enum Hops<'a, Ctx>
+where
+    Ctx: ValidationContext,
+{
+    Single {
+        verifier: CounterpartyVerifier<'a, Ctx>,
+        conn_id: &'a ConnectionId,
+        conn_end: &'a ConnectionEnd,
+        proof_height: Height,
+    },
+    Multi {
+        client_state: ClientStateRef<Ctx>,
+        key_proof: CommitmentProofBytes,
+        prefix: CommitmentPrefix,
+        root: CommitmentRoot,
+        timestamp: Timestamp,
+        height: Height,
+        counterparty_hops: Vec<ConnectionId>,
+    },
+}
+
+impl<'a, Ctx> ChannelVerifier<'a, Ctx>
+where
+    Ctx: ValidationContext,
+{
+    /// Checks that the client of the first connection end can verify proofs
+    /// at the given height and, over more than one hop, verifies the proofs
+    /// of the intermediate chains.
+    ///
+    /// `conn_end` is the connection end of the first hop, stored by the host.
+    pub fn new(
+        ctx: &'a Ctx,
+        connection_hops: &'a [ConnectionId],
+        conn_end: &'a ConnectionEnd,
+        proof: &'a CommitmentProofBytes,
+        proof_height: Height,
+    ) -> Result<Self, ContextError> {
+        let hops = match connection_hops {
+            [] => return Err(ChannelError::EmptyConnectionHops.into()),
+            [conn_id] => Hops::Single {
+                verifier: CounterpartyVerifier::new(ctx, conn_end, proof_height)?,
+                conn_id,
+                conn_end,
+                proof_height,
+            },
+            _ => Self::resolve_hops(ctx, connection_hops, conn_end, proof, proof_height)?,
+        };
+
+        Ok(Self { ctx, proof, hops })
+    }
+
+    fn resolve_hops(
+        ctx: &'a Ctx,
+        connection_hops: &[ConnectionId],
+        conn_end: &'a ConnectionEnd,
+        proof: &'a CommitmentProofBytes,
+        proof_height: Height,
+    ) -> Result<Hops<'a, Ctx>, ContextError> {
+        let client_id = conn_end.client_id();
+
+        if client_id.is_localhost() {
+            return Err(ChannelError::InvalidMultihopProof {
+                reason: "multi-hop channels cannot start over localhost".to_string(),
+            }
+            .into());
+        }
+
+        let proofs = MsgMultihopProofs::try_from(proof)?;
+        proofs.verify_hops_count(connection_hops)?;
+
+        let client_val_ctx = ctx.get_client_validation_context();
+        let client_state = client_val_ctx.client_state(client_id)?;
+
+        client_state
+            .status(client_val_ctx, client_id)?
+            .verify_is_active()?;
+
+        client_state.validate_proof_height(proof_height)?;
+
+        let consensus_state = client_val_ctx.consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            proof_height.revision_number(),
+            proof_height.revision_height(),
+        ))?;
+
+        // Intermediate chains are proven against the roots of the consensus
+        // states along the path, which clients verifying proofs through their
+        // context, such as 08-wasm clients, do not expose.
+        if consensus_state.root().is_empty() {
+            return Err(ChannelError::MultihopUnsupportedClient {
+                client_id: client_id.clone(),
+            }
+            .into());
+        }
+
+        let mut prefix = conn_end.counterparty().prefix().clone();
+        let mut root = consensus_state.root().clone();
+        let mut timestamp = consensus_state.timestamp();
+        let mut height = proof_height;
+        let mut counterparty_hops =
+            vec![counterparty_connection_id(conn_end, &connection_hops[0])?];
+
+        let hop_proofs = proofs
+            .connection_proofs
+            .into_iter()
+            .zip(proofs.consensus_proofs);
+
+        for (hop, (conn_id, (conn_proof, cons_proof))) in
+            (1u64..).zip(connection_hops[1..].iter().zip(hop_proofs))
+        {
+            let hop_conn_end = conn_proof.connection_end;
+
+            ctx.charge(CostEvent::proof_verified(&conn_proof.proof))?;
+            client_state
+                .verify_membership(
+                    &prefix,
+                    &conn_proof.proof,
+                    &root,
+                    Path::Connection(ConnectionPath::new(conn_id)),
+                    hop_conn_end.clone().encode_vec(),
+                )
+                .map_err(|e| ChannelError::MultihopVerificationFailed {
+                    hop,
+                    client_error: e,
+                })?;
+
+            hop_conn_end.verify_state_matches(&ConnectionState::Open)?;
+
+            let cons_height = cons_proof.consensus_height;
+
+            ctx.charge(CostEvent::proof_verified(&cons_proof.proof))?;
+            client_state
+                .verify_membership(
+                    &prefix,
+                    &cons_proof.proof,
+                    &root,
+                    Path::ClientConsensusState(ClientConsensusStatePath::new(
+                        hop_conn_end.client_id().clone(),
+                        cons_height.revision_number(),
+                        cons_height.revision_height(),
+                    )),
+                    cons_proof.consensus_state.to_vec(),
+                )
+                .map_err(|e| ChannelError::MultihopVerificationFailed {
+                    hop,
+                    client_error: e,
+                })?;
+
+            let hop_consensus_state =
+                ConsensusStateRef::<Ctx>::try_from(cons_proof.consensus_state).map_err(|_| {
+                    ChannelError::InvalidMultihopProof {
+                        reason: format!("unknown consensus state type at hop {hop}"),
+                    }
+                })?;
+
+            prefix = hop_conn_end.counterparty().prefix().clone();
+            root = hop_consensus_state.root().clone();
+            timestamp = hop_consensus_state.timestamp();
+            height = cons_height;
+            counterparty_hops.push(counterparty_connection_id(&hop_conn_end, conn_id)?);
+        }
+
+        // The counterparty lists its connection hops from its own end.
+        counterparty_hops.reverse();
+
+        Ok(Hops::Multi {
+            client_state,
+            key_proof: proofs.key_proof,
+            prefix,
+            root,
+            timestamp,
+            height,
+            counterparty_hops,
+        })
+    }
+
+    /// Returns the connection hops of the counterparty channel end, as seen
+    /// from the counterparty.
+    pub fn counterparty_connection_hops(&self) -> Result<Vec<ConnectionId>, ChannelError> {
+        match &self.hops {
+            Hops::Single {
+                conn_id, conn_end, ..
+            } => Ok(vec![counterparty_connection_id(conn_end, conn_id)?]),
+            Hops::Multi {
+                counterparty_hops, ..
+            } => Ok(counterparty_hops.clone()),
+        }
+    }
+
+    /// Returns the height of the counterparty at which the proof was taken.
+    ///
+    /// Over more than one hop, this is the height of the consensus state of
+    /// the counterparty stored by the last intermediate chain.
+    pub fn height(&self) -> Height {
+        match &self.hops {
+            Hops::Single { proof_height, .. } => *proof_height,
+            Hops::Multi { height, .. } => *height,
+        }
+    }
+
+    /// Returns the timestamp of the counterparty at the proof height.
+    pub fn timestamp(&self) -> Result<Timestamp, ContextError> {
+        match &self.hops {
+            Hops::Single { verifier, .. } => verifier.timestamp(),
+            Hops::Multi { timestamp, .. } => Ok(*timestamp),
+        }
+    }
+
+    /// Verifies that the counterparty stores the given value at the given path.
+    pub fn verify_membership(&self, path: Path, value: Vec<u8>) -> Result<(), ClientError> {
+        match &self.hops {
+            Hops::Single { verifier, .. } => verifier.verify_membership(self.proof, path, value),
+            Hops::Multi {
+                client_state,
+                key_proof,
+                prefix,
+                root,
+                ..
+            } => {
+                self.ctx.charge(CostEvent::proof_verified(key_proof))?;
+
+                client_state.verify_membership(prefix, key_proof, root, path, value)
+            }
+        }
+    }
+
+    /// Verifies that the counterparty stores no value at the given path.
+    pub fn verify_non_membership(&self, path: Path) -> Result<(), ClientError> {
+        match &self.hops {
+            Hops::Single { verifier, .. } => verifier.verify_non_membership(self.proof, path),
+            Hops::Multi {
+                client_state,
+                key_proof,
+                prefix,
+                root,
+                ..
+            } => {
+                self.ctx.charge(CostEvent::proof_verified(key_proof))?;
+
+                client_state.verify_non_membership(prefix, key_proof, root, path)
+            }
+        }
+    }
+}
+
+fn counterparty_connection_id(
+    conn_end: &ConnectionEnd,
+    conn_id: &ConnectionId,
+) -> Result<ConnectionId, ChannelError> {
+    conn_end.counterparty().connection_id().cloned().ok_or(
+        ChannelError::UndefinedConnectionCounterparty {
+            connection_id: conn_id.clone(),
+        },
+    )
+}
@@ -53,10 +53,6 @@ impl TryFrom<RawIdentifiedChannel> for IdentifiedChannelEnd {
     type Error = ChannelError;
 
     fn try_from(value: RawIdentifiedChannel) -> Result<Self, Self::Error> {
-        if value.upgrade_sequence != 0 {
-            return Err(ChannelError::UnsupportedChannelUpgradeSequence);
-        }
-
         let raw_channel_end = RawChannel {
             state: value.state,
             ordering: value.ordering,
@@ -89,7 +85,7 @@ impl From<IdentifiedChannelEnd> for RawIdentifiedChannel {
             version: value.channel_end.version.to_string(),
             port_id: value.port_id.to_string(),
             channel_id: value.channel_id.to_string(),
-            upgrade_sequence: 0,
+            upgrade_sequence: value.channel_end.upgrade_sequence,
         }
     }
 }
@@ -116,14 +112,17 @@ pub struct ChannelEnd {
     pub remote: Counterparty,
     pub connection_hops: Vec<ConnectionId>,
     pub version: Version,
+    /// The sequence of the latest upgrade attempt on this channel end. It is
+    /// `0` for channels that have never attempted an upgrade.
+    pub upgrade_sequence: u64,
 }
 
 impl Display for ChannelEnd {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(
             f,
-            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {} }}",
-            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version
+            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {}, upgrade_sequence: {} }}",
+            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version, self.upgrade_sequence
         )
     }
 }
@@ -153,7 +152,11 @@ impl TryFrom<RawChannel> for ChannelEnd {
 
         let version = value.version.into();
 
-        ChannelEnd::new(chan_state, chan_ordering, remote, connection_hops, version)
+        let mut channel_end =
+            ChannelEnd::new(chan_state, chan_ordering, remote, connection_hops, version)?;
+        channel_end.set_upgrade_sequence(value.upgrade_sequence);
+
+        Ok(channel_end)
     }
 }
 
@@ -169,7 +172,7 @@ impl From<ChannelEnd> for RawChannel {
                 .map(|v| v.as_str().to_string())
                 .collect(),
             version: value.version.to_string(),
-            upgrade_sequence: 0,
+            upgrade_sequence: value.upgrade_sequence,
         }
     }
 }
@@ -193,6 +196,7 @@ impl ChannelEnd {
             remote,
             connection_hops,
             version,
+            upgrade_sequence: 0,
         }
     }
 
@@ -223,6 +227,18 @@ impl ChannelEnd {
         self.remote.channel_id = Some(c);
     }
 
+    pub fn set_ordering(&mut self, o: Order) {
+        self.ordering = o;
+    }
+
+    pub fn set_connection_hops(&mut self, hops: Vec<ConnectionId>) {
+        self.connection_hops = hops;
+    }
+
+    pub fn set_upgrade_sequence(&mut self, sequence: u64) {
+        self.upgrade_sequence = sequence;
+    }
+
     /// Returns `true` if this `ChannelEnd` is in state [`State::Open`].
     pub fn is_open(&self) -> bool {
         self.state == State::Open
@@ -248,6 +264,16 @@ impl ChannelEnd {
         &self.version
     }
 
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence
+    }
+
+    /// Returns `true` if this `ChannelEnd` is in the middle of an upgrade,
+    /// i.e. in state [`State::Flushing`] or [`State::FlushComplete`].
+    pub fn is_upgrading(&self) -> bool {
+        matches!(self.state, State::Flushing | State::FlushComplete)
+    }
+
     pub fn validate_basic(&self) -> Result<(), ChannelError> {
         if self.state == State::Uninitialized {
             return Err(ChannelError::InvalidState {
@@ -314,11 +340,22 @@ impl ChannelEnd {
 
     /// Checks if the `connection_hops` has a length of `expected`.
     ///
-    /// Note: Current IBC version only supports one connection hop.
+    /// Note: Channel closing and upgrades only support one connection hop.
     pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
         verify_connection_hops_length(&self.connection_hops, 1)
     }
 
+    /// Checks that the `connection_hops` holds at least one connection.
+    pub fn verify_connection_hops_not_empty(&self) -> Result<(), ChannelError> {
+        verify_connection_hops_not_empty(&self.connection_hops)
+    }
+
+    /// Returns `true` if this `ChannelEnd` runs over more than one connection,
+    /// as an ICS-33 multi-hop channel.
+    pub fn is_multihop(&self) -> bool {
+        self.connection_hops.len() > 1
+    }
+
     pub fn version_matches(&self, other: &Version) -> bool {
         self.version().eq(other)
     }
@@ -338,6 +375,16 @@ pub(crate) fn verify_connection_hops_length(
     Ok(())
 }
 
+/// Checks that the `connection_hops` holds at least one connection.
+pub(crate) fn verify_connection_hops_not_empty(
+    connection_hops: &[ConnectionId],
+) -> Result<(), ChannelError> {
+    if connection_hops.is_empty() {
+        return Err(ChannelError::EmptyConnectionHops);
+    }
+    Ok(())
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -454,6 +501,9 @@ pub enum Order {
     None = 0isize,
     Unordered = 1isize,
     Ordered = 2isize,
+    /// Packets are received in order, but a packet that timed out may be
+    /// skipped by the receiving end without closing the channel.
+    OrderedAllowTimeout = 3isize,
 }
 
 impl Display for Order {
@@ -470,6 +520,7 @@ impl Order {
             Self::None => "ORDER_NONE_UNSPECIFIED",
             Self::Unordered => "ORDER_UNORDERED",
             Self::Ordered => "ORDER_ORDERED",
+            Self::OrderedAllowTimeout => "ORDER_ORDERED_ALLOW_TIMEOUT",
         }
     }
 
@@ -479,8 +530,9 @@ impl Order {
             0 => Ok(Self::None),
             1 => Ok(Self::Unordered),
             2 => Ok(Self::Ordered),
+            3 => Ok(Self::OrderedAllowTimeout),
             _ => Err(ChannelError::InvalidOrderType {
-                expected: "Must be one of 0, 1, 2".to_string(),
+                expected: "Must be one of 0, 1, 2, 3".to_string(),
                 actual: nr.to_string(),
             }),
         }
@@ -495,8 +547,11 @@ impl FromStr for Order {
             "uninitialized" => Ok(Self::None),
             "unordered" => Ok(Self::Unordered),
             "ordered" => Ok(Self::Ordered),
+            "ordered_allow_timeout" => Ok(Self::OrderedAllowTimeout),
             _ => Err(ChannelError::InvalidOrderType {
-                expected: "Must be one of 'uninitialized', 'unordered', 'ordered'".to_string(),
+                expected:
+                    "Must be one of 'uninitialized', 'unordered', 'ordered', 'ordered_allow_timeout'"
+                        .to_string(),
                 actual: s.to_string(),
             }),
         }
@@ -525,6 +580,8 @@ pub enum State {
     TryOpen = 2isize,
     Open = 3isize,
     Closed = 4isize,
+    Flushing = 5isize,
+    FlushComplete = 6isize,
 }
 
 impl State {
@@ -536,6 +593,8 @@ impl State {
             Self::TryOpen => "TRYOPEN",
             Self::Open => "OPEN",
             Self::Closed => "CLOSED",
+            Self::Flushing => "FLUSHING",
+            Self::FlushComplete => "FLUSHCOMPLETE",
         }
     }
 
@@ -547,8 +606,10 @@ impl State {
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
             4 => Ok(Self::Closed),
+            5 => Ok(Self::Flushing),
+            6 => Ok(Self::FlushComplete),
             _ => Err(ChannelError::InvalidState {
-                expected: "Must be one of: 0, 1, 2, 3, 4".to_string(),
+                expected: "Must be one of: 0, 1, 2, 3, 4, 5, 6".to_string(),
                 actual: s.to_string(),
             }),
         }
@@ -579,3 +640,23 @@ impl Display for State {
         write!(f, "{}", self.as_string())
     }
 }
+
+impl FromStr for State {
+    type Err = ChannelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().trim_start_matches("STATE_") {
+            "UNINITIALIZED" | "UNINITIALIZED_UNSPECIFIED" => Ok(Self::Uninitialized),
+            "INIT" => Ok(Self::Init),
+            "TRYOPEN" => Ok(Self::TryOpen),
+            "OPEN" => Ok(Self::Open),
+            "CLOSED" => Ok(Self::Closed),
+            "FLUSHING" => Ok(Self::Flushing),
+            "FLUSHCOMPLETE" => Ok(Self::FlushComplete),
+            _ => Err(ChannelError::InvalidState {
+                expected: "Must be one of: UNINITIALIZED, INIT, TRYOPEN, OPEN, CLOSED, FLUSHING, FLUSHCOMPLETE".to_string(),
+                actual: s.to_string(),
+            }),
+        }
+    }
+}
@@ -108,7 +108,7 @@ pub fn compute_ack_commitment(ack: &Acknowledgement) -> AcknowledgementCommitmen
 ///
 /// Note that computing commitments with anything other than SHA256 will
 /// break the Merkle proofs of the IBC provable store.
-fn hash(data: &[u8]) -> [u8; 32] {
+pub(crate) fn hash(data: &[u8]) -> [u8; 32] {
     use sha2::Digest;
 
     sha2::Sha256::digest(data).into()
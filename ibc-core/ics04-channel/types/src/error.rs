@@ -4,11 +4,11 @@ use displaydoc::Display;
 use ibc_core_client_types::{error as client_error, Height};
 use ibc_core_connection_types::error as connection_error;
 use ibc_core_host_types::error::IdentifierError;
-use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
 use ibc_primitives::{ParseTimestampError, Timestamp};
 
-use super::channel::Counterparty;
+use super::channel::{Counterparty, Order};
 use super::timeout::TimeoutHeight;
 use crate::channel::State;
 use crate::Version;
@@ -25,6 +25,17 @@ pub enum ChannelError {
     InvalidOrderType { expected: String, actual: String },
     /// invalid connection hops length: expected `{expected}`; actual `{actual}`
     InvalidConnectionHopsLength { expected: u64, actual: u64 },
+    /// empty connection hops
+    EmptyConnectionHops,
+    /// invalid multi-hop proof: `{reason}`
+    InvalidMultihopProof { reason: String },
+    /// client `{client_id}` does not verify proofs against the commitment roots of its consensus states, which multi-hop proofs require
+    MultihopUnsupportedClient { client_id: ClientId },
+    /// multi-hop proof verification failed at hop `{hop}`: `{client_error}`
+    MultihopVerificationFailed {
+        hop: u64,
+        client_error: client_error::ClientError,
+    },
     /// invalid signer error: `{reason}`
     InvalidSigner { reason: String },
     /// invalid proof: missing height
@@ -71,6 +82,56 @@ pub enum ChannelError {
     InvalidIdentifier(IdentifierError),
     /// channel counter overflow error
     CounterOverflow,
+    /// missing upgrade fields
+    MissingUpgradeFields,
+    /// missing upgrade timeout
+    MissingUpgradeTimeout,
+    /// missing upgrade
+    MissingUpgrade,
+    /// missing error receipt
+    MissingErrorReceipt,
+    /// invalid upgrade timeout: timeout height and timestamp cannot both be unset
+    InvalidUpgradeTimeout,
+    /// no upgrade exists for the channel end (`{port_id}`, `{channel_id}`)
+    UpgradeNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// no counterparty upgrade exists for the channel end (`{port_id}`, `{channel_id}`)
+    CounterpartyUpgradeNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// no upgrade error receipt exists for the channel end (`{port_id}`, `{channel_id}`)
+    UpgradeErrorReceiptNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// invalid upgrade sequence: expected `{expected}`, actual `{actual}`
+    InvalidUpgradeSequence { expected: u64, actual: u64 },
+    /// upgrade fields are identical to the current channel end parameters
+    IdenticalUpgradeFields,
+    /// upgrade fields mismatch: expected `{expected}`, actual `{actual}`
+    UpgradeFieldsMismatch { expected: String, actual: String },
+    /// upgrading the channel ordering from `{from}` to `{to}` is not supported
+    UnsupportedOrderingUpgrade { from: Order, to: Order },
+    /// upgrade timeout has elapsed
+    UpgradeTimeoutElapsed,
+    /// upgrade timeout has not been reached yet
+    UpgradeTimeoutNotReached,
+    /// channel has in-flight packets
+    PendingInflightPackets,
+    /// upgrade aborted at sequence `{upgrade_sequence}`: `{description}`
+    UpgradeAborted {
+        upgrade_sequence: u64,
+        description: String,
+    },
+    /// mismatched event type: expected `{expected}`, actual `{actual}`
+    MismatchedEventType { expected: String, actual: String },
+    /// missing event attribute `{key}`
+    MissingEventAttribute { key: String },
+    /// invalid event attribute `{key}`: `{reason}`
+    InvalidEventAttribute { key: String, reason: String },
     /// other error: `{description}`
     Other { description: String },
 }
@@ -99,6 +160,8 @@ pub enum PacketError {
     ConnectionNotOpen { connection_id: ConnectionId },
     /// Receipt for the packet `{sequence}` not found
     PacketReceiptNotFound { sequence: Sequence },
+    /// Packet `{sequence}` has not been received
+    PacketNotReceived { sequence: Sequence },
     /// The stored commitment of the packet `{sequence}` is incorrect
     IncorrectPacketCommitment { sequence: Sequence },
     /// implementation specific error
@@ -142,6 +205,24 @@ pub enum PacketError {
     InvalidPacketTimestamp(ParseTimestampError),
     /// missing timeout
     MissingTimeout,
+    /// invalid packet timeout timestamp
+    InvalidTimeoutTimestamp,
+    /// packet must carry at least one payload
+    EmptyPayloads,
+    /// invalid payload: `{reason}`
+    InvalidPayload { reason: String },
+    /// mismatched counterparty client: expected `{expected}`, actual `{actual}`
+    MismatchedCounterpartyClient {
+        expected: ClientId,
+        actual: ClientId,
+    },
+    /// mismatched number of acknowledgements: expected `{expected}`, actual `{actual}`
+    MismatchedAcknowledgementCount { expected: usize, actual: usize },
+    /// Packet timeout timestamp `{timeout_timestamp}` > counterparty timestamp `{chain_timestamp}`
+    PacketTimeoutTimestampNotReached {
+        timeout_timestamp: Timestamp,
+        chain_timestamp: Timestamp,
+    },
     /// invalid identifier error: `{0}`
     InvalidIdentifier(IdentifierError),
     /// Missing sequence number for sending packets on port `{port_id}` and channel `{channel_id}`
@@ -201,6 +282,9 @@ impl std::error::Error for ChannelError {
             Self::InvalidIdentifier(e) => Some(e),
             Self::PacketVerificationFailed {
                 client_error: e, ..
+            }
+            | Self::MultihopVerificationFailed {
+                client_error: e, ..
             } => Some(e),
             Self::InvalidStringAsSequence { error: e, .. } => Some(e),
             _ => None,
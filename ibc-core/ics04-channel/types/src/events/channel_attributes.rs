@@ -4,6 +4,8 @@ use derive_more::From;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
 use tendermint::abci;
 
+use super::parse_event_attribute;
+use crate::error::ChannelError;
 use crate::Version;
 
 const CONNECTION_ID_ATTRIBUTE_KEY: &str = "connection_id";
@@ -39,6 +41,14 @@ impl From<PortIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for PortIdAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<PortId>(event, PORT_ID_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -62,6 +72,14 @@ impl From<ChannelIdAttribute> for abci::EventAttribute {
         (CHANNEL_ID_ATTRIBUTE_KEY, attr.channel_id.as_str()).into()
     }
 }
+
+impl TryFrom<&abci::Event> for ChannelIdAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<ChannelId>(event, CHANNEL_ID_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -89,6 +107,14 @@ impl From<CounterpartyPortIdAttribute> for abci::EventAttribute {
             .into()
     }
 }
+
+impl TryFrom<&abci::Event> for CounterpartyPortIdAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<PortId>(event, COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -117,6 +143,15 @@ impl From<CounterpartyChannelIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for CounterpartyChannelIdAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<ChannelId>(event, COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 impl AsRef<ChannelId> for CounterpartyChannelIdAttribute {
     fn as_ref(&self) -> &ChannelId {
         &self.counterparty_channel_id
@@ -147,6 +182,14 @@ impl From<ConnectionIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for ConnectionIdAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<ConnectionId>(event, CONNECTION_ID_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -170,3 +213,11 @@ impl From<VersionAttribute> for abci::EventAttribute {
         (VERSION_ATTRIBUTE_KEY, attr.version.as_str()).into()
     }
 }
+
+impl TryFrom<&abci::Event> for VersionAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<Version>(event, VERSION_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
@@ -2,6 +2,10 @@
 
 mod channel_attributes;
 mod packet_attributes;
+mod upgrade_attributes;
+
+use core::fmt::Display;
+use core::str::FromStr;
 
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
@@ -18,8 +22,13 @@ use self::packet_attributes::{
     PacketConnectionIdAttribute, PacketDataAttribute, SequenceAttribute, SrcChannelIdAttribute,
     SrcPortIdAttribute, TimeoutHeightAttribute, TimeoutTimestampAttribute,
 };
+use self::upgrade_attributes::{
+    ChannelStateAttribute, ErrorReceiptAttribute, UpgradeConnectionHopsAttribute,
+    UpgradeOrderingAttribute, UpgradeSequenceAttribute, UpgradeTimeoutHeightAttribute,
+    UpgradeTimeoutTimestampAttribute, UpgradeVersionAttribute,
+};
 use super::acknowledgement::Acknowledgement;
-use super::channel::Order;
+use super::channel::{Order, State};
 use super::timeout::TimeoutHeight;
 use super::Version;
 use crate::error::ChannelError;
@@ -27,20 +36,30 @@ use crate::packet::Packet;
 
 /// Channel event types corresponding to ibc-go's channel events:
 /// https://github.com/cosmos/ibc-go/blob/c4413c5877f9ef883494da1721cb18caaba7f7f5/modules/core/04-channel/types/events.go#L52-L72
-const CHANNEL_OPEN_INIT_EVENT: &str = "channel_open_init";
-const CHANNEL_OPEN_TRY_EVENT: &str = "channel_open_try";
-const CHANNEL_OPEN_ACK_EVENT: &str = "channel_open_ack";
-const CHANNEL_OPEN_CONFIRM_EVENT: &str = "channel_open_confirm";
-const CHANNEL_CLOSE_INIT_EVENT: &str = "channel_close_init";
-const CHANNEL_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
-const CHANNEL_CLOSED_EVENT: &str = "channel_close";
+pub const CHANNEL_OPEN_INIT_EVENT: &str = "channel_open_init";
+pub const CHANNEL_OPEN_TRY_EVENT: &str = "channel_open_try";
+pub const CHANNEL_OPEN_ACK_EVENT: &str = "channel_open_ack";
+pub const CHANNEL_OPEN_CONFIRM_EVENT: &str = "channel_open_confirm";
+pub const CHANNEL_CLOSE_INIT_EVENT: &str = "channel_close_init";
+pub const CHANNEL_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
+pub const CHANNEL_CLOSED_EVENT: &str = "channel_close";
+
+/// Channel upgrade event types
+pub const CHANNEL_UPGRADE_INIT_EVENT: &str = "channel_upgrade_init";
+pub const CHANNEL_UPGRADE_TRY_EVENT: &str = "channel_upgrade_try";
+pub const CHANNEL_UPGRADE_ACK_EVENT: &str = "channel_upgrade_ack";
+pub const CHANNEL_UPGRADE_CONFIRM_EVENT: &str = "channel_upgrade_confirm";
+pub const CHANNEL_UPGRADE_OPEN_EVENT: &str = "channel_upgrade_open";
+pub const CHANNEL_UPGRADE_TIMEOUT_EVENT: &str = "channel_upgrade_timeout";
+pub const CHANNEL_UPGRADE_CANCEL_EVENT: &str = "channel_upgrade_cancelled";
+pub const CHANNEL_UPGRADE_ERROR_EVENT: &str = "channel_upgrade_error";
 
 /// Packet event types
-const SEND_PACKET_EVENT: &str = "send_packet";
-const RECEIVE_PACKET_EVENT: &str = "recv_packet";
-const WRITE_ACK_EVENT: &str = "write_acknowledgement";
-const ACK_PACKET_EVENT: &str = "acknowledge_packet";
-const TIMEOUT_EVENT: &str = "timeout_packet";
+pub const SEND_PACKET_EVENT: &str = "send_packet";
+pub const RECEIVE_PACKET_EVENT: &str = "recv_packet";
+pub const WRITE_ACK_EVENT: &str = "write_acknowledgement";
+pub const ACK_PACKET_EVENT: &str = "acknowledge_packet";
+pub const TIMEOUT_EVENT: &str = "timeout_packet";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -117,6 +136,24 @@ impl From<OpenInit> for abci::Event {
     }
 }
 
+/// The counterparty channel identifier is not known yet on `ChanOpenInit`, so
+/// the empty `counterparty_channel_id` attribute is ignored.
+impl TryFrom<abci::Event> for OpenInit {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_OPEN_INIT_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            conn_id_attr_on_a: (&event).try_into()?,
+            version_attr_on_a: (&event).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -198,6 +235,23 @@ impl From<OpenTry> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenTry {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_OPEN_TRY_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            conn_id_attr_on_b: (&event).try_into()?,
+            version_attr_on_b: (&event).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -272,6 +326,851 @@ impl From<OpenAck> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for OpenAck {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_OPEN_ACK_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            conn_id_attr_on_a: (&event).try_into()?,
+        })
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OpenConfirm {
+    port_id_attr_on_b: PortIdAttribute,
+    chan_id_attr_on_b: ChannelIdAttribute,
+    port_id_attr_on_a: CounterpartyPortIdAttribute,
+    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
+    conn_id_attr_on_b: ConnectionIdAttribute,
+}
+
+impl OpenConfirm {
+    pub fn new(
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        conn_id_on_b: ConnectionId,
+    ) -> Self {
+        Self {
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            conn_id_attr_on_b: conn_id_on_b.into(),
+        }
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.channel_id
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.counterparty_port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.counterparty_channel_id
+    }
+    pub fn conn_id_on_b(&self) -> &ConnectionId {
+        &self.conn_id_attr_on_b.connection_id
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_OPEN_CONFIRM_EVENT
+    }
+}
+
+impl From<OpenConfirm> for abci::Event {
+    fn from(o: OpenConfirm) -> Self {
+        abci::Event {
+            kind: CHANNEL_OPEN_CONFIRM_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.conn_id_attr_on_b.into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for OpenConfirm {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_OPEN_CONFIRM_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            conn_id_attr_on_b: (&event).try_into()?,
+        })
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloseInit {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    conn_id_attr_on_a: ConnectionIdAttribute,
+}
+
+impl CloseInit {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        conn_id_on_a: ConnectionId,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            conn_id_attr_on_a: conn_id_on_a.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn conn_id_on_a(&self) -> &ConnectionId {
+        &self.conn_id_attr_on_a.connection_id
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_CLOSE_INIT_EVENT
+    }
+}
+
+impl From<CloseInit> for abci::Event {
+    fn from(o: CloseInit) -> Self {
+        abci::Event {
+            kind: CHANNEL_CLOSE_INIT_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.conn_id_attr_on_a.into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for CloseInit {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_CLOSE_INIT_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            conn_id_attr_on_a: (&event).try_into()?,
+        })
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloseConfirm {
+    port_id_attr_on_b: PortIdAttribute,
+    chan_id_attr_on_b: ChannelIdAttribute,
+    port_id_attr_on_a: CounterpartyPortIdAttribute,
+    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
+    conn_id_attr_on_b: ConnectionIdAttribute,
+}
+
+impl CloseConfirm {
+    pub fn new(
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        conn_id_on_b: ConnectionId,
+    ) -> Self {
+        Self {
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            conn_id_attr_on_b: conn_id_on_b.into(),
+        }
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.channel_id
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.counterparty_port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.counterparty_channel_id
+    }
+    pub fn conn_id_on_b(&self) -> &ConnectionId {
+        &self.conn_id_attr_on_b.connection_id
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_CLOSE_CONFIRM_EVENT
+    }
+}
+
+impl From<CloseConfirm> for abci::Event {
+    fn from(o: CloseConfirm) -> Self {
+        abci::Event {
+            kind: CHANNEL_CLOSE_CONFIRM_EVENT.to_string(),
+            attributes: vec![
+                o.port_id_attr_on_b.into(),
+                o.chan_id_attr_on_b.into(),
+                o.port_id_attr_on_a.into(),
+                o.chan_id_attr_on_a.into(),
+                o.conn_id_attr_on_b.into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for CloseConfirm {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_CLOSE_CONFIRM_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            conn_id_attr_on_b: (&event).try_into()?,
+        })
+    }
+}
+
+/// A `ChannelClosed` event is emitted when a channel is closed as a result of a packet timing out. Note that
+/// since optimistic packet sends (i.e. send a packet before channel handshake is complete) are supported,
+/// we might not have a counterparty channel id value yet. This would happen if a packet is sent right
+/// after a `ChannelOpenInit` message.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelClosed {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    maybe_chan_id_attr_on_b: Option<CounterpartyChannelIdAttribute>,
+    conn_id_attr_on_a: ConnectionIdAttribute,
+    channel_ordering_attr: ChannelOrderingAttribute,
+}
+
+impl ChannelClosed {
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        maybe_chan_id_on_b: Option<ChannelId>,
+        conn_id_on_a: ConnectionId,
+        channel_ordering: Order,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            maybe_chan_id_attr_on_b: maybe_chan_id_on_b.map(Into::into),
+            conn_id_attr_on_a: conn_id_on_a.into(),
+            channel_ordering_attr: channel_ordering.into(),
+        }
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_a(&self) -> Option<&ChannelId> {
+        self.maybe_chan_id_attr_on_b.as_ref().map(AsRef::as_ref)
+    }
+    pub fn conn_id_on_b(&self) -> &ConnectionId {
+        &self.conn_id_attr_on_a.connection_id
+    }
+    pub fn channel_ordering(&self) -> &Order {
+        &self.channel_ordering_attr.order
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_CLOSED_EVENT
+    }
+}
+
+impl From<ChannelClosed> for abci::Event {
+    fn from(ev: ChannelClosed) -> Self {
+        abci::Event {
+            kind: CHANNEL_CLOSED_EVENT.to_string(),
+            attributes: vec![
+                ev.port_id_attr_on_a.into(),
+                ev.chan_id_attr_on_a.into(),
+                ev.port_id_attr_on_b.into(),
+                ev.maybe_chan_id_attr_on_b.map_or_else(
+                    || (COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY, "").into(),
+                    Into::into,
+                ),
+                ev.conn_id_attr_on_a.into(),
+                ev.channel_ordering_attr.into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for ChannelClosed {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_CLOSED_EVENT)?;
+
+        let maybe_chan_id_attr_on_b =
+            match event_attribute(&event, COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY)? {
+                "" => None,
+                _ => Some((&event).try_into()?),
+            };
+
+        Ok(Self {
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            maybe_chan_id_attr_on_b,
+            conn_id_attr_on_a: (&event).try_into()?,
+            channel_ordering_attr: (&event).try_into()?,
+        })
+    }
+}
+
+/// Emitted when a channel upgrade is initiated on chain A (`ChanUpgradeInit`).
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeInit {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_connection_hops_attr: UpgradeConnectionHopsAttribute,
+    upgrade_version_attr: UpgradeVersionAttribute,
+    upgrade_ordering_attr: UpgradeOrderingAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeInit {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_connection_hops: Vec<ConnectionId>,
+        upgrade_version: Version,
+        upgrade_ordering: Order,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_connection_hops_attr: upgrade_connection_hops.into(),
+            upgrade_version_attr: upgrade_version.into(),
+            upgrade_ordering_attr: upgrade_ordering.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_connection_hops(&self) -> &[ConnectionId] {
+        &self.upgrade_connection_hops_attr.upgrade_connection_hops
+    }
+    pub fn upgrade_version(&self) -> &Version {
+        &self.upgrade_version_attr.upgrade_version
+    }
+    pub fn upgrade_ordering(&self) -> &Order {
+        &self.upgrade_ordering_attr.upgrade_ordering
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_INIT_EVENT
+    }
+}
+
+impl From<UpgradeInit> for abci::Event {
+    fn from(ev: UpgradeInit) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_INIT_EVENT.to_string(),
+            attributes: vec![
+                ev.port_id_attr_on_a.into(),
+                ev.chan_id_attr_on_a.into(),
+                ev.port_id_attr_on_b.into(),
+                ev.chan_id_attr_on_b.into(),
+                ev.upgrade_connection_hops_attr.into(),
+                ev.upgrade_version_attr.into(),
+                ev.upgrade_ordering_attr.into(),
+                ev.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for UpgradeInit {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_UPGRADE_INIT_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            upgrade_connection_hops_attr: (&event).try_into()?,
+            upgrade_version_attr: (&event).try_into()?,
+            upgrade_ordering_attr: (&event).try_into()?,
+            upgrade_sequence_attr: (&event).try_into()?,
+        })
+    }
+}
+
+/// Emitted on chain B when it agrees to the channel upgrade proposed by chain A (`ChanUpgradeTry`).
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTry {
+    port_id_attr_on_b: PortIdAttribute,
+    chan_id_attr_on_b: ChannelIdAttribute,
+    port_id_attr_on_a: CounterpartyPortIdAttribute,
+    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
+    upgrade_connection_hops_attr: UpgradeConnectionHopsAttribute,
+    upgrade_version_attr: UpgradeVersionAttribute,
+    upgrade_ordering_attr: UpgradeOrderingAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeTry {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        upgrade_connection_hops: Vec<ConnectionId>,
+        upgrade_version: Version,
+        upgrade_ordering: Order,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            upgrade_connection_hops_attr: upgrade_connection_hops.into(),
+            upgrade_version_attr: upgrade_version.into(),
+            upgrade_ordering_attr: upgrade_ordering.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.channel_id
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.counterparty_port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.counterparty_channel_id
+    }
+    pub fn upgrade_connection_hops(&self) -> &[ConnectionId] {
+        &self.upgrade_connection_hops_attr.upgrade_connection_hops
+    }
+    pub fn upgrade_version(&self) -> &Version {
+        &self.upgrade_version_attr.upgrade_version
+    }
+    pub fn upgrade_ordering(&self) -> &Order {
+        &self.upgrade_ordering_attr.upgrade_ordering
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_TRY_EVENT
+    }
+}
+
+impl From<UpgradeTry> for abci::Event {
+    fn from(ev: UpgradeTry) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_TRY_EVENT.to_string(),
+            attributes: vec![
+                ev.port_id_attr_on_b.into(),
+                ev.chan_id_attr_on_b.into(),
+                ev.port_id_attr_on_a.into(),
+                ev.chan_id_attr_on_a.into(),
+                ev.upgrade_connection_hops_attr.into(),
+                ev.upgrade_version_attr.into(),
+                ev.upgrade_ordering_attr.into(),
+                ev.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for UpgradeTry {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_UPGRADE_TRY_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            upgrade_connection_hops_attr: (&event).try_into()?,
+            upgrade_version_attr: (&event).try_into()?,
+            upgrade_ordering_attr: (&event).try_into()?,
+            upgrade_sequence_attr: (&event).try_into()?,
+        })
+    }
+}
+
+/// Emitted on chain A upon acknowledging the upgrade agreed to by chain B (`ChanUpgradeAck`).
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeAck {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_connection_hops_attr: UpgradeConnectionHopsAttribute,
+    upgrade_version_attr: UpgradeVersionAttribute,
+    upgrade_ordering_attr: UpgradeOrderingAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeAck {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_connection_hops: Vec<ConnectionId>,
+        upgrade_version: Version,
+        upgrade_ordering: Order,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_connection_hops_attr: upgrade_connection_hops.into(),
+            upgrade_version_attr: upgrade_version.into(),
+            upgrade_ordering_attr: upgrade_ordering.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_connection_hops(&self) -> &[ConnectionId] {
+        &self.upgrade_connection_hops_attr.upgrade_connection_hops
+    }
+    pub fn upgrade_version(&self) -> &Version {
+        &self.upgrade_version_attr.upgrade_version
+    }
+    pub fn upgrade_ordering(&self) -> &Order {
+        &self.upgrade_ordering_attr.upgrade_ordering
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_ACK_EVENT
+    }
+}
+
+impl From<UpgradeAck> for abci::Event {
+    fn from(ev: UpgradeAck) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_ACK_EVENT.to_string(),
+            attributes: vec![
+                ev.port_id_attr_on_a.into(),
+                ev.chan_id_attr_on_a.into(),
+                ev.port_id_attr_on_b.into(),
+                ev.chan_id_attr_on_b.into(),
+                ev.upgrade_connection_hops_attr.into(),
+                ev.upgrade_version_attr.into(),
+                ev.upgrade_ordering_attr.into(),
+                ev.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for UpgradeAck {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_UPGRADE_ACK_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            upgrade_connection_hops_attr: (&event).try_into()?,
+            upgrade_version_attr: (&event).try_into()?,
+            upgrade_ordering_attr: (&event).try_into()?,
+            upgrade_sequence_attr: (&event).try_into()?,
+        })
+    }
+}
+
+/// Emitted on chain B upon `ChanUpgradeConfirm`, with the resulting state of its channel end.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeConfirm {
+    port_id_attr_on_b: PortIdAttribute,
+    chan_id_attr_on_b: ChannelIdAttribute,
+    port_id_attr_on_a: CounterpartyPortIdAttribute,
+    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
+    channel_state_attr: ChannelStateAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeConfirm {
+    pub fn new(
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        channel_state: State,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            port_id_attr_on_a: port_id_on_a.into(),
+            chan_id_attr_on_a: chan_id_on_a.into(),
+            channel_state_attr: channel_state.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.channel_id
+    }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.counterparty_port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.counterparty_channel_id
+    }
+    pub fn channel_state(&self) -> &State {
+        &self.channel_state_attr.channel_state
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_CONFIRM_EVENT
+    }
+}
+
+impl From<UpgradeConfirm> for abci::Event {
+    fn from(ev: UpgradeConfirm) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_CONFIRM_EVENT.to_string(),
+            attributes: vec![
+                ev.port_id_attr_on_b.into(),
+                ev.chan_id_attr_on_b.into(),
+                ev.port_id_attr_on_a.into(),
+                ev.chan_id_attr_on_a.into(),
+                ev.channel_state_attr.into(),
+                ev.upgrade_sequence_attr.into(),
+            ],
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for UpgradeConfirm {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_UPGRADE_CONFIRM_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            channel_state_attr: (&event).try_into()?,
+            upgrade_sequence_attr: (&event).try_into()?,
+        })
+    }
+}
+
+/// Emitted once a channel end switched to the upgraded parameters and is open again.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -286,66 +1185,116 @@ impl From<OpenAck> for abci::Event {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct OpenConfirm {
-    port_id_attr_on_b: PortIdAttribute,
-    chan_id_attr_on_b: ChannelIdAttribute,
-    port_id_attr_on_a: CounterpartyPortIdAttribute,
-    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
-    conn_id_attr_on_b: ConnectionIdAttribute,
+pub struct UpgradeOpen {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    channel_state_attr: ChannelStateAttribute,
+    upgrade_connection_hops_attr: UpgradeConnectionHopsAttribute,
+    upgrade_version_attr: UpgradeVersionAttribute,
+    upgrade_ordering_attr: UpgradeOrderingAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
 }
 
-impl OpenConfirm {
+impl UpgradeOpen {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
-        port_id_on_b: PortId,
-        chan_id_on_b: ChannelId,
         port_id_on_a: PortId,
         chan_id_on_a: ChannelId,
-        conn_id_on_b: ConnectionId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        channel_state: State,
+        upgrade_connection_hops: Vec<ConnectionId>,
+        upgrade_version: Version,
+        upgrade_ordering: Order,
+        upgrade_sequence: u64,
     ) -> Self {
         Self {
-            port_id_attr_on_b: port_id_on_b.into(),
-            chan_id_attr_on_b: chan_id_on_b.into(),
             port_id_attr_on_a: port_id_on_a.into(),
             chan_id_attr_on_a: chan_id_on_a.into(),
-            conn_id_attr_on_b: conn_id_on_b.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            channel_state_attr: channel_state.into(),
+            upgrade_connection_hops_attr: upgrade_connection_hops.into(),
+            upgrade_version_attr: upgrade_version.into(),
+            upgrade_ordering_attr: upgrade_ordering.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
         }
     }
+    pub fn port_id_on_a(&self) -> &PortId {
+        &self.port_id_attr_on_a.port_id
+    }
+    pub fn chan_id_on_a(&self) -> &ChannelId {
+        &self.chan_id_attr_on_a.channel_id
+    }
     pub fn port_id_on_b(&self) -> &PortId {
-        &self.port_id_attr_on_b.port_id
+        &self.port_id_attr_on_b.counterparty_port_id
     }
     pub fn chan_id_on_b(&self) -> &ChannelId {
-        &self.chan_id_attr_on_b.channel_id
+        &self.chan_id_attr_on_b.counterparty_channel_id
     }
-    pub fn port_id_on_a(&self) -> &PortId {
-        &self.port_id_attr_on_a.counterparty_port_id
+    pub fn channel_state(&self) -> &State {
+        &self.channel_state_attr.channel_state
     }
-    pub fn chan_id_on_a(&self) -> &ChannelId {
-        &self.chan_id_attr_on_a.counterparty_channel_id
+    pub fn upgrade_connection_hops(&self) -> &[ConnectionId] {
+        &self.upgrade_connection_hops_attr.upgrade_connection_hops
     }
-    pub fn conn_id_on_b(&self) -> &ConnectionId {
-        &self.conn_id_attr_on_b.connection_id
+    pub fn upgrade_version(&self) -> &Version {
+        &self.upgrade_version_attr.upgrade_version
+    }
+    pub fn upgrade_ordering(&self) -> &Order {
+        &self.upgrade_ordering_attr.upgrade_ordering
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
     }
 
     pub fn event_type(&self) -> &str {
-        CHANNEL_OPEN_CONFIRM_EVENT
+        CHANNEL_UPGRADE_OPEN_EVENT
     }
 }
 
-impl From<OpenConfirm> for abci::Event {
-    fn from(o: OpenConfirm) -> Self {
+impl From<UpgradeOpen> for abci::Event {
+    fn from(ev: UpgradeOpen) -> Self {
         abci::Event {
-            kind: CHANNEL_OPEN_CONFIRM_EVENT.to_string(),
+            kind: CHANNEL_UPGRADE_OPEN_EVENT.to_string(),
             attributes: vec![
-                o.port_id_attr_on_b.into(),
-                o.chan_id_attr_on_b.into(),
-                o.port_id_attr_on_a.into(),
-                o.chan_id_attr_on_a.into(),
-                o.conn_id_attr_on_b.into(),
+                ev.port_id_attr_on_a.into(),
+                ev.chan_id_attr_on_a.into(),
+                ev.port_id_attr_on_b.into(),
+                ev.chan_id_attr_on_b.into(),
+                ev.channel_state_attr.into(),
+                ev.upgrade_connection_hops_attr.into(),
+                ev.upgrade_version_attr.into(),
+                ev.upgrade_ordering_attr.into(),
+                ev.upgrade_sequence_attr.into(),
             ],
         }
     }
 }
 
+impl TryFrom<abci::Event> for UpgradeOpen {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_UPGRADE_OPEN_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            channel_state_attr: (&event).try_into()?,
+            upgrade_connection_hops_attr: (&event).try_into()?,
+            upgrade_version_attr: (&event).try_into()?,
+            upgrade_ordering_attr: (&event).try_into()?,
+            upgrade_sequence_attr: (&event).try_into()?,
+        })
+    }
+}
+
+/// Emitted when an upgrade is aborted because its timeout elapsed on the counterparty.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -360,28 +1309,34 @@ impl From<OpenConfirm> for abci::Event {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct CloseInit {
+pub struct UpgradeTimeout {
     port_id_attr_on_a: PortIdAttribute,
     chan_id_attr_on_a: ChannelIdAttribute,
     port_id_attr_on_b: CounterpartyPortIdAttribute,
     chan_id_attr_on_b: CounterpartyChannelIdAttribute,
-    conn_id_attr_on_a: ConnectionIdAttribute,
+    upgrade_timeout_height_attr: UpgradeTimeoutHeightAttribute,
+    upgrade_timeout_timestamp_attr: UpgradeTimeoutTimestampAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
 }
 
-impl CloseInit {
+impl UpgradeTimeout {
     pub fn new(
         port_id_on_a: PortId,
         chan_id_on_a: ChannelId,
         port_id_on_b: PortId,
         chan_id_on_b: ChannelId,
-        conn_id_on_a: ConnectionId,
+        upgrade_timeout_height: TimeoutHeight,
+        upgrade_timeout_timestamp: Timestamp,
+        upgrade_sequence: u64,
     ) -> Self {
         Self {
             port_id_attr_on_a: port_id_on_a.into(),
             chan_id_attr_on_a: chan_id_on_a.into(),
             port_id_attr_on_b: port_id_on_b.into(),
             chan_id_attr_on_b: chan_id_on_b.into(),
-            conn_id_attr_on_a: conn_id_on_a.into(),
+            upgrade_timeout_height_attr: upgrade_timeout_height.into(),
+            upgrade_timeout_timestamp_attr: upgrade_timeout_timestamp.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
         }
     }
     pub fn port_id_on_a(&self) -> &PortId {
@@ -396,30 +1351,59 @@ impl CloseInit {
     pub fn chan_id_on_b(&self) -> &ChannelId {
         &self.chan_id_attr_on_b.counterparty_channel_id
     }
-    pub fn conn_id_on_a(&self) -> &ConnectionId {
-        &self.conn_id_attr_on_a.connection_id
+    pub fn upgrade_timeout_height(&self) -> &TimeoutHeight {
+        &self.upgrade_timeout_height_attr.upgrade_timeout_height
+    }
+    pub fn upgrade_timeout_timestamp(&self) -> &Timestamp {
+        &self
+            .upgrade_timeout_timestamp_attr
+            .upgrade_timeout_timestamp
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
     }
 
     pub fn event_type(&self) -> &str {
-        CHANNEL_CLOSE_INIT_EVENT
+        CHANNEL_UPGRADE_TIMEOUT_EVENT
     }
 }
 
-impl From<CloseInit> for abci::Event {
-    fn from(o: CloseInit) -> Self {
+impl From<UpgradeTimeout> for abci::Event {
+    fn from(ev: UpgradeTimeout) -> Self {
         abci::Event {
-            kind: CHANNEL_CLOSE_INIT_EVENT.to_string(),
+            kind: CHANNEL_UPGRADE_TIMEOUT_EVENT.to_string(),
             attributes: vec![
-                o.port_id_attr_on_a.into(),
-                o.chan_id_attr_on_a.into(),
-                o.port_id_attr_on_b.into(),
-                o.chan_id_attr_on_b.into(),
-                o.conn_id_attr_on_a.into(),
+                ev.port_id_attr_on_a.into(),
+                ev.chan_id_attr_on_a.into(),
+                ev.port_id_attr_on_b.into(),
+                ev.chan_id_attr_on_b.into(),
+                ev.upgrade_timeout_height_attr.into(),
+                ev.upgrade_timeout_timestamp_attr.into(),
+                ev.upgrade_sequence_attr.into(),
             ],
         }
     }
 }
 
+impl TryFrom<abci::Event> for UpgradeTimeout {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_UPGRADE_TIMEOUT_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            upgrade_timeout_height_attr: (&event).try_into()?,
+            upgrade_timeout_timestamp_attr: (&event).try_into()?,
+            upgrade_sequence_attr: (&event).try_into()?,
+        })
+    }
+}
+
+/// Emitted when an upgrade is cancelled after the counterparty aborted it.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -434,70 +1418,83 @@ impl From<CloseInit> for abci::Event {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct CloseConfirm {
-    port_id_attr_on_b: PortIdAttribute,
-    chan_id_attr_on_b: ChannelIdAttribute,
-    port_id_attr_on_a: CounterpartyPortIdAttribute,
-    chan_id_attr_on_a: CounterpartyChannelIdAttribute,
-    conn_id_attr_on_b: ConnectionIdAttribute,
+pub struct UpgradeCancel {
+    port_id_attr_on_a: PortIdAttribute,
+    chan_id_attr_on_a: ChannelIdAttribute,
+    port_id_attr_on_b: CounterpartyPortIdAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
 }
 
-impl CloseConfirm {
+impl UpgradeCancel {
     pub fn new(
-        port_id_on_b: PortId,
-        chan_id_on_b: ChannelId,
         port_id_on_a: PortId,
         chan_id_on_a: ChannelId,
-        conn_id_on_b: ConnectionId,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
     ) -> Self {
         Self {
-            port_id_attr_on_b: port_id_on_b.into(),
-            chan_id_attr_on_b: chan_id_on_b.into(),
             port_id_attr_on_a: port_id_on_a.into(),
             chan_id_attr_on_a: chan_id_on_a.into(),
-            conn_id_attr_on_b: conn_id_on_b.into(),
+            port_id_attr_on_b: port_id_on_b.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
         }
     }
-    pub fn port_id_on_b(&self) -> &PortId {
-        &self.port_id_attr_on_b.port_id
-    }
-    pub fn chan_id_on_b(&self) -> &ChannelId {
-        &self.chan_id_attr_on_b.channel_id
-    }
     pub fn port_id_on_a(&self) -> &PortId {
-        &self.port_id_attr_on_a.counterparty_port_id
+        &self.port_id_attr_on_a.port_id
     }
     pub fn chan_id_on_a(&self) -> &ChannelId {
-        &self.chan_id_attr_on_a.counterparty_channel_id
+        &self.chan_id_attr_on_a.channel_id
     }
-    pub fn conn_id_on_b(&self) -> &ConnectionId {
-        &self.conn_id_attr_on_b.connection_id
+    pub fn port_id_on_b(&self) -> &PortId {
+        &self.port_id_attr_on_b.counterparty_port_id
+    }
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
     }
 
     pub fn event_type(&self) -> &str {
-        CHANNEL_CLOSE_CONFIRM_EVENT
+        CHANNEL_UPGRADE_CANCEL_EVENT
     }
 }
 
-impl From<CloseConfirm> for abci::Event {
-    fn from(o: CloseConfirm) -> Self {
+impl From<UpgradeCancel> for abci::Event {
+    fn from(ev: UpgradeCancel) -> Self {
         abci::Event {
-            kind: CHANNEL_CLOSE_CONFIRM_EVENT.to_string(),
+            kind: CHANNEL_UPGRADE_CANCEL_EVENT.to_string(),
             attributes: vec![
-                o.port_id_attr_on_b.into(),
-                o.chan_id_attr_on_b.into(),
-                o.port_id_attr_on_a.into(),
-                o.chan_id_attr_on_a.into(),
-                o.conn_id_attr_on_b.into(),
+                ev.port_id_attr_on_a.into(),
+                ev.chan_id_attr_on_a.into(),
+                ev.port_id_attr_on_b.into(),
+                ev.chan_id_attr_on_b.into(),
+                ev.upgrade_sequence_attr.into(),
             ],
         }
     }
 }
 
-/// A `ChannelClosed` event is emitted when a channel is closed as a result of a packet timing out. Note that
-/// since optimistic packet sends (i.e. send a packet before channel handshake is complete) are supported,
-/// we might not have a counterparty channel id value yet. This would happen if a packet is sent right
-/// after a `ChannelOpenInit` message.
+impl TryFrom<abci::Event> for UpgradeCancel {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_UPGRADE_CANCEL_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            upgrade_sequence_attr: (&event).try_into()?,
+        })
+    }
+}
+
+/// Emitted when an upgrade is aborted on the host chain and an error receipt is written.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -512,76 +1509,90 @@ impl From<CloseConfirm> for abci::Event {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct ChannelClosed {
+pub struct UpgradeError {
     port_id_attr_on_a: PortIdAttribute,
     chan_id_attr_on_a: ChannelIdAttribute,
     port_id_attr_on_b: CounterpartyPortIdAttribute,
-    maybe_chan_id_attr_on_b: Option<CounterpartyChannelIdAttribute>,
-    conn_id_attr_on_a: ConnectionIdAttribute,
-    channel_ordering_attr: ChannelOrderingAttribute,
+    chan_id_attr_on_b: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+    error_receipt_attr: ErrorReceiptAttribute,
 }
 
-impl ChannelClosed {
+impl UpgradeError {
     pub fn new(
         port_id_on_a: PortId,
         chan_id_on_a: ChannelId,
         port_id_on_b: PortId,
-        maybe_chan_id_on_b: Option<ChannelId>,
-        conn_id_on_a: ConnectionId,
-        channel_ordering: Order,
+        chan_id_on_b: ChannelId,
+        upgrade_sequence: u64,
+        error_receipt: String,
     ) -> Self {
         Self {
             port_id_attr_on_a: port_id_on_a.into(),
             chan_id_attr_on_a: chan_id_on_a.into(),
             port_id_attr_on_b: port_id_on_b.into(),
-            maybe_chan_id_attr_on_b: maybe_chan_id_on_b.map(Into::into),
-            conn_id_attr_on_a: conn_id_on_a.into(),
-            channel_ordering_attr: channel_ordering.into(),
+            chan_id_attr_on_b: chan_id_on_b.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+            error_receipt_attr: error_receipt.into(),
         }
     }
-    pub fn port_id_on_b(&self) -> &PortId {
+    pub fn port_id_on_a(&self) -> &PortId {
         &self.port_id_attr_on_a.port_id
     }
-    pub fn chan_id_on_b(&self) -> &ChannelId {
+    pub fn chan_id_on_a(&self) -> &ChannelId {
         &self.chan_id_attr_on_a.channel_id
     }
-    pub fn port_id_on_a(&self) -> &PortId {
+    pub fn port_id_on_b(&self) -> &PortId {
         &self.port_id_attr_on_b.counterparty_port_id
     }
-    pub fn chan_id_on_a(&self) -> Option<&ChannelId> {
-        self.maybe_chan_id_attr_on_b.as_ref().map(AsRef::as_ref)
+    pub fn chan_id_on_b(&self) -> &ChannelId {
+        &self.chan_id_attr_on_b.counterparty_channel_id
     }
-    pub fn conn_id_on_b(&self) -> &ConnectionId {
-        &self.conn_id_attr_on_a.connection_id
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence_attr.upgrade_sequence
     }
-    pub fn channel_ordering(&self) -> &Order {
-        &self.channel_ordering_attr.order
+    pub fn error_receipt(&self) -> &str {
+        &self.error_receipt_attr.error_receipt
     }
 
     pub fn event_type(&self) -> &str {
-        CHANNEL_CLOSED_EVENT
+        CHANNEL_UPGRADE_ERROR_EVENT
     }
 }
 
-impl From<ChannelClosed> for abci::Event {
-    fn from(ev: ChannelClosed) -> Self {
+impl From<UpgradeError> for abci::Event {
+    fn from(ev: UpgradeError) -> Self {
         abci::Event {
-            kind: CHANNEL_CLOSED_EVENT.to_string(),
+            kind: CHANNEL_UPGRADE_ERROR_EVENT.to_string(),
             attributes: vec![
                 ev.port_id_attr_on_a.into(),
                 ev.chan_id_attr_on_a.into(),
                 ev.port_id_attr_on_b.into(),
-                ev.maybe_chan_id_attr_on_b.map_or_else(
-                    || (COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY, "").into(),
-                    Into::into,
-                ),
-                ev.conn_id_attr_on_a.into(),
-                ev.channel_ordering_attr.into(),
+                ev.chan_id_attr_on_b.into(),
+                ev.upgrade_sequence_attr.into(),
+                ev.error_receipt_attr.into(),
             ],
         }
     }
 }
 
+impl TryFrom<abci::Event> for UpgradeError {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, CHANNEL_UPGRADE_ERROR_EVENT)?;
+
+        Ok(Self {
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            upgrade_sequence_attr: (&event).try_into()?,
+            error_receipt_attr: (&event).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -693,6 +1704,27 @@ impl TryFrom<SendPacket> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for SendPacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, SEND_PACKET_EVENT)?;
+
+        Ok(Self {
+            packet_data_attr: (&event).try_into()?,
+            timeout_height_attr_on_b: (&event).try_into()?,
+            timeout_timestamp_attr_on_b: (&event).try_into()?,
+            seq_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            channel_ordering_attr: (&event).try_into()?,
+            conn_id_attr_on_a: (&event).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -804,6 +1836,27 @@ impl TryFrom<ReceivePacket> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for ReceivePacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, RECEIVE_PACKET_EVENT)?;
+
+        Ok(Self {
+            packet_data_attr: (&event).try_into()?,
+            timeout_height_attr_on_b: (&event).try_into()?,
+            timeout_timestamp_attr_on_b: (&event).try_into()?,
+            seq_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            channel_ordering_attr: (&event).try_into()?,
+            conn_id_attr_on_b: (&event).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -919,6 +1972,27 @@ impl TryFrom<WriteAcknowledgement> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for WriteAcknowledgement {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, WRITE_ACK_EVENT)?;
+
+        Ok(Self {
+            packet_data: (&event).try_into()?,
+            timeout_height_attr_on_b: (&event).try_into()?,
+            timeout_timestamp_attr_on_b: (&event).try_into()?,
+            seq_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            acknowledgement: (&event).try_into()?,
+            conn_id_attr_on_b: (&event).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -1022,6 +2096,26 @@ impl TryFrom<AcknowledgePacket> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for AcknowledgePacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, ACK_PACKET_EVENT)?;
+
+        Ok(Self {
+            timeout_height_attr_on_b: (&event).try_into()?,
+            timeout_timestamp_attr_on_b: (&event).try_into()?,
+            seq_on_a: (&event).try_into()?,
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            channel_ordering_attr: (&event).try_into()?,
+            conn_id_attr_on_a: (&event).try_into()?,
+        })
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -1118,6 +2212,72 @@ impl TryFrom<TimeoutPacket> for abci::Event {
     }
 }
 
+impl TryFrom<abci::Event> for TimeoutPacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, TIMEOUT_EVENT)?;
+
+        Ok(Self {
+            timeout_height_attr_on_b: (&event).try_into()?,
+            timeout_timestamp_attr_on_b: (&event).try_into()?,
+            seq_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_a: (&event).try_into()?,
+            chan_id_attr_on_a: (&event).try_into()?,
+            port_id_attr_on_b: (&event).try_into()?,
+            chan_id_attr_on_b: (&event).try_into()?,
+            channel_ordering_attr: (&event).try_into()?,
+        })
+    }
+}
+
+/// Checks that an ABCI event is of the expected type.
+pub(crate) fn verify_event_type(event: &abci::Event, expected: &str) -> Result<(), ChannelError> {
+    if event.kind != expected {
+        return Err(ChannelError::MismatchedEventType {
+            expected: expected.to_string(),
+            actual: event.kind.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the value of the attribute of an ABCI event with the given key.
+pub(crate) fn event_attribute<'a>(
+    event: &'a abci::Event,
+    key: &str,
+) -> Result<&'a str, ChannelError> {
+    let attribute = event
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key_str().is_ok_and(|k| k == key))
+        .ok_or_else(|| ChannelError::MissingEventAttribute {
+            key: key.to_string(),
+        })?;
+
+    attribute
+        .value_str()
+        .map_err(|e| invalid_event_attribute(key, e))
+}
+
+/// Parses the value of the attribute of an ABCI event with the given key.
+fn parse_event_attribute<T>(event: &abci::Event, key: &str) -> Result<T, ChannelError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    T::from_str(event_attribute(event, key)?).map_err(|e| invalid_event_attribute(key, e))
+}
+
+/// Builds the error returned when an event attribute cannot be parsed.
+pub(crate) fn invalid_event_attribute(key: &str, reason: impl Display) -> ChannelError {
+    ChannelError::InvalidEventAttribute {
+        key: key.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tendermint::abci::Event as AbciEvent;
@@ -1264,4 +2424,147 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn ibc_to_abci_channel_upgrade_events() {
+        let upgrade_init: AbciEvent = UpgradeInit::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ChannelId::new(1),
+            vec![ConnectionId::zero()],
+            Version::new("ics20-1".to_string()),
+            Order::Unordered,
+            1,
+        )
+        .into();
+
+        assert_eq!(upgrade_init.kind, CHANNEL_UPGRADE_INIT_EVENT);
+        let attributes: Vec<(&str, &str)> = upgrade_init
+            .attributes
+            .iter()
+            .map(|a| (a.key_str().unwrap(), a.value_str().unwrap()))
+            .collect();
+        assert_eq!(
+            attributes,
+            vec![
+                ("port_id", "transfer"),
+                ("channel_id", "channel-0"),
+                ("counterparty_port_id", "transfer"),
+                ("counterparty_channel_id", "channel-1"),
+                ("upgrade_connection_hops", "connection-0"),
+                ("upgrade_version", "ics20-1"),
+                ("upgrade_ordering", "ORDER_UNORDERED"),
+                ("upgrade_sequence", "1"),
+            ]
+        );
+
+        let upgrade_error: AbciEvent = UpgradeError::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ChannelId::new(1),
+            2,
+            "upgrade timeout has elapsed".to_string(),
+        )
+        .into();
+
+        assert_eq!(upgrade_error.kind, CHANNEL_UPGRADE_ERROR_EVENT);
+        let last = upgrade_error.attributes.last().unwrap();
+        assert_eq!(last.key_str().unwrap(), "error_receipt");
+        assert_eq!(last.value_str().unwrap(), "upgrade timeout has elapsed");
+    }
+
+    #[test]
+    fn abci_to_ibc_channel_events() {
+        let open_init = OpenInit::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ConnectionId::zero(),
+            Version::new("ics20-1".to_string()),
+        );
+        assert_eq!(
+            OpenInit::try_from(AbciEvent::from(open_init.clone())).unwrap(),
+            open_init
+        );
+        assert!(matches!(
+            OpenTry::try_from(AbciEvent::from(open_init)),
+            Err(ChannelError::MismatchedEventType { .. })
+        ));
+
+        let channel_closed = ChannelClosed::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            None,
+            ConnectionId::zero(),
+            Order::Ordered,
+        );
+        assert_eq!(
+            ChannelClosed::try_from(AbciEvent::from(channel_closed.clone())).unwrap(),
+            channel_closed
+        );
+
+        let upgrade_timeout = UpgradeTimeout::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ChannelId::new(1),
+            TimeoutHeight::Never,
+            Timestamp::from_nanoseconds(10).unwrap(),
+            1,
+        );
+        assert_eq!(
+            UpgradeTimeout::try_from(AbciEvent::from(upgrade_timeout.clone())).unwrap(),
+            upgrade_timeout
+        );
+    }
+
+    #[test]
+    fn abci_to_ibc_packet_events() {
+        let packet = Packet {
+            seq_on_a: 1.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: b"packet data".to_vec(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(10).unwrap(),
+        };
+        let write_ack = WriteAcknowledgement::new(
+            packet,
+            Acknowledgement::try_from(b"ack".to_vec()).unwrap(),
+            ConnectionId::zero(),
+        );
+        let abci_event = AbciEvent::try_from(write_ack.clone()).unwrap();
+
+        assert_eq!(
+            WriteAcknowledgement::try_from(abci_event.clone()).unwrap(),
+            write_ack
+        );
+
+        // Events emitted without the hex-encoded attributes are parsed from
+        // the UTF-8 ones.
+        let mut utf8_event = abci_event.clone();
+        utf8_event
+            .attributes
+            .retain(|a| !a.key_str().unwrap().ends_with("_hex"));
+        assert_eq!(
+            WriteAcknowledgement::try_from(utf8_event).unwrap(),
+            write_ack
+        );
+
+        let mut malformed_event = abci_event;
+        for attribute in malformed_event.attributes.iter_mut() {
+            if attribute.key_str().unwrap() == "packet_data_hex" {
+                *attribute = ("packet_data_hex", "not hex").into();
+            }
+        }
+        assert!(matches!(
+            WriteAcknowledgement::try_from(malformed_event),
+            Err(ChannelError::InvalidEventAttribute { .. })
+        ));
+    }
 }
@@ -10,6 +10,7 @@ use ibc_primitives::Timestamp;
 use subtle_encoding::hex;
 use tendermint::abci;
 
+use super::{event_attribute, invalid_event_attribute, parse_event_attribute};
 use crate::acknowledgement::Acknowledgement;
 use crate::channel::Order;
 use crate::error::ChannelError;
@@ -69,6 +70,17 @@ impl TryFrom<PacketDataAttribute> for Vec<abci::EventAttribute> {
     }
 }
 
+/// Parses the packet data from the hex-encoded attribute, falling back on the
+/// deprecated UTF-8 one when the former is absent.
+impl TryFrom<&abci::Event> for PacketDataAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        bytes_event_attribute(event, PKT_DATA_HEX_ATTRIBUTE_KEY, PKT_DATA_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -98,6 +110,18 @@ impl From<TimeoutHeightAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for TimeoutHeightAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        let value = event_attribute(event, PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY)?;
+
+        TimeoutHeight::from_event_attribute_value(value)
+            .map(Self::from)
+            .map_err(|e| invalid_event_attribute(PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY, e))
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -126,6 +150,15 @@ impl From<TimeoutTimestampAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for TimeoutTimestampAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<Timestamp>(event, PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -150,6 +183,14 @@ impl From<SequenceAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for SequenceAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<Sequence>(event, PKT_SEQ_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -174,6 +215,14 @@ impl From<SrcPortIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for SrcPortIdAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<PortId>(event, PKT_SRC_PORT_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -198,6 +247,14 @@ impl From<SrcChannelIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for SrcChannelIdAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<ChannelId>(event, PKT_SRC_CHANNEL_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -222,6 +279,14 @@ impl From<DstPortIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for DstPortIdAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<PortId>(event, PKT_DST_PORT_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -246,6 +311,14 @@ impl From<DstChannelIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for DstChannelIdAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<ChannelId>(event, PKT_DST_CHANNEL_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -270,6 +343,14 @@ impl From<ChannelOrderingAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for ChannelOrderingAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<Order>(event, PKT_CHANNEL_ORDERING_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -294,6 +375,15 @@ impl From<PacketConnectionIdAttribute> for abci::EventAttribute {
     }
 }
 
+impl TryFrom<&abci::Event> for PacketConnectionIdAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<ConnectionId>(event, PKT_CONNECTION_ID_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -338,3 +428,34 @@ impl TryFrom<AcknowledgementAttribute> for Vec<abci::EventAttribute> {
         Ok(tags)
     }
 }
+
+/// Parses the acknowledgement from the hex-encoded attribute, falling back on
+/// the deprecated UTF-8 one when the former is absent.
+impl TryFrom<&abci::Event> for AcknowledgementAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        let acknowledgement =
+            bytes_event_attribute(event, PKT_ACK_HEX_ATTRIBUTE_KEY, PKT_ACK_ATTRIBUTE_KEY)?;
+
+        Acknowledgement::try_from(acknowledgement)
+            .map(Self::from)
+            .map_err(|e| invalid_event_attribute(PKT_ACK_HEX_ATTRIBUTE_KEY, e))
+    }
+}
+
+/// Returns the bytes held by the hex-encoded attribute of an ABCI event, or by
+/// its UTF-8 counterpart when the event has no hex-encoded one.
+fn bytes_event_attribute(
+    event: &abci::Event,
+    hex_key: &str,
+    utf8_key: &str,
+) -> Result<Vec<u8>, ChannelError> {
+    match event_attribute(event, hex_key) {
+        Ok(value) => hex::decode(value).map_err(|e| invalid_event_attribute(hex_key, e)),
+        Err(ChannelError::MissingEventAttribute { .. }) => {
+            event_attribute(event, utf8_key).map(|value| value.as_bytes().to_vec())
+        }
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,317 @@
+//! This module holds all the abci event attributes for IBC events emitted
+//! during the channel upgrade handshake.
+use core::str::FromStr;
+
+use derive_more::From;
+use ibc_core_host_types::identifiers::ConnectionId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use tendermint::abci;
+
+use super::{event_attribute, invalid_event_attribute, parse_event_attribute};
+use crate::channel::{Order, State};
+use crate::error::ChannelError;
+use crate::timeout::TimeoutHeight;
+use crate::Version;
+
+const UPGRADE_CONNECTION_HOPS_ATTRIBUTE_KEY: &str = "upgrade_connection_hops";
+const UPGRADE_VERSION_ATTRIBUTE_KEY: &str = "upgrade_version";
+const UPGRADE_ORDERING_ATTRIBUTE_KEY: &str = "upgrade_ordering";
+const UPGRADE_SEQUENCE_ATTRIBUTE_KEY: &str = "upgrade_sequence";
+const CHANNEL_STATE_ATTRIBUTE_KEY: &str = "channel_state";
+const UPGRADE_TIMEOUT_HEIGHT_ATTRIBUTE_KEY: &str = "upgrade_timeout_height";
+const UPGRADE_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "upgrade_timeout_timestamp";
+const ERROR_RECEIPT_ATTRIBUTE_KEY: &str = "error_receipt";
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeConnectionHopsAttribute {
+    pub upgrade_connection_hops: Vec<ConnectionId>,
+}
+
+impl From<UpgradeConnectionHopsAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeConnectionHopsAttribute) -> Self {
+        let hops: Vec<&str> = attr
+            .upgrade_connection_hops
+            .iter()
+            .map(ConnectionId::as_str)
+            .collect();
+        (UPGRADE_CONNECTION_HOPS_ATTRIBUTE_KEY, hops.join(",")).into()
+    }
+}
+
+impl TryFrom<&abci::Event> for UpgradeConnectionHopsAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        let upgrade_connection_hops =
+            event_attribute(event, UPGRADE_CONNECTION_HOPS_ATTRIBUTE_KEY)?
+                .split(',')
+                .filter(|hop| !hop.is_empty())
+                .map(|hop| {
+                    ConnectionId::from_str(hop).map_err(|e| {
+                        invalid_event_attribute(UPGRADE_CONNECTION_HOPS_ATTRIBUTE_KEY, e)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::from(upgrade_connection_hops))
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeVersionAttribute {
+    pub upgrade_version: Version,
+}
+
+impl From<UpgradeVersionAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeVersionAttribute) -> Self {
+        (UPGRADE_VERSION_ATTRIBUTE_KEY, attr.upgrade_version.as_str()).into()
+    }
+}
+
+impl TryFrom<&abci::Event> for UpgradeVersionAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<Version>(event, UPGRADE_VERSION_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeOrderingAttribute {
+    pub upgrade_ordering: Order,
+}
+
+impl From<UpgradeOrderingAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeOrderingAttribute) -> Self {
+        (
+            UPGRADE_ORDERING_ATTRIBUTE_KEY,
+            attr.upgrade_ordering.as_str(),
+        )
+            .into()
+    }
+}
+
+impl TryFrom<&abci::Event> for UpgradeOrderingAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<Order>(event, UPGRADE_ORDERING_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeSequenceAttribute {
+    pub upgrade_sequence: u64,
+}
+
+impl From<UpgradeSequenceAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeSequenceAttribute) -> Self {
+        (
+            UPGRADE_SEQUENCE_ATTRIBUTE_KEY,
+            attr.upgrade_sequence.to_string(),
+        )
+            .into()
+    }
+}
+
+impl TryFrom<&abci::Event> for UpgradeSequenceAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<u64>(event, UPGRADE_SEQUENCE_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct ChannelStateAttribute {
+    pub channel_state: State,
+}
+
+impl From<ChannelStateAttribute> for abci::EventAttribute {
+    fn from(attr: ChannelStateAttribute) -> Self {
+        (CHANNEL_STATE_ATTRIBUTE_KEY, attr.channel_state.as_string()).into()
+    }
+}
+
+impl TryFrom<&abci::Event> for ChannelStateAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<State>(event, CHANNEL_STATE_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeTimeoutHeightAttribute {
+    pub upgrade_timeout_height: TimeoutHeight,
+}
+
+impl From<UpgradeTimeoutHeightAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeTimeoutHeightAttribute) -> Self {
+        (
+            UPGRADE_TIMEOUT_HEIGHT_ATTRIBUTE_KEY,
+            attr.upgrade_timeout_height.to_event_attribute_value(),
+        )
+            .into()
+    }
+}
+
+impl TryFrom<&abci::Event> for UpgradeTimeoutHeightAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        let value = event_attribute(event, UPGRADE_TIMEOUT_HEIGHT_ATTRIBUTE_KEY)?;
+
+        TimeoutHeight::from_event_attribute_value(value)
+            .map(Self::from)
+            .map_err(|e| invalid_event_attribute(UPGRADE_TIMEOUT_HEIGHT_ATTRIBUTE_KEY, e))
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeTimeoutTimestampAttribute {
+    pub upgrade_timeout_timestamp: Timestamp,
+}
+
+impl From<UpgradeTimeoutTimestampAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeTimeoutTimestampAttribute) -> Self {
+        (
+            UPGRADE_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY,
+            attr.upgrade_timeout_timestamp.nanoseconds().to_string(),
+        )
+            .into()
+    }
+}
+
+impl TryFrom<&abci::Event> for UpgradeTimeoutTimestampAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<Timestamp>(event, UPGRADE_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY)
+            .map(Self::from)
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct ErrorReceiptAttribute {
+    pub error_receipt: String,
+}
+
+impl From<ErrorReceiptAttribute> for abci::EventAttribute {
+    fn from(attr: ErrorReceiptAttribute) -> Self {
+        (ERROR_RECEIPT_ATTRIBUTE_KEY, attr.error_receipt).into()
+    }
+}
+
+impl TryFrom<&abci::Event> for ErrorReceiptAttribute {
+    type Error = ChannelError;
+
+    fn try_from(event: &abci::Event) -> Result<Self, Self::Error> {
+        parse_event_attribute::<String>(event, ERROR_RECEIPT_ATTRIBUTE_KEY).map(Self::from)
+    }
+}
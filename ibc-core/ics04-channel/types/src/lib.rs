@@ -21,8 +21,11 @@ pub mod error;
 pub mod events;
 
 pub mod msgs;
+pub mod multihop;
 pub mod packet;
 pub mod timeout;
+pub mod upgrade;
+pub mod v2;
 
 pub mod acknowledgement;
 pub mod commitment;
@@ -27,6 +27,9 @@ pub struct MsgChannelCloseConfirm {
     pub proof_chan_end_on_a: CommitmentProofBytes,
     pub proof_height_on_a: Height,
     pub signer: Signer,
+    /// The upgrade sequence of the counterparty channel end, which is part
+    /// of the closed channel end proven by `proof_chan_end_on_a`.
+    pub counterparty_upgrade_sequence: u64,
 }
 
 impl Protobuf<RawMsgChannelCloseConfirm> for MsgChannelCloseConfirm {}
@@ -35,10 +38,6 @@ impl TryFrom<RawMsgChannelCloseConfirm> for MsgChannelCloseConfirm {
     type Error = ChannelError;
 
     fn try_from(raw_msg: RawMsgChannelCloseConfirm) -> Result<Self, Self::Error> {
-        if raw_msg.counterparty_upgrade_sequence != 0 {
-            return Err(ChannelError::UnsupportedChannelUpgradeSequence);
-        }
-
         Ok(MsgChannelCloseConfirm {
             port_id_on_b: raw_msg.port_id.parse()?,
             chan_id_on_b: raw_msg.channel_id.parse()?,
@@ -51,6 +50,7 @@ impl TryFrom<RawMsgChannelCloseConfirm> for MsgChannelCloseConfirm {
                 .and_then(|raw_height| raw_height.try_into().ok())
                 .ok_or(ChannelError::MissingHeight)?,
             signer: raw_msg.signer.into(),
+            counterparty_upgrade_sequence: raw_msg.counterparty_upgrade_sequence,
         })
     }
 }
@@ -63,7 +63,7 @@ impl From<MsgChannelCloseConfirm> for RawMsgChannelCloseConfirm {
             proof_init: domain_msg.proof_chan_end_on_a.clone().into(),
             proof_height: Some(domain_msg.proof_height_on_a.into()),
             signer: domain_msg.signer.to_string(),
-            counterparty_upgrade_sequence: 0,
+            counterparty_upgrade_sequence: domain_msg.counterparty_upgrade_sequence,
         }
     }
 }
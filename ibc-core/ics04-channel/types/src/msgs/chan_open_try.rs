@@ -6,7 +6,7 @@ use ibc_primitives::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenTry as RawMsgChannelOpenTry;
 use ibc_proto::Protobuf;
 
-use crate::channel::{verify_connection_hops_length, ChannelEnd, Counterparty, Order, State};
+use crate::channel::{verify_connection_hops_not_empty, ChannelEnd, Counterparty, Order, State};
 use crate::error::ChannelError;
 use crate::Version;
 
@@ -39,11 +39,11 @@ pub struct MsgChannelOpenTry {
 }
 
 impl MsgChannelOpenTry {
-    /// Checks if the `connection_hops` has a length of `expected`.
+    /// Checks that the `connection_hops` holds at least one connection.
     ///
-    /// Note: Current IBC version only supports one connection hop.
+    /// Note: More than one connection hop makes an ICS-33 multi-hop channel.
     pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
-        verify_connection_hops_length(&self.connection_hops_on_b, 1)
+        verify_connection_hops_not_empty(&self.connection_hops_on_b)
     }
 }
 
@@ -6,7 +6,7 @@ use ibc_primitives::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
 use ibc_proto::Protobuf;
 
-use crate::error::{ChannelError, PacketError};
+use crate::error::PacketError;
 use crate::packet::Packet;
 
 pub const TIMEOUT_ON_CLOSE_TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeoutOnClose";
@@ -27,6 +27,9 @@ pub struct MsgTimeoutOnClose {
     pub proof_close_on_b: CommitmentProofBytes,
     pub proof_height_on_b: Height,
     pub signer: Signer,
+    /// The upgrade sequence of the counterparty channel end, which is part
+    /// of the closed channel end proven by `proof_close_on_b`.
+    pub counterparty_upgrade_sequence: u64,
 }
 
 impl Protobuf<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {}
@@ -39,12 +42,6 @@ impl TryFrom<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {
             return Err(PacketError::ZeroPacketSequence);
         }
 
-        if raw_msg.counterparty_upgrade_sequence != 0 {
-            return Err(PacketError::Channel(
-                ChannelError::UnsupportedChannelUpgradeSequence,
-            ));
-        }
-
         Ok(MsgTimeoutOnClose {
             packet: raw_msg
                 .packet
@@ -64,6 +61,7 @@ impl TryFrom<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {
                 .and_then(|raw_height| raw_height.try_into().ok())
                 .ok_or(PacketError::MissingHeight)?,
             signer: raw_msg.signer.into(),
+            counterparty_upgrade_sequence: raw_msg.counterparty_upgrade_sequence,
         })
     }
 }
@@ -77,7 +75,7 @@ impl From<MsgTimeoutOnClose> for RawMsgTimeoutOnClose {
             proof_height: Some(domain_msg.proof_height_on_b.into()),
             next_sequence_recv: domain_msg.next_seq_recv_on_b.into(),
             signer: domain_msg.signer.to_string(),
-            counterparty_upgrade_sequence: 0,
+            counterparty_upgrade_sequence: domain_msg.counterparty_upgrade_sequence,
         }
     }
 }
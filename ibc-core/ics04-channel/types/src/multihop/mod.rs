@@ -0,0 +1,263 @@
+//! Implementation of the ICS-33 multi-hop proofs.
+//!
+//! A multi-hop channel runs over a path of connections, listed by its
+//! `connection_hops`, that goes through one or more intermediate chains. The
+//! host only has a client of the first intermediate chain: any value stored by
+//! the counterparty is thus proven through a chain of proofs, where each
+//! intermediate chain proves its connection end towards the next chain and the
+//! consensus state of the next chain it stores.
+//!
+//! The encoded [`MsgMultihopProofs`] is carried in the proof fields of the
+//! channel and packet messages, in place of the single-hop commitment proof.
+
+pub mod raw;
+
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_host_types::identifiers::ConnectionId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_proto::Protobuf;
+use prost::Message;
+
+use self::raw::{MsgMultihopProofs as RawMsgMultihopProofs, MultihopProof as RawMultihopProof};
+use crate::error::ChannelError;
+
+/// The proof that an intermediate chain stores the connection end towards the
+/// next chain of the path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectionHopProof {
+    pub proof: CommitmentProofBytes,
+    pub connection_end: ConnectionEnd,
+}
+
+impl TryFrom<RawMultihopProof> for ConnectionHopProof {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawMultihopProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proof: multihop_proof_bytes(raw.proof)?,
+            connection_end: ConnectionEnd::decode_vec(&raw.value).map_err(|e| {
+                ChannelError::InvalidMultihopProof {
+                    reason: format!("invalid connection end: {e}"),
+                }
+            })?,
+        })
+    }
+}
+
+impl From<ConnectionHopProof> for RawMultihopProof {
+    fn from(hop_proof: ConnectionHopProof) -> Self {
+        RawMultihopProof {
+            proof: hop_proof.proof.into(),
+            value: hop_proof.connection_end.encode_vec(),
+            height: None,
+        }
+    }
+}
+
+/// The proof that an intermediate chain stores the consensus state of the next
+/// chain of the path at the given height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsensusHopProof {
+    pub proof: CommitmentProofBytes,
+    pub consensus_height: Height,
+    pub consensus_state: Any,
+}
+
+impl TryFrom<RawMultihopProof> for ConsensusHopProof {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawMultihopProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proof: multihop_proof_bytes(raw.proof)?,
+            consensus_height: raw
+                .height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(ChannelError::MissingHeight)?,
+            consensus_state: Any::decode(raw.value.as_slice()).map_err(|e| {
+                ChannelError::InvalidMultihopProof {
+                    reason: format!("invalid consensus state: {e}"),
+                }
+            })?,
+        })
+    }
+}
+
+impl From<ConsensusHopProof> for RawMultihopProof {
+    fn from(hop_proof: ConsensusHopProof) -> Self {
+        RawMultihopProof {
+            proof: hop_proof.proof.into(),
+            value: hop_proof.consensus_state.encode_to_vec(),
+            height: Some(hop_proof.consensus_height.into()),
+        }
+    }
+}
+
+/// The proofs of a value stored by the counterparty of a multi-hop channel.
+///
+/// Both the connection and the consensus proofs are ordered from the first
+/// intermediate chain, whose client is held by the host, to the last one,
+/// which holds the client of the counterparty.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MsgMultihopProofs {
+    /// Proof of the value on the counterparty chain
+    pub key_proof: CommitmentProofBytes,
+    pub connection_proofs: Vec<ConnectionHopProof>,
+    pub consensus_proofs: Vec<ConsensusHopProof>,
+}
+
+impl MsgMultihopProofs {
+    /// Checks that the proofs cover all the intermediate chains of the given
+    /// connection hops.
+    pub fn verify_hops_count(&self, connection_hops: &[ConnectionId]) -> Result<(), ChannelError> {
+        let expected = connection_hops.len().saturating_sub(1);
+
+        if self.connection_proofs.len() != expected || self.consensus_proofs.len() != expected {
+            return Err(ChannelError::InvalidMultihopProof {
+                reason: format!(
+                    "expected {expected} connection and consensus proofs, got {} and {}",
+                    self.connection_proofs.len(),
+                    self.consensus_proofs.len()
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawMsgMultihopProofs> for MsgMultihopProofs {}
+
+impl TryFrom<RawMsgMultihopProofs> for MsgMultihopProofs {
+    type Error = ChannelError;
+
+    fn try_from(raw: RawMsgMultihopProofs) -> Result<Self, Self::Error> {
+        Ok(Self {
+            key_proof: multihop_proof_bytes(raw.key_proof)?,
+            connection_proofs: raw
+                .connection_proofs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            consensus_proofs: raw
+                .consensus_proofs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<MsgMultihopProofs> for RawMsgMultihopProofs {
+    fn from(proofs: MsgMultihopProofs) -> Self {
+        RawMsgMultihopProofs {
+            key_proof: proofs.key_proof.into(),
+            connection_proofs: proofs
+                .connection_proofs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            consensus_proofs: proofs
+                .consensus_proofs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<&CommitmentProofBytes> for MsgMultihopProofs {
+    type Error = ChannelError;
+
+    fn try_from(proof: &CommitmentProofBytes) -> Result<Self, Self::Error> {
+        Self::decode_vec(proof.as_ref()).map_err(|e| ChannelError::InvalidMultihopProof {
+            reason: e.to_string(),
+        })
+    }
+}
+
+impl TryFrom<MsgMultihopProofs> for CommitmentProofBytes {
+    type Error = ChannelError;
+
+    fn try_from(proofs: MsgMultihopProofs) -> Result<Self, Self::Error> {
+        multihop_proof_bytes(proofs.encode_vec())
+    }
+}
+
+fn multihop_proof_bytes(bytes: Vec<u8>) -> Result<CommitmentProofBytes, ChannelError> {
+    CommitmentProofBytes::try_from(bytes).map_err(|e| ChannelError::InvalidMultihopProof {
+        reason: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use ibc_core_commitment_types::commitment::CommitmentPrefix;
+    use ibc_core_connection_types::version::Version as ConnectionVersion;
+    use ibc_core_connection_types::{Counterparty, State};
+    use ibc_core_host_types::identifiers::ClientId;
+
+    use super::*;
+
+    fn dummy_proofs() -> MsgMultihopProofs {
+        let connection_end = ConnectionEnd::new(
+            State::Open,
+            ClientId::new("07-tendermint", 1).unwrap(),
+            Counterparty::new(
+                ClientId::new("07-tendermint", 2).unwrap(),
+                Some(ConnectionId::new(3)),
+                CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+            ),
+            ConnectionVersion::compatibles(),
+            Duration::ZERO,
+        )
+        .unwrap();
+
+        MsgMultihopProofs {
+            key_proof: vec![1].try_into().unwrap(),
+            connection_proofs: vec![ConnectionHopProof {
+                proof: vec![2].try_into().unwrap(),
+                connection_end,
+            }],
+            consensus_proofs: vec![ConsensusHopProof {
+                proof: vec![3].try_into().unwrap(),
+                consensus_height: Height::new(0, 10).unwrap(),
+                consensus_state: Any {
+                    type_url: "/ibc.mock.ConsensusState".to_string(),
+                    value: vec![4],
+                },
+            }],
+        }
+    }
+
+    #[test]
+    fn multihop_proofs_roundtrip_through_proof_bytes() {
+        let proofs = dummy_proofs();
+
+        let proof_bytes = CommitmentProofBytes::try_from(proofs.clone()).unwrap();
+
+        assert_eq!(MsgMultihopProofs::try_from(&proof_bytes).unwrap(), proofs);
+    }
+
+    #[test]
+    fn multihop_proofs_hops_count() {
+        let proofs = dummy_proofs();
+
+        let hops = |n: u64| (0..n).map(ConnectionId::new).collect::<Vec<_>>();
+
+        assert!(proofs.verify_hops_count(&hops(2)).is_ok());
+        assert!(proofs.verify_hops_count(&hops(1)).is_err());
+        assert!(proofs.verify_hops_count(&hops(3)).is_err());
+    }
+
+    #[test]
+    fn multihop_proofs_reject_single_hop_proof() {
+        let proof_bytes = CommitmentProofBytes::try_from(vec![0xff, 0x01]).unwrap();
+
+        assert!(MsgMultihopProofs::try_from(&proof_bytes).is_err());
+    }
+}
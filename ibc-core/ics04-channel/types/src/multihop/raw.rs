@@ -0,0 +1,44 @@
+//! Protobuf definitions of the ICS-33 multi-hop proofs. They are defined here
+//! until `ibc-proto` ships them.
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height;
+
+/// The proof of a value stored by an intermediate chain of a multi-hop channel.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MultihopProof {
+    /// the commitment proof of the value
+    #[prost(bytes = "vec", tag = "1")]
+    pub proof: Vec<u8>,
+    /// the proven value
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: Vec<u8>,
+    /// the height of the consensus state, for consensus state proofs only
+    #[prost(message, optional, tag = "3")]
+    pub height: Option<Height>,
+}
+
+impl prost::Name for MultihopProof {
+    const NAME: &'static str = "MultihopProof";
+    const PACKAGE: &'static str = "ibc.core.channel.v1";
+}
+
+/// The proofs of a value stored by the counterparty of a multi-hop channel,
+/// along with the proofs of the connections and consensus states that chain
+/// it to the host.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgMultihopProofs {
+    /// the proof of the value on the counterparty chain
+    #[prost(bytes = "vec", tag = "1")]
+    pub key_proof: Vec<u8>,
+    /// the proofs of the connection ends of the intermediate chains
+    #[prost(message, repeated, tag = "2")]
+    pub connection_proofs: Vec<MultihopProof>,
+    /// the proofs of the consensus states stored by the intermediate chains
+    #[prost(message, repeated, tag = "3")]
+    pub consensus_proofs: Vec<MultihopProof>,
+}
+
+impl prost::Name for MsgMultihopProofs {
+    const NAME: &'static str = "MsgMultihopProofs";
+    const PACKAGE: &'static str = "ibc.core.channel.v1";
+}
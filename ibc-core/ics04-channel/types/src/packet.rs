@@ -19,7 +19,8 @@ pub enum PacketMsgType {
     TimeoutOnClose,
 }
 
-/// Packet receipt, used over unordered channels.
+/// Packet receipt, used over unordered channels, and over ordered allow timeout
+/// channels to record the packets that timed out.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -33,9 +34,20 @@ pub enum PacketMsgType {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Receipt {
     Ok,
+    Timeout,
+}
+
+impl Receipt {
+    /// Returns the value that the host commits to at the receipt path.
+    pub fn to_vec(&self) -> Vec<u8> {
+        match self {
+            Self::Ok => vec![1],
+            Self::Timeout => vec![2],
+        }
+    }
 }
 
 impl core::fmt::Display for PacketMsgType {
@@ -1,9 +1,10 @@
 //! Types and utilities pertaining to packet timeouts.
 
 use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
 
 use ibc_core_client_types::error::ClientError;
-use ibc_core_client_types::Height;
+use ibc_core_client_types::{Height, HeightError};
 use ibc_primitives::prelude::*;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
@@ -82,6 +83,15 @@ impl TimeoutHeight {
             TimeoutHeight::Never => "0-0".into(),
         }
     }
+
+    /// Parses a value formatted for an ABCI event attribute, as returned by
+    /// [`TimeoutHeight::to_event_attribute_value`].
+    pub fn from_event_attribute_value(value: &str) -> Result<Self, HeightError> {
+        match value {
+            "0-0" => Ok(TimeoutHeight::Never),
+            _ => Height::from_str(value).map(TimeoutHeight::At),
+        }
+    }
 }
 
 impl TryFrom<RawHeight> for TimeoutHeight {
@@ -0,0 +1,367 @@
+//! Types pertaining to the channel upgrade handshake, as described in
+//! [ICS-04](https://github.com/cosmos/ibc/blob/main/spec/core/ics-004-channel-and-packet-semantics/UPGRADES.md).
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::utils::PrettySlice;
+use ibc_primitives::Timestamp;
+use ibc_proto::ibc::core::channel::v1::{
+    ErrorReceipt as RawErrorReceipt, Timeout as RawUpgradeTimeout, Upgrade as RawUpgrade,
+    UpgradeFields as RawUpgradeFields,
+};
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+use ibc_proto::Protobuf;
+
+use crate::channel::{verify_connection_hops_length, ChannelEnd, Order};
+use crate::error::ChannelError;
+use crate::timeout::TimeoutHeight;
+use crate::Version;
+
+/// The parameters of a channel end that may be modified by an upgrade.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeFields {
+    pub ordering: Order,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+}
+
+impl UpgradeFields {
+    pub fn new(ordering: Order, connection_hops: Vec<ConnectionId>, version: Version) -> Self {
+        Self {
+            ordering,
+            connection_hops,
+            version,
+        }
+    }
+
+    pub fn validate_basic(&self) -> Result<(), ChannelError> {
+        if self.ordering == Order::None {
+            return Err(ChannelError::InvalidOrderType {
+                expected: "Channel ordering cannot be None".to_string(),
+                actual: self.ordering.to_string(),
+            });
+        }
+
+        verify_connection_hops_length(&self.connection_hops, 1)?;
+
+        if self.version.is_empty() {
+            return Err(ChannelError::Other {
+                description: "upgrade version cannot be empty".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if applying these fields to the given channel end
+    /// would leave its parameters unchanged.
+    pub fn is_identical_to(&self, channel_end: &ChannelEnd) -> bool {
+        self.ordering == channel_end.ordering
+            && self.connection_hops == channel_end.connection_hops
+            && self.version == channel_end.version
+    }
+}
+
+impl Display for UpgradeFields {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "UpgradeFields {{ ordering: {}, connection_hops: {}, version: {} }}",
+            self.ordering,
+            PrettySlice(&self.connection_hops),
+            self.version
+        )
+    }
+}
+
+impl Protobuf<RawUpgradeFields> for UpgradeFields {}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = ChannelError;
+
+    fn try_from(value: RawUpgradeFields) -> Result<Self, Self::Error> {
+        let connection_hops = value
+            .connection_hops
+            .into_iter()
+            .map(|conn_id| ConnectionId::from_str(conn_id.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let fields = Self {
+            ordering: Order::from_i32(value.ordering)?,
+            connection_hops,
+            version: value.version.into(),
+        };
+
+        fields.validate_basic()?;
+
+        Ok(fields)
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(value: UpgradeFields) -> Self {
+        RawUpgradeFields {
+            ordering: value.ordering as i32,
+            connection_hops: value
+                .connection_hops
+                .iter()
+                .map(|v| v.as_str().to_string())
+                .collect(),
+            version: value.version.to_string(),
+        }
+    }
+}
+
+/// The absolute timeout of a channel upgrade, after which the counterparty
+/// must not move to `FLUSHCOMPLETE` and the upgrade may be cancelled.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UpgradeTimeout {
+    pub height: TimeoutHeight,
+    pub timestamp: Timestamp,
+}
+
+impl UpgradeTimeout {
+    /// Creates a new `UpgradeTimeout`, checking that at least one of the
+    /// height or the timestamp is set.
+    pub fn new(height: TimeoutHeight, timestamp: Timestamp) -> Result<Self, ChannelError> {
+        if !height.is_set() && !timestamp.is_set() {
+            return Err(ChannelError::InvalidUpgradeTimeout);
+        }
+
+        Ok(Self { height, timestamp })
+    }
+
+    /// Checks whether the timeout has passed relative to the given height
+    /// and timestamp of the chain on which it is evaluated.
+    ///
+    /// Note: unlike packet timeouts, an upgrade timeout is reached once the
+    /// chain is *at or past* the timeout height/timestamp, as in ibc-go.
+    pub fn has_expired(&self, height: Height, timestamp: &Timestamp) -> bool {
+        let height_expired = match self.height {
+            TimeoutHeight::At(timeout_height) => height >= timeout_height,
+            TimeoutHeight::Never => false,
+        };
+
+        let timestamp_expired =
+            self.timestamp.is_set() && timestamp.nanoseconds() >= self.timestamp.nanoseconds();
+
+        height_expired || timestamp_expired
+    }
+}
+
+impl Display for UpgradeTimeout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "UpgradeTimeout {{ height: {}, timestamp: {} }}",
+            self.height, self.timestamp
+        )
+    }
+}
+
+impl Protobuf<RawUpgradeTimeout> for UpgradeTimeout {}
+
+impl TryFrom<RawUpgradeTimeout> for UpgradeTimeout {
+    type Error = ChannelError;
+
+    fn try_from(value: RawUpgradeTimeout) -> Result<Self, Self::Error> {
+        let height = TimeoutHeight::try_from(value.height)
+            .map_err(|_| ChannelError::InvalidUpgradeTimeout)?;
+
+        let timestamp = Timestamp::from_nanoseconds(value.timestamp)
+            .map_err(|_| ChannelError::InvalidUpgradeTimeout)?;
+
+        Self::new(height, timestamp)
+    }
+}
+
+impl From<UpgradeTimeout> for RawUpgradeTimeout {
+    fn from(value: UpgradeTimeout) -> Self {
+        RawUpgradeTimeout {
+            height: value.height.into(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// A proposed upgrade of a channel end, stored by each end while the
+/// upgrade handshake is in progress.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Upgrade {
+    pub fields: UpgradeFields,
+    /// The timeout is only set once the counterparty is known, i.e. after
+    /// `ChanUpgradeTry` on the responder or `ChanUpgradeAck` on the initiator.
+    pub timeout: Option<UpgradeTimeout>,
+    /// The next sequence to be sent on the channel end when flushing started.
+    /// It is `0` until the end starts flushing.
+    pub next_sequence_send: Sequence,
+}
+
+impl Upgrade {
+    pub fn new(
+        fields: UpgradeFields,
+        timeout: Option<UpgradeTimeout>,
+        next_sequence_send: Sequence,
+    ) -> Self {
+        Self {
+            fields,
+            timeout,
+            next_sequence_send,
+        }
+    }
+
+    /// Returns the timeout of this upgrade, failing if none has been set.
+    pub fn timeout(&self) -> Result<&UpgradeTimeout, ChannelError> {
+        self.timeout
+            .as_ref()
+            .ok_or(ChannelError::MissingUpgradeTimeout)
+    }
+}
+
+impl Protobuf<RawUpgrade> for Upgrade {}
+
+impl TryFrom<RawUpgrade> for Upgrade {
+    type Error = ChannelError;
+
+    fn try_from(value: RawUpgrade) -> Result<Self, Self::Error> {
+        let fields = value
+            .fields
+            .ok_or(ChannelError::MissingUpgradeFields)?
+            .try_into()?;
+
+        // ibc-go encodes an unset timeout as a zero height and timestamp.
+        let timeout = match value.timeout {
+            Some(RawUpgradeTimeout {
+                height:
+                    None
+                    | Some(RawHeight {
+                        revision_number: 0,
+                        revision_height: 0,
+                    }),
+                timestamp: 0,
+            })
+            | None => None,
+            Some(raw_timeout) => Some(raw_timeout.try_into()?),
+        };
+
+        Ok(Self {
+            fields,
+            timeout,
+            next_sequence_send: value.next_sequence_send.into(),
+        })
+    }
+}
+
+impl From<Upgrade> for RawUpgrade {
+    fn from(value: Upgrade) -> Self {
+        RawUpgrade {
+            fields: Some(value.fields.into()),
+            timeout: Some(value.timeout.map_or_else(
+                || RawUpgradeTimeout {
+                    height: Some(RawHeight {
+                        revision_number: 0,
+                        revision_height: 0,
+                    }),
+                    timestamp: 0,
+                },
+                Into::into,
+            )),
+            next_sequence_send: value.next_sequence_send.into(),
+        }
+    }
+}
+
+/// A record of an aborted upgrade attempt, written so that the counterparty
+/// can prove the failure and cancel the upgrade on its end.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorReceipt {
+    /// The upgrade sequence at which the upgrade was aborted.
+    pub sequence: u64,
+    pub message: String,
+}
+
+impl ErrorReceipt {
+    pub fn new(sequence: u64, message: String) -> Self {
+        Self { sequence, message }
+    }
+}
+
+impl Protobuf<RawErrorReceipt> for ErrorReceipt {}
+
+impl From<RawErrorReceipt> for ErrorReceipt {
+    fn from(value: RawErrorReceipt) -> Self {
+        Self {
+            sequence: value.sequence,
+            message: value.message,
+        }
+    }
+}
+
+impl From<ErrorReceipt> for RawErrorReceipt {
+    fn from(value: ErrorReceipt) -> Self {
+        RawErrorReceipt {
+            sequence: value.sequence,
+            message: value.message,
+        }
+    }
+}
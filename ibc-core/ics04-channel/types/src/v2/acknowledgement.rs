@@ -0,0 +1,124 @@
+//! Defines the IBC v2 acknowledgement type.
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+
+use super::raw::Acknowledgement as RawAcknowledgement;
+use crate::error::PacketError;
+
+/// The acknowledgement written by the receiving chain when any of the payloads
+/// of a packet fails to be received: the SHA-256 hash of
+/// `UNIVERSAL_ERROR_ACKNOWLEDGEMENT`, as defined by `ibc-go`.
+///
+/// It is then the only application acknowledgement of the packet, and is
+/// passed as such to the application of every payload on the sending chain.
+pub const UNIVERSAL_ERROR_ACKNOWLEDGEMENT: [u8; 32] = [
+    0x47, 0x74, 0xd4, 0xa5, 0x75, 0x99, 0x3f, 0x96, 0x3b, 0x1c, 0x06, 0x57, 0x37, 0x36, 0x61, 0x7a,
+    0x45, 0x7a, 0xbe, 0xf8, 0x58, 0x91, 0x78, 0xdb, 0x8d, 0x10, 0xc9, 0x4b, 0x4a, 0xb5, 0x11, 0xab,
+];
+
+/// The acknowledgement of an IBC v2 packet, holding the acknowledgement of the
+/// application of each of its payloads, in order.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Acknowledgement {
+    app_acknowledgements: Vec<Vec<u8>>,
+}
+
+impl Acknowledgement {
+    /// Builds the acknowledgement of a packet from the acknowledgements of
+    /// its payloads, none of which may be empty.
+    pub fn new(app_acknowledgements: Vec<Vec<u8>>) -> Result<Self, PacketError> {
+        if app_acknowledgements.is_empty() || app_acknowledgements.iter().any(Vec::is_empty) {
+            return Err(PacketError::InvalidAcknowledgement);
+        }
+
+        Ok(Self {
+            app_acknowledgements,
+        })
+    }
+
+    /// Returns the acknowledgement written when receiving a packet fails.
+    pub fn universal_error() -> Self {
+        Self {
+            app_acknowledgements: vec![UNIVERSAL_ERROR_ACKNOWLEDGEMENT.to_vec()],
+        }
+    }
+
+    /// Returns whether the packet failed to be received.
+    pub fn is_universal_error(&self) -> bool {
+        self.app_acknowledgements.len() == 1
+            && self.app_acknowledgements[0] == UNIVERSAL_ERROR_ACKNOWLEDGEMENT
+    }
+
+    pub fn app_acknowledgements(&self) -> &[Vec<u8>] {
+        &self.app_acknowledgements
+    }
+
+    /// Returns the acknowledgement to pass to the application of the payload
+    /// at the given index, if any.
+    pub fn app_acknowledgement(&self, payload_index: usize) -> Option<&[u8]> {
+        if self.is_universal_error() {
+            return Some(&UNIVERSAL_ERROR_ACKNOWLEDGEMENT);
+        }
+
+        self.app_acknowledgements
+            .get(payload_index)
+            .map(Vec::as_slice)
+    }
+}
+
+impl Protobuf<RawAcknowledgement> for Acknowledgement {}
+
+impl TryFrom<RawAcknowledgement> for Acknowledgement {
+    type Error = PacketError;
+
+    fn try_from(raw_ack: RawAcknowledgement) -> Result<Self, Self::Error> {
+        Self::new(raw_ack.app_acknowledgements)
+    }
+}
+
+impl From<Acknowledgement> for RawAcknowledgement {
+    fn from(ack: Acknowledgement) -> Self {
+        RawAcknowledgement {
+            app_acknowledgements: ack.app_acknowledgements,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::Digest;
+
+    use super::*;
+
+    #[test]
+    fn universal_error_acknowledgement() {
+        let expected: [u8; 32] = sha2::Sha256::digest(b"UNIVERSAL_ERROR_ACKNOWLEDGEMENT").into();
+
+        assert_eq!(UNIVERSAL_ERROR_ACKNOWLEDGEMENT, expected);
+
+        let ack = Acknowledgement::universal_error();
+        assert!(ack.is_universal_error());
+        assert_eq!(ack.app_acknowledgement(3), Some(&expected[..]));
+    }
+
+    #[test]
+    fn empty_acknowledgement() {
+        assert!(Acknowledgement::new(vec![]).is_err());
+        assert!(Acknowledgement::new(vec![vec![1], vec![]]).is_err());
+        assert!(Acknowledgement::new(vec![vec![1], vec![2]]).is_ok());
+    }
+}
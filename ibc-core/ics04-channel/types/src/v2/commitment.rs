@@ -0,0 +1,50 @@
+//! Commitments of the IBC v2 packets and acknowledgements, as computed by
+//! `ibc-go`.
+use ibc_primitives::prelude::*;
+
+use super::acknowledgement::Acknowledgement;
+use super::packet::{Packet, Payload};
+use crate::commitment::{hash, AcknowledgementCommitment, PacketCommitment};
+
+/// The version byte prefixed to the IBC v2 commitments, so that they never
+/// collide with the commitments of ICS-04 packets.
+const COMMITMENT_VERSION: u8 = 2;
+
+/// Computes the commitment of an IBC v2 packet.
+///
+/// The packet commits to its destination client, its timeout and its payloads.
+/// Its sequence and source client are committed to by the path it is stored
+/// at.
+pub fn compute_packet_commitment(packet: &Packet) -> PacketCommitment {
+    let payloads_hash_input: Vec<u8> = packet.payloads.iter().flat_map(hash_payload).collect();
+
+    let mut hash_input = Vec::with_capacity(1 + 32 * 3);
+    hash_input.push(COMMITMENT_VERSION);
+    hash_input.extend_from_slice(&hash(packet.client_id_on_b.as_bytes()));
+    hash_input.extend_from_slice(&hash(&packet.timeout_timestamp_secs().to_be_bytes()));
+    hash_input.extend_from_slice(&hash(&payloads_hash_input));
+
+    hash(&hash_input).to_vec().into()
+}
+
+/// Computes the commitment of the acknowledgement of an IBC v2 packet.
+pub fn compute_ack_commitment(ack: &Acknowledgement) -> AcknowledgementCommitment {
+    let mut hash_input = vec![COMMITMENT_VERSION];
+
+    for app_ack in ack.app_acknowledgements() {
+        hash_input.extend_from_slice(&hash(app_ack));
+    }
+
+    hash(&hash_input).to_vec().into()
+}
+
+fn hash_payload(payload: &Payload) -> [u8; 32] {
+    let mut hash_input = Vec::with_capacity(32 * 5);
+    hash_input.extend_from_slice(&hash(payload.port_id_on_a.as_bytes()));
+    hash_input.extend_from_slice(&hash(payload.port_id_on_b.as_bytes()));
+    hash_input.extend_from_slice(&hash(payload.version.as_bytes()));
+    hash_input.extend_from_slice(&hash(payload.encoding.as_bytes()));
+    hash_input.extend_from_slice(&hash(&payload.value));
+
+    hash(&hash_input)
+}
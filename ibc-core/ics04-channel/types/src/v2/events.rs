@@ -0,0 +1,399 @@
+//! Types for the IBC v2 events emitted from Tendermint Websocket by the
+//! channel-less packet handlers.
+//!
+//! The events have the same types as their ICS-04 counterparts, and are told
+//! apart by the attribute holding the whole encoded packet.
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+use subtle_encoding::hex;
+use tendermint::abci;
+
+use super::acknowledgement::Acknowledgement;
+use super::packet::Packet;
+use crate::error::ChannelError;
+use crate::events::{
+    event_attribute, invalid_event_attribute, verify_event_type, ACK_PACKET_EVENT,
+    RECEIVE_PACKET_EVENT, SEND_PACKET_EVENT, TIMEOUT_EVENT, WRITE_ACK_EVENT,
+};
+
+const SRC_CLIENT_ID_ATTRIBUTE_KEY: &str = "packet_source_client";
+const DST_CLIENT_ID_ATTRIBUTE_KEY: &str = "packet_dest_client";
+const SEQUENCE_ATTRIBUTE_KEY: &str = "packet_sequence";
+const TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_timeout_timestamp";
+pub const ENCODED_PACKET_ATTRIBUTE_KEY: &str = "encoded_packet_hex";
+const ENCODED_ACK_ATTRIBUTE_KEY: &str = "encoded_acknowledgement_hex";
+
+/// Returns whether an ABCI event was emitted by the IBC v2 packet handlers.
+pub fn is_v2_packet_event(event: &abci::Event) -> bool {
+    event.attributes.iter().any(|attribute| {
+        attribute
+            .key_str()
+            .is_ok_and(|k| k == ENCODED_PACKET_ATTRIBUTE_KEY)
+    })
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SendPacket {
+    packet: Packet,
+}
+
+impl SendPacket {
+    pub fn new(packet: Packet) -> Self {
+        Self { packet }
+    }
+
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    pub fn event_type(&self) -> &str {
+        SEND_PACKET_EVENT
+    }
+}
+
+impl From<SendPacket> for abci::Event {
+    fn from(v: SendPacket) -> Self {
+        abci::Event {
+            kind: SEND_PACKET_EVENT.to_string(),
+            attributes: packet_attributes(v.packet),
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for SendPacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, SEND_PACKET_EVENT)?;
+
+        Ok(Self {
+            packet: packet_from_event(&event)?,
+        })
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReceivePacket {
+    packet: Packet,
+}
+
+impl ReceivePacket {
+    pub fn new(packet: Packet) -> Self {
+        Self { packet }
+    }
+
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    pub fn event_type(&self) -> &str {
+        RECEIVE_PACKET_EVENT
+    }
+}
+
+impl From<ReceivePacket> for abci::Event {
+    fn from(v: ReceivePacket) -> Self {
+        abci::Event {
+            kind: RECEIVE_PACKET_EVENT.to_string(),
+            attributes: packet_attributes(v.packet),
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for ReceivePacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, RECEIVE_PACKET_EVENT)?;
+
+        Ok(Self {
+            packet: packet_from_event(&event)?,
+        })
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WriteAcknowledgement {
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+}
+
+impl WriteAcknowledgement {
+    pub fn new(packet: Packet, acknowledgement: Acknowledgement) -> Self {
+        Self {
+            packet,
+            acknowledgement,
+        }
+    }
+
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    pub fn acknowledgement(&self) -> &Acknowledgement {
+        &self.acknowledgement
+    }
+
+    pub fn event_type(&self) -> &str {
+        WRITE_ACK_EVENT
+    }
+}
+
+impl From<WriteAcknowledgement> for abci::Event {
+    fn from(v: WriteAcknowledgement) -> Self {
+        let mut attributes = packet_attributes(v.packet);
+        attributes.push(
+            (
+                ENCODED_ACK_ATTRIBUTE_KEY,
+                hex_str(&v.acknowledgement.encode_vec()),
+            )
+                .into(),
+        );
+
+        abci::Event {
+            kind: WRITE_ACK_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for WriteAcknowledgement {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, WRITE_ACK_EVENT)?;
+
+        let encoded_ack = hex::decode(event_attribute(&event, ENCODED_ACK_ATTRIBUTE_KEY)?)
+            .map_err(|e| invalid_event_attribute(ENCODED_ACK_ATTRIBUTE_KEY, e))?;
+
+        Ok(Self {
+            packet: packet_from_event(&event)?,
+            acknowledgement: Acknowledgement::decode_vec(&encoded_ack)
+                .map_err(|e| invalid_event_attribute(ENCODED_ACK_ATTRIBUTE_KEY, e))?,
+        })
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AcknowledgePacket {
+    packet: Packet,
+}
+
+impl AcknowledgePacket {
+    pub fn new(packet: Packet) -> Self {
+        Self { packet }
+    }
+
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    pub fn event_type(&self) -> &str {
+        ACK_PACKET_EVENT
+    }
+}
+
+impl From<AcknowledgePacket> for abci::Event {
+    fn from(v: AcknowledgePacket) -> Self {
+        abci::Event {
+            kind: ACK_PACKET_EVENT.to_string(),
+            attributes: packet_attributes(v.packet),
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for AcknowledgePacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, ACK_PACKET_EVENT)?;
+
+        Ok(Self {
+            packet: packet_from_event(&event)?,
+        })
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimeoutPacket {
+    packet: Packet,
+}
+
+impl TimeoutPacket {
+    pub fn new(packet: Packet) -> Self {
+        Self { packet }
+    }
+
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    pub fn event_type(&self) -> &str {
+        TIMEOUT_EVENT
+    }
+}
+
+impl From<TimeoutPacket> for abci::Event {
+    fn from(v: TimeoutPacket) -> Self {
+        abci::Event {
+            kind: TIMEOUT_EVENT.to_string(),
+            attributes: packet_attributes(v.packet),
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for TimeoutPacket {
+    type Error = ChannelError;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        verify_event_type(&event, TIMEOUT_EVENT)?;
+
+        Ok(Self {
+            packet: packet_from_event(&event)?,
+        })
+    }
+}
+
+/// Returns the attributes of an event about an IBC v2 packet, as emitted by
+/// `ibc-go`.
+fn packet_attributes(packet: Packet) -> Vec<abci::EventAttribute> {
+    vec![
+        (SRC_CLIENT_ID_ATTRIBUTE_KEY, packet.client_id_on_a.as_str()).into(),
+        (DST_CLIENT_ID_ATTRIBUTE_KEY, packet.client_id_on_b.as_str()).into(),
+        (SEQUENCE_ATTRIBUTE_KEY, packet.seq_on_a.to_string()).into(),
+        (
+            TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY,
+            packet.timeout_timestamp_secs().to_string(),
+        )
+            .into(),
+        (ENCODED_PACKET_ATTRIBUTE_KEY, hex_str(&packet.encode_vec())).into(),
+    ]
+}
+
+/// Decodes the packet of an event from its encoded attribute, which holds all
+/// the other attributes.
+fn packet_from_event(event: &abci::Event) -> Result<Packet, ChannelError> {
+    let encoded_packet = hex::decode(event_attribute(event, ENCODED_PACKET_ATTRIBUTE_KEY)?)
+        .map_err(|e| invalid_event_attribute(ENCODED_PACKET_ATTRIBUTE_KEY, e))?;
+
+    Packet::decode_vec(&encoded_packet)
+        .map_err(|e| invalid_event_attribute(ENCODED_PACKET_ATTRIBUTE_KEY, e))
+}
+
+fn hex_str(bytes: &[u8]) -> String {
+    String::from_utf8(hex::encode(bytes)).expect("Never fails because hexadecimal is valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_host_types::identifiers::{ClientId, PortId, Sequence};
+    use ibc_primitives::Timestamp;
+
+    use super::*;
+    use crate::v2::packet::Payload;
+
+    fn dummy_packet() -> Packet {
+        Packet {
+            seq_on_a: Sequence::from(1),
+            client_id_on_a: ClientId::new("07-tendermint", 0).unwrap(),
+            client_id_on_b: ClientId::new("07-tendermint", 1).unwrap(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap(),
+            payloads: vec![Payload {
+                port_id_on_a: PortId::transfer(),
+                port_id_on_b: PortId::transfer(),
+                version: "ics20-1".to_string(),
+                encoding: "application/json".to_string(),
+                value: b"data".to_vec(),
+            }],
+        }
+    }
+
+    #[test]
+    fn v2_packet_events_roundtrip() {
+        let packet = dummy_packet();
+
+        let event: abci::Event = SendPacket::new(packet.clone()).into();
+        assert!(is_v2_packet_event(&event));
+        assert_eq!(
+            event_attribute(&event, TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY).unwrap(),
+            "1700000000"
+        );
+        assert_eq!(
+            SendPacket::try_from(event).unwrap(),
+            SendPacket::new(packet.clone())
+        );
+
+        let write_ack =
+            WriteAcknowledgement::new(packet.clone(), Acknowledgement::universal_error());
+        let event: abci::Event = write_ack.clone().into();
+        assert_eq!(WriteAcknowledgement::try_from(event).unwrap(), write_ack);
+
+        let event: abci::Event = TimeoutPacket::new(packet).into();
+        assert!(AcknowledgePacket::try_from(event).is_err());
+    }
+}
@@ -0,0 +1,13 @@
+//! Implementation of the IBC v2 packet data structures.
+//!
+//! IBC v2 does without channels and connections: packets are sent from a
+//! client of the sending chain to its registered counterparty client on the
+//! receiving chain, and each carries one or more payloads addressed to the
+//! ports of the applications on both ends.
+
+pub mod acknowledgement;
+pub mod commitment;
+pub mod events;
+pub mod msgs;
+pub mod packet;
+pub mod raw;
@@ -0,0 +1,64 @@
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::{Signer, Timestamp};
+use ibc_proto::Protobuf;
+
+use crate::error::PacketError;
+use crate::v2::packet::{timeout_from_secs, timeout_to_secs, Payload};
+use crate::v2::raw::MsgSendPacket as RawMsgSendPacket;
+
+pub const SEND_PACKET_TYPE_URL: &str = "/ibc.core.channel.v2.MsgSendPacket";
+
+///
+/// Message definition for the "packet sending" datagram of IBC v2.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgSendPacket {
+    /// The client through which the packet is sent
+    pub client_id_on_a: ClientId,
+    /// The timeout of the packet on the receiving chain
+    pub timeout_timestamp_on_b: Timestamp,
+    /// The payloads of the packet
+    pub payloads: Vec<Payload>,
+    /// The signer of the message
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgSendPacket> for MsgSendPacket {}
+
+impl TryFrom<RawMsgSendPacket> for MsgSendPacket {
+    type Error = PacketError;
+
+    fn try_from(raw_msg: RawMsgSendPacket) -> Result<Self, Self::Error> {
+        if raw_msg.payloads.is_empty() {
+            return Err(PacketError::EmptyPayloads);
+        }
+
+        Ok(MsgSendPacket {
+            client_id_on_a: raw_msg.source_client.parse()?,
+            timeout_timestamp_on_b: timeout_from_secs(raw_msg.timeout_timestamp)?,
+            payloads: raw_msg
+                .payloads
+                .into_iter()
+                .map(Payload::try_from)
+                .collect::<Result<_, _>>()?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgSendPacket> for RawMsgSendPacket {
+    fn from(domain_msg: MsgSendPacket) -> Self {
+        RawMsgSendPacket {
+            source_client: domain_msg.client_id_on_a.to_string(),
+            timeout_timestamp: timeout_to_secs(&domain_msg.timeout_timestamp_on_b),
+            payloads: domain_msg.payloads.into_iter().map(Into::into).collect(),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::Protobuf;
+
+use crate::error::PacketError;
+use crate::v2::packet::Packet;
+use crate::v2::raw::MsgTimeout as RawMsgTimeout;
+
+pub const TIMEOUT_TYPE_URL: &str = "/ibc.core.channel.v2.MsgTimeout";
+
+///
+/// Message definition for the timeouts of IBC v2 packets.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgTimeout {
+    pub packet: Packet,
+    /// Proof that the packet was not received by the receiving chain
+    pub proof_unreceived_on_b: CommitmentProofBytes,
+    /// Height at which the non-membership proof in this message were taken
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgTimeout> for MsgTimeout {}
+
+impl TryFrom<RawMsgTimeout> for MsgTimeout {
+    type Error = PacketError;
+
+    fn try_from(raw_msg: RawMsgTimeout) -> Result<Self, Self::Error> {
+        Ok(MsgTimeout {
+            packet: raw_msg
+                .packet
+                .ok_or(PacketError::MissingPacket)?
+                .try_into()?,
+            proof_unreceived_on_b: raw_msg
+                .proof_unreceived
+                .try_into()
+                .map_err(|_| PacketError::InvalidProof)?,
+            proof_height_on_b: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(PacketError::MissingHeight)?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgTimeout> for RawMsgTimeout {
+    fn from(domain_msg: MsgTimeout) -> Self {
+        RawMsgTimeout {
+            packet: Some(domain_msg.packet.into()),
+            proof_unreceived: domain_msg.proof_unreceived_on_b.into(),
+            proof_height: Some(domain_msg.proof_height_on_b.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,301 @@
+//! Defines the IBC v2 packet and payload types.
+use ibc_core_host_types::identifiers::{ClientId, PortId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Expiry::Expired;
+use ibc_primitives::Timestamp;
+use ibc_proto::Protobuf;
+
+use super::raw::{Packet as RawPacket, Payload as RawPayload};
+use crate::error::PacketError;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// The IBC v2 packet, sent from a client of the sending chain to its
+/// counterparty client on the receiving chain.
+///
+/// Unlike ICS-04 packets, it is not bound to a channel: it is keyed by the
+/// identifiers of the two clients, and carries one or more payloads, each
+/// addressed to an application port.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Packet {
+    pub seq_on_a: Sequence,
+    pub client_id_on_a: ClientId,
+    pub client_id_on_b: ClientId,
+    /// The timeout of the packet on the receiving chain. IBC v2 packets time
+    /// out at a given timestamp only, with a precision of one second.
+    pub timeout_timestamp_on_b: Timestamp,
+    pub payloads: Vec<Payload>,
+}
+
+impl Packet {
+    /// Checks whether the packet timed out at the given timestamp of the
+    /// receiving chain.
+    pub fn timed_out(&self, dst_chain_ts: &Timestamp) -> bool {
+        matches!(
+            dst_chain_ts.check_expiry(&self.timeout_timestamp_on_b),
+            Expired
+        )
+    }
+
+    /// Returns the timeout of the packet in seconds since the unix epoch, as
+    /// committed to by the sending chain.
+    pub fn timeout_timestamp_secs(&self) -> u64 {
+        timeout_to_secs(&self.timeout_timestamp_on_b)
+    }
+}
+
+/// Converts a timeout in seconds since the unix epoch, as carried by the raw
+/// IBC v2 types, into a timestamp. IBC v2 packets must have a timeout.
+pub(crate) fn timeout_from_secs(secs: u64) -> Result<Timestamp, PacketError> {
+    if secs == 0 {
+        return Err(PacketError::MissingTimeout);
+    }
+
+    let nanoseconds = secs
+        .checked_mul(NANOS_PER_SECOND)
+        .ok_or(PacketError::InvalidTimeoutTimestamp)?;
+
+    Timestamp::from_nanoseconds(nanoseconds).map_err(PacketError::InvalidPacketTimestamp)
+}
+
+/// Converts a timeout into seconds since the unix epoch, truncating its
+/// sub-second precision.
+pub(crate) fn timeout_to_secs(timestamp: &Timestamp) -> u64 {
+    timestamp.nanoseconds() / NANOS_PER_SECOND
+}
+
+impl Protobuf<RawPacket> for Packet {}
+
+impl TryFrom<RawPacket> for Packet {
+    type Error = PacketError;
+
+    fn try_from(raw_pkt: RawPacket) -> Result<Self, Self::Error> {
+        if Sequence::from(raw_pkt.sequence).is_zero() {
+            return Err(PacketError::ZeroPacketSequence);
+        }
+
+        if raw_pkt.payloads.is_empty() {
+            return Err(PacketError::EmptyPayloads);
+        }
+
+        Ok(Packet {
+            seq_on_a: Sequence::from(raw_pkt.sequence),
+            client_id_on_a: raw_pkt.source_client.parse()?,
+            client_id_on_b: raw_pkt.destination_client.parse()?,
+            timeout_timestamp_on_b: timeout_from_secs(raw_pkt.timeout_timestamp)?,
+            payloads: raw_pkt
+                .payloads
+                .into_iter()
+                .map(Payload::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<Packet> for RawPacket {
+    fn from(packet: Packet) -> Self {
+        RawPacket {
+            sequence: packet.seq_on_a.value(),
+            timeout_timestamp: packet.timeout_timestamp_secs(),
+            source_client: packet.client_id_on_a.to_string(),
+            destination_client: packet.client_id_on_b.to_string(),
+            payloads: packet.payloads.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The data sent by an application of the sending chain to an application of
+/// the receiving chain, within an IBC v2 packet.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Payload {
+    pub port_id_on_a: PortId,
+    pub port_id_on_b: PortId,
+    pub version: String,
+    pub encoding: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "ibc_core_commitment_types::serializer::ser_hex_upper")
+    )]
+    pub value: Vec<u8>,
+}
+
+impl Protobuf<RawPayload> for Payload {}
+
+impl TryFrom<RawPayload> for Payload {
+    type Error = PacketError;
+
+    fn try_from(raw_payload: RawPayload) -> Result<Self, Self::Error> {
+        if raw_payload.version.is_empty() || raw_payload.encoding.is_empty() {
+            return Err(PacketError::InvalidPayload {
+                reason: "version and encoding cannot be empty".to_string(),
+            });
+        }
+
+        if raw_payload.value.is_empty() {
+            return Err(PacketError::ZeroPacketData);
+        }
+
+        Ok(Payload {
+            port_id_on_a: raw_payload.source_port.parse()?,
+            port_id_on_b: raw_payload.destination_port.parse()?,
+            version: raw_payload.version,
+            encoding: raw_payload.encoding,
+            value: raw_payload.value,
+        })
+    }
+}
+
+impl From<Payload> for RawPayload {
+    fn from(payload: Payload) -> Self {
+        RawPayload {
+            source_port: payload.port_id_on_a.to_string(),
+            destination_port: payload.port_id_on_b.to_string(),
+            version: payload.version,
+            encoding: payload.encoding,
+            value: payload.value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_raw_packet() -> RawPacket {
+        RawPacket {
+            sequence: 1,
+            source_client: "07-tendermint-0".to_string(),
+            destination_client: "07-tendermint-1".to_string(),
+            timeout_timestamp: 1_700_000_000,
+            payloads: vec![RawPayload {
+                source_port: "transfer".to_string(),
+                destination_port: "transfer".to_string(),
+                version: "ics20-1".to_string(),
+                encoding: "application/json".to_string(),
+                value: b"data".to_vec(),
+            }],
+        }
+    }
+
+    #[test]
+    fn packet_roundtrip() {
+        let raw = dummy_raw_packet();
+
+        let packet = Packet::try_from(raw.clone()).unwrap();
+
+        assert_eq!(packet.timeout_timestamp_secs(), raw.timeout_timestamp);
+        assert_eq!(RawPacket::from(packet), raw);
+    }
+
+    #[test]
+    fn packet_try_from_raw() {
+        struct Test {
+            name: String,
+            raw: RawPacket,
+            want_pass: bool,
+        }
+
+        let tests = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: dummy_raw_packet(),
+                want_pass: true,
+            },
+            Test {
+                name: "Zero sequence".to_string(),
+                raw: RawPacket {
+                    sequence: 0,
+                    ..dummy_raw_packet()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing timeout".to_string(),
+                raw: RawPacket {
+                    timeout_timestamp: 0,
+                    ..dummy_raw_packet()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "No payload".to_string(),
+                raw: RawPacket {
+                    payloads: vec![],
+                    ..dummy_raw_packet()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Bad source client".to_string(),
+                raw: RawPacket {
+                    source_client: "x".to_string(),
+                    ..dummy_raw_packet()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Empty payload value".to_string(),
+                raw: RawPacket {
+                    payloads: vec![RawPayload {
+                        value: vec![],
+                        ..dummy_raw_packet().payloads[0].clone()
+                    }],
+                    ..dummy_raw_packet()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Empty payload encoding".to_string(),
+                raw: RawPacket {
+                    payloads: vec![RawPayload {
+                        encoding: String::new(),
+                        ..dummy_raw_packet().payloads[0].clone()
+                    }],
+                    ..dummy_raw_packet()
+                },
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = Packet::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "Packet::try_from failed for test {}, \nraw packet {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res.err(),
+            );
+        }
+    }
+}
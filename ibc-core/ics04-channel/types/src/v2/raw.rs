@@ -0,0 +1,143 @@
+//! Protobuf definitions of the IBC v2 packets and messages, mirroring those of
+//! `ibc-go`. They are defined here until `ibc-proto` ships them.
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height;
+
+/// A packet sent from a source client to a destination client, carrying one
+/// or more application payloads.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Packet {
+    /// the sequence of the packet, unique for its source client
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    /// the identifier of the client on the sending chain
+    #[prost(string, tag = "2")]
+    pub source_client: String,
+    /// the identifier of the client on the receiving chain
+    #[prost(string, tag = "3")]
+    pub destination_client: String,
+    /// the timeout of the packet on the receiving chain, in seconds since the
+    /// unix epoch
+    #[prost(uint64, tag = "4")]
+    pub timeout_timestamp: u64,
+    /// the payloads of the packet
+    #[prost(message, repeated, tag = "5")]
+    pub payloads: Vec<Payload>,
+}
+
+impl prost::Name for Packet {
+    const NAME: &'static str = "Packet";
+    const PACKAGE: &'static str = "ibc.core.channel.v2";
+}
+
+/// The data sent by an application of the source chain to an application of
+/// the destination chain.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Payload {
+    /// the port of the sending application
+    #[prost(string, tag = "1")]
+    pub source_port: String,
+    /// the port of the receiving application
+    #[prost(string, tag = "2")]
+    pub destination_port: String,
+    /// the version of the application
+    #[prost(string, tag = "3")]
+    pub version: String,
+    /// the encoding of the value
+    #[prost(string, tag = "4")]
+    pub encoding: String,
+    /// the application data
+    #[prost(bytes = "vec", tag = "5")]
+    pub value: Vec<u8>,
+}
+
+impl prost::Name for Payload {
+    const NAME: &'static str = "Payload";
+    const PACKAGE: &'static str = "ibc.core.channel.v2";
+}
+
+/// The acknowledgement of a packet, holding one acknowledgement per payload.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Acknowledgement {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub app_acknowledgements: Vec<Vec<u8>>,
+}
+
+impl prost::Name for Acknowledgement {
+    const NAME: &'static str = "Acknowledgement";
+    const PACKAGE: &'static str = "ibc.core.channel.v2";
+}
+
+/// Sends a packet from the source client.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSendPacket {
+    #[prost(string, tag = "1")]
+    pub source_client: String,
+    #[prost(uint64, tag = "2")]
+    pub timeout_timestamp: u64,
+    #[prost(message, repeated, tag = "3")]
+    pub payloads: Vec<Payload>,
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+impl prost::Name for MsgSendPacket {
+    const NAME: &'static str = "MsgSendPacket";
+    const PACKAGE: &'static str = "ibc.core.channel.v2";
+}
+
+/// Receives a packet on the destination client.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgRecvPacket {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof_commitment: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+impl prost::Name for MsgRecvPacket {
+    const NAME: &'static str = "MsgRecvPacket";
+    const PACKAGE: &'static str = "ibc.core.channel.v2";
+}
+
+/// Acknowledges a packet on the source client.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgAcknowledgement {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(message, optional, tag = "2")]
+    pub acknowledgement: Option<Acknowledgement>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub proof_acked: Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "5")]
+    pub signer: String,
+}
+
+impl prost::Name for MsgAcknowledgement {
+    const NAME: &'static str = "MsgAcknowledgement";
+    const PACKAGE: &'static str = "ibc.core.channel.v2";
+}
+
+/// Times a packet out on the source client.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgTimeout {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof_unreceived: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: Option<Height>,
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+impl prost::Name for MsgTimeout {
+    const NAME: &'static str = "MsgTimeout";
+    const PACKAGE: &'static str = "ibc.core.channel.v2";
+}
@@ -3,21 +3,27 @@ use core::time::Duration;
 use ibc_core_channel_types::channel::ChannelEnd;
 use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade};
 use ibc_core_client_context::prelude::*;
-use ibc_core_client_types::Height;
+use ibc_core_client_types::{CounterpartyInfo, Height};
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
 use ibc_core_connection_types::version::{pick_version, Version as ConnectionVersion};
 use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_handler_types::metering::CostEvent;
+use ibc_core_handler_types::simulation::StateChange;
 use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
 use ibc_core_host_types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, AckV2Path, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath,
+    ClientConnectionPath, ClientCounterpartyPath, CommitmentPath, CommitmentV2Path, ConnectionPath,
+    CounterpartyUpgradePath, Path, ReceiptPath, ReceiptV2Path, SeqAckPath, SeqRecvPath,
+    SeqSendPath, SeqSendV2Path,
 };
 use ibc_primitives::prelude::*;
 use ibc_primitives::{Signer, Timestamp};
 
+use crate::localhost::{self, LocalhostClientState};
 use crate::utils::calculate_block_delay;
 
 /// Context to be implemented by the host that provides all "read-only" methods.
@@ -53,6 +59,28 @@ pub trait ValidationContext {
     /// Returns the ConnectionEnd for the given identifier `conn_id`.
     fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError>;
 
+    /// Returns the ConnectionEnd for the given identifier `conn_id`, including
+    /// the sentinel `connection-localhost`, which the host does not store.
+    ///
+    /// Handlers look connections up through this method, so that the localhost
+    /// connection is available on every host.
+    fn resolve_connection_end(
+        &self,
+        conn_id: &ConnectionId,
+    ) -> Result<ConnectionEnd, ContextError> {
+        if conn_id.is_localhost() {
+            return Ok(localhost::connection_end(self.commitment_prefix())?);
+        }
+
+        self.connection_end(conn_id)
+    }
+
+    /// Returns the sentinel `09-localhost` client state, whose latest height
+    /// is the current height of the host.
+    fn localhost_client_state(&self) -> Result<LocalhostClientState, ContextError> {
+        Ok(LocalhostClientState::new(self.host_height()?))
+    }
+
     /// Validates the `ClientState` of the host chain stored on the counterparty
     /// chain against the host's internal state.
     ///
@@ -70,6 +98,16 @@ pub trait ValidationContext {
     /// Returns the prefix that the local chain uses in the KV store.
     fn commitment_prefix(&self) -> CommitmentPrefix;
 
+    /// Returns the value stored at the given path in the host's own store, or
+    /// `None` if there is none.
+    ///
+    /// This is the hook through which the `09-localhost` client verifies the
+    /// state of the host instead of proofs. The value must therefore be
+    /// encoded as the one that would be proven to a counterparty: e.g.
+    /// protobuf-encoded connection and channel ends, raw packet and
+    /// acknowledgement commitments, and big-endian sequence numbers.
+    fn read_store(&self, path: &Path) -> Result<Option<Vec<u8>>, ContextError>;
+
     /// Returns a counter on how many connections have been created thus far.
     fn connection_counter(&self) -> Result<u64, ContextError>;
 
@@ -121,6 +159,64 @@ pub trait ValidationContext {
         ack_path: &AckPath,
     ) -> Result<AcknowledgementCommitment, ContextError>;
 
+    /// Returns the upgrade in progress for the channel end at the given path.
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError>;
+
+    /// Returns the upgrade of the counterparty channel end, as stored upon
+    /// `ChanUpgradeAck` or `ChanUpgradeConfirm`.
+    fn counterparty_upgrade(
+        &self,
+        counterparty_upgrade_path: &CounterpartyUpgradePath,
+    ) -> Result<Upgrade, ContextError>;
+
+    /// Returns the error receipt of the last aborted upgrade for the channel
+    /// end at the given path.
+    fn channel_upgrade_error(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError>;
+
+    /// Returns `true` if any packet commitment is still stored for the given
+    /// channel end, i.e. if there are packets sent on the channel that have
+    /// been neither acknowledged nor timed out.
+    fn has_inflight_packets(&self, channel_end_path: &ChannelEndPath)
+        -> Result<bool, ContextError>;
+
+    /// Returns the counterparty registered for the client at the given path,
+    /// through which IBC v2 packets are sent and received.
+    fn client_counterparty(
+        &self,
+        counterparty_path: &ClientCounterpartyPath,
+    ) -> Result<CounterpartyInfo, ContextError>;
+
+    /// Returns the sequence number for the next IBC v2 packet to be sent
+    /// through the client of the given store path.
+    fn get_next_sequence_send_v2(
+        &self,
+        seq_send_path: &SeqSendV2Path,
+    ) -> Result<Sequence, ContextError>;
+
+    /// Returns the IBC v2 packet commitment for the given store path
+    fn get_packet_commitment_v2(
+        &self,
+        commitment_path: &CommitmentV2Path,
+    ) -> Result<PacketCommitment, ContextError>;
+
+    /// Returns the IBC v2 packet receipt for the given store path
+    fn get_packet_receipt_v2(&self, receipt_path: &ReceiptV2Path) -> Result<Receipt, ContextError>;
+
+    /// Returns the IBC v2 packet acknowledgement for the given store path
+    fn get_packet_acknowledgement_v2(
+        &self,
+        ack_path: &AckV2Path,
+    ) -> Result<AcknowledgementCommitment, ContextError>;
+
+    /// Returns the duration after which a channel upgrade times out, counted
+    /// from the moment the channel end starts flushing.
+    fn channel_upgrade_timeout(&self) -> Duration {
+        Duration::from_secs(600)
+    }
+
     /// Returns a counter on the number of channel ids have been created thus far.
     /// The value of this counter should increase only via method
     /// `ExecutionContext::increase_channel_counter`.
@@ -138,6 +234,19 @@ pub trait ValidationContext {
     /// Validates the `signer` field of IBC messages, which represents the address
     /// of the user/relayer that signed the given message.
     fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+
+    /// Charges the host for the resources consumed while handling a message.
+    ///
+    /// Delegates to [`ClientValidationContext::charge`], so that the handlers
+    /// and the light clients are metered through the same hook, which hosts
+    /// override on their client validation context.
+    ///
+    /// The handlers do not charge the store accesses they make through this
+    /// context, which are left to the store of the host, see
+    /// [`ClientValidationContext::charge`].
+    fn charge(&self, event: CostEvent) -> Result<(), ContextError> {
+        self.get_client_validation_context().charge(event)
+    }
 }
 
 /// Context to be implemented by the host that provides all "write-only" methods.
@@ -229,6 +338,82 @@ pub trait ExecutionContext: ValidationContext {
         seq: Sequence,
     ) -> Result<(), ContextError>;
 
+    /// Stores the given upgrade for the channel end at the given path.
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError>;
+
+    /// Deletes the upgrade stored at the given path.
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given counterparty upgrade at the given path.
+    fn store_counterparty_upgrade(
+        &mut self,
+        counterparty_upgrade_path: &CounterpartyUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError>;
+
+    /// Deletes the counterparty upgrade stored at the given path.
+    fn delete_counterparty_upgrade(
+        &mut self,
+        counterparty_upgrade_path: &CounterpartyUpgradePath,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given error receipt at the given path, overwriting any
+    /// previously stored receipt.
+    fn store_channel_upgrade_error(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the counterparty registered for the client at the given path.
+    fn store_client_counterparty(
+        &mut self,
+        counterparty_path: &ClientCounterpartyPath,
+        counterparty: CounterpartyInfo,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given IBC v2 `nextSequenceSend` number at the given store
+    /// path
+    fn store_next_sequence_send_v2(
+        &mut self,
+        seq_send_path: &SeqSendV2Path,
+        seq: Sequence,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given IBC v2 packet commitment at the given store path
+    fn store_packet_commitment_v2(
+        &mut self,
+        commitment_path: &CommitmentV2Path,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError>;
+
+    /// Deletes the IBC v2 packet commitment at the given store path
+    fn delete_packet_commitment_v2(
+        &mut self,
+        commitment_path: &CommitmentV2Path,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given IBC v2 packet receipt at the given store path
+    fn store_packet_receipt_v2(
+        &mut self,
+        receipt_path: &ReceiptV2Path,
+        receipt: Receipt,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given IBC v2 packet acknowledgement at the given store path
+    fn store_packet_acknowledgement_v2(
+        &mut self,
+        ack_path: &AckV2Path,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError>;
+
     /// Called upon channel identifier creation (Init or Try message processing).
     /// Increases the counter which keeps track of how many channels have been created.
     fn increase_channel_counter(&mut self) -> Result<(), ContextError>;
@@ -240,6 +425,45 @@ pub trait ExecutionContext: ValidationContext {
     fn log_message(&mut self, message: String) -> Result<(), ContextError>;
 }
 
+/// Context to be implemented by hosts able to cache the writes made through
+/// their [`ExecutionContext`], so that they can be discarded.
+///
+/// Trait used for the top-level `dispatch_batch` entrypoints in the `ibc-core`
+/// crate, which roll back the writes of the messages that fail, and for the
+/// `simulate` entrypoint, which discards the writes of all the messages.
+///
+/// Caches are nested: each call to `begin_cache` opens a new cache on top of
+/// the previous one, in which all subsequent writes are kept until the cache
+/// is either committed or discarded. Reads must observe the writes of all the
+/// open caches.
+pub trait CachedExecutionContext: ExecutionContext {
+    /// Opens a new cache, on top of the current state, for all subsequent
+    /// writes, events and logs.
+    fn begin_cache(&mut self);
+
+    /// Commits the writes, events and logs of the innermost cache into the
+    /// state below it, i.e. the enclosing cache if any or the host's state.
+    ///
+    /// The cache is closed whether or not the commit succeeds.
+    fn commit_cache(&mut self) -> Result<(), ContextError>;
+
+    /// Discards the innermost cache, along with its writes, events and logs.
+    fn discard_cache(&mut self);
+
+    /// Returns the changes made to the IBC state within the innermost cache,
+    /// with one change per changed path, in a deterministic order.
+    ///
+    /// The written values must be encoded as those returned by
+    /// [`ValidationContext::read_store`].
+    fn cached_changes(&self) -> Vec<StateChange>;
+
+    /// Returns the events emitted within the innermost cache, in order.
+    fn cached_events(&self) -> Vec<IbcEvent>;
+
+    /// Returns the messages logged within the innermost cache, in order.
+    fn cached_logs(&self) -> Vec<String>;
+}
+
 /// Convenient type alias for `ClientStateRef`, providing access to client
 /// validation methods within the context.
 pub type ClientStateRef<Ctx> =
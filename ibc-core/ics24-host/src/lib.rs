@@ -22,6 +22,8 @@ pub(crate) mod utils;
 mod context;
 pub use context::*;
 
+pub mod localhost;
+
 /// Re-exports ICS-24 data structures from `ibc-core-host-types` crate.
 pub mod types {
     #[doc(inline)]
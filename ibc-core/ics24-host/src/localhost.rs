@@ -0,0 +1,99 @@
+//! Provides the sentinel `09-localhost` client and `connection-localhost`
+//! connection, which let two modules of the same host talk to each other over
+//! IBC without a relayer.
+//!
+//! Rather than verifying proofs, the localhost client reads the proven path
+//! directly from the host's own store, through [`ValidationContext::read_store`].
+//! Neither the client nor the connection are stored by the host: both are
+//! derived from its current state whenever they are looked up.
+
+use core::time::Duration;
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::version::Version as ConnectionVersion;
+use ibc_core_connection_types::{ConnectionEnd, Counterparty, State as ConnectionState};
+use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
+use ibc_core_host_types::path::Path;
+use ibc_primitives::prelude::*;
+
+use crate::ValidationContext;
+
+/// The sentinel `09-localhost` client state.
+///
+/// Its latest height is always the current height of the host, and it never
+/// expires nor gets frozen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalhostClientState {
+    latest_height: Height,
+}
+
+impl LocalhostClientState {
+    pub fn new(latest_height: Height) -> Self {
+        Self { latest_height }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    /// Validates that the proof height is not ahead of the host.
+    pub fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        if self.latest_height < proof_height {
+            return Err(ClientError::InvalidProofHeight {
+                latest_height: self.latest_height,
+                proof_height,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that the host store holds the given value at the given path.
+    pub fn verify_membership<Ctx>(
+        &self,
+        ctx: &Ctx,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError>
+    where
+        Ctx: ValidationContext + ?Sized,
+    {
+        match ctx.read_store(&path)? {
+            Some(stored_value) if stored_value == value => Ok(()),
+            _ => Err(ClientError::LocalhostMembershipFailure { path }),
+        }
+    }
+
+    /// Verifies that the host store holds no value at the given path.
+    pub fn verify_non_membership<Ctx>(&self, ctx: &Ctx, path: Path) -> Result<(), ClientError>
+    where
+        Ctx: ValidationContext + ?Sized,
+    {
+        match ctx.read_store(&path)? {
+            None => Ok(()),
+            Some(_) => Err(ClientError::LocalhostNonMembershipFailure { path }),
+        }
+    }
+}
+
+/// Returns the sentinel `connection-localhost` connection end of a host using
+/// the given commitment prefix.
+///
+/// The connection is always open, with no delay period, and is its own
+/// counterparty through the `09-localhost` client.
+pub fn connection_end(prefix: CommitmentPrefix) -> Result<ConnectionEnd, ConnectionError> {
+    ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::localhost(),
+        Counterparty::new(
+            ClientId::localhost(),
+            Some(ConnectionId::localhost()),
+            prefix,
+        ),
+        ConnectionVersion::compatibles(),
+        Duration::ZERO,
+    )
+}
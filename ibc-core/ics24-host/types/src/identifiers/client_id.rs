@@ -6,6 +6,10 @@ use ibc_primitives::prelude::*;
 use crate::error::IdentifierError;
 use crate::validate::{validate_client_identifier, validate_client_type};
 
+/// Identifier of the sentinel localhost client, through which the host
+/// verifies its own state.
+const LOCALHOST_CLIENT_ID: &str = "09-localhost";
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -66,6 +70,20 @@ impl ClientId {
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    /// Returns the identifier of the sentinel `09-localhost` client.
+    ///
+    /// Unlike other client identifiers, it is not suffixed with a counter, as
+    /// there is only ever one localhost client, which the host never stores.
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CLIENT_ID.to_string())
+    }
+
+    /// Returns `true` if this is the identifier of the sentinel
+    /// `09-localhost` client.
+    pub fn is_localhost(&self) -> bool {
+        self.as_str() == LOCALHOST_CLIENT_ID
+    }
 }
 
 impl FromStr for ClientId {
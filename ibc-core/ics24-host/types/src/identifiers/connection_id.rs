@@ -9,6 +9,10 @@ use crate::validate::validate_connection_identifier;
 
 const CONNECTION_ID_PREFIX: &str = "connection";
 
+/// Identifier of the sentinel localhost connection, which connects the host to
+/// itself through the `09-localhost` client.
+pub(crate) const LOCALHOST_CONNECTION_ID: &str = "connection-localhost";
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -61,6 +65,25 @@ impl ConnectionId {
     pub fn zero() -> Self {
         Self::new(0)
     }
+
+    /// Returns the identifier of the sentinel `connection-localhost`
+    /// connection.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::ConnectionId;
+    /// let conn_id = ConnectionId::localhost();
+    /// assert_eq!(&conn_id, "connection-localhost");
+    /// assert!(conn_id.is_localhost());
+    /// ```
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CONNECTION_ID.to_string())
+    }
+
+    /// Returns `true` if this is the identifier of the sentinel
+    /// `connection-localhost` connection.
+    pub fn is_localhost(&self) -> bool {
+        self.as_str() == LOCALHOST_CONNECTION_ID
+    }
 }
 
 /// This implementation provides a `to_string` method.
@@ -13,5 +13,6 @@ pub use channel_id::ChannelId;
 pub use client_id::ClientId;
 pub use client_type::ClientType;
 pub use connection_id::ConnectionId;
+pub(crate) use connection_id::LOCALHOST_CONNECTION_ID;
 pub use port_id::PortId;
 pub use sequence::Sequence;
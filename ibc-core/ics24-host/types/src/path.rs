@@ -29,6 +29,11 @@ pub const NEXT_SEQ_ACK_PREFIX: &str = "nextSequenceAck";
 pub const PACKET_COMMITMENT_PREFIX: &str = "commitments";
 pub const PACKET_ACK_PREFIX: &str = "acks";
 pub const PACKET_RECEIPT_PREFIX: &str = "receipts";
+pub const CHANNEL_UPGRADE_PREFIX: &str = "channelUpgrades";
+pub const UPGRADE_PREFIX: &str = "upgrades";
+pub const UPGRADE_ERROR_PREFIX: &str = "upgradeError";
+pub const COUNTERPARTY_UPGRADE_PREFIX: &str = "counterpartyUpgrade";
+pub const CLIENT_COUNTERPARTY: &str = "counterparty";
 
 pub const ITERATE_CONSENSUS_STATE_PREFIX: &str = "iterateConsensusStates";
 pub const PROCESSED_TIME: &str = "processedTime";
@@ -43,6 +48,21 @@ pub const UPGRADED_CLIENT_STATE: &str = "upgradedClient";
 pub const UPGRADED_CLIENT_CONSENSUS_STATE: &str = "upgradedConsState";
 
 /// The Path enum abstracts out the different sub-paths.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    ),
+    // The derived codecs cast the variant indices to `usize`.
+    allow(trivial_numeric_casts)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From, Display)]
 pub enum Path {
     NextClientSequence(NextClientSequencePath),
@@ -63,6 +83,14 @@ pub enum Path {
     Ack(AckPath),
     Receipt(ReceiptPath),
     UpgradeClient(UpgradeClientPath),
+    ChannelUpgrade(ChannelUpgradePath),
+    ChannelUpgradeError(ChannelUpgradeErrorPath),
+    CounterpartyUpgrade(CounterpartyUpgradePath),
+    ClientCounterparty(ClientCounterpartyPath),
+    SeqSendV2(SeqSendV2Path),
+    CommitmentV2(CommitmentV2Path),
+    AckV2(AckV2Path),
+    ReceiptV2(ReceiptV2Path),
 }
 
 #[cfg_attr(
@@ -643,6 +671,81 @@ impl ReceiptPath {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{CHANNEL_UPGRADE_PREFIX}/{UPGRADE_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}"
+)]
+pub struct ChannelUpgradePath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradePath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradePath {
+        ChannelUpgradePath(port_id.clone(), channel_id.clone())
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{CHANNEL_UPGRADE_PREFIX}/{UPGRADE_ERROR_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}"
+)]
+pub struct ChannelUpgradeErrorPath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradeErrorPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradeErrorPath {
+        ChannelUpgradeErrorPath(port_id.clone(), channel_id.clone())
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{CHANNEL_UPGRADE_PREFIX}/{COUNTERPARTY_UPGRADE_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}"
+)]
+pub struct CounterpartyUpgradePath(pub PortId, pub ChannelId);
+
+impl CounterpartyUpgradePath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> CounterpartyUpgradePath {
+        CounterpartyUpgradePath(port_id.clone(), channel_id.clone())
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -665,6 +768,144 @@ pub enum UpgradeClientPath {
     UpgradedClientConsensusState(u64),
 }
 
+/// The counterparty of a client, registered to send IBC v2 packets through it.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{CLIENT_PREFIX}/{_0}/{CLIENT_COUNTERPARTY}")]
+pub struct ClientCounterpartyPath(pub ClientId);
+
+impl ClientCounterpartyPath {
+    pub fn new(client_id: ClientId) -> ClientCounterpartyPath {
+        ClientCounterpartyPath(client_id)
+    }
+}
+
+/// The next sequence of the IBC v2 packets sent through a client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{NEXT_SEQ_SEND_PREFIX}/{_0}")]
+pub struct SeqSendV2Path(pub ClientId);
+
+impl SeqSendV2Path {
+    pub fn new(client_id: &ClientId) -> SeqSendV2Path {
+        SeqSendV2Path(client_id.clone())
+    }
+}
+
+/// The commitment of an IBC v2 packet sent through a client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{PACKET_COMMITMENT_PREFIX}/{client_id}/{SEQUENCE_PREFIX}/{sequence}")]
+pub struct CommitmentV2Path {
+    pub client_id: ClientId,
+    pub sequence: Sequence,
+}
+
+impl CommitmentV2Path {
+    pub fn new(client_id: &ClientId, sequence: Sequence) -> CommitmentV2Path {
+        CommitmentV2Path {
+            client_id: client_id.clone(),
+            sequence,
+        }
+    }
+}
+
+/// The acknowledgement commitment of an IBC v2 packet received through a client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{PACKET_ACK_PREFIX}/{client_id}/{SEQUENCE_PREFIX}/{sequence}")]
+pub struct AckV2Path {
+    pub client_id: ClientId,
+    pub sequence: Sequence,
+}
+
+impl AckV2Path {
+    pub fn new(client_id: &ClientId, sequence: Sequence) -> AckV2Path {
+        AckV2Path {
+            client_id: client_id.clone(),
+            sequence,
+        }
+    }
+}
+
+/// The receipt of an IBC v2 packet received through a client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{PACKET_RECEIPT_PREFIX}/{client_id}/{SEQUENCE_PREFIX}/{sequence}")]
+pub struct ReceiptV2Path {
+    pub client_id: ClientId,
+    pub sequence: Sequence,
+}
+
+impl ReceiptV2Path {
+    pub fn new(client_id: &ClientId, sequence: Sequence) -> ReceiptV2Path {
+        ReceiptV2Path {
+            client_id: client_id.clone(),
+            sequence,
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -724,6 +965,8 @@ impl FromStr for Path {
             .or_else(|| parse_acks(&components))
             .or_else(|| parse_receipts(&components))
             .or_else(|| parse_upgrades(&components))
+            .or_else(|| parse_channel_upgrades(&components))
+            .or_else(|| parse_v2_packet_paths(&components))
             .ok_or(PathError::ParseFailure {
                 path: s.to_string(),
             })
@@ -756,6 +999,7 @@ fn parse_client_paths(components: &[&str]) -> Option<Path> {
         match components[2] {
             CLIENT_STATE => Some(ClientStatePath(client_id).into()),
             CONNECTION_PREFIX => Some(ClientConnectionPath(client_id).into()),
+            CLIENT_COUNTERPARTY => Some(ClientCounterpartyPath(client_id).into()),
             _ => None,
         }
     } else if components.len() == 4 || components.len() == 5 {
@@ -1071,6 +1315,79 @@ fn parse_upgrades(components: &[&str]) -> Option<Path> {
     }
 }
 
+fn parse_channel_upgrades(components: &[&str]) -> Option<Path> {
+    if components.len() != 6 {
+        return None;
+    }
+
+    let first = *components.first()?;
+
+    if first != CHANNEL_UPGRADE_PREFIX {
+        return None;
+    }
+
+    let port = parse_ports(&components[2..=3]);
+    let channel = parse_channels(&components[4..=5]);
+
+    let Some(Path::Ports(PortPath(port_id))) = port else {
+        return None;
+    };
+
+    let Some(SubPath::Channels(channel_id)) = channel else {
+        return None;
+    };
+
+    match components[1] {
+        UPGRADE_PREFIX => Some(ChannelUpgradePath(port_id, channel_id).into()),
+        UPGRADE_ERROR_PREFIX => Some(ChannelUpgradeErrorPath(port_id, channel_id).into()),
+        COUNTERPARTY_UPGRADE_PREFIX => Some(CounterpartyUpgradePath(port_id, channel_id).into()),
+        _ => None,
+    }
+}
+
+/// Parses the paths of the IBC v2 packets, which are keyed by client rather
+/// than by port and channel.
+fn parse_v2_packet_paths(components: &[&str]) -> Option<Path> {
+    let first = *components.first()?;
+
+    let client_id = ClientId::from_str(components.get(1)?).ok()?;
+
+    match components.len() {
+        2 if first == NEXT_SEQ_SEND_PREFIX => Some(SeqSendV2Path(client_id).into()),
+        4 => {
+            let Some(SubPath::Sequences(sequence)) = parse_sequences(&components[2..]) else {
+                return None;
+            };
+
+            match first {
+                PACKET_COMMITMENT_PREFIX => Some(
+                    CommitmentV2Path {
+                        client_id,
+                        sequence,
+                    }
+                    .into(),
+                ),
+                PACKET_ACK_PREFIX => Some(
+                    AckV2Path {
+                        client_id,
+                        sequence,
+                    }
+                    .into(),
+                ),
+                PACKET_RECEIPT_PREFIX => Some(
+                    ReceiptV2Path {
+                        client_id,
+                        sequence,
+                    }
+                    .into(),
+                ),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1176,6 +1493,47 @@ mod tests {
         "upgradedIBCState/0/upgradedConsState",
         Path::UpgradeClient(UpgradeClientPath::UpgradedClientConsensusState(0))
     )]
+    #[case(
+        "channelUpgrades/upgrades/ports/transfer/channels/channel-0",
+        Path::ChannelUpgrade(ChannelUpgradePath(PortId::transfer(), ChannelId::zero()))
+    )]
+    #[case(
+        "channelUpgrades/upgradeError/ports/transfer/channels/channel-0",
+        Path::ChannelUpgradeError(ChannelUpgradeErrorPath(PortId::transfer(), ChannelId::zero()))
+    )]
+    #[case(
+        "channelUpgrades/counterpartyUpgrade/ports/transfer/channels/channel-0",
+        Path::CounterpartyUpgrade(CounterpartyUpgradePath(PortId::transfer(), ChannelId::zero()))
+    )]
+    #[case(
+        "clients/07-tendermint-0/counterparty",
+        Path::ClientCounterparty(ClientCounterpartyPath(ClientId::new_dummy()))
+    )]
+    #[case(
+        "nextSequenceSend/07-tendermint-0",
+        Path::SeqSendV2(SeqSendV2Path(ClientId::new_dummy()))
+    )]
+    #[case(
+        "commitments/07-tendermint-0/sequences/1",
+        Path::CommitmentV2(CommitmentV2Path {
+            client_id: ClientId::new_dummy(),
+            sequence: Sequence::from(1),
+        })
+    )]
+    #[case(
+        "acks/07-tendermint-0/sequences/1",
+        Path::AckV2(AckV2Path {
+            client_id: ClientId::new_dummy(),
+            sequence: Sequence::from(1),
+        })
+    )]
+    #[case(
+        "receipts/07-tendermint-0/sequences/1",
+        Path::ReceiptV2(ReceiptV2Path {
+            client_id: ClientId::new_dummy(),
+            sequence: Sequence::from(1),
+        })
+    )]
     fn test_successful_parsing(#[case] path_str: &str, #[case] path: Path) {
         // can be parsed into Path
         assert_eq!(Path::from_str(path_str).expect("no error"), path);
@@ -1381,6 +1739,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_v2_packet_paths_fn() {
+        let path = "commitments/07-tendermint-0/sequences/0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(
+            parse_v2_packet_paths(&components),
+            Some(Path::CommitmentV2(CommitmentV2Path {
+                client_id: ClientId::new_dummy(),
+                sequence: Sequence::from(0),
+            })),
+        );
+
+        let path = "commitments/ports/transfer/channels/channel-0/sequences/0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(parse_v2_packet_paths(&components), None);
+
+        let path = "nextSequenceRecv/07-tendermint-0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(parse_v2_packet_paths(&components), None);
+    }
+
     #[test]
     fn test_parse_upgrades_fn() {
         let path = "upgradedIBCState/0/upgradedClient";
@@ -1403,4 +1785,34 @@ mod tests {
             )),
         )
     }
+
+    #[test]
+    fn test_parse_channel_upgrades_fn() {
+        let path = "channelUpgrades/upgrades/ports/transfer/channels/channel-0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(
+            parse_channel_upgrades(&components),
+            Some(Path::ChannelUpgrade(ChannelUpgradePath(
+                PortId::transfer(),
+                ChannelId::zero()
+            ))),
+        );
+
+        let path = "channelUpgrades/upgradeError/ports/transfer/channels/channel-0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(
+            parse_channel_upgrades(&components),
+            Some(Path::ChannelUpgradeError(ChannelUpgradeErrorPath(
+                PortId::transfer(),
+                ChannelId::zero()
+            ))),
+        );
+
+        let path = "channelUpgrades/unknown/ports/transfer/channels/channel-0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(parse_channel_upgrades(&components), None);
+    }
 }
@@ -1,7 +1,7 @@
 use ibc_primitives::prelude::*;
 
 use crate::error::IdentifierError as Error;
-use crate::identifiers::{ChannelId, ConnectionId};
+use crate::identifiers::{ChannelId, ConnectionId, LOCALHOST_CONNECTION_ID};
 
 const VALID_SPECIAL_CHARS: &str = "._+-#[]<>";
 
@@ -100,10 +100,14 @@ pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
 /// Default validator function for Connection identifiers.
 ///
 /// A valid connection identifier must be between 10-64 characters as specified
-/// in the ICS-24 spec.
+/// in the ICS-24 spec. Besides the sentinel `connection-localhost`, it must be
+/// indexed by a counter.
 pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
     validate_identifier_length(id, 10, 64)?;
+    if id == LOCALHOST_CONNECTION_ID {
+        return Ok(());
+    }
     validate_named_u64_index(id, ConnectionId::prefix())?;
     Ok(())
 }
@@ -174,6 +178,12 @@ mod tests {
         validate_connection_identifier("connection-18446744073709551615").expect("success");
     }
 
+    #[test]
+    fn parse_localhost_connection_id() {
+        validate_connection_identifier("connection-localhost").expect("success");
+        validate_connection_identifier("connection-localhost1").expect_err("failure");
+    }
+
     #[test]
     fn parse_invalid_connection_id_non_indexed() {
         // invalid indexing for connection id
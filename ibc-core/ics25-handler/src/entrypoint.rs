@@ -3,25 +3,38 @@ use ibc_core_channel::handler::{
     chan_close_confirm_validate, chan_close_init_execute, chan_close_init_validate,
     chan_open_ack_execute, chan_open_ack_validate, chan_open_confirm_execute,
     chan_open_confirm_validate, chan_open_init_execute, chan_open_init_validate,
-    chan_open_try_execute, chan_open_try_validate, recv_packet_execute, recv_packet_validate,
-    timeout_packet_execute, timeout_packet_validate, TimeoutMsgType,
+    chan_open_try_execute, chan_open_try_validate, chan_upgrade_ack_execute,
+    chan_upgrade_ack_validate, chan_upgrade_cancel_execute, chan_upgrade_cancel_validate,
+    chan_upgrade_confirm_execute, chan_upgrade_confirm_validate, chan_upgrade_init_execute,
+    chan_upgrade_init_validate, chan_upgrade_open_execute, chan_upgrade_open_validate,
+    chan_upgrade_timeout_execute, chan_upgrade_timeout_validate, chan_upgrade_try_execute,
+    chan_upgrade_try_validate, recv_packet_execute, recv_packet_validate, timeout_packet_execute,
+    timeout_packet_validate, v2, TimeoutMsgType,
 };
 use ibc_core_channel::types::msgs::{
     channel_msg_to_port_id, packet_msg_to_port_id, ChannelMsg, PacketMsg,
 };
+use ibc_core_channel::types::v2::msgs::PacketMsg as PacketV2Msg;
 use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
-use ibc_core_client::handler::{create_client, update_client, upgrade_client};
+use ibc_core_client::handler::{
+    create_client, prune_consensus_states as prune_consensus_states_handler, register_counterparty,
+    update_client, upgrade_client,
+};
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::msgs::{ClientMsg, MsgUpdateOrMisbehaviour};
 use ibc_core_connection::handler::{
     conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try,
 };
 use ibc_core_connection::types::msgs::ConnectionMsg;
-use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::batch::BatchOutcome;
+use ibc_core_handler_types::error::{BatchError, ContextError};
 use ibc_core_handler_types::msgs::MsgEnvelope;
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_handler_types::simulation::SimulationOutcome;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::{CachedExecutionContext, ExecutionContext, ValidationContext};
 use ibc_core_router::router::Router;
 use ibc_core_router::types::error::RouterError;
+use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
 
 /// Entrypoint which performs both validation and message execution
@@ -40,6 +53,166 @@ where
     execute(ctx, router, msg)
 }
 
+/// Entrypoint which dispatches a batch of messages atomically
+///
+/// The messages are dispatched in order within a cache opened on top of the
+/// host's state: their writes, events and logs are committed only if all of
+/// them succeed, and are all discarded as soon as one of them fails.
+///
+/// Returns the events and logs of the batch, as committed.
+///
+/// Note that only the writes made through the context are cached: the writes
+/// of the modules to their own state are rolled back only if these modules
+/// share the cache of the host.
+pub fn dispatch_batch<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msgs: Vec<MsgEnvelope>,
+) -> Result<BatchOutcome, BatchError>
+where
+    Ctx: CachedExecutionContext,
+    <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error: Into<ClientError>,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+    <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    ctx.begin_cache();
+
+    for (index, msg) in msgs.into_iter().enumerate() {
+        if let Err(error) = dispatch(ctx, router, msg) {
+            ctx.discard_cache();
+            return Err(BatchError::Message { index, error });
+        }
+    }
+
+    commit_batch(ctx)
+}
+
+/// Entrypoint which dispatches a batch of messages, each one within its own
+/// savepoint
+///
+/// The writes, events and logs of each message are kept if it succeeds, and
+/// discarded if it fails, without aborting the rest of the batch. Those of the
+/// whole batch are committed to the host's state at once, at the end of the
+/// batch. Returns the outcome of each message, in order, along with the events
+/// and logs of the batch, as committed.
+///
+/// As with [`dispatch_batch`], only the writes made through the context are
+/// cached.
+pub fn dispatch_batch_with_savepoints<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msgs: Vec<MsgEnvelope>,
+) -> Result<(Vec<Result<(), ContextError>>, BatchOutcome), BatchError>
+where
+    Ctx: CachedExecutionContext,
+    <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error: Into<ClientError>,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+    <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    let mut outcomes = Vec::with_capacity(msgs.len());
+
+    ctx.begin_cache();
+
+    for msg in msgs {
+        ctx.begin_cache();
+
+        match dispatch(ctx, router, msg) {
+            Ok(()) => {
+                if let Err(e) = ctx.commit_cache() {
+                    ctx.discard_cache();
+                    return Err(BatchError::Commit(e));
+                }
+                outcomes.push(Ok(()));
+            }
+            Err(e) => {
+                ctx.discard_cache();
+                outcomes.push(Err(e));
+            }
+        }
+    }
+
+    let batch_outcome = commit_batch(ctx)?;
+
+    Ok((outcomes, batch_outcome))
+}
+
+/// Commits the outermost cache of a batch, returning the events and logs it
+/// holds.
+fn commit_batch<Ctx>(ctx: &mut Ctx) -> Result<BatchOutcome, BatchError>
+where
+    Ctx: CachedExecutionContext,
+{
+    let outcome = BatchOutcome {
+        events: ctx.cached_events(),
+        logs: ctx.cached_logs(),
+    };
+
+    ctx.commit_cache().map_err(BatchError::Commit)?;
+
+    Ok(outcome)
+}
+
+/// Entrypoint which simulates the dispatch of a batch of messages, without
+/// modifying the state of the host
+///
+/// The messages are dispatched in order within a cache, as with
+/// [`dispatch_batch`]. Once all of them succeed, the changes, events and logs
+/// of the cache are collected, and the cache is discarded.
+///
+/// Note that only the writes made through the context are cached: the
+/// modules of the router must thus be backed by the same cache, or be
+/// disposable copies, so as not to keep the writes made to their own state.
+pub fn simulate<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msgs: Vec<MsgEnvelope>,
+) -> Result<SimulationOutcome, BatchError>
+where
+    Ctx: CachedExecutionContext,
+    <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error: Into<ClientError>,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+    <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    ctx.begin_cache();
+
+    for (index, msg) in msgs.into_iter().enumerate() {
+        if let Err(error) = dispatch(ctx, router, msg) {
+            ctx.discard_cache();
+            return Err(BatchError::Message { index, error });
+        }
+    }
+
+    let outcome = SimulationOutcome {
+        changes: ctx.cached_changes(),
+        events: ctx.cached_events(),
+        logs: ctx.cached_logs(),
+    };
+
+    ctx.discard_cache();
+
+    Ok(outcome)
+}
+
+/// Entrypoint which prunes the expired consensus states of a client, outside
+/// of client updates
+///
+/// Prunes at most `budget` consensus states, oldest first, and returns how
+/// many were pruned. Client updates only prune a few expired consensus states
+/// each, so hosts call this entrypoint for the rest, e.g. from a governance
+/// proposal or at the end of their blocks, until it prunes fewer consensus
+/// states than the budget, meaning that the client has none expired left.
+pub fn prune_consensus_states<Ctx>(
+    ctx: &mut Ctx,
+    client_id: &ClientId,
+    budget: usize,
+) -> Result<usize, ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    prune_consensus_states_handler::validate(ctx, client_id)?;
+    prune_consensus_states_handler::execute(ctx, client_id, budget)
+}
+
 /// Entrypoint which only performs message validation
 ///
 /// If a transaction contains `n` messages `m_1` ... `m_n`, then
@@ -69,6 +242,7 @@ where
                 // authorized via a passing governance proposal
                 Ok(())
             }
+            ClientMsg::RegisterCounterparty(msg) => register_counterparty::validate(ctx, msg),
         },
         MsgEnvelope::Connection(msg) => match msg {
             ConnectionMsg::OpenInit(msg) => conn_open_init::validate(ctx, msg),
@@ -94,6 +268,13 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_validate(ctx, module, msg),
                 ChannelMsg::CloseInit(msg) => chan_close_init_validate(ctx, module, msg),
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_validate(ctx, module, msg),
+                ChannelMsg::UpgradeInit(msg) => chan_upgrade_init_validate(ctx, module, msg),
+                ChannelMsg::UpgradeTry(msg) => chan_upgrade_try_validate(ctx, module, msg),
+                ChannelMsg::UpgradeAck(msg) => chan_upgrade_ack_validate(ctx, module, msg),
+                ChannelMsg::UpgradeConfirm(msg) => chan_upgrade_confirm_validate(ctx, module, msg),
+                ChannelMsg::UpgradeOpen(msg) => chan_upgrade_open_validate(ctx, module, msg),
+                ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_validate(ctx, module, msg),
+                ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_validate(ctx, module, msg),
             }
         }
         MsgEnvelope::Packet(msg) => {
@@ -118,6 +299,13 @@ where
                 }
             }
         }
+        // IBC v2 packets are routed payload by payload by their handlers.
+        MsgEnvelope::PacketV2(msg) => match msg {
+            PacketV2Msg::Send(msg) => v2::send_packet_validate(ctx, router, msg),
+            PacketV2Msg::Recv(msg) => v2::recv_packet_validate(ctx, router, msg),
+            PacketV2Msg::Ack(msg) => v2::acknowledgement_packet_validate(ctx, router, msg),
+            PacketV2Msg::Timeout(msg) => v2::timeout_packet_validate(ctx, router, msg),
+        },
     }
 }
 
@@ -146,6 +334,7 @@ where
                 // authorized via a passing governance proposal
                 Ok(())
             }
+            ClientMsg::RegisterCounterparty(msg) => register_counterparty::execute(ctx, msg),
         },
         MsgEnvelope::Connection(msg) => match msg {
             ConnectionMsg::OpenInit(msg) => conn_open_init::execute(ctx, msg),
@@ -171,6 +360,13 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_execute(ctx, module, msg),
                 ChannelMsg::CloseInit(msg) => chan_close_init_execute(ctx, module, msg),
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_execute(ctx, module, msg),
+                ChannelMsg::UpgradeInit(msg) => chan_upgrade_init_execute(ctx, module, msg),
+                ChannelMsg::UpgradeTry(msg) => chan_upgrade_try_execute(ctx, module, msg),
+                ChannelMsg::UpgradeAck(msg) => chan_upgrade_ack_execute(ctx, module, msg),
+                ChannelMsg::UpgradeConfirm(msg) => chan_upgrade_confirm_execute(ctx, module, msg),
+                ChannelMsg::UpgradeOpen(msg) => chan_upgrade_open_execute(ctx, module, msg),
+                ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_execute(ctx, module, msg),
+                ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_execute(ctx, module, msg),
             }
         }
         MsgEnvelope::Packet(msg) => {
@@ -195,5 +391,12 @@ where
                 }
             }
         }
+        // IBC v2 packets are routed payload by payload by their handlers.
+        MsgEnvelope::PacketV2(msg) => match msg {
+            PacketV2Msg::Send(msg) => v2::send_packet_execute(ctx, router, msg),
+            PacketV2Msg::Recv(msg) => v2::recv_packet_execute(ctx, router, msg),
+            PacketV2Msg::Ack(msg) => v2::acknowledgement_packet_execute(ctx, router, msg),
+            PacketV2Msg::Timeout(msg) => v2::timeout_packet_execute(ctx, router, msg),
+        },
     }
 }
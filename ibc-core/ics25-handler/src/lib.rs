@@ -8,7 +8,11 @@
 //! to the context (e.g. [`ExecutionContext`](ibc_core_host::ExecutionContext))
 //! while processing `M`. If the transaction containing `M` consists of multiple
 //! messages, then typically the state modifications from all messages is
-//! expected to be rolled back as well.
+//! expected to be rolled back as well. Hosts without such a rollback mechanism
+//! may rely on the batch entrypoints, e.g.
+//! [`dispatch_batch`](entrypoint::dispatch_batch), which roll back the
+//! modifications made through a
+//! [`CachedExecutionContext`](ibc_core_host::CachedExecutionContext).
 #![no_std]
 #![forbid(unsafe_code)]
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
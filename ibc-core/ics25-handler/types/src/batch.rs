@@ -0,0 +1,30 @@
+//! Defines the outcome of dispatching a batch of IBC messages
+
+use ibc_primitives::prelude::*;
+
+use crate::events::IbcEvent;
+
+/// The events emitted and the messages logged by the dispatch of a batch of
+/// IBC messages, once committed to the state of the host.
+///
+/// Those of the messages whose writes were rolled back are left out.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchOutcome {
+    /// The events emitted, in order.
+    pub events: Vec<IbcEvent>,
+    /// The messages logged, in order.
+    pub logs: Vec<String>,
+}
@@ -34,6 +34,15 @@ impl From<ContextError> for ClientError {
     }
 }
 
+/// Error raised by the batch dispatch and simulation entrypoints
+#[derive(Debug, Display)]
+pub enum BatchError {
+    /// message `{index}` of the batch failed: {error}
+    Message { index: usize, error: ContextError },
+    /// failed to commit the writes of the batch: {0}
+    Commit(ContextError),
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ContextError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -46,3 +55,13 @@ impl std::error::Error for ContextError {
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Message { error: e, .. } => Some(e),
+            Self::Commit(e) => Some(e),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Defines events emitted during handling of IBC messages
 
 use displaydoc::Display;
+use ibc_core_channel_types::v2::events as ChannelV2Events;
 use ibc_core_channel_types::{error as channel_error, events as ChannelEvents};
 use ibc_core_client_types::error as client_error;
 use ibc_core_client_types::events::{self as ClientEvents};
@@ -30,6 +31,10 @@ pub enum Error {
     IncorrectEventType { event: String },
     /// module event cannot use core event types: `{event:?}`
     MalformedModuleEvent { event: ModuleEvent },
+    /// missing event attribute `{key}`
+    MissingEventAttribute { key: String },
+    /// invalid event attribute `{key}`: `{reason}`
+    InvalidEventAttribute { key: String, reason: String },
 }
 
 #[cfg(feature = "std")]
@@ -80,6 +85,14 @@ pub enum IbcEvent {
     OpenConfirmChannel(ChannelEvents::OpenConfirm),
     CloseInitChannel(ChannelEvents::CloseInit),
     CloseConfirmChannel(ChannelEvents::CloseConfirm),
+    UpgradeInitChannel(ChannelEvents::UpgradeInit),
+    UpgradeTryChannel(ChannelEvents::UpgradeTry),
+    UpgradeAckChannel(ChannelEvents::UpgradeAck),
+    UpgradeConfirmChannel(ChannelEvents::UpgradeConfirm),
+    UpgradeOpenChannel(ChannelEvents::UpgradeOpen),
+    UpgradeTimeoutChannel(ChannelEvents::UpgradeTimeout),
+    UpgradeCancelChannel(ChannelEvents::UpgradeCancel),
+    UpgradeErrorChannel(ChannelEvents::UpgradeError),
 
     SendPacket(ChannelEvents::SendPacket),
     ReceivePacket(ChannelEvents::ReceivePacket),
@@ -88,6 +101,12 @@ pub enum IbcEvent {
     TimeoutPacket(ChannelEvents::TimeoutPacket),
     ChannelClosed(ChannelEvents::ChannelClosed),
 
+    SendPacketV2(ChannelV2Events::SendPacket),
+    ReceivePacketV2(ChannelV2Events::ReceivePacket),
+    WriteAcknowledgementV2(ChannelV2Events::WriteAcknowledgement),
+    AcknowledgePacketV2(ChannelV2Events::AcknowledgePacket),
+    TimeoutPacketV2(ChannelV2Events::TimeoutPacket),
+
     Module(ModuleEvent),
     Message(MessageEvent),
 }
@@ -111,12 +130,25 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::OpenConfirmChannel(event) => event.into(),
             IbcEvent::CloseInitChannel(event) => event.into(),
             IbcEvent::CloseConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeInitChannel(event) => event.into(),
+            IbcEvent::UpgradeTryChannel(event) => event.into(),
+            IbcEvent::UpgradeAckChannel(event) => event.into(),
+            IbcEvent::UpgradeConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeOpenChannel(event) => event.into(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.into(),
+            IbcEvent::UpgradeCancelChannel(event) => event.into(),
+            IbcEvent::UpgradeErrorChannel(event) => event.into(),
             IbcEvent::SendPacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::ReceivePacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::WriteAcknowledgement(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::AcknowledgePacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::TimeoutPacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::ChannelClosed(event) => event.into(),
+            IbcEvent::SendPacketV2(event) => event.into(),
+            IbcEvent::ReceivePacketV2(event) => event.into(),
+            IbcEvent::WriteAcknowledgementV2(event) => event.into(),
+            IbcEvent::AcknowledgePacketV2(event) => event.into(),
+            IbcEvent::TimeoutPacketV2(event) => event.into(),
             IbcEvent::Module(event) => event.into(),
             IbcEvent::Message(event) => abci::Event {
                 kind: MESSAGE_EVENT.to_string(),
@@ -126,6 +158,161 @@ impl TryFrom<IbcEvent> for abci::Event {
     }
 }
 
+/// Parses an ABCI event back into the IBC event it was converted from.
+///
+/// Events whose type is not one of the core IBC event types are parsed as
+/// [`ModuleEvent`]s.
+impl TryFrom<abci::Event> for IbcEvent {
+    type Error = Error;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        use ChannelEvents::{
+            ACK_PACKET_EVENT, CHANNEL_CLOSED_EVENT, CHANNEL_CLOSE_CONFIRM_EVENT,
+            CHANNEL_CLOSE_INIT_EVENT, CHANNEL_OPEN_ACK_EVENT, CHANNEL_OPEN_CONFIRM_EVENT,
+            CHANNEL_OPEN_INIT_EVENT, CHANNEL_OPEN_TRY_EVENT, CHANNEL_UPGRADE_ACK_EVENT,
+            CHANNEL_UPGRADE_CANCEL_EVENT, CHANNEL_UPGRADE_CONFIRM_EVENT,
+            CHANNEL_UPGRADE_ERROR_EVENT, CHANNEL_UPGRADE_INIT_EVENT, CHANNEL_UPGRADE_OPEN_EVENT,
+            CHANNEL_UPGRADE_TIMEOUT_EVENT, CHANNEL_UPGRADE_TRY_EVENT, RECEIVE_PACKET_EVENT,
+            SEND_PACKET_EVENT, TIMEOUT_EVENT, WRITE_ACK_EVENT,
+        };
+        use ChannelV2Events::is_v2_packet_event;
+        use ClientEvents::{
+            CLIENT_MISBEHAVIOUR_EVENT, CREATE_CLIENT_EVENT, UPDATE_CLIENT_EVENT,
+            UPGRADE_CLIENT_EVENT,
+        };
+        use ConnectionEvents::{
+            CONNECTION_OPEN_ACK_EVENT, CONNECTION_OPEN_CONFIRM_EVENT, CONNECTION_OPEN_INIT_EVENT,
+            CONNECTION_OPEN_TRY_EVENT,
+        };
+
+        Ok(match event.kind.as_str() {
+            CREATE_CLIENT_EVENT => IbcEvent::CreateClient(event.try_into().map_err(Error::Client)?),
+            UPDATE_CLIENT_EVENT => IbcEvent::UpdateClient(event.try_into().map_err(Error::Client)?),
+            UPGRADE_CLIENT_EVENT => {
+                IbcEvent::UpgradeClient(event.try_into().map_err(Error::Client)?)
+            }
+            CLIENT_MISBEHAVIOUR_EVENT => {
+                IbcEvent::ClientMisbehaviour(event.try_into().map_err(Error::Client)?)
+            }
+            CONNECTION_OPEN_INIT_EVENT => {
+                IbcEvent::OpenInitConnection(event.try_into().map_err(Error::Connection)?)
+            }
+            CONNECTION_OPEN_TRY_EVENT => {
+                IbcEvent::OpenTryConnection(event.try_into().map_err(Error::Connection)?)
+            }
+            CONNECTION_OPEN_ACK_EVENT => {
+                IbcEvent::OpenAckConnection(event.try_into().map_err(Error::Connection)?)
+            }
+            CONNECTION_OPEN_CONFIRM_EVENT => {
+                IbcEvent::OpenConfirmConnection(event.try_into().map_err(Error::Connection)?)
+            }
+            CHANNEL_OPEN_INIT_EVENT => {
+                IbcEvent::OpenInitChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_OPEN_TRY_EVENT => {
+                IbcEvent::OpenTryChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_OPEN_ACK_EVENT => {
+                IbcEvent::OpenAckChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_OPEN_CONFIRM_EVENT => {
+                IbcEvent::OpenConfirmChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_CLOSE_INIT_EVENT => {
+                IbcEvent::CloseInitChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_CLOSE_CONFIRM_EVENT => {
+                IbcEvent::CloseConfirmChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_UPGRADE_INIT_EVENT => {
+                IbcEvent::UpgradeInitChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_UPGRADE_TRY_EVENT => {
+                IbcEvent::UpgradeTryChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_UPGRADE_ACK_EVENT => {
+                IbcEvent::UpgradeAckChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_UPGRADE_CONFIRM_EVENT => {
+                IbcEvent::UpgradeConfirmChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_UPGRADE_OPEN_EVENT => {
+                IbcEvent::UpgradeOpenChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_UPGRADE_TIMEOUT_EVENT => {
+                IbcEvent::UpgradeTimeoutChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_UPGRADE_CANCEL_EVENT => {
+                IbcEvent::UpgradeCancelChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            CHANNEL_UPGRADE_ERROR_EVENT => {
+                IbcEvent::UpgradeErrorChannel(event.try_into().map_err(Error::Channel)?)
+            }
+            SEND_PACKET_EVENT if is_v2_packet_event(&event) => {
+                IbcEvent::SendPacketV2(event.try_into().map_err(Error::Channel)?)
+            }
+            RECEIVE_PACKET_EVENT if is_v2_packet_event(&event) => {
+                IbcEvent::ReceivePacketV2(event.try_into().map_err(Error::Channel)?)
+            }
+            WRITE_ACK_EVENT if is_v2_packet_event(&event) => {
+                IbcEvent::WriteAcknowledgementV2(event.try_into().map_err(Error::Channel)?)
+            }
+            ACK_PACKET_EVENT if is_v2_packet_event(&event) => {
+                IbcEvent::AcknowledgePacketV2(event.try_into().map_err(Error::Channel)?)
+            }
+            TIMEOUT_EVENT if is_v2_packet_event(&event) => {
+                IbcEvent::TimeoutPacketV2(event.try_into().map_err(Error::Channel)?)
+            }
+            SEND_PACKET_EVENT => IbcEvent::SendPacket(event.try_into().map_err(Error::Channel)?),
+            RECEIVE_PACKET_EVENT => {
+                IbcEvent::ReceivePacket(event.try_into().map_err(Error::Channel)?)
+            }
+            WRITE_ACK_EVENT => {
+                IbcEvent::WriteAcknowledgement(event.try_into().map_err(Error::Channel)?)
+            }
+            ACK_PACKET_EVENT => {
+                IbcEvent::AcknowledgePacket(event.try_into().map_err(Error::Channel)?)
+            }
+            TIMEOUT_EVENT => IbcEvent::TimeoutPacket(event.try_into().map_err(Error::Channel)?),
+            CHANNEL_CLOSED_EVENT => {
+                IbcEvent::ChannelClosed(event.try_into().map_err(Error::Channel)?)
+            }
+            MESSAGE_EVENT => IbcEvent::Message(event.try_into()?),
+            _ => IbcEvent::Module(module_event(event)?),
+        })
+    }
+}
+
+/// Converts an ABCI event to a [`ModuleEvent`], whose attributes must be
+/// valid UTF-8.
+fn module_event(event: abci::Event) -> Result<ModuleEvent, Error> {
+    let attributes = event
+        .attributes
+        .iter()
+        .map(|attribute| {
+            let key = attribute
+                .key_str()
+                .map_err(|e| Error::InvalidEventAttribute {
+                    key: String::from_utf8_lossy(attribute.key_bytes()).into_owned(),
+                    reason: e.to_string(),
+                })?;
+            let value = attribute
+                .value_str()
+                .map_err(|e| Error::InvalidEventAttribute {
+                    key: key.to_string(),
+                    reason: e.to_string(),
+                })?;
+
+            Ok((key, value).into())
+        })
+        .collect::<Result<_, Error>>()?;
+
+    Ok(ModuleEvent {
+        kind: event.kind,
+        attributes,
+    })
+}
+
 impl IbcEvent {
     pub fn event_type(&self) -> &str {
         match self {
@@ -143,12 +330,25 @@ impl IbcEvent {
             IbcEvent::OpenConfirmChannel(event) => event.event_type(),
             IbcEvent::CloseInitChannel(event) => event.event_type(),
             IbcEvent::CloseConfirmChannel(event) => event.event_type(),
+            IbcEvent::UpgradeInitChannel(event) => event.event_type(),
+            IbcEvent::UpgradeTryChannel(event) => event.event_type(),
+            IbcEvent::UpgradeAckChannel(event) => event.event_type(),
+            IbcEvent::UpgradeConfirmChannel(event) => event.event_type(),
+            IbcEvent::UpgradeOpenChannel(event) => event.event_type(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.event_type(),
+            IbcEvent::UpgradeCancelChannel(event) => event.event_type(),
+            IbcEvent::UpgradeErrorChannel(event) => event.event_type(),
             IbcEvent::SendPacket(event) => event.event_type(),
             IbcEvent::ReceivePacket(event) => event.event_type(),
             IbcEvent::WriteAcknowledgement(event) => event.event_type(),
             IbcEvent::AcknowledgePacket(event) => event.event_type(),
             IbcEvent::TimeoutPacket(event) => event.event_type(),
             IbcEvent::ChannelClosed(event) => event.event_type(),
+            IbcEvent::SendPacketV2(event) => event.event_type(),
+            IbcEvent::ReceivePacketV2(event) => event.event_type(),
+            IbcEvent::WriteAcknowledgementV2(event) => event.event_type(),
+            IbcEvent::AcknowledgePacketV2(event) => event.event_type(),
+            IbcEvent::TimeoutPacketV2(event) => event.event_type(),
             IbcEvent::Module(module_event) => module_event.kind.as_str(),
             IbcEvent::Message(_) => MESSAGE_EVENT,
         }
@@ -195,6 +395,43 @@ impl MessageEvent {
     }
 }
 
+impl From<&str> for MessageEvent {
+    /// Maps the value of the `module` attribute back to the message event.
+    fn from(module_attribute: &str) -> Self {
+        match module_attribute {
+            "ibc_client" => MessageEvent::Client,
+            "ibc_connection" => MessageEvent::Connection,
+            "ibc_channel" => MessageEvent::Channel,
+            module_name => MessageEvent::Module(module_name.to_string()),
+        }
+    }
+}
+
+impl TryFrom<abci::Event> for MessageEvent {
+    type Error = Error;
+
+    fn try_from(event: abci::Event) -> Result<Self, Self::Error> {
+        if event.kind != MESSAGE_EVENT {
+            return Err(Error::IncorrectEventType { event: event.kind });
+        }
+
+        let module_attribute = event
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key_str().is_ok_and(|key| key == "module"))
+            .ok_or_else(|| Error::MissingEventAttribute {
+                key: "module".to_string(),
+            })?
+            .value_str()
+            .map_err(|e| Error::InvalidEventAttribute {
+                key: "module".to_string(),
+                reason: e.to_string(),
+            })?;
+
+        Ok(module_attribute.into())
+    }
+}
+
 impl From<MessageEvent> for IbcEvent {
     fn from(e: MessageEvent) -> Self {
         IbcEvent::Message(e)
@@ -206,3 +443,83 @@ impl From<ModuleEvent> for IbcEvent {
         IbcEvent::Module(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_channel_types::channel::Order;
+    use ibc_core_channel_types::v2::packet::{Packet as PacketV2, Payload};
+    use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+    use ibc_primitives::Timestamp;
+
+    use super::*;
+
+    #[test]
+    fn abci_to_ibc_events() {
+        let events = vec![
+            IbcEvent::Message(MessageEvent::Channel),
+            IbcEvent::Message(MessageEvent::Module("transfer".to_string())),
+            IbcEvent::ChannelClosed(ChannelEvents::ChannelClosed::new(
+                PortId::transfer(),
+                ChannelId::zero(),
+                PortId::transfer(),
+                Some(ChannelId::new(1)),
+                ConnectionId::zero(),
+                Order::Unordered,
+            )),
+            IbcEvent::Module(ModuleEvent {
+                kind: "fungible_token_packet".to_string(),
+                attributes: vec![("module", "transfer").into(), ("success", "true").into()],
+            }),
+        ];
+
+        for event in events {
+            let abci_event = abci::Event::try_from(event.clone()).unwrap();
+
+            assert_eq!(IbcEvent::try_from(abci_event).unwrap(), event);
+        }
+    }
+
+    #[test]
+    fn abci_to_ibc_v2_packet_events() {
+        let packet = PacketV2 {
+            seq_on_a: Sequence::from(1),
+            client_id_on_a: ClientId::new("07-tendermint", 0).unwrap(),
+            client_id_on_b: ClientId::new("07-tendermint", 1).unwrap(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap(),
+            payloads: vec![Payload {
+                port_id_on_a: PortId::transfer(),
+                port_id_on_b: PortId::transfer(),
+                version: "ics20-1".to_string(),
+                encoding: "application/json".to_string(),
+                value: b"data".to_vec(),
+            }],
+        };
+
+        let events = vec![
+            IbcEvent::SendPacketV2(ChannelV2Events::SendPacket::new(packet.clone())),
+            IbcEvent::ReceivePacketV2(ChannelV2Events::ReceivePacket::new(packet.clone())),
+            IbcEvent::TimeoutPacketV2(ChannelV2Events::TimeoutPacket::new(packet)),
+        ];
+
+        for event in events {
+            let abci_event = abci::Event::try_from(event.clone()).unwrap();
+
+            assert_eq!(IbcEvent::try_from(abci_event).unwrap(), event);
+        }
+    }
+
+    #[test]
+    fn abci_to_ibc_event_missing_attribute() {
+        let abci_event = abci::Event {
+            kind: ChannelEvents::CHANNEL_OPEN_ACK_EVENT.to_string(),
+            attributes: vec![("port_id", "transfer").into()],
+        };
+
+        assert!(matches!(
+            IbcEvent::try_from(abci_event),
+            Err(Error::Channel(
+                channel_error::ChannelError::MissingEventAttribute { .. }
+            ))
+        ));
+    }
+}
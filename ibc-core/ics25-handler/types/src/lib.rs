@@ -16,6 +16,9 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod batch;
 pub mod error;
 pub mod events;
+pub mod metering;
 pub mod msgs;
+pub mod simulation;
@@ -0,0 +1,176 @@
+//! Defines the cost events through which hosts meter the resources consumed
+//! while handling IBC messages
+
+use displaydoc::Display;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+
+/// A resource consumption reported by the handlers, the light clients or the
+/// store of the host, for the host to charge for.
+///
+/// Store reads and writes are never reported by the core handlers, which
+/// access the store through the typed getters and setters of the contexts:
+/// only the store of the host knows how the values are encoded, and hence
+/// how many bytes it reads and writes. The store reports them itself, e.g.
+/// from its own implementation of the contexts as `ibc-host-kv` does, and
+/// hosts whose store is already metered, such as Cosmos SDK chains, need not
+/// report them at all.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    ),
+    // The derived codecs cast the variant indices to `usize`.
+    allow(trivial_numeric_casts)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Display)]
+pub enum CostEvent {
+    /// proof of `{bytes}` bytes verified
+    ProofVerified { bytes: u64 },
+    /// `{count}` signatures checked
+    SignaturesChecked { count: u64 },
+    /// value of `{bytes}` bytes read from the store
+    ///
+    /// Reported by the store of the host, not by the core handlers.
+    StoreRead { bytes: u64 },
+    /// value of `{bytes}` bytes written to the store
+    ///
+    /// Reported by the store of the host, not by the core handlers.
+    StoreWrite { bytes: u64 },
+    /// `{count}` consensus states pruned
+    ConsensusStatesPruned { count: u64 },
+}
+
+impl CostEvent {
+    /// Reports the verification of the given proof.
+    pub fn proof_verified(proof: &CommitmentProofBytes) -> Self {
+        Self::ProofVerified {
+            bytes: byte_len(proof.as_ref()),
+        }
+    }
+
+    /// Reports the read of the given value from the store.
+    pub fn store_read(value: &[u8]) -> Self {
+        Self::StoreRead {
+            bytes: byte_len(value),
+        }
+    }
+
+    /// Reports the write of the given value to the store.
+    ///
+    /// The deletion of a value is reported as the write of an empty one.
+    pub fn store_write(value: &[u8]) -> Self {
+        Self::StoreWrite {
+            bytes: byte_len(value),
+        }
+    }
+}
+
+fn byte_len(bytes: &[u8]) -> u64 {
+    u64::try_from(bytes.len()).unwrap_or(u64::MAX)
+}
+
+/// The gas charged for each [`CostEvent`].
+///
+/// The reference table, returned by [`CostTable::default`], follows the
+/// default gas configuration of the Cosmos SDK for store accesses and ed25519
+/// signatures, so that hosts outside of it meter IBC alike.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CostTable {
+    /// Flat gas charged per verified proof.
+    pub proof_flat: u64,
+    /// Gas charged per byte of verified proof.
+    pub proof_per_byte: u64,
+    /// Gas charged per checked signature.
+    pub signature: u64,
+    /// Flat gas charged per store read.
+    pub read_flat: u64,
+    /// Gas charged per byte read from the store.
+    pub read_per_byte: u64,
+    /// Flat gas charged per store write.
+    pub write_flat: u64,
+    /// Gas charged per byte written to the store.
+    pub write_per_byte: u64,
+    /// Gas charged per pruned consensus state.
+    pub consensus_state_pruned: u64,
+}
+
+impl CostTable {
+    /// Returns the gas charged for the given cost event.
+    ///
+    /// Saturates at `u64::MAX` rather than overflowing.
+    pub fn gas(&self, event: &CostEvent) -> u64 {
+        match *event {
+            CostEvent::ProofVerified { bytes } => self
+                .proof_flat
+                .saturating_add(self.proof_per_byte.saturating_mul(bytes)),
+            CostEvent::SignaturesChecked { count } => self.signature.saturating_mul(count),
+            CostEvent::StoreRead { bytes } => self
+                .read_flat
+                .saturating_add(self.read_per_byte.saturating_mul(bytes)),
+            CostEvent::StoreWrite { bytes } => self
+                .write_flat
+                .saturating_add(self.write_per_byte.saturating_mul(bytes)),
+            CostEvent::ConsensusStatesPruned { count } => {
+                self.consensus_state_pruned.saturating_mul(count)
+            }
+        }
+    }
+}
+
+impl Default for CostTable {
+    fn default() -> Self {
+        Self {
+            proof_flat: 1_000,
+            proof_per_byte: 3,
+            signature: 590,
+            read_flat: 1_000,
+            read_per_byte: 3,
+            write_flat: 2_000,
+            write_per_byte: 30,
+            consensus_state_pruned: 1_000,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_cost_table() {
+        let table = CostTable::default();
+
+        assert_eq!(table.gas(&CostEvent::ProofVerified { bytes: 100 }), 1_300);
+        assert_eq!(table.gas(&CostEvent::SignaturesChecked { count: 4 }), 2_360);
+        assert_eq!(table.gas(&CostEvent::StoreRead { bytes: 10 }), 1_030);
+        assert_eq!(table.gas(&CostEvent::StoreWrite { bytes: 10 }), 2_300);
+        assert_eq!(
+            table.gas(&CostEvent::ConsensusStatesPruned { count: 2 }),
+            2_000
+        );
+        assert_eq!(
+            table.gas(&CostEvent::ProofVerified { bytes: u64::MAX }),
+            u64::MAX
+        );
+    }
+}
@@ -1,16 +1,21 @@
 use ibc_core_channel_types::msgs::{
     ChannelMsg, MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
-    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
-    MsgTimeoutOnClose, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL,
-    CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL,
-    CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL, RECV_PACKET_TYPE_URL,
-    TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgChannelUpgradeAck,
+    MsgChannelUpgradeCancel, MsgChannelUpgradeConfirm, MsgChannelUpgradeInit,
+    MsgChannelUpgradeOpen, MsgChannelUpgradeTimeout, MsgChannelUpgradeTry, MsgRecvPacket,
+    MsgTimeout, MsgTimeoutOnClose, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL,
+    CHAN_CLOSE_CONFIRM_TYPE_URL, CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL,
+    CHAN_OPEN_CONFIRM_TYPE_URL, CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL,
+    CHAN_UPGRADE_ACK_TYPE_URL, CHAN_UPGRADE_CANCEL_TYPE_URL, CHAN_UPGRADE_CONFIRM_TYPE_URL,
+    CHAN_UPGRADE_INIT_TYPE_URL, CHAN_UPGRADE_OPEN_TYPE_URL, CHAN_UPGRADE_TIMEOUT_TYPE_URL,
+    CHAN_UPGRADE_TRY_TYPE_URL, RECV_PACKET_TYPE_URL, TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
 };
+use ibc_core_channel_types::v2;
 #[allow(deprecated)]
 use ibc_core_client_types::msgs::{
-    ClientMsg, MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient, MsgUpgradeClient,
-    CREATE_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_TYPE_URL,
-    UPGRADE_CLIENT_TYPE_URL,
+    ClientMsg, MsgCreateClient, MsgRegisterCounterparty, MsgSubmitMisbehaviour, MsgUpdateClient,
+    MsgUpgradeClient, CREATE_CLIENT_TYPE_URL, REGISTER_COUNTERPARTY_TYPE_URL,
+    SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_TYPE_URL, UPGRADE_CLIENT_TYPE_URL,
 };
 use ibc_core_connection_types::msgs::{
     ConnectionMsg, MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
@@ -34,6 +39,7 @@ pub enum MsgEnvelope {
     Connection(ConnectionMsg),
     Channel(ChannelMsg),
     Packet(PacketMsg),
+    PacketV2(v2::msgs::PacketMsg),
 }
 
 #[allow(deprecated)]
@@ -167,6 +173,67 @@ impl TryFrom<Any> for MsgEnvelope {
                     })?;
                 Ok(MsgEnvelope::Channel(ChannelMsg::CloseConfirm(domain_msg)))
             }
+            CHAN_UPGRADE_INIT_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeInit::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeInit(domain_msg)))
+            }
+            CHAN_UPGRADE_TRY_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeTry::decode_vec(&any_msg.value).map_err(|e| {
+                    RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeTry(domain_msg)))
+            }
+            CHAN_UPGRADE_ACK_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeAck::decode_vec(&any_msg.value).map_err(|e| {
+                    RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeAck(domain_msg)))
+            }
+            CHAN_UPGRADE_CONFIRM_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeConfirm::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeConfirm(domain_msg)))
+            }
+            CHAN_UPGRADE_OPEN_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeOpen::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeOpen(domain_msg)))
+            }
+            CHAN_UPGRADE_TIMEOUT_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeTimeout::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeTimeout(domain_msg)))
+            }
+            CHAN_UPGRADE_CANCEL_TYPE_URL => {
+                let domain_msg =
+                    MsgChannelUpgradeCancel::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeCancel(domain_msg)))
+            }
             // ICS04 packet messages
             RECV_PACKET_TYPE_URL => {
                 let domain_msg = MsgRecvPacket::decode_vec(&any_msg.value).map_err(|e| {
@@ -200,6 +267,56 @@ impl TryFrom<Any> for MsgEnvelope {
                 })?;
                 Ok(MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(domain_msg)))
             }
+
+            // IBC v2 messages
+            REGISTER_COUNTERPARTY_TYPE_URL => {
+                let domain_msg =
+                    MsgRegisterCounterparty::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::Client(ClientMsg::RegisterCounterparty(
+                    domain_msg,
+                )))
+            }
+            v2::msgs::SEND_PACKET_TYPE_URL => {
+                let domain_msg =
+                    v2::msgs::MsgSendPacket::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::PacketV2(v2::msgs::PacketMsg::Send(domain_msg)))
+            }
+            v2::msgs::RECV_PACKET_TYPE_URL => {
+                let domain_msg =
+                    v2::msgs::MsgRecvPacket::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::PacketV2(v2::msgs::PacketMsg::Recv(domain_msg)))
+            }
+            v2::msgs::ACKNOWLEDGEMENT_TYPE_URL => {
+                let domain_msg =
+                    v2::msgs::MsgAcknowledgement::decode_vec(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(MsgEnvelope::PacketV2(v2::msgs::PacketMsg::Ack(domain_msg)))
+            }
+            v2::msgs::TIMEOUT_TYPE_URL => {
+                let domain_msg = v2::msgs::MsgTimeout::decode_vec(&any_msg.value).map_err(|e| {
+                    RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Ok(MsgEnvelope::PacketV2(v2::msgs::PacketMsg::Timeout(
+                    domain_msg,
+                )))
+            }
             _ => Err(RouterError::UnknownMessageTypeUrl {
                 url: any_msg.type_url,
             }),
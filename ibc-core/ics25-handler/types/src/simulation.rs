@@ -0,0 +1,65 @@
+//! Defines the outcome of simulating the dispatch of IBC messages
+
+use ibc_core_host_types::path::Path;
+use ibc_primitives::prelude::*;
+
+use crate::events::IbcEvent;
+
+/// A change made to the IBC state at an ICS-24 path.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    ),
+    // The derived codecs cast the variant indices to `usize`.
+    allow(trivial_numeric_casts)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StateChange {
+    /// The given value is written at the path, encoded as it is committed to
+    /// in the state of the host.
+    Write { path: Path, value: Vec<u8> },
+    /// The value stored at the path is deleted.
+    Delete { path: Path },
+}
+
+impl StateChange {
+    /// Returns the path of the changed value.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Write { path, .. } | Self::Delete { path } => path,
+        }
+    }
+}
+
+/// What the dispatch of a batch of IBC messages would have done, had it not
+/// been simulated.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulationOutcome {
+    /// The changes made to the IBC state, one per changed path.
+    pub changes: Vec<StateChange>,
+    /// The events emitted, in order.
+    pub events: Vec<IbcEvent>,
+    /// The messages logged, in order.
+    pub logs: Vec<String>,
+}
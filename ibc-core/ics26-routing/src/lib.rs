@@ -14,8 +14,10 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod middleware;
 pub mod module;
 pub mod router;
+pub mod v2;
 
 /// Re-exports router data structures from the `ibc-core-router-types` crate
 pub mod types {
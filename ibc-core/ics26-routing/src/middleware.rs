@@ -0,0 +1,856 @@
+//! Defines the `Middleware` trait, which lets modules be stacked on top of
+//! each other, and the `Ics4Wrapper` trait through which packets are sent
+//! down such a stack.
+use core::fmt::Debug;
+
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{Counterparty, Order};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::Version;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core_router_types::module::ModuleExtras;
+use ibc_primitives::Signer;
+
+use crate::module::Module;
+
+/// The outbound side of a middleware stack, through which an application
+/// sends its packets and writes the acknowledgements it deferred. Each
+/// middleware of the stack gets the chance to act on the packet or the
+/// acknowledgement before passing it on to the next one, down to the core
+/// channel handler.
+pub trait Ics4Wrapper {
+    /// Sends the packet down the stack.
+    fn send_packet(&mut self, packet: Packet) -> Result<(), ContextError>;
+
+    /// Writes the acknowledgement of a received packet down the stack, for
+    /// applications which deferred it upon receiving the packet.
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), ContextError>;
+
+    /// Returns the version of the given channel as seen from the layer
+    /// above, i.e. stripped from the metadata of this layer and the ones
+    /// below it.
+    fn app_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, ContextError>;
+}
+
+/// A module wrapping an inner [`Module`], such as another middleware or the
+/// base application of the stack.
+///
+/// Every [`Module`] callback has a default implementation forwarding the
+/// call to the inner module, so that a middleware only overrides the
+/// callbacks it intercepts. Middlewares that add their own metadata to
+/// channel versions or acknowledgements only need to implement the
+/// `wrap_*`/`unwrap_*` methods, which the default callbacks apply around the
+/// inner module.
+///
+/// Every `Middleware` is a [`Module`], hence it can be bound to a port or
+/// wrapped by another middleware. Since both traits define the callbacks,
+/// they must be called through [`Module`] wherever both are in scope.
+pub trait Middleware: Debug {
+    type Inner: Module;
+
+    fn inner(&self) -> &Self::Inner;
+
+    fn inner_mut(&mut self) -> &mut Self::Inner;
+
+    /// Wraps the version returned by the inner module with the metadata of
+    /// this middleware.
+    fn wrap_version(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        app_version: Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(app_version)
+    }
+
+    /// Strips the metadata of this middleware from a version, returning the
+    /// version of the inner module.
+    fn unwrap_version(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(version.clone())
+    }
+
+    /// Wraps the acknowledgement written by the inner module upon receiving
+    /// a packet. Not called if the inner module defers the acknowledgement,
+    /// which is wrapped by [`Self::write_acknowledgement`] instead.
+    fn wrap_acknowledgement(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+        app_acknowledgement: Acknowledgement,
+    ) -> Acknowledgement {
+        app_acknowledgement
+    }
+
+    /// Strips the metadata of this middleware from an acknowledgement,
+    /// returning the acknowledgement of the inner module.
+    fn unwrap_acknowledgement(
+        &self,
+        _packet: &Packet,
+        acknowledgement: &Acknowledgement,
+    ) -> Result<Acknowledgement, PacketError> {
+        Ok(acknowledgement.clone())
+    }
+
+    /// Sends a packet of the inner module through `next`, the layer below
+    /// this middleware.
+    ///
+    /// Packets are sent while the middleware stack may already be borrowed,
+    /// e.g. from within a callback, hence this only takes `&self`.
+    fn send_packet(&self, next: &mut dyn Ics4Wrapper, packet: Packet) -> Result<(), ContextError> {
+        next.send_packet(packet)
+    }
+
+    /// Writes an acknowledgement the inner module deferred upon receiving
+    /// the packet through `next`, the layer below this middleware.
+    ///
+    /// The relayer of the packet is not known anymore at this point, hence
+    /// middlewares wrapping acknowledgements must override this, e.g. with
+    /// the relayer they recorded when the inner module deferred the
+    /// acknowledgement.
+    fn write_acknowledgement(
+        &self,
+        next: &mut dyn Ics4Wrapper,
+        packet: &Packet,
+        app_acknowledgement: Acknowledgement,
+    ) -> Result<(), ContextError> {
+        next.write_acknowledgement(packet, app_acknowledgement)
+    }
+
+    /// Returns the version of the given channel as seen from the inner
+    /// module, given `next`, the layer below this middleware.
+    fn app_version(
+        &self,
+        next: &dyn Ics4Wrapper,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, ContextError> {
+        let version = next.app_version(port_id, channel_id)?;
+
+        Ok(self.unwrap_version(port_id, channel_id, &version)?)
+    }
+
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, version)?;
+
+        let app_version = self.inner().on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &app_version,
+        )?;
+
+        self.wrap_version(port_id, channel_id, app_version)
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, version)?;
+
+        let (extras, app_version) = self.inner_mut().on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &app_version,
+        )?;
+
+        Ok((extras, self.wrap_version(port_id, channel_id, app_version)?))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, counterparty_version)?;
+
+        let app_version = self.inner().on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &app_version,
+        )?;
+
+        self.wrap_version(port_id, channel_id, app_version)
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, counterparty_version)?;
+
+        let (extras, app_version) = self.inner_mut().on_chan_open_try_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &app_version,
+        )?;
+
+        Ok((extras, self.wrap_version(port_id, channel_id, app_version)?))
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, counterparty_version)?;
+
+        self.inner()
+            .on_chan_open_ack_validate(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, counterparty_version)?;
+
+        self.inner_mut()
+            .on_chan_open_ack_execute(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.inner()
+            .on_chan_open_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.inner_mut()
+            .on_chan_open_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.inner()
+            .on_chan_close_init_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.inner_mut()
+            .on_chan_close_init_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.inner()
+            .on_chan_close_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.inner_mut()
+            .on_chan_close_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, proposed_version)?;
+
+        let app_version = self.inner().on_chan_upgrade_init_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            &app_version,
+        )?;
+
+        self.wrap_version(port_id, channel_id, app_version)
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, proposed_version)?;
+
+        let (extras, app_version) = self.inner_mut().on_chan_upgrade_init_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            &app_version,
+        )?;
+
+        Ok((extras, self.wrap_version(port_id, channel_id, app_version)?))
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, counterparty_version)?;
+
+        let app_version = self.inner().on_chan_upgrade_try_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            &app_version,
+        )?;
+
+        self.wrap_version(port_id, channel_id, app_version)
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, counterparty_version)?;
+
+        let (extras, app_version) = self.inner_mut().on_chan_upgrade_try_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            &app_version,
+        )?;
+
+        Ok((extras, self.wrap_version(port_id, channel_id, app_version)?))
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, counterparty_version)?;
+
+        self.inner()
+            .on_chan_upgrade_ack_validate(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let app_version = self.unwrap_version(port_id, channel_id, counterparty_version)?;
+
+        self.inner_mut()
+            .on_chan_upgrade_ack_execute(port_id, channel_id, &app_version)
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> ModuleExtras {
+        // The upgraded version was accepted by this middleware during the
+        // handshake, hence it can always be unwrapped.
+        let app_version = self
+            .unwrap_version(port_id, channel_id, version)
+            .unwrap_or_else(|_| version.clone());
+
+        self.inner_mut().on_chan_upgrade_open_execute(
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            &app_version,
+        )
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (extras, app_acknowledgement) =
+            self.inner_mut().on_recv_packet_execute(packet, relayer);
+
+        let acknowledgement =
+            app_acknowledgement.map(|app_ack| self.wrap_acknowledgement(packet, relayer, app_ack));
+
+        (extras, acknowledgement)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        let app_acknowledgement = self.unwrap_acknowledgement(packet, acknowledgement)?;
+
+        self.inner()
+            .on_acknowledgement_packet_validate(packet, &app_acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let app_acknowledgement = match self.unwrap_acknowledgement(packet, acknowledgement) {
+            Ok(app_acknowledgement) => app_acknowledgement,
+            Err(e) => return (ModuleExtras::empty(), Err(e)),
+        };
+
+        self.inner_mut()
+            .on_acknowledgement_packet_execute(packet, &app_acknowledgement, relayer)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.inner().on_timeout_packet_validate(packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.inner_mut().on_timeout_packet_execute(packet, relayer)
+    }
+}
+
+impl<M> Module for M
+where
+    M: Middleware,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Middleware::on_chan_open_init_validate(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Middleware::on_chan_open_init_execute(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Middleware::on_chan_open_try_validate(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Middleware::on_chan_open_try_execute(
+            self,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_chan_open_ack_validate(self, port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Middleware::on_chan_open_ack_execute(self, port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_chan_open_confirm_validate(self, port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Middleware::on_chan_open_confirm_execute(self, port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_chan_close_init_validate(self, port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Middleware::on_chan_close_init_execute(self, port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_chan_close_confirm_validate(self, port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Middleware::on_chan_close_confirm_execute(self, port_id, channel_id)
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Middleware::on_chan_upgrade_init_validate(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Middleware::on_chan_upgrade_init_execute(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Middleware::on_chan_upgrade_try_validate(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Middleware::on_chan_upgrade_try_execute(
+            self,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Middleware::on_chan_upgrade_ack_validate(self, port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Middleware::on_chan_upgrade_ack_execute(self, port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> ModuleExtras {
+        Middleware::on_chan_upgrade_open_execute(
+            self,
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            version,
+        )
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        Middleware::on_recv_packet_execute(self, packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Middleware::on_acknowledgement_packet_validate(self, packet, acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        Middleware::on_acknowledgement_packet_execute(self, packet, acknowledgement, relayer)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Middleware::on_timeout_packet_validate(self, packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        Middleware::on_timeout_packet_execute(self, packet, relayer)
+    }
+}
+
+/// A layer of the outbound side of a middleware stack: sends packets
+/// through the given middleware, then through `next`.
+///
+/// Stacks are built from the core channel handler up, e.g. for an
+/// application wrapped by `pfm`, itself wrapped by `fee`:
+/// `Ics4WrapperLayer::new(fee.inner(), &mut Ics4WrapperLayer::new(&fee, &mut core))`,
+/// where `core` is the [`Ics4Wrapper`] of the host.
+#[derive(Debug)]
+pub struct Ics4WrapperLayer<'a, M, W> {
+    middleware: &'a M,
+    next: &'a mut W,
+}
+
+impl<'a, M, W> Ics4WrapperLayer<'a, M, W>
+where
+    M: Middleware,
+    W: Ics4Wrapper,
+{
+    pub fn new(middleware: &'a M, next: &'a mut W) -> Self {
+        Self { middleware, next }
+    }
+}
+
+impl<M, W> Ics4Wrapper for Ics4WrapperLayer<'_, M, W>
+where
+    M: Middleware,
+    W: Ics4Wrapper,
+{
+    fn send_packet(&mut self, packet: Packet) -> Result<(), ContextError> {
+        self.middleware.send_packet(&mut *self.next, packet)
+    }
+
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), ContextError> {
+        self.middleware
+            .write_acknowledgement(&mut *self.next, packet, acknowledgement)
+    }
+
+    fn app_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, ContextError> {
+        self.middleware
+            .app_version(&*self.next, port_id, channel_id)
+    }
+}
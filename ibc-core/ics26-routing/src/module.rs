@@ -118,16 +118,112 @@ pub trait Module: Debug {
         Ok(ModuleExtras::empty())
     }
 
+    /// Called when a channel upgrade is initiated on the host chain via
+    /// `ChanUpgradeInit`. Returns the version the application proposes for
+    /// the upgraded channel.
+    ///
+    /// By default, the module rejects channel upgrades.
+    fn on_chan_upgrade_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(upgrade_not_supported())
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(upgrade_not_supported())
+    }
+
+    /// Called when the counterparty initiated a channel upgrade and the host
+    /// chain processes `ChanUpgradeTry`. Returns the version the application
+    /// agrees to for the upgraded channel.
+    ///
+    /// By default, the module rejects channel upgrades.
+    fn on_chan_upgrade_try_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(upgrade_not_supported())
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(upgrade_not_supported())
+    }
+
+    /// Called on the initiating chain upon `ChanUpgradeAck`, giving the
+    /// application a chance to reject the version chosen by the counterparty.
+    ///
+    /// By default, the module rejects channel upgrades.
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Err(upgrade_not_supported())
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Err(upgrade_not_supported())
+    }
+
+    /// Called once the upgrade completed and the channel end has switched
+    /// to the upgraded parameters. This callback cannot fail, since the
+    /// upgrade has already been agreed upon by both ends.
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _version: &Version,
+    ) -> ModuleExtras {
+        ModuleExtras::empty()
+    }
+
     // Note: no `on_recv_packet_validate()`
     // the `onRecvPacket` callback always succeeds
     // if any error occurs, than an "error acknowledgement"
     // must be returned
 
+    /// Returns `None` as acknowledgement if the module defers writing it,
+    /// e.g. until a packet it forwards on behalf of this one is acknowledged.
+    /// Core then neither stores an acknowledgement commitment nor emits a
+    /// `WriteAcknowledgement` event for the packet, which the module does in a
+    /// later block through the `write_acknowledgement` handler of
+    /// `ibc-core-channel`.
     fn on_recv_packet_execute(
         &mut self,
         packet: &Packet,
         relayer: &Signer,
-    ) -> (ModuleExtras, Acknowledgement);
+    ) -> (ModuleExtras, Option<Acknowledgement>);
 
     fn on_acknowledgement_packet_validate(
         &self,
@@ -159,3 +255,9 @@ pub trait Module: Debug {
         relayer: &Signer,
     ) -> (ModuleExtras, Result<(), PacketError>);
 }
+
+fn upgrade_not_supported() -> ChannelError {
+    ChannelError::AppModule {
+        description: "channel upgrades are not supported by this module".to_string(),
+    }
+}
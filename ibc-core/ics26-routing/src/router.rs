@@ -4,6 +4,7 @@ use ibc_core_host_types::identifiers::PortId;
 use ibc_core_router_types::module::ModuleId;
 
 use crate::module::Module;
+use crate::v2::PayloadModule;
 
 /// Router as defined in ICS-26, which binds modules to ports.
 pub trait Router {
@@ -15,4 +16,19 @@ pub trait Router {
 
     /// Return the module_id associated with a given port_id
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId>;
+
+    /// Returns a reference to the IBC v2 application bound to the specified
+    /// port, to which the payloads addressed to the port are routed.
+    ///
+    /// Routers of hosts that do not support IBC v2 keep the default
+    /// implementation, which binds no application.
+    fn get_payload_route(&self, _port_id: &PortId) -> Option<&dyn PayloadModule> {
+        None
+    }
+
+    /// Returns a mutable reference to the IBC v2 application bound to the
+    /// specified port
+    fn get_payload_route_mut(&mut self, _port_id: &PortId) -> Option<&mut dyn PayloadModule> {
+        None
+    }
 }
@@ -0,0 +1,102 @@
+//! Defines the `PayloadModule`, the trait of the IBC v2 applications to which
+//! the payloads of the IBC v2 packets are routed.
+use core::fmt::Debug;
+
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::v2::packet::Payload;
+use ibc_core_host_types::identifiers::{ClientId, Sequence};
+use ibc_core_router_types::module::ModuleExtras;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+/// The outcome of receiving a payload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecvPayloadResult {
+    /// The payload was received, with the given application acknowledgement,
+    /// which cannot be empty.
+    Success(Vec<u8>),
+    /// The payload failed to be received.
+    ///
+    /// The whole packet then fails to be received: the modules must not commit
+    /// any state change for it, since the modules of the other payloads of the
+    /// packet are not notified of the failure. The sending chain is told
+    /// about it through the universal error acknowledgement.
+    Failure,
+}
+
+/// An IBC v2 application, bound to a port, which sends and receives the
+/// payloads of the IBC v2 packets.
+///
+/// Unlike a [`Module`](crate::module::Module), the application takes no part in
+/// any handshake: packets are sent between two clients which are each other's
+/// counterparty, and each of their payloads is routed to the application bound
+/// to its port.
+pub trait PayloadModule: Debug {
+    fn on_send_payload_validate(
+        &self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        seq_on_a: Sequence,
+        payload: &Payload,
+        signer: &Signer,
+    ) -> Result<(), PacketError>;
+
+    fn on_send_payload_execute(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        seq_on_a: Sequence,
+        payload: &Payload,
+        signer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>);
+
+    // Note: no `on_recv_payload_validate()`, since receiving a payload never
+    // fails the message: failures are acknowledged instead.
+
+    fn on_recv_payload_execute(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        seq_on_a: Sequence,
+        payload: &Payload,
+        relayer: &Signer,
+    ) -> (ModuleExtras, RecvPayloadResult);
+
+    fn on_acknowledgement_payload_validate(
+        &self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        seq_on_a: Sequence,
+        payload: &Payload,
+        acknowledgement: &[u8],
+        relayer: &Signer,
+    ) -> Result<(), PacketError>;
+
+    fn on_acknowledgement_payload_execute(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        seq_on_a: Sequence,
+        payload: &Payload,
+        acknowledgement: &[u8],
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>);
+
+    fn on_timeout_payload_validate(
+        &self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        seq_on_a: Sequence,
+        payload: &Payload,
+        relayer: &Signer,
+    ) -> Result<(), PacketError>;
+
+    fn on_timeout_payload_execute(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        seq_on_a: Sequence,
+        payload: &Payload,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>);
+}
@@ -51,6 +51,14 @@ pub(crate) fn impl_ClientStateExecution(
         imports,
     );
 
+    let prune_expired_consensus_states_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { prune_expired_consensus_states(cs, ctx, client_id, budget) },
+        imports,
+    );
+
     // The imports we need for the generated code.
     let Any = imports.any();
     let ClientId = imports.client_id();
@@ -127,6 +135,17 @@ pub(crate) fn impl_ClientStateExecution(
                     #(#update_on_recovery_impl),*
                 }
             }
+
+            fn prune_expired_consensus_states(
+                &self,
+                ctx: &mut #E,
+                client_id: &#ClientId,
+                budget: usize,
+            ) -> core::result::Result<usize, #ClientError> {
+                match self {
+                    #(#prune_expired_consensus_states_impl),*
+                }
+            }
         }
 
     }
@@ -45,12 +45,49 @@ pub(crate) fn impl_ClientStateValidation(
         imports,
     );
 
+    let verify_upgrade_client_at_height_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { verify_upgrade_client_at_height(cs, ctx, client_cons_state_path, upgraded_client_state, upgraded_consensus_state, proof_upgrade_client, proof_upgrade_consensus_state) },
+        imports,
+    );
+
+    let verify_membership_at_height_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { verify_membership_at_height(cs, ctx, client_cons_state_path, prefix, proof, path, value) },
+        imports,
+    );
+
+    let verify_non_membership_at_height_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { verify_non_membership_at_height(cs, ctx, client_cons_state_path, prefix, proof, path) },
+        imports,
+    );
+
+    let timestamp_at_height_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { timestamp_at_height(cs, ctx, client_cons_state_path) },
+        imports,
+    );
+
     // The imports we need for the generated code.
     let Any = imports.any();
     let ClientId = imports.client_id();
+    let ClientConsensusStatePath = imports.client_consensus_state_path();
     let ClientError = imports.client_error();
     let ClientStateValidation = imports.client_state_validation();
+    let CommitmentPrefix = imports.commitment_prefix();
+    let CommitmentProofBytes = imports.commitment_proof_bytes();
+    let Path = imports.path();
     let Status = imports.status();
+    let Timestamp = imports.timestamp();
 
     // The types we need for the generated code.
     let HostClientState = client_state_enum_name;
@@ -106,6 +143,57 @@ pub(crate) fn impl_ClientStateValidation(
                     #(#check_substitute_impl),*
                 }
             }
+
+            fn verify_upgrade_client_at_height(
+                &self,
+                ctx: &#V,
+                client_cons_state_path: &#ClientConsensusStatePath,
+                upgraded_client_state: #Any,
+                upgraded_consensus_state: #Any,
+                proof_upgrade_client: #CommitmentProofBytes,
+                proof_upgrade_consensus_state: #CommitmentProofBytes,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_upgrade_client_at_height_impl),*
+                }
+            }
+
+            fn verify_membership_at_height(
+                &self,
+                ctx: &#V,
+                client_cons_state_path: &#ClientConsensusStatePath,
+                prefix: &#CommitmentPrefix,
+                proof: &#CommitmentProofBytes,
+                path: #Path,
+                value: Vec<u8>,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_membership_at_height_impl),*
+                }
+            }
+
+            fn verify_non_membership_at_height(
+                &self,
+                ctx: &#V,
+                client_cons_state_path: &#ClientConsensusStatePath,
+                prefix: &#CommitmentPrefix,
+                proof: &#CommitmentProofBytes,
+                path: #Path,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_non_membership_at_height_impl),*
+                }
+            }
+
+            fn timestamp_at_height(
+                &self,
+                ctx: &#V,
+                client_cons_state_path: &#ClientConsensusStatePath,
+            ) -> core::result::Result<#Timestamp, #ClientError> {
+                match self {
+                    #(#timestamp_at_height_impl),*
+                }
+            }
         }
 
     }
@@ -0,0 +1,346 @@
+//! Implementation of the client contexts for the [`KvContext`] type.
+
+use ibc::core::client::context::{
+    ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
+};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::metering::CostEvent;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{
+    iteration_key, ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath,
+    ClientUpdateTimePath, CLIENT_PREFIX, ITERATE_CONSENSUS_STATE_PREFIX,
+};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::Protobuf;
+use ibc::core::primitives::Timestamp;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+use crate::context::KvContext;
+use crate::host::KvHost;
+
+impl<H> KvContext<H>
+where
+    H: KvHost,
+{
+    /// Iterates over the heights of all the consensus states of the given
+    /// client, in ascending order, or in descending order if `reverse` is set,
+    /// along with their keys and raw values in the index of the heights.
+    ///
+    /// As the consensus states are stored at the ICS-24 paths, which do not
+    /// sort as their heights do, their heights are also indexed under the
+    /// iteration keys of `ibc-go`, which do.
+    pub(crate) fn consensus_heights(
+        &self,
+        client_id: &ClientId,
+        reverse: bool,
+    ) -> impl Iterator<Item = (Height, Vec<u8>, Vec<u8>)> + '_ {
+        let prefix = consensus_heights_prefix(client_id);
+
+        self.prefix_entries(&prefix, reverse)
+            .filter_map(move |(key, value)| {
+                let key: [u8; 16] = key[prefix.len()..].try_into().ok()?;
+                let (revision_number, revision_height) = key.split_at(8);
+                let height = Height::new(
+                    u64::from_be_bytes(revision_number.try_into().ok()?),
+                    u64::from_be_bytes(revision_height.try_into().ok()?),
+                )
+                .ok()?;
+                Some((height, key.to_vec(), value))
+            })
+    }
+
+    /// Returns the first height of a consensus state of the given client
+    /// that satisfies `predicate`, searching in ascending order, or in
+    /// descending order if `reverse` is set, and charging the host for each
+    /// entry of the index of the heights read along the way.
+    fn find_consensus_height(
+        &self,
+        client_id: &ClientId,
+        reverse: bool,
+        predicate: impl Fn(&Height) -> bool,
+    ) -> Result<Option<Height>, ContextError> {
+        for (height, _, value) in self.consensus_heights(client_id, reverse) {
+            self.host().charge(CostEvent::store_read(&value))?;
+
+            if predicate(&height) {
+                return Ok(Some(height));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<H> ClientValidationContext for KvContext<H>
+where
+    H: KvHost,
+{
+    type ClientStateRef = H::ClientState;
+    type ConsensusStateRef = H::ConsensusState;
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, ContextError> {
+        Ok(self
+            .get_any(&ClientStatePath::new(client_id.clone()))
+            .map_err(|description| ClientError::Other { description })?
+            .ok_or(ClientError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            })?)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusStateRef, ContextError> {
+        let height = Height::new(
+            client_cons_state_path.revision_number,
+            client_cons_state_path.revision_height,
+        )
+        .map_err(|_| ClientError::InvalidHeight)?;
+
+        Ok(self
+            .get_any(client_cons_state_path)
+            .map_err(|description| ClientError::Other { description })?
+            .ok_or(ClientError::ConsensusStateNotFound {
+                client_id: client_cons_state_path.client_id.clone(),
+                height,
+            })?)
+    }
+
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), ContextError> {
+        let update_meta_not_found = || ClientError::UpdateMetaDataNotFound {
+            client_id: client_id.clone(),
+            height: *height,
+        };
+
+        let processed_time = self
+            .get_u64(&ClientUpdateTimePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ))
+            .map_err(|description| ClientError::Other { description })?
+            .ok_or_else(update_meta_not_found)?;
+
+        let processed_timestamp =
+            Timestamp::from_nanoseconds(processed_time).map_err(|e| ClientError::Other {
+                description: e.to_string(),
+            })?;
+
+        let processed_height = self
+            .get_proto::<Height, RawHeight>(&ClientUpdateHeightPath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ))
+            .map_err(|description| ClientError::Other { description })?
+            .ok_or_else(update_meta_not_found)?;
+
+        Ok((processed_timestamp, processed_height))
+    }
+
+    fn charge(&self, event: CostEvent) -> Result<(), ContextError> {
+        self.host().charge(event)
+    }
+}
+
+impl<H> ClientExecutionContext for KvContext<H>
+where
+    H: KvHost,
+{
+    type ClientStateMut = H::ClientState;
+
+    fn store_client_state(
+        &mut self,
+        client_state_path: ClientStatePath,
+        client_state: Self::ClientStateRef,
+    ) -> Result<(), ContextError> {
+        self.set_any(&client_state_path, client_state)
+            .map_err(|description| ClientError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::ConsensusStateRef,
+    ) -> Result<(), ContextError> {
+        let height = Height::new(
+            consensus_state_path.revision_number,
+            consensus_state_path.revision_height,
+        )?;
+
+        self.set_any(&consensus_state_path, consensus_state)
+            .map_err(|description| ClientError::Other { description })?;
+
+        self.write_key(
+            consensus_height_key(&consensus_state_path),
+            Some(height.encode_vec()),
+        )
+        .map_err(|description| ClientError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        self.delete(&consensus_state_path)
+            .map_err(|description| ClientError::Other { description })?;
+
+        self.write_key(consensus_height_key(&consensus_state_path), None)
+            .map_err(|description| ClientError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), ContextError> {
+        self.set_u64(
+            &ClientUpdateTimePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            host_timestamp.nanoseconds(),
+        )
+        .map_err(|description| ClientError::Other { description })?;
+
+        self.set_proto::<Height, RawHeight>(
+            &ClientUpdateHeightPath::new(
+                client_id,
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            host_height,
+        )
+        .map_err(|description| ClientError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn delete_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.delete(&ClientUpdateTimePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ))
+        .map_err(|description| ClientError::Other { description })?;
+
+        self.delete(&ClientUpdateHeightPath::new(
+            client_id,
+            height.revision_number(),
+            height.revision_height(),
+        ))
+        .map_err(|description| ClientError::Other { description })?;
+
+        Ok(())
+    }
+}
+
+impl<H> ExtClientValidationContext for KvContext<H>
+where
+    H: KvHost,
+{
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        ValidationContext::host_timestamp(self)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        ValidationContext::host_height(self)
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        self.consensus_heights(client_id, false)
+            .map(|(height, _, value)| {
+                self.host().charge(CostEvent::store_read(&value))?;
+                Ok(height)
+            })
+            .collect()
+    }
+
+    /// Iterates lazily over the heights, without charging the host for the
+    /// entries of the index read: callers such as the pruning of expired
+    /// consensus states are rather charged for the consensus states they read.
+    fn ascending_consensus_state_heights<'a>(
+        &'a self,
+        client_id: &ClientId,
+    ) -> Result<Box<dyn Iterator<Item = Height> + 'a>, ContextError> {
+        Ok(Box::new(
+            self.consensus_heights(client_id, false)
+                .map(|(height, _, _)| height),
+        ))
+    }
+
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        self.find_consensus_height(client_id, false, |consensus_height| {
+            consensus_height > height
+        })?
+        .map(|consensus_height| {
+            self.consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                consensus_height.revision_number(),
+                consensus_height.revision_height(),
+            ))
+        })
+        .transpose()
+    }
+
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        self.find_consensus_height(client_id, true, |consensus_height| {
+            consensus_height < height
+        })?
+        .map(|consensus_height| {
+            self.consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                consensus_height.revision_number(),
+                consensus_height.revision_height(),
+            ))
+        })
+        .transpose()
+    }
+}
+
+/// Returns the prefix of the keys under which the heights of the consensus
+/// states of the given client are indexed.
+fn consensus_heights_prefix(client_id: &ClientId) -> Vec<u8> {
+    format!("{CLIENT_PREFIX}/{client_id}/{ITERATE_CONSENSUS_STATE_PREFIX}").into_bytes()
+}
+
+/// Returns the key under which the height of the consensus state stored at
+/// the given path is indexed, as in `ibc-go`.
+fn consensus_height_key(consensus_state_path: &ClientConsensusStatePath) -> Vec<u8> {
+    [
+        format!("{CLIENT_PREFIX}/{}/", consensus_state_path.client_id).into_bytes(),
+        iteration_key(
+            consensus_state_path.revision_number,
+            consensus_state_path.revision_height,
+        ),
+    ]
+    .concat()
+}
@@ -0,0 +1,447 @@
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::iter::Peekable;
+use core::str::FromStr;
+
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::metering::CostEvent;
+use ibc::core::handler::types::simulation::StateChange;
+use ibc::core::host::types::path::Path;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::{Any, Protobuf};
+use ibc_query::types::{Page, PageRequest};
+use prost::Message;
+
+use crate::host::KvHost;
+use crate::store::ProvableKvStore;
+
+/// Implements the IBC core contexts over the store of a [`KvHost`].
+///
+/// The events emitted and the messages logged by the handlers are buffered
+/// until the host takes them, e.g. at the end of each transaction.
+///
+/// Writes can also be cached on top of the store, through the
+/// [`CachedExecutionContext`](ibc::core::host::CachedExecutionContext)
+/// implementation, until they are committed or discarded along with the
+/// events and logs that came with them.
+pub struct KvContext<H>
+where
+    H: KvHost,
+{
+    host: H,
+    store: H::Store,
+    caches: Vec<WriteCache>,
+    events: Vec<IbcEvent>,
+    logs: Vec<String>,
+}
+
+/// The writes cached on top of the store, or of the enclosing cache, where
+/// `None` marks a deletion.
+struct WriteCache {
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+    events_len: usize,
+    logs_len: usize,
+}
+
+impl<H> KvContext<H>
+where
+    H: KvHost,
+{
+    /// Constructs a new context over the given host and store.
+    pub fn new(host: H, store: H::Store) -> Self {
+        Self {
+            host,
+            store,
+            caches: Vec::new(),
+            events: Vec::new(),
+            logs: Vec::new(),
+        }
+    }
+
+    /// Returns the host of the context.
+    pub fn host(&self) -> &H {
+        &self.host
+    }
+
+    /// Returns the host of the context, mutably.
+    pub fn host_mut(&mut self) -> &mut H {
+        &mut self.host
+    }
+
+    /// Returns the store of the context.
+    pub fn store(&self) -> &H::Store {
+        &self.store
+    }
+
+    /// Returns the store of the context, mutably.
+    pub fn store_mut(&mut self) -> &mut H::Store {
+        &mut self.store
+    }
+
+    /// Takes the IBC events emitted since the last call.
+    pub fn take_events(&mut self) -> Vec<IbcEvent> {
+        core::mem::take(&mut self.events)
+    }
+
+    /// Takes the messages logged since the last call.
+    pub fn take_logs(&mut self) -> Vec<String> {
+        core::mem::take(&mut self.logs)
+    }
+
+    /// Consumes the context, returning its host and store.
+    ///
+    /// The writes of the caches that are still open are lost.
+    pub fn into_parts(self) -> (H, H::Store) {
+        (self.host, self.store)
+    }
+
+    pub(crate) fn push_event(&mut self, event: IbcEvent) {
+        self.events.push(event);
+    }
+
+    pub(crate) fn push_log(&mut self, message: String) {
+        self.logs.push(message);
+    }
+
+    /// Opens a new cache for the subsequent writes, events and logs.
+    pub(crate) fn push_cache(&mut self) {
+        self.caches.push(WriteCache {
+            writes: BTreeMap::new(),
+            events_len: self.events.len(),
+            logs_len: self.logs.len(),
+        });
+    }
+
+    /// Commits the writes of the innermost cache into the enclosing one, or
+    /// into the store if there is none.
+    pub(crate) fn commit_top_cache(&mut self) -> Result<(), String> {
+        let Some(cache) = self.caches.pop() else {
+            return Err("no cache to commit".to_string());
+        };
+
+        if let Some(parent) = self.caches.last_mut() {
+            parent.writes.extend(cache.writes);
+            return Ok(());
+        }
+
+        for (key, value) in cache.writes {
+            match value {
+                Some(value) => self.store.set(key, value),
+                None => self.store.delete(&key),
+            }
+            .map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the changes made within the innermost cache, in the order of
+    /// their keys.
+    pub(crate) fn top_cache_changes(&self) -> Vec<StateChange> {
+        let Some(cache) = self.caches.last() else {
+            return Vec::new();
+        };
+
+        cache
+            .writes
+            .iter()
+            .filter_map(|(key, value)| {
+                let path = parse_path(key)?;
+                Some(match value {
+                    Some(value) => StateChange::Write {
+                        path,
+                        value: value.clone(),
+                    },
+                    None => StateChange::Delete { path },
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the events emitted within the innermost cache.
+    pub(crate) fn top_cache_events(&self) -> &[IbcEvent] {
+        let start = self.caches.last().map_or(0, |cache| cache.events_len);
+
+        &self.events[start..]
+    }
+
+    /// Returns the messages logged within the innermost cache.
+    pub(crate) fn top_cache_logs(&self) -> &[String] {
+        let start = self.caches.last().map_or(0, |cache| cache.logs_len);
+
+        &self.logs[start..]
+    }
+
+    /// Discards the innermost cache, along with the events and logs emitted
+    /// since it was opened.
+    pub(crate) fn discard_top_cache(&mut self) {
+        if let Some(cache) = self.caches.pop() {
+            self.events.truncate(cache.events_len);
+            self.logs.truncate(cache.logs_len);
+        }
+    }
+
+    /// Returns the raw value stored at the given path, charging the host for
+    /// the read.
+    pub(crate) fn get_raw(&self, path: &impl Display) -> Result<Option<Vec<u8>>, String> {
+        let value = self.peek_raw(path);
+
+        self.host
+            .charge(CostEvent::store_read(value.as_deref().unwrap_or_default()))
+            .map_err(|e| e.to_string())?;
+
+        Ok(value)
+    }
+
+    /// Returns the raw value stored at the given path, without charging the
+    /// host, e.g. to serve queries.
+    pub(crate) fn peek_raw(&self, path: &impl Display) -> Option<Vec<u8>> {
+        let key = path_key(path);
+
+        for cache in self.caches.iter().rev() {
+            if let Some(value) = cache.writes.get(&key) {
+                return value.clone();
+            }
+        }
+
+        self.store.get(key.as_slice())
+    }
+
+    /// Returns the protobuf-decoded value stored at the given path.
+    pub(crate) fn get_proto<T, Raw>(&self, path: &impl Display) -> Result<Option<T>, String>
+    where
+        T: Protobuf<Raw>,
+        Raw: Message + Default + From<T>,
+        <T as TryFrom<Raw>>::Error: Display,
+    {
+        self.get_raw(path)?
+            .map(|value| T::decode_vec(&value).map_err(|e| e.to_string()))
+            .transpose()
+    }
+
+    /// Returns the `Any`-decoded value stored at the given path.
+    pub(crate) fn get_any<T>(&self, path: &impl Display) -> Result<Option<T>, String>
+    where
+        T: TryFrom<Any>,
+        <T as TryFrom<Any>>::Error: Display,
+    {
+        self.get_raw(path)?
+            .map(|value| decode_any(&value))
+            .transpose()
+    }
+
+    /// Returns the big-endian `u64` stored at the given path.
+    pub(crate) fn get_u64(&self, path: &impl Display) -> Result<Option<u64>, String> {
+        self.get_raw(path)?
+            .map(|value| {
+                value
+                    .try_into()
+                    .map(u64::from_be_bytes)
+                    .map_err(|_| format!("value at `{path}` is not a big-endian u64"))
+            })
+            .transpose()
+    }
+
+    /// Stores the given raw value at the given path.
+    pub(crate) fn set_raw(&mut self, path: &impl Display, value: Vec<u8>) -> Result<(), String> {
+        self.write_key(path_key(path), Some(value))
+    }
+
+    /// Stores the given value at the given path, protobuf-encoded.
+    pub(crate) fn set_proto<T, Raw>(&mut self, path: &impl Display, value: T) -> Result<(), String>
+    where
+        T: Protobuf<Raw>,
+        Raw: Message + Default + From<T>,
+        <T as TryFrom<Raw>>::Error: Display,
+    {
+        self.set_raw(path, value.encode_vec())
+    }
+
+    /// Stores the given value at the given path, `Any`-encoded.
+    pub(crate) fn set_any<T>(&mut self, path: &impl Display, value: T) -> Result<(), String>
+    where
+        T: Into<Any>,
+    {
+        self.set_raw(path, value.into().encode_to_vec())
+    }
+
+    /// Stores the given `u64` at the given path, big-endian encoded.
+    pub(crate) fn set_u64(&mut self, path: &impl Display, value: u64) -> Result<(), String> {
+        self.set_raw(path, value.to_be_bytes().to_vec())
+    }
+
+    /// Deletes the value stored at the given path.
+    pub(crate) fn delete(&mut self, path: &impl Display) -> Result<(), String> {
+        self.write_key(path_key(path), None)
+    }
+
+    /// Stores the given raw value at the given store key, or deletes the
+    /// value stored there if `None`, charging the host for the write.
+    pub(crate) fn write_key(&mut self, key: Vec<u8>, value: Option<Vec<u8>>) -> Result<(), String> {
+        self.host
+            .charge(CostEvent::store_write(value.as_deref().unwrap_or_default()))
+            .map_err(|e| e.to_string())?;
+
+        if let Some(cache) = self.caches.last_mut() {
+            cache.writes.insert(key, value);
+            return Ok(());
+        }
+
+        match value {
+            Some(value) => self.store.set(key, value),
+            None => self.store.delete(&key),
+        }
+        .map_err(|e| e.to_string())
+    }
+
+    /// Iterates over the raw entries stored under the given key prefix, as
+    /// seen through the open caches, in ascending order of their keys, or in
+    /// descending order if `reverse` is set.
+    ///
+    /// The store is read lazily, as the entries are consumed: only the writes
+    /// cached under the prefix, i.e. those of the ongoing transaction, are
+    /// collected beforehand.
+    pub(crate) fn prefix_entries<'a>(&'a self, prefix: &[u8], reverse: bool) -> PrefixEntries<'a> {
+        let mut writes = BTreeMap::new();
+
+        for cache in &self.caches {
+            writes.extend(
+                cache
+                    .writes
+                    .range(prefix.to_vec()..)
+                    .take_while(|(key, _)| key.starts_with(prefix))
+                    .map(|(key, value)| (key.clone(), value.clone())),
+            );
+        }
+
+        let (entries, writes): (Box<dyn Iterator<Item = _>>, Box<dyn Iterator<Item = _>>) =
+            if reverse {
+                (
+                    self.store.prefix_iter_rev(prefix),
+                    Box::new(writes.into_iter().rev()),
+                )
+            } else {
+                (self.store.prefix_iter(prefix), Box::new(writes.into_iter()))
+            };
+
+        PrefixEntries {
+            entries: entries.peekable(),
+            writes: writes.peekable(),
+            reverse,
+        }
+    }
+
+    /// Iterates over the IBC paths of all the values stored under the given
+    /// parent path, along with their raw values.
+    pub(crate) fn paths_under(
+        &self,
+        parent: &impl Display,
+    ) -> impl Iterator<Item = (Path, Vec<u8>)> + '_ {
+        let prefix = format!("{parent}/");
+
+        self.prefix_entries(prefix.as_bytes(), false)
+            .filter_map(|(key, value)| Some((parse_path(&key)?, value)))
+    }
+
+    /// Returns the requested page of the values stored under the given parent
+    /// path, among those whose IBC path `select` picks an item from.
+    ///
+    /// The items are paginated in the order of their store keys, which also
+    /// serve as pagination keys, relative to the parent path: as in `ibc-go`,
+    /// sequences thus come in the lexicographic order of their decimal
+    /// representations. Only the values of the page are returned, for
+    /// the caller to decode.
+    pub(crate) fn paginate_under<T>(
+        &self,
+        parent: &impl Display,
+        page_request: &PageRequest,
+        select: impl Fn(Path) -> Option<T>,
+    ) -> Page<(T, Vec<u8>)> {
+        let prefix = format!("{parent}/");
+
+        let entries = self
+            .prefix_entries(prefix.as_bytes(), page_request.reverse)
+            .filter_map(|(key, value)| Some((select(parse_path(&key)?)?, key, value)));
+
+        let (page, page_response) =
+            page_request.paginate_ordered(entries, |(_, key, _)| key[prefix.len()..].to_vec());
+
+        (
+            page.into_iter()
+                .map(|(item, _, value)| (item, value))
+                .collect(),
+            page_response,
+        )
+    }
+
+    /// Returns `true` if any value is stored under the given parent path.
+    pub(crate) fn has_paths_under(&self, parent: &impl Display) -> bool {
+        let prefix = format!("{parent}/");
+
+        self.prefix_entries(prefix.as_bytes(), false)
+            .next()
+            .is_some()
+    }
+}
+
+type Entries<'a, V> = Peekable<Box<dyn Iterator<Item = (Vec<u8>, V)> + 'a>>;
+
+/// Iterates over the entries of the store under a prefix, merged with the
+/// writes cached under that prefix.
+pub(crate) struct PrefixEntries<'a> {
+    entries: Entries<'a, Vec<u8>>,
+    writes: Entries<'a, Option<Vec<u8>>>,
+    reverse: bool,
+}
+
+impl Iterator for PrefixEntries<'_> {
+    type Item = (Vec<u8>, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // The order of the next stored entry relative to the next cached
+            // write, in the order of the iteration.
+            let ordering = match (self.entries.peek(), self.writes.peek()) {
+                (_, None) => return self.entries.next(),
+                (None, Some(_)) => Ordering::Greater,
+                (Some((key, _)), Some((write_key, _))) if self.reverse => write_key.cmp(key),
+                (Some((key, _)), Some((write_key, _))) => key.cmp(write_key),
+            };
+
+            match ordering {
+                Ordering::Less => return self.entries.next(),
+                // The cached write shadows the stored entry.
+                Ordering::Equal => {
+                    self.entries.next();
+                }
+                Ordering::Greater => {}
+            }
+
+            // Deletions are skipped.
+            if let Some((key, Some(value))) = self.writes.next() {
+                return Some((key, value));
+            }
+        }
+    }
+}
+
+/// Parses the IBC path stored at the given key, if any.
+fn parse_path(key: &[u8]) -> Option<Path> {
+    Path::from_str(core::str::from_utf8(key).ok()?).ok()
+}
+
+/// Decodes a value stored as an encoded `Any`.
+pub(crate) fn decode_any<T>(value: &[u8]) -> Result<T, String>
+where
+    T: TryFrom<Any>,
+    <T as TryFrom<Any>>::Error: Display,
+{
+    let any = Any::decode(value).map_err(|e| e.to_string())?;
+
+    T::try_from(any).map_err(|e| e.to_string())
+}
+
+/// Returns the store key of the given ICS-24 path.
+pub(crate) fn path_key(path: &impl Display) -> Vec<u8> {
+    path.to_string().into_bytes()
+}
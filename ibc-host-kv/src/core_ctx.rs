@@ -0,0 +1,683 @@
+//! Implementation of the `ValidationContext` and `ExecutionContext` traits for
+//! the [`KvContext`] type.
+
+use core::fmt::Display;
+use core::str::FromStr;
+use core::time::Duration;
+
+use ibc::core::channel::types::channel::ChannelEnd;
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::error::{ChannelError, PacketError};
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::raw::CounterpartyInfo as RawCounterpartyInfo;
+use ibc::core::client::types::{CounterpartyInfo, Height};
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::error::ConnectionError;
+use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::simulation::StateChange;
+use ibc::core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc::core::host::types::path::{
+    AckPath, AckV2Path, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath,
+    ClientConnectionPath, ClientCounterpartyPath, CommitmentPath, CommitmentV2Path, ConnectionPath,
+    CounterpartyUpgradePath, NextChannelSequencePath, NextClientSequencePath,
+    NextConnectionSequencePath, Path, ReceiptPath, ReceiptV2Path, SeqAckPath, SeqRecvPath,
+    SeqSendPath, SeqSendV2Path,
+};
+use ibc::core::host::{CachedExecutionContext, ExecutionContext, ValidationContext};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc_proto::ibc::core::channel::v1::{
+    Channel as RawChannelEnd, ErrorReceipt as RawErrorReceipt, Upgrade as RawUpgrade,
+};
+use ibc_proto::ibc::core::connection::v1::{
+    ClientPaths as RawClientPaths, ConnectionEnd as RawConnectionEnd,
+};
+use prost::Message;
+
+use crate::context::KvContext;
+use crate::host::KvHost;
+
+impl<H> KvContext<H>
+where
+    H: KvHost,
+{
+    /// Returns the identifiers of the connections of the client at the given
+    /// path, stored as a protobuf-encoded `ClientPaths`.
+    pub(crate) fn client_connections(
+        &self,
+        client_connection_path: &ClientConnectionPath,
+    ) -> Result<Vec<ConnectionId>, ContextError> {
+        let Some(value) = self
+            .get_raw(client_connection_path)
+            .map_err(|description| ConnectionError::Other { description })?
+        else {
+            return Ok(Vec::new());
+        };
+
+        let client_paths =
+            RawClientPaths::decode(value.as_slice()).map_err(|e| ConnectionError::Other {
+                description: e.to_string(),
+            })?;
+
+        client_paths
+            .paths
+            .iter()
+            .map(|conn_id| {
+                ConnectionId::from_str(conn_id).map_err(|e| {
+                    ConnectionError::Other {
+                        description: e.to_string(),
+                    }
+                    .into()
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the receipt stored at the given path, if any.
+    pub(crate) fn receipt(&self, path: &impl Display) -> Result<Option<Receipt>, String> {
+        Ok(self.get_raw(path)?.map(|value| {
+            if value == Receipt::Timeout.to_vec() {
+                Receipt::Timeout
+            } else {
+                Receipt::Ok
+            }
+        }))
+    }
+}
+
+impl<H> ValidationContext for KvContext<H>
+where
+    H: KvHost,
+{
+    type V = Self;
+    type HostClientState = H::HostClientState;
+    type HostConsensusState = H::HostConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.host().host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.host().host_timestamp()
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.host().host_consensus_state(height)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        Ok(self
+            .get_u64(&NextClientSequencePath)
+            .map_err(|description| ClientError::Other { description })?
+            .unwrap_or_default())
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        Ok(self
+            .get_proto::<ConnectionEnd, RawConnectionEnd>(&ConnectionPath::new(conn_id))
+            .map_err(|description| ConnectionError::Other { description })?
+            .ok_or(ConnectionError::ConnectionNotFound {
+                connection_id: conn_id.clone(),
+            })?)
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        self.host()
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.host().commitment_prefix()
+    }
+
+    fn read_store(&self, path: &Path) -> Result<Option<Vec<u8>>, ContextError> {
+        // Values are stored as they are proven to counterparties.
+        Ok(self
+            .get_raw(path)
+            .map_err(|description| ClientError::Other { description })?)
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        Ok(self
+            .get_u64(&NextConnectionSequencePath)
+            .map_err(|description| ConnectionError::Other { description })?
+            .unwrap_or_default())
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        Ok(self
+            .get_proto::<ChannelEnd, RawChannelEnd>(channel_end_path)
+            .map_err(|description| ChannelError::Other { description })?
+            .ok_or(ChannelError::ChannelNotFound {
+                port_id: channel_end_path.0.clone(),
+                channel_id: channel_end_path.1.clone(),
+            })?)
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        Ok(self
+            .get_u64(seq_send_path)
+            .map_err(|description| PacketError::Other { description })?
+            .map(Sequence::from)
+            .ok_or(PacketError::MissingNextSendSeq {
+                port_id: seq_send_path.0.clone(),
+                channel_id: seq_send_path.1.clone(),
+            })?)
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        Ok(self
+            .get_u64(seq_recv_path)
+            .map_err(|description| PacketError::Other { description })?
+            .map(Sequence::from)
+            .ok_or(PacketError::MissingNextRecvSeq {
+                port_id: seq_recv_path.0.clone(),
+                channel_id: seq_recv_path.1.clone(),
+            })?)
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        Ok(self
+            .get_u64(seq_ack_path)
+            .map_err(|description| PacketError::Other { description })?
+            .map(Sequence::from)
+            .ok_or(PacketError::MissingNextAckSeq {
+                port_id: seq_ack_path.0.clone(),
+                channel_id: seq_ack_path.1.clone(),
+            })?)
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        Ok(self
+            .get_raw(commitment_path)
+            .map_err(|description| PacketError::Other { description })?
+            .map(PacketCommitment::from)
+            .ok_or(PacketError::PacketCommitmentNotFound {
+                sequence: commitment_path.sequence,
+            })?)
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        Ok(self
+            .receipt(receipt_path)
+            .map_err(|description| PacketError::Other { description })?
+            .ok_or(PacketError::PacketReceiptNotFound {
+                sequence: receipt_path.sequence,
+            })?)
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        Ok(self
+            .get_raw(ack_path)
+            .map_err(|description| PacketError::Other { description })?
+            .map(AcknowledgementCommitment::from)
+            .ok_or(PacketError::PacketAcknowledgementNotFound {
+                sequence: ack_path.sequence,
+            })?)
+    }
+
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError> {
+        Ok(self
+            .get_proto::<Upgrade, RawUpgrade>(upgrade_path)
+            .map_err(|description| ChannelError::Other { description })?
+            .ok_or(ChannelError::UpgradeNotFound {
+                port_id: upgrade_path.0.clone(),
+                channel_id: upgrade_path.1.clone(),
+            })?)
+    }
+
+    fn counterparty_upgrade(
+        &self,
+        counterparty_upgrade_path: &CounterpartyUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        Ok(self
+            .get_proto::<Upgrade, RawUpgrade>(counterparty_upgrade_path)
+            .map_err(|description| ChannelError::Other { description })?
+            .ok_or(ChannelError::CounterpartyUpgradeNotFound {
+                port_id: counterparty_upgrade_path.0.clone(),
+                channel_id: counterparty_upgrade_path.1.clone(),
+            })?)
+    }
+
+    fn channel_upgrade_error(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError> {
+        Ok(self
+            .get_proto::<ErrorReceipt, RawErrorReceipt>(upgrade_error_path)
+            .map_err(|description| ChannelError::Other { description })?
+            .ok_or(ChannelError::UpgradeErrorReceiptNotFound {
+                port_id: upgrade_error_path.0.clone(),
+                channel_id: upgrade_error_path.1.clone(),
+            })?)
+    }
+
+    fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        Ok(self.has_paths_under(&channel_end_path.commitments_path()))
+    }
+
+    fn client_counterparty(
+        &self,
+        counterparty_path: &ClientCounterpartyPath,
+    ) -> Result<CounterpartyInfo, ContextError> {
+        Ok(self
+            .get_proto::<CounterpartyInfo, RawCounterpartyInfo>(counterparty_path)
+            .map_err(|description| ClientError::Other { description })?
+            .ok_or(ClientError::CounterpartyNotFound {
+                client_id: counterparty_path.0.clone(),
+            })?)
+    }
+
+    fn get_next_sequence_send_v2(
+        &self,
+        seq_send_path: &SeqSendV2Path,
+    ) -> Result<Sequence, ContextError> {
+        Ok(self
+            .get_u64(seq_send_path)
+            .map_err(|description| PacketError::Other { description })?
+            .map(Sequence::from)
+            .ok_or(ClientError::CounterpartyNotFound {
+                client_id: seq_send_path.0.clone(),
+            })?)
+    }
+
+    fn get_packet_commitment_v2(
+        &self,
+        commitment_path: &CommitmentV2Path,
+    ) -> Result<PacketCommitment, ContextError> {
+        Ok(self
+            .get_raw(commitment_path)
+            .map_err(|description| PacketError::Other { description })?
+            .map(PacketCommitment::from)
+            .ok_or(PacketError::PacketCommitmentNotFound {
+                sequence: commitment_path.sequence,
+            })?)
+    }
+
+    fn get_packet_receipt_v2(&self, receipt_path: &ReceiptV2Path) -> Result<Receipt, ContextError> {
+        Ok(self
+            .receipt(receipt_path)
+            .map_err(|description| PacketError::Other { description })?
+            .ok_or(PacketError::PacketReceiptNotFound {
+                sequence: receipt_path.sequence,
+            })?)
+    }
+
+    fn get_packet_acknowledgement_v2(
+        &self,
+        ack_path: &AckV2Path,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        Ok(self
+            .get_raw(ack_path)
+            .map_err(|description| PacketError::Other { description })?
+            .map(AcknowledgementCommitment::from)
+            .ok_or(PacketError::PacketAcknowledgementNotFound {
+                sequence: ack_path.sequence,
+            })?)
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        Ok(self
+            .get_u64(&NextChannelSequencePath)
+            .map_err(|description| ChannelError::Other { description })?
+            .unwrap_or_default())
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.host().max_expected_time_per_block()
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.host().validate_message_signer(signer)
+    }
+}
+
+impl<H> ExecutionContext for KvContext<H>
+where
+    H: KvHost,
+{
+    type E = Self;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        let client_counter = self.client_counter()?;
+
+        self.set_u64(&NextClientSequencePath, client_counter + 1)
+            .map_err(|description| ClientError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.set_proto::<ConnectionEnd, RawConnectionEnd>(connection_path, connection_end)
+            .map_err(|description| ConnectionError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        let mut conn_ids = self.client_connections(client_connection_path)?;
+        conn_ids.push(conn_id);
+
+        let client_paths = RawClientPaths {
+            paths: conn_ids.iter().map(ToString::to_string).collect(),
+        };
+
+        self.set_raw(client_connection_path, client_paths.encode_to_vec())
+            .map_err(|description| ConnectionError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        let connection_counter = self.connection_counter()?;
+
+        self.set_u64(&NextConnectionSequencePath, connection_counter + 1)
+            .map_err(|description| ConnectionError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.set_raw(commitment_path, commitment.into_vec())
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.delete(commitment_path)
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.set_raw(receipt_path, receipt.to_vec())
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.set_raw(ack_path, ack_commitment.into_vec())
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.delete(ack_path)
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.set_proto::<ChannelEnd, RawChannelEnd>(channel_end_path, channel_end)
+            .map_err(|description| ChannelError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.set_u64(seq_send_path, seq.value())
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.set_u64(seq_recv_path, seq.value())
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.set_u64(seq_ack_path, seq.value())
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.set_proto::<Upgrade, RawUpgrade>(upgrade_path, upgrade)
+            .map_err(|description| ChannelError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.delete(upgrade_path)
+            .map_err(|description| ChannelError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_counterparty_upgrade(
+        &mut self,
+        counterparty_upgrade_path: &CounterpartyUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.set_proto::<Upgrade, RawUpgrade>(counterparty_upgrade_path, upgrade)
+            .map_err(|description| ChannelError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn delete_counterparty_upgrade(
+        &mut self,
+        counterparty_upgrade_path: &CounterpartyUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.delete(counterparty_upgrade_path)
+            .map_err(|description| ChannelError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        self.set_proto::<ErrorReceipt, RawErrorReceipt>(upgrade_error_path, error_receipt)
+            .map_err(|description| ChannelError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_client_counterparty(
+        &mut self,
+        counterparty_path: &ClientCounterpartyPath,
+        counterparty: CounterpartyInfo,
+    ) -> Result<(), ContextError> {
+        self.set_proto::<CounterpartyInfo, RawCounterpartyInfo>(counterparty_path, counterparty)
+            .map_err(|description| ClientError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_next_sequence_send_v2(
+        &mut self,
+        seq_send_path: &SeqSendV2Path,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.set_u64(seq_send_path, seq.value())
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_packet_commitment_v2(
+        &mut self,
+        commitment_path: &CommitmentV2Path,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.set_raw(commitment_path, commitment.into_vec())
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn delete_packet_commitment_v2(
+        &mut self,
+        commitment_path: &CommitmentV2Path,
+    ) -> Result<(), ContextError> {
+        self.delete(commitment_path)
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_packet_receipt_v2(
+        &mut self,
+        receipt_path: &ReceiptV2Path,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.set_raw(receipt_path, receipt.to_vec())
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement_v2(
+        &mut self,
+        ack_path: &AckV2Path,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.set_raw(ack_path, ack_commitment.into_vec())
+            .map_err(|description| PacketError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        let channel_counter = self.channel_counter()?;
+
+        self.set_u64(&NextChannelSequencePath, channel_counter + 1)
+            .map_err(|description| ChannelError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.push_event(event);
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.push_log(message);
+        Ok(())
+    }
+}
+
+impl<H> CachedExecutionContext for KvContext<H>
+where
+    H: KvHost,
+{
+    fn begin_cache(&mut self) {
+        self.push_cache();
+    }
+
+    fn commit_cache(&mut self) -> Result<(), ContextError> {
+        self.commit_top_cache()
+            .map_err(|description| ClientError::Other { description })?;
+
+        Ok(())
+    }
+
+    fn discard_cache(&mut self) {
+        self.discard_top_cache();
+    }
+
+    fn cached_changes(&self) -> Vec<StateChange> {
+        self.top_cache_changes()
+    }
+
+    fn cached_events(&self) -> Vec<IbcEvent> {
+        self.top_cache_events().to_vec()
+    }
+
+    fn cached_logs(&self) -> Vec<String> {
+        self.top_cache_logs().to_vec()
+    }
+}
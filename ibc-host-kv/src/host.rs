@@ -0,0 +1,69 @@
+use core::time::Duration;
+
+use ibc::core::client::context::client_state::{ClientStateExecution, ClientStateValidation};
+use ibc::core::client::context::consensus_state::ConsensusState;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::metering::CostEvent;
+use ibc::core::primitives::proto::Any;
+use ibc::core::primitives::{Signer, Timestamp};
+
+use crate::context::KvContext;
+use crate::store::ProvableKvStore;
+
+/// Enables hosts to plug their store and chain-specific details into the
+/// generic [`KvContext`] object.
+///
+/// Client and consensus states are stored as `Any`, so that a host supports as
+/// many light clients as its `ClientState` and `ConsensusState` types, usually
+/// enums over the supported clients, can be converted from.
+pub trait KvHost: Sized {
+    /// The store in which the IBC state is kept.
+    type Store: ProvableKvStore;
+    /// The client state type of all the supported light clients.
+    type ClientState: ClientStateExecution<KvContext<Self>> + TryFrom<Any, Error = ClientError>;
+    /// The consensus state type of all the supported light clients.
+    type ConsensusState: ConsensusState + TryFrom<Any, Error = ClientError>;
+    /// The client state type of the host chain, as stored by counterparties.
+    type HostClientState: ClientStateValidation<KvContext<Self>>;
+    /// The consensus state type of the host chain.
+    type HostConsensusState: ConsensusState;
+
+    /// Returns the current height of the host chain.
+    fn host_height(&self) -> Result<Height, ContextError>;
+
+    /// Returns the current timestamp of the host chain.
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
+    /// Returns the consensus state of the host chain at the given height.
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError>;
+
+    /// Validates the client state of the host chain stored on a counterparty
+    /// chain against the host's internal state.
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError>;
+
+    /// Validates the `signer` field of IBC messages.
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+
+    /// Returns the prefix under which the store is committed to in the state
+    /// of the host chain, e.g. `ibc` for the IBC store of Cosmos SDK chains.
+    fn commitment_prefix(&self) -> CommitmentPrefix;
+
+    /// Returns the maximum expected time per block.
+    fn max_expected_time_per_block(&self) -> Duration;
+
+    /// Charges the host for the resources consumed while handling a message,
+    /// including the reads and writes of the store made by the
+    /// [`KvContext`]. Does nothing by default.
+    fn charge(&self, _event: CostEvent) -> Result<(), ContextError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+//! Provides ready-made implementations of the IBC core contexts over a
+//! provable key-value store.
+//!
+//! Implementing the [`ValidationContext`](ibc::core::host::ValidationContext)
+//! and [`ExecutionContext`](ibc::core::host::ExecutionContext) traits mostly
+//! amounts to reading and writing the IBC state at the paths defined by
+//! ICS-24. This crate does so once and for all: the [`KvContext`] object
+//! implements these traits, along with the client contexts and the
+//! [`QueryContext`](ibc_query::core::context::QueryContext) of the gRPC query
+//! services, over any store implementing the small [`ProvableKvStore`] trait.
+//!
+//! The state is stored at the canonical ICS-24 paths, as encoded by
+//! `ibc-go`, so that it can be proven to any counterparty:
+//! - connection and channel ends, channel upgrades, error receipts and client
+//!   counterparties are protobuf-encoded;
+//! - client and consensus states are protobuf-encoded as `Any`;
+//! - sequences, counters and processing times are big-endian `u64`s;
+//! - packet commitments and acknowledgements are stored as is;
+//! - the heights of the consensus states are also indexed at the iteration
+//!   keys of `ibc-go`, which sort as the heights do.
+//!
+//! A host then only supplies its store, along with the details that are
+//! specific to its chain, through the [`KvHost`] trait.
+//!
+//! Example
+//! ```rust,ignore
+//! use ibc::core::entrypoint::dispatch;
+//! use ibc_host_kv::{KvContext, KvHost, ProvableKvStore};
+//!
+//! struct MyStore;
+//! impl ProvableKvStore for MyStore { }
+//!
+//! struct MyHost;
+//! impl KvHost for MyHost {
+//!     type Store = MyStore;
+//!     // ...
+//! }
+//!
+//! let mut ctx = KvContext::new(MyHost, MyStore);
+//!
+//! dispatch(&mut ctx, &mut router, msg)?;
+//!
+//! let events = ctx.take_events();
+//! ```
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+mod client_ctx;
+mod context;
+mod core_ctx;
+mod host;
+mod query_ctx;
+mod store;
+
+pub use context::KvContext;
+pub use host::KvHost;
+pub use store::ProvableKvStore;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,393 @@
+//! Implementation of the `ProvableContext` and `QueryContext` traits for the
+//! [`KvContext`] type.
+
+use core::fmt::Display;
+
+use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::packet::PacketState;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::error::ConnectionError;
+use ibc::core::connection::types::{ConnectionEnd, IdentifiedConnectionEnd};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::path::{
+    full_consensus_state_path, AckPath, ChannelEndPath, ClientConnectionPath,
+    ClientConsensusStatePath, ClientStatePath, CommitmentPath, ConnectionPath, Path, ReceiptPath,
+};
+use ibc::core::host::{ClientStateRef, ConsensusStateRef};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::{Any, Protobuf};
+use ibc_query::core::context::{ProvableContext, QueryContext};
+use ibc_query::types::{Page, PageRequest};
+
+use crate::context::{decode_any, path_key, KvContext};
+use crate::host::KvHost;
+use crate::store::ProvableKvStore;
+
+impl<H> ProvableContext for KvContext<H>
+where
+    H: KvHost,
+{
+    fn get_proof(&self, height: Height, path: &Path) -> Option<Vec<u8>> {
+        self.store().prove(height, &path_key(path))
+    }
+}
+
+impl<H> QueryContext for KvContext<H>
+where
+    H: KvHost,
+{
+    fn client_states(&self) -> Result<Vec<(ClientId, ClientStateRef<Self>)>, ContextError> {
+        self.paths_under(&ClientStatePath::prefix())
+            .filter_map(|(path, value)| match path {
+                Path::ClientState(client_state_path) => Some((client_state_path.0, value)),
+                _ => None,
+            })
+            .map(|(client_id, value)| Ok((client_id, decode_client_value(&value)?)))
+            .collect()
+    }
+
+    fn paginated_client_states(
+        &self,
+        page_request: &PageRequest,
+    ) -> Result<Page<(ClientId, ClientStateRef<Self>)>, ContextError> {
+        let (client_states, page_response) = self.paginate_under(
+            &ClientStatePath::prefix(),
+            page_request,
+            |path| match path {
+                Path::ClientState(client_state_path) => Some(client_state_path.0),
+                _ => None,
+            },
+        );
+
+        let client_states = client_states
+            .into_iter()
+            .map(|(client_id, value)| Ok((client_id, decode_client_value(&value)?)))
+            .collect::<Result<_, ContextError>>()?;
+
+        Ok((client_states, page_response))
+    }
+
+    fn consensus_states(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<(Height, ConsensusStateRef<Self>)>, ContextError> {
+        self.paths_under(&full_consensus_state_path(client_id))
+            .filter_map(|(path, value)| match path {
+                Path::ClientConsensusState(consensus_path) => Some((consensus_path, value)),
+                _ => None,
+            })
+            .map(|(consensus_path, value)| {
+                let height = Height::new(
+                    consensus_path.revision_number,
+                    consensus_path.revision_height,
+                )?;
+                Ok((height, decode_client_value(&value)?))
+            })
+            .collect()
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        Ok(self
+            .consensus_heights(client_id, false)
+            .map(|(height, _, _)| height)
+            .collect())
+    }
+
+    /// Returns the requested page of all consensus states for the given
+    /// client, in the order of their heights, which are keyed as in the
+    /// default implementation.
+    fn paginated_consensus_states(
+        &self,
+        client_id: &ClientId,
+        page_request: &PageRequest,
+    ) -> Result<Page<(Height, ConsensusStateRef<Self>)>, ContextError> {
+        let (heights, page_response) =
+            self.paginated_consensus_state_heights(client_id, page_request)?;
+
+        let consensus_states = heights
+            .into_iter()
+            .map(|height| {
+                let consensus_state = self.consensus_state(&ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    height.revision_number(),
+                    height.revision_height(),
+                ))?;
+                Ok((height, consensus_state))
+            })
+            .collect::<Result<_, ContextError>>()?;
+
+        Ok((consensus_states, page_response))
+    }
+
+    fn paginated_consensus_state_heights(
+        &self,
+        client_id: &ClientId,
+        page_request: &PageRequest,
+    ) -> Result<Page<Height>, ContextError> {
+        let (heights, page_response) = page_request.paginate_ordered(
+            self.consensus_heights(client_id, page_request.reverse),
+            |(_, key, _)| key.clone(),
+        );
+
+        Ok((
+            heights.into_iter().map(|(height, _, _)| height).collect(),
+            page_response,
+        ))
+    }
+
+    fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError> {
+        self.paths_under(&ConnectionPath::prefix())
+            .filter_map(|(path, value)| match path {
+                Path::Connection(connection_path) => Some((connection_path.0, value)),
+                _ => None,
+            })
+            .map(|(connection_id, value)| decode_connection_end(connection_id, &value))
+            .collect()
+    }
+
+    fn paginated_connection_ends(
+        &self,
+        page_request: &PageRequest,
+    ) -> Result<Page<IdentifiedConnectionEnd>, ContextError> {
+        let (connection_ends, page_response) =
+            self.paginate_under(&ConnectionPath::prefix(), page_request, |path| match path {
+                Path::Connection(connection_path) => Some(connection_path.0),
+                _ => None,
+            });
+
+        let connection_ends = connection_ends
+            .into_iter()
+            .map(|(connection_id, value)| decode_connection_end(connection_id, &value))
+            .collect::<Result<_, _>>()?;
+
+        Ok((connection_ends, page_response))
+    }
+
+    fn client_connection_ends(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<ConnectionId>, ContextError> {
+        self.client_connections(&ClientConnectionPath::new(client_id.clone()))
+    }
+
+    fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError> {
+        self.paths_under(&ChannelEndPath::prefix())
+            .filter_map(|(path, value)| match path {
+                Path::ChannelEnd(channel_end_path) => Some((channel_end_path, value)),
+                _ => None,
+            })
+            .map(|(channel_end_path, value)| decode_channel_end(channel_end_path, &value))
+            .collect()
+    }
+
+    fn paginated_channel_ends(
+        &self,
+        page_request: &PageRequest,
+    ) -> Result<Page<IdentifiedChannelEnd>, ContextError> {
+        let (channel_ends, page_response) =
+            self.paginate_under(&ChannelEndPath::prefix(), page_request, |path| match path {
+                Path::ChannelEnd(channel_end_path) => Some(channel_end_path),
+                _ => None,
+            });
+
+        let channel_ends = channel_ends
+            .into_iter()
+            .map(|(channel_end_path, value)| decode_channel_end(channel_end_path, &value))
+            .collect::<Result<_, _>>()?;
+
+        Ok((channel_ends, page_response))
+    }
+
+    fn packet_commitments(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<PacketState>, ContextError> {
+        Ok(self
+            .paths_under(&channel_end_path.commitments_path())
+            .filter_map(|(path, data)| match path {
+                Path::Commitment(commitment_path) => Some(PacketState {
+                    port_id: commitment_path.port_id,
+                    chan_id: commitment_path.channel_id,
+                    seq: commitment_path.sequence,
+                    data,
+                }),
+                _ => None,
+            })
+            .collect())
+    }
+
+    fn paginated_packet_commitments(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        page_request: &PageRequest,
+    ) -> Result<Page<PacketState>, ContextError> {
+        let (commitments, page_response) =
+            self.paginate_under(&channel_end_path.commitments_path(), page_request, |path| {
+                match path {
+                    Path::Commitment(commitment_path) => Some(commitment_path),
+                    _ => None,
+                }
+            });
+
+        let commitments = commitments
+            .into_iter()
+            .map(|(commitment_path, data)| PacketState {
+                port_id: commitment_path.port_id,
+                chan_id: commitment_path.channel_id,
+                seq: commitment_path.sequence,
+                data,
+            })
+            .collect();
+
+        Ok((commitments, page_response))
+    }
+
+    fn packet_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl ExactSizeIterator<Item = Sequence>,
+    ) -> Result<Vec<PacketState>, ContextError> {
+        if sequences.len() == 0 {
+            // if sequences is empty, return all the acks
+            return Ok(self
+                .paths_under(&channel_end_path.acks_path())
+                .filter_map(|(path, data)| match path {
+                    Path::Ack(ack_path) => Some(PacketState {
+                        port_id: ack_path.port_id,
+                        chan_id: ack_path.channel_id,
+                        seq: ack_path.sequence,
+                        data,
+                    }),
+                    _ => None,
+                })
+                .collect());
+        }
+
+        Ok(sequences
+            .filter_map(|seq| {
+                let data =
+                    self.peek_raw(&AckPath::new(&channel_end_path.0, &channel_end_path.1, seq))?;
+                Some(PacketState {
+                    port_id: channel_end_path.0.clone(),
+                    chan_id: channel_end_path.1.clone(),
+                    seq,
+                    data,
+                })
+            })
+            .collect())
+    }
+
+    fn paginated_packet_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        page_request: &PageRequest,
+    ) -> Result<Page<PacketState>, ContextError> {
+        let (acknowledgements, page_response) = self.paginate_under(
+            &channel_end_path.acks_path(),
+            page_request,
+            |path| match path {
+                Path::Ack(ack_path) => Some(ack_path),
+                _ => None,
+            },
+        );
+
+        let acknowledgements = acknowledgements
+            .into_iter()
+            .map(|(ack_path, data)| PacketState {
+                port_id: ack_path.port_id,
+                chan_id: ack_path.channel_id,
+                seq: ack_path.sequence,
+                data,
+            })
+            .collect();
+
+        Ok((acknowledgements, page_response))
+    }
+
+    fn unreceived_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl ExactSizeIterator<Item = Sequence>,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        Ok(sequences
+            .filter(|seq| {
+                self.peek_raw(&ReceiptPath::new(
+                    &channel_end_path.0,
+                    &channel_end_path.1,
+                    *seq,
+                ))
+                .is_none()
+            })
+            .collect())
+    }
+
+    fn unreceived_acks(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl ExactSizeIterator<Item = Sequence>,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        if sequences.len() == 0 {
+            // if sequences is empty, return all the packets still committed to
+            return Ok(self
+                .packet_commitments(channel_end_path)?
+                .into_iter()
+                .map(|packet_state| packet_state.seq)
+                .collect());
+        }
+
+        Ok(sequences
+            .filter(|seq| {
+                self.peek_raw(&CommitmentPath::new(
+                    &channel_end_path.0,
+                    &channel_end_path.1,
+                    *seq,
+                ))
+                .is_some()
+            })
+            .collect())
+    }
+}
+
+/// Decodes a client or consensus state stored as an encoded `Any`.
+fn decode_client_value<T>(value: &[u8]) -> Result<T, ContextError>
+where
+    T: TryFrom<Any>,
+    <T as TryFrom<Any>>::Error: Display,
+{
+    Ok(decode_any(value).map_err(|description| ClientError::Other { description })?)
+}
+
+/// Decodes a connection end stored at the path of the given identifier.
+fn decode_connection_end(
+    connection_id: ConnectionId,
+    value: &[u8],
+) -> Result<IdentifiedConnectionEnd, ContextError> {
+    let connection_end = ConnectionEnd::decode_vec(value).map_err(|e| ConnectionError::Other {
+        description: e.to_string(),
+    })?;
+
+    Ok(IdentifiedConnectionEnd {
+        connection_id,
+        connection_end,
+    })
+}
+
+/// Decodes a channel end stored at the given path.
+fn decode_channel_end(
+    channel_end_path: ChannelEndPath,
+    value: &[u8],
+) -> Result<IdentifiedChannelEnd, ContextError> {
+    let channel_end = ChannelEnd::decode_vec(value).map_err(|e| ChannelError::Other {
+        description: e.to_string(),
+    })?;
+
+    Ok(IdentifiedChannelEnd {
+        port_id: channel_end_path.0,
+        channel_id: channel_end_path.1,
+        channel_end,
+    })
+}
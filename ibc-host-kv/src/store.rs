@@ -0,0 +1,58 @@
+use core::fmt::Display;
+
+use ibc::core::client::types::Height;
+use ibc::core::primitives::prelude::*;
+
+/// The key-value store in which a host keeps its IBC state.
+///
+/// Keys are the ICS-24 paths of the IBC state, e.g.
+/// `connections/connection-0`, without any prefix: the store is expected to
+/// be dedicated to IBC, and its commitment to be proven under the prefix
+/// returned by [`KvHost::commitment_prefix`](crate::KvHost::commitment_prefix).
+///
+/// Reads are expected to see the writes of the block being executed, while
+/// proofs are only ever requested at committed heights.
+pub trait ProvableKvStore {
+    type Error: Display;
+
+    /// Returns the value stored at the given key, if any.
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>>;
+
+    /// Stores the given value at the given key, overwriting any previous one.
+    fn set(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<(), Self::Error>;
+
+    /// Deletes the value stored at the given key, if any.
+    fn delete(&mut self, key: &[u8]) -> Result<(), Self::Error>;
+
+    /// Iterates over all the key-value pairs whose key starts with the given
+    /// prefix, in the ascending lexicographic order of their keys.
+    fn prefix_iter<'a>(
+        &'a self,
+        prefix: &[u8],
+    ) -> Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'a>;
+
+    /// Iterates over all the key-value pairs whose key starts with the given
+    /// prefix, in the descending lexicographic order of their keys.
+    ///
+    /// Defaults to collecting the pairs returned by
+    /// [`prefix_iter`](Self::prefix_iter) in order to reverse them. Stores
+    /// that can iterate backwards should override it, so that the queries of
+    /// the last pages don't read the whole prefix.
+    fn prefix_iter_rev<'a>(
+        &'a self,
+        prefix: &[u8],
+    ) -> Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'a> {
+        let entries: Vec<_> = self.prefix_iter(prefix).collect();
+
+        Box::new(entries.into_iter().rev())
+    }
+
+    /// Returns the encoded commitment proof of the value stored at the given
+    /// key, or of its absence, at the given committed height.
+    ///
+    /// The proof is returned as is by the gRPC query services, and must
+    /// therefore be verifiable by the counterparty: for Cosmos SDK chains,
+    /// this is an encoded `MerkleProof` that chains the proof of the key in
+    /// the IBC store with the proof of the IBC store in the application state.
+    fn prove(&self, height: Height, key: &[u8]) -> Option<Vec<u8>>;
+}
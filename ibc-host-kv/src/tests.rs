@@ -0,0 +1,536 @@
+use core::cell::RefCell;
+use core::ops::Bound;
+use core::time::Duration;
+
+use ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc::clients::tendermint::types::ConsensusState as ConsensusStateType;
+use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::client::context::{ClientExecutionContext, ExtClientValidationContext};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentRoot};
+use ibc::core::connection::types::version::Version;
+use ibc::core::connection::types::{ConnectionEnd, Counterparty, State};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::metering::CostEvent;
+use ibc::core::handler::types::simulation::StateChange;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, CommitmentPath, ConnectionPath,
+    ReceiptPath, SeqSendPath,
+};
+use ibc::core::host::{CachedExecutionContext, ExecutionContext, ValidationContext};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc_query::core::context::QueryContext;
+use ibc_query::types::PageRequest;
+
+use super::*;
+
+#[derive(Default)]
+struct InMemoryStore(BTreeMap<Vec<u8>, Vec<u8>>);
+
+impl ProvableKvStore for InMemoryStore {
+    type Error = String;
+
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.0.get(key).cloned()
+    }
+
+    fn set(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<(), Self::Error> {
+        self.0.insert(key, value);
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &[u8]) -> Result<(), Self::Error> {
+        self.0.remove(key);
+        Ok(())
+    }
+
+    fn prefix_iter<'a>(
+        &'a self,
+        prefix: &[u8],
+    ) -> Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'a> {
+        let prefix = prefix.to_vec();
+        Box::new(
+            self.0
+                .range::<Vec<u8>, _>((Bound::Included(&prefix), Bound::Unbounded))
+                .take_while(move |(key, _)| key.starts_with(&prefix))
+                .map(|(key, value)| (key.clone(), value.clone())),
+        )
+    }
+
+    fn prove(&self, _height: Height, _key: &[u8]) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+/// A host recording the cost events it is charged for.
+#[derive(Default)]
+struct TestHost {
+    cost_events: RefCell<Vec<CostEvent>>,
+}
+
+impl KvHost for TestHost {
+    type Store = InMemoryStore;
+    type ClientState = TmClientState;
+    type ConsensusState = TmConsensusState;
+    type HostClientState = TmClientState;
+    type HostConsensusState = TmConsensusState;
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        Ok(Height::new(0, 10)?)
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        Ok(Timestamp::none())
+    }
+
+    fn host_consensus_state(
+        &self,
+        _height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        Ok(dummy_consensus_state(10))
+    }
+
+    fn validate_self_client(
+        &self,
+        _client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        CommitmentPrefix::try_from(b"ibc".to_vec()).expect("non-empty prefix")
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        Duration::from_secs(5)
+    }
+
+    fn charge(&self, event: CostEvent) -> Result<(), ContextError> {
+        self.cost_events.borrow_mut().push(event);
+        Ok(())
+    }
+}
+
+fn dummy_consensus_state(secs: i64) -> TmConsensusState {
+    ConsensusStateType::new(
+        CommitmentRoot::from_bytes(b"root"),
+        tendermint::Time::from_unix_timestamp(secs, 0).expect("valid time"),
+        tendermint::Hash::None,
+    )
+    .into()
+}
+
+fn context() -> KvContext<TestHost> {
+    KvContext::new(TestHost::default(), InMemoryStore::default())
+}
+
+#[test]
+fn test_connection_storage() {
+    let mut ctx = context();
+    let client_id = ClientId::new("07-tendermint", 0).expect("valid client id");
+    let conn_id = ConnectionId::new(0);
+    let conn_end = ConnectionEnd::new(
+        State::Init,
+        client_id.clone(),
+        Counterparty::new(client_id.clone(), None, ctx.commitment_prefix()),
+        Version::compatibles(),
+        Duration::ZERO,
+    )
+    .expect("valid connection end");
+
+    assert_eq!(ctx.connection_counter().expect("no error"), 0);
+
+    ctx.store_connection(&ConnectionPath::new(&conn_id), conn_end.clone())
+        .expect("no error");
+    ctx.store_connection_to_client(
+        &ClientConnectionPath::new(client_id.clone()),
+        conn_id.clone(),
+    )
+    .expect("no error");
+    ctx.increase_connection_counter().expect("no error");
+
+    assert!(ctx.store().get(b"connections/connection-0").is_some());
+    assert_eq!(ctx.connection_end(&conn_id).expect("no error"), conn_end);
+    assert_eq!(ctx.connection_counter().expect("no error"), 1);
+    assert_eq!(
+        ctx.client_connection_ends(&client_id).expect("no error"),
+        vec![conn_id.clone()]
+    );
+
+    let connection_ends = ctx.connection_ends().expect("no error");
+    assert_eq!(connection_ends.len(), 1);
+    assert_eq!(connection_ends[0].connection_id, conn_id);
+}
+
+#[test]
+fn test_packet_storage() {
+    let mut ctx = context();
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::new(0);
+    let seq = Sequence::from(1);
+
+    let commitment_path = CommitmentPath::new(&port_id, &chan_id, seq);
+    let receipt_path = ReceiptPath::new(&port_id, &chan_id, seq);
+    let seq_send_path = SeqSendPath::new(&port_id, &chan_id);
+    let channel_end_path = ChannelEndPath::new(&port_id, &chan_id);
+
+    assert!(ctx.get_next_sequence_send(&seq_send_path).is_err());
+    assert!(!ctx
+        .has_inflight_packets(&channel_end_path)
+        .expect("no error"));
+
+    ctx.store_next_sequence_send(&seq_send_path, Sequence::from(2))
+        .expect("no error");
+    ctx.store_packet_commitment(&commitment_path, PacketCommitment::from(vec![1, 2, 3]))
+        .expect("no error");
+    ctx.store_packet_receipt(&receipt_path, Receipt::Ok)
+        .expect("no error");
+
+    assert_eq!(
+        ctx.get_next_sequence_send(&seq_send_path)
+            .expect("no error"),
+        Sequence::from(2)
+    );
+    assert_eq!(
+        ctx.get_packet_commitment(&commitment_path)
+            .expect("no error")
+            .into_vec(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        ctx.get_packet_receipt(&receipt_path).expect("no error"),
+        Receipt::Ok
+    );
+    assert!(ctx
+        .has_inflight_packets(&channel_end_path)
+        .expect("no error"));
+
+    let commitments = ctx.packet_commitments(&channel_end_path).expect("no error");
+    assert_eq!(commitments.len(), 1);
+    assert_eq!(commitments[0].seq, seq);
+
+    let unreceived = ctx
+        .unreceived_packets(&channel_end_path, [1, 2].into_iter().map(Sequence::from))
+        .expect("no error");
+    assert_eq!(unreceived, vec![Sequence::from(2)]);
+
+    ctx.delete_packet_commitment(&commitment_path)
+        .expect("no error");
+    assert!(!ctx
+        .has_inflight_packets(&channel_end_path)
+        .expect("no error"));
+}
+
+/// Queries the packet commitments of the given channel end two by two,
+/// resuming each query at the next key returned by the previous one.
+fn paginate_commitments(
+    ctx: &KvContext<TestHost>,
+    channel_end_path: &ChannelEndPath,
+    reverse: bool,
+) -> Vec<Vec<u64>> {
+    let mut pages = Vec::new();
+    let mut request = PageRequest {
+        limit: 2,
+        reverse,
+        ..Default::default()
+    };
+
+    loop {
+        let (page, page_response) = ctx
+            .paginated_packet_commitments(channel_end_path, &request)
+            .expect("no error");
+        pages.push(page.into_iter().map(|state| state.seq.value()).collect());
+
+        if page_response.next_key.is_empty() {
+            return pages;
+        }
+
+        request.key = page_response.next_key;
+    }
+}
+
+#[test]
+fn test_paginated_packet_commitments() {
+    let mut ctx = context();
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::new(0);
+    let channel_end_path = ChannelEndPath::new(&port_id, &chan_id);
+
+    for seq in 1..=5 {
+        ctx.store_packet_commitment(
+            &CommitmentPath::new(&port_id, &chan_id, Sequence::from(seq)),
+            PacketCommitment::from(vec![seq as u8]),
+        )
+        .expect("no error");
+    }
+
+    // A commitment on another channel is left out.
+    ctx.store_packet_commitment(
+        &CommitmentPath::new(&port_id, &ChannelId::new(1), Sequence::from(1)),
+        PacketCommitment::from(vec![0]),
+    )
+    .expect("no error");
+
+    assert_eq!(
+        paginate_commitments(&ctx, &channel_end_path, false),
+        vec![vec![1, 2], vec![3, 4], vec![5]]
+    );
+    assert_eq!(
+        paginate_commitments(&ctx, &channel_end_path, true),
+        vec![vec![5, 4], vec![3, 2], vec![1]]
+    );
+
+    let request = PageRequest {
+        offset: 1,
+        limit: 2,
+        count_total: true,
+        ..Default::default()
+    };
+    let (page, page_response) = ctx
+        .paginated_packet_commitments(&channel_end_path, &request)
+        .expect("no error");
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].seq, Sequence::from(2));
+    assert_eq!(page[0].data, vec![2]);
+    assert_eq!(page_response.total, 5);
+}
+
+#[test]
+fn test_cached_writes() {
+    let mut ctx = context();
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::new(0);
+    let channel_end_path = ChannelEndPath::new(&port_id, &chan_id);
+    let seq_send_path = SeqSendPath::new(&port_id, &chan_id);
+    let first_path = CommitmentPath::new(&port_id, &chan_id, Sequence::from(1));
+    let second_path = CommitmentPath::new(&port_id, &chan_id, Sequence::from(2));
+
+    ctx.store_packet_commitment(&first_path, PacketCommitment::from(vec![1]))
+        .expect("no error");
+
+    ctx.begin_cache();
+    ctx.store_packet_commitment(&second_path, PacketCommitment::from(vec![2]))
+        .expect("no error");
+    ctx.delete_packet_commitment(&first_path).expect("no error");
+    ctx.log_message("cached".to_string()).expect("no error");
+
+    // The cached writes are visible through the context only.
+    let commitments = ctx.packet_commitments(&channel_end_path).expect("no error");
+    assert_eq!(commitments.len(), 1);
+    assert_eq!(commitments[0].seq, Sequence::from(2));
+    assert!(ctx
+        .store()
+        .get(second_path.to_string().as_bytes())
+        .is_none());
+
+    // Discarding a nested cache leaves the enclosing one untouched.
+    ctx.begin_cache();
+    ctx.store_next_sequence_send(&seq_send_path, Sequence::from(5))
+        .expect("no error");
+    ctx.log_message("discarded".to_string()).expect("no error");
+    ctx.discard_cache();
+
+    assert!(ctx.get_next_sequence_send(&seq_send_path).is_err());
+    assert!(ctx.get_packet_commitment(&second_path).is_ok());
+
+    ctx.commit_cache().expect("no error");
+
+    assert!(ctx.store().get(first_path.to_string().as_bytes()).is_none());
+    assert!(ctx
+        .store()
+        .get(second_path.to_string().as_bytes())
+        .is_some());
+    assert_eq!(ctx.take_logs(), vec!["cached".to_string()]);
+}
+
+#[test]
+fn test_cached_pagination() {
+    let mut ctx = context();
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::new(0);
+    let channel_end_path = ChannelEndPath::new(&port_id, &chan_id);
+    let commitment_path = |seq: u64| CommitmentPath::new(&port_id, &chan_id, seq.into());
+
+    for seq in 1..=3 {
+        ctx.store_packet_commitment(&commitment_path(seq), PacketCommitment::from(vec![1]))
+            .expect("no error");
+    }
+
+    // The cached writes and deletions are merged with the stored entries.
+    ctx.begin_cache();
+    ctx.store_packet_commitment(&commitment_path(1), PacketCommitment::from(vec![2]))
+        .expect("no error");
+    ctx.delete_packet_commitment(&commitment_path(2))
+        .expect("no error");
+    ctx.store_packet_commitment(&commitment_path(4), PacketCommitment::from(vec![2]))
+        .expect("no error");
+
+    assert_eq!(
+        paginate_commitments(&ctx, &channel_end_path, false),
+        vec![vec![1, 3], vec![4]]
+    );
+    assert_eq!(
+        paginate_commitments(&ctx, &channel_end_path, true),
+        vec![vec![4, 3], vec![1]]
+    );
+
+    let data: Vec<_> = ctx
+        .packet_commitments(&channel_end_path)
+        .expect("no error")
+        .into_iter()
+        .map(|state| state.data)
+        .collect();
+    assert_eq!(data, vec![vec![2], vec![1], vec![2]]);
+}
+
+#[test]
+fn test_cached_changes() {
+    let mut ctx = context();
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::new(0);
+    let first_path = CommitmentPath::new(&port_id, &chan_id, Sequence::from(1));
+    let second_path = CommitmentPath::new(&port_id, &chan_id, Sequence::from(2));
+
+    ctx.store_packet_commitment(&first_path, PacketCommitment::from(vec![1]))
+        .expect("no error");
+    ctx.log_message("committed".to_string()).expect("no error");
+
+    ctx.begin_cache();
+    ctx.delete_packet_commitment(&first_path).expect("no error");
+    ctx.store_packet_commitment(&second_path, PacketCommitment::from(vec![2]))
+        .expect("no error");
+    ctx.log_message("cached".to_string()).expect("no error");
+
+    assert_eq!(
+        ctx.cached_changes(),
+        vec![
+            StateChange::Delete {
+                path: first_path.clone().into(),
+            },
+            StateChange::Write {
+                path: second_path.clone().into(),
+                value: vec![2],
+            },
+        ]
+    );
+    assert!(ctx.cached_events().is_empty());
+    assert_eq!(ctx.cached_logs(), vec!["cached".to_string()]);
+
+    ctx.discard_cache();
+
+    assert!(ctx.get_packet_commitment(&first_path).is_ok());
+    assert!(ctx.get_packet_commitment(&second_path).is_err());
+    assert_eq!(ctx.take_logs(), vec!["committed".to_string()]);
+}
+
+#[test]
+fn test_consensus_state_heights_are_sorted() {
+    let mut ctx = context();
+    let client_id = ClientId::new("07-tendermint", 0).expect("valid client id");
+
+    // Stored in this order, the keys of heights 9 and 10 do not sort
+    // numerically.
+    for revision_height in [10, 2, 9] {
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(client_id.clone(), 0, revision_height),
+            dummy_consensus_state(revision_height as i64),
+        )
+        .expect("no error");
+    }
+
+    let heights =
+        ExtClientValidationContext::consensus_state_heights(&ctx, &client_id).expect("no error");
+    assert_eq!(
+        heights,
+        [2, 9, 10]
+            .into_iter()
+            .map(|h| Height::new(0, h).expect("valid height"))
+            .collect::<Vec<_>>()
+    );
+
+    let height = Height::new(0, 9).expect("valid height");
+    let next = ctx
+        .next_consensus_state(&client_id, &height)
+        .expect("no error")
+        .expect("next consensus state exists");
+    assert_eq!(next, dummy_consensus_state(10));
+    let prev = ctx
+        .prev_consensus_state(&client_id, &height)
+        .expect("no error")
+        .expect("prev consensus state exists");
+    assert_eq!(prev, dummy_consensus_state(2));
+
+    let request = PageRequest {
+        limit: 2,
+        ..Default::default()
+    };
+    let (page, page_response) = ctx
+        .paginated_consensus_state_heights(&client_id, &request)
+        .expect("no error");
+    assert_eq!(page, heights[..2]);
+
+    let request = PageRequest {
+        key: page_response.next_key,
+        limit: 2,
+        ..Default::default()
+    };
+    let (page, page_response) = ctx
+        .paginated_consensus_state_heights(&client_id, &request)
+        .expect("no error");
+    assert_eq!(page, heights[2..]);
+    assert!(page_response.next_key.is_empty());
+
+    ctx.delete_consensus_state(ClientConsensusStatePath::new(client_id.clone(), 0, 9))
+        .expect("no error");
+
+    let heights: Vec<_> = ctx
+        .ascending_consensus_state_heights(&client_id)
+        .expect("no error")
+        .map(|height| height.revision_height())
+        .collect();
+    assert_eq!(heights, vec![2, 10]);
+}
+
+#[test]
+fn test_store_accesses_are_charged() {
+    let mut ctx = context();
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::new(0);
+    let commitment_path = CommitmentPath::new(&port_id, &chan_id, Sequence::from(1));
+    let receipt_path = ReceiptPath::new(&port_id, &chan_id, Sequence::from(1));
+
+    ctx.store_packet_commitment(&commitment_path, PacketCommitment::from(vec![0; 32]))
+        .expect("no error");
+    ctx.get_packet_commitment(&commitment_path)
+        .expect("no error");
+    assert!(ctx.get_packet_receipt(&receipt_path).is_err());
+    ctx.delete_packet_commitment(&commitment_path)
+        .expect("no error");
+
+    // Values are charged as stored, and missing ones as empty.
+    assert_eq!(
+        ctx.host().cost_events.take(),
+        vec![
+            CostEvent::StoreWrite { bytes: 32 },
+            CostEvent::StoreRead { bytes: 32 },
+            CostEvent::StoreRead { bytes: 0 },
+            CostEvent::StoreWrite { bytes: 0 },
+        ]
+    );
+
+    // Queries peeking at the store are not charged.
+    ctx.unreceived_packets(
+        &ChannelEndPath::new(&port_id, &chan_id),
+        [Sequence::from(1)].into_iter(),
+    )
+    .expect("no error");
+    assert!(ctx.host().cost_events.take().is_empty());
+}
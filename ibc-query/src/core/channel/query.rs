@@ -6,7 +6,7 @@ use ibc::core::host::types::path::{
     ReceiptPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::{ConsensusStateRef, ValidationContext};
-use ibc::primitives::prelude::format;
+use ibc::primitives::prelude::{format, Vec};
 use ibc_proto::google::protobuf::Any;
 
 use super::{
@@ -23,7 +23,7 @@ use super::{
     QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
 };
 use crate::core::client::IdentifiedClientState;
-use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::context::{channel_key, ProvableContext, QueryContext};
 use crate::error::QueryError;
 
 /// Queries for a specific IBC channel by the given channel and port ids and
@@ -58,17 +58,20 @@ where
 /// Queries for all existing IBC channels and returns the corresponding channel ends
 pub fn query_channels<I>(
     ibc_ctx: &I,
-    _request: &QueryChannelsRequest,
+    request: &QueryChannelsRequest,
 ) -> Result<QueryChannelsResponse, QueryError>
 where
     I: QueryContext,
 {
-    let channel_ends = ibc_ctx.channel_ends()?;
+    let page_request = request.pagination.clone().unwrap_or_default();
+    page_request.validate()?;
+
+    let (channel_ends, page_response) = ibc_ctx.paginated_channel_ends(&page_request)?;
 
     Ok(QueryChannelsResponse::new(
         channel_ends,
         ibc_ctx.host_height()?,
-        None,
+        Some(page_response),
     ))
 }
 
@@ -80,9 +83,11 @@ pub fn query_connection_channels<I>(
 where
     I: QueryContext,
 {
-    let all_channel_ends = ibc_ctx.channel_ends()?;
+    let page_request = request.pagination.clone().unwrap_or_default();
+    page_request.validate()?;
 
-    let connection_channel_ends = all_channel_ends
+    let mut connection_channel_ends: Vec<_> = ibc_ctx
+        .channel_ends()?
         .into_iter()
         .filter(|channel_end| {
             channel_end
@@ -91,13 +96,16 @@ where
                 .iter()
                 .any(|connection_hop| connection_hop == &request.connection_id)
         })
-        .map(Into::into)
         .collect();
+    connection_channel_ends.sort_by_cached_key(channel_key);
+
+    let (connection_channel_ends, page_response) =
+        page_request.paginate(connection_channel_ends.into_iter(), channel_key);
 
     Ok(QueryConnectionChannelsResponse::new(
         connection_channel_ends,
         ibc_ctx.host_height()?,
-        None,
+        Some(page_response),
     ))
 }
 
@@ -253,18 +261,18 @@ pub fn query_packet_commitments<I>(
 where
     I: QueryContext,
 {
+    let page_request = request.pagination.clone().unwrap_or_default();
+    page_request.validate()?;
+
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
-    let commitments = ibc_ctx
-        .packet_commitments(&channel_end_path)?
-        .into_iter()
-        .map(Into::into)
-        .collect();
+    let (commitments, page_response) =
+        ibc_ctx.paginated_packet_commitments(&channel_end_path, &page_request)?;
 
     Ok(QueryPacketCommitmentsResponse::new(
-        commitments,
+        commitments.into_iter().map(Into::into).collect(),
         ibc_ctx.host_height()?,
-        None,
+        Some(page_response),
     ))
 }
 
@@ -345,24 +353,35 @@ pub fn query_packet_acknowledgements<I>(
 where
     I: QueryContext,
 {
-    let commitment_sequences = request
-        .packet_commitment_sequences
-        .iter()
-        .copied()
-        .map(Into::into);
-
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
-    let acknowledgements = ibc_ctx
-        .packet_acknowledgements(&channel_end_path, commitment_sequences)?
-        .into_iter()
-        .map(Into::into)
-        .collect();
+    // As in ibc-go, the acknowledgements of the requested sequences are
+    // returned all at once, and only the full list is paginated.
+    let (acknowledgements, page_response) = if request.packet_commitment_sequences.is_empty() {
+        let page_request = request.pagination.clone().unwrap_or_default();
+        page_request.validate()?;
+
+        let (acknowledgements, page_response) =
+            ibc_ctx.paginated_packet_acknowledgements(&channel_end_path, &page_request)?;
+
+        (acknowledgements, Some(page_response))
+    } else {
+        let commitment_sequences = request
+            .packet_commitment_sequences
+            .iter()
+            .copied()
+            .map(Into::into);
+
+        let acknowledgements =
+            ibc_ctx.packet_acknowledgements(&channel_end_path, commitment_sequences)?;
+
+        (acknowledgements, None)
+    };
 
     Ok(QueryPacketAcknowledgementsResponse::new(
-        acknowledgements,
+        acknowledgements.into_iter().map(Into::into).collect(),
         ibc_ctx.host_height()?,
-        None,
+        page_response,
     ))
 }
 
@@ -33,8 +33,6 @@ use super::{
 use crate::core::context::QueryContext;
 use crate::utils::{IntoDomain, IntoResponse, TryIntoDomain};
 
-// TODO(rano): currently the services don't support pagination, so we return all the results.
-
 /// The generic `I` must be a type where writes from one thread are readable from another.
 /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
 pub struct ChannelQueryService<I>
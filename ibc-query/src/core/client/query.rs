@@ -64,20 +64,22 @@ where
 /// Queries for all the existing client states.
 pub fn query_client_states<I>(
     ibc_ctx: &I,
-    _request: &QueryClientStatesRequest,
+    request: &QueryClientStatesRequest,
 ) -> Result<QueryClientStatesResponse, QueryError>
 where
     I: QueryContext,
 {
-    let client_states = ibc_ctx.client_states()?;
+    let page_request = request.pagination.clone().unwrap_or_default();
+    page_request.validate()?;
+
+    let (client_states, page_response) = ibc_ctx.paginated_client_states(&page_request)?;
 
     Ok(QueryClientStatesResponse::new(
         client_states
             .into_iter()
             .map(|(id, state)| IdentifiedClientState::new(id, state.into()))
             .collect(),
-        // no support for pagination yet
-        None,
+        Some(page_response),
     ))
 }
 
@@ -150,15 +152,18 @@ where
     I: QueryContext,
     ConsensusStateRef<I>: Into<Any>,
 {
-    let consensus_states = ibc_ctx.consensus_states(&request.client_id)?;
+    let page_request = request.pagination.clone().unwrap_or_default();
+    page_request.validate()?;
+
+    let (consensus_states, page_response) =
+        ibc_ctx.paginated_consensus_states(&request.client_id, &page_request)?;
 
     Ok(QueryConsensusStatesResponse::new(
         consensus_states
             .into_iter()
             .map(|(height, state)| ConsensusStateWithHeight::new(height, state.into()))
             .collect(),
-        // no support for pagination yet,
-        None,
+        Some(page_response),
     ))
 }
 
@@ -170,12 +175,15 @@ pub fn query_consensus_state_heights<I>(
 where
     I: QueryContext,
 {
-    let consensus_state_heights = ibc_ctx.consensus_state_heights(&request.client_id)?;
+    let page_request = request.pagination.clone().unwrap_or_default();
+    page_request.validate()?;
+
+    let (consensus_state_heights, page_response) =
+        ibc_ctx.paginated_consensus_state_heights(&request.client_id, &page_request)?;
 
     Ok(QueryConsensusStateHeightsResponse::new(
         consensus_state_heights,
-        // no support for pagination yet
-        None,
+        Some(page_response),
     ))
 }
 
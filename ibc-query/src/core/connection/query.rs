@@ -57,17 +57,20 @@ where
 /// Queries for all the existing connection ends.
 pub fn query_connections<I>(
     ibc_ctx: &I,
-    _request: &QueryConnectionsRequest,
+    request: &QueryConnectionsRequest,
 ) -> Result<QueryConnectionsResponse, QueryError>
 where
     I: QueryContext,
 {
-    let connections = ibc_ctx.connection_ends()?;
+    let page_request = request.pagination.clone().unwrap_or_default();
+    page_request.validate()?;
+
+    let (connections, page_response) = ibc_ctx.paginated_connection_ends(&page_request)?;
 
     Ok(QueryConnectionsResponse::new(
         connections,
         ibc_ctx.host_height()?,
-        None,
+        Some(page_response),
     ))
 }
 
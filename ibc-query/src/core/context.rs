@@ -10,6 +10,8 @@ use ibc::core::host::types::path::{ChannelEndPath, Path};
 use ibc::core::host::{ClientStateRef, ConsensusStateRef, ValidationContext};
 use ibc::core::primitives::prelude::*;
 
+use crate::types::{Page, PageRequest};
+
 /// Context to be implemented by the host to provide proofs in query responses
 pub trait ProvableContext {
     /// Returns the proof for the given path at the given height.
@@ -18,6 +20,14 @@ pub trait ProvableContext {
 }
 
 /// Context to be implemented by the host that provides gRPC query services.
+///
+/// The `paginated_*` methods serve the paginated queries. Their default
+/// implementations are mere fallbacks, which load the whole collection
+/// through the unpaginated methods before returning the requested page:
+/// hosts should override them to only read the requested page from their
+/// store. The pagination keys are opaque to clients, which only pass them
+/// back to resume after a page, so the overrides may key the items as their
+/// store orders them.
 pub trait QueryContext: ProvableContext + ValidationContext {
     // Client queries
 
@@ -33,11 +43,85 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     /// Returns the list of all heights at which consensus states for the given client are.
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
 
+    /// Returns the requested page of all clients.
+    ///
+    /// The default implementation loads every client through
+    /// [`Self::client_states`], and keys them by their identifiers.
+    fn paginated_client_states(
+        &self,
+        page_request: &PageRequest,
+    ) -> Result<Page<(ClientId, ClientStateRef<Self>)>, ContextError> {
+        let mut client_states = self.client_states()?;
+        client_states.sort_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+
+        Ok(
+            page_request.paginate(client_states.into_iter(), |(client_id, _)| {
+                client_id.as_bytes().to_vec()
+            }),
+        )
+    }
+
+    /// Returns the requested page of all consensus states for the given
+    /// client.
+    ///
+    /// The default implementation loads every consensus state through
+    /// [`Self::consensus_states`], and keys them by their heights as
+    /// big-endian revision numbers and heights.
+    fn paginated_consensus_states(
+        &self,
+        client_id: &ClientId,
+        page_request: &PageRequest,
+    ) -> Result<Page<(Height, ConsensusStateRef<Self>)>, ContextError> {
+        let mut consensus_states = self.consensus_states(client_id)?;
+        consensus_states.sort_by_key(|(height, _)| *height);
+
+        Ok(
+            page_request.paginate(consensus_states.into_iter(), |(height, _)| {
+                height_key(height)
+            }),
+        )
+    }
+
+    /// Returns the requested page of all heights at which consensus states
+    /// for the given client are.
+    ///
+    /// The default implementation loads every height through
+    /// [`Self::consensus_state_heights`], and keys them as
+    /// [`Self::paginated_consensus_states`] does.
+    fn paginated_consensus_state_heights(
+        &self,
+        client_id: &ClientId,
+        page_request: &PageRequest,
+    ) -> Result<Page<Height>, ContextError> {
+        let mut heights = self.consensus_state_heights(client_id)?;
+        heights.sort();
+
+        Ok(page_request.paginate(heights.into_iter(), height_key))
+    }
+
     // Connection queries
 
     /// Returns the list of all connection ends.
     fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError>;
 
+    /// Returns the requested page of all connection ends.
+    ///
+    /// The default implementation loads every connection end through
+    /// [`Self::connection_ends`], and keys them by their identifiers.
+    fn paginated_connection_ends(
+        &self,
+        page_request: &PageRequest,
+    ) -> Result<Page<IdentifiedConnectionEnd>, ContextError> {
+        let mut connection_ends = self.connection_ends()?;
+        connection_ends.sort_by(|a, b| a.connection_id.as_bytes().cmp(b.connection_id.as_bytes()));
+
+        Ok(
+            page_request.paginate(connection_ends.into_iter(), |connection_end| {
+                connection_end.connection_id.as_bytes().to_vec()
+            }),
+        )
+    }
+
     /// Returns the list of all connection ids of the given client.
     fn client_connection_ends(
         &self,
@@ -49,6 +133,21 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     /// Returns the list of all channel ends.
     fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError>;
 
+    /// Returns the requested page of all channel ends.
+    ///
+    /// The default implementation loads every channel end through
+    /// [`Self::channel_ends`], and keys them by their
+    /// `{port_id}/{channel_id}` paths.
+    fn paginated_channel_ends(
+        &self,
+        page_request: &PageRequest,
+    ) -> Result<Page<IdentifiedChannelEnd>, ContextError> {
+        let mut channel_ends = self.channel_ends()?;
+        channel_ends.sort_by_cached_key(channel_key);
+
+        Ok(page_request.paginate(channel_ends.into_iter(), channel_key))
+    }
+
     // Packet queries
 
     /// Returns the list of all packet commitments for the given channel end.
@@ -57,6 +156,27 @@ pub trait QueryContext: ProvableContext + ValidationContext {
         channel_end_path: &ChannelEndPath,
     ) -> Result<Vec<PacketState>, ContextError>;
 
+    /// Returns the requested page of all packet commitments for the given
+    /// channel end.
+    ///
+    /// The default implementation loads every commitment through
+    /// [`Self::packet_commitments`], and keys them by their big-endian
+    /// sequences.
+    fn paginated_packet_commitments(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        page_request: &PageRequest,
+    ) -> Result<Page<PacketState>, ContextError> {
+        let mut commitments = self.packet_commitments(channel_end_path)?;
+        commitments.sort_by_key(|commitment| commitment.seq);
+
+        Ok(
+            page_request.paginate(commitments.into_iter(), |commitment| {
+                commitment.seq.to_vec()
+            }),
+        )
+    }
+
     /// Filters the list of packet sequences for the given channel end that are acknowledged.
     /// Returns all the packet acknowledgements if `sequences` is empty.
     fn packet_acknowledgements(
@@ -65,6 +185,28 @@ pub trait QueryContext: ProvableContext + ValidationContext {
         sequences: impl ExactSizeIterator<Item = Sequence>,
     ) -> Result<Vec<PacketState>, ContextError>;
 
+    /// Returns the requested page of all packet acknowledgements for the given
+    /// channel end.
+    ///
+    /// The default implementation loads every acknowledgement through
+    /// [`Self::packet_acknowledgements`], and keys them by their big-endian
+    /// sequences.
+    fn paginated_packet_acknowledgements(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        page_request: &PageRequest,
+    ) -> Result<Page<PacketState>, ContextError> {
+        let mut acknowledgements =
+            self.packet_acknowledgements(channel_end_path, core::iter::empty())?;
+        acknowledgements.sort_by_key(|acknowledgement| acknowledgement.seq);
+
+        Ok(
+            page_request.paginate(acknowledgements.into_iter(), |acknowledgement| {
+                acknowledgement.seq.to_vec()
+            }),
+        )
+    }
+
     /// Filters the packet sequences for the given channel end that are not received.
     fn unreceived_packets(
         &self,
@@ -80,3 +222,17 @@ pub trait QueryContext: ProvableContext + ValidationContext {
         sequences: impl ExactSizeIterator<Item = Sequence>,
     ) -> Result<Vec<Sequence>, ContextError>;
 }
+
+/// Returns the pagination key of a height, which sorts as the height does.
+fn height_key(height: &Height) -> Vec<u8> {
+    [
+        height.revision_number().to_be_bytes(),
+        height.revision_height().to_be_bytes(),
+    ]
+    .concat()
+}
+
+/// Returns the pagination key of a channel end.
+pub(crate) fn channel_key(channel_end: &IdentifiedChannelEnd) -> Vec<u8> {
+    format!("{}/{}", channel_end.port_id, channel_end.channel_id).into_bytes()
+}
@@ -18,6 +18,8 @@ pub enum QueryError {
     ProofNotFound(String),
     /// Missing field: {0}
     MissingField(String),
+    /// Invalid pagination: {0}
+    InvalidPagination(String),
 }
 
 impl QueryError {
@@ -28,6 +30,10 @@ impl QueryError {
     pub fn missing_field<T: ToString>(description: T) -> Self {
         Self::MissingField(description.to_string())
     }
+
+    pub fn invalid_pagination<T: ToString>(description: T) -> Self {
+        Self::InvalidPagination(description.to_string())
+    }
 }
 
 impl From<QueryError> for Status {
@@ -37,6 +43,7 @@ impl From<QueryError> for Status {
             QueryError::IdentifierError(id_err) => Self::internal(id_err.to_string()),
             QueryError::ProofNotFound(description) => Self::not_found(description),
             QueryError::MissingField(description) => Self::invalid_argument(description),
+            QueryError::InvalidPagination(description) => Self::invalid_argument(description),
         }
     }
 }
@@ -3,6 +3,8 @@ use ibc_proto::cosmos::base::query::v1beta1::{
     PageRequest as RawPageRequest, PageResponse as RawPageResponse,
 };
 
+use crate::error::QueryError;
+
 pub type Proof = Vec<u8>;
 
 #[derive(Clone, Debug, Default)]
@@ -30,12 +32,122 @@ pub struct PageRequest {
 }
 
 impl PageRequest {
+    /// Number of results returned in a page when the request sets no limit,
+    /// as in the Cosmos SDK.
+    pub const DEFAULT_LIMIT: u64 = 100;
+
     pub fn all() -> Self {
         Self {
             limit: u64::MAX,
             ..Default::default()
         }
     }
+
+    /// Checks that the request doesn't set both a key and an offset.
+    pub fn validate(&self) -> Result<(), QueryError> {
+        if !self.key.is_empty() && self.offset > 0 {
+            return Err(QueryError::invalid_pagination(
+                "either offset or key is expected, got both",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the page of the given items that this request asks for, along
+    /// with the [`PageResponse`] to return to the client.
+    ///
+    /// The items must be sorted in ascending order of their keys, as returned
+    /// by `key_of`, which are compared byte by byte. Only the items up to the
+    /// end of the page, and one more to find the next key, are consumed,
+    /// unless the total number of items is to be counted.
+    pub fn paginate<T, I, F>(&self, items: I, key_of: F) -> (Vec<T>, PageResponse)
+    where
+        I: DoubleEndedIterator<Item = T>,
+        F: Fn(&T) -> Vec<u8>,
+    {
+        if self.reverse {
+            self.paginate_ordered(items.rev(), key_of)
+        } else {
+            self.paginate_ordered(items, key_of)
+        }
+    }
+
+    /// Returns the page of the given items that this request asks for, as
+    /// [`Self::paginate`] does, from items that are already in the order the
+    /// request asks for: in descending order of their keys if `reverse` is
+    /// set, and in ascending order otherwise.
+    ///
+    /// This lets hosts paginate over the iterators of their store, which
+    /// usually can't be reversed.
+    pub fn paginate_ordered<T, I, F>(&self, items: I, key_of: F) -> (Vec<T>, PageResponse)
+    where
+        I: Iterator<Item = T>,
+        F: Fn(&T) -> Vec<u8>,
+    {
+        if self.reverse {
+            self.paginate_sorted(items, key_of, |key, start| key > start)
+        } else {
+            self.paginate_sorted(items, key_of, |key, start| key < start)
+        }
+    }
+
+    fn paginate_sorted<T>(
+        &self,
+        items: impl Iterator<Item = T>,
+        key_of: impl Fn(&T) -> Vec<u8>,
+        is_before: impl Fn(&[u8], &[u8]) -> bool,
+    ) -> (Vec<T>, PageResponse) {
+        // As in the Cosmos SDK, a request without a limit gets a default
+        // page, along with the total number of items.
+        let (limit, count_total) = match self.limit {
+            0 => (Self::DEFAULT_LIMIT, true),
+            limit => (limit, self.count_total),
+        };
+
+        let mut page = Vec::new();
+        let mut page_response = PageResponse::default();
+
+        if !self.key.is_empty() {
+            // The total is not counted when paginating by key.
+            let mut items =
+                items.skip_while(|item| is_before(key_of(item).as_slice(), self.key.as_slice()));
+
+            page.extend(
+                items
+                    .by_ref()
+                    .take(usize::try_from(limit).unwrap_or(usize::MAX)),
+            );
+
+            if let Some(next_item) = items.next() {
+                page_response.next_key = key_of(&next_item);
+            }
+
+            return (page, page_response);
+        }
+
+        let end = self.offset.saturating_add(limit);
+
+        for (index, item) in (0u64..).zip(items) {
+            if index >= end {
+                if index == end {
+                    page_response.next_key = key_of(&item);
+                }
+
+                if !count_total {
+                    break;
+                }
+            } else if index >= self.offset {
+                page.push(item);
+            }
+
+            if count_total {
+                page_response.total = index + 1;
+            }
+        }
+
+        (page, page_response)
+    }
 }
 
 impl From<PageRequest> for RawPageRequest {
@@ -75,6 +187,10 @@ pub struct PageResponse {
     pub total: u64,
 }
 
+/// A page of the results of a paginated query, along with the
+/// [`PageResponse`] to return to the client.
+pub type Page<T> = (Vec<T>, PageResponse);
+
 impl From<PageResponse> for RawPageResponse {
     fn from(response: PageResponse) -> Self {
         Self {
@@ -92,3 +208,141 @@ impl From<RawPageResponse> for PageResponse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(items: &[u64]) -> Vec<u8> {
+        items.iter().flat_map(|item| item.to_be_bytes()).collect()
+    }
+
+    fn paginate(request: &PageRequest) -> (Vec<u64>, PageResponse) {
+        request.paginate(0..10u64, |item| item.to_be_bytes().to_vec())
+    }
+
+    #[test]
+    fn paginate_by_offset() {
+        let request = PageRequest {
+            offset: 2,
+            limit: 3,
+            count_total: true,
+            ..Default::default()
+        };
+
+        let (page, page_response) = paginate(&request);
+
+        assert_eq!(page, vec![2, 3, 4]);
+        assert_eq!(page_response.next_key, keys(&[5]));
+        assert_eq!(page_response.total, 10);
+    }
+
+    #[test]
+    fn paginate_by_key() {
+        let request = PageRequest {
+            key: keys(&[5]),
+            limit: 3,
+            ..Default::default()
+        };
+
+        let (page, page_response) = paginate(&request);
+
+        assert_eq!(page, vec![5, 6, 7]);
+        assert_eq!(page_response.next_key, keys(&[8]));
+        assert_eq!(page_response.total, 0);
+    }
+
+    #[test]
+    fn paginate_in_reverse() {
+        let request = PageRequest {
+            key: keys(&[5]),
+            limit: 3,
+            reverse: true,
+            ..Default::default()
+        };
+
+        let (page, page_response) = paginate(&request);
+
+        assert_eq!(page, vec![5, 4, 3]);
+        assert_eq!(page_response.next_key, keys(&[2]));
+    }
+
+    #[test]
+    fn paginate_last_page() {
+        let request = PageRequest {
+            offset: 8,
+            limit: 3,
+            ..Default::default()
+        };
+
+        let (page, page_response) = paginate(&request);
+
+        assert_eq!(page, vec![8, 9]);
+        assert!(page_response.next_key.is_empty());
+        assert_eq!(page_response.total, 0);
+    }
+
+    #[test]
+    fn paginate_without_limit() {
+        let (page, page_response) = paginate(&PageRequest::default());
+
+        assert_eq!(page.len(), 10);
+        assert!(page_response.next_key.is_empty());
+        assert_eq!(page_response.total, 10);
+    }
+
+    #[test]
+    fn paginate_all() {
+        let (page, page_response) = paginate(&PageRequest::all());
+
+        assert_eq!(page.len(), 10);
+        assert!(page_response.next_key.is_empty());
+    }
+
+    /// Queries the pages of the given size one after the other, resuming each
+    /// query at the next key returned by the previous one.
+    fn paginate_by_next_key(limit: u64, reverse: bool) -> Vec<Vec<u64>> {
+        let mut pages = Vec::new();
+        let mut request = PageRequest {
+            limit,
+            reverse,
+            ..Default::default()
+        };
+
+        loop {
+            let (page, page_response) = paginate(&request);
+            pages.push(page);
+
+            if page_response.next_key.is_empty() {
+                return pages;
+            }
+
+            request.key = page_response.next_key;
+        }
+    }
+
+    #[test]
+    fn paginate_resuming_at_next_key() {
+        let pages = paginate_by_next_key(4, false);
+
+        assert_eq!(pages, vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9]]);
+    }
+
+    #[test]
+    fn paginate_in_reverse_resuming_at_next_key() {
+        let pages = paginate_by_next_key(4, true);
+
+        assert_eq!(pages, vec![vec![9, 8, 7, 6], vec![5, 4, 3, 2], vec![1, 0]]);
+    }
+
+    #[test]
+    fn validate_key_and_offset() {
+        let request = PageRequest {
+            key: keys(&[5]),
+            offset: 2,
+            ..Default::default()
+        };
+
+        assert!(request.validate().is_err());
+    }
+}
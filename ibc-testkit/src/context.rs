@@ -5,9 +5,11 @@ use basecoin_store::context::ProvableStore;
 use basecoin_store::impls::InMemoryStore;
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::upgrade::Upgrade;
 use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::client::context::{ClientExecutionContext, ClientValidationContext};
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{CounterpartyInfo, Height};
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::{dispatch, execute, validate};
 use ibc::core::handler::types::error::ContextError;
@@ -15,8 +17,9 @@ use ibc::core::handler::types::events::IbcEvent;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, ConnectionPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, ClientCounterpartyPath,
+    ClientStatePath, CommitmentPath, CommitmentV2Path, ConnectionPath, CounterpartyUpgradePath,
+    ReceiptPath, ReceiptV2Path, SeqAckPath, SeqRecvPath, SeqSendPath, SeqSendV2Path,
 };
 use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::primitives::prelude::*;
@@ -397,6 +400,38 @@ where
         self
     }
 
+    /// Bootstraps a channel upgrade to this context.
+    ///
+    /// This does not bootstrap any corresponding IBC channel, connection or light client.
+    pub fn with_channel_upgrade(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        upgrade: Upgrade,
+    ) -> Self {
+        let upgrade_path = ChannelUpgradePath::new(&port_id, &chan_id);
+        self.ibc_store
+            .store_channel_upgrade(&upgrade_path, upgrade)
+            .expect("error writing to store");
+        self
+    }
+
+    /// Bootstraps the upgrade of the counterparty of a channel to this context.
+    ///
+    /// This does not bootstrap any corresponding IBC channel, connection or light client.
+    pub fn with_counterparty_upgrade(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        upgrade: Upgrade,
+    ) -> Self {
+        let counterparty_upgrade_path = CounterpartyUpgradePath::new(&port_id, &chan_id);
+        self.ibc_store
+            .store_counterparty_upgrade(&counterparty_upgrade_path, upgrade)
+            .expect("error writing to store");
+        self
+    }
+
     /// Bootstraps a send sequence to this context.
     ///
     /// This does not bootstrap any corresponding IBC channel, connection or light client.
@@ -462,6 +497,71 @@ where
         self
     }
 
+    /// Bootstraps a packet receipt to this context.
+    ///
+    /// This does not bootstrap any corresponding IBC channel, connection or light client.
+    pub fn with_packet_receipt(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        seq: Sequence,
+    ) -> Self {
+        let receipt_path = ReceiptPath::new(&port_id, &chan_id, seq);
+        self.ibc_store
+            .store_packet_receipt(&receipt_path, Receipt::Ok)
+            .expect("error writing to store");
+        self
+    }
+
+    /// Bootstraps the counterparty of a client to this context, as registered
+    /// for IBC v2, along with the sequence of the first packet to be sent
+    /// through the client.
+    ///
+    /// This does not bootstrap the client itself.
+    pub fn with_client_counterparty(
+        mut self,
+        client_id: &ClientId,
+        counterparty: CounterpartyInfo,
+    ) -> Self {
+        self.ibc_store
+            .store_client_counterparty(
+                &ClientCounterpartyPath::new(client_id.clone()),
+                counterparty,
+            )
+            .expect("error writing to store");
+        self.ibc_store
+            .store_next_sequence_send_v2(&SeqSendV2Path::new(client_id), Sequence::from(1))
+            .expect("error writing to store");
+        self
+    }
+
+    /// Bootstraps an IBC v2 packet commitment to this context.
+    ///
+    /// This does not bootstrap any corresponding client or counterparty.
+    pub fn with_packet_commitment_v2(
+        mut self,
+        client_id: &ClientId,
+        seq: Sequence,
+        data: PacketCommitment,
+    ) -> Self {
+        let commitment_path = CommitmentV2Path::new(client_id, seq);
+        self.ibc_store
+            .store_packet_commitment_v2(&commitment_path, data)
+            .expect("error writing to store");
+        self
+    }
+
+    /// Bootstraps an IBC v2 packet receipt to this context.
+    ///
+    /// This does not bootstrap any corresponding client or counterparty.
+    pub fn with_packet_receipt_v2(mut self, client_id: &ClientId, seq: Sequence) -> Self {
+        let receipt_path = ReceiptV2Path::new(client_id, seq);
+        self.ibc_store
+            .store_packet_receipt_v2(&receipt_path, Receipt::Ok)
+            .expect("error writing to store");
+        self
+    }
+
     /// Calls [`validate`] function on [`MsgEnvelope`] using the context's IBC store and router.
     pub fn validate(&mut self, msg: MsgEnvelope) -> Result<(), ContextError> {
         validate(&self.ibc_store, &self.ibc_router, msg)
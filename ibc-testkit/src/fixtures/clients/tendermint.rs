@@ -5,7 +5,6 @@ use basecoin_store::avl::get_proof_spec as basecoin_proof_spec;
 use ibc::clients::tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::tendermint::types::error::{Error as ClientError, Error};
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
-#[cfg(feature = "serde")]
 use ibc::clients::tendermint::types::Header;
 use ibc::clients::tendermint::types::{
     AllowUpdate, ClientState as ClientStateType, TrustThreshold,
@@ -18,6 +17,8 @@ use ibc::core::primitives::prelude::*;
 use tendermint::block::Header as TmHeader;
 use typed_builder::TypedBuilder;
 
+use crate::hosts::{TendermintHost, TestBlock, TestHost};
+
 /// Returns a dummy tendermint `ClientState` by given `frozen_height`, for testing purposes only!
 pub fn dummy_tm_client_state_from_raw(frozen_height: RawHeight) -> Result<TmClientState, Error> {
     ClientStateType::try_from(dummy_raw_tm_client_state(frozen_height)).map(TmClientState::from)
@@ -66,6 +67,47 @@ pub fn dummy_raw_tm_client_state(frozen_height: RawHeight) -> RawTmClientState {
     }
 }
 
+/// Returns a header of a fork of the chain of the given host at the given
+/// height, i.e. with another app hash than the block of the host, trusting
+/// the block of the host at `trusted_height`, for testing equivocation.
+pub fn dummy_equivocating_header(
+    host: &TendermintHost,
+    height: Height,
+    trusted_height: Height,
+) -> Header {
+    let block = host.get_block(&height).expect("Never fails");
+    let trusted_block = host.get_block(&trusted_height).expect("Never fails");
+
+    host.generate_block(
+        b"equivocating_root".to_vec(),
+        height.revision_height(),
+        block.timestamp(),
+        &Default::default(),
+    )
+    .into_header_with_trusted(&trusted_block)
+    .into()
+}
+
+/// Returns a header of the chain of the given host at a height it has not
+/// reached yet, but with the time of its latest block, trusting the block of
+/// the host at `trusted_height`, for testing BFT time violations.
+pub fn dummy_bft_time_violating_header(
+    host: &TendermintHost,
+    height: Height,
+    trusted_height: Height,
+) -> Header {
+    let trusted_block = host.get_block(&trusted_height).expect("Never fails");
+
+    host.generate_block(
+        Vec::new(),
+        height.revision_height(),
+        host.latest_block().timestamp(),
+        &Default::default(),
+    )
+    .into_header_with_trusted(&trusted_block)
+    .into()
+}
+
 #[derive(TypedBuilder, Debug)]
 pub struct ClientStateConfig {
     #[builder(default = TrustThreshold::ONE_THIRD)]
@@ -0,0 +1,114 @@
+use ibc::core::channel::types::proto::v1::{
+    MsgChannelUpgradeInit as RawMsgChannelUpgradeInit, UpgradeFields as RawUpgradeFields,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use crate::fixtures::core::signer::dummy_bech32_account;
+
+/// Returns a dummy `RawUpgradeFields`, for testing purposes only!
+/// The proposed channel is unordered, over the connection `connection-0`.
+pub fn dummy_raw_upgrade_fields(version: &str) -> RawUpgradeFields {
+    RawUpgradeFields {
+        ordering: 1,
+        connection_hops: vec![ConnectionId::zero().to_string()],
+        version: version.to_string(),
+    }
+}
+
+/// Returns a dummy `RawMsgChannelUpgradeInit`, for testing purposes only!
+pub fn dummy_raw_msg_chan_upgrade_init(version: &str) -> RawMsgChannelUpgradeInit {
+    RawMsgChannelUpgradeInit {
+        port_id: PortId::transfer().to_string(),
+        channel_id: ChannelId::zero().to_string(),
+        fields: Some(dummy_raw_upgrade_fields(version)),
+        signer: dummy_bech32_account(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::msgs::MsgChannelUpgradeInit;
+
+    use super::*;
+
+    #[test]
+    fn parse_channel_upgrade_init_msg() {
+        struct Test {
+            name: String,
+            raw: RawMsgChannelUpgradeInit,
+            want_pass: bool,
+        }
+
+        let default_raw_msg = dummy_raw_msg_chan_upgrade_init("ics20-2");
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Bad port, name too short".to_string(),
+                raw: RawMsgChannelUpgradeInit {
+                    port_id: "p".to_string(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing upgrade fields".to_string(),
+                raw: RawMsgChannelUpgradeInit {
+                    fields: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Proposed ordering cannot be None".to_string(),
+                raw: RawMsgChannelUpgradeInit {
+                    fields: Some(RawUpgradeFields {
+                        ordering: 0,
+                        ..dummy_raw_upgrade_fields("ics20-2")
+                    }),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Proposed version cannot be empty".to_string(),
+                raw: dummy_raw_msg_chan_upgrade_init(""),
+                want_pass: false,
+            },
+            Test {
+                name: "Multiple connection hops are not supported".to_string(),
+                raw: RawMsgChannelUpgradeInit {
+                    fields: Some(RawUpgradeFields {
+                        connection_hops: vec![
+                            "connection-0".to_string(),
+                            "connection-1".to_string(),
+                        ],
+                        ..dummy_raw_upgrade_fields("ics20-2")
+                    }),
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res_msg = MsgChannelUpgradeInit::try_from(test.raw.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res_msg.is_ok(),
+                "MsgChannelUpgradeInit::try_from failed for test {}, \nraw msg {:?} with error {:?}",
+                test.name,
+                test.raw,
+                res_msg.err(),
+            );
+        }
+    }
+}
@@ -5,6 +5,7 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_init;
 mod packet;
 mod recv_packet;
 mod timeout;
@@ -23,6 +24,7 @@ pub use self::chan_open_ack::*;
 pub use self::chan_open_confirm::*;
 pub use self::chan_open_init::*;
 pub use self::chan_open_try::*;
+pub use self::chan_upgrade_init::*;
 pub use self::packet::*;
 pub use self::recv_packet::*;
 pub use self::timeout::*;
@@ -174,6 +176,11 @@ mod tests {
                 want_res: Order::Ordered,
                 want_err: false,
             },
+            Test {
+                ordering: "ORDER_ORDERED_ALLOW_TIMEOUT",
+                want_res: Order::OrderedAllowTimeout,
+                want_err: false,
+            },
             Test {
                 ordering: "UNKNOWN_ORDER",
                 want_res: Order::None,
@@ -2,14 +2,17 @@ use core::str::FromStr;
 
 use ibc::clients::tendermint::client_state::ClientState;
 use ibc::clients::tendermint::consensus_state::ConsensusState;
+use ibc::clients::tendermint::misbehaviour_detector::TrustedHeaderSource;
 use ibc::clients::tendermint::types::proto::v1::Header as RawHeader;
 use ibc::clients::tendermint::types::{Header, TENDERMINT_HEADER_TYPE_URL};
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::ChainId;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
+use tendermint::block::signed_header::SignedHeader;
 use tendermint::block::Header as TmHeader;
 use tendermint::validator::Set as ValidatorSet;
 use tendermint_testgen::light_block::TmLightBlock;
@@ -99,6 +102,26 @@ impl TestHost for TendermintHost {
     }
 }
 
+/// The history of the host serves as the trusted chain when detecting the
+/// misbehaviour of its counterparty light clients.
+impl TrustedHeaderSource for TendermintHost {
+    fn latest_height(&self) -> Result<Height, ClientError> {
+        Ok(TestHost::latest_height(self))
+    }
+
+    fn signed_header(&self, height: &Height) -> Result<Option<SignedHeader>, ClientError> {
+        Ok(self.get_block(height).map(|block| block.signed_header))
+    }
+
+    fn validator_set(&self, height: &Height) -> Result<ValidatorSet, ClientError> {
+        self.get_block(height)
+            .map(|block| block.validators)
+            .ok_or_else(|| ClientError::Other {
+                description: format!("no block at height {height}"),
+            })
+    }
+}
+
 impl TestBlock for TmLightBlock {
     type Header = TendermintHeader;
 
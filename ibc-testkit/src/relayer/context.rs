@@ -507,7 +507,7 @@ where
         // module creates the send_packet
         send_transfer(
             self.get_ctx_a_mut().ibc_store_mut(),
-            &mut DummyTransferModule,
+            &mut DummyTransferModule::new(),
             msg,
         )
         .expect("successfully created send_packet");
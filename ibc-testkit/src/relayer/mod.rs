@@ -1,4 +1,5 @@
 pub mod context;
 pub mod error;
 pub mod integration;
+pub mod multihop;
 pub mod utils;
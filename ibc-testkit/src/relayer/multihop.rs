@@ -0,0 +1,578 @@
+use core::marker::PhantomData;
+
+use ibc::core::channel::handler::send_packet;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::Order;
+use ibc::core::channel::types::msgs::{
+    ChannelMsg, MsgAcknowledgement, MsgChannelOpenAck, MsgChannelOpenConfirm, MsgChannelOpenInit,
+    MsgChannelOpenTry, MsgRecvPacket, PacketMsg,
+};
+use ibc::core::channel::types::multihop::{
+    ConnectionHopProof, ConsensusHopProof, MsgMultihopProofs,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, ConnectionPath, Path,
+};
+use ibc::core::host::ValidationContext;
+use ibc::primitives::{Signer, Timestamp};
+use ibc_query::core::context::ProvableContext;
+
+use crate::context::TestContext;
+use crate::fixtures::core::signer::dummy_account_id;
+use crate::hosts::{HostClientState, TestHost};
+use crate::relayer::utils::TypedRelayerOps;
+use crate::testapp::ibc::core::types::DefaultIbcStore;
+
+/// Implements the relaying of an ICS-33 multi-hop channel between `A` and `C`,
+/// through the intermediate chain `B`. `A` and `C` only hold a client of `B`,
+/// which holds a client of both.
+///
+/// As for [`TypedRelayerOps`], all the implementations are in one direction:
+/// from `A` to `C`. For the functions in the opposite direction, use
+/// `TypedMultihopRelayerOps::<C, B, A>` instead.
+#[derive(Debug, Default)]
+pub struct TypedMultihopRelayerOps<A, B, C>(PhantomData<A>, PhantomData<B>, PhantomData<C>)
+where
+    A: TestHost,
+    B: TestHost,
+    C: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<C>: ClientStateValidation<DefaultIbcStore>;
+
+impl<A, B, C> TypedMultihopRelayerOps<A, B, C>
+where
+    A: TestHost,
+    B: TestHost,
+    C: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<C>: ClientStateValidation<DefaultIbcStore>,
+{
+    /// Updates the client of `A` on `B`, then the client of `B` on `C`, so
+    /// that `C` can verify the latest state of `A` through `B`.
+    pub fn update_clients_on_c_with_sync(
+        ctx_a: &mut TestContext<A>,
+        ctx_b: &mut TestContext<B>,
+        ctx_c: &mut TestContext<C>,
+        client_id_on_b: ClientId,
+        client_id_on_c: ClientId,
+        signer: Signer,
+    ) {
+        TypedRelayerOps::<B, A>::update_client_on_a_with_sync(
+            ctx_b,
+            ctx_a,
+            client_id_on_b,
+            signer.clone(),
+        );
+
+        TypedRelayerOps::<C, B>::update_client_on_a_with_sync(ctx_c, ctx_b, client_id_on_c, signer);
+    }
+
+    /// Builds the multi-hop proof of the value stored by `A` at the given path,
+    /// for `C` to verify through `B`.
+    ///
+    /// `conn_id_on_b` is the connection of `B` towards `A`. The value is proven
+    /// at the height of `A` last known to `B`, and the returned proof height is
+    /// the latest height of `B`, which `C` must know of.
+    pub fn multihop_proof_on_c(
+        ctx_a: &TestContext<A>,
+        ctx_b: &TestContext<B>,
+        conn_id_on_b: &ConnectionId,
+        path_on_a: Path,
+    ) -> (CommitmentProofBytes, Height) {
+        let proof_height_on_b = ctx_b.latest_height();
+
+        let conn_end_on_b = ctx_b
+            .ibc_store()
+            .connection_end(conn_id_on_b)
+            .expect("connection exists");
+
+        let client_id_on_b = conn_end_on_b.client_id().clone();
+
+        let height_of_a_on_b = ctx_b
+            .ibc_store()
+            .get_client_validation_context()
+            .client_state(&client_id_on_b)
+            .expect("client state exists")
+            .latest_height();
+
+        let consensus_state_path_on_b = ClientConsensusStatePath::new(
+            client_id_on_b,
+            height_of_a_on_b.revision_number(),
+            height_of_a_on_b.revision_height(),
+        );
+
+        let consensus_state_of_a_on_b = ctx_b
+            .ibc_store()
+            .get_client_validation_context()
+            .consensus_state(&consensus_state_path_on_b)
+            .expect("consensus state exists");
+
+        let key_proof = ctx_a
+            .ibc_store()
+            .get_proof(height_of_a_on_b, &path_on_a)
+            .expect("value proof exists")
+            .try_into()
+            .expect("value merkle proof");
+
+        let proof_conn_end_on_b = ctx_b
+            .ibc_store()
+            .get_proof(proof_height_on_b, &ConnectionPath::new(conn_id_on_b).into())
+            .expect("connection end exists")
+            .try_into()
+            .expect("value merkle proof");
+
+        let proof_consensus_state_of_a_on_b = ctx_b
+            .ibc_store()
+            .get_proof(proof_height_on_b, &consensus_state_path_on_b.into())
+            .expect("consensus state exists")
+            .try_into()
+            .expect("value merkle proof");
+
+        let proofs = MsgMultihopProofs {
+            key_proof,
+            connection_proofs: vec![ConnectionHopProof {
+                proof: proof_conn_end_on_b,
+                connection_end: conn_end_on_b,
+            }],
+            consensus_proofs: vec![ConsensusHopProof {
+                proof: proof_consensus_state_of_a_on_b,
+                consensus_height: height_of_a_on_b,
+                consensus_state: consensus_state_of_a_on_b.into(),
+            }],
+        };
+
+        (
+            proofs.try_into().expect("multi-hop proof"),
+            proof_height_on_b,
+        )
+    }
+
+    /// `A` initiates a multi-hop channel towards `C`, over the given connection
+    /// hops. Returns the channel identifier of `A`.
+    pub fn channel_open_init_on_a(
+        ctx_a: &mut TestContext<A>,
+        connection_hops_on_a: Vec<ConnectionId>,
+        port_id_on_a: PortId,
+        port_id_on_c: PortId,
+        signer: Signer,
+    ) -> ChannelId {
+        let msg_for_a = MsgEnvelope::Channel(ChannelMsg::OpenInit(MsgChannelOpenInit {
+            port_id_on_a,
+            connection_hops_on_a,
+            port_id_on_b: port_id_on_c,
+            ordering: Order::Unordered,
+            signer,
+            version_proposal: ChannelVersion::empty(),
+        }));
+
+        ctx_a.deliver(msg_for_a).expect("success");
+
+        let Some(IbcEvent::OpenInitChannel(open_init_channel_event)) =
+            ctx_a.ibc_store().events.lock().last().cloned()
+        else {
+            panic!("unexpected event")
+        };
+
+        open_init_channel_event.chan_id_on_a().clone()
+    }
+
+    /// `C` receives the multi-hop channel opening attempt by `A`.
+    /// Returns the channel identifier of `C`.
+    pub fn channel_open_try_on_c(
+        ctx_c: &mut TestContext<C>,
+        ctx_b: &TestContext<B>,
+        ctx_a: &TestContext<A>,
+        connection_hops_on_c: Vec<ConnectionId>,
+        chan_id_on_a: ChannelId,
+        port_id_on_a: PortId,
+        signer: Signer,
+    ) -> ChannelId {
+        let (proof_chan_end_on_a, proof_height_on_b) = Self::multihop_proof_on_c(
+            ctx_a,
+            ctx_b,
+            &connection_hops_on_c[1],
+            ChannelEndPath::new(&port_id_on_a, &chan_id_on_a).into(),
+        );
+
+        #[allow(deprecated)]
+        let msg_for_c = MsgEnvelope::Channel(ChannelMsg::OpenTry(MsgChannelOpenTry {
+            port_id_on_b: PortId::transfer(),
+            connection_hops_on_b: connection_hops_on_c,
+            port_id_on_a,
+            chan_id_on_a,
+            version_supported_on_a: ChannelVersion::empty(),
+            proof_chan_end_on_a,
+            proof_height_on_a: proof_height_on_b,
+            ordering: Order::Unordered,
+            signer,
+
+            version_proposal: ChannelVersion::empty(),
+        }));
+
+        ctx_c.deliver(msg_for_c).expect("success");
+
+        let Some(IbcEvent::OpenTryChannel(open_try_channel_event)) =
+            ctx_c.ibc_store().events.lock().last().cloned()
+        else {
+            panic!("unexpected event")
+        };
+
+        open_try_channel_event.chan_id_on_b().clone()
+    }
+
+    /// `A` receives the acknowledgement by `C` of the multi-hop channel
+    /// opening attempt.
+    #[allow(clippy::too_many_arguments)]
+    pub fn channel_open_ack_on_a(
+        ctx_a: &mut TestContext<A>,
+        ctx_b: &TestContext<B>,
+        ctx_c: &TestContext<C>,
+        conn_id_of_c_on_b: &ConnectionId,
+        chan_id_on_a: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_c: ChannelId,
+        port_id_on_c: PortId,
+        signer: Signer,
+    ) {
+        let (proof_chan_end_on_c, proof_height_on_b) =
+            TypedMultihopRelayerOps::<C, B, A>::multihop_proof_on_c(
+                ctx_c,
+                ctx_b,
+                conn_id_of_c_on_b,
+                ChannelEndPath::new(&port_id_on_c, &chan_id_on_c).into(),
+            );
+
+        let msg_for_a = MsgEnvelope::Channel(ChannelMsg::OpenAck(MsgChannelOpenAck {
+            port_id_on_a,
+            chan_id_on_a,
+            chan_id_on_b: chan_id_on_c,
+            version_on_b: ChannelVersion::empty(),
+            proof_chan_end_on_b: proof_chan_end_on_c,
+            proof_height_on_b,
+            signer,
+        }));
+
+        ctx_a.deliver(msg_for_a).expect("success");
+
+        let Some(IbcEvent::OpenAckChannel(_)) = ctx_a.ibc_store().events.lock().last().cloned()
+        else {
+            panic!("unexpected event")
+        };
+    }
+
+    /// `C` receives the confirmation by `A` that the multi-hop channel is open.
+    #[allow(clippy::too_many_arguments)]
+    pub fn channel_open_confirm_on_c(
+        ctx_c: &mut TestContext<C>,
+        ctx_b: &TestContext<B>,
+        ctx_a: &TestContext<A>,
+        conn_id_of_a_on_b: &ConnectionId,
+        chan_id_on_a: ChannelId,
+        port_id_on_a: PortId,
+        chan_id_on_c: ChannelId,
+        port_id_on_c: PortId,
+        signer: Signer,
+    ) {
+        let (proof_chan_end_on_a, proof_height_on_b) = Self::multihop_proof_on_c(
+            ctx_a,
+            ctx_b,
+            conn_id_of_a_on_b,
+            ChannelEndPath::new(&port_id_on_a, &chan_id_on_a).into(),
+        );
+
+        let msg_for_c = MsgEnvelope::Channel(ChannelMsg::OpenConfirm(MsgChannelOpenConfirm {
+            port_id_on_b: port_id_on_c,
+            chan_id_on_b: chan_id_on_c,
+            proof_chan_end_on_a,
+            proof_height_on_a: proof_height_on_b,
+            signer,
+        }));
+
+        ctx_c.deliver(msg_for_c).expect("success");
+
+        let Some(IbcEvent::OpenConfirmChannel(_)) = ctx_c.ibc_store().events.lock().last().cloned()
+        else {
+            panic!("unexpected event")
+        };
+    }
+
+    /// `C` receives a packet sent by `A` over a multi-hop channel.
+    /// Returns `C`'s acknowledgement of receipt.
+    pub fn packet_recv_on_c(
+        ctx_c: &mut TestContext<C>,
+        ctx_b: &TestContext<B>,
+        ctx_a: &TestContext<A>,
+        conn_id_of_a_on_b: &ConnectionId,
+        packet: Packet,
+        signer: Signer,
+    ) -> Acknowledgement {
+        let (proof_commitment_on_a, proof_height_on_b) = Self::multihop_proof_on_c(
+            ctx_a,
+            ctx_b,
+            conn_id_of_a_on_b,
+            CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a).into(),
+        );
+
+        let msg_for_c = MsgEnvelope::Packet(PacketMsg::Recv(MsgRecvPacket {
+            packet,
+            proof_commitment_on_a,
+            proof_height_on_a: proof_height_on_b,
+            signer,
+        }));
+
+        ctx_c.deliver(msg_for_c).expect("success");
+
+        let Some(IbcEvent::WriteAcknowledgement(write_ack_event)) =
+            ctx_c.ibc_store().events.lock().last().cloned()
+        else {
+            panic!("unexpected event")
+        };
+
+        write_ack_event.acknowledgement().clone()
+    }
+
+    /// `A` receives the acknowledgement by `C` of a packet sent over a
+    /// multi-hop channel.
+    pub fn packet_ack_on_a(
+        ctx_a: &mut TestContext<A>,
+        ctx_b: &TestContext<B>,
+        ctx_c: &TestContext<C>,
+        conn_id_of_c_on_b: &ConnectionId,
+        packet: Packet,
+        acknowledgement: Acknowledgement,
+        signer: Signer,
+    ) {
+        let (proof_acked_on_c, proof_height_on_b) =
+            TypedMultihopRelayerOps::<C, B, A>::multihop_proof_on_c(
+                ctx_c,
+                ctx_b,
+                conn_id_of_c_on_b,
+                AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a).into(),
+            );
+
+        let msg_for_a = MsgEnvelope::Packet(PacketMsg::Ack(MsgAcknowledgement {
+            packet,
+            acknowledgement,
+            proof_acked_on_b: proof_acked_on_c,
+            proof_height_on_b,
+            signer,
+        }));
+
+        ctx_a.deliver(msg_for_a).expect("success");
+
+        let Some(IbcEvent::AcknowledgePacket(_)) = ctx_a.ibc_store().events.lock().last().cloned()
+        else {
+            panic!("unexpected event")
+        };
+    }
+}
+
+/// Integration test for ICS-33 multi-hop channels. This test connects three
+/// [`TestHost`]s, `A`-`B` and `B`-`C`, opens a multi-hop channel between `A`
+/// and `C` through `B`, and relays a packet over it.
+pub fn multihop_integration_test<A, B, C>()
+where
+    A: TestHost,
+    B: TestHost,
+    C: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<C>: ClientStateValidation<DefaultIbcStore>,
+{
+    let mut ctx_a = TestContext::<A>::default();
+    let mut ctx_b = TestContext::<B>::default();
+    let mut ctx_c = TestContext::<C>::default();
+
+    let signer = dummy_account_id();
+
+    // clients: `A` and `C` track `B`, which tracks both
+    let client_id_of_b_on_a =
+        TypedRelayerOps::<A, B>::create_client_on_a(&mut ctx_a, &ctx_b, signer.clone());
+    let client_id_of_a_on_b =
+        TypedRelayerOps::<B, A>::create_client_on_a(&mut ctx_b, &ctx_a, signer.clone());
+    let client_id_of_c_on_b =
+        TypedRelayerOps::<B, C>::create_client_on_a(&mut ctx_b, &ctx_c, signer.clone());
+    let client_id_of_b_on_c =
+        TypedRelayerOps::<C, B>::create_client_on_a(&mut ctx_c, &ctx_b, signer.clone());
+
+    // connections: `A`-`B` and `B`-`C`
+    let (conn_id_on_a, conn_id_of_a_on_b) = TypedRelayerOps::<A, B>::create_connection_on_a(
+        &mut ctx_a,
+        &mut ctx_b,
+        client_id_of_b_on_a.clone(),
+        client_id_of_a_on_b.clone(),
+        signer.clone(),
+    );
+
+    let (conn_id_of_c_on_b, conn_id_on_c) = TypedRelayerOps::<B, C>::create_connection_on_a(
+        &mut ctx_b,
+        &mut ctx_c,
+        client_id_of_c_on_b.clone(),
+        client_id_of_b_on_c.clone(),
+        signer.clone(),
+    );
+
+    let connection_hops_on_a = vec![conn_id_on_a, conn_id_of_c_on_b.clone()];
+    let connection_hops_on_c = vec![conn_id_on_c, conn_id_of_a_on_b.clone()];
+
+    // multi-hop channel from `A` to `C`
+    let chan_id_on_a = TypedMultihopRelayerOps::<A, B, C>::channel_open_init_on_a(
+        &mut ctx_a,
+        connection_hops_on_a.clone(),
+        PortId::transfer(),
+        PortId::transfer(),
+        signer.clone(),
+    );
+
+    TypedMultihopRelayerOps::<A, B, C>::update_clients_on_c_with_sync(
+        &mut ctx_a,
+        &mut ctx_b,
+        &mut ctx_c,
+        client_id_of_a_on_b.clone(),
+        client_id_of_b_on_c.clone(),
+        signer.clone(),
+    );
+
+    let chan_id_on_c = TypedMultihopRelayerOps::<A, B, C>::channel_open_try_on_c(
+        &mut ctx_c,
+        &ctx_b,
+        &ctx_a,
+        connection_hops_on_c.clone(),
+        chan_id_on_a.clone(),
+        PortId::transfer(),
+        signer.clone(),
+    );
+
+    TypedMultihopRelayerOps::<C, B, A>::update_clients_on_c_with_sync(
+        &mut ctx_c,
+        &mut ctx_b,
+        &mut ctx_a,
+        client_id_of_c_on_b.clone(),
+        client_id_of_b_on_a.clone(),
+        signer.clone(),
+    );
+
+    TypedMultihopRelayerOps::<A, B, C>::channel_open_ack_on_a(
+        &mut ctx_a,
+        &ctx_b,
+        &ctx_c,
+        &conn_id_of_c_on_b,
+        chan_id_on_a.clone(),
+        PortId::transfer(),
+        chan_id_on_c.clone(),
+        PortId::transfer(),
+        signer.clone(),
+    );
+
+    TypedMultihopRelayerOps::<A, B, C>::update_clients_on_c_with_sync(
+        &mut ctx_a,
+        &mut ctx_b,
+        &mut ctx_c,
+        client_id_of_a_on_b.clone(),
+        client_id_of_b_on_c.clone(),
+        signer.clone(),
+    );
+
+    TypedMultihopRelayerOps::<A, B, C>::channel_open_confirm_on_c(
+        &mut ctx_c,
+        &ctx_b,
+        &ctx_a,
+        &conn_id_of_a_on_b,
+        chan_id_on_a.clone(),
+        PortId::transfer(),
+        chan_id_on_c.clone(),
+        PortId::transfer(),
+        signer.clone(),
+    );
+
+    let chan_end_on_a = ctx_a
+        .ibc_store()
+        .channel_end(&ChannelEndPath::new(&PortId::transfer(), &chan_id_on_a))
+        .expect("channel end exists");
+    let chan_end_on_c = ctx_c
+        .ibc_store()
+        .channel_end(&ChannelEndPath::new(&PortId::transfer(), &chan_id_on_c))
+        .expect("channel end exists");
+
+    assert!(chan_end_on_a.is_open() && chan_end_on_a.is_multihop());
+    assert!(chan_end_on_c.is_open() && chan_end_on_c.is_multihop());
+    assert_eq!(chan_end_on_a.connection_hops(), &connection_hops_on_a);
+    assert_eq!(chan_end_on_c.connection_hops(), &connection_hops_on_c);
+
+    // packet from `A` to `C`
+    let packet = Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a,
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: chan_id_on_c,
+        data: b"multi-hop packet".to_vec(),
+        timeout_height_on_b: TimeoutHeight::At(ctx_c.latest_height().add(100)),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    send_packet(ctx_a.ibc_store_mut(), packet.clone()).expect("packet sent");
+
+    // send_packet wasn't committed, hence produce a block
+    ctx_a.advance_block_height();
+
+    TypedMultihopRelayerOps::<A, B, C>::update_clients_on_c_with_sync(
+        &mut ctx_a,
+        &mut ctx_b,
+        &mut ctx_c,
+        client_id_of_a_on_b,
+        client_id_of_b_on_c,
+        signer.clone(),
+    );
+
+    let acknowledgement = TypedMultihopRelayerOps::<A, B, C>::packet_recv_on_c(
+        &mut ctx_c,
+        &ctx_b,
+        &ctx_a,
+        &conn_id_of_a_on_b,
+        packet.clone(),
+        signer.clone(),
+    );
+
+    TypedMultihopRelayerOps::<C, B, A>::update_clients_on_c_with_sync(
+        &mut ctx_c,
+        &mut ctx_b,
+        &mut ctx_a,
+        client_id_of_c_on_b,
+        client_id_of_b_on_a,
+        signer.clone(),
+    );
+
+    TypedMultihopRelayerOps::<A, B, C>::packet_ack_on_a(
+        &mut ctx_a,
+        &ctx_b,
+        &ctx_c,
+        &conn_id_of_c_on_b,
+        packet,
+        acknowledgement,
+        signer,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hosts::MockHost;
+
+    #[test]
+    fn multihop_integration_test_for_mock_hosts() {
+        multihop_integration_test::<MockHost, MockHost, MockHost>();
+    }
+}
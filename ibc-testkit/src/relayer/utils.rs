@@ -611,6 +611,7 @@ where
             proof_chan_end_on_a,
             proof_height_on_a,
             signer,
+            counterparty_upgrade_sequence: 0,
         }));
 
         ctx_b.deliver(msg_for_b).expect("success");
@@ -946,6 +947,7 @@ where
             proof_close_on_b,
             proof_height_on_b,
             signer,
+            counterparty_upgrade_sequence: 0,
         }));
 
         ctx_a.deliver(msg_for_a).expect("success");
@@ -0,0 +1,233 @@
+use ibc::apps::fee::context::{FeeExecutionContext, FeeValidationContext};
+use ibc::apps::fee::types::error::FeeError;
+use ibc::apps::fee::types::transfer::PrefixedCoin;
+use ibc::apps::fee::types::{PacketFee, PacketId};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+use super::types::{MockFeeContext, SharedFeeContext};
+
+impl FeeValidationContext for MockFeeContext {
+    type AccountId = Signer;
+
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.fee_enabled_channels
+            .contains(&(port_id.clone(), channel_id.clone()))
+    }
+
+    fn payee(&self, relayer: &Signer, channel_id: &ChannelId) -> Option<Signer> {
+        self.payees
+            .get(&(relayer.clone(), channel_id.clone()))
+            .cloned()
+    }
+
+    fn counterparty_payee(&self, relayer: &Signer, channel_id: &ChannelId) -> Option<Signer> {
+        self.counterparty_payees
+            .get(&(relayer.clone(), channel_id.clone()))
+            .cloned()
+    }
+
+    fn forward_relayer(&self, packet_id: &PacketId) -> Option<Signer> {
+        self.forward_relayers.get(packet_id).cloned()
+    }
+
+    fn packet_fees(&self, packet_id: &PacketId) -> Result<Vec<PacketFee>, FeeError> {
+        Ok(self.packet_fees.get(packet_id).cloned().unwrap_or_default())
+    }
+
+    fn escrow_fee_validate(
+        &self,
+        _payer: &Self::AccountId,
+        _coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError> {
+        Ok(())
+    }
+}
+
+impl FeeExecutionContext for MockFeeContext {
+    fn enable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError> {
+        self.fee_enabled_channels
+            .insert((port_id.clone(), channel_id.clone()));
+        Ok(())
+    }
+
+    fn disable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError> {
+        self.fee_enabled_channels
+            .remove(&(port_id.clone(), channel_id.clone()));
+        Ok(())
+    }
+
+    fn store_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        payee: Signer,
+    ) -> Result<(), FeeError> {
+        self.payees
+            .insert((relayer.clone(), channel_id.clone()), payee);
+        Ok(())
+    }
+
+    fn store_counterparty_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        counterparty_payee: Signer,
+    ) -> Result<(), FeeError> {
+        self.counterparty_payees
+            .insert((relayer.clone(), channel_id.clone()), counterparty_payee);
+        Ok(())
+    }
+
+    fn store_forward_relayer(
+        &mut self,
+        packet_id: &PacketId,
+        forward_relayer: Signer,
+    ) -> Result<(), FeeError> {
+        self.forward_relayers
+            .insert(packet_id.clone(), forward_relayer);
+        Ok(())
+    }
+
+    fn delete_forward_relayer(&mut self, packet_id: &PacketId) -> Result<(), FeeError> {
+        self.forward_relayers.remove(packet_id);
+        Ok(())
+    }
+
+    fn store_packet_fees(
+        &mut self,
+        packet_id: &PacketId,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), FeeError> {
+        self.packet_fees.insert(packet_id.clone(), packet_fees);
+        Ok(())
+    }
+
+    fn delete_packet_fees(&mut self, packet_id: &PacketId) -> Result<(), FeeError> {
+        self.packet_fees.remove(packet_id);
+        Ok(())
+    }
+
+    fn escrow_fee_execute(
+        &mut self,
+        payer: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError> {
+        self.escrowed.push((payer.clone(), coins.to_vec()));
+        Ok(())
+    }
+
+    fn distribute_fee_execute(
+        &mut self,
+        receiver: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError> {
+        self.distributed.push((receiver.clone(), coins.to_vec()));
+        Ok(())
+    }
+}
+
+impl FeeValidationContext for SharedFeeContext {
+    type AccountId = Signer;
+
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.0.lock().is_fee_enabled(port_id, channel_id)
+    }
+
+    fn payee(&self, relayer: &Signer, channel_id: &ChannelId) -> Option<Signer> {
+        self.0.lock().payee(relayer, channel_id)
+    }
+
+    fn counterparty_payee(&self, relayer: &Signer, channel_id: &ChannelId) -> Option<Signer> {
+        self.0.lock().counterparty_payee(relayer, channel_id)
+    }
+
+    fn forward_relayer(&self, packet_id: &PacketId) -> Option<Signer> {
+        self.0.lock().forward_relayer(packet_id)
+    }
+
+    fn packet_fees(&self, packet_id: &PacketId) -> Result<Vec<PacketFee>, FeeError> {
+        self.0.lock().packet_fees(packet_id)
+    }
+
+    fn escrow_fee_validate(
+        &self,
+        payer: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError> {
+        self.0.lock().escrow_fee_validate(payer, coins)
+    }
+}
+
+impl FeeExecutionContext for SharedFeeContext {
+    fn enable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError> {
+        self.0.lock().enable_fee(port_id, channel_id)
+    }
+
+    fn disable_fee(&mut self, port_id: &PortId, channel_id: &ChannelId) -> Result<(), FeeError> {
+        self.0.lock().disable_fee(port_id, channel_id)
+    }
+
+    fn store_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        payee: Signer,
+    ) -> Result<(), FeeError> {
+        self.0.lock().store_payee(relayer, channel_id, payee)
+    }
+
+    fn store_counterparty_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        counterparty_payee: Signer,
+    ) -> Result<(), FeeError> {
+        self.0
+            .lock()
+            .store_counterparty_payee(relayer, channel_id, counterparty_payee)
+    }
+
+    fn store_forward_relayer(
+        &mut self,
+        packet_id: &PacketId,
+        forward_relayer: Signer,
+    ) -> Result<(), FeeError> {
+        self.0
+            .lock()
+            .store_forward_relayer(packet_id, forward_relayer)
+    }
+
+    fn delete_forward_relayer(&mut self, packet_id: &PacketId) -> Result<(), FeeError> {
+        self.0.lock().delete_forward_relayer(packet_id)
+    }
+
+    fn store_packet_fees(
+        &mut self,
+        packet_id: &PacketId,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), FeeError> {
+        self.0.lock().store_packet_fees(packet_id, packet_fees)
+    }
+
+    fn delete_packet_fees(&mut self, packet_id: &PacketId) -> Result<(), FeeError> {
+        self.0.lock().delete_packet_fees(packet_id)
+    }
+
+    fn escrow_fee_execute(
+        &mut self,
+        payer: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError> {
+        self.0.lock().escrow_fee_execute(payer, coins)
+    }
+
+    fn distribute_fee_execute(
+        &mut self,
+        receiver: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError> {
+        self.0.lock().distribute_fee_execute(receiver, coins)
+    }
+}
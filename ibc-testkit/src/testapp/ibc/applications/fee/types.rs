@@ -0,0 +1,50 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::sync::Arc;
+
+use ibc::apps::fee::types::transfer::PrefixedCoin;
+use ibc::apps::fee::types::{PacketFee, PacketId};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use parking_lot::Mutex;
+
+/// An in-memory implementation of the fee middleware contexts, which records
+/// the fees moved in and out of the escrow instead of handling balances.
+#[derive(Debug, Default)]
+pub struct MockFeeContext {
+    pub fee_enabled_channels: BTreeSet<(PortId, ChannelId)>,
+    pub payees: BTreeMap<(Signer, ChannelId), Signer>,
+    pub counterparty_payees: BTreeMap<(Signer, ChannelId), Signer>,
+    pub packet_fees: BTreeMap<PacketId, Vec<PacketFee>>,
+    /// The forward relayers of the received packets whose acknowledgement
+    /// is deferred.
+    pub forward_relayers: BTreeMap<PacketId, Signer>,
+    /// The fees escrowed so far, along with the account they were paid by.
+    pub escrowed: Vec<(Signer, Vec<PrefixedCoin>)>,
+    /// The fees distributed so far, along with the account they were paid to.
+    pub distributed: Vec<(Signer, Vec<PrefixedCoin>)>,
+}
+
+impl MockFeeContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks the fee middleware as enabled on the given channel.
+    pub fn with_fee_enabled(mut self, port_id: PortId, channel_id: ChannelId) -> Self {
+        self.fee_enabled_channels.insert((port_id, channel_id));
+        self
+    }
+}
+
+/// A [`MockFeeContext`] shared between the fee middleware and the layers of
+/// the stack it wraps, e.g. for the packet forward middleware to write the
+/// acknowledgements it held through a `FeeIcs4Wrapper`.
+#[derive(Clone, Debug, Default)]
+pub struct SharedFeeContext(pub Arc<Mutex<MockFeeContext>>);
+
+impl SharedFeeContext {
+    pub fn new(ctx: MockFeeContext) -> Self {
+        Self(Arc::new(Mutex::new(ctx)))
+    }
+}
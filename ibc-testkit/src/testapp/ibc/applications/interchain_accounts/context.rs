@@ -0,0 +1,172 @@
+use ibc::apps::interchain_accounts::context::{
+    ControllerExecutionContext, ControllerValidationContext, HostExecutionContext,
+    HostValidationContext,
+};
+use ibc::apps::interchain_accounts::types::error::InterchainAccountError;
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::Any;
+use ibc::core::primitives::Signer;
+
+use super::types::{MockControllerContext, MockHostContext};
+
+fn channel_not_found(port_id: &PortId, channel_id: &ChannelId) -> InterchainAccountError {
+    ContextError::from(ChannelError::ChannelNotFound {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+    })
+    .into()
+}
+
+impl ControllerValidationContext for MockControllerContext {
+    fn is_controller_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn channel_connection_id(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ConnectionId, InterchainAccountError> {
+        self.channel_connections
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .ok_or_else(|| channel_not_found(port_id, channel_id))
+    }
+
+    fn active_channel_id(
+        &self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+    ) -> Option<ChannelId> {
+        self.active_channels
+            .get(&(connection_id.clone(), port_id.clone()))
+            .cloned()
+    }
+
+    fn interchain_account(&self, connection_id: &ConnectionId, port_id: &PortId) -> Option<Signer> {
+        self.interchain_accounts
+            .get(&(connection_id.clone(), port_id.clone()))
+            .cloned()
+    }
+}
+
+impl ControllerExecutionContext for MockControllerContext {
+    fn store_active_channel_id(
+        &mut self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+        channel_id: ChannelId,
+    ) -> Result<(), InterchainAccountError> {
+        self.active_channels
+            .insert((connection_id.clone(), port_id.clone()), channel_id);
+        Ok(())
+    }
+
+    fn store_interchain_account(
+        &mut self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+        address: Signer,
+    ) -> Result<(), InterchainAccountError> {
+        self.interchain_accounts
+            .insert((connection_id.clone(), port_id.clone()), address);
+        Ok(())
+    }
+}
+
+impl HostValidationContext for MockHostContext {
+    fn is_host_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn channel_connection_id(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ConnectionId, InterchainAccountError> {
+        self.channel_connections
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .ok_or_else(|| channel_not_found(port_id, channel_id))
+    }
+
+    fn active_channel_id(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Option<ChannelId> {
+        self.active_channels
+            .get(&(connection_id.clone(), controller_port_id.clone()))
+            .cloned()
+    }
+
+    fn interchain_account(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Option<Signer> {
+        self.interchain_accounts
+            .get(&(connection_id.clone(), controller_port_id.clone()))
+            .cloned()
+    }
+
+    fn is_message_allowed(&self, type_url: &str) -> bool {
+        self.allowed_messages.contains(type_url)
+    }
+}
+
+impl HostExecutionContext for MockHostContext {
+    fn create_interchain_account(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Signer, InterchainAccountError> {
+        Ok(format!("ica-{connection_id}-{controller_port_id}").into())
+    }
+
+    fn store_active_channel_id(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        channel_id: ChannelId,
+    ) -> Result<(), InterchainAccountError> {
+        self.active_channels.insert(
+            (connection_id.clone(), controller_port_id.clone()),
+            channel_id,
+        );
+        Ok(())
+    }
+
+    fn store_interchain_account(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        address: Signer,
+    ) -> Result<(), InterchainAccountError> {
+        self.interchain_accounts
+            .insert((connection_id.clone(), controller_port_id.clone()), address);
+        Ok(())
+    }
+
+    fn execute_tx(
+        &mut self,
+        interchain_account: &Signer,
+        messages: Vec<Any>,
+    ) -> Result<Vec<Any>, InterchainAccountError> {
+        let msg_responses = messages
+            .iter()
+            .map(|msg| Any {
+                type_url: format!("{}Response", msg.type_url),
+                value: Vec::new(),
+            })
+            .collect();
+
+        self.executed_txs
+            .push((interchain_account.clone(), messages));
+
+        Ok(msg_responses)
+    }
+}
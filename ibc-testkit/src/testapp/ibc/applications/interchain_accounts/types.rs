@@ -0,0 +1,100 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::Any;
+use ibc::core::primitives::Signer;
+
+/// An in-memory implementation of the interchain accounts controller
+/// contexts.
+#[derive(Debug)]
+pub struct MockControllerContext {
+    pub enabled: bool,
+    /// The connection each channel is built on.
+    pub channel_connections: BTreeMap<(PortId, ChannelId), ConnectionId>,
+    pub active_channels: BTreeMap<(ConnectionId, PortId), ChannelId>,
+    pub interchain_accounts: BTreeMap<(ConnectionId, PortId), Signer>,
+}
+
+impl MockControllerContext {
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            channel_connections: BTreeMap::new(),
+            active_channels: BTreeMap::new(),
+            interchain_accounts: BTreeMap::new(),
+        }
+    }
+
+    /// Records the connection the given channel is built on.
+    pub fn with_channel(
+        mut self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        connection_id: ConnectionId,
+    ) -> Self {
+        self.channel_connections
+            .insert((port_id, channel_id), connection_id);
+        self
+    }
+}
+
+impl Default for MockControllerContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An in-memory implementation of the interchain accounts host contexts,
+/// which records the transactions it is asked to execute instead of
+/// executing them, and answers each message with an empty response.
+#[derive(Debug)]
+pub struct MockHostContext {
+    pub enabled: bool,
+    /// The connection each channel is built on.
+    pub channel_connections: BTreeMap<(PortId, ChannelId), ConnectionId>,
+    pub active_channels: BTreeMap<(ConnectionId, PortId), ChannelId>,
+    pub interchain_accounts: BTreeMap<(ConnectionId, PortId), Signer>,
+    /// The type URLs of the messages interchain accounts may execute.
+    pub allowed_messages: BTreeSet<String>,
+    /// The transactions executed so far, along with the interchain account
+    /// they were executed by.
+    pub executed_txs: Vec<(Signer, Vec<Any>)>,
+}
+
+impl MockHostContext {
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            channel_connections: BTreeMap::new(),
+            active_channels: BTreeMap::new(),
+            interchain_accounts: BTreeMap::new(),
+            allowed_messages: BTreeSet::new(),
+            executed_txs: Vec::new(),
+        }
+    }
+
+    /// Records the connection the given channel is built on.
+    pub fn with_channel(
+        mut self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        connection_id: ConnectionId,
+    ) -> Self {
+        self.channel_connections
+            .insert((port_id, channel_id), connection_id);
+        self
+    }
+
+    /// Allows interchain accounts to execute messages of the given type.
+    pub fn with_allowed_message(mut self, type_url: impl ToString) -> Self {
+        self.allowed_messages.insert(type_url.to_string());
+        self
+    }
+}
+
+impl Default for MockHostContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,2 +1,5 @@
+pub mod fee;
+pub mod interchain_accounts;
 pub mod nft_transfer;
+pub mod packet_forward;
 pub mod transfer;
@@ -0,0 +1,99 @@
+use core::time::Duration;
+
+use ibc::apps::fee::module::FeeIcs4Wrapper;
+use ibc::apps::packet_forward::context::{
+    PacketForwardExecutionContext, PacketForwardValidationContext,
+};
+use ibc::apps::packet_forward::types::error::ForwardError;
+use ibc::apps::packet_forward::types::InFlightPacket;
+use ibc::core::channel::context::ChannelIcs4Wrapper;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::middleware::Ics4Wrapper;
+
+use super::types::MockPacketForwardContext;
+use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use crate::testapp::ibc::core::types::DefaultIbcStore;
+
+impl PacketForwardValidationContext for MockPacketForwardContext {
+    fn forward_timeout(&self) -> Duration {
+        self.forward_timeout
+    }
+
+    fn forward_retries(&self) -> u8 {
+        self.forward_retries
+    }
+
+    fn intermediate_receiver(
+        &self,
+        channel_id: &ChannelId,
+        original_sender: &Signer,
+    ) -> Result<Signer, ForwardError> {
+        Ok(format!("pfm-{channel_id}-{original_sender}").into())
+    }
+
+    fn in_flight_packet(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<Option<InFlightPacket>, ForwardError> {
+        Ok(self
+            .in_flight_packets
+            .get(&(port_id.clone(), channel_id.clone(), sequence))
+            .cloned())
+    }
+}
+
+impl PacketForwardExecutionContext for MockPacketForwardContext {
+    type IbcCtx = DefaultIbcStore;
+    type TokenCtx = DummyTransferModule;
+
+    fn forward_ctx_mut(&mut self) -> (&mut Self::IbcCtx, &mut Self::TokenCtx) {
+        (&mut self.ibc_store, &mut self.bank)
+    }
+
+    fn write_acknowledgement(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), ForwardError> {
+        let mut core = ChannelIcs4Wrapper::new(&mut self.ibc_store);
+
+        match &mut self.fee_ctx {
+            Some(fee_ctx) => FeeIcs4Wrapper::new(fee_ctx, &mut core)
+                .write_acknowledgement(packet, acknowledgement)?,
+            None => core.write_acknowledgement(packet, acknowledgement)?,
+        }
+
+        Ok(())
+    }
+
+    fn store_in_flight_packet(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        in_flight_packet: InFlightPacket,
+    ) -> Result<(), ForwardError> {
+        self.in_flight_packets.insert(
+            (port_id.clone(), channel_id.clone(), sequence),
+            in_flight_packet,
+        );
+        Ok(())
+    }
+
+    fn delete_in_flight_packet(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), ForwardError> {
+        self.in_flight_packets
+            .remove(&(port_id.clone(), channel_id.clone(), sequence));
+        Ok(())
+    }
+}
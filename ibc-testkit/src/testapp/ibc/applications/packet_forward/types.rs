@@ -0,0 +1,53 @@
+use alloc::collections::BTreeMap;
+use core::time::Duration;
+
+use ibc::apps::packet_forward::types::{
+    InFlightPacket, DEFAULT_FORWARD_RETRIES, DEFAULT_FORWARD_TIMEOUT,
+};
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+
+use crate::testapp::ibc::applications::fee::types::SharedFeeContext;
+use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use crate::testapp::ibc::core::types::DefaultIbcStore;
+
+/// An in-memory implementation of the packet forward middleware contexts,
+/// which sends the forwarded packets through a [`DefaultIbcStore`] and moves
+/// no balances.
+#[derive(Debug)]
+pub struct MockPacketForwardContext {
+    pub ibc_store: DefaultIbcStore,
+    pub bank: DummyTransferModule,
+    pub in_flight_packets: BTreeMap<(PortId, ChannelId, Sequence), InFlightPacket>,
+    pub forward_timeout: Duration,
+    pub forward_retries: u8,
+    /// The context of the fee middleware wrapping the packet forward
+    /// middleware, if any, which the held acknowledgements are written
+    /// through.
+    pub fee_ctx: Option<SharedFeeContext>,
+}
+
+impl MockPacketForwardContext {
+    pub fn new(ibc_store: DefaultIbcStore) -> Self {
+        Self {
+            ibc_store,
+            bank: DummyTransferModule::new(),
+            in_flight_packets: BTreeMap::new(),
+            forward_timeout: DEFAULT_FORWARD_TIMEOUT,
+            forward_retries: DEFAULT_FORWARD_RETRIES,
+            fee_ctx: None,
+        }
+    }
+
+    /// Sets the number of times forwarded packets are retried by default.
+    pub fn with_forward_retries(mut self, forward_retries: u8) -> Self {
+        self.forward_retries = forward_retries;
+        self
+    }
+
+    /// Writes the held acknowledgements through the fee middleware with the
+    /// given context, which wraps the packet forward middleware.
+    pub fn with_fee_ctx(mut self, fee_ctx: SharedFeeContext) -> Self {
+        self.fee_ctx = Some(fee_ctx);
+        self
+    }
+}
@@ -1,6 +1,6 @@
 use ibc::apps::transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use ibc::apps::transfer::types::error::TokenTransferError;
-use ibc::apps::transfer::types::{Memo, PrefixedCoin};
+use ibc::apps::transfer::types::{Ics20Version, Memo, PrefixedCoin};
 use ibc::core::host::types::identifiers::{ChannelId, PortId};
 use ibc::core::primitives::Signer;
 
@@ -13,6 +13,18 @@ impl TokenTransferValidationContext for DummyTransferModule {
         Ok(PortId::transfer())
     }
 
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Ics20Version, TokenTransferError> {
+        Ok(self
+            .channel_versions
+            .get(&(port_id.clone(), channel_id.clone()))
+            .copied()
+            .unwrap_or(Ics20Version::V1))
+    }
+
     fn can_send_coins(&self) -> Result<(), TokenTransferError> {
         Ok(())
     }
@@ -60,6 +72,17 @@ impl TokenTransferValidationContext for DummyTransferModule {
 }
 
 impl TokenTransferExecutionContext for DummyTransferModule {
+    fn store_channel_version(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        version: Ics20Version,
+    ) -> Result<(), TokenTransferError> {
+        self.channel_versions
+            .insert((port_id.clone(), channel_id.clone()), version);
+        Ok(())
+    }
+
     fn escrow_coins_execute(
         &mut self,
         _from_account: &Self::AccountId,
@@ -1,9 +1,20 @@
+use alloc::collections::BTreeMap;
+
+use ibc::apps::transfer::types::Ics20Version;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+
 #[derive(Debug)]
-pub struct DummyTransferModule;
+pub struct DummyTransferModule {
+    /// The token transfer versions negotiated on the channels the module
+    /// opened. Other channels are `ics20-1` ones.
+    pub channel_versions: BTreeMap<(PortId, ChannelId), Ics20Version>,
+}
 
 impl DummyTransferModule {
     pub fn new() -> Self {
-        Self
+        Self {
+            channel_versions: BTreeMap::new(),
+        }
     }
 }
 
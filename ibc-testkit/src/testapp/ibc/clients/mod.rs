@@ -1,4 +1,5 @@
 pub mod mock;
+pub mod wasm;
 
 use alloc::fmt::Debug;
 
@@ -15,6 +16,10 @@ use ibc::core::client::types::Height;
 use ibc::core::primitives::prelude::*;
 use ibc::derive::{ClientState, ConsensusState};
 use ibc::primitives::proto::{Any, Protobuf};
+use ibc_client_wasm::client_state::ClientState as WasmClientState;
+use ibc_client_wasm::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_client_wasm::types::client_state::WASM_CLIENT_STATE_TYPE_URL;
+use ibc_client_wasm::types::consensus_state::WASM_CONSENSUS_STATE_TYPE_URL;
 
 use super::core::types::MockIbcStore;
 use crate::testapp::ibc::clients::mock::client_state::{
@@ -30,6 +35,7 @@ use crate::testapp::ibc::clients::mock::consensus_state::{
 pub enum AnyClientState {
     Tendermint(TmClientState),
     Mock(MockClientState),
+    Wasm(WasmClientState),
 }
 
 impl AnyClientState {
@@ -37,6 +43,7 @@ impl AnyClientState {
         match self {
             Self::Tendermint(cs) => cs.inner().latest_height,
             Self::Mock(cs) => cs.latest_height(),
+            Self::Wasm(cs) => cs.inner().latest_height,
         }
     }
 
@@ -44,6 +51,8 @@ impl AnyClientState {
         match self {
             Self::Tendermint(cs) => cs.inner().is_frozen(),
             Self::Mock(cs) => cs.is_frozen(),
+            // only the contract of an 08-wasm client knows whether it is frozen
+            Self::Wasm(_) => false,
         }
     }
 }
@@ -58,6 +67,8 @@ impl TryFrom<Any> for AnyClientState {
             Ok(TmClientState::try_from(raw)?.into())
         } else if raw.type_url == MOCK_CLIENT_STATE_TYPE_URL {
             MockClientState::try_from(raw).map(Into::into)
+        } else if raw.type_url == WASM_CLIENT_STATE_TYPE_URL {
+            WasmClientState::try_from(raw).map(Into::into)
         } else {
             Err(ClientError::Other {
                 description: "failed to deserialize message".to_string(),
@@ -71,6 +82,7 @@ impl From<AnyClientState> for Any {
         match host_client_state {
             AnyClientState::Tendermint(cs) => cs.into(),
             AnyClientState::Mock(cs) => cs.into(),
+            AnyClientState::Wasm(cs) => cs.into(),
         }
     }
 }
@@ -91,6 +103,7 @@ impl From<ConsensusStateType> for AnyConsensusState {
 pub enum AnyConsensusState {
     Tendermint(TmConsensusState),
     Mock(MockConsensusState),
+    Wasm(WasmConsensusState),
 }
 
 impl TryFrom<Any> for AnyConsensusState {
@@ -101,6 +114,8 @@ impl TryFrom<Any> for AnyConsensusState {
             Ok(TmConsensusState::try_from(raw)?.into())
         } else if raw.type_url == MOCK_CONSENSUS_STATE_TYPE_URL {
             MockConsensusState::try_from(raw).map(Into::into)
+        } else if raw.type_url == WASM_CONSENSUS_STATE_TYPE_URL {
+            WasmConsensusState::try_from(raw).map(Into::into)
         } else {
             Err(ClientError::Other {
                 description: "failed to deserialize message".to_string(),
@@ -114,6 +129,7 @@ impl From<AnyConsensusState> for Any {
         match host_consensus_state {
             AnyConsensusState::Tendermint(cs) => cs.into(),
             AnyConsensusState::Mock(cs) => cs.into(),
+            AnyConsensusState::Wasm(cs) => cs.into(),
         }
     }
 }
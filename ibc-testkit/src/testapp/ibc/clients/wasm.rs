@@ -0,0 +1,111 @@
+//! A mock VM running the contracts of the 08-wasm clients of the testkit.
+//!
+//! As the mock client, the mock contract trusts the chain it tracks: it
+//! accepts every client message and proof, and records the calls it
+//! receives. It stores nothing when instantiated, so the states of its
+//! clients are to be set in the store of the host directly, e.g. with
+//! `MockContext::with_client_state`.
+
+use ibc::core::primitives::prelude::*;
+use ibc_client_wasm::handler::checksum;
+use ibc_client_wasm::store::WasmStore;
+use ibc_client_wasm::types::error::Error;
+use ibc_client_wasm::types::Bytes;
+use ibc_client_wasm::vm::{ContractEnv, ContractOutput, WasmVm};
+use parking_lot::Mutex;
+
+/// A call the mock contract received.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockWasmCall {
+    pub entry_point: &'static str,
+    pub msg: String,
+}
+
+/// A VM whose contracts accept every client message and proof.
+#[derive(Debug, Default)]
+pub struct MockWasmVm {
+    /// The calls the contracts received, in order
+    pub calls: Mutex<Vec<MockWasmCall>>,
+}
+
+impl MockWasmVm {
+    /// Returns the calls of the given entry point whose message starts with
+    /// the given JSON key, e.g. `verify_membership`.
+    pub fn calls_of(&self, entry_point: &str, msg_key: &str) -> Vec<MockWasmCall> {
+        let prefix = format!("{{\"{msg_key}\"");
+
+        self.calls
+            .lock()
+            .iter()
+            .filter(|call| call.entry_point == entry_point && call.msg.starts_with(&prefix))
+            .cloned()
+            .collect()
+    }
+
+    fn record(&self, entry_point: &'static str, msg: &[u8]) {
+        self.calls.lock().push(MockWasmCall {
+            entry_point,
+            msg: String::from_utf8_lossy(msg).into_owned(),
+        });
+    }
+}
+
+impl WasmVm for MockWasmVm {
+    fn store_code(&self, byte_code: &[u8]) -> Result<Bytes, Error> {
+        Ok(checksum(byte_code))
+    }
+
+    fn remove_code(&self, _checksum: &[u8]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn instantiate(
+        &self,
+        _env: &ContractEnv,
+        _store: &dyn WasmStore,
+        msg: &[u8],
+    ) -> Result<ContractOutput, Error> {
+        self.record("instantiate", msg);
+
+        Ok(ContractOutput::default())
+    }
+
+    fn sudo(
+        &self,
+        _env: &ContractEnv,
+        _store: &dyn WasmStore,
+        msg: &[u8],
+    ) -> Result<ContractOutput, Error> {
+        self.record("sudo", msg);
+
+        Ok(ContractOutput::default())
+    }
+
+    fn migrate(
+        &self,
+        _env: &ContractEnv,
+        _store: &dyn WasmStore,
+        msg: &[u8],
+    ) -> Result<ContractOutput, Error> {
+        self.record("migrate", msg);
+
+        Ok(ContractOutput::default())
+    }
+
+    /// Answers every query at once: the client is active, has seen no
+    /// misbehaviour, and its consensus states are as recent as the host.
+    fn query(
+        &self,
+        env: &ContractEnv,
+        _store: &dyn WasmStore,
+        msg: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        self.record("query", msg);
+
+        Ok(format!(
+            r#"{{"is_valid":true,"status":"Active","found_misbehaviour":false,"timestamp":{}}}"#,
+            env.host_timestamp.nanoseconds()
+        )
+        .into_bytes())
+    }
+}
@@ -1,23 +1,30 @@
 use core::fmt::Debug;
+use core::ops::Bound;
 
 use basecoin_store::context::{ProvableStore, Store};
-use basecoin_store::types::Height as StoreHeight;
+use basecoin_store::types::{Height as StoreHeight, Path as StorePath};
 use ibc::core::client::context::{
     ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
 };
 use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
 use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::metering::CostEvent;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId};
 use ibc::core::host::types::path::{
     ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath, ClientUpdateTimePath, Path,
 };
 use ibc::core::host::ValidationContext;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, Timestamp};
 use ibc::primitives::prelude::*;
+use ibc_client_wasm::context::{WasmClientExecutionContext, WasmClientValidationContext};
+use ibc_client_wasm::store::{self as wasm_store, Order, StoreEntries};
+use subtle_encoding::{Encoding, Hex};
 
 use super::types::MockIbcStore;
+use crate::fixtures::core::signer::dummy_account_id;
 use crate::testapp::ibc::clients::mock::client_state::MockClientContext;
+use crate::testapp::ibc::clients::wasm::MockWasmVm;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 
 pub type PortChannelIdMap<V> = BTreeMap<PortId, BTreeMap<ChannelId, V>>;
@@ -85,6 +92,34 @@ where
             .collect::<Result<Vec<_>, _>>()
     }
 
+    /// Iterates over the heights of the consensus states of a client, looking
+    /// each one up in the index of the heights past the previous one, so that
+    /// the index isn't kept locked in between.
+    fn ascending_consensus_state_heights<'a>(
+        &'a self,
+        client_id: &ClientId,
+    ) -> Result<Box<dyn Iterator<Item = Height> + 'a>, ContextError> {
+        let client_id = client_id.clone();
+        let mut last_height = None;
+
+        Ok(Box::new(core::iter::from_fn(move || {
+            let consensus_state_heights = self.consensus_state_heights.lock();
+            let heights = consensus_state_heights.get(&client_id)?;
+
+            let next_height = match last_height {
+                Some(last_height) => heights
+                    .range((Bound::Excluded(last_height), Bound::Unbounded))
+                    .next(),
+                None => heights.first(),
+            }
+            .copied()?;
+
+            last_height = Some(next_height);
+
+            Some(next_height)
+        })))
+    }
+
     fn next_consensus_state(
         &self,
         client_id: &ClientId,
@@ -150,6 +185,34 @@ where
 
         Ok(consensus_state)
     }
+
+    fn is_header_verified(
+        &self,
+        client_id: &ClientId,
+        header_hash: &[u8],
+        trusted_height: &Height,
+    ) -> Result<bool, ContextError> {
+        Ok(self.verified_headers.lock().contains(&(
+            client_id.clone(),
+            header_hash.to_vec(),
+            *trusted_height,
+        )))
+    }
+
+    fn cache_verified_header(
+        &self,
+        client_id: &ClientId,
+        header_hash: &[u8],
+        trusted_height: &Height,
+    ) -> Result<(), ContextError> {
+        self.verified_headers.lock().insert((
+            client_id.clone(),
+            header_hash.to_vec(),
+            *trusted_height,
+        ));
+
+        Ok(())
+    }
 }
 
 impl<S> ClientValidationContext for MockIbcStore<S>
@@ -222,6 +285,14 @@ where
 
         Ok((processed_timestamp, processed_height))
     }
+
+    /// Records the cost event, for tests to check the metering of the
+    /// handlers and light clients.
+    fn charge(&self, event: CostEvent) -> Result<(), ContextError> {
+        self.cost_events.lock().push(event);
+
+        Ok(())
+    }
 }
 
 impl<S> ClientExecutionContext for MockIbcStore<S>
@@ -251,11 +322,23 @@ where
         consensus_state_path: ClientConsensusStatePath,
         consensus_state: Self::ConsensusStateRef,
     ) -> Result<(), ContextError> {
+        let height = Height::new(
+            consensus_state_path.revision_number,
+            consensus_state_path.revision_height,
+        )?;
+
         self.consensus_state_store
-            .set(consensus_state_path, consensus_state)
+            .set(consensus_state_path.clone(), consensus_state)
             .map_err(|_| ClientError::Other {
                 description: "Consensus state store error".to_string(),
             })?;
+
+        self.consensus_state_heights
+            .lock()
+            .entry(consensus_state_path.client_id)
+            .or_default()
+            .insert(height);
+
         Ok(())
     }
 
@@ -263,7 +346,20 @@ where
         &mut self,
         consensus_state_path: ClientConsensusStatePath,
     ) -> Result<(), ContextError> {
-        self.consensus_state_store.delete(consensus_state_path);
+        self.consensus_state_store
+            .delete(consensus_state_path.clone());
+
+        if let Some(heights) = self
+            .consensus_state_heights
+            .lock()
+            .get_mut(&consensus_state_path.client_id)
+        {
+            heights.remove(&Height::new(
+                consensus_state_path.revision_number,
+                consensus_state_path.revision_height,
+            )?);
+        }
+
         Ok(())
     }
 
@@ -323,3 +419,129 @@ where
         Ok(())
     }
 }
+
+impl<S> MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    /// Returns the path under which the given key of the store of an 08-wasm
+    /// client is stored, i.e. the key prefixed with `clients/{client_id}/`.
+    fn client_store_path(client_id: &ClientId, key: &[u8]) -> Result<StorePath, ContextError> {
+        let key = core::str::from_utf8(key).map_err(|e| ClientError::Other {
+            description: format!("the mock store only supports UTF-8 keys: {e}"),
+        })?;
+
+        Ok(format!("clients/{client_id}/{key}").into())
+    }
+
+    fn wasm_checksum_path(checksum: &[u8]) -> StorePath {
+        format!(
+            "wasm/checksums/{}",
+            Hex::lower_case()
+                .encode_to_string(checksum)
+                .expect("never fails")
+        )
+        .into()
+    }
+}
+
+impl<S> WasmClientValidationContext for MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    type Vm = MockWasmVm;
+
+    fn wasm_vm(&self) -> &MockWasmVm {
+        &self.wasm_vm
+    }
+
+    fn client_store_get(
+        &self,
+        client_id: &ClientId,
+        key: &[u8],
+    ) -> Result<Option<Vec<u8>>, ContextError> {
+        Ok(self.store.get(
+            StoreHeight::Pending,
+            &Self::client_store_path(client_id, key)?,
+        ))
+    }
+
+    fn client_store_range(
+        &self,
+        client_id: &ClientId,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Result<StoreEntries, ContextError> {
+        let prefix = format!("clients/{client_id}/");
+
+        let entries: BTreeMap<_, _> = self
+            .store
+            .get_keys(&format!("clients/{client_id}").into())
+            .into_iter()
+            .filter_map(|path| {
+                let key = path.to_string().strip_prefix(&prefix)?.as_bytes().to_vec();
+                let value = self.store.get(StoreHeight::Pending, &path)?;
+                Some((key, value))
+            })
+            .collect();
+
+        Ok(wasm_store::range(&entries, start, end, order))
+    }
+
+    fn has_checksum(&self, checksum: &[u8]) -> Result<bool, ContextError> {
+        Ok(self
+            .wasm_checksum_store
+            .is_path_set(StoreHeight::Pending, &Self::wasm_checksum_path(checksum)))
+    }
+
+    fn wasm_authority(&self) -> Result<Signer, ContextError> {
+        Ok(dummy_account_id())
+    }
+}
+
+impl<S> WasmClientExecutionContext for MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    fn client_store_set(
+        &mut self,
+        client_id: &ClientId,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(), ContextError> {
+        self.store
+            .set(Self::client_store_path(client_id, &key)?, value)
+            .map_err(|_| ClientError::Other {
+                description: "client store error".to_string(),
+            })?;
+
+        Ok(())
+    }
+
+    fn client_store_remove(
+        &mut self,
+        client_id: &ClientId,
+        key: &[u8],
+    ) -> Result<(), ContextError> {
+        self.store.delete(&Self::client_store_path(client_id, key)?);
+
+        Ok(())
+    }
+
+    fn store_checksum(&mut self, checksum: Vec<u8>) -> Result<(), ContextError> {
+        self.wasm_checksum_store
+            .set_path(Self::wasm_checksum_path(&checksum))
+            .map_err(|_| ClientError::Other {
+                description: "checksum store error".to_string(),
+            })?;
+
+        Ok(())
+    }
+
+    fn delete_checksum(&mut self, checksum: &[u8]) -> Result<(), ContextError> {
+        self.store.delete(&Self::wasm_checksum_path(checksum));
+
+        Ok(())
+    }
+}
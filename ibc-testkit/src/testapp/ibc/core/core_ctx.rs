@@ -1,6 +1,7 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
 use core::fmt::Debug;
+use core::str::FromStr;
 use core::time::Duration;
 
 use basecoin_store::context::{ProvableStore, Store};
@@ -9,27 +10,35 @@ use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::packet::{PacketState, Receipt};
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
 use ibc::core::client::context::consensus_state::ConsensusState;
 use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{CounterpartyInfo, Height};
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
 use ibc::core::commitment_types::merkle::MerkleProof;
 use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::{ConnectionEnd, IdentifiedConnectionEnd};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::simulation::StateChange;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath,
-    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, Path, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, AckV2Path, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath,
+    ClientConnectionPath, ClientCounterpartyPath, CommitmentPath, CommitmentV2Path, ConnectionPath,
+    CounterpartyUpgradePath, NextChannelSequencePath, NextClientSequencePath,
+    NextConnectionSequencePath, Path, ReceiptPath, ReceiptV2Path, SeqAckPath, SeqRecvPath,
+    SeqSendPath, SeqSendV2Path,
+};
+use ibc::core::host::{
+    CachedExecutionContext, ClientStateRef, ConsensusStateRef, ExecutionContext, ValidationContext,
 };
-use ibc::core::host::{ClientStateRef, ConsensusStateRef, ExecutionContext, ValidationContext};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::{Signer, Timestamp};
+use ibc::primitives::proto::Protobuf;
 use ibc::primitives::ToVec;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
 use ibc_query::core::context::{ProvableContext, QueryContext};
+use ibc_query::types::{Page, PageRequest};
 
 use super::types::{MockIbcStore, DEFAULT_BLOCK_TIME_SECS};
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
@@ -138,6 +147,58 @@ where
         CommitmentPrefix::try_from(b"mock".to_vec()).expect("Never fails")
     }
 
+    fn read_store(&self, path: &Path) -> Result<Option<Vec<u8>>, ContextError> {
+        let value = match path {
+            Path::Connection(connection_path) => self
+                .connection_end_store
+                .get(StoreHeight::Pending, connection_path)
+                .map(|connection_end| connection_end.encode_vec()),
+            Path::ChannelEnd(channel_end_path) => self
+                .channel_end_store
+                .get(StoreHeight::Pending, channel_end_path)
+                .map(|channel_end| channel_end.encode_vec()),
+            Path::SeqRecv(seq_recv_path) => self
+                .recv_sequence_store
+                .get(StoreHeight::Pending, seq_recv_path)
+                .map(|seq| seq.to_vec()),
+            Path::Commitment(commitment_path) => self
+                .packet_commitment_store
+                .get(StoreHeight::Pending, commitment_path)
+                .map(PacketCommitment::into_vec),
+            Path::Ack(ack_path) => self
+                .packet_ack_store
+                .get(StoreHeight::Pending, ack_path)
+                .map(AcknowledgementCommitment::into_vec),
+            Path::Receipt(receipt_path) => self
+                .get_packet_receipt(receipt_path)
+                .ok()
+                .map(|receipt| receipt.to_vec()),
+            Path::ChannelUpgrade(upgrade_path) => self
+                .channel_upgrade_store
+                .get(StoreHeight::Pending, &upgrade_path.to_string().into())
+                .map(|upgrade| upgrade.encode_vec()),
+            Path::ChannelUpgradeError(upgrade_error_path) => self
+                .upgrade_error_receipt_store
+                .get(StoreHeight::Pending, &upgrade_error_path.to_string().into())
+                .map(|error_receipt| error_receipt.encode_vec()),
+            Path::CommitmentV2(commitment_path) => self
+                .packet_commitment_v2_store
+                .get(StoreHeight::Pending, &commitment_path.to_string().into())
+                .map(PacketCommitment::into_vec),
+            Path::AckV2(ack_path) => self
+                .packet_ack_v2_store
+                .get(StoreHeight::Pending, &ack_path.to_string().into())
+                .map(AcknowledgementCommitment::into_vec),
+            Path::ReceiptV2(receipt_path) => self
+                .get_packet_receipt_v2(receipt_path)
+                .ok()
+                .map(|receipt| receipt.to_vec()),
+            _ => None,
+        };
+
+        Ok(value)
+    }
+
     fn connection_counter(&self) -> Result<u64, ContextError> {
         Ok(self
             .conn_counter
@@ -211,20 +272,30 @@ where
     }
 
     fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
-        Ok(self
-            .packet_receipt_store
-            .is_path_set(
-                StoreHeight::Pending,
-                &ReceiptPath::new(
-                    &receipt_path.port_id,
-                    &receipt_path.channel_id,
-                    receipt_path.sequence,
-                ),
-            )
-            .then_some(Receipt::Ok)
-            .ok_or(PacketError::PacketReceiptNotFound {
+        if !self.packet_receipt_store.is_path_set(
+            StoreHeight::Pending,
+            &ReceiptPath::new(
+                &receipt_path.port_id,
+                &receipt_path.channel_id,
+                receipt_path.sequence,
+            ),
+        ) {
+            return Err(PacketError::PacketReceiptNotFound {
                 sequence: receipt_path.sequence,
-            })?)
+            }
+            .into());
+        }
+
+        let is_timeout = self.packet_timeout_receipt_store.is_path_set(
+            StoreHeight::Pending,
+            &format!("{receipt_path}/timeout").into(),
+        );
+
+        Ok(if is_timeout {
+            Receipt::Timeout
+        } else {
+            Receipt::Ok
+        })
     }
 
     fn get_packet_acknowledgement(
@@ -242,6 +313,109 @@ where
             })?)
     }
 
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError> {
+        Ok(self
+            .channel_upgrade_store
+            .get(StoreHeight::Pending, &upgrade_path.to_string().into())
+            .ok_or(ChannelError::UpgradeNotFound {
+                port_id: upgrade_path.0.clone(),
+                channel_id: upgrade_path.1.clone(),
+            })?)
+    }
+
+    fn counterparty_upgrade(
+        &self,
+        upgrade_path: &CounterpartyUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        Ok(self
+            .counterparty_upgrade_store
+            .get(StoreHeight::Pending, &upgrade_path.to_string().into())
+            .ok_or(ChannelError::CounterpartyUpgradeNotFound {
+                port_id: upgrade_path.0.clone(),
+                channel_id: upgrade_path.1.clone(),
+            })?)
+    }
+
+    fn channel_upgrade_error(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError> {
+        Ok(self
+            .upgrade_error_receipt_store
+            .get(StoreHeight::Pending, &upgrade_error_path.to_string().into())
+            .ok_or(ChannelError::UpgradeErrorReceiptNotFound {
+                port_id: upgrade_error_path.0.clone(),
+                channel_id: upgrade_error_path.1.clone(),
+            })?)
+    }
+
+    fn client_counterparty(
+        &self,
+        counterparty_path: &ClientCounterpartyPath,
+    ) -> Result<CounterpartyInfo, ContextError> {
+        Ok(self
+            .client_counterparty_store
+            .get(StoreHeight::Pending, &counterparty_path.to_string().into())
+            .ok_or(ClientError::CounterpartyNotFound {
+                client_id: counterparty_path.0.clone(),
+            })?)
+    }
+
+    fn get_next_sequence_send_v2(
+        &self,
+        seq_send_path: &SeqSendV2Path,
+    ) -> Result<Sequence, ContextError> {
+        Ok(self
+            .send_sequence_v2_store
+            .get(StoreHeight::Pending, &seq_send_path.to_string().into())
+            .ok_or(PacketError::ImplementationSpecific)?)
+    }
+
+    fn get_packet_commitment_v2(
+        &self,
+        commitment_path: &CommitmentV2Path,
+    ) -> Result<PacketCommitment, ContextError> {
+        Ok(self
+            .packet_commitment_v2_store
+            .get(StoreHeight::Pending, &commitment_path.to_string().into())
+            .ok_or(PacketError::PacketCommitmentNotFound {
+                sequence: commitment_path.sequence,
+            })?)
+    }
+
+    fn get_packet_receipt_v2(&self, receipt_path: &ReceiptV2Path) -> Result<Receipt, ContextError> {
+        if !self
+            .packet_receipt_v2_store
+            .is_path_set(StoreHeight::Pending, &receipt_path.to_string().into())
+        {
+            return Err(PacketError::PacketReceiptNotFound {
+                sequence: receipt_path.sequence,
+            }
+            .into());
+        }
+
+        Ok(Receipt::Ok)
+    }
+
+    fn get_packet_acknowledgement_v2(
+        &self,
+        ack_path: &AckV2Path,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        Ok(self
+            .packet_ack_v2_store
+            .get(StoreHeight::Pending, &ack_path.to_string().into())
+            .ok_or(PacketError::PacketAcknowledgementNotFound {
+                sequence: ack_path.sequence,
+            })?)
+    }
+
+    fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        Ok(!self.packet_commitments(channel_end_path)?.is_empty())
+    }
+
     /// Returns a counter of the number of channel ids that have been created thus far.
     /// The value of this counter should increase only via the
     /// `ChannelKeeper::increase_channel_counter` method.
@@ -505,6 +679,62 @@ where
             .collect::<Result<Vec<_>, _>>()
     }
 
+    /// Returns the requested page of the packet commitments associated with a
+    /// channel, loading only the commitments of that page.
+    fn paginated_packet_commitments(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        page_request: &PageRequest,
+    ) -> Result<Page<PacketState>, ContextError> {
+        let path = format!(
+            "commitments/ports/{}/channels/{}/sequences",
+            channel_end_path.0, channel_end_path.1
+        )
+        .try_into()
+        .map_err(|_| PacketError::Other {
+            description: "Invalid commitment path".into(),
+        })?;
+
+        let mut commitment_paths: Vec<_> = self
+            .packet_commitment_store
+            .get_keys(&path)
+            .into_iter()
+            .filter_map(|path| {
+                if let Ok(Path::Commitment(commitment_path)) = path.try_into() {
+                    Some(commitment_path)
+                } else {
+                    None
+                }
+            })
+            .filter(|commitment_path| {
+                self.packet_commitment_store
+                    .get(StoreHeight::Pending, commitment_path)
+                    .is_some()
+            })
+            .collect();
+        commitment_paths.sort_by_key(|commitment_path| commitment_path.sequence);
+
+        let (commitment_paths, page_response) = page_request
+            .paginate(commitment_paths.into_iter(), |commitment_path| {
+                commitment_path.sequence.to_vec()
+            });
+
+        let commitments = commitment_paths
+            .into_iter()
+            .map(|commitment_path| {
+                self.get_packet_commitment(&commitment_path)
+                    .map(|packet| PacketState {
+                        seq: commitment_path.sequence,
+                        port_id: commitment_path.port_id,
+                        chan_id: commitment_path.channel_id,
+                        data: packet.as_ref().into(),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((commitments, page_response))
+    }
+
     /// Returns the acknowledged packets associated with a channel.
     ///
     /// Takes a sequence list as an argument.
@@ -739,11 +969,16 @@ where
     fn store_packet_receipt(
         &mut self,
         receipt_path: &ReceiptPath,
-        _receipt: Receipt,
+        receipt: Receipt,
     ) -> Result<(), ContextError> {
         self.packet_receipt_store
             .set_path(receipt_path.clone())
             .map_err(|_| PacketError::ImplementationSpecific)?;
+        if let Receipt::Timeout = receipt {
+            self.packet_timeout_receipt_store
+                .set_path(format!("{receipt_path}/timeout").into())
+                .map_err(|_| PacketError::ImplementationSpecific)?;
+        }
         Ok(())
     }
 
@@ -809,6 +1044,129 @@ where
         Ok(())
     }
 
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.channel_upgrade_store
+            .set(upgrade_path.to_string().into(), upgrade)
+            .map_err(|_| ChannelError::Other {
+                description: "Channel upgrade store error".to_string(),
+            })?;
+        Ok(())
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.channel_upgrade_store
+            .delete(upgrade_path.to_string().into());
+        Ok(())
+    }
+
+    fn store_counterparty_upgrade(
+        &mut self,
+        upgrade_path: &CounterpartyUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.counterparty_upgrade_store
+            .set(upgrade_path.to_string().into(), upgrade)
+            .map_err(|_| ChannelError::Other {
+                description: "Counterparty upgrade store error".to_string(),
+            })?;
+        Ok(())
+    }
+
+    fn delete_counterparty_upgrade(
+        &mut self,
+        upgrade_path: &CounterpartyUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.counterparty_upgrade_store
+            .delete(upgrade_path.to_string().into());
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        self.upgrade_error_receipt_store
+            .set(upgrade_error_path.to_string().into(), error_receipt)
+            .map_err(|_| ChannelError::Other {
+                description: "Upgrade error receipt store error".to_string(),
+            })?;
+        Ok(())
+    }
+
+    fn store_client_counterparty(
+        &mut self,
+        counterparty_path: &ClientCounterpartyPath,
+        counterparty: CounterpartyInfo,
+    ) -> Result<(), ContextError> {
+        self.client_counterparty_store
+            .set(counterparty_path.to_string().into(), counterparty)
+            .map_err(|_| ClientError::Other {
+                description: "Client counterparty store error".to_string(),
+            })?;
+        Ok(())
+    }
+
+    fn store_next_sequence_send_v2(
+        &mut self,
+        seq_send_path: &SeqSendV2Path,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.send_sequence_v2_store
+            .set(seq_send_path.to_string().into(), seq)
+            .map_err(|_| PacketError::ImplementationSpecific)?;
+        Ok(())
+    }
+
+    fn store_packet_commitment_v2(
+        &mut self,
+        commitment_path: &CommitmentV2Path,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.packet_commitment_v2_store
+            .set(commitment_path.to_string().into(), commitment)
+            .map_err(|_| PacketError::ImplementationSpecific)?;
+        Ok(())
+    }
+
+    fn delete_packet_commitment_v2(
+        &mut self,
+        commitment_path: &CommitmentV2Path,
+    ) -> Result<(), ContextError> {
+        self.packet_commitment_v2_store
+            .delete(commitment_path.to_string().into());
+        Ok(())
+    }
+
+    fn store_packet_receipt_v2(
+        &mut self,
+        receipt_path: &ReceiptV2Path,
+        _receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.packet_receipt_v2_store
+            .set_path(receipt_path.to_string().into())
+            .map_err(|_| PacketError::ImplementationSpecific)?;
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement_v2(
+        &mut self,
+        ack_path: &AckV2Path,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.packet_ack_v2_store
+            .set(ack_path.to_string().into(), ack_commitment)
+            .map_err(|_| PacketError::ImplementationSpecific)?;
+        Ok(())
+    }
+
     fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
         let current_sequence = self
             .channel_counter
@@ -836,3 +1194,85 @@ where
         Ok(())
     }
 }
+
+impl<S> CachedExecutionContext for MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    fn begin_cache(&mut self) {
+        let snapshot = self.snapshot();
+        self.caches.lock().push(snapshot);
+    }
+
+    fn commit_cache(&mut self) -> Result<(), ContextError> {
+        // the writes are already made to the store
+        self.caches.lock().pop();
+        Ok(())
+    }
+
+    fn discard_cache(&mut self) {
+        let snapshot = self.caches.lock().pop();
+
+        if let Some(snapshot) = snapshot {
+            self.restore(snapshot);
+        }
+    }
+
+    fn cached_changes(&self) -> Vec<StateChange> {
+        let caches = self.caches.lock();
+        let Some(cache) = caches.last() else {
+            return Vec::new();
+        };
+
+        let entries = self.store_entries();
+
+        let deleted = cache
+            .entries
+            .keys()
+            .filter(|path| !entries.contains_key(*path))
+            .filter_map(|path| {
+                Some(StateChange::Delete {
+                    path: Path::from_str(path).ok()?,
+                })
+            });
+
+        let written = entries
+            .iter()
+            .filter(|(path, value)| cache.entries.get(*path) != Some(value))
+            .filter_map(|(path, value)| {
+                let path = Path::from_str(path).ok()?;
+                // the paths `read_store` does not serve are reported as stored
+                let value = self
+                    .read_store(&path)
+                    .ok()
+                    .flatten()
+                    .unwrap_or_else(|| value.clone());
+                Some(StateChange::Write { path, value })
+            });
+
+        let mut changes: Vec<_> = deleted.chain(written).collect();
+        changes.sort_by_key(|change| change.path().to_string());
+
+        changes
+    }
+
+    fn cached_events(&self) -> Vec<IbcEvent> {
+        let start = self
+            .caches
+            .lock()
+            .last()
+            .map_or(0, |cache| cache.events.len());
+
+        self.events.lock()[start..].to_vec()
+    }
+
+    fn cached_logs(&self) -> Vec<String> {
+        let start = self
+            .caches
+            .lock()
+            .last()
+            .map_or(0, |cache| cache.logs.len());
+
+        self.logs.lock()[start..].to_vec()
+    }
+}
@@ -4,6 +4,7 @@ use ibc::core::host::types::identifiers::PortId;
 use ibc::core::router::module::Module;
 use ibc::core::router::router::Router;
 use ibc::core::router::types::module::ModuleId;
+use ibc::core::router::v2::PayloadModule;
 
 use super::types::MockRouter;
 
@@ -30,4 +31,18 @@ impl Router for MockRouter {
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
         self.port_to_module.get(port_id).cloned()
     }
+
+    fn get_payload_route(&self, port_id: &PortId) -> Option<&dyn PayloadModule> {
+        self.payload_router.get(port_id).map(Arc::as_ref)
+    }
+
+    fn get_payload_route_mut(&mut self, port_id: &PortId) -> Option<&mut dyn PayloadModule> {
+        match self.payload_router.get_mut(port_id) {
+            Some(arc_mod) => match Arc::get_mut(arc_mod) {
+                Some(m) => Some(m),
+                None => None,
+            },
+            None => None,
+        }
+    }
 }
@@ -0,0 +1,109 @@
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::error::{ChannelError, PacketError};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::middleware::{Ics4Wrapper, Middleware};
+use ibc::core::router::module::Module;
+
+/// The prefix [`DummyMiddleware`] adds to channel versions and
+/// acknowledgements.
+pub const DUMMY_MIDDLEWARE_PREFIX: &str = "dummy-middleware:";
+
+/// A middleware that prefixes the versions and the acknowledgements of the
+/// module it wraps with [`DUMMY_MIDDLEWARE_PREFIX`], and forwards every
+/// callback to it.
+#[derive(Debug)]
+pub struct DummyMiddleware<M> {
+    inner: M,
+}
+
+impl<M> DummyMiddleware<M>
+where
+    M: Module,
+{
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<M> Middleware for DummyMiddleware<M>
+where
+    M: Module,
+{
+    type Inner = M;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    fn inner_mut(&mut self) -> &mut Self::Inner {
+        &mut self.inner
+    }
+
+    fn wrap_version(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        app_version: Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(Version::new(format!(
+            "{DUMMY_MIDDLEWARE_PREFIX}{app_version}"
+        )))
+    }
+
+    fn unwrap_version(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        version
+            .as_str()
+            .strip_prefix(DUMMY_MIDDLEWARE_PREFIX)
+            .map(|app_version| Version::new(app_version.to_string()))
+            .ok_or_else(|| ChannelError::AppModule {
+                description: format!("version `{version}` is not wrapped by the dummy middleware"),
+            })
+    }
+
+    fn wrap_acknowledgement(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+        app_acknowledgement: Acknowledgement,
+    ) -> Acknowledgement {
+        prefix_acknowledgement(app_acknowledgement)
+    }
+
+    fn unwrap_acknowledgement(
+        &self,
+        _packet: &Packet,
+        acknowledgement: &Acknowledgement,
+    ) -> Result<Acknowledgement, PacketError> {
+        acknowledgement
+            .as_bytes()
+            .strip_prefix(DUMMY_MIDDLEWARE_PREFIX.as_bytes())
+            .and_then(|app_acknowledgement| app_acknowledgement.to_vec().try_into().ok())
+            .ok_or(PacketError::InvalidAcknowledgement)
+    }
+
+    fn write_acknowledgement(
+        &self,
+        next: &mut dyn Ics4Wrapper,
+        packet: &Packet,
+        app_acknowledgement: Acknowledgement,
+    ) -> Result<(), ContextError> {
+        next.write_acknowledgement(packet, prefix_acknowledgement(app_acknowledgement))
+    }
+}
+
+fn prefix_acknowledgement(app_acknowledgement: Acknowledgement) -> Acknowledgement {
+    let mut bytes = DUMMY_MIDDLEWARE_PREFIX.as_bytes().to_vec();
+    bytes.extend_from_slice(app_acknowledgement.as_bytes());
+
+    bytes.try_into().expect("Never fails")
+}
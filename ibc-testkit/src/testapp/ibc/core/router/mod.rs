@@ -1,4 +1,8 @@
 mod context;
+mod middleware;
+mod payload;
 mod types;
 
+pub use self::middleware::*;
+pub use self::payload::*;
 pub use self::types::*;
@@ -0,0 +1,134 @@
+use ibc::core::channel::types::error::PacketError;
+use ibc::core::channel::types::v2::packet::Payload;
+use ibc::core::host::types::identifiers::{ClientId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::types::event::ModuleEvent;
+use ibc::core::router::types::module::ModuleExtras;
+use ibc::core::router::v2::{PayloadModule, RecvPayloadResult};
+
+/// The kind of the events [`DummyPayloadModule`] emits on each callback.
+pub const DUMMY_PAYLOAD_EVENT: &str = "dummy_payload";
+
+/// The application acknowledgement of the payloads [`DummyPayloadModule`]
+/// receives.
+pub const DUMMY_PAYLOAD_ACK: &[u8] = b"dummy-payload-ack";
+
+/// An IBC v2 application that accepts every payload, and emits an event
+/// naming each callback it is called with.
+#[derive(Debug, Default)]
+pub struct DummyPayloadModule {
+    /// Whether receiving a payload fails.
+    pub fail_recv: bool,
+}
+
+impl DummyPayloadModule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a module failing to receive every payload.
+    pub fn failing_recv() -> Self {
+        Self { fail_recv: true }
+    }
+
+    fn extras(callback: &str, seq_on_a: Sequence) -> ModuleExtras {
+        ModuleExtras {
+            events: vec![ModuleEvent {
+                kind: DUMMY_PAYLOAD_EVENT.to_string(),
+                attributes: vec![
+                    ("callback", callback).into(),
+                    ("sequence", seq_on_a.to_string()).into(),
+                ],
+            }],
+            log: Vec::new(),
+        }
+    }
+}
+
+impl PayloadModule for DummyPayloadModule {
+    fn on_send_payload_validate(
+        &self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        _seq_on_a: Sequence,
+        _payload: &Payload,
+        _signer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_send_payload_execute(
+        &mut self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        seq_on_a: Sequence,
+        _payload: &Payload,
+        _signer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (Self::extras("send", seq_on_a), Ok(()))
+    }
+
+    fn on_recv_payload_execute(
+        &mut self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        seq_on_a: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, RecvPayloadResult) {
+        let result = if self.fail_recv {
+            RecvPayloadResult::Failure
+        } else {
+            RecvPayloadResult::Success(DUMMY_PAYLOAD_ACK.to_vec())
+        };
+
+        (Self::extras("recv", seq_on_a), result)
+    }
+
+    fn on_acknowledgement_payload_validate(
+        &self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        _seq_on_a: Sequence,
+        _payload: &Payload,
+        _acknowledgement: &[u8],
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_payload_execute(
+        &mut self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        seq_on_a: Sequence,
+        _payload: &Payload,
+        _acknowledgement: &[u8],
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (Self::extras("acknowledgement", seq_on_a), Ok(()))
+    }
+
+    fn on_timeout_payload_validate(
+        &self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        _seq_on_a: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_payload_execute(
+        &mut self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        seq_on_a: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (Self::extras("timeout", seq_on_a), Ok(()))
+    }
+}
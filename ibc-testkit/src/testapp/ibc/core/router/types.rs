@@ -5,6 +5,7 @@ use ibc::core::host::types::identifiers::PortId;
 use ibc::core::primitives::prelude::*;
 use ibc::core::router::module::Module;
 use ibc::core::router::types::module::ModuleId;
+use ibc::core::router::v2::PayloadModule;
 
 use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
 
@@ -14,6 +15,9 @@ pub struct MockRouter {
 
     /// Maps ports to the the module that owns it
     pub port_to_module: BTreeMap<PortId, ModuleId>,
+
+    /// Maps ports to the IBC v2 application bound to it
+    pub payload_router: BTreeMap<PortId, Arc<dyn PayloadModule>>,
 }
 
 impl MockRouter {
@@ -44,6 +48,17 @@ impl MockRouter {
         }
     }
 
+    pub fn add_payload_route(
+        &mut self,
+        port_id: PortId,
+        module: impl PayloadModule + 'static,
+    ) -> Result<(), String> {
+        match self.payload_router.insert(port_id, Arc::new(module)) {
+            None => Ok(()),
+            Some(_) => Err("Duplicate port_id".to_owned()),
+        }
+    }
+
     pub fn scope_port_to_module(&mut self, port_id: PortId, module_id: ModuleId) {
         self.port_to_module.insert(port_id, module_id);
     }
@@ -1,18 +1,25 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use core::fmt::Debug;
 
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::impls::SharedStore;
-use basecoin_store::types::{BinStore, JsonStore, ProtobufStore, TypedSet, TypedStore};
+use basecoin_store::types::{
+    BinStore, Height as StoreHeight, JsonStore, Path as StorePath, ProtobufStore, TypedSet,
+    TypedStore,
+};
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
 use ibc::core::client::context::client_state::ClientStateValidation;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::raw::CounterpartyInfo as RawCounterpartyInfo;
+use ibc::core::client::types::{CounterpartyInfo, Height};
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc::core::handler::types::metering::CostEvent;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
     ClientUpdateHeightPath, ClientUpdateTimePath, CommitmentPath, ConnectionPath,
@@ -22,7 +29,9 @@ use ibc::core::host::types::path::{
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
 use ibc_proto::google::protobuf::Any;
-use ibc_proto::ibc::core::channel::v1::Channel as RawChannelEnd;
+use ibc_proto::ibc::core::channel::v1::{
+    Channel as RawChannelEnd, ErrorReceipt as RawErrorReceipt, Upgrade as RawUpgrade,
+};
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 use ibc_proto::ibc::core::connection::v1::ConnectionEnd as RawConnectionEnd;
 use ibc_proto::ics23::CommitmentProof;
@@ -33,6 +42,7 @@ use crate::context::{MockStore, TestContext};
 use crate::fixtures::core::context::TestContextConfig;
 use crate::hosts::{HostClientState, TestBlock, TestHeader, TestHost};
 use crate::testapp::ibc::clients::mock::header::MockHeader;
+use crate::testapp::ibc::clients::wasm::MockWasmVm;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 pub const DEFAULT_BLOCK_TIME_SECS: u64 = 3;
 
@@ -66,6 +76,9 @@ where
     /// A typed-store for AnyConsensusState
     pub consensus_state_store:
         ProtobufStore<SharedStore<S>, ClientConsensusStatePath, AnyConsensusState, Any>,
+    /// The heights of the consensus states of each client, for them to be
+    /// iterated in ascending order
+    pub consensus_state_heights: Arc<Mutex<BTreeMap<ClientId, BTreeSet<Height>>>>,
     /// A typed-store for ConnectionEnd
     pub connection_end_store:
         ProtobufStore<SharedStore<S>, ConnectionPath, ConnectionEnd, RawConnectionEnd>,
@@ -83,8 +96,51 @@ where
     pub packet_commitment_store: BinStore<SharedStore<S>, CommitmentPath, PacketCommitment>,
     /// A typed-store for packet receipts
     pub packet_receipt_store: TypedSet<SharedStore<S>, ReceiptPath>,
+    /// A typed-set of the packet receipts that record a timeout, keyed by the
+    /// [`ReceiptPath`] suffixed with `/timeout`
+    pub packet_timeout_receipt_store: TypedSet<SharedStore<S>, StorePath>,
     /// A typed-store for packet ack
     pub packet_ack_store: BinStore<SharedStore<S>, AckPath, AcknowledgementCommitment>,
+    /// A typed-store for channel upgrades, keyed by [`ChannelUpgradePath`]
+    ///
+    /// [`ChannelUpgradePath`]: ibc::core::host::types::path::ChannelUpgradePath
+    pub channel_upgrade_store: ProtobufStore<SharedStore<S>, StorePath, Upgrade, RawUpgrade>,
+    /// A typed-store for counterparty upgrades, keyed by [`CounterpartyUpgradePath`]
+    ///
+    /// [`CounterpartyUpgradePath`]: ibc::core::host::types::path::CounterpartyUpgradePath
+    pub counterparty_upgrade_store: ProtobufStore<SharedStore<S>, StorePath, Upgrade, RawUpgrade>,
+    /// A typed-store for upgrade error receipts, keyed by [`ChannelUpgradeErrorPath`]
+    ///
+    /// [`ChannelUpgradeErrorPath`]: ibc::core::host::types::path::ChannelUpgradeErrorPath
+    pub upgrade_error_receipt_store:
+        ProtobufStore<SharedStore<S>, StorePath, ErrorReceipt, RawErrorReceipt>,
+    /// A typed-store for the counterparties of the clients, keyed by
+    /// [`ClientCounterpartyPath`]
+    ///
+    /// [`ClientCounterpartyPath`]: ibc::core::host::types::path::ClientCounterpartyPath
+    pub client_counterparty_store:
+        ProtobufStore<SharedStore<S>, StorePath, CounterpartyInfo, RawCounterpartyInfo>,
+    /// A typed-store for IBC v2 send sequences, keyed by [`SeqSendV2Path`]
+    ///
+    /// [`SeqSendV2Path`]: ibc::core::host::types::path::SeqSendV2Path
+    pub send_sequence_v2_store: JsonStore<SharedStore<S>, StorePath, Sequence>,
+    /// A typed-store for IBC v2 packet commitments, keyed by [`CommitmentV2Path`]
+    ///
+    /// [`CommitmentV2Path`]: ibc::core::host::types::path::CommitmentV2Path
+    pub packet_commitment_v2_store: BinStore<SharedStore<S>, StorePath, PacketCommitment>,
+    /// A typed-set of the IBC v2 packet receipts, keyed by [`ReceiptV2Path`]
+    ///
+    /// [`ReceiptV2Path`]: ibc::core::host::types::path::ReceiptV2Path
+    pub packet_receipt_v2_store: TypedSet<SharedStore<S>, StorePath>,
+    /// A typed-store for IBC v2 packet acks, keyed by [`AckV2Path`]
+    ///
+    /// [`AckV2Path`]: ibc::core::host::types::path::AckV2Path
+    pub packet_ack_v2_store: BinStore<SharedStore<S>, StorePath, AcknowledgementCommitment>,
+    /// A typed-set of the checksums of the byte code of 08-wasm contracts,
+    /// keyed by their hex encoding under `wasm/checksums`
+    pub wasm_checksum_store: TypedSet<SharedStore<S>, StorePath>,
+    /// The VM running the contracts of 08-wasm clients
+    pub wasm_vm: MockWasmVm,
     /// Map of host consensus states
     pub host_consensus_states: Arc<Mutex<BTreeMap<u64, AnyConsensusState>>>,
     /// Map of older ibc commitment proofs
@@ -93,6 +149,35 @@ where
     pub events: Arc<Mutex<Vec<IbcEvent>>>,
     /// message logs
     pub logs: Arc<Mutex<Vec<String>>>,
+    /// The headers verified during the current block, keyed by client,
+    /// header hash and trusted height
+    pub verified_headers: Arc<Mutex<BTreeSet<(ClientId, Vec<u8>, Height)>>>,
+    /// The cost events charged while handling messages, in order
+    pub cost_events: Arc<Mutex<Vec<CostEvent>>>,
+    /// The snapshots of the state taken when each of the open caches was
+    /// opened, innermost last
+    pub caches: Arc<Mutex<Vec<MockCache>>>,
+}
+
+/// The state of a [`MockIbcStore`] when a cache was opened, which it is
+/// restored to if the cache is discarded.
+///
+/// The writes of the cache are made to the store itself: they are the
+/// difference between the snapshot and the current state. The snapshot
+/// covers every mutable field of the context but the `cost_events` and the
+/// calls recorded by the `wasm_vm`, which report the resources consumed and
+/// the work done, whether or not it is rolled back.
+#[derive(Debug)]
+pub struct MockCache {
+    /// The entries of the store, keyed by path
+    pub entries: BTreeMap<String, Vec<u8>>,
+    pub revision_number: u64,
+    pub consensus_state_heights: BTreeMap<ClientId, BTreeSet<Height>>,
+    pub host_consensus_states: BTreeMap<u64, AnyConsensusState>,
+    pub ibc_commitment_proofs: BTreeMap<u64, CommitmentProof>,
+    pub verified_headers: BTreeSet<(ClientId, Vec<u8>, Height)>,
+    pub events: Vec<IbcEvent>,
+    pub logs: Vec<String>,
 }
 
 impl<S> MockIbcStore<S>
@@ -129,6 +214,7 @@ where
             ibc_commiment_proofs: Arc::new(Mutex::new(Default::default())),
             client_state_store: TypedStore::new(shared_store.clone()),
             consensus_state_store: TypedStore::new(shared_store.clone()),
+            consensus_state_heights: Arc::new(Mutex::new(BTreeMap::new())),
             connection_end_store: TypedStore::new(shared_store.clone()),
             connection_ids_store: TypedStore::new(shared_store.clone()),
             channel_end_store: TypedStore::new(shared_store.clone()),
@@ -137,9 +223,23 @@ where
             ack_sequence_store: TypedStore::new(shared_store.clone()),
             packet_commitment_store: TypedStore::new(shared_store.clone()),
             packet_receipt_store: TypedStore::new(shared_store.clone()),
+            packet_timeout_receipt_store: TypedStore::new(shared_store.clone()),
             packet_ack_store: TypedStore::new(shared_store.clone()),
+            channel_upgrade_store: TypedStore::new(shared_store.clone()),
+            counterparty_upgrade_store: TypedStore::new(shared_store.clone()),
+            upgrade_error_receipt_store: TypedStore::new(shared_store.clone()),
+            client_counterparty_store: TypedStore::new(shared_store.clone()),
+            send_sequence_v2_store: TypedStore::new(shared_store.clone()),
+            packet_commitment_v2_store: TypedStore::new(shared_store.clone()),
+            packet_receipt_v2_store: TypedStore::new(shared_store.clone()),
+            packet_ack_v2_store: TypedStore::new(shared_store.clone()),
+            wasm_checksum_store: TypedStore::new(shared_store.clone()),
+            wasm_vm: MockWasmVm::default(),
             events: Arc::new(Mutex::new(Vec::new())),
             logs: Arc::new(Mutex::new(Vec::new())),
+            verified_headers: Arc::new(Mutex::new(BTreeSet::new())),
+            cost_events: Arc::new(Mutex::new(Vec::new())),
+            caches: Arc::new(Mutex::new(Vec::new())),
             store: shared_store,
         }
     }
@@ -163,12 +263,66 @@ where
         assert_eq!(self.store.current_height(), height);
         self.store_host_consensus_state(height, consensus_state);
         self.store_ibc_commitment_proof(height, proof);
+        self.verified_headers.lock().clear();
     }
 
     pub fn end_block(&mut self) -> Result<Vec<u8>, <SharedStore<S> as Store>::Error> {
         self.store.commit()
     }
 
+    /// Returns every entry of the store, keyed by path.
+    pub fn store_entries(&self) -> BTreeMap<String, Vec<u8>> {
+        self.store
+            .get_keys(&String::new().into())
+            .into_iter()
+            .filter_map(|path| {
+                let value = self.store.get(StoreHeight::Pending, &path)?;
+                Some((path.to_string(), value))
+            })
+            .collect()
+    }
+
+    /// Takes a snapshot of the state, for it to be restored if the cache
+    /// opened along with it is discarded.
+    pub fn snapshot(&self) -> MockCache {
+        MockCache {
+            entries: self.store_entries(),
+            revision_number: *self.revision_number.lock(),
+            consensus_state_heights: self.consensus_state_heights.lock().clone(),
+            host_consensus_states: self.host_consensus_states.lock().clone(),
+            ibc_commitment_proofs: self.ibc_commiment_proofs.lock().clone(),
+            verified_headers: self.verified_headers.lock().clone(),
+            events: self.events.lock().clone(),
+            logs: self.logs.lock().clone(),
+        }
+    }
+
+    /// Restores the state to the given snapshot, undoing the writes, events
+    /// and logs made since it was taken, whether to the store or in memory.
+    pub fn restore(&mut self, snapshot: MockCache) {
+        let entries = self.store_entries();
+
+        for path in entries.keys() {
+            if !snapshot.entries.contains_key(path) {
+                self.store.delete(&path.clone().into());
+            }
+        }
+
+        for (path, value) in snapshot.entries {
+            if entries.get(&path) != Some(&value) {
+                self.store.set(path.into(), value).expect("no error");
+            }
+        }
+
+        *self.revision_number.lock() = snapshot.revision_number;
+        *self.consensus_state_heights.lock() = snapshot.consensus_state_heights;
+        *self.host_consensus_states.lock() = snapshot.host_consensus_states;
+        *self.ibc_commiment_proofs.lock() = snapshot.ibc_commitment_proofs;
+        *self.verified_headers.lock() = snapshot.verified_headers;
+        *self.events.lock() = snapshot.events;
+        *self.logs.lock() = snapshot.logs;
+    }
+
     pub fn prune_host_consensus_states_till(&self, height: &Height) {
         assert!(height.revision_number() == *self.revision_number.lock());
         let mut history = self.host_consensus_states.lock();
@@ -276,12 +430,12 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
+            ) -> (ModuleExtras, Option<Acknowledgement>) {
                 self.counter += 1;
 
                 (
                     ModuleExtras::empty(),
-                    Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    Some(Acknowledgement::try_from(vec![1u8]).expect("Never fails")),
                 )
             }
 
@@ -376,10 +530,10 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
+            ) -> (ModuleExtras, Option<Acknowledgement>) {
                 (
                     ModuleExtras::empty(),
-                    Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    Some(Acknowledgement::try_from(vec![1u8]).expect("Never fails")),
                 )
             }
 
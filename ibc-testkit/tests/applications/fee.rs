@@ -0,0 +1,227 @@
+use ibc::apps::fee::module::FeeMiddleware;
+use ibc::apps::fee::types::transfer::{Amount, PrefixedCoin};
+use ibc::apps::fee::types::{Fee, IncentivizedAcknowledgement, Metadata, PacketFee, PacketId};
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::module::Module;
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use ibc_testkit::testapp::ibc::applications::fee::types::MockFeeContext;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use rstest::*;
+
+type DummyFeeModule = FeeMiddleware<MockFeeContext, DummyTransferModule>;
+
+fn coins(amount: u64) -> Vec<PrefixedCoin> {
+    vec![PrefixedCoin {
+        denom: "stake".parse().unwrap(),
+        amount: Amount::from(amount),
+    }]
+}
+
+fn signer(address: &str) -> Signer {
+    address.to_string().into()
+}
+
+#[fixture]
+fn packet() -> Packet {
+    PacketConfig::builder().build()
+}
+
+/// A fee middleware with fees enabled on the channel of the dummy packet.
+#[fixture]
+fn fee_module(packet: Packet) -> DummyFeeModule {
+    let ctx = MockFeeContext::new().with_fee_enabled(packet.port_id_on_a, packet.chan_id_on_a);
+
+    FeeMiddleware::new(ctx, DummyTransferModule::new())
+}
+
+fn store_packet_fee(fee_module: &mut DummyFeeModule, packet: &Packet) {
+    let packet_id = PacketId::new(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        packet.seq_on_a,
+    );
+    let packet_fee = PacketFee::new(
+        Fee::new(coins(10), coins(5), coins(7)),
+        signer("cosmos1refund"),
+    );
+
+    fee_module
+        .ctx_mut()
+        .packet_fees
+        .insert(packet_id, vec![packet_fee]);
+}
+
+#[test]
+fn test_open_init_wraps_app_version() {
+    let mut fee_module = FeeMiddleware::new(MockFeeContext::new(), DummyTransferModule::new());
+
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::new(1);
+    let counterparty = Counterparty::new(port_id.clone(), None);
+    let connection_hops = vec![ConnectionId::new(0)];
+
+    let app_version = Version::new("ics20-1".to_string());
+    let fee_version = Metadata::new(app_version.clone()).to_version();
+
+    let (_, version) = fee_module
+        .on_chan_open_init_execute(
+            Order::Unordered,
+            &connection_hops,
+            &port_id,
+            &channel_id,
+            &counterparty,
+            &fee_version,
+        )
+        .unwrap();
+
+    assert_eq!(version, fee_version);
+    assert!(fee_module
+        .ctx()
+        .fee_enabled_channels
+        .contains(&(port_id.clone(), channel_id.clone())));
+
+    // A channel opened without the fee metadata is passed through.
+    let other_channel_id = ChannelId::new(2);
+    let (_, version) = fee_module
+        .on_chan_open_init_execute(
+            Order::Unordered,
+            &connection_hops,
+            &port_id,
+            &other_channel_id,
+            &counterparty,
+            &app_version,
+        )
+        .unwrap();
+
+    assert_eq!(version, app_version);
+    assert!(!fee_module
+        .ctx()
+        .fee_enabled_channels
+        .contains(&(port_id.clone(), other_channel_id)));
+
+    // An unsupported fee version is rejected.
+    let unsupported_version =
+        Version::new(r#"{"fee_version":"ics29-2","app_version":"ics20-1"}"#.to_string());
+    let res = fee_module.on_chan_open_init_validate(
+        Order::Unordered,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &unsupported_version,
+    );
+
+    assert!(res.is_err());
+}
+
+#[rstest]
+fn test_recv_packet_wraps_ack(mut fee_module: DummyFeeModule, packet: Packet) {
+    let relayer = signer("cosmos1relayer");
+    fee_module.ctx_mut().counterparty_payees.insert(
+        (relayer.clone(), packet.chan_id_on_b.clone()),
+        signer("cosmos1forward"),
+    );
+
+    let (_, ack) = fee_module.on_recv_packet_execute(&packet, &relayer);
+    let ack = IncentivizedAcknowledgement::try_from(&ack.unwrap()).unwrap();
+
+    assert_eq!(ack.app_acknowledgement.as_bytes(), &[1u8]);
+    assert_eq!(ack.forward_relayer_address, signer("cosmos1forward"));
+    assert!(ack.underlying_app_success);
+}
+
+#[rstest]
+fn test_ack_distributes_fees(mut fee_module: DummyFeeModule, packet: Packet) {
+    store_packet_fee(&mut fee_module, &packet);
+
+    let relayer = signer("cosmos1relayer");
+    fee_module.ctx_mut().payees.insert(
+        (relayer.clone(), packet.chan_id_on_a.clone()),
+        signer("cosmos1payee"),
+    );
+
+    let ack: Acknowledgement = IncentivizedAcknowledgement {
+        app_acknowledgement: vec![1u8].try_into().unwrap(),
+        forward_relayer_address: signer("cosmos1forward"),
+        underlying_app_success: true,
+    }
+    .into();
+
+    fee_module
+        .on_acknowledgement_packet_validate(&packet, &ack, &relayer)
+        .unwrap();
+
+    let (extras, res) = fee_module.on_acknowledgement_packet_execute(&packet, &ack, &relayer);
+
+    assert!(res.is_ok());
+    assert_eq!(extras.events.len(), 3);
+    assert_eq!(
+        fee_module.ctx().distributed,
+        vec![
+            (signer("cosmos1forward"), coins(10)),
+            (signer("cosmos1payee"), coins(5)),
+            (signer("cosmos1refund"), coins(2)),
+        ]
+    );
+    assert!(fee_module.ctx().packet_fees.is_empty());
+}
+
+#[rstest]
+fn test_ack_without_forward_relayer_refunds_recv_fee(
+    mut fee_module: DummyFeeModule,
+    packet: Packet,
+) {
+    store_packet_fee(&mut fee_module, &packet);
+
+    let relayer = signer("cosmos1relayer");
+    let ack: Acknowledgement = IncentivizedAcknowledgement {
+        app_acknowledgement: vec![1u8].try_into().unwrap(),
+        forward_relayer_address: signer(""),
+        underlying_app_success: true,
+    }
+    .into();
+
+    let (_, res) = fee_module.on_acknowledgement_packet_execute(&packet, &ack, &relayer);
+
+    assert!(res.is_ok());
+    assert_eq!(
+        fee_module.ctx().distributed,
+        vec![
+            (signer("cosmos1refund"), coins(10)),
+            (relayer, coins(5)),
+            (signer("cosmos1refund"), coins(2)),
+        ]
+    );
+}
+
+#[rstest]
+fn test_ack_rejects_plain_ack_on_fee_channel(fee_module: DummyFeeModule, packet: Packet) {
+    let ack = vec![1u8].try_into().unwrap();
+    let relayer = signer("cosmos1relayer");
+
+    let res = fee_module.on_acknowledgement_packet_validate(&packet, &ack, &relayer);
+
+    assert!(res.is_err());
+}
+
+#[rstest]
+fn test_timeout_distributes_fees(mut fee_module: DummyFeeModule, packet: Packet) {
+    store_packet_fee(&mut fee_module, &packet);
+
+    let relayer = signer("cosmos1relayer");
+    let (extras, res) = fee_module.on_timeout_packet_execute(&packet, &relayer);
+
+    assert!(res.is_ok());
+    assert_eq!(extras.events.len(), 2);
+    assert_eq!(
+        fee_module.ctx().distributed,
+        vec![(relayer, coins(7)), (signer("cosmos1refund"), coins(10))]
+    );
+    assert!(fee_module.ctx().packet_fees.is_empty());
+}
@@ -0,0 +1,264 @@
+use ibc::apps::interchain_accounts::module::{ControllerModule, HostModule};
+use ibc::apps::interchain_accounts::types::{
+    controller_port_id, host_port_id, CosmosTx, InterchainAccountPacketData, Metadata,
+};
+use ibc::core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::Any;
+use ibc::core::primitives::Signer;
+use ibc::core::router::module::Module;
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use ibc_testkit::testapp::ibc::applications::interchain_accounts::types::{
+    MockControllerContext, MockHostContext,
+};
+use rstest::*;
+
+const MSG_SEND_TYPE_URL: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+fn owner() -> Signer {
+    "cosmos1owner".to_string().into()
+}
+
+fn controller_port() -> PortId {
+    controller_port_id(&owner()).unwrap()
+}
+
+fn controller_channel() -> ChannelId {
+    ChannelId::new(0)
+}
+
+fn host_channel() -> ChannelId {
+    ChannelId::new(1)
+}
+
+fn controller_connection() -> ConnectionId {
+    ConnectionId::new(0)
+}
+
+fn host_connection() -> ConnectionId {
+    ConnectionId::new(1)
+}
+
+fn default_version() -> Version {
+    Metadata::new(controller_connection(), host_connection()).to_version()
+}
+
+#[fixture]
+fn controller_module() -> ControllerModule<MockControllerContext> {
+    let ctx = MockControllerContext::new().with_channel(
+        controller_port(),
+        controller_channel(),
+        controller_connection(),
+    );
+
+    ControllerModule::new(ctx)
+}
+
+#[fixture]
+fn host_module() -> HostModule<MockHostContext> {
+    let ctx = MockHostContext::new()
+        .with_channel(host_port_id(), host_channel(), host_connection())
+        .with_allowed_message(MSG_SEND_TYPE_URL);
+
+    HostModule::new(ctx)
+}
+
+/// Runs the host side of the handshake and returns the version chosen by
+/// the host.
+fn open_try(host_module: &mut HostModule<MockHostContext>) -> Version {
+    let (_, version) = host_module
+        .on_chan_open_try_execute(
+            Order::Ordered,
+            &[host_connection()],
+            &host_port_id(),
+            &host_channel(),
+            &Counterparty::new(controller_port(), Some(controller_channel())),
+            &default_version(),
+        )
+        .unwrap();
+
+    version
+}
+
+fn execute_tx_packet(type_url: &str) -> Packet {
+    let tx = CosmosTx::new(vec![Any {
+        type_url: type_url.to_string(),
+        value: vec![1, 2, 3],
+    }]);
+    let packet_data = InterchainAccountPacketData::execute_tx(tx, String::new());
+
+    PacketConfig::builder()
+        .port_id_on_a(controller_port())
+        .chan_id_on_a(controller_channel())
+        .port_id_on_b(host_port_id())
+        .chan_id_on_b(host_channel())
+        .data(serde_json::to_vec(&packet_data).unwrap())
+        .build()
+}
+
+#[rstest]
+fn test_controller_open_init(controller_module: ControllerModule<MockControllerContext>) {
+    let counterparty = Counterparty::new(host_port_id(), None);
+
+    let version = controller_module
+        .on_chan_open_init_validate(
+            Order::Ordered,
+            &[controller_connection()],
+            &controller_port(),
+            &controller_channel(),
+            &counterparty,
+            &default_version(),
+        )
+        .unwrap();
+
+    assert_eq!(version, default_version());
+
+    // channels must be ordered or unordered
+    assert!(controller_module
+        .on_chan_open_init_validate(
+            Order::None,
+            &[controller_connection()],
+            &controller_port(),
+            &controller_channel(),
+            &counterparty,
+            &default_version(),
+        )
+        .is_err());
+
+    // the counterparty must be the host port
+    assert!(controller_module
+        .on_chan_open_init_validate(
+            Order::Ordered,
+            &[controller_connection()],
+            &controller_port(),
+            &controller_channel(),
+            &Counterparty::new(PortId::transfer(), None),
+            &default_version(),
+        )
+        .is_err());
+
+    // the metadata must refer to the channel connection
+    assert!(controller_module
+        .on_chan_open_init_validate(
+            Order::Ordered,
+            &[host_connection()],
+            &controller_port(),
+            &controller_channel(),
+            &counterparty,
+            &default_version(),
+        )
+        .is_err());
+}
+
+#[rstest]
+fn test_host_open_try_creates_account(mut host_module: HostModule<MockHostContext>) {
+    let version = open_try(&mut host_module);
+
+    let metadata = Metadata::from_version(&version).unwrap();
+    let address = host_module
+        .ctx()
+        .interchain_accounts
+        .get(&(host_connection(), controller_port()))
+        .cloned()
+        .unwrap();
+
+    assert_eq!(metadata.address, address);
+    assert_eq!(
+        host_module
+            .ctx()
+            .active_channels
+            .get(&(host_connection(), controller_port())),
+        Some(&host_channel())
+    );
+
+    // reopening the channel reuses the existing account
+    let version = open_try(&mut host_module);
+
+    assert_eq!(Metadata::from_version(&version).unwrap().address, address);
+    assert_eq!(host_module.ctx().interchain_accounts.len(), 1);
+}
+
+#[rstest]
+fn test_controller_open_ack_stores_account(
+    mut controller_module: ControllerModule<MockControllerContext>,
+    mut host_module: HostModule<MockHostContext>,
+) {
+    // the host must return the address of the account
+    assert!(controller_module
+        .on_chan_open_ack_validate(
+            &controller_port(),
+            &controller_channel(),
+            &default_version()
+        )
+        .is_err());
+
+    let version = open_try(&mut host_module);
+
+    controller_module
+        .on_chan_open_ack_execute(&controller_port(), &controller_channel(), &version)
+        .unwrap();
+
+    let ctx = controller_module.ctx();
+
+    assert_eq!(
+        ctx.interchain_accounts
+            .get(&(controller_connection(), controller_port())),
+        Some(&Metadata::from_version(&version).unwrap().address)
+    );
+    assert_eq!(
+        ctx.active_channels
+            .get(&(controller_connection(), controller_port())),
+        Some(&controller_channel())
+    );
+}
+
+#[rstest]
+fn test_host_recv_packet_executes_tx(mut host_module: HostModule<MockHostContext>) {
+    open_try(&mut host_module);
+
+    let packet = execute_tx_packet(MSG_SEND_TYPE_URL);
+
+    let (extras, ack) = host_module.on_recv_packet_execute(&packet, &"relayer".to_string().into());
+
+    let ack_status: AcknowledgementStatus =
+        serde_json::from_slice(ack.unwrap().as_bytes()).unwrap();
+
+    assert!(ack_status.is_successful());
+    assert_eq!(extras.events.len(), 1);
+    assert_eq!(host_module.ctx().executed_txs.len(), 1);
+    assert_eq!(
+        host_module.ctx().executed_txs[0].1[0].type_url,
+        MSG_SEND_TYPE_URL
+    );
+}
+
+#[rstest]
+fn test_host_recv_packet_rejects_disallowed_message(mut host_module: HostModule<MockHostContext>) {
+    open_try(&mut host_module);
+
+    let packet = execute_tx_packet("/cosmos.staking.v1beta1.MsgDelegate");
+
+    let (_, ack) = host_module.on_recv_packet_execute(&packet, &"relayer".to_string().into());
+
+    let ack_status: AcknowledgementStatus =
+        serde_json::from_slice(ack.unwrap().as_bytes()).unwrap();
+
+    assert!(!ack_status.is_successful());
+    assert!(host_module.ctx().executed_txs.is_empty());
+}
+
+#[rstest]
+fn test_host_recv_packet_without_account(mut host_module: HostModule<MockHostContext>) {
+    let packet = execute_tx_packet(MSG_SEND_TYPE_URL);
+
+    let (_, ack) = host_module.on_recv_packet_execute(&packet, &"relayer".to_string().into());
+
+    let ack_status: AcknowledgementStatus =
+        serde_json::from_slice(ack.unwrap().as_bytes()).unwrap();
+
+    assert!(!ack_status.is_successful());
+}
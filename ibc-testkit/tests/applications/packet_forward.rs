@@ -0,0 +1,399 @@
+use core::str::FromStr;
+
+use ibc::apps::fee::module::FeeMiddleware;
+use ibc::apps::fee::types::IncentivizedAcknowledgement;
+use ibc::apps::packet_forward::module::PacketForwardMiddleware;
+use ibc::apps::transfer::types::packet::{ForwardingPacketData, PacketData, PacketDataV2};
+use ibc::apps::transfer::types::{ack_success_b64, Ics20Version, PrefixedCoin, TracePrefix};
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_ack_commitment;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{AckPath, CommitmentPath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::proto::Protobuf;
+use ibc::core::primitives::*;
+use ibc::core::router::module::Module;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::fee::types::{MockFeeContext, SharedFeeContext};
+use ibc_testkit::testapp::ibc::applications::packet_forward::types::MockPacketForwardContext;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+type DummyForwardModule = PacketForwardMiddleware<MockPacketForwardContext, DummyTransferModule>;
+
+/// The channel on which the packets to forward are received.
+fn inbound_channel() -> ChannelId {
+    ChannelId::new(0)
+}
+
+/// The channel through which the packets are forwarded to the next hop.
+fn forward_channel() -> ChannelId {
+    ChannelId::new(1)
+}
+
+fn open_channel(counterparty_channel_id: ChannelId) -> ChannelEnd {
+    ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(counterparty_channel_id)),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap()
+}
+
+#[fixture]
+fn pfm() -> DummyForwardModule {
+    let client_id = ClientId::new("07-tendermint", 0).unwrap();
+    let client_height = Height::new(0, 5).unwrap();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).unwrap(),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end)
+        .with_channel(
+            PortId::transfer(),
+            inbound_channel(),
+            open_channel(ChannelId::new(0)),
+        )
+        .with_channel(
+            PortId::transfer(),
+            forward_channel(),
+            open_channel(ChannelId::new(7)),
+        )
+        .with_send_sequence(PortId::transfer(), forward_channel(), 1.into())
+        // the receipt core stores upon receiving the inbound packet, which the
+        // held acknowledgement is later written for
+        .with_packet_receipt(PortId::transfer(), inbound_channel(), 3.into());
+
+    PacketForwardMiddleware::new(
+        MockPacketForwardContext::new(ctx.ibc_store),
+        DummyTransferModule::new(),
+    )
+}
+
+fn inbound_packet(memo: &str) -> Packet {
+    let data = PacketData {
+        token: PrefixedCoin::from_str("100uatom").unwrap(),
+        sender: "cosmos1sender".to_string().into(),
+        receiver: "cosmos1receiver".to_string().into(),
+        memo: memo.into(),
+    };
+
+    PacketConfig::builder()
+        .seq_on_a(3.into())
+        .chan_id_on_a(ChannelId::new(0))
+        .chan_id_on_b(inbound_channel())
+        .data(serde_json::to_vec(&data).unwrap())
+        .build()
+}
+
+fn forward_memo(retries: u8) -> String {
+    format!(
+        r#"{{"forward":{{"receiver":"cosmos1final","port":"transfer","channel":"{}","retries":{retries}}}}}"#,
+        forward_channel()
+    )
+}
+
+/// The packet the middleware sends to the next hop with the given sequence.
+fn forwarded_packet(pfm: &DummyForwardModule, sequence: u64) -> Packet {
+    let data = PacketData {
+        token: PrefixedCoin::from_str("100transfer/channel-0/uatom").unwrap(),
+        sender: format!("pfm-{}-cosmos1sender", inbound_channel()).into(),
+        receiver: "cosmos1final".to_string().into(),
+        memo: "".into(),
+    };
+
+    let commitment_path =
+        CommitmentPath::new(&PortId::transfer(), &forward_channel(), sequence.into());
+    assert!(pfm
+        .ctx()
+        .ibc_store
+        .get_packet_commitment(&commitment_path)
+        .is_ok());
+
+    PacketConfig::builder()
+        .seq_on_a(sequence.into())
+        .chan_id_on_a(forward_channel())
+        .chan_id_on_b(ChannelId::new(7))
+        .data(serde_json::to_vec(&data).unwrap())
+        .build()
+}
+
+fn written_ack_commitment(pfm: &DummyForwardModule, packet: &Packet) -> Option<Vec<u8>> {
+    let ack_path = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+    pfm.ctx()
+        .ibc_store
+        .get_packet_acknowledgement(&ack_path)
+        .ok()
+        .map(|commitment| commitment.into_vec())
+}
+
+#[rstest]
+fn test_transfer_without_forward_is_passed_through(mut pfm: DummyForwardModule) {
+    let packet = inbound_packet("just a memo");
+
+    let (_, ack) = pfm.on_recv_packet_execute(&packet, &dummy_account_id());
+
+    // the acknowledgement of `DummyTransferModule`
+    assert_eq!(ack.unwrap().as_bytes(), &[1u8]);
+    assert!(pfm.ctx().in_flight_packets.is_empty());
+}
+
+#[rstest]
+fn test_invalid_forward_metadata_is_rejected(mut pfm: DummyForwardModule) {
+    let packet = inbound_packet(r#"{"forward":{"receiver":"cosmos1final"}}"#);
+
+    let (_, ack) = pfm.on_recv_packet_execute(&packet, &dummy_account_id());
+
+    let ack_status: AcknowledgementStatus =
+        serde_json::from_slice(ack.unwrap().as_bytes()).unwrap();
+    assert!(!ack_status.is_successful());
+    assert!(pfm.ctx().in_flight_packets.is_empty());
+}
+
+#[rstest]
+fn test_forward_holds_ack_until_acknowledged(mut pfm: DummyForwardModule) {
+    let packet = inbound_packet(&forward_memo(0));
+
+    let (_, ack) = pfm.on_recv_packet_execute(&packet, &dummy_account_id());
+
+    assert!(ack.is_none());
+
+    let in_flight_packet = pfm
+        .ctx()
+        .in_flight_packets
+        .get(&(PortId::transfer(), forward_channel(), Sequence::from(1)))
+        .cloned()
+        .unwrap();
+    assert_eq!(in_flight_packet.original_packet, packet);
+
+    let forwarded_packet = forwarded_packet(&pfm, 1);
+    let forward_ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+
+    let (_, result) =
+        pfm.on_acknowledgement_packet_execute(&forwarded_packet, &forward_ack, &dummy_account_id());
+
+    result.unwrap();
+    assert!(pfm.ctx().in_flight_packets.is_empty());
+    assert_eq!(
+        written_ack_commitment(&pfm, &packet),
+        Some(compute_ack_commitment(&forward_ack).into_vec())
+    );
+}
+
+#[rstest]
+fn test_forward_error_ack_is_passed_back(mut pfm: DummyForwardModule) {
+    let packet = inbound_packet(&forward_memo(0));
+
+    pfm.on_recv_packet_execute(&packet, &dummy_account_id());
+
+    let forwarded_packet = forwarded_packet(&pfm, 1);
+    let forward_ack: Acknowledgement =
+        AcknowledgementStatus::error(StatusValue::new("receive failed").unwrap()).into();
+
+    let (_, result) =
+        pfm.on_acknowledgement_packet_execute(&forwarded_packet, &forward_ack, &dummy_account_id());
+
+    result.unwrap();
+    assert!(pfm.ctx().in_flight_packets.is_empty());
+
+    let ack_commitment = written_ack_commitment(&pfm, &packet).unwrap();
+    let success_ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+    assert_ne!(
+        ack_commitment,
+        compute_ack_commitment(&success_ack).into_vec()
+    );
+}
+
+#[rstest]
+fn test_forward_is_retried_on_timeout(mut pfm: DummyForwardModule) {
+    let packet = inbound_packet(&forward_memo(1));
+
+    pfm.on_recv_packet_execute(&packet, &dummy_account_id());
+
+    // the first timeout sends the packet again
+    let forwarded_packet = forwarded_packet(&pfm, 1);
+
+    let (_, result) = pfm.on_timeout_packet_execute(&forwarded_packet, &dummy_account_id());
+
+    result.unwrap();
+    assert!(written_ack_commitment(&pfm, &packet).is_none());
+
+    let in_flight_packet = pfm
+        .ctx()
+        .in_flight_packets
+        .get(&(PortId::transfer(), forward_channel(), Sequence::from(2)))
+        .cloned()
+        .unwrap();
+    assert_eq!(in_flight_packet.retries_remaining, 0);
+    assert_eq!(pfm.ctx().in_flight_packets.len(), 1);
+
+    // the second one gives up and writes an error acknowledgement
+    let retried_packet = forwarded_packet(&pfm, 2);
+
+    let (_, result) = pfm.on_timeout_packet_execute(&retried_packet, &dummy_account_id());
+
+    result.unwrap();
+    assert!(pfm.ctx().in_flight_packets.is_empty());
+    assert!(written_ack_commitment(&pfm, &packet).is_some());
+}
+
+#[rstest]
+fn test_forward_ack_is_wrapped_by_fee_middleware(mut pfm: DummyForwardModule) {
+    let relayer = dummy_account_id();
+
+    let fee_ctx = SharedFeeContext::new(
+        MockFeeContext::new().with_fee_enabled(PortId::transfer(), inbound_channel()),
+    );
+    fee_ctx.0.lock().counterparty_payees.insert(
+        (relayer.clone(), inbound_channel()),
+        "cosmos1forward".to_string().into(),
+    );
+
+    pfm.ctx_mut().fee_ctx = Some(fee_ctx.clone());
+    let mut stack = FeeMiddleware::new(fee_ctx.clone(), pfm);
+
+    let packet = inbound_packet(&forward_memo(0));
+
+    let (_, ack) = stack.on_recv_packet_execute(&packet, &relayer);
+
+    // the fee middleware records the forward relayer of the held acknowledgement
+    assert!(ack.is_none());
+    assert_eq!(fee_ctx.0.lock().forward_relayers.len(), 1);
+
+    let forwarded_packet = forwarded_packet(stack.app(), 1);
+    let forward_ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+
+    let (_, result) =
+        stack.on_acknowledgement_packet_execute(&forwarded_packet, &forward_ack, &relayer);
+
+    result.unwrap();
+    assert!(fee_ctx.0.lock().forward_relayers.is_empty());
+
+    let incentivized_ack: Acknowledgement = IncentivizedAcknowledgement {
+        app_acknowledgement: forward_ack,
+        forward_relayer_address: "cosmos1forward".to_string().into(),
+        underlying_app_success: true,
+    }
+    .into();
+    assert_eq!(
+        written_ack_commitment(stack.app(), &packet),
+        Some(compute_ack_commitment(&incentivized_ack).into_vec())
+    );
+}
+
+#[rstest]
+fn test_forwarding_hops_of_v2_transfer(mut pfm: DummyForwardModule) {
+    for channel_id in [inbound_channel(), forward_channel()] {
+        pfm.ctx_mut()
+            .bank
+            .channel_versions
+            .insert((PortId::transfer(), channel_id), Ics20Version::V2);
+    }
+
+    let data = PacketDataV2 {
+        tokens: vec![
+            PrefixedCoin::from_str("100uatom").unwrap(),
+            PrefixedCoin::from_str("50transfer/channel-0/uosmo").unwrap(),
+        ],
+        sender: "cosmos1sender".to_string().into(),
+        receiver: "cosmos1final".to_string().into(),
+        memo: "".into(),
+        forwarding: ForwardingPacketData {
+            hops: vec![TracePrefix::new(PortId::transfer(), forward_channel())],
+            destination_memo: "for the final receiver".into(),
+        },
+    };
+
+    let packet = PacketConfig::builder()
+        .seq_on_a(3.into())
+        .chan_id_on_a(ChannelId::new(0))
+        .chan_id_on_b(inbound_channel())
+        .data(data.encode_vec())
+        .build();
+
+    let (_, ack) = pfm.on_recv_packet_execute(&packet, &dummy_account_id());
+
+    assert!(ack.is_none());
+    assert_eq!(pfm.ctx().in_flight_packets.len(), 1);
+
+    // the last hop receives the tokens traced through this chain, along with
+    // the destination memo
+    let sent_data = pfm
+        .ctx()
+        .ibc_store
+        .events
+        .lock()
+        .iter()
+        .find_map(|event| match event {
+            IbcEvent::SendPacket(send_packet) => Some(send_packet.packet_data().to_vec()),
+            _ => None,
+        })
+        .unwrap();
+
+    assert_eq!(
+        PacketDataV2::decode_vec(&sent_data).unwrap(),
+        PacketDataV2 {
+            tokens: vec![
+                PrefixedCoin::from_str("100transfer/channel-0/uatom").unwrap(),
+                PrefixedCoin::from_str("50uosmo").unwrap(),
+            ],
+            sender: format!("pfm-{}-cosmos1sender", inbound_channel()).into(),
+            receiver: "cosmos1final".to_string().into(),
+            memo: "for the final receiver".into(),
+            forwarding: ForwardingPacketData::default(),
+        }
+    );
+
+    let forwarded_packet = PacketConfig::builder()
+        .seq_on_a(1.into())
+        .chan_id_on_a(forward_channel())
+        .chan_id_on_b(ChannelId::new(7))
+        .data(sent_data)
+        .build();
+    let forward_ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+
+    let (_, result) =
+        pfm.on_acknowledgement_packet_execute(&forwarded_packet, &forward_ack, &dummy_account_id());
+
+    result.unwrap();
+    assert!(pfm.ctx().in_flight_packets.is_empty());
+    assert_eq!(
+        written_ack_commitment(&pfm, &packet),
+        Some(compute_ack_commitment(&forward_ack).into_vec())
+    );
+}
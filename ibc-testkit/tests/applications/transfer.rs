@@ -1,14 +1,38 @@
+use core::str::FromStr;
+
+use ibc::apps::transfer::context::TokenTransferValidationContext;
+use ibc::apps::transfer::handler::send_transfer;
 use ibc::apps::transfer::module::{
-    on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_chan_open_ack_execute, on_chan_open_init_execute, on_chan_open_init_validate,
+    on_chan_open_try_execute, on_chan_open_try_validate, on_recv_packet_execute,
+};
+use ibc::apps::transfer::types::msgs::transfer::MsgTransferV2;
+use ibc::apps::transfer::types::packet::{ForwardingPacketData, PacketData, PacketDataV2};
+use ibc::apps::transfer::types::{
+    ack_success_b64, Ics20Version, PrefixedCoin, TracePrefix, VERSION, VERSION_V2,
 };
-use ibc::apps::transfer::types::VERSION;
-use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::proto::Protobuf;
+use ibc::core::primitives::{Timestamp, ZERO_DURATION};
 use ibc::cosmos_host::utils::cosmos_adr028_escrow_address;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
 use subtle_encoding::bech32;
 
 fn get_defaults() -> (
@@ -26,7 +50,7 @@ fn get_defaults() -> (
     let counterparty = Counterparty::new(port_id.clone(), Some(channel_id.clone()));
 
     (
-        DummyTransferModule,
+        DummyTransferModule::new(),
         order,
         connection_hops,
         port_id,
@@ -166,3 +190,236 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+/// If the relayer proposes ics20-2, then return ics20-2 and record it
+#[test]
+fn test_on_chan_open_init_ics20_v2_version() {
+    let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    let in_version = Version::new(VERSION_V2.to_string());
+
+    on_chan_open_init_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &in_version,
+    )
+    .unwrap();
+
+    let (_, out_version) = on_chan_open_init_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &in_version,
+    )
+    .unwrap();
+
+    assert_eq!(out_version, in_version);
+    assert_eq!(
+        ctx.channel_version(&port_id, &channel_id).unwrap(),
+        Ics20Version::V2
+    );
+}
+
+/// If the counterparty proposes either supported version, then agree to it
+#[test]
+fn test_on_chan_open_try_counterparty_version_is_kept() {
+    for version in [Ics20Version::V1, Ics20Version::V2] {
+        let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+        let (_, out_version) = on_chan_open_try_execute(
+            &mut ctx,
+            order,
+            &connection_hops,
+            &port_id,
+            &channel_id,
+            &counterparty,
+            &version.into(),
+        )
+        .unwrap();
+
+        assert_eq!(out_version, Version::from(version));
+        assert_eq!(ctx.channel_version(&port_id, &channel_id).unwrap(), version);
+    }
+}
+
+/// The channel ends up with the version the counterparty agreed to
+#[test]
+fn test_on_chan_open_ack_records_counterparty_version() {
+    let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    on_chan_open_init_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &Version::new(VERSION.to_string()),
+    )
+    .unwrap();
+
+    on_chan_open_ack_execute(
+        &mut ctx,
+        &port_id,
+        &channel_id,
+        &Version::new(VERSION_V2.to_string()),
+    )
+    .unwrap();
+
+    assert_eq!(
+        ctx.channel_version(&port_id, &channel_id).unwrap(),
+        Ics20Version::V2
+    );
+}
+
+fn dummy_packet_data_v2() -> PacketDataV2 {
+    PacketDataV2 {
+        tokens: vec![
+            PrefixedCoin::from_str("100uatom").unwrap(),
+            PrefixedCoin::from_str("50transfer/channel-1/uosmo").unwrap(),
+        ],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+        forwarding: ForwardingPacketData::default(),
+    }
+}
+
+fn dummy_packet(data: Vec<u8>) -> Packet {
+    PacketConfig::builder()
+        .chan_id_on_b(ChannelId::new(1))
+        .data(data)
+        .build()
+}
+
+fn dummy_ctx_with_channel_version(version: Ics20Version) -> DummyTransferModule {
+    let mut ctx = DummyTransferModule::new();
+    ctx.channel_versions
+        .insert((PortId::transfer(), ChannelId::new(1)), version);
+    ctx
+}
+
+#[test]
+fn test_recv_multi_token_packet_on_v2_channel() {
+    let mut ctx = dummy_ctx_with_channel_version(Ics20Version::V2);
+
+    let packet = dummy_packet(dummy_packet_data_v2().encode_vec());
+
+    let (extras, ack) = on_recv_packet_execute(&mut ctx, &packet);
+
+    let ack_status: AcknowledgementStatus = serde_json::from_slice(ack.as_bytes()).unwrap();
+    assert_eq!(
+        ack_status,
+        AcknowledgementStatus::success(ack_success_b64())
+    );
+
+    // one receive event per token, after the denom trace events of the
+    // minted vouchers
+    let recv_events = extras
+        .events
+        .iter()
+        .filter(|event| event.kind == "fungible_token_packet")
+        .count();
+    assert_eq!(recv_events, 2);
+}
+
+#[test]
+fn test_recv_packet_is_decoded_by_channel_version() {
+    let v1_data = PacketData {
+        token: PrefixedCoin::from_str("100uatom").unwrap(),
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".into(),
+    };
+
+    // a v1 packet received on a v1 channel
+    let mut ctx = dummy_ctx_with_channel_version(Ics20Version::V1);
+    let packet = dummy_packet(serde_json::to_vec(&v1_data).unwrap());
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+    let ack_status: AcknowledgementStatus = serde_json::from_slice(ack.as_bytes()).unwrap();
+    assert!(ack_status.is_successful());
+
+    // a v2 packet received on a v1 channel
+    let packet = dummy_packet(PacketDataV2::from(v1_data).encode_vec());
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet);
+    let ack_status: AcknowledgementStatus = serde_json::from_slice(ack.as_bytes()).unwrap();
+    assert!(!ack_status.is_successful());
+}
+
+/// Forwarding is left to the packet forward middleware
+#[test]
+fn test_recv_packet_with_forwarding_hops_is_rejected() {
+    let mut ctx = dummy_ctx_with_channel_version(Ics20Version::V2);
+
+    let mut data = dummy_packet_data_v2();
+    data.forwarding = ForwardingPacketData {
+        hops: vec![TracePrefix::new(PortId::transfer(), ChannelId::new(5))],
+        destination_memo: "".into(),
+    };
+
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &dummy_packet(data.encode_vec()));
+
+    let ack_status: AcknowledgementStatus = serde_json::from_slice(ack.as_bytes()).unwrap();
+    assert!(!ack_status.is_successful());
+}
+
+#[test]
+fn test_send_multi_token_transfer_requires_v2_channel() {
+    let client_id = ClientId::new("07-tendermint", 0).unwrap();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).unwrap(),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+        vec![ConnectionId::zero()],
+        Version::new(VERSION_V2.to_string()),
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end)
+        .with_channel(PortId::transfer(), ChannelId::new(1), chan_end)
+        .with_send_sequence(PortId::transfer(), ChannelId::new(1), 1.into());
+
+    let msg = MsgTransferV2 {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        packet_data: dummy_packet_data_v2(),
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 100).unwrap()),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let mut token_ctx = DummyTransferModule::new();
+
+    assert!(send_transfer(&mut ctx.ibc_store, &mut token_ctx, msg.clone()).is_err());
+
+    token_ctx
+        .channel_versions
+        .insert((PortId::transfer(), ChannelId::new(1)), Ics20Version::V2);
+
+    send_transfer(&mut ctx.ibc_store, &mut token_ctx, msg).unwrap();
+}
@@ -0,0 +1,210 @@
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::entrypoint::{dispatch, dispatch_batch, dispatch_batch_with_savepoints, simulate};
+use ibc::core::handler::types::error::BatchError;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::handler::types::simulation::StateChange;
+use ibc::core::host::types::path::{ClientStatePath, NextClientSequencePath, Path};
+use ibc::core::host::{CachedExecutionContext, ValidationContext};
+use ibc::core::primitives::prelude::*;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::DefaultIbcStore;
+use test_log::test;
+
+fn create_client_msg(height: u64) -> MsgEnvelope {
+    let header = MockHeader::new(Height::new(0, height).unwrap());
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(header).into(),
+        MockConsensusState::new(header).into(),
+        dummy_account_id(),
+    );
+
+    MsgEnvelope::from(ClientMsg::from(msg))
+}
+
+/// A message which fails, as it updates a client that does not exist.
+fn failing_msg() -> MsgEnvelope {
+    MsgEnvelope::from(ClientMsg::UpdateClient(MsgUpdateClient {
+        client_id: mock_client_type().build_client_id(99),
+        client_message: MockHeader::new(Height::new(0, 50).unwrap()).into(),
+        signer: dummy_account_id(),
+    }))
+}
+
+fn create_client_events(events: &[IbcEvent]) -> usize {
+    events
+        .iter()
+        .filter(|event| matches!(event, IbcEvent::CreateClient(_)))
+        .count()
+}
+
+#[test]
+fn test_dispatch_batch_commits_all_messages() {
+    let mut ctx = DefaultIbcStore::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let outcome = dispatch_batch(
+        &mut ctx,
+        &mut router,
+        vec![create_client_msg(10), create_client_msg(20)],
+    )
+    .unwrap();
+
+    assert_eq!(ctx.client_counter().unwrap(), 2);
+    assert_eq!(create_client_events(&outcome.events), 2);
+    assert_eq!(outcome.events, *ctx.events.lock());
+    assert!(ctx.caches.lock().is_empty());
+}
+
+#[test]
+fn test_dispatch_batch_rolls_back_on_failure() {
+    let mut ctx = DefaultIbcStore::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let res = dispatch_batch(
+        &mut ctx,
+        &mut router,
+        vec![create_client_msg(10), create_client_msg(20), failing_msg()],
+    );
+
+    assert!(matches!(res, Err(BatchError::Message { index: 2, .. })));
+
+    // the writes and events of the messages preceding the failed one are
+    // rolled back as well
+    let client_id = mock_client_type().build_client_id(0);
+    assert_eq!(ctx.client_counter().unwrap(), 0);
+    assert!(ctx.client_state(&client_id).is_err());
+    assert!(ctx.consensus_state_heights.lock().is_empty());
+    assert!(ctx.events.lock().is_empty());
+    assert!(ctx.caches.lock().is_empty());
+}
+
+#[test]
+fn test_discarded_cache_restores_in_memory_state() {
+    let mut ctx = DefaultIbcStore::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let host_consensus_states = ctx.host_consensus_states.lock().clone();
+    assert!(!host_consensus_states.is_empty());
+
+    ctx.begin_cache();
+
+    dispatch(&mut ctx, &mut router, create_client_msg(10)).unwrap();
+
+    // a message mutating the state the context keeps in memory, and failing
+    // afterwards
+    ctx.host_consensus_states.lock().clear();
+    ctx.ibc_commiment_proofs.lock().clear();
+    ctx.events.lock().clear();
+    ctx.logs.lock().push("about to fail".to_string());
+    assert!(dispatch(&mut ctx, &mut router, failing_msg()).is_err());
+
+    ctx.discard_cache();
+
+    assert_eq!(*ctx.host_consensus_states.lock(), host_consensus_states);
+    assert!(!ctx.ibc_commiment_proofs.lock().is_empty());
+    assert!(ctx.consensus_state_heights.lock().is_empty());
+    assert!(ctx.events.lock().is_empty());
+    assert!(ctx.logs.lock().is_empty());
+    assert!(ctx.caches.lock().is_empty());
+}
+
+#[test]
+fn test_dispatch_batch_with_savepoints() {
+    let mut ctx = DefaultIbcStore::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let (results, outcome) = dispatch_batch_with_savepoints(
+        &mut ctx,
+        &mut router,
+        vec![create_client_msg(10), failing_msg(), create_client_msg(20)],
+    )
+    .unwrap();
+
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+
+    // only the failed message is rolled back
+    assert_eq!(ctx.client_counter().unwrap(), 2);
+    for (client_counter, height) in [(0, 10), (1, 20)] {
+        let client_id = mock_client_type().build_client_id(client_counter);
+        let heights = ctx.consensus_state_heights(&client_id).unwrap();
+
+        assert_eq!(heights, vec![Height::new(0, height).unwrap()]);
+    }
+
+    assert_eq!(create_client_events(&outcome.events), 2);
+    assert_eq!(outcome.events, *ctx.events.lock());
+    assert!(ctx.caches.lock().is_empty());
+}
+
+#[test]
+fn test_simulate_reports_changes_without_writing() {
+    let mut ctx = DefaultIbcStore::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let entries = ctx.store_entries();
+
+    let outcome = simulate(
+        &mut ctx,
+        &mut router,
+        vec![create_client_msg(10), create_client_msg(20)],
+    )
+    .unwrap();
+
+    let client_id = mock_client_type().build_client_id(1);
+    let changed_paths: Vec<Path> = outcome
+        .changes
+        .iter()
+        .map(|change| change.path().clone())
+        .collect();
+
+    assert!(changed_paths.contains(&Path::ClientState(ClientStatePath::new(client_id.clone()))));
+    assert!(changed_paths.contains(&Path::NextClientSequence(NextClientSequencePath)));
+    assert!(outcome
+        .changes
+        .iter()
+        .all(|change| matches!(change, StateChange::Write { .. })));
+    assert_eq!(create_client_events(&outcome.events), 2);
+
+    // the store is left as it was
+    assert_eq!(ctx.store_entries(), entries);
+    assert_eq!(ctx.client_counter().unwrap(), 0);
+    assert!(ctx.client_state(&client_id).is_err());
+    assert!(ctx.consensus_state_heights(&client_id).unwrap().is_empty());
+    assert!(ctx.events.lock().is_empty());
+    assert!(ctx.caches.lock().is_empty());
+}
+
+#[test]
+fn test_simulate_failing_message_leaves_store_unchanged() {
+    let mut ctx = DefaultIbcStore::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let entries = ctx.store_entries();
+
+    let res = simulate(
+        &mut ctx,
+        &mut router,
+        vec![create_client_msg(10), failing_msg()],
+    );
+
+    assert!(matches!(res, Err(BatchError::Message { index: 1, .. })));
+
+    let client_id = mock_client_type().build_client_id(0);
+    assert_eq!(ctx.store_entries(), entries);
+    assert_eq!(ctx.client_counter().unwrap(), 0);
+    assert!(ctx.consensus_state_heights(&client_id).unwrap().is_empty());
+    assert!(ctx.events.lock().is_empty());
+    assert!(ctx.caches.lock().is_empty());
+}
@@ -1,6 +1,7 @@
 #[cfg(feature = "serde")]
 pub mod create_client;
 pub mod recover_client;
+pub mod register_counterparty;
 pub mod update_client;
 #[cfg(feature = "serde")]
 pub mod upgrade_client;
@@ -0,0 +1,125 @@
+use ibc::core::client::types::msgs::{ClientMsg, MsgRegisterCounterparty};
+use ibc::core::client::types::{CounterpartyInfo, Height};
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ClientId, Sequence};
+use ibc::core::host::types::path::{ClientCounterpartyPath, SeqSendV2Path};
+use ibc::core::host::ValidationContext;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+struct Fixture {
+    ctx: MockContext,
+    router: MockRouter,
+    msg: MsgRegisterCounterparty,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let ctx = MockContext::default().with_light_client(
+        &client_id,
+        LightClientState::<MockHost>::with_latest_height(Height::new(0, 2).unwrap()),
+    );
+
+    let msg = MsgRegisterCounterparty {
+        client_id,
+        counterparty_client_id: ClientId::new("07-tendermint", 1).expect("no error"),
+        counterparty_commitment_prefix: CommitmentPrefix::try_from(b"ibc".to_vec())
+            .expect("no error"),
+        signer: dummy_account_id(),
+    };
+
+    Fixture {
+        ctx,
+        router: MockRouter::default(),
+        msg,
+    }
+}
+
+#[rstest]
+fn register_counterparty_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+        msg,
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::RegisterCounterparty(msg.clone()));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "validation happy path: {res:?}");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "execution happy path: {res:?}");
+
+    assert_eq!(
+        ctx.ibc_store
+            .client_counterparty(&ClientCounterpartyPath::new(msg.client_id.clone()))
+            .unwrap(),
+        CounterpartyInfo::new(
+            msg.counterparty_client_id,
+            msg.counterparty_commitment_prefix
+        )
+    );
+    assert_eq!(
+        ctx.ibc_store
+            .get_next_sequence_send_v2(&SeqSendV2Path::new(&msg.client_id))
+            .unwrap(),
+        Sequence::from(1)
+    );
+
+    let ibc_events = ctx.get_events();
+    assert_eq!(ibc_events.len(), 1);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Client)
+    ));
+}
+
+#[rstest]
+fn register_counterparty_fail_already_registered(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+        msg,
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::RegisterCounterparty(msg));
+
+    execute(&mut ctx.ibc_store, &mut router, msg_envelope.clone()).unwrap();
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the counterparty of a client cannot be changed"
+    );
+}
+
+#[rstest]
+fn register_counterparty_fail_no_client(fixture: Fixture) {
+    let Fixture {
+        router, mut msg, ..
+    } = fixture;
+
+    msg.client_id = ClientId::new("07-tendermint", 2).expect("no error");
+
+    let ctx = MockContext::default();
+    let msg_envelope = MsgEnvelope::from(ClientMsg::RegisterCounterparty(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the client does not exist"
+    );
+}
@@ -3,20 +3,26 @@ use core::str::FromStr;
 use core::time::Duration;
 
 use basecoin_store::context::ProvableStore;
-use ibc::clients::tendermint::client_state::ClientState;
+use ibc::clients::tendermint::client_state::{header_hash, verify_client_message, ClientState};
+use ibc::clients::tendermint::host_functions::{HostFunctionsProvider, HostSha256, HostVerifier};
+use ibc::clients::tendermint::misbehaviour_detector::detect_misbehaviour;
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
 use ibc::clients::tendermint::types::{
     client_type as tm_client_type, ClientState as TmClientState, Header as TmHeader,
-    Misbehaviour as TmMisbehaviour,
+    HeaderBundle as TmHeaderBundle, Misbehaviour as TmMisbehaviour, TrustThreshold,
 };
 use ibc::core::client::context::client_state::ClientStateValidation;
-use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::context::{
+    ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
+};
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
 use ibc::core::client::types::proto::v1::Height as RawHeight;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::specs::ProofSpecs;
-use ibc::core::entrypoint::{execute, validate};
+use ibc::core::entrypoint::{execute, prune_consensus_states, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::metering::CostEvent;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChainId, ClientId, ClientType};
 use ibc::core::host::types::path::ClientConsensusStatePath;
@@ -25,7 +31,9 @@ use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
 use ibc_testkit::context::{MockContext, TendermintContext, TestContext};
-use ibc_testkit::fixtures::clients::tendermint::ClientStateConfig;
+use ibc_testkit::fixtures::clients::tendermint::{
+    dummy_bft_time_violating_header, dummy_equivocating_header, ClientStateConfig,
+};
 use ibc_testkit::fixtures::core::context::TestContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::hosts::tendermint::BlockParams;
@@ -38,12 +46,16 @@ use ibc_testkit::testapp::ibc::clients::mock::client_state::{
 };
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{
     DefaultIbcStore, LightClientBuilder, LightClientState, MockIbcStore,
 };
 use rstest::*;
+use tendermint::block::CommitSig;
+use tendermint::crypto::default::signature::Verifier as SignatureVerifier;
+use tendermint::crypto::default::Sha256;
+use tendermint::{PublicKey, Signature};
 use tendermint_testgen::Validator as TestgenValidator;
 use tracing::debug;
 
@@ -318,6 +330,114 @@ fn test_consensus_state_pruning() {
     );
 }
 
+#[rstest]
+fn test_prune_expired_consensus_states_with_budget() {
+    let client_id = tm_client_type().build_client_id(0);
+    let consensus_heights = [1, 2, 3, 4, 5].map(|height| Height::new(1, height).unwrap());
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(consensus_heights[4])
+        .build::<TendermintContext>();
+
+    // The consensus states are a block apart, the latest one being as old as
+    // the host: the three oldest ones are thus expired.
+    let mut ctx = TestContextConfig::builder()
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights(consensus_heights)
+                .params(
+                    ClientStateConfig::builder()
+                        .trusting_period(Duration::from_secs(5))
+                        .build(),
+                )
+                .build(),
+        );
+
+    let has_consensus_state = |ctx: &MockContext, height: &Height| {
+        ctx.ibc_store
+            .consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ))
+            .is_ok()
+    };
+
+    let pruned = prune_consensus_states(&mut ctx.ibc_store, &client_id, 2).unwrap();
+    assert_eq!(pruned, 2);
+    assert!(!has_consensus_state(&ctx, &consensus_heights[1]));
+    assert!(has_consensus_state(&ctx, &consensus_heights[2]));
+
+    let pruned = prune_consensus_states(&mut ctx.ibc_store, &client_id, 10).unwrap();
+    assert_eq!(pruned, 1);
+    assert!(!has_consensus_state(&ctx, &consensus_heights[2]));
+    assert!(has_consensus_state(&ctx, &consensus_heights[3]));
+    assert!(ctx
+        .ibc_store
+        .client_update_meta(&client_id, &consensus_heights[2])
+        .is_err());
+
+    let pruned = prune_consensus_states(&mut ctx.ibc_store, &client_id, 10).unwrap();
+    assert_eq!(pruned, 0);
+
+    assert!(
+        prune_consensus_states(&mut ctx.ibc_store, &tm_client_type().build_client_id(1), 10)
+            .is_err()
+    );
+}
+
+#[rstest]
+fn test_ascending_consensus_state_heights() {
+    let client_id = tm_client_type().build_client_id(0);
+    let height = |revision_height| Height::new(1, revision_height).unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .build(),
+        )
+        .latest_height(height(10))
+        .build::<TendermintContext>();
+
+    // The paths of heights 9 and 10 do not sort numerically.
+    let mut ctx = TestContextConfig::builder()
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([height(2), height(9), height(10)])
+                .build(),
+        );
+
+    let heights: Vec<_> = ctx
+        .ibc_store
+        .ascending_consensus_state_heights(&client_id)
+        .unwrap()
+        .collect();
+    assert_eq!(heights, vec![height(2), height(9), height(10)]);
+
+    ctx.ibc_store
+        .delete_consensus_state(ClientConsensusStatePath::new(client_id.clone(), 1, 9))
+        .unwrap();
+
+    let heights: Vec<_> = ctx
+        .ibc_store
+        .ascending_consensus_state_heights(&client_id)
+        .unwrap()
+        .collect();
+    assert_eq!(heights, vec![height(2), height(10)]);
+}
+
 #[rstest]
 fn test_update_nonexisting_client(fixture: Fixture) {
     let Fixture { ctx, router } = fixture;
@@ -397,6 +517,247 @@ fn test_update_synthetic_tendermint_client_adjacent_ok() {
     assert_eq!(client_state.latest_height(), latest_header_height);
 }
 
+#[rstest]
+fn test_update_synthetic_tendermint_client_charges_cost_events() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    let signature_count = TmHeader::from(block.clone())
+        .signed_header
+        .commit
+        .signatures
+        .len() as u64;
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    }));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let validate_events = core::mem::take(&mut *ctx.ibc_store.cost_events.lock());
+
+    // Store accesses are charged by the store of the host, which the mock
+    // context does not meter, leaving the signatures of the header checked.
+    assert_eq!(
+        validate_events,
+        [CostEvent::SignaturesChecked {
+            count: signature_count
+        }]
+    );
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    assert!(!ctx
+        .ibc_store
+        .cost_events
+        .lock()
+        .iter()
+        .any(|event| matches!(
+            event,
+            CostEvent::StoreRead { .. } | CostEvent::StoreWrite { .. }
+        )));
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_charges_present_signatures_only() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let validators = vec![
+        TestgenValidator::new("1").voting_power(25),
+        TestgenValidator::new("2").voting_power(25),
+        TestgenValidator::new("3").voting_power(25),
+        TestgenValidator::new("4").voting_power(25),
+    ];
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .block_params_history(BlockParams::from_validator_history(vec![
+            validators.clone(),
+            validators.clone(),
+            validators,
+        ]))
+        .build::<TendermintContext>();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let router = MockRouter::new_with_transfer();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    // One validator out of four is absent, leaving three quarters of the
+    // voting power signing the block.
+    let mut header = TmHeader::from(block);
+    let signatures = &mut header.signed_header.commit.signatures;
+    assert_eq!(signatures.len(), 4);
+    signatures[3] = CommitSig::BlockIdFlagAbsent;
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id,
+        client_message: header.into(),
+        signer: dummy_account_id(),
+    }));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    assert_eq!(
+        core::mem::take(&mut *ctx.ibc_store.cost_events.lock()),
+        [CostEvent::SignaturesChecked { count: 3 }]
+    );
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_duplicate_header_cached() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    let AnyClientState::Tendermint(client_state) = ctx.ibc_store.client_state(&client_id).unwrap()
+    else {
+        panic!("tendermint client state is expected")
+    };
+    let options = client_state.inner().as_light_client_options().unwrap();
+
+    let tm_header = TmHeader::from(block.clone());
+
+    // The hash the header is cached under for other client parameters.
+    let mut other_options = options;
+    other_options.trusting_period += Duration::from_secs(1);
+    let other_header_hash = header_hash::<Sha256>(&tm_header, &other_options);
+
+    let header_hash = header_hash::<Sha256>(&tm_header, &options);
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    }));
+
+    assert!(!ctx
+        .ibc_store
+        .is_header_verified(&client_id, &header_hash, &client_height)
+        .unwrap());
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    // The header is only cached once the update is executed.
+    assert!(!ctx
+        .ibc_store
+        .is_header_verified(&client_id, &header_hash, &client_height)
+        .unwrap());
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    assert!(ctx
+        .ibc_store
+        .is_header_verified(&client_id, &header_hash, &client_height)
+        .unwrap());
+
+    // The header is not taken as verified under other client parameters.
+    assert!(!ctx
+        .ibc_store
+        .is_header_verified(&client_id, &other_header_hash, &client_height)
+        .unwrap());
+
+    // A competing relayer submits the same header within the block, which is
+    // not verified again.
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), update_height);
+
+    // The cache only lasts for the duration of a block.
+    ctx.advance_block_height();
+
+    assert!(!ctx
+        .ibc_store
+        .is_header_verified(&client_id, &header_hash, &client_height)
+        .unwrap());
+}
+
 #[rstest]
 fn test_update_synthetic_tendermint_client_validator_change_ok() {
     let client_id = tm_client_type().build_client_id(0);
@@ -690,6 +1051,238 @@ fn test_update_synthetic_tendermint_client_validator_change_fail() {
     assert!(res.is_err());
 }
 
+#[rstest]
+fn test_update_synthetic_tendermint_client_header_bundle_ok() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    // Same validator history as in
+    // `test_update_synthetic_tendermint_client_validator_change_fail`, where
+    // height-22 cannot be trusted from height-20, but can be through
+    // height-21.
+    let ctx_b_val_history = vec![
+        // validator set of height-20
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // next validator set of height-20
+        // validator set of height-21
+        vec![
+            TestgenValidator::new("1").voting_power(90),
+            TestgenValidator::new("2").voting_power(10),
+        ],
+        // next validator set of height-21
+        // validator set of height-22
+        vec![
+            TestgenValidator::new("4").voting_power(90),
+            TestgenValidator::new("2").voting_power(10),
+        ],
+        // validator set of height-23
+        vec![
+            TestgenValidator::new("1").voting_power(20),
+            TestgenValidator::new("2").voting_power(80),
+        ],
+    ];
+
+    let block_params = BlockParams::from_validator_history(ctx_b_val_history);
+
+    let intermediate_height = client_height.increment();
+    let update_height = client_height.add(block_params.len() as u64 - 1);
+
+    assert_eq!(update_height.revision_height(), 22);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .block_params_history(block_params)
+        .build::<TendermintContext>();
+
+    let mut ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let header_at = |height: Height, trusted_height: Height| {
+        let trusted_next_validator_set = ctx_b
+            .host_block(&trusted_height)
+            .expect("no error")
+            .next_validators
+            .clone();
+
+        let mut block = ctx_b.host_block(&height).unwrap().into_header();
+
+        block.set_trusted_height(trusted_height);
+        block.set_trusted_next_validators_set(trusted_next_validator_set);
+
+        TmHeader::from(block)
+    };
+
+    let msg_update_client = |headers: Vec<TmHeader>| {
+        MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: TmHeaderBundle::new(headers).unwrap().into(),
+            signer: dummy_account_id(),
+        }))
+    };
+
+    // The headers of a bundle must each trust the height of the previous one.
+    let msg_envelope = msg_update_client(vec![
+        header_at(intermediate_height, client_height),
+        header_at(update_height, client_height),
+    ]);
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
+    assert!(res.is_err());
+
+    let msg_envelope = msg_update_client(vec![
+        header_at(intermediate_height, client_height),
+        header_at(update_height, intermediate_height),
+    ]);
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+    let res = execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx_a.ibc_store.client_state(&client_id).unwrap();
+    assert_eq!(client_state.latest_height(), update_height);
+
+    // Only the consensus state of the final header is stored.
+    let consensus_state_path = |height: Height| {
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        )
+    };
+
+    assert!(ctx_a
+        .ibc_store
+        .consensus_state(&consensus_state_path(update_height))
+        .is_ok());
+    assert!(ctx_a
+        .ibc_store
+        .consensus_state(&consensus_state_path(intermediate_height))
+        .is_err());
+}
+
+/// Host functions delegating to the `rust-crypto` implementations of
+/// tendermint-rs.
+struct RustCryptoHostFunctions;
+
+impl HostFunctionsProvider for RustCryptoHostFunctions {
+    fn sha2_256(message: &[u8]) -> [u8; 32] {
+        <Sha256 as tendermint::crypto::Sha256>::digest(message)
+    }
+
+    fn ed25519_verify(signature: &[u8], message: &[u8], public_key: &[u8]) -> bool {
+        let (Some(public_key), Ok(signature)) = (
+            PublicKey::from_raw_ed25519(public_key),
+            Signature::try_from(signature),
+        ) else {
+            return false;
+        };
+
+        <SignatureVerifier as tendermint::crypto::signature::Verifier>::verify(
+            public_key, message, &signature,
+        )
+        .is_ok()
+    }
+
+    fn secp256k1_verify(_signature: &[u8], _message: &[u8], _public_key: &[u8]) -> bool {
+        false
+    }
+}
+
+/// Host functions rejecting every signature.
+struct RejectingHostFunctions;
+
+impl HostFunctionsProvider for RejectingHostFunctions {
+    fn sha2_256(message: &[u8]) -> [u8; 32] {
+        RustCryptoHostFunctions::sha2_256(message)
+    }
+
+    fn ed25519_verify(_signature: &[u8], _message: &[u8], _public_key: &[u8]) -> bool {
+        false
+    }
+
+    fn secp256k1_verify(_signature: &[u8], _message: &[u8], _public_key: &[u8]) -> bool {
+        false
+    }
+}
+
+#[rstest]
+fn test_verify_synthetic_tendermint_header_with_host_functions() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let AnyClientState::Tendermint(client_state) =
+        ctx_a.ibc_store.client_state(&client_id).unwrap()
+    else {
+        panic!("tendermint client state is expected")
+    };
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+
+    // The signatures of the header are verified by the host functions.
+    let res = verify_client_message::<_, HostSha256<RejectingHostFunctions>>(
+        client_state.inner(),
+        &ctx_a.ibc_store,
+        &client_id,
+        block.clone().into(),
+        &HostVerifier::<RejectingHostFunctions>::default(),
+    );
+    assert!(res.is_err());
+
+    let res = verify_client_message::<_, HostSha256<RustCryptoHostFunctions>>(
+        client_state.inner(),
+        &ctx_a.ibc_store,
+        &client_id,
+        block.into(),
+        &HostVerifier::<RustCryptoHostFunctions>::default(),
+    );
+    assert!(res.is_ok(), "result: {res:?}");
+}
+
 #[rstest]
 fn test_update_synthetic_tendermint_client_malicious_validator_change_pass() {
     let client_id = tm_client_type().build_client_id(0);
@@ -1390,6 +1983,202 @@ fn test_misbehaviour_synthetic_tendermint_bft_time() {
     ensure_misbehaviour(&ctx_a.ibc_store, &client_id, &tm_client_type());
 }
 
+/// Tests that the misbehaviour detector builds valid evidence of equivocation
+/// from a header of a fork of the chain.
+#[rstest]
+fn test_detect_misbehaviour_synthetic_tendermint_equivocation() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(misbehaviour_height)
+        .build::<TendermintContext>();
+
+    let mut ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    // The header of the chain itself is no evidence of misbehaviour.
+    let header: TmHeader = ctx_b
+        .host_block(&misbehaviour_height)
+        .unwrap()
+        .into_header_with_trusted(&ctx_b.host_block(&client_height).unwrap())
+        .into();
+
+    let res = detect_misbehaviour::<_, Sha256>(
+        &ctx_b.host,
+        &client_id,
+        TrustThreshold::ONE_THIRD,
+        &header,
+    );
+    assert!(matches!(res, Ok(None)), "result: {res:?}");
+
+    let header = dummy_equivocating_header(&ctx_b.host, misbehaviour_height, client_height);
+
+    let misbehaviour = detect_misbehaviour::<_, Sha256>(
+        &ctx_b.host,
+        &client_id,
+        TrustThreshold::ONE_THIRD,
+        &header,
+    )
+    .unwrap()
+    .expect("equivocation is detected");
+
+    assert_eq!(misbehaviour.header1().height(), misbehaviour_height);
+    assert_eq!(misbehaviour.header2().height(), misbehaviour_height);
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: misbehaviour.into(),
+        signer: dummy_account_id(),
+    }));
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+    let res = execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+    ensure_misbehaviour(&ctx_a.ibc_store, &client_id, &tm_client_type());
+}
+
+/// Tests that the misbehaviour detector builds valid evidence of a BFT time
+/// violation from a header beyond the latest height of the chain, which is
+/// not after its latest block.
+#[rstest]
+fn test_detect_misbehaviour_synthetic_tendermint_bft_time() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let latest_height = Height::new(1, 22).unwrap();
+    let misbehaviour_height = Height::new(1, 23).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(latest_height)
+        .build::<TendermintContext>();
+
+    let mut ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let header = dummy_bft_time_violating_header(&ctx_b.host, misbehaviour_height, client_height);
+
+    let misbehaviour = detect_misbehaviour::<_, Sha256>(
+        &ctx_b.host,
+        &client_id,
+        TrustThreshold::ONE_THIRD,
+        &header,
+    )
+    .unwrap()
+    .expect("BFT time violation is detected");
+
+    assert_eq!(misbehaviour.header1().height(), misbehaviour_height);
+    assert_eq!(misbehaviour.header2().height(), latest_height);
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: misbehaviour.into(),
+        signer: dummy_account_id(),
+    }));
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+    let res = execute(&mut ctx_a.ibc_store, &mut router_a, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+    ensure_misbehaviour(&ctx_a.ibc_store, &client_id, &tm_client_type());
+}
+
+/// Tests that the misbehaviour detector, which does not bisect the chain,
+/// fails to build evidence from a header of the chain signed by a validator
+/// set the client does not trust yet.
+#[rstest]
+fn test_detect_misbehaviour_synthetic_tendermint_validator_change() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b_val_history = vec![
+        // validator set of height-20
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // next validator set of height-20
+        // validator set of height-21
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // next validator set of height-21
+        // validator set of height-22
+        // no overlap with the trusted validator set of height-20
+        vec![
+            TestgenValidator::new("3").voting_power(50),
+            TestgenValidator::new("4").voting_power(50),
+        ],
+        // next validator set of height-22
+        vec![
+            TestgenValidator::new("3").voting_power(50),
+            TestgenValidator::new("4").voting_power(50),
+        ],
+    ];
+
+    let block_params = BlockParams::from_validator_history(ctx_b_val_history);
+
+    let misbehaviour_height = client_height.add(block_params.len() as u64 - 1);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(misbehaviour_height)
+        .block_params_history(block_params)
+        .build::<TendermintContext>();
+
+    // The fork is signed by the validators trusted at height-20.
+    let header = dummy_equivocating_header(&ctx_b.host, misbehaviour_height, client_height);
+
+    let res = detect_misbehaviour::<_, Sha256>(
+        &ctx_b.host,
+        &client_id,
+        TrustThreshold::ONE_THIRD,
+        &header,
+    );
+
+    assert!(
+        matches!(res, Err(ClientError::ClientSpecific { .. })),
+        "result: {res:?}"
+    );
+}
+
 #[rstest]
 fn test_expired_client() {
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
@@ -13,6 +13,11 @@ use ibc::core::host::types::identifiers::{ChainId, ClientId};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::ZERO_DURATION;
+use ibc_client_wasm::client_state::ClientState as WasmClientState;
+use ibc_client_wasm::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_client_wasm::handler::checksum;
+use ibc_client_wasm::types::client_state::ClientState as WasmClientStateType;
+use ibc_client_wasm::types::consensus_state::ConsensusState as WasmConsensusStateType;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_ack;
 use ibc_testkit::fixtures::core::context::TestContextConfig;
@@ -27,6 +32,7 @@ enum Ctx {
     NewWithConnection,
     NewWithConnectionEndOpen,
     DefaultWithConnection,
+    NewWithWasmClientConnection,
 }
 
 fn conn_open_ack_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenAck> {
@@ -55,6 +61,17 @@ fn conn_open_ack_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenAck> {
     )
     .unwrap();
 
+    // A connection end over an 08-wasm client, whose proofs go through its contract.
+    let wasm_client_id = ClientId::new("08-wasm", 0).unwrap();
+    let wasm_conn_end = ConnectionEnd::new(
+        State::Init,
+        wasm_client_id.clone(),
+        default_conn_end.counterparty().clone(),
+        vec![msg.version.clone()],
+        ZERO_DURATION,
+    )
+    .unwrap();
+
     // A connection end with incorrect state `Open`; will be part of the context.
     let mut conn_end_open = default_conn_end.clone();
     conn_end_open.set_state(State::Open); // incorrect field
@@ -99,6 +116,26 @@ fn conn_open_ack_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenAck> {
                 .with_connection(conn_id, conn_end_open)
                 .ibc_store
         }
+        Ctx::NewWithWasmClientConnection => {
+            let wasm_client_state = WasmClientStateType {
+                data: b"data".to_vec(),
+                checksum: checksum(b"byte code"),
+                latest_height: proof_height,
+            };
+
+            ctx_new
+                .with_client_state(
+                    &wasm_client_id,
+                    WasmClientState::from(wasm_client_state).into(),
+                )
+                .with_consensus_state(
+                    &wasm_client_id,
+                    proof_height,
+                    WasmConsensusState::from(WasmConsensusStateType::new(b"data".to_vec())).into(),
+                )
+                .with_connection(conn_id, wasm_conn_end)
+                .ibc_store
+        }
     };
 
     Fixture { ctx, msg }
@@ -184,6 +221,19 @@ fn conn_open_ack_healthy() {
     conn_open_ack_execute(&mut fxt, Expect::Success);
 }
 
+#[test]
+fn conn_open_ack_over_wasm_client() {
+    let mut fxt = conn_open_ack_fixture(Ctx::NewWithWasmClientConnection);
+    conn_open_ack_validate(&fxt, Expect::Success);
+    conn_open_ack_execute(&mut fxt, Expect::Success);
+
+    // The proofs of the connection end, client state and consensus state.
+    assert_eq!(
+        fxt.ctx.wasm_vm.calls_of("sudo", "verify_membership").len(),
+        3
+    );
+}
+
 #[test]
 fn conn_open_ack_no_connection() {
     let fxt = conn_open_ack_fixture(Ctx::New);
@@ -0,0 +1,352 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeConfirm};
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, CounterpartyUpgradePath,
+};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_upgrade_fields;
+use ibc_testkit::fixtures::core::commitment::dummy_commitment_proof_bytes;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeConfirm,
+    pub chan_end: ChannelEnd,
+    pub upgrade: Upgrade,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::zero();
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::zero();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    // Chain B started flushing upon `ChanUpgradeTry`.
+    let mut chan_end = ChannelEnd::new(
+        ChannelState::Flushing,
+        Order::Unordered,
+        Counterparty::new(port_id.clone(), Some(chan_id.clone())),
+        vec![conn_id.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+    chan_end.set_upgrade_sequence(1);
+
+    let fields = UpgradeFields::try_from(dummy_raw_upgrade_fields("ics20-2")).unwrap();
+
+    let upgrade = Upgrade::new(
+        fields.clone(),
+        Some(upgrade_timeout_at(1_000)),
+        Sequence::from(1),
+    );
+
+    let default_context = MockContext::default();
+    let proof_height = default_context.ibc_store.host_height().unwrap();
+
+    let context = default_context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(proof_height),
+        )
+        .with_connection(conn_id, conn_end);
+
+    let msg = MsgChannelUpgradeConfirm {
+        port_id_on_b: port_id,
+        chan_id_on_b: chan_id,
+        counterparty_chan_state: ChannelState::Flushing,
+        counterparty_upgrade: Upgrade::new(
+            fields,
+            Some(upgrade_timeout_at(1_000)),
+            Sequence::from(1),
+        ),
+        proof_chan_end_on_a: dummy_commitment_proof_bytes(),
+        proof_upgrade_on_a: dummy_commitment_proof_bytes(),
+        proof_height_on_a: proof_height,
+        signer: dummy_account_id(),
+    };
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        msg,
+        chan_end,
+        upgrade,
+    }
+}
+
+fn upgrade_timeout_at(height: u64) -> UpgradeTimeout {
+    UpgradeTimeout::new(
+        TimeoutHeight::At(Height::new(0, height).unwrap()),
+        Timestamp::none(),
+    )
+    .unwrap()
+}
+
+#[rstest]
+fn chan_upgrade_confirm_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        chan_end,
+        upgrade,
+    } = fixture;
+
+    let context = context
+        .with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end)
+        .with_channel_upgrade(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), upgrade);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation expected to succeed (happy path). Error: {res:?}"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_confirm_fail_channel_not_flushing(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        mut chan_end,
+        upgrade,
+    } = fixture;
+
+    chan_end.set_state(ChannelState::Open);
+
+    let context = context
+        .with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end)
+        .with_channel_upgrade(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), upgrade);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the channel is not flushing"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_confirm_execute_flush_complete(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        chan_end,
+        upgrade,
+    } = fixture;
+
+    let mut context = context
+        .with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end)
+        .with_channel_upgrade(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), upgrade);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeConfirmChannel(_)));
+
+    // Chain A is still flushing: the upgrade is left for `ChanUpgradeOpen`.
+    let chan_end_path = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end = context.ibc_store.channel_end(&chan_end_path).unwrap();
+
+    assert_eq!(chan_end.state, ChannelState::FlushComplete);
+
+    let counterparty_upgrade_path =
+        CounterpartyUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let counterparty_upgrade = context
+        .ibc_store
+        .counterparty_upgrade(&counterparty_upgrade_path)
+        .unwrap();
+
+    assert_eq!(counterparty_upgrade, msg.counterparty_upgrade);
+}
+
+#[rstest]
+fn chan_upgrade_confirm_execute_inflight_packets(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        mut msg,
+        chan_end,
+        upgrade,
+    } = fixture;
+
+    msg.counterparty_chan_state = ChannelState::FlushComplete;
+
+    let mut context = context
+        .with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end)
+        .with_channel_upgrade(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), upgrade)
+        .with_packet_commitment(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            Sequence::from(1),
+            PacketCommitment::from(vec![1u8; 32]),
+        );
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    // The upgrade cannot complete until the packets in flight are flushed.
+    let chan_end_path = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end = context.ibc_store.channel_end(&chan_end_path).unwrap();
+
+    assert_eq!(chan_end.state, ChannelState::Flushing);
+    assert_eq!(chan_end.version(), &Version::new("ics20-1".to_string()));
+}
+
+#[rstest]
+fn chan_upgrade_confirm_execute_open_upgrade(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        mut msg,
+        chan_end,
+        upgrade,
+    } = fixture;
+
+    msg.counterparty_chan_state = ChannelState::FlushComplete;
+
+    let mut context = context
+        .with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end)
+        .with_channel_upgrade(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), upgrade);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path. Error: {res:?}");
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 3);
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeConfirmChannel(_)));
+    assert!(matches!(ibc_events[2], IbcEvent::UpgradeOpenChannel(_)));
+
+    // Both ends are done flushing: the channel switches to the upgrade.
+    let chan_end_path = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end = context.ibc_store.channel_end(&chan_end_path).unwrap();
+
+    assert_eq!(chan_end.state, ChannelState::Open);
+    assert_eq!(chan_end.version(), &Version::new("ics20-2".to_string()));
+    assert_eq!(chan_end.upgrade_sequence(), 1);
+
+    let upgrade_path = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    assert!(context.ibc_store.channel_upgrade(&upgrade_path).is_err());
+
+    let counterparty_upgrade_path =
+        CounterpartyUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    assert!(context
+        .ibc_store
+        .counterparty_upgrade(&counterparty_upgrade_path)
+        .is_err());
+}
+
+#[rstest]
+fn chan_upgrade_confirm_execute_abort_timeout_elapsed(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        mut msg,
+        chan_end,
+        upgrade,
+    } = fixture;
+
+    msg.counterparty_upgrade.timeout = Some(upgrade_timeout_at(1));
+
+    let mut context = context
+        .with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end)
+        .with_channel_upgrade(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), upgrade);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds as the upgrade is aborted. Error: {res:?}"
+    );
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution aborts the upgrade");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeErrorChannel(_)));
+
+    let chan_end_path = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end = context.ibc_store.channel_end(&chan_end_path).unwrap();
+
+    assert_eq!(chan_end.state, ChannelState::Open);
+    assert_eq!(chan_end.version(), &Version::new("ics20-1".to_string()));
+
+    let upgrade_path = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    assert!(context.ibc_store.channel_upgrade(&upgrade_path).is_err());
+
+    let receipt_path = ChannelUpgradeErrorPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let receipt = context
+        .ibc_store
+        .channel_upgrade_error(&receipt_path)
+        .unwrap();
+
+    assert_eq!(receipt.sequence, 1);
+}
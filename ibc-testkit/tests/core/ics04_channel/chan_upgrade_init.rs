@@ -0,0 +1,204 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeInit};
+use ibc::core::channel::types::Version;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_upgrade_init;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeInit,
+    pub chan_end: ChannelEnd,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::zero();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg = MsgChannelUpgradeInit::try_from(dummy_raw_msg_chan_upgrade_init("ics20-2")).unwrap();
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+        vec![conn_id.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let context = {
+        let default_context = MockContext::default();
+        let client_consensus_state_height = default_context.ibc_store.host_height().unwrap();
+
+        default_context
+            .with_light_client(
+                &client_id,
+                LightClientState::<MockHost>::with_latest_height(client_consensus_state_height),
+            )
+            .with_connection(conn_id, conn_end)
+    };
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        msg,
+        chan_end,
+    }
+}
+
+#[rstest]
+fn chan_upgrade_init_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        chan_end,
+    } = fixture;
+
+    let context =
+        context.with_channel(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), chan_end);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation expected to succeed (happy path). Error: {res:?}"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_channel_not_open(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        mut chan_end,
+    } = fixture;
+
+    chan_end.set_state(ChannelState::Flushing);
+
+    let context =
+        context.with_channel(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), chan_end);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the channel is not open"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_identical_fields(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg: _,
+        chan_end,
+    } = fixture;
+
+    let msg = MsgChannelUpgradeInit::try_from(dummy_raw_msg_chan_upgrade_init("ics20-1")).unwrap();
+
+    let context =
+        context.with_channel(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), chan_end);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the upgrade does not change the channel"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_init_fail_unordered_to_ordered(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        chan_end,
+    } = fixture;
+
+    msg.fields.ordering = Order::Ordered;
+
+    let context =
+        context.with_channel(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), chan_end);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because an unordered channel cannot become ordered"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_init_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        chan_end,
+    } = fixture;
+
+    let mut context =
+        context.with_channel(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), chan_end);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeInitChannel(_)));
+
+    let chan_end_path = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end = context.ibc_store.channel_end(&chan_end_path).unwrap();
+
+    assert_eq!(chan_end.state, ChannelState::Open);
+    assert_eq!(chan_end.upgrade_sequence(), 1);
+
+    let upgrade_path = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade = context.ibc_store.channel_upgrade(&upgrade_path).unwrap();
+
+    assert_eq!(upgrade.fields, msg.fields);
+}
@@ -0,0 +1,238 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeTimeout};
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_upgrade_fields;
+use ibc_testkit::fixtures::core::commitment::dummy_commitment_proof_bytes;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeTimeout,
+    pub chan_end: ChannelEnd,
+    pub upgrade: Upgrade,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::zero();
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::zero();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut chan_end = ChannelEnd::new(
+        ChannelState::Flushing,
+        Order::Unordered,
+        Counterparty::new(port_id.clone(), Some(chan_id.clone())),
+        vec![conn_id.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+    chan_end.set_upgrade_sequence(1);
+
+    let default_context = MockContext::default();
+    let proof_height = default_context.ibc_store.host_height().unwrap();
+
+    // The upgrade timed out on chain B at the proof height.
+    let upgrade = Upgrade::new(
+        UpgradeFields::try_from(dummy_raw_upgrade_fields("ics20-2")).unwrap(),
+        Some(UpgradeTimeout::new(TimeoutHeight::At(proof_height), Timestamp::none()).unwrap()),
+        Sequence::from(1),
+    );
+
+    let context = default_context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(proof_height),
+        )
+        .with_connection(conn_id.clone(), conn_end);
+
+    // Chain B is still flushing.
+    let mut counterparty_chan_end = ChannelEnd::new(
+        ChannelState::Flushing,
+        Order::Unordered,
+        Counterparty::new(port_id.clone(), Some(chan_id.clone())),
+        vec![conn_id],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+    counterparty_chan_end.set_upgrade_sequence(1);
+
+    let msg = MsgChannelUpgradeTimeout {
+        port_id_on_a: port_id,
+        chan_id_on_a: chan_id,
+        counterparty_chan_end,
+        proof_chan_end_on_b: dummy_commitment_proof_bytes(),
+        proof_height_on_b: proof_height,
+        signer: dummy_account_id(),
+    };
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        msg,
+        chan_end,
+        upgrade,
+    }
+}
+
+#[rstest]
+fn chan_upgrade_timeout_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        chan_end,
+        upgrade,
+    } = fixture;
+
+    let context = context
+        .with_channel(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), chan_end)
+        .with_channel_upgrade(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), upgrade);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation expected to succeed (happy path). Error: {res:?}"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_timeout_fail_timeout_not_reached(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        chan_end,
+        mut upgrade,
+    } = fixture;
+
+    upgrade.timeout = Some(
+        UpgradeTimeout::new(
+            TimeoutHeight::At(Height::new(0, 1_000).unwrap()),
+            Timestamp::none(),
+        )
+        .unwrap(),
+    );
+
+    let context = context
+        .with_channel(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), chan_end)
+        .with_channel_upgrade(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), upgrade);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the timeout has not been reached"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_timeout_fail_counterparty_flush_complete(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        chan_end,
+        upgrade,
+    } = fixture;
+
+    msg.counterparty_chan_end
+        .set_state(ChannelState::FlushComplete);
+
+    let context = context
+        .with_channel(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), chan_end)
+        .with_channel_upgrade(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), upgrade);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because chain B may already complete the upgrade"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_timeout_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        chan_end,
+        upgrade,
+    } = fixture;
+
+    let mut context = context
+        .with_channel(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), chan_end)
+        .with_channel_upgrade(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), upgrade);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 3);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeTimeoutChannel(_)));
+    assert!(matches!(ibc_events[2], IbcEvent::UpgradeErrorChannel(_)));
+
+    let chan_end_path = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end = context.ibc_store.channel_end(&chan_end_path).unwrap();
+
+    assert_eq!(chan_end.state, ChannelState::Open);
+    assert_eq!(chan_end.version(), &Version::new("ics20-1".to_string()));
+    assert_eq!(chan_end.upgrade_sequence(), 1);
+
+    let upgrade_path = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    assert!(context.ibc_store.channel_upgrade(&upgrade_path).is_err());
+
+    // The error receipt lets chain B cancel the upgrade as well.
+    let receipt_path = ChannelUpgradeErrorPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let receipt = context
+        .ibc_store
+        .channel_upgrade_error(&receipt_path)
+        .unwrap();
+
+    assert_eq!(receipt.sequence, 1);
+}
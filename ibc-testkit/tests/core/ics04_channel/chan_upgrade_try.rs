@@ -0,0 +1,350 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeTry};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields};
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_upgrade_fields;
+use ibc_testkit::fixtures::core::commitment::dummy_commitment_proof_bytes;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeTry,
+    pub chan_end: ChannelEnd,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::zero();
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::zero();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(port_id.clone(), Some(chan_id.clone())),
+        vec![conn_id.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let default_context = MockContext::default();
+    let proof_height = default_context.ibc_store.host_height().unwrap();
+
+    let context = default_context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(proof_height),
+        )
+        .with_connection(conn_id.clone(), conn_end)
+        .with_send_sequence(port_id.clone(), chan_id.clone(), Sequence::from(1));
+
+    let msg = MsgChannelUpgradeTry {
+        port_id_on_b: port_id,
+        chan_id_on_b: chan_id,
+        proposed_upgrade_connection_hops: vec![conn_id],
+        counterparty_upgrade_fields: UpgradeFields::try_from(dummy_raw_upgrade_fields("ics20-2"))
+            .unwrap(),
+        counterparty_upgrade_sequence: 1,
+        proof_chan_end_on_a: dummy_commitment_proof_bytes(),
+        proof_upgrade_on_a: dummy_commitment_proof_bytes(),
+        proof_height_on_a: proof_height,
+        signer: dummy_account_id(),
+    };
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        msg,
+        chan_end,
+    }
+}
+
+/// Returns the upgrade initiated by chain B itself, as stored upon
+/// `ChanUpgradeInit`.
+fn initiated_upgrade(version: &str) -> Upgrade {
+    Upgrade::new(
+        UpgradeFields::try_from(dummy_raw_upgrade_fields(version)).unwrap(),
+        None,
+        Sequence::from(0),
+    )
+}
+
+#[rstest]
+fn chan_upgrade_try_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        chan_end,
+    } = fixture;
+
+    let context =
+        context.with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation expected to succeed (happy path). Error: {res:?}"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_try_fail_channel_not_open(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        mut chan_end,
+    } = fixture;
+
+    chan_end.set_state(ChannelState::Flushing);
+
+    let context =
+        context.with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the channel is not open"
+    );
+}
+
+#[rstest]
+fn chan_upgrade_try_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        chan_end,
+    } = fixture;
+
+    let mut context =
+        context.with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeTryChannel(_)));
+
+    let chan_end_path = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end = context.ibc_store.channel_end(&chan_end_path).unwrap();
+
+    assert_eq!(chan_end.state, ChannelState::Flushing);
+    assert_eq!(chan_end.upgrade_sequence(), 1);
+
+    let upgrade_path = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let upgrade = context.ibc_store.channel_upgrade(&upgrade_path).unwrap();
+
+    assert_eq!(upgrade.fields, msg.counterparty_upgrade_fields);
+    assert_eq!(upgrade.next_sequence_send, Sequence::from(1));
+    assert!(upgrade.timeout.is_some());
+}
+
+#[rstest]
+fn chan_upgrade_try_execute_crossing_hellos(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        mut chan_end,
+    } = fixture;
+
+    // Chain B initiated the same upgrade concurrently.
+    chan_end.set_upgrade_sequence(1);
+
+    let mut context = context
+        .with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end)
+        .with_channel_upgrade(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            initiated_upgrade("ics20-2"),
+        );
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation of crossing hellos. Error: {res:?}");
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution of crossing hellos");
+
+    let chan_end_path = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end = context.ibc_store.channel_end(&chan_end_path).unwrap();
+
+    // The upgrade sequence is not incremented again.
+    assert_eq!(chan_end.state, ChannelState::Flushing);
+    assert_eq!(chan_end.upgrade_sequence(), 1);
+
+    let upgrade_path = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let upgrade = context.ibc_store.channel_upgrade(&upgrade_path).unwrap();
+
+    assert!(upgrade.timeout.is_some());
+}
+
+#[rstest]
+fn chan_upgrade_try_execute_crossing_hellos_abort(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        mut chan_end,
+    } = fixture;
+
+    // Chain B initiated an upgrade to a version chain A does not agree on.
+    chan_end.set_upgrade_sequence(1);
+
+    let mut context = context
+        .with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end)
+        .with_channel_upgrade(
+            msg.port_id_on_b.clone(),
+            msg.chan_id_on_b.clone(),
+            initiated_upgrade("ics20-3"),
+        );
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds as the upgrade is aborted. Error: {res:?}"
+    );
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution aborts the upgrade");
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeErrorChannel(_)));
+
+    let chan_end_path = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end = context.ibc_store.channel_end(&chan_end_path).unwrap();
+
+    assert_eq!(chan_end.state, ChannelState::Open);
+    assert_eq!(chan_end.upgrade_sequence(), 1);
+
+    // The upgrade chain B initiated is aborted as well.
+    let upgrade_path = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    assert!(context.ibc_store.channel_upgrade(&upgrade_path).is_err());
+
+    let receipt_path = ChannelUpgradeErrorPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let receipt = context
+        .ibc_store
+        .channel_upgrade_error(&receipt_path)
+        .unwrap();
+
+    assert_eq!(receipt.sequence, 1);
+}
+
+#[rstest]
+fn chan_upgrade_try_execute_abort_outdated_sequence(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        mut chan_end,
+    } = fixture;
+
+    // Chain B already went through an upgrade chain A does not know about.
+    chan_end.set_upgrade_sequence(2);
+
+    let mut context =
+        context.with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution aborts the upgrade");
+
+    let chan_end_path = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end = context.ibc_store.channel_end(&chan_end_path).unwrap();
+
+    assert_eq!(chan_end.state, ChannelState::Open);
+    assert_eq!(chan_end.upgrade_sequence(), 3);
+
+    // Chain A is made to cancel at the sequence it is lagging behind at.
+    let receipt_path = ChannelUpgradeErrorPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let receipt = context
+        .ibc_store
+        .channel_upgrade_error(&receipt_path)
+        .unwrap();
+
+    assert_eq!(receipt.sequence, 2);
+}
+
+#[rstest]
+fn chan_upgrade_try_fail_missing_proof_height(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        chan_end,
+    } = fixture;
+
+    // The client has no consensus state at that height.
+    msg.proof_height_on_a = Height::new(0, 1_000).unwrap();
+
+    let context =
+        context.with_channel(msg.port_id_on_b.clone(), msg.chan_id_on_b.clone(), chan_end);
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the proof height is unknown"
+    );
+}
@@ -0,0 +1,192 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_packet_commitment;
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenInit, MsgRecvPacket, PacketMsg};
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version;
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_chan_open_init, dummy_raw_msg_recv_packet,
+};
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgRecvPacket,
+}
+
+/// Two channels of the `transfer` port, `channel-0` and `channel-1`, which are
+/// each other's counterparty over the `connection-localhost` connection.
+#[fixture]
+fn fixture() -> Fixture {
+    let context = MockContext::default();
+
+    let router = MockRouter::new_with_transfer();
+
+    let host_height = context.latest_height();
+
+    let mut msg =
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(host_height.revision_height())).unwrap();
+
+    msg.packet.chan_id_on_b = ChannelId::new(1);
+    msg.packet.timeout_height_on_b = TimeoutHeight::At(host_height.add(10));
+
+    let chan_end = |counterparty_chan_id: ChannelId| {
+        ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(counterparty_chan_id)),
+            vec![ConnectionId::localhost()],
+            Version::new("ics20-1".to_string()),
+        )
+        .unwrap()
+    };
+
+    let context = context
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end(ChannelId::new(1)),
+        )
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::new(1),
+            chan_end(ChannelId::zero()),
+        );
+
+    Fixture {
+        context,
+        router,
+        msg,
+    }
+}
+
+#[rstest]
+fn localhost_recv_packet_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture;
+
+    let packet = &msg.packet;
+    let context = context.with_packet_commitment(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        packet.seq_on_a,
+        compute_packet_commitment(
+            &packet.data,
+            &packet.timeout_height_on_b,
+            &packet.timeout_timestamp_on_b,
+        ),
+    );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Happy path: validation should succeed. Error: {res:?}"
+    )
+}
+
+#[rstest]
+fn localhost_recv_packet_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+    } = fixture;
+
+    let packet = &msg.packet;
+    let mut context = context.with_packet_commitment(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        packet.seq_on_a,
+        compute_packet_commitment(
+            &packet.data,
+            &packet.timeout_height_on_b,
+            &packet.timeout_timestamp_on_b,
+        ),
+    );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Happy path: execution should succeed");
+}
+
+#[rstest]
+fn localhost_recv_packet_fail_no_commitment(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the packet was never sent on the host"
+    )
+}
+
+#[rstest]
+fn localhost_recv_packet_fail_proof_height_ahead_of_host(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+    } = fixture;
+
+    msg.proof_height_on_a = context.latest_height().increment();
+
+    let packet = &msg.packet;
+    let context = context.with_packet_commitment(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        packet.seq_on_a,
+        compute_packet_commitment(
+            &packet.data,
+            &packet.timeout_height_on_b,
+            &packet.timeout_timestamp_on_b,
+        ),
+    );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the proof height is ahead of the host"
+    )
+}
+
+#[test]
+fn localhost_chan_open_init_validate_happy_path() {
+    let context = MockContext::default();
+    let router = MockRouter::new_with_transfer();
+
+    let mut msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+    msg.connection_hops_on_a = vec![ConnectionId::localhost()];
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds without any stored connection nor client. Error: {res:?}"
+    )
+}
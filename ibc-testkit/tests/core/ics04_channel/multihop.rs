@@ -0,0 +1,197 @@
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenTry};
+use ibc::core::channel::types::multihop::{
+    ConnectionHopProof, ConsensusHopProof, MsgMultihopProofs,
+};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::validate;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::primitives::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_try;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub ctx: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelOpenTry,
+    pub proofs: MsgMultihopProofs,
+}
+
+/// The host `B` opens a multi-hop channel with `A` through an intermediate
+/// chain `X`: over `connection-2` towards `X`, then over `connection-7` of `X`
+/// towards `A`.
+#[fixture]
+fn fixture() -> Fixture {
+    let proof_height = 10;
+    let conn_id_on_b = ConnectionId::new(2);
+    let client_id_on_b = mock_client_type().build_client_id(45);
+
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id_on_b.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let conn_end_on_x = ConnectionEnd::new(
+        ConnectionState::Open,
+        mock_client_type().build_client_id(3),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(1))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let consensus_height_of_a_on_x = Height::new(0, 5).unwrap();
+
+    let proofs = MsgMultihopProofs {
+        key_proof: vec![1].try_into().unwrap(),
+        connection_proofs: vec![ConnectionHopProof {
+            proof: vec![2].try_into().unwrap(),
+            connection_end: conn_end_on_x,
+        }],
+        consensus_proofs: vec![ConsensusHopProof {
+            proof: vec![3].try_into().unwrap(),
+            consensus_height: consensus_height_of_a_on_x,
+            consensus_state: MockConsensusState::new(MockHeader::new(consensus_height_of_a_on_x))
+                .into(),
+        }],
+    };
+
+    let mut msg = MsgChannelOpenTry::try_from(dummy_raw_msg_chan_open_try(proof_height)).unwrap();
+    msg.connection_hops_on_b = vec![conn_id_on_b.clone(), ConnectionId::new(7)];
+    msg.proof_chan_end_on_a = proofs.clone().try_into().unwrap();
+
+    let ctx = MockContext::default()
+        .with_light_client(
+            &client_id_on_b,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_b, conn_end_on_b);
+
+    let router = MockRouter::new_with_transfer();
+
+    Fixture {
+        ctx,
+        router,
+        msg,
+        proofs,
+    }
+}
+
+#[rstest]
+fn multihop_chan_open_try_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        ctx, router, msg, ..
+    } = fixture;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Happy path: validation should succeed. Error: {res:?}"
+    )
+}
+
+#[rstest]
+fn multihop_chan_open_try_fail_single_hop_proof(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        mut msg,
+        ..
+    } = fixture;
+
+    msg.proof_chan_end_on_a = CommitmentProofBytes::try_from(vec![0xff, 0x01]).unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the proof is not a multi-hop proof"
+    )
+}
+
+#[rstest]
+fn multihop_chan_open_try_fail_missing_hop_proofs(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        mut msg,
+        ..
+    } = fixture;
+
+    msg.connection_hops_on_b.push(ConnectionId::new(8));
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the proofs do not cover all the intermediate chains"
+    )
+}
+
+#[rstest]
+fn multihop_chan_open_try_fail_intermediate_connection_not_open(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        mut msg,
+        mut proofs,
+    } = fixture;
+
+    proofs.connection_proofs[0].connection_end.state = ConnectionState::Init;
+    msg.proof_chan_end_on_a = proofs.try_into().unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the connection of the intermediate chain is not open"
+    )
+}
+
+#[rstest]
+fn multihop_chan_open_try_fail_over_localhost(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        mut msg,
+        ..
+    } = fixture;
+
+    msg.connection_hops_on_b[0] = ConnectionId::localhost();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because multi-hop channels cannot start over localhost"
+    )
+}
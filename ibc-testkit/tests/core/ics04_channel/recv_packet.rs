@@ -1,6 +1,6 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
-use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::packet::{Packet, Receipt};
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
@@ -10,8 +10,11 @@ use ibc::core::connection::types::{
 };
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::metering::CostEvent;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ReceiptPath, SeqRecvPath};
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::{dummy_msg_recv_packet, dummy_raw_msg_recv_packet};
@@ -241,3 +244,129 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
     ));
     assert!(matches!(&ibc_events[3], &IbcEvent::WriteAcknowledgement(_)));
 }
+
+#[rstest]
+fn recv_packet_charges_cost_events(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    let mut context = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b.clone())
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b.clone())
+        .advance_block_up_to_height(host_height);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg.clone()));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path. Error: {res:?}");
+
+    let validate_events = core::mem::take(&mut *context.ibc_store.cost_events.lock());
+
+    // Store accesses are charged by the store of the host, which the mock
+    // context does not meter, leaving the proof verified.
+    assert_eq!(
+        validate_events,
+        [CostEvent::proof_verified(&msg.proof_commitment_on_a)]
+    );
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    assert!(context.ibc_store.cost_events.lock().is_empty());
+}
+
+#[rstest]
+fn recv_packet_ordered_allow_timeout_timeout_expired(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        mut chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    chan_end_on_b.ordering = Order::OrderedAllowTimeout;
+
+    let packet_old = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: Vec::new(),
+        timeout_height_on_b: client_height.into(),
+        timeout_timestamp_on_b: Timestamp::from_nanoseconds(1).unwrap(),
+    };
+
+    let msg_packet_old = dummy_msg_recv_packet(
+        packet_old.clone(),
+        msg.proof_commitment_on_a.clone(),
+        msg.proof_height_on_a,
+        dummy_account_id(),
+    );
+
+    let mut context = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_recv_sequence(PortId::transfer(), ChannelId::zero(), 1.into())
+        .advance_block_up_to_height(host_height);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg_packet_old));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "recv_packet validation should succeed for a timed out packet over an ordered allow timeout channel. err: {res:?}"
+    );
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    // The timeout is recorded without calling the module
+    assert!(context.get_events().is_empty());
+
+    let receipt_path_on_b = ReceiptPath::new(
+        &packet_old.port_id_on_b,
+        &packet_old.chan_id_on_b,
+        packet_old.seq_on_a,
+    );
+    assert_eq!(
+        context
+            .ibc_store
+            .get_packet_receipt(&receipt_path_on_b)
+            .unwrap(),
+        Receipt::Timeout
+    );
+
+    let seq_recv_path_on_b = SeqRecvPath::new(&packet_old.port_id_on_b, &packet_old.chan_id_on_b);
+    assert_eq!(
+        context
+            .ibc_store
+            .get_next_sequence_recv(&seq_recv_path_on_b)
+            .unwrap(),
+        2.into()
+    );
+}
@@ -1,6 +1,7 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
 use ibc::core::channel::types::msgs::{MsgTimeout, PacketMsg};
+use ibc::core::channel::types::packet::Receipt;
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
@@ -13,7 +14,10 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::host::types::path::{
+    ChannelEndPath, ClientConsensusStatePath, ReceiptPath, SeqAckPath,
+};
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
@@ -418,3 +422,298 @@ fn timeout_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ibc_events[3], IbcEvent::ChannelClosed(_)));
 }
+
+#[rstest]
+fn timeout_ordered_allow_timeout_channel_validate(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        mut msg,
+        chan_end_on_a_ordered,
+        conn_end_on_a,
+        packet_commitment,
+        client_height,
+        ..
+    } = fixture;
+
+    let mut chan_end_on_a = chan_end_on_a_ordered;
+    chan_end_on_a.ordering = Order::OrderedAllowTimeout;
+
+    // The counterparty recorded the timeout of the packet
+    msg.next_seq_recv_on_b = msg.packet.seq_on_a.increment();
+
+    let packet = msg.packet.clone();
+
+    let ctx = ctx
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_ack_sequence(PortId::transfer(), ChannelId::zero(), packet.seq_on_a)
+        .with_packet_commitment(
+            packet.port_id_on_a,
+            packet.chan_id_on_a,
+            packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Good parameters for ordered allow timeout channels. err: {res:?}"
+    )
+}
+
+#[rstest]
+fn timeout_ordered_allow_timeout_channel_validate_not_received(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        chan_end_on_a_ordered,
+        conn_end_on_a,
+        packet_commitment,
+        client_height,
+        ..
+    } = fixture;
+
+    let mut chan_end_on_a = chan_end_on_a_ordered;
+    chan_end_on_a.ordering = Order::OrderedAllowTimeout;
+
+    let packet = msg.packet.clone();
+
+    let ctx = ctx
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_ack_sequence(PortId::transfer(), ChannelId::zero(), packet.seq_on_a)
+        .with_packet_commitment(
+            packet.port_id_on_a,
+            packet.chan_id_on_a,
+            packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "The counterparty has not reached the packet yet. err: {res:?}"
+    )
+}
+
+#[rstest]
+fn timeout_ordered_allow_timeout_chan_execute(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        mut msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_ordered,
+        ..
+    } = fixture;
+
+    let mut chan_end_on_a = chan_end_on_a_ordered;
+    chan_end_on_a.ordering = Order::OrderedAllowTimeout;
+
+    msg.next_seq_recv_on_b = msg.packet.seq_on_a.increment();
+
+    let packet = msg.packet.clone();
+
+    let mut ctx = ctx
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_ack_sequence(PortId::transfer(), ChannelId::zero(), packet.seq_on_a)
+        .with_packet_commitment(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    let ibc_events = ctx.get_events();
+
+    // The channel is not closed
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::TimeoutPacket(_)));
+
+    let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+    let chan_end_on_a = ctx.ibc_store.channel_end(&chan_end_path_on_a).unwrap();
+    assert_eq!(chan_end_on_a.state, State::Open);
+
+    let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+    assert_eq!(
+        ctx.ibc_store
+            .get_next_sequence_ack(&seq_ack_path_on_a)
+            .unwrap(),
+        packet.seq_on_a.increment()
+    );
+}
+
+struct LocalhostFixture {
+    ctx: MockContext,
+    router: MockRouter,
+    msg: MsgTimeout,
+}
+
+/// A packet sent from `channel-0` to `channel-1` of the `transfer` port, two
+/// ordered allow timeout channels over the `connection-localhost` connection,
+/// which timed out on `channel-1`.
+///
+/// The state of the counterparty is read from the store of the host rather
+/// than proven, so that the claims of the relayer are actually checked.
+#[fixture]
+fn localhost_fixture() -> LocalhostFixture {
+    let ctx = MockContext::default();
+    let router = MockRouter::new_with_transfer();
+
+    let host_height = ctx.latest_height();
+
+    let mut msg =
+        MsgTimeout::try_from(dummy_raw_msg_timeout(host_height.revision_height(), 1, 0)).unwrap();
+    msg.packet.chan_id_on_b = ChannelId::new(1);
+
+    let packet = msg.packet.clone();
+
+    let chan_end = |counterparty_chan_id: ChannelId| {
+        ChannelEnd::new(
+            State::Open,
+            Order::OrderedAllowTimeout,
+            Counterparty::new(PortId::transfer(), Some(counterparty_chan_id)),
+            vec![ConnectionId::localhost()],
+            Version::new("ics20-1".to_string()),
+        )
+        .unwrap()
+    };
+
+    let ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end(ChannelId::new(1)),
+        )
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::new(1),
+            chan_end(ChannelId::zero()),
+        )
+        .with_ack_sequence(PortId::transfer(), ChannelId::zero(), packet.seq_on_a)
+        .with_packet_commitment(
+            packet.port_id_on_a,
+            packet.chan_id_on_a,
+            packet.seq_on_a,
+            compute_packet_commitment(
+                &packet.data,
+                &packet.timeout_height_on_b,
+                &packet.timeout_timestamp_on_b,
+            ),
+        );
+
+    LocalhostFixture { ctx, router, msg }
+}
+
+#[rstest]
+fn timeout_ordered_allow_timeout_localhost_validate_not_received(
+    localhost_fixture: LocalhostFixture,
+) {
+    let LocalhostFixture { ctx, router, msg } = localhost_fixture;
+
+    let ctx = ctx.with_recv_sequence(PortId::transfer(), ChannelId::new(1), msg.packet.seq_on_a);
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "The counterparty has not reached the packet yet. err: {res:?}"
+    )
+}
+
+#[rstest]
+fn timeout_ordered_allow_timeout_localhost_fail_forged_next_seq_recv(
+    localhost_fixture: LocalhostFixture,
+) {
+    let LocalhostFixture { ctx, router, msg } = localhost_fixture;
+
+    // The counterparty received the packet, which the relayer hides by
+    // claiming a stale next sequence to receive.
+    let ctx = ctx.with_recv_sequence(
+        PortId::transfer(),
+        ChannelId::new(1),
+        msg.packet.seq_on_a.increment(),
+    );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the next sequence to receive is not the one claimed"
+    )
+}
+
+#[rstest]
+fn timeout_ordered_allow_timeout_localhost_timeout_recorded(localhost_fixture: LocalhostFixture) {
+    let LocalhostFixture {
+        ctx,
+        router,
+        mut msg,
+    } = localhost_fixture;
+
+    msg.next_seq_recv_on_b = msg.packet.seq_on_a.increment();
+
+    let mut ctx = ctx.with_recv_sequence(
+        PortId::transfer(),
+        ChannelId::new(1),
+        msg.packet.seq_on_a.increment(),
+    );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg.clone()));
+
+    // The counterparty moved past the packet without recording its timeout.
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the counterparty received the packet"
+    );
+
+    let receipt_path_on_b = ReceiptPath::new(
+        &msg.packet.port_id_on_b,
+        &msg.packet.chan_id_on_b,
+        msg.packet.seq_on_a,
+    );
+    ctx.ibc_store
+        .store_packet_receipt(&receipt_path_on_b, Receipt::Timeout)
+        .unwrap();
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "The counterparty recorded the timeout of the packet. err: {res:?}"
+    )
+}
@@ -0,0 +1,614 @@
+use core::time::Duration;
+
+use ibc::core::channel::types::v2::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::v2::commitment::{
+    compute_ack_commitment, compute_packet_commitment,
+};
+use ibc::core::channel::types::v2::msgs::{
+    MsgAcknowledgement, MsgRecvPacket, MsgSendPacket, MsgTimeout, PacketMsg,
+};
+use ibc::core::channel::types::v2::packet::{Packet, Payload};
+use ibc::core::client::types::{CounterpartyInfo, Height};
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ClientId, PortId, Sequence};
+use ibc::core::host::types::path::{AckV2Path, CommitmentV2Path, ReceiptV2Path, SeqSendV2Path};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::Timestamp;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_proof;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::core::router::{DummyPayloadModule, MockRouter, DUMMY_PAYLOAD_ACK};
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+use test_log::test;
+
+struct Fixture {
+    ctx: MockContext,
+    router: MockRouter,
+    /// The client of the host
+    client_id: ClientId,
+    /// The counterparty of the client of the host
+    counterparty_client_id: ClientId,
+    client_height: Height,
+}
+
+/// A host whose `07-tendermint-0` client has the `07-tendermint-1` client as
+/// its counterparty, and a router binding the `transfer` port to an IBC v2
+/// application.
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let counterparty_client_id = ClientId::new("07-tendermint", 1).expect("no error");
+
+    let client_height = Height::new(0, 2).unwrap();
+    let ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_client_counterparty(
+            &client_id,
+            CounterpartyInfo::new(
+                counterparty_client_id.clone(),
+                CommitmentPrefix::try_from(b"ibc".to_vec()).expect("no error"),
+            ),
+        );
+
+    let mut router = MockRouter::default();
+    router
+        .add_payload_route(PortId::transfer(), DummyPayloadModule::new())
+        .unwrap();
+
+    Fixture {
+        ctx,
+        router,
+        client_id,
+        counterparty_client_id,
+        client_height,
+    }
+}
+
+fn dummy_payload() -> Payload {
+    Payload {
+        port_id_on_a: PortId::transfer(),
+        port_id_on_b: PortId::transfer(),
+        version: "ics20-1".to_string(),
+        encoding: "application/json".to_string(),
+        value: b"data".to_vec(),
+    }
+}
+
+fn dummy_packet(
+    client_id_on_a: &ClientId,
+    client_id_on_b: &ClientId,
+    timeout_timestamp_on_b: Timestamp,
+) -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(1),
+        client_id_on_a: client_id_on_a.clone(),
+        client_id_on_b: client_id_on_b.clone(),
+        timeout_timestamp_on_b,
+        payloads: vec![dummy_payload()],
+    }
+}
+
+fn future_timestamp(ctx: &MockContext) -> Timestamp {
+    (ctx.latest_timestamp() + Duration::from_secs(3600)).unwrap()
+}
+
+fn past_timestamp(ctx: &MockContext) -> Timestamp {
+    Timestamp::from_nanoseconds(ctx.latest_timestamp().nanoseconds() - 1).unwrap()
+}
+
+fn dummy_proof_bytes() -> CommitmentProofBytes {
+    CommitmentProofBytes::try_from(dummy_proof()).unwrap()
+}
+
+#[rstest]
+fn send_packet_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+        client_id,
+        counterparty_client_id,
+        ..
+    } = fixture;
+
+    let timeout_timestamp_on_b = future_timestamp(&ctx);
+    let msg = MsgSendPacket {
+        client_id_on_a: client_id.clone(),
+        timeout_timestamp_on_b,
+        payloads: vec![dummy_payload()],
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "validation happy path: {res:?}");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "execution happy path: {res:?}");
+
+    let packet = dummy_packet(&client_id, &counterparty_client_id, timeout_timestamp_on_b);
+    assert_eq!(
+        ctx.ibc_store
+            .get_packet_commitment_v2(&CommitmentV2Path::new(&client_id, packet.seq_on_a))
+            .unwrap(),
+        compute_packet_commitment(&packet)
+    );
+    assert_eq!(
+        ctx.ibc_store
+            .get_next_sequence_send_v2(&SeqSendV2Path::new(&client_id))
+            .unwrap(),
+        Sequence::from(2)
+    );
+
+    let ibc_events = ctx.get_events();
+    assert_eq!(ibc_events.len(), 3);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(&ibc_events[1], IbcEvent::SendPacketV2(e) if e.packet() == &packet));
+    assert!(matches!(ibc_events[2], IbcEvent::Module(_)));
+}
+
+#[rstest]
+fn send_packet_fail_unknown_port(fixture: Fixture) {
+    let Fixture { ctx, client_id, .. } = fixture;
+
+    let msg = MsgSendPacket {
+        client_id_on_a: client_id,
+        timeout_timestamp_on_b: future_timestamp(&ctx),
+        payloads: vec![dummy_payload()],
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &MockRouter::default(), msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because no application is bound to the port of the payload"
+    );
+}
+
+#[rstest]
+fn send_packet_fail_no_counterparty(fixture: Fixture) {
+    let Fixture {
+        router,
+        client_id,
+        client_height,
+        ..
+    } = fixture;
+
+    let ctx = MockContext::default().with_light_client(
+        &client_id,
+        LightClientState::<MockHost>::with_latest_height(client_height),
+    );
+
+    let msg = MsgSendPacket {
+        client_id_on_a: client_id,
+        timeout_timestamp_on_b: future_timestamp(&ctx),
+        payloads: vec![dummy_payload()],
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because no counterparty is registered for the client"
+    );
+}
+
+#[rstest]
+fn send_packet_fail_timed_out(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        client_id,
+        ..
+    } = fixture;
+
+    let msg = MsgSendPacket {
+        client_id_on_a: client_id,
+        timeout_timestamp_on_b: past_timestamp(&ctx),
+        payloads: vec![dummy_payload()],
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the packet already timed out"
+    );
+}
+
+#[rstest]
+fn recv_packet_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+        client_id,
+        counterparty_client_id,
+        client_height,
+    } = fixture;
+
+    let packet = dummy_packet(&counterparty_client_id, &client_id, future_timestamp(&ctx));
+    let msg = MsgRecvPacket {
+        packet: packet.clone(),
+        proof_commitment_on_a: dummy_proof_bytes(),
+        proof_height_on_a: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "validation happy path: {res:?}");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "execution happy path: {res:?}");
+
+    assert!(ctx
+        .ibc_store
+        .get_packet_receipt_v2(&ReceiptV2Path::new(&client_id, packet.seq_on_a))
+        .is_ok());
+
+    let acknowledgement = Acknowledgement::new(vec![DUMMY_PAYLOAD_ACK.to_vec()]).unwrap();
+    assert_eq!(
+        ctx.ibc_store
+            .get_packet_acknowledgement_v2(&AckV2Path::new(&client_id, packet.seq_on_a))
+            .unwrap(),
+        compute_ack_commitment(&acknowledgement)
+    );
+
+    let ibc_events = ctx.get_events();
+    assert_eq!(ibc_events.len(), 4);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::ReceivePacketV2(_)));
+    assert!(matches!(
+        &ibc_events[2],
+        IbcEvent::WriteAcknowledgementV2(e) if e.acknowledgement() == &acknowledgement
+    ));
+    assert!(matches!(ibc_events[3], IbcEvent::Module(_)));
+}
+
+#[rstest]
+fn recv_packet_failure_writes_universal_error_ack(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        client_id,
+        counterparty_client_id,
+        client_height,
+        ..
+    } = fixture;
+
+    let mut router = MockRouter::default();
+    router
+        .add_payload_route(PortId::transfer(), DummyPayloadModule::failing_recv())
+        .unwrap();
+
+    let packet = dummy_packet(&counterparty_client_id, &client_id, future_timestamp(&ctx));
+    let msg = MsgRecvPacket {
+        packet: packet.clone(),
+        proof_commitment_on_a: dummy_proof_bytes(),
+        proof_height_on_a: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(
+        res.is_ok(),
+        "Execution succeeds even though the payload fails to be received: {res:?}"
+    );
+
+    assert_eq!(
+        ctx.ibc_store
+            .get_packet_acknowledgement_v2(&AckV2Path::new(&client_id, packet.seq_on_a))
+            .unwrap(),
+        compute_ack_commitment(&Acknowledgement::universal_error())
+    );
+}
+
+#[rstest]
+fn recv_packet_fail_mismatched_counterparty(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        client_id,
+        client_height,
+        ..
+    } = fixture;
+
+    let other_client_id = ClientId::new("07-tendermint", 2).expect("no error");
+    let msg = MsgRecvPacket {
+        packet: dummy_packet(&other_client_id, &client_id, future_timestamp(&ctx)),
+        proof_commitment_on_a: dummy_proof_bytes(),
+        proof_height_on_a: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the packet was not sent by the counterparty client"
+    );
+}
+
+#[rstest]
+fn recv_packet_fail_timed_out(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        client_id,
+        counterparty_client_id,
+        client_height,
+    } = fixture;
+
+    let msg = MsgRecvPacket {
+        packet: dummy_packet(&counterparty_client_id, &client_id, past_timestamp(&ctx)),
+        proof_commitment_on_a: dummy_proof_bytes(),
+        proof_height_on_a: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the packet timed out on the host"
+    );
+}
+
+/// NO-OP case
+#[rstest]
+fn recv_packet_no_op_already_received(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        client_id,
+        counterparty_client_id,
+        client_height,
+    } = fixture;
+
+    let packet = dummy_packet(&counterparty_client_id, &client_id, future_timestamp(&ctx));
+    let mut ctx = ctx.with_packet_receipt_v2(&client_id, packet.seq_on_a);
+
+    let msg = MsgRecvPacket {
+        packet,
+        proof_commitment_on_a: dummy_proof_bytes(),
+        proof_height_on_a: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(
+        res.is_ok(),
+        "Validation succeeds for a packet already received"
+    );
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(
+        res.is_ok(),
+        "Execution succeeds for a packet already received"
+    );
+    assert!(ctx.get_events().is_empty());
+}
+
+#[rstest]
+fn ack_packet_happy_path(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        client_id,
+        counterparty_client_id,
+        client_height,
+    } = fixture;
+
+    let packet = dummy_packet(&client_id, &counterparty_client_id, future_timestamp(&ctx));
+    let mut ctx = ctx.with_packet_commitment_v2(
+        &client_id,
+        packet.seq_on_a,
+        compute_packet_commitment(&packet),
+    );
+
+    let msg = MsgAcknowledgement {
+        packet: packet.clone(),
+        acknowledgement: Acknowledgement::new(vec![DUMMY_PAYLOAD_ACK.to_vec()]).unwrap(),
+        proof_acked_on_b: dummy_proof_bytes(),
+        proof_height_on_b: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "validation happy path: {res:?}");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "execution happy path: {res:?}");
+
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment_v2(&CommitmentV2Path::new(&client_id, packet.seq_on_a))
+        .is_err());
+
+    let ibc_events = ctx.get_events();
+    assert_eq!(ibc_events.len(), 3);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::AcknowledgePacketV2(_)));
+    assert!(matches!(ibc_events[2], IbcEvent::Module(_)));
+}
+
+#[rstest]
+fn ack_packet_fail_mismatched_ack_count(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        client_id,
+        counterparty_client_id,
+        client_height,
+    } = fixture;
+
+    let packet = dummy_packet(&client_id, &counterparty_client_id, future_timestamp(&ctx));
+    let ctx = ctx.with_packet_commitment_v2(
+        &client_id,
+        packet.seq_on_a,
+        compute_packet_commitment(&packet),
+    );
+
+    let msg = MsgAcknowledgement {
+        packet,
+        acknowledgement: Acknowledgement::new(vec![
+            DUMMY_PAYLOAD_ACK.to_vec(),
+            DUMMY_PAYLOAD_ACK.to_vec(),
+        ])
+        .unwrap(),
+        proof_acked_on_b: dummy_proof_bytes(),
+        proof_height_on_b: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the packet has a single payload"
+    );
+}
+
+/// NO-OP case
+#[rstest]
+fn ack_packet_no_op_no_commitment(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        client_id,
+        counterparty_client_id,
+        client_height,
+    } = fixture;
+
+    let msg = MsgAcknowledgement {
+        packet: dummy_packet(&client_id, &counterparty_client_id, future_timestamp(&ctx)),
+        acknowledgement: Acknowledgement::universal_error(),
+        proof_acked_on_b: dummy_proof_bytes(),
+        proof_height_on_b: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds when no packet commitment is present"
+    );
+}
+
+#[rstest]
+fn timeout_packet_happy_path(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        client_id,
+        counterparty_client_id,
+        client_height,
+    } = fixture;
+
+    // in case of timeout, timeout timestamp should be less than host's timestamp
+    let packet = dummy_packet(&client_id, &counterparty_client_id, past_timestamp(&ctx));
+    let mut ctx = ctx.with_packet_commitment_v2(
+        &client_id,
+        packet.seq_on_a,
+        compute_packet_commitment(&packet),
+    );
+
+    let msg = MsgTimeout {
+        packet: packet.clone(),
+        proof_unreceived_on_b: dummy_proof_bytes(),
+        proof_height_on_b: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "validation happy path: {res:?}");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "execution happy path: {res:?}");
+
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment_v2(&CommitmentV2Path::new(&client_id, packet.seq_on_a))
+        .is_err());
+
+    let ibc_events = ctx.get_events();
+    assert_eq!(ibc_events.len(), 3);
+    assert!(matches!(ibc_events[1], IbcEvent::TimeoutPacketV2(_)));
+}
+
+#[rstest]
+fn timeout_packet_fail_timeout_not_reached(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        client_id,
+        counterparty_client_id,
+        client_height,
+    } = fixture;
+
+    let packet = dummy_packet(&client_id, &counterparty_client_id, future_timestamp(&ctx));
+    let ctx = ctx.with_packet_commitment_v2(
+        &client_id,
+        packet.seq_on_a,
+        compute_packet_commitment(&packet),
+    );
+
+    let msg = MsgTimeout {
+        packet,
+        proof_unreceived_on_b: dummy_proof_bytes(),
+        proof_height_on_b: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the counterparty did not reach the timeout of the packet"
+    );
+}
@@ -0,0 +1,181 @@
+use ibc::core::channel::handler::write_acknowledgement;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_ack_commitment;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::AckPath;
+use ibc::core::host::ValidationContext;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub packet: Packet,
+    pub chan_end_on_b: ChannelEnd,
+    pub acknowledgement: Acknowledgement,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let context = MockContext::default();
+
+    let packet: Packet = PacketConfig::builder().seq_on_a(1.into()).build();
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let acknowledgement = Acknowledgement::try_from(vec![1u8]).unwrap();
+
+    Fixture {
+        context,
+        packet,
+        chan_end_on_b,
+        acknowledgement,
+    }
+}
+
+#[rstest]
+fn write_acknowledgement_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        packet,
+        chan_end_on_b,
+        acknowledgement,
+    } = fixture;
+
+    let mut context = context
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_packet_receipt(PortId::transfer(), ChannelId::zero(), packet.seq_on_a);
+
+    let res = write_acknowledgement(&mut context.ibc_store, &packet, acknowledgement.clone());
+
+    assert!(
+        res.is_ok(),
+        "Happy path: writing the acknowledgement should succeed. Error: {res:?}"
+    );
+
+    let ack_path = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    assert_eq!(
+        context
+            .ibc_store
+            .get_packet_acknowledgement(&ack_path)
+            .unwrap(),
+        compute_ack_commitment(&acknowledgement)
+    );
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        &ibc_events[0],
+        &IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(&ibc_events[1], &IbcEvent::WriteAcknowledgement(_)));
+}
+
+#[rstest]
+fn write_acknowledgement_ordered_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        packet,
+        mut chan_end_on_b,
+        acknowledgement,
+    } = fixture;
+
+    chan_end_on_b.ordering = Order::Ordered;
+
+    let mut context = context
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_recv_sequence(
+            PortId::transfer(),
+            ChannelId::zero(),
+            packet.seq_on_a.increment(),
+        );
+
+    let res = write_acknowledgement(&mut context.ibc_store, &packet, acknowledgement);
+
+    assert!(
+        res.is_ok(),
+        "Happy path: writing the acknowledgement should succeed. Error: {res:?}"
+    );
+}
+
+#[rstest]
+fn write_acknowledgement_fail_not_received(fixture: Fixture) {
+    let Fixture {
+        context,
+        packet,
+        chan_end_on_b,
+        acknowledgement,
+    } = fixture;
+
+    let mut context = context.with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    let res = write_acknowledgement(&mut context.ibc_store, &packet, acknowledgement);
+
+    assert!(
+        res.is_err(),
+        "Writing the acknowledgement fails because the packet was never received"
+    );
+    assert!(context.get_events().is_empty());
+}
+
+#[rstest]
+fn write_acknowledgement_fail_already_acknowledged(fixture: Fixture) {
+    let Fixture {
+        context,
+        packet,
+        chan_end_on_b,
+        acknowledgement,
+    } = fixture;
+
+    let mut context = context
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_packet_receipt(PortId::transfer(), ChannelId::zero(), packet.seq_on_a);
+
+    write_acknowledgement(&mut context.ibc_store, &packet, acknowledgement.clone()).unwrap();
+
+    let res = write_acknowledgement(&mut context.ibc_store, &packet, acknowledgement);
+
+    assert!(
+        res.is_err(),
+        "Writing the acknowledgement again fails because the packet is already acknowledged"
+    );
+}
+
+#[rstest]
+fn write_acknowledgement_fail_closed_channel(fixture: Fixture) {
+    let Fixture {
+        context,
+        packet,
+        mut chan_end_on_b,
+        acknowledgement,
+    } = fixture;
+
+    chan_end_on_b.state = State::Closed;
+
+    let mut context = context
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_packet_receipt(PortId::transfer(), ChannelId::zero(), packet.seq_on_a);
+
+    let res = write_acknowledgement(&mut context.ibc_store, &packet, acknowledgement);
+
+    assert!(
+        res.is_err(),
+        "Writing the acknowledgement fails because the channel is closed"
+    );
+}
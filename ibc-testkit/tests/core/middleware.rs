@@ -0,0 +1,174 @@
+use core::ops::Add;
+use core::time::Duration;
+
+use ibc::core::channel::context::ChannelIcs4Wrapper;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc::core::router::middleware::{Ics4Wrapper, Ics4WrapperLayer};
+use ibc::core::router::module::Module;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{dummy_raw_packet, PacketConfig};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::router::{DummyMiddleware, DUMMY_MIDDLEWARE_PREFIX};
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use test_log::test;
+
+type DummyStack = DummyMiddleware<DummyMiddleware<DummyTransferModule>>;
+
+fn dummy_stack() -> DummyStack {
+    DummyMiddleware::new(DummyMiddleware::new(DummyTransferModule::new()))
+}
+
+/// Wraps the given version once per middleware of the dummy stack.
+fn stack_version(app_version: &str) -> Version {
+    Version::new(format!(
+        "{DUMMY_MIDDLEWARE_PREFIX}{DUMMY_MIDDLEWARE_PREFIX}{app_version}"
+    ))
+}
+
+#[test]
+fn test_middleware_wraps_versions() {
+    let mut stack = dummy_stack();
+
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::zero();
+    let counterparty = Counterparty::new(PortId::transfer(), None);
+    let connection_hops = vec![ConnectionId::zero()];
+
+    let (_, version) = stack
+        .on_chan_open_init_execute(
+            Order::Unordered,
+            &connection_hops,
+            &port_id,
+            &channel_id,
+            &counterparty,
+            &stack_version("ics20-1"),
+        )
+        .unwrap();
+
+    assert_eq!(version, stack_version("ics20-1"));
+
+    // a version missing the metadata of one of the middlewares is rejected
+    let partially_wrapped_version = Version::new(format!("{DUMMY_MIDDLEWARE_PREFIX}ics20-1"));
+
+    assert!(stack
+        .on_chan_open_try_validate(
+            Order::Unordered,
+            &connection_hops,
+            &port_id,
+            &channel_id,
+            &counterparty,
+            &partially_wrapped_version,
+        )
+        .is_err());
+}
+
+#[test]
+fn test_middleware_wraps_acknowledgements() {
+    let mut stack = dummy_stack();
+    let packet: Packet = PacketConfig::builder().build();
+    let relayer = dummy_account_id();
+
+    let (_, ack) = stack.on_recv_packet_execute(&packet, &relayer);
+    let ack = ack.expect("the acknowledgement is written synchronously");
+
+    let mut expected_ack = stack_version("").as_str().as_bytes().to_vec();
+    // the acknowledgement of `DummyTransferModule`
+    expected_ack.push(1);
+
+    assert_eq!(ack.as_bytes(), expected_ack.as_slice());
+
+    stack
+        .on_acknowledgement_packet_validate(&packet, &ack, &relayer)
+        .unwrap();
+
+    // an acknowledgement missing the metadata of the middlewares is rejected
+    let app_ack = Acknowledgement::try_from(vec![1]).unwrap();
+
+    assert!(stack
+        .on_acknowledgement_packet_validate(&packet, &app_ack, &relayer)
+        .is_err());
+}
+
+#[test]
+fn test_middleware_send_packet_pass_through() {
+    let client_id = ClientId::new("07-tendermint", 0).unwrap();
+    let client_height = Height::new(0, 5).unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        stack_version("ics20-1"),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).unwrap(),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a);
+
+    let timeout_timestamp = Timestamp::now().add(Duration::from_secs(10)).unwrap();
+
+    let mut packet: Packet = dummy_raw_packet(10, timeout_timestamp.nanoseconds())
+        .try_into()
+        .unwrap();
+    packet.seq_on_a = 1.into();
+    packet.data = vec![0];
+
+    let stack = dummy_stack();
+
+    {
+        let mut core = ChannelIcs4Wrapper::new(&mut ctx.ibc_store);
+        let mut outer = Ics4WrapperLayer::new(&stack, &mut core);
+        let inner = ibc::core::router::middleware::Middleware::inner(&stack);
+        let mut ics4_wrapper = Ics4WrapperLayer::new(inner, &mut outer);
+
+        let app_version = ics4_wrapper
+            .app_version(&PortId::transfer(), &ChannelId::zero())
+            .unwrap();
+
+        assert_eq!(app_version, Version::new("ics20-1".to_string()));
+
+        ics4_wrapper.send_packet(packet.clone()).unwrap();
+    }
+
+    let commitment_path =
+        CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+
+    assert!(ctx
+        .ibc_store
+        .get_packet_commitment(&commitment_path)
+        .is_ok());
+}
@@ -1,5 +1,7 @@
+pub mod entrypoint;
 pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
+pub mod middleware;
 #[cfg(feature = "serde")]
 pub mod router;